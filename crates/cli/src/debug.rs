@@ -0,0 +1,173 @@
+use mihi::simulation::{simulate, SimulationParams};
+use mihi::word::select_all_words;
+
+// Default values for the simulation when none were given.
+const DEFAULT_DAYS: usize = 30;
+const DEFAULT_ACCURACY: f64 = 0.85;
+const DEFAULT_REVIEWS: usize = 15;
+
+// Width of the bars for the retention curve.
+const BAR_WIDTH: usize = 30;
+
+fn help(msg: Option<&str>) {
+    if let Some(msg) = msg {
+        println!("{}.\n", msg);
+    }
+
+    println!("mihi debug: Tools for developers of this application.\n");
+    println!("usage: mihi debug [OPTIONS] <COMMAND> [COMMAND OPTIONS]\n");
+
+    println!("Options:");
+    println!("   -h, --help\t\tPrint this message.\n");
+
+    println!("Commands:");
+    println!("   simulate\t\tSimulate practice sessions against the scheduler.");
+}
+
+fn simulate_help(msg: Option<&str>) {
+    if let Some(msg) = msg {
+        println!("{}.\n", msg);
+    }
+
+    println!("mihi debug simulate: Simulate practice sessions against the scheduler.\n");
+    println!("usage: mihi debug simulate [OPTIONS]\n");
+
+    println!("The words from the database are reviewed in memory, one session per day, and");
+    println!("the database is left untouched.\n");
+
+    println!("Options:");
+    println!("   -a, --accuracy <F>\tProbability for a review to be correct, from 0 to 1 (default: {DEFAULT_ACCURACY}).");
+    println!("   -d, --days <N>\tNumber of days to simulate (default: {DEFAULT_DAYS}).");
+    println!("   -h, --help\t\tPrint this message.");
+    println!("   -r, --reviews <N>\tNumber of reviews per day (default: {DEFAULT_REVIEWS}).");
+    println!("   -s, --seed <N>\tSeed for the outcome of the reviews (default: 1).");
+}
+
+// Parse the arguments for the 'simulate' command and print the resulting
+// workload and retention for each day.
+fn run_simulate(args: Vec<String>) -> i32 {
+    let mut it = args.into_iter();
+    let mut params = SimulationParams {
+        days: DEFAULT_DAYS,
+        accuracy: DEFAULT_ACCURACY,
+        reviews_per_day: DEFAULT_REVIEWS,
+        seed: 1,
+    };
+
+    while let Some(first) = it.next() {
+        match first.as_str() {
+            "-h" | "--help" => {
+                simulate_help(None);
+                return 0;
+            }
+            "-a" | "--accuracy" => match it.next().map(|n| n.trim().parse::<f64>()) {
+                Some(Ok(n)) if (0.0..=1.0).contains(&n) => params.accuracy = n,
+                _ => {
+                    simulate_help(Some(
+                        "error: debug: you have to provide an accuracy between 0 and 1",
+                    ));
+                    return 1;
+                }
+            },
+            "-d" | "--days" => match it.next().map(|n| n.trim().parse::<usize>()) {
+                Some(Ok(n)) if n > 0 => params.days = n,
+                _ => {
+                    simulate_help(Some(
+                        "error: debug: you have to provide a positive number of days",
+                    ));
+                    return 1;
+                }
+            },
+            "-r" | "--reviews" => match it.next().map(|n| n.trim().parse::<usize>()) {
+                Some(Ok(n)) if n > 0 => params.reviews_per_day = n,
+                _ => {
+                    simulate_help(Some(
+                        "error: debug: you have to provide a positive number of reviews",
+                    ));
+                    return 1;
+                }
+            },
+            "-s" | "--seed" => match it.next().map(|n| n.trim().parse::<u64>()) {
+                Some(Ok(n)) => params.seed = n,
+                _ => {
+                    simulate_help(Some("error: debug: you have to provide a numeric seed"));
+                    return 1;
+                }
+            },
+            _ => {
+                simulate_help(Some(
+                    format!("error: debug: unknown flag '{first}'").as_str(),
+                ));
+                return 1;
+            }
+        }
+    }
+
+    let words: Vec<_> = match select_all_words() {
+        Ok(words) => words
+            .into_iter()
+            .filter(|word| {
+                word.translation
+                    .as_object()
+                    .is_some_and(|translation| !translation.is_empty())
+            })
+            .collect(),
+        Err(e) => {
+            println!("error: debug: {e}");
+            return 1;
+        }
+    };
+    if words.is_empty() {
+        println!("error: debug: there are no words with a translation to review");
+        return 1;
+    }
+
+    println!(
+        "Simulating {} days of {} reviews with an accuracy of {:.0}% on {} words.\n",
+        params.days,
+        params.reviews_per_day,
+        params.accuracy * 100.0,
+        words.len()
+    );
+    println!("Day\tReviews\tCorrect\tPending\tSeen\tLearned\tRetention");
+    for stats in simulate(&words, &params) {
+        let retention = stats.retention();
+        println!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{:>5.1}% {}",
+            stats.day,
+            stats.reviews,
+            stats.correct,
+            stats.pending,
+            stats.seen,
+            stats.learned,
+            retention,
+            "█".repeat((retention * BAR_WIDTH as f64 / 100.0).round() as usize)
+        );
+    }
+
+    0
+}
+
+pub fn run(args: Vec<String>) {
+    let mut it = args.into_iter();
+
+    match it.next() {
+        Some(first) => match first.as_str() {
+            "-h" | "--help" => {
+                help(None);
+                std::process::exit(0);
+            }
+            "simulate" => std::process::exit(run_simulate(it.collect())),
+            _ => {
+                help(Some(
+                    format!("error: debug: unknown flag or command '{first}'").as_str(),
+                ));
+                std::process::exit(1);
+            }
+        },
+        None => {
+            help(Some("error: debug: you have to provide a command"));
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use mihi::schedule::select_due_words;
+use serde_json::json;
+
+fn help() {
+    println!("mihi due: Show how many words are due for review today.\n");
+    println!("usage: mihi due [OPTIONS]\n");
+
+    println!("Options:");
+    println!("   -h, --help\t\tPrint this message.");
+    println!("   --json\t\tPrint the counts as a JSON object (e.g. for status bars).");
+}
+
+pub fn run(args: Vec<String>) {
+    let mut json = false;
+
+    for arg in args {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                help();
+                std::process::exit(0);
+            }
+            "--json" => {
+                json = true;
+            }
+            _ => {
+                println!("error: due: unknown flag: '{}'", arg.as_str());
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let due = match select_due_words() {
+        Ok(due) => due,
+        Err(e) => {
+            println!("error: due: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    if json {
+        let categories: serde_json::Map<String, serde_json::Value> = due
+            .categories
+            .iter()
+            .map(|(category, count)| (category.to_string(), json!(count)))
+            .collect();
+        let tags: serde_json::Map<String, serde_json::Value> = due
+            .tags
+            .iter()
+            .map(|(tag, count)| (tag.clone(), json!(count)))
+            .collect();
+        println!(
+            "{}",
+            json!({"total": due.total, "categories": categories, "tags": tags})
+        );
+        return;
+    }
+
+    match due.total {
+        0 => {
+            println!("Nothing is due for review today.");
+            return;
+        }
+        1 => println!("1 word is due for review today."),
+        n => println!("{n} words are due for review today."),
+    }
+
+    println!("\nBy category:");
+    for (category, count) in &due.categories {
+        println!("   {category}: {count}");
+    }
+    if !due.tags.is_empty() {
+        println!("\nBy tag:");
+        for (tag, count) in &due.tags {
+            println!("   {tag}: {count}");
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use inquire::Text;
+use mihi::exam::{
+    build_exam, expand_tag_spec, partial_credit, save_exam, select_exams, ExamSummary, TagGroup,
+};
+use mihi::tag::select_tag_names;
+
+use crate::interactive::{can_prompt, Ask};
+use crate::locale::current_locale;
+
+// Number of questions for an exam when none was given.
+const DEFAULT_SIZE: usize = 20;
+
+fn help(msg: Option<&str>) {
+    if let Some(msg) = msg {
+        println!("{}.\n", msg);
+    }
+
+    println!("mihi exam: Take an exam on the words from the given tags.\n");
+    println!("usage: mihi exam [OPTIONS]\n");
+
+    println!("Options:");
+    println!("   -h, --help\t\tPrint this message.");
+    println!("   -l, --list\t\tShow the summaries of the exams taken so far.");
+    println!("   -s, --size <N>\tNumber of questions (default: {DEFAULT_SIZE}).");
+    println!("   -t, --tag <SPEC>\tTake the words from the given tag. It can be a range of numbered tags such as 'cap-1..cap-10', and children tags (e.g. 'cap-1/lectiō') are included as well. Multiple tags can be provided.");
+}
+
+// Print the summaries of all the exams taken so far.
+fn list() -> i32 {
+    match select_exams() {
+        Ok(exams) => {
+            if exams.is_empty() {
+                println!("exam: no exams have been taken yet");
+            }
+            for (idx, exam) in exams.iter().enumerate() {
+                if idx > 0 {
+                    println!();
+                }
+                println!("{exam}");
+            }
+            0
+        }
+        Err(e) => {
+            println!("error: exam: {e}");
+            1
+        }
+    }
+}
+
+// Ask the questions of an exam built from the given tag `specs`, and save its
+// summary unless the user aborted it.
+fn take(specs: &[String], size: usize) -> i32 {
+    let existing = match select_tag_names(&None) {
+        Ok(names) => names,
+        Err(e) => {
+            println!("error: exam: {e}");
+            return 1;
+        }
+    };
+    let mut groups: Vec<TagGroup> = vec![];
+    for spec in specs {
+        match expand_tag_spec(spec, &existing) {
+            Ok(expanded) => groups.extend(expanded),
+            Err(e) => {
+                println!("error: exam: {e}");
+                return 1;
+            }
+        }
+    }
+
+    let locale = current_locale();
+    let questions = match build_exam(&groups, size, locale.to_code()) {
+        Ok(questions) => questions,
+        Err(e) => {
+            println!("error: exam: {e}");
+            return 1;
+        }
+    };
+    if questions.is_empty() {
+        println!("error: exam: there are no words with a translation for these tags");
+        return 1;
+    }
+    if questions.len() < size {
+        println!(
+            "warning: exam: there are only {} words available for these tags",
+            questions.len()
+        );
+    }
+
+    let mut scores = vec![];
+    for (idx, question) in questions.iter().enumerate() {
+        println!(
+            "\nQuestion {}/{}: {}",
+            idx + 1,
+            questions.len(),
+            question.word.enunciated
+        );
+        let Ok(answer) = Text::new(format!("Translation ({locale}):").as_str()).ask() else {
+            println!("Exam aborted, nothing was saved.");
+            return 0;
+        };
+
+        let score = partial_credit(&answer, &question.expected);
+        if score == 1.0 {
+            println!("\x1b[92m✓ {}\x1b[0m", question.expected);
+        } else if score > 0.0 {
+            println!("\x1b[93m½ {}\x1b[0m", question.expected);
+        } else {
+            println!("\x1b[91m❌{}\x1b[0m", question.expected);
+        }
+        scores.push(score);
+    }
+
+    let summary = ExamSummary::new(&specs.join(", "), &questions, &scores);
+    match save_exam(summary) {
+        Ok(summary) => {
+            println!("\n{summary}");
+            0
+        }
+        Err(e) => {
+            println!("error: exam: {e}");
+            1
+        }
+    }
+}
+
+pub fn run(args: Vec<String>) {
+    let mut it = args.into_iter();
+    let mut specs: Vec<String> = vec![];
+    let mut size = DEFAULT_SIZE;
+    let mut listing = false;
+
+    while let Some(first) = it.next() {
+        match first.as_str() {
+            "-h" | "--help" => {
+                help(None);
+                std::process::exit(0);
+            }
+            "-l" | "--list" => {
+                listing = true;
+            }
+            "-s" | "--size" => match it.next().map(|n| n.trim().parse::<usize>()) {
+                Some(Ok(n)) if n > 0 => size = n,
+                _ => {
+                    help(Some(
+                        "error: exam: you have to provide a positive number of questions",
+                    ));
+                    std::process::exit(1);
+                }
+            },
+            "-t" | "--tag" => match it.next() {
+                Some(spec) => specs.push(spec.trim().to_string()),
+                None => {
+                    help(Some("error: exam: you have to provide a tag name"));
+                    std::process::exit(1);
+                }
+            },
+            _ => {
+                help(Some(
+                    format!("error: exam: unknown flag or command '{first}'").as_str(),
+                ));
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if listing {
+        std::process::exit(list());
+    }
+    if specs.is_empty() {
+        help(Some("error: exam: you have to provide at least one tag"));
+        std::process::exit(1);
+    }
+    if !can_prompt() {
+        println!("error: exam: this command needs an interactive terminal");
+        std::process::exit(1);
+    }
+
+    std::process::exit(take(&specs, size));
+}
@@ -1,8 +1,23 @@
-use inquire::{Confirm, Editor, Select, Text};
+use crate::interactive::{can_prompt, confirm, is_interactive, print_lines, take_flag, Ask};
+use crate::markdown::to_ansi;
+use crate::run::{run_exercise, SessionOptions};
+use crate::tags::paint_tag;
+use inquire::{Editor, MultiSelect, Select, Text};
 use mihi::exercise::{
-    create_exercise, delete_exercise, find_exercise_by_title, select_by_title, update_exercise,
-    Exercise, ExerciseKind,
+    create_exercise, delete_exercise, find_exercise_by_title, from_markdown, select_by_title,
+    select_exercise_words, select_timed_exercises, set_exercise_words, to_markdown,
+    update_exercise, Exercise, ExerciseKind, DEFAULT_EXERCISE_MINUTES, MAX_SCORE,
 };
+use mihi::pensum::Pensum;
+use mihi::tag::{
+    attach_tag_to_exercise, dettach_tags_from_exercise, find_or_create_tag, select_tag_names,
+    select_tags_for, select_tags_for_exercise, Tag,
+};
+use mihi::template::Template;
+use mihi::word::select_enunciated;
+use std::collections::HashSet;
+use std::io::{stdout, IsTerminal};
+use std::path::Path;
 use std::vec::IntoIter;
 
 // Show the help message.
@@ -16,12 +31,91 @@ fn help(msg: Option<&str>) {
 
     println!("Options:");
     println!("   -h, --help\t\tPrint this message.");
+    println!(
+        "   --hide-solution\tOnly for 'show': ask for confirmation before showing the solution."
+    );
+    println!("   --json\t\tOnly for 'ls': print the list as a JSON array.");
+    println!("   -k, --kind <KIND>\tOnly for 'run': only pick exercises of the given <KIND>.");
+    println!(
+        "   --minutes <N>\tOnly for 'run': pick as many exercises as fit in <N> minutes. Required unless a title is given."
+    );
+    println!("   -y, --yes\t\tOnly for 'rm': do not ask for confirmation. Required when there is no terminal.");
 
     println!("\nSubcommands:");
+    println!("   clone\t\t\tCreate a copy of an exercise and edit it. The copy has its title suffixed with '(copy)'.");
     println!("   create\t\tCreate a new exercise.");
     println!("   edit\t\t\tEdit information from an exercise.");
+    println!(
+        "   export <DIR>\t\tWrite each exercise as a Markdown file with front matter into <DIR>."
+    );
+    println!("   import <DIR>\t\tCreate or update exercises from the Markdown files in <DIR>, as written by 'export'.");
     println!("   ls\t\t\tList exercises from the database.");
     println!("   rm\t\t\tRemove an exercises from the database.");
+    println!("   show\t\t\tShow all the information from an exercise.");
+    println!("   run\t\t\tPractice exercises which fit in the given time budget, or the one matching the given title.");
+}
+
+// Interactively ask the user for the enunciate and the solution of an exercise
+// of the given `kind`, starting with the ones from the given `exercise`.
+fn ask_for_enunciate_and_solution(
+    kind: ExerciseKind,
+    exercise: &Exercise,
+) -> Result<(String, String), String> {
+    let Ok(enunciate) = Editor::new("Enunciate:")
+        .with_predefined_text(&exercise.enunciate)
+        .with_file_extension(".md")
+        .ask()
+    else {
+        return Err("abort!".to_string());
+    };
+    let enunciate = enunciate.trim().to_string();
+    if enunciate.is_empty() {
+        return Err("the enunciate is required".to_string());
+    }
+
+    // The solution of a pensum is usually given by the answers on its blanks,
+    // unless some of them were written as '___'. In that case, the user has to
+    // fill them in on the enunciate itself.
+    let pensum = match kind {
+        ExerciseKind::Pensum => Some(Pensum::try_from(enunciate.as_str())?),
+        _ => None,
+    };
+    let template = match kind {
+        ExerciseKind::Template => Some(Template::enunciate(&enunciate)?),
+        _ => None,
+    };
+    let solution = match pensum {
+        Some(pensum) if !pensum.needs_solution() => pensum.solution(),
+        // A dictation is just the text being dictated.
+        _ if matches!(kind, ExerciseKind::Dictation) => enunciate.clone(),
+        _ => {
+            let predefined = if pensum.is_some() && exercise.solution.is_empty() {
+                &enunciate
+            } else {
+                &exercise.solution
+            };
+            let Ok(solution) = Editor::new("Solution:")
+                .with_predefined_text(predefined)
+                .with_file_extension(".md")
+                .ask()
+            else {
+                return Err("abort!".to_string());
+            };
+            let solution = solution.trim().to_string();
+            if pensum.is_some() {
+                Pensum::with_solution(&enunciate, &solution)?;
+            }
+            if let Some(template) = &template {
+                Template::solution(template, &solution)?;
+            }
+            solution
+        }
+    };
+    if solution.trim().is_empty() {
+        return Err("the solution is required".to_string());
+    }
+
+    Ok((enunciate, solution))
 }
 
 // Interactively ask the user to fill up an exercise based on the given
@@ -29,7 +123,7 @@ fn help(msg: Option<&str>) {
 fn ask_for_exercise_based_on(exercise: Exercise) -> Result<Exercise, String> {
     let Ok(title) = Text::new("Title:")
         .with_initial_value(&exercise.title)
-        .prompt()
+        .ask()
     else {
         return Err("abort!".to_string());
     };
@@ -37,49 +131,123 @@ fn ask_for_exercise_based_on(exercise: Exercise) -> Result<Exercise, String> {
         return Err("the title is required".to_string());
     }
 
-    let Ok(enunciate) = Editor::new("Enunciate:")
-        .with_predefined_text(&exercise.enunciate)
-        .with_file_extension(".md")
-        .prompt()
+    let kinds = vec![
+        ExerciseKind::Simple,
+        ExerciseKind::Pensum,
+        ExerciseKind::Numerical,
+        ExerciseKind::Transformation,
+        ExerciseKind::Template,
+        ExerciseKind::Parsing,
+        ExerciseKind::Composition,
+        ExerciseKind::Dictation,
+    ];
+    let Ok(kind) = Select::new("Kind:", kinds)
+        .with_starting_cursor(exercise.kind as usize)
+        .with_help_message(
+            "A pensum has blanks like '{marī|mare?hint}', '[marī]' or '___' on its enunciate, and a template has placeholders like '{{noun:acc:sg}}' or '{{adjective:acc:sg:noun}}'. A composition is an English text with a Latin model translation, and a dictation is a Latin text read out loud",
+        )
+        .ask()
     else {
         return Err("abort!".to_string());
     };
-    let enunciate = enunciate.trim().to_string();
-    if enunciate.is_empty() {
-        return Err("the enunciate is required".to_string());
-    }
 
-    let Ok(solution) = Editor::new("Solution:")
-        .with_predefined_text(&exercise.solution)
-        .with_file_extension(".md")
-        .prompt()
-    else {
-        return Err("abort!".to_string());
+    // Some exercises are generated each time, so there is nothing to be
+    // written for them.
+    let (enunciate, solution) = match kind.generated_enunciate() {
+        Some(enunciate) => (enunciate.to_string(), String::new()),
+        None => ask_for_enunciate_and_solution(kind, &exercise)?,
     };
-    let solution = solution.trim().to_string();
-    if solution.trim().is_empty() {
-        return Err("the solution is required".to_string());
-    }
 
     let Ok(lessons) = Editor::new("Lessons:")
         .with_predefined_text(&exercise.lessons)
         .with_file_extension(".md")
-        .prompt()
+        .ask()
     else {
         return Err("abort!".to_string());
     };
     let lessons = lessons.trim().to_string();
 
+    let initial = if exercise.minutes == 0 {
+        DEFAULT_EXERCISE_MINUTES
+    } else {
+        exercise.minutes
+    };
+    let Ok(minutes) = Text::new("Expected minutes:")
+        .with_initial_value(&initial.to_string())
+        .ask()
+    else {
+        return Err("abort!".to_string());
+    };
+    let minutes = match minutes.trim().parse::<usize>() {
+        Ok(0) | Err(_) => return Err("the expected minutes must be a positive number".to_string()),
+        Ok(minutes) => minutes,
+    };
+
     Ok(Exercise {
         id: exercise.id,
         title,
         enunciate,
         solution,
         lessons,
-        kind: ExerciseKind::Simple,
+        kind,
+        minutes,
+        score: exercise.score,
+        attempts: exercise.attempts,
+        last_attempted_at: exercise.last_attempted_at,
+        due_at: exercise.due_at,
     })
 }
 
+// Interactively ask the user for the tags of an exercise, starting with the
+// given `current` ones. Nothing is asked if there are no tags at all.
+fn ask_for_tags(current: &[Tag]) -> Result<Vec<Tag>, String> {
+    let all_tags = select_tags_for(None)?;
+    if all_tags.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let default_indices: Vec<usize> = all_tags
+        .iter()
+        .enumerate()
+        .filter(|(_, tag)| current.iter().any(|c| c.id == tag.id))
+        .map(|(idx, _)| idx)
+        .collect();
+    match MultiSelect::new("Tags:", all_tags)
+        .with_starting_cursor(0)
+        .with_default(&default_indices)
+        .ask()
+    {
+        Ok(selected) => Ok(selected),
+        Err(_) => Err("abort!".to_string()),
+    }
+}
+
+// Interactively ask the user for the words which are exercised by an exercise,
+// starting with the given `current` ones. Nothing is asked if there are no
+// words at all.
+fn ask_for_words(current: &[String]) -> Result<Vec<String>, String> {
+    let all_words = select_enunciated(None, &[])?;
+    if all_words.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let default_indices: Vec<usize> = all_words
+        .iter()
+        .enumerate()
+        .filter(|(_, word)| current.contains(word))
+        .map(|(idx, _)| idx)
+        .collect();
+    match MultiSelect::new("Words:", all_words)
+        .with_starting_cursor(0)
+        .with_default(&default_indices)
+        .with_help_message("The exercise is only proposed once these words are mastered")
+        .ask()
+    {
+        Ok(selected) => Ok(selected),
+        Err(_) => Err("abort!".to_string()),
+    }
+}
+
 fn create(args: IntoIter<String>) -> i32 {
     if args.len() > 0 {
         help(Some(
@@ -96,9 +264,37 @@ fn create(args: IntoIter<String>) -> i32 {
         }
     };
 
+    let tags = match ask_for_tags(&[]) {
+        Ok(tags) => tags,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+    let words = match ask_for_words(&[]) {
+        Ok(words) => words,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+
+    save_new_exercise(exercise, &tags, &words)
+}
+
+// Creates the given `exercise` with the given `tags` and `words` linked to it.
+fn save_new_exercise(exercise: Exercise, tags: &[Tag], words: &[String]) -> i32 {
     let title = exercise.title.clone();
     match create_exercise(exercise) {
-        Ok(_) => {
+        Ok(exercise_id) => {
+            for tag in tags {
+                if let Err(e) = attach_tag_to_exercise(tag.id as i64, exercise_id) {
+                    println!("warning: exercises: {e}");
+                }
+            }
+            if let Err(e) = set_exercise_words(exercise_id, words) {
+                println!("warning: exercises: {e}");
+            }
             println!("Exercise '{title}' has been successfully created!");
             0
         }
@@ -109,15 +305,83 @@ fn create(args: IntoIter<String>) -> i32 {
     }
 }
 
+fn clone(mut args: IntoIter<String>) -> i32 {
+    if args.len() > 1 {
+        help(Some("error: exercises: too many filters"));
+        return 1;
+    }
+
+    let original = match select_single_exercise(args.next()) {
+        Ok(exercise) => exercise,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+    let tags = match select_tags_for_exercise(original.id) {
+        Ok(tags) => tags,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+    let words = match select_exercise_words(original.id) {
+        Ok(words) => words,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+
+    // The copy starts from scratch: it has never been attempted.
+    let copy = Exercise {
+        title: format!("{} (copy)", original.title),
+        enunciate: original.enunciate,
+        solution: original.solution,
+        lessons: original.lessons,
+        kind: original.kind,
+        minutes: original.minutes,
+        ..Default::default()
+    };
+    let exercise = match ask_for_exercise_based_on(copy) {
+        Ok(ex) => ex,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+    if find_exercise_by_title(&exercise.title).is_ok() {
+        println!("error: exercises: '{}' already exists", exercise.title);
+        return 1;
+    }
+    let selected_tags = match ask_for_tags(&tags) {
+        Ok(selected) => selected,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+    let selected_words = match ask_for_words(&words) {
+        Ok(selected) => selected,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+
+    save_new_exercise(exercise, &selected_tags, &selected_words)
+}
+
 fn select_single_exercise(search: Option<String>) -> Result<Exercise, String> {
-    let exercises = select_by_title(search)?;
+    let exercises = select_by_title(search, &[])?;
 
     let title = match exercises.len() {
         0 => return Err("not found".to_string()),
         1 => exercises.first().unwrap().to_owned(),
+        n if !is_interactive() => return Err(format!("{n} exercises match, be more specific")),
         _ => match Select::new("Which exercise?", exercises)
             .with_page_size(20)
-            .prompt()
+            .ask()
         {
             Ok(choice) => choice,
             Err(_) => return Err("abort!".to_string()),
@@ -141,6 +405,21 @@ fn edit(mut args: IntoIter<String>) -> i32 {
         }
     };
 
+    let tags = match select_tags_for_exercise(exercise.id) {
+        Ok(tags) => tags,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+    let words = match select_exercise_words(exercise.id) {
+        Ok(words) => words,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+
     let exercise = match ask_for_exercise_based_on(exercise) {
         Ok(ex) => ex,
         Err(e) => {
@@ -148,10 +427,44 @@ fn edit(mut args: IntoIter<String>) -> i32 {
             return 1;
         }
     };
+    let selected_tags = match ask_for_tags(&tags) {
+        Ok(selected) => selected,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+    let selected_words = match ask_for_words(&words) {
+        Ok(selected) => selected,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+    let tags_to_remove: Vec<i32> = tags
+        .iter()
+        .filter(|et| !selected_tags.iter().any(|st| st.id == et.id))
+        .map(|et| et.id)
+        .collect();
 
     let title = exercise.title.clone();
+    let exercise_id = exercise.id as i64;
     match update_exercise(exercise) {
         Ok(_) => {
+            for st in &selected_tags {
+                if tags.iter().any(|et| st.id == et.id) {
+                    continue;
+                }
+                if let Err(e) = attach_tag_to_exercise(st.id as i64, exercise_id) {
+                    println!("warning: exercises: {e}");
+                }
+            }
+            if let Err(e) = dettach_tags_from_exercise(&tags_to_remove, exercise_id) {
+                println!("warning: exercises: {e}");
+            }
+            if let Err(e) = set_exercise_words(exercise_id, &selected_words) {
+                println!("warning: exercises: {e}");
+            }
             println!("Exercise '{title}' has been successfully updated!");
             0
         }
@@ -162,13 +475,57 @@ fn edit(mut args: IntoIter<String>) -> i32 {
     }
 }
 
-fn ls(mut args: IntoIter<String>) -> i32 {
-    if args.len() > 1 {
+fn ls(args: IntoIter<String>) -> i32 {
+    let (json, mut args) = take_flag(args, &["--json"]);
+    let mut tags = vec![];
+    let mut filters = vec![];
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-t" | "--tag" | "--tags" => match args.next() {
+                Some(t) => {
+                    for name in t.split(',').map(|name| name.trim().to_string()) {
+                        if name.is_empty() {
+                            continue;
+                        }
+                        if let Ok(results) = select_tag_names(&Some(name.clone())) {
+                            if results.is_empty() {
+                                println!("warning: exercises: the tag '{}' does not exist.", name);
+                            } else {
+                                tags.push(name)
+                            }
+                        }
+                    }
+                }
+                None => {
+                    help(Some("error: exercises: you have to provide a tag name"));
+                    return 1;
+                }
+            },
+            _ => filters.push(arg),
+        }
+    }
+    if filters.len() > 1 {
         help(Some("error: exercises: too many filters"));
         return 1;
     }
 
-    let exercises = select_by_title(args.next()).unwrap_or(vec![]);
+    let exercises = match select_by_title(filters.pop(), &tags) {
+        Ok(exercises) => exercises,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+    if json {
+        println!("{}", serde_json::json!(exercises));
+        return 0;
+    }
+    if !is_interactive() {
+        // Plain titles are easier to consume from other tools.
+        print_lines(&exercises);
+        return 0;
+    }
     for exe in exercises {
         println!("- '{}'", exe);
     }
@@ -176,7 +533,8 @@ fn ls(mut args: IntoIter<String>) -> i32 {
     0
 }
 
-fn rm(mut args: IntoIter<String>) -> i32 {
+fn rm(args: IntoIter<String>) -> i32 {
+    let (yes, mut args) = take_flag(args, &["-y", "--yes"]);
     if args.len() > 1 {
         help(Some("error: exercises: too many filters"));
         return 1;
@@ -191,11 +549,10 @@ fn rm(mut args: IntoIter<String>) -> i32 {
     };
     let selection = exercise.title.as_str();
 
-    let ans = Confirm::new(
+    let ans = confirm(
         format!("Do you really want to remove '{selection}' from the database?",).as_str(),
-    )
-    .with_default(false)
-    .prompt();
+        yes,
+    );
 
     match ans {
         Ok(true) => match delete_exercise(selection) {
@@ -208,7 +565,355 @@ fn rm(mut args: IntoIter<String>) -> i32 {
         Ok(false) => {
             println!("Doing nothing...");
         }
-        Err(_) => return 1,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    }
+
+    0
+}
+
+// Returns the name of the Markdown file for an exercise with the given
+// `title`, which is not on the given set of `taken` names.
+fn file_name_for(title: &str, taken: &HashSet<String>) -> String {
+    let mut slug = String::new();
+    for c in title.to_lowercase().chars() {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    let slug = if slug.is_empty() { "exercise" } else { slug };
+
+    let mut name = format!("{slug}.md");
+    let mut idx = 2;
+    while taken.contains(&name) {
+        name = format!("{slug}-{idx}.md");
+        idx += 1;
+    }
+    name
+}
+
+fn export(mut args: IntoIter<String>) -> i32 {
+    let (Some(dir), None) = (args.next(), args.next()) else {
+        help(Some(
+            "error: exercises: you have to provide exactly one directory",
+        ));
+        return 1;
+    };
+    let dir = Path::new(&dir);
+    if let Err(e) = std::fs::create_dir_all(dir) {
+        println!(
+            "error: exercises: could not create '{}': {e}",
+            dir.display()
+        );
+        return 1;
+    }
+
+    let titles = match select_by_title(None, &[]) {
+        Ok(titles) => titles,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+    let mut taken = HashSet::new();
+    for title in &titles {
+        let exercise = match find_exercise_by_title(title) {
+            Ok(exercise) => exercise,
+            Err(e) => {
+                println!("error: exercises: {e}");
+                return 1;
+            }
+        };
+        let tags = match select_tags_for_exercise(exercise.id) {
+            Ok(tags) => tags.into_iter().map(|t| t.name).collect::<Vec<_>>(),
+            Err(e) => {
+                println!("error: exercises: {e}");
+                return 1;
+            }
+        };
+
+        let name = file_name_for(title, &taken);
+        let path = dir.join(&name);
+        if let Err(e) = std::fs::write(&path, to_markdown(&exercise, &tags)) {
+            println!(
+                "error: exercises: could not write '{}': {e}",
+                path.display()
+            );
+            return 1;
+        }
+        taken.insert(name);
+    }
+
+    println!(
+        "Exported {} exercises into '{}'.",
+        titles.len(),
+        dir.display()
+    );
+    0
+}
+
+// Sets the tags with the given `names` as the only ones for the exercise
+// identified by `exercise_id`, creating them if needed.
+fn set_exercise_tags(exercise_id: i64, names: &[String]) -> Result<(), String> {
+    let current = select_tags_for_exercise(exercise_id as i32)?;
+
+    let mut ids = vec![];
+    for name in names {
+        let id = find_or_create_tag(name)?;
+        if !current.iter().any(|t| t.id as i64 == id) {
+            attach_tag_to_exercise(id, exercise_id)?;
+        }
+        ids.push(id);
+    }
+
+    let to_remove: Vec<i32> = current
+        .iter()
+        .filter(|t| !ids.contains(&(t.id as i64)))
+        .map(|t| t.id)
+        .collect();
+    dettach_tags_from_exercise(&to_remove, exercise_id)
+}
+
+fn import(mut args: IntoIter<String>) -> i32 {
+    let (Some(dir), None) = (args.next(), args.next()) else {
+        help(Some(
+            "error: exercises: you have to provide exactly one directory",
+        ));
+        return 1;
+    };
+    let dir = Path::new(&dir);
+    let mut paths = match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+            .collect::<Vec<_>>(),
+        Err(e) => {
+            println!("error: exercises: could not read '{}': {e}", dir.display());
+            return 1;
+        }
+    };
+    paths.sort();
+
+    let (mut created, mut updated, mut failed) = (0, 0, 0);
+    for path in paths {
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| from_markdown(&text));
+        let (mut exercise, tags) = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                println!("warning: exercises: {}: {e}", path.display());
+                failed += 1;
+                continue;
+            }
+        };
+
+        // Exercises are matched by their title, which is unique.
+        let res = match find_exercise_by_title(&exercise.title) {
+            Ok(existing) => {
+                exercise.id = existing.id;
+                update_exercise(exercise).map(|_| {
+                    updated += 1;
+                    existing.id as i64
+                })
+            }
+            Err(_) => create_exercise(exercise).inspect(|_| created += 1),
+        };
+        match res.and_then(|id| set_exercise_tags(id, &tags)) {
+            Ok(_) => {}
+            Err(e) => {
+                println!("warning: exercises: {}: {e}", path.display());
+                failed += 1;
+            }
+        }
+    }
+
+    println!(
+        "Imported exercises from '{}': {created} created, {updated} updated.",
+        dir.display()
+    );
+    if failed > 0 {
+        println!("error: exercises: {failed} files could not be imported");
+        return 1;
+    }
+    0
+}
+
+// Prints the given Markdown `text` under the given `header`, rendered for the
+// terminal if the output goes there.
+fn print_markdown(header: &str, text: &str) {
+    if stdout().is_terminal() {
+        println!("\n{header}:\n{}", to_ansi(text));
+    } else {
+        println!("\n{header}:\n{text}");
+    }
+}
+
+fn show(args: IntoIter<String>) -> i32 {
+    let (hide_solution, mut args) = take_flag(args, &["--hide-solution"]);
+    if args.len() > 1 {
+        help(Some("error: exercises: too many filters"));
+        return 1;
+    }
+    if hide_solution && !can_prompt() {
+        println!("error: exercises: '--hide-solution' needs a terminal to ask for confirmation");
+        return 1;
+    }
+
+    let exercise = match select_single_exercise(args.next()) {
+        Ok(exercise) => exercise,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+    let tags = match select_tags_for_exercise(exercise.id) {
+        Ok(tags) => tags,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+
+    println!("Title: {}", exercise.title);
+    println!("Kind: {}", exercise.kind);
+    println!("Expected minutes: {}", exercise.minutes);
+    if !tags.is_empty() {
+        println!(
+            "Tags: {}",
+            tags.iter().map(paint_tag).collect::<Vec<_>>().join(", ")
+        );
+    }
+    if let Some(score) = exercise.score {
+        println!("Last score: {score}/{MAX_SCORE}");
+    }
+    match select_exercise_words(exercise.id) {
+        Ok(words) if !words.is_empty() => println!("Words: {}", words.join("; ")),
+        Ok(_) => {}
+        Err(e) => println!("warning: exercises: {e}"),
+    }
+    match &exercise.last_attempted_at {
+        Some(last) => println!("Attempts: {} (last on {last})", exercise.attempts),
+        None => println!("Attempts: 0"),
+    }
+    if let Some(due) = &exercise.due_at {
+        println!("Due on: {due}");
+    }
+
+    print_markdown("Enunciate", &exercise.enunciate);
+    if !exercise.solution.trim().is_empty() {
+        match confirm("Show the solution?", !hide_solution) {
+            Ok(true) => print_markdown("Solution", &exercise.solution),
+            Ok(false) => {}
+            Err(e) => {
+                println!("error: exercises: {e}");
+                return 1;
+            }
+        }
+    }
+    if !exercise.lessons.trim().is_empty() {
+        print_markdown("Lessons", &exercise.lessons);
+    }
+
+    0
+}
+
+// Practice with the most relevant exercises which fit in the time budget given
+// by the '--minutes' flag, or with the one matching the given title.
+fn practice(mut args: IntoIter<String>) -> i32 {
+    let mut minutes: Option<usize> = None;
+    let mut kind: Option<ExerciseKind> = None;
+    let mut search: Option<String> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--minutes" => match args.next().map(|m| m.parse::<usize>()) {
+                Some(Ok(m)) if m > 0 => minutes = Some(m),
+                _ => {
+                    help(Some(
+                        "error: exercises: '--minutes' expects a positive number",
+                    ));
+                    return 1;
+                }
+            },
+            "-k" | "--kind" => match args.next() {
+                Some(k) => match k.trim().to_lowercase().as_str().try_into() {
+                    Ok(k) => kind = Some(k),
+                    Err(e) => {
+                        help(Some(format!("error: exercises: {e}").as_str()));
+                        return 1;
+                    }
+                },
+                None => {
+                    help(Some("error: exercises: you have to provide a kind"));
+                    return 1;
+                }
+            },
+            _ if arg.starts_with('-') => {
+                help(Some(
+                    format!("error: exercises: unknown flag '{arg}'").as_str(),
+                ));
+                return 1;
+            }
+            _ => {
+                if search.is_some() {
+                    help(Some("error: exercises: too many titles"));
+                    return 1;
+                }
+                search = Some(arg);
+            }
+        }
+    }
+
+    if search.is_some() && (minutes.is_some() || kind.is_some()) {
+        help(Some(
+            "error: exercises: a title cannot be combined with '--minutes' or '--kind'",
+        ));
+        return 1;
+    }
+    if search.is_none() && minutes.is_none() {
+        help(Some("error: exercises: you have to provide '--minutes'"));
+        return 1;
+    }
+    if !can_prompt() {
+        println!("error: exercises: this command needs an interactive terminal");
+        return 1;
+    }
+
+    let Some(minutes) = minutes else {
+        return match select_single_exercise(search) {
+            Ok(exercise) => {
+                run_exercise(&exercise, &SessionOptions::default());
+                0
+            }
+            Err(e) => {
+                println!("error: exercises: {e}");
+                1
+            }
+        };
+    };
+
+    let exercises = match select_timed_exercises(kind, minutes) {
+        Ok(exercises) => exercises,
+        Err(e) => {
+            println!("error: exercises: {e}");
+            return 1;
+        }
+    };
+    if exercises.is_empty() {
+        println!("exercises: no exercises fit in {minutes} minutes");
+        return 0;
+    }
+
+    for exercise in exercises {
+        if !run_exercise(&exercise, &SessionOptions::default()) {
+            break;
+        }
     }
 
     0
@@ -230,18 +935,33 @@ pub fn run(args: Vec<String>) {
                 help(None);
                 std::process::exit(0);
             }
+            "clone" => {
+                std::process::exit(clone(it));
+            }
             "create" => {
                 std::process::exit(create(it));
             }
             "edit" => {
                 std::process::exit(edit(it));
             }
+            "export" => {
+                std::process::exit(export(it));
+            }
+            "import" => {
+                std::process::exit(import(it));
+            }
             "ls" => {
                 std::process::exit(ls(it));
             }
             "rm" => {
                 std::process::exit(rm(it));
             }
+            "show" => {
+                std::process::exit(show(it));
+            }
+            "run" => {
+                std::process::exit(practice(it));
+            }
             _ => {
                 help(Some(
                     format!("error: exercises: unknown flag or command '{first}'").as_str(),
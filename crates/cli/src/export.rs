@@ -0,0 +1,78 @@
+use mihi::archive::export_database;
+
+// Show the help message.
+fn help(msg: Option<&str>) {
+    if let Some(msg) = msg {
+        println!("{}.\n", msg);
+    }
+
+    println!("mihi export: Write the whole database into a JSON file.\n");
+    println!("usage: mihi export [OPTIONS] <file>\n");
+
+    println!("The file has all the tags, lessons, words and exercises, including the");
+    println!("progress made on them, and the history of notes, sessions, drills, exercise");
+    println!("attempts and exams. It can be read by 'mihi import' on another machine or");
+    println!("by another user.\n");
+
+    println!("Options:");
+    println!("   -h, --help\t\tPrint this message.");
+}
+
+pub fn run(args: Vec<String>) {
+    let mut files = vec![];
+
+    for arg in args {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                help(None);
+                std::process::exit(0);
+            }
+            _ if arg.starts_with('-') => {
+                help(Some(
+                    format!("error: export: unknown flag '{arg}'").as_str(),
+                ));
+                std::process::exit(1);
+            }
+            _ => files.push(arg),
+        }
+    }
+    let [file] = files.as_slice() else {
+        help(Some("error: export: you have to provide exactly one file"));
+        std::process::exit(1);
+    };
+
+    let archive = match export_database() {
+        Ok(archive) => archive,
+        Err(e) => {
+            println!("error: export: {e}");
+            std::process::exit(1);
+        }
+    };
+    if let Err(e) = write_archive(file, &archive) {
+        println!("error: export: {e}");
+        std::process::exit(1);
+    }
+    println!("Exported {} into '{file}'.", describe_archive(&archive));
+}
+
+/// Writes the given `archive` into the given `file` as pretty JSON.
+pub fn write_archive(file: &str, archive: &serde_json::Value) -> Result<(), String> {
+    let contents = serde_json::to_string_pretty(archive).unwrap_or_default();
+    match std::fs::write(file, contents + "\n") {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not write '{file}': {e}")),
+    }
+}
+
+/// Returns how many items of each kind are in the given `archive` (e.g. '3
+/// words, 1 exercises, 0 tags and 0 lessons').
+pub fn describe_archive(archive: &serde_json::Value) -> String {
+    let count = |section: &str| archive[section].as_array().map_or(0, Vec::len);
+    format!(
+        "{} words, {} exercises, {} tags and {} lessons",
+        count("words"),
+        count("exercises"),
+        count("tags"),
+        count("lessons")
+    )
+}
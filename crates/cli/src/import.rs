@@ -0,0 +1,78 @@
+use mihi::archive::{import_database, ConflictPolicy};
+
+// Show the help message.
+fn help(msg: Option<&str>) {
+    if let Some(msg) = msg {
+        println!("{}.\n", msg);
+    }
+
+    println!("mihi import: Read a JSON file written by 'mihi export' into the database.\n");
+    println!("usage: mihi import [OPTIONS] <file>\n");
+
+    println!("Options:");
+    println!("   -h, --help\t\tPrint this message.");
+    println!("   --on-conflict <POLICY>\tWhat to do with tags, lessons, words and exercises that already exist. One of: skip (the default), overwrite, merge (keep the one updated last).");
+}
+
+pub fn run(args: Vec<String>) {
+    let mut files = vec![];
+    let mut policy = ConflictPolicy::default();
+    let mut it = args.into_iter();
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                help(None);
+                std::process::exit(0);
+            }
+            "--on-conflict" => match it.next().map(|v| ConflictPolicy::try_from(v.as_str())) {
+                Some(Ok(value)) => policy = value,
+                Some(Err(e)) => {
+                    help(Some(format!("error: import: {e}").as_str()));
+                    std::process::exit(1);
+                }
+                None => {
+                    help(Some(
+                        "error: import: you have to provide a value for '--on-conflict'",
+                    ));
+                    std::process::exit(1);
+                }
+            },
+            _ if arg.starts_with('-') => {
+                help(Some(
+                    format!("error: import: unknown flag '{arg}'").as_str(),
+                ));
+                std::process::exit(1);
+            }
+            _ => files.push(arg),
+        }
+    }
+    let [file] = files.as_slice() else {
+        help(Some("error: import: you have to provide exactly one file"));
+        std::process::exit(1);
+    };
+
+    let archive = match read_archive(file) {
+        Ok(archive) => archive,
+        Err(e) => {
+            println!("error: import: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    match import_database(&archive, policy) {
+        Ok(summary) => println!("{summary}"),
+        Err(e) => {
+            println!("error: import: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Reads the JSON archive from the given `file`.
+pub fn read_archive(file: &str) -> Result<serde_json::Value, String> {
+    std::fs::read_to_string(file)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| serde_json::from_str(&contents).map_err(|e| e.to_string()))
+        .map_err(|e| format!("could not read '{file}': {e}"))
+}
@@ -1,94 +1,227 @@
 use mihi::cfg::configuration;
-use mihi::inflection::{get_adjective_table, get_inflected_from, get_noun_table};
-use mihi::word::{Category, Word};
+use mihi::inflection::{
+    get_adjective_table, get_comparative_table, get_conjugated_from, get_inflected_from,
+    get_noun_table, get_pronoun_table, get_superlative_table, get_verb_table, Case,
+    ConjugationTable, DeclensionInfo, DeclensionTable, MoodTable,
+};
+use mihi::word::{select_related_words, Category, RelationKind, Word};
 
 fn print_noun_inflection(word: &Word) -> Result<(), String> {
     let table = get_noun_table(word)?;
 
     println!("\n== Inflection ==\n");
 
-    for idx in configuration().case_order.to_usizes() {
-        match idx {
-            0 => println!(
-                "Nominative:\t{}",
-                get_inflected_from(word, &table.nominative)
-            ),
-            1 => println!("Vocative:\t{}", get_inflected_from(word, &table.vocative)),
-            2 => println!(
-                "Accusative:\t{}",
-                get_inflected_from(word, &table.accusative)
-            ),
-            3 => println!("Genitive:\t{}", get_inflected_from(word, &table.genitive)),
-            4 => println!("Dative:\t\t{}", get_inflected_from(word, &table.dative)),
-            5 => println!("Ablative:\t{}", get_inflected_from(word, &table.ablative)),
-            6 => {
-                if word.locative {
-                    println!("Locative:\t{}", get_inflected_from(word, &table.locative));
-                }
-            }
-            _ => {}
-        }
+    for case in configuration().case_order.cases_for(word) {
+        println!(
+            "{}{}",
+            case_label(case),
+            get_inflected_from(word, table.row(case))
+        );
     }
 
     Ok(())
 }
 
+// Returns the label for the given `case` when printing inflection tables,
+// aligned with tabs.
+fn case_label(case: Case) -> String {
+    let label = format!("{}:", case.title());
+    if label.len() < 8 {
+        format!("{label}\t\t")
+    } else {
+        format!("{label}\t")
+    }
+}
+
 fn print_adjective_inflection(word: &Word) -> Result<(), String> {
     let tables = get_adjective_table(word)?;
 
     println!("\n== Inflection ==\n");
+    print_adjective_tables(word, &tables);
+
+    // Words which are comparatives or superlatives themselves (e.g. 'melior')
+    // are flagged as 'nonpositive'.
+    if word.is_flag_set("notcomparable") || word.is_flag_set("nonpositive") {
+        return Ok(());
+    }
+
+    let related = select_related_words(word)?;
+    let comparative = &related[RelationKind::Comparative as usize - 1];
+    let superlative = &related[RelationKind::Superlative as usize - 1];
+
+    println!("\n== Comparison ==");
+    println!("\nComparative:\n");
+    print_adjective_tables(
+        comparative.first().unwrap_or(word),
+        &get_comparative_table(word, comparative)?,
+    );
+    println!("\nSuperlative:\n");
+    print_adjective_tables(
+        superlative.first().unwrap_or(word),
+        &get_superlative_table(word, superlative)?,
+    );
+
+    Ok(())
+}
+
+// Prints the given adjective `tables` for the masculine, feminine and neuter of
+// the given `word`.
+fn print_adjective_tables(word: &Word, tables: &[DeclensionTable; 3]) {
+    for case in configuration().case_order.cases_for(word) {
+        println!(
+            "{}{} | {} | {}",
+            case_label(case),
+            get_inflected_from(word, tables[0].row(case)),
+            get_inflected_from(word, tables[1].row(case)),
+            get_inflected_from(word, tables[2].row(case))
+        );
+    }
+}
+
+fn print_pronoun_inflection(word: &Word) -> Result<(), String> {
+    let tables = get_pronoun_table(word)?;
+
+    println!("\n== Inflection ==\n");
+
+    for case in configuration().case_order.cases_for(word) {
+        let rows: Vec<&[DeclensionInfo; 2]> = tables.iter().map(|table| table.row(case)).collect();
+
+        // Most pronouns have no vocative (and 'sē' has no nominative), so skip
+        // the cases with no forms at all.
+        if rows
+            .iter()
+            .all(|row| row.iter().all(|info| info.inflected.is_empty()))
+        {
+            continue;
+        }
+
+        println!(
+            "{}{}",
+            case_label(case),
+            rows.iter()
+                .map(|row| get_inflected_from(word, row))
+                .collect::<Vec<_>>()
+                .join(" | ")
+        );
+    }
+
+    Ok(())
+}
+
+fn print_mood(title: &str, mood: &MoodTable) {
+    if mood.is_empty() {
+        return;
+    }
+
+    println!("\n{title}:\n");
+    for (name, tense) in mood.tenses() {
+        if !tense.is_empty() {
+            println!("{:<16}{}", format!("{name}:"), get_conjugated_from(tense));
+        }
+    }
+}
+
+// Prints the row for a non-finite form, unless it's empty.
+fn print_nonfinite(name: &str, info: &DeclensionInfo) {
+    if !info.inflected.is_empty() {
+        println!("{:<24}{}", format!("{name}:"), info.inflected.join("/"));
+    }
+}
 
-    for idx in configuration().case_order.to_usizes() {
-        match idx {
-            0 => println!(
-                "Nominative:\t{} | {} | {}",
-                get_inflected_from(word, &tables[0].nominative),
-                get_inflected_from(word, &tables[1].nominative),
-                get_inflected_from(word, &tables[2].nominative)
-            ),
-            1 => println!(
-                "Vocative:\t{} | {} | {}",
-                get_inflected_from(word, &tables[0].vocative),
-                get_inflected_from(word, &tables[1].vocative),
-                get_inflected_from(word, &tables[2].vocative)
-            ),
-            2 => println!(
-                "Accusative:\t{} | {} | {}",
-                get_inflected_from(word, &tables[0].accusative),
-                get_inflected_from(word, &tables[1].accusative),
-                get_inflected_from(word, &tables[2].accusative)
-            ),
-            3 => println!(
-                "Genitive:\t{} | {} | {}",
-                get_inflected_from(word, &tables[0].genitive),
-                get_inflected_from(word, &tables[1].genitive),
-                get_inflected_from(word, &tables[2].genitive)
-            ),
-            4 => println!(
-                "Dative:\t\t{} | {} | {}",
-                get_inflected_from(word, &tables[0].dative),
-                get_inflected_from(word, &tables[1].dative),
-                get_inflected_from(word, &tables[2].dative)
-            ),
-            5 => println!(
-                "Ablative:\t{} | {} | {}",
-                get_inflected_from(word, &tables[0].ablative),
-                get_inflected_from(word, &tables[1].ablative),
-                get_inflected_from(word, &tables[2].ablative)
-            ),
-            6 => {
-                if word.locative {
-                    println!(
-                        "Locative:\t{} | {} | {}",
-                        get_inflected_from(word, &tables[0].locative),
-                        get_inflected_from(word, &tables[1].locative),
-                        get_inflected_from(word, &tables[2].locative)
-                    );
-                }
+// Prints the infinitives and participles of the verb.
+fn print_verb_extra(table: &ConjugationTable) {
+    let infinitive = &table.infinitive;
+    let participle = &table.participle;
+    let rows = [
+        ("Present active", &infinitive.present[0]),
+        ("Present passive", &infinitive.present[1]),
+        ("Perfect active", &infinitive.perfect[0]),
+        ("Perfect passive", &infinitive.perfect[1]),
+        ("Future active", &infinitive.future[0]),
+        ("Future passive", &infinitive.future[1]),
+    ];
+    let participles = [
+        ("Present active", &participle.present_active),
+        ("Perfect passive", &participle.perfect_passive),
+        ("Future active", &participle.future_active),
+        ("Gerundive", &participle.gerundive),
+    ];
+    let gerund = [
+        &table.gerund.genitive,
+        &table.gerund.dative,
+        &table.gerund.accusative,
+        &table.gerund.ablative,
+    ];
+    let supine = [&table.supine.accusative, &table.supine.ablative];
+
+    let is_empty = |infos: &[&DeclensionInfo]| infos.iter().all(|info| info.inflected.is_empty());
+    let has_rows = !is_empty(&rows.map(|(_, info)| info));
+    let has_participles = !is_empty(&participles.map(|(_, info)| info));
+    if !has_rows && !has_participles && is_empty(&gerund) && is_empty(&supine) {
+        return;
+    }
+
+    println!("\n== Extra ==");
+    if has_rows {
+        println!("\nInfinitives:\n");
+        for (name, info) in rows {
+            print_nonfinite(name, info);
+        }
+    }
+    if has_participles {
+        println!("\nParticiples:\n");
+        for (name, info) in participles {
+            print_nonfinite(name, info);
+        }
+    }
+    if !is_empty(&gerund) || !is_empty(&supine) {
+        println!();
+        for (name, infos) in [("Gerund", gerund.as_slice()), ("Supine", supine.as_slice())] {
+            if !is_empty(infos) {
+                println!(
+                    "{:<24}{}",
+                    format!("{name}:"),
+                    infos
+                        .iter()
+                        .map(|info| info.inflected.join("/"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
             }
-            _ => {}
         }
     }
+}
+
+fn print_verb_inflection(word: &Word) -> Result<(), String> {
+    let table = get_verb_table(word)?;
+
+    println!("\n== Inflection ==");
+
+    let deponent = word.is_flag_set("deponent") || word.is_flag_set("semideponent");
+    for (name, voices) in [
+        ("Indicative", &table.indicative),
+        ("Subjunctive", &table.subjunctive),
+        ("Imperative", &table.imperative),
+    ] {
+        print_mood(format!("{name} active").as_str(), &voices[0]);
+        if deponent {
+            print_mood(
+                format!("{name} (passive forms, active meaning)").as_str(),
+                &voices[1],
+            );
+        } else {
+            print_mood(format!("{name} passive").as_str(), &voices[1]);
+        }
+    }
+    for (name, moods) in [
+        ("Active periphrastic", &table.periphrastic.active),
+        ("Passive periphrastic", &table.periphrastic.passive),
+    ] {
+        print_mood(format!("{name} indicative").as_str(), &moods[0]);
+        print_mood(format!("{name} subjunctive").as_str(), &moods[1]);
+    }
+
+    print_verb_extra(&table);
 
     Ok(())
 }
@@ -101,9 +234,9 @@ pub fn print_full_inflection_for(word: Word) -> Result<(), String> {
     match word.category {
         Category::Noun => print_noun_inflection(&word)?,
         Category::Adjective => print_adjective_inflection(&word)?,
-        Category::Verb
-        | Category::Pronoun
-        | Category::Adverb
+        Category::Verb => print_verb_inflection(&word)?,
+        Category::Pronoun => print_pronoun_inflection(&word)?,
+        Category::Adverb
         | Category::Preposition
         | Category::Conjunction
         | Category::Interjection
@@ -119,8 +252,14 @@ pub fn print_full_inflection_for(word: Word) -> Result<(), String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use mihi::inflection::DeclensionTable;
-    use mihi::word::{find_by, select_enunciated};
+    use mihi::analysis::{analyze, count_lemmas, find_example, Analysis, Analyzer};
+    use mihi::cfg::{CaseOrder, MacronStrictness};
+    use mihi::inflection::{
+        get_synopsis, get_voice_pairs, inflect, push_alternative_forms, Case, Number,
+    };
+    use mihi::render::{conjugation_tables, declension_table, Format};
+    use mihi::sentence::{NounPhrase, Sentence, Substitution};
+    use mihi::word::{find_by, select_enunciated, Gender};
 
     fn get_word(enunciated: &str) -> Word {
         let words = select_enunciated(Some(enunciated.to_string()), &[]).unwrap();
@@ -131,23 +270,12 @@ mod tests {
     }
 
     fn stringify_with(word: &Word, table: &DeclensionTable) -> String {
-        let mut res = get_inflected_from(word, &table.nominative);
-        res.push_str(" | ");
-        res.push_str(get_inflected_from(word, &table.vocative).as_str());
-        res.push_str(" | ");
-        res.push_str(get_inflected_from(word, &table.accusative).as_str());
-        res.push_str(" | ");
-        res.push_str(get_inflected_from(word, &table.genitive).as_str());
-        res.push_str(" | ");
-        res.push_str(get_inflected_from(word, &table.dative).as_str());
-        res.push_str(" | ");
-        res.push_str(get_inflected_from(word, &table.ablative).as_str());
-        if word.locative {
-            res.push_str(" | ");
-            res.push_str(get_inflected_from(word, &table.locative).as_str());
-        }
-
-        res
+        Case::ALL
+            .into_iter()
+            .filter(|&case| case != Case::Locative || word.locative)
+            .map(|case| get_inflected_from(word, table.row(case)))
+            .collect::<Vec<String>>()
+            .join(" | ")
     }
 
     fn assert_noun_table(enunciated: &str, expected: &str) {
@@ -245,7 +373,27 @@ mod tests {
         );
         assert_noun_table(
             "domus, domūs/domī",
-            "domus, domūs | domus, domūs | domum, domūs/domōs | domūs/domī, domuum/domōrum | domuī/domō/domū, domibus | domū/domō, domibus | domī, ",
+            "domus, domūs | domus, domūs | domum, domōs/domūs | domūs/domī, domuum/domōrum | domuī/domō, domibus | domō/domū, domibus | domī",
+        );
+        assert_noun_table(
+            "Aenēās, Aenēae",
+            "Aenēās | Aenēā | Aenēān/Aenēam | Aenēae | Aenēae | Aenēā",
+        );
+        assert_noun_table(
+            "Anchīsēs, Anchīsae",
+            "Anchīsēs | Anchīsē/Anchīsā | Anchīsēn/Anchīsam | Anchīsae | Anchīsae | Anchīsē/Anchīsā",
+        );
+        assert_noun_table(
+            "epitomē, epitomēs",
+            "epitomē, epitomae | epitomē, epitomae | epitomēn, epitomās | epitomēs, epitomārum | epitomae, epitomīs | epitomē, epitomīs",
+        );
+        assert_noun_table(
+            "Dīdō, Dīdōnis",
+            "Dīdō | Dīdō | Dīdōnem/Dīdō | Dīdōnis/Dīdūs | Dīdōnī/Dīdō | Dīdōne/Dīdō",
+        );
+        assert_noun_table(
+            "hērōs, hērōis",
+            "hērōs, hērōes | hērōs, hērōes | hērōa/hērōem, hērōas/hērōēs | hērōis, hērōum | hērōī, hērōibus | hērōe, hērōibus",
         );
         assert_noun_table(
             "diēs, diēī",
@@ -257,6 +405,343 @@ mod tests {
         );
     }
 
+    fn conjugated_tenses(mood: &MoodTable) -> Vec<String> {
+        [
+            &mood.present,
+            &mood.imperfect,
+            &mood.future,
+            &mood.perfect,
+            &mood.pluperfect,
+            &mood.future_perfect,
+        ]
+        .iter()
+        .map(|tense| get_conjugated_from(tense))
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<String>>()
+    }
+
+    fn assert_verb_indicative(enunciated: &str, active: &[&str], passive: &[&str]) {
+        let word = get_word(enunciated);
+        let table = get_verb_table(&word).unwrap();
+
+        for (idx, expected) in [active, passive].iter().enumerate() {
+            assert_eq!(conjugated_tenses(&table.indicative[idx]), *expected);
+        }
+    }
+
+    fn assert_verb_subjunctive(enunciated: &str, active: &[&str], passive: &[&str]) {
+        let word = get_word(enunciated);
+        let table = get_verb_table(&word).unwrap();
+
+        for (idx, expected) in [active, passive].iter().enumerate() {
+            assert_eq!(conjugated_tenses(&table.subjunctive[idx]), *expected);
+        }
+    }
+
+    #[test]
+    fn test_verbs_indicative() {
+        assert_verb_indicative(
+            "amō, amāre, amāvī, amātum",
+            &[
+                "amō, amās, amat, amāmus, amātis, amant",
+                "amābam, amābās, amābat, amābāmus, amābātis, amābant",
+                "amābō, amābis, amābit, amābimus, amābitis, amābunt",
+                "amāvī, amāvistī, amāvit, amāvimus, amāvistis, amāvērunt",
+                "amāveram, amāverās, amāverat, amāverāmus, amāverātis, amāverant",
+                "amāverō, amāveris, amāverit, amāverimus, amāveritis, amāverint",
+            ],
+            &[
+                "amor, amāris, amātur, amāmur, amāminī, amantur",
+                "amābar, amābāris, amābātur, amābāmur, amābāminī, amābantur",
+                "amābor, amāberis, amābitur, amābimur, amābiminī, amābuntur",
+                "amātus sum, amātus es, amātus est, amātī sumus, amātī estis, amātī sunt",
+                "amātus eram, amātus erās, amātus erat, amātī erāmus, amātī erātis, amātī erant",
+                "amātus erō, amātus eris, amātus erit, amātī erimus, amātī eritis, amātī erunt",
+            ],
+        );
+        assert_verb_indicative(
+            "dormiō, dormīre, dormīvī, dormītum",
+            &[
+                "dormiō, dormīs, dormit, dormīmus, dormītis, dormiunt",
+                "dormiēbam, dormiēbās, dormiēbat, dormiēbāmus, dormiēbātis, dormiēbant",
+                "dormiam, dormiēs, dormiet, dormiēmus, dormiētis, dormient",
+                "dormīvī, dormīvistī, dormīvit, dormīvimus, dormīvistis, dormīvērunt",
+                "dormīveram, dormīverās, dormīverat, dormīverāmus, dormīverātis, dormīverant",
+                "dormīverō, dormīveris, dormīverit, dormīverimus, dormīveritis, dormīverint",
+            ],
+            &[],
+        );
+        assert_verb_indicative(
+            "loquor, loquī, locūtus sum",
+            &[],
+            &[
+                "loquor, loqueris, loquitur, loquimur, loquiminī, loquuntur",
+                "loquēbar, loquēbāris, loquēbātur, loquēbāmur, loquēbāminī, loquēbantur",
+                "loquar, loquēris, loquētur, loquēmur, loquēminī, loquentur",
+                "locūtus sum, locūtus es, locūtus est, locūtī sumus, locūtī estis, locūtī sunt",
+                "locūtus eram, locūtus erās, locūtus erat, locūtī erāmus, locūtī erātis, locūtī erant",
+                "locūtus erō, locūtus eris, locūtus erit, locūtī erimus, locūtī eritis, locūtī erunt",
+            ],
+        );
+        assert_verb_indicative(
+            "sedeō, sedēre, sēdī, sessum",
+            &[
+                "sedeō, sedēs, sedet, sedēmus, sedētis, sedent",
+                "sedēbam, sedēbās, sedēbat, sedēbāmus, sedēbātis, sedēbant",
+                "sedēbō, sedēbis, sedēbit, sedēbimus, sedēbitis, sedēbunt",
+                "sēdī, sēdistī, sēdit, sēdimus, sēdistis, sēdērunt",
+                "sēderam, sēderās, sēderat, sēderāmus, sēderātis, sēderant",
+                "sēderō, sēderis, sēderit, sēderimus, sēderitis, sēderint",
+            ],
+            &[
+                "sedētur",
+                "sedēbātur",
+                "sedēbitur",
+                "sessum est",
+                "sessum erat",
+                "sessum erit",
+            ],
+        );
+        assert_verb_indicative(
+            "sum, esse, fuī, futūrus",
+            &[
+                "sum, es, est, sumus, estis, sunt",
+                "eram, erās, erat, erāmus, erātis, erant",
+                "erō, eris, erit, erimus, eritis, erunt",
+                "fuī, fuistī, fuit, fuimus, fuistis, fuērunt",
+                "fueram, fuerās, fuerat, fuerāmus, fuerātis, fuerant",
+                "fuerō, fueris, fuerit, fuerimus, fueritis, fuerint",
+            ],
+            &[],
+        );
+        assert_verb_indicative(
+            "gaudeō, gaudēre, gāvīsus sum",
+            &[
+                "gaudeō, gaudēs, gaudet, gaudēmus, gaudētis, gaudent",
+                "gaudēbam, gaudēbās, gaudēbat, gaudēbāmus, gaudēbātis, gaudēbant",
+                "gaudēbō, gaudēbis, gaudēbit, gaudēbimus, gaudēbitis, gaudēbunt",
+            ],
+            &[
+                "gāvīsus sum, gāvīsus es, gāvīsus est, gāvīsī sumus, gāvīsī estis, gāvīsī sunt",
+                "gāvīsus eram, gāvīsus erās, gāvīsus erat, gāvīsī erāmus, gāvīsī erātis, gāvīsī erant",
+                "gāvīsus erō, gāvīsus eris, gāvīsus erit, gāvīsī erimus, gāvīsī eritis, gāvīsī erunt",
+            ],
+        );
+    }
+
+    fn assert_verb_imperative(enunciated: &str, active: &[&str], passive: &[&str]) {
+        let word = get_word(enunciated);
+        let table = get_verb_table(&word).unwrap();
+
+        for (idx, expected) in [active, passive].iter().enumerate() {
+            assert_eq!(conjugated_tenses(&table.imperative[idx]), *expected);
+        }
+    }
+
+    #[test]
+    fn test_verbs_imperative() {
+        assert_verb_imperative(
+            "amō, amāre, amāvī, amātum",
+            &["amā, amāte", "amātō, amātō, amātōte, amantō"],
+            &["amāre, amāminī", "amātor, amātor, amantor"],
+        );
+        assert_verb_imperative(
+            "dūcō, dūcere, dūxī, ductum",
+            &["dūc, dūcite", "dūcitō, dūcitō, dūcitōte, dūcuntō"],
+            &["dūcere, dūciminī", "dūcitor, dūcitor, dūcuntor"],
+        );
+        assert_verb_imperative(
+            "loquor, loquī, locūtus sum",
+            &[],
+            &["loquere, loquiminī", "loquitor, loquitor, loquuntor"],
+        );
+        assert_verb_imperative(
+            "ferō, ferre, tulī, lātum",
+            &["fer, ferte", "fertō, fertō, fertōte, feruntō"],
+            &["ferre, feriminī", "fertor, fertor, feruntor"],
+        );
+        assert_verb_imperative("volō, velle, voluī", &[], &[]);
+    }
+
+    // Asserts the infinitives (present, perfect and future; active and then
+    // passive) and the participles (present active, perfect passive, future
+    // active and gerundive) of the given verb, with empty strings for missing
+    // forms.
+    fn assert_verb_nonfinite(enunciated: &str, infinitives: [&str; 6], participles: [&str; 4]) {
+        let word = get_word(enunciated);
+        let table = get_verb_table(&word).unwrap();
+        let join = |info: &DeclensionInfo| info.inflected.join("/");
+
+        let infinitive = &table.infinitive;
+        assert_eq!(
+            [
+                &infinitive.present[0],
+                &infinitive.present[1],
+                &infinitive.perfect[0],
+                &infinitive.perfect[1],
+                &infinitive.future[0],
+                &infinitive.future[1],
+            ]
+            .map(join),
+            infinitives
+        );
+
+        let participle = &table.participle;
+        assert_eq!(
+            [
+                &participle.present_active,
+                &participle.perfect_passive,
+                &participle.future_active,
+                &participle.gerundive,
+            ]
+            .map(join),
+            participles
+        );
+    }
+
+    #[test]
+    fn test_verbs_nonfinite() {
+        assert_verb_nonfinite(
+            "amō, amāre, amāvī, amātum",
+            [
+                "amāre",
+                "amārī",
+                "amāvisse",
+                "amātum esse",
+                "amātūrum esse",
+                "amātum īrī",
+            ],
+            ["amāns", "amātus", "amātūrus", "amandus"],
+        );
+        assert_verb_nonfinite(
+            "loquor, loquī, locūtus sum",
+            ["", "loquī", "", "locūtum esse", "locūtūrum esse", ""],
+            ["loquēns", "locūtus", "locūtūrus", "loquendus"],
+        );
+        assert_verb_nonfinite(
+            "gaudeō, gaudēre, gāvīsus sum",
+            ["gaudēre", "", "", "gāvīsum esse", "gāvīsūrum esse", ""],
+            ["gaudēns", "gāvīsus", "gāvīsūrus", "gaudendus"],
+        );
+        assert_verb_nonfinite(
+            "sum, esse, fuī, futūrus",
+            ["esse", "", "fuisse", "", "futūrum esse", ""],
+            ["", "", "futūrus", ""],
+        );
+        assert_verb_nonfinite(
+            "volō, velle, voluī",
+            ["velle", "", "voluisse", "", "", ""],
+            ["volēns", "", "", ""],
+        );
+    }
+
+    // Asserts the gerund (genitive, dative, accusative, ablative) and the
+    // supine (accusative, ablative) of the given verb.
+    fn assert_verb_gerund_supine(enunciated: &str, gerund: [&str; 4], supine: [&str; 2]) {
+        let word = get_word(enunciated);
+        let table = get_verb_table(&word).unwrap();
+        let join = |info: &DeclensionInfo| info.inflected.join("/");
+
+        assert_eq!(
+            [
+                &table.gerund.genitive,
+                &table.gerund.dative,
+                &table.gerund.accusative,
+                &table.gerund.ablative,
+            ]
+            .map(join),
+            gerund
+        );
+        assert_eq!(
+            [&table.supine.accusative, &table.supine.ablative].map(join),
+            supine
+        );
+    }
+
+    #[test]
+    fn test_verbs_gerund_supine() {
+        assert_verb_gerund_supine(
+            "amō, amāre, amāvī, amātum",
+            ["amandī", "amandō", "amandum", "amandō"],
+            ["amātum", "amātū"],
+        );
+        assert_verb_gerund_supine(
+            "loquor, loquī, locūtus sum",
+            ["loquendī", "loquendō", "loquendum", "loquendō"],
+            ["locūtum", "locūtū"],
+        );
+        assert_verb_gerund_supine(
+            "eō, īre, iī, itum",
+            ["eundī", "eundō", "eundum", "eundō"],
+            ["itum", "itū"],
+        );
+        assert_verb_gerund_supine("sum, esse, fuī, futūrus", ["", "", "", ""], ["", ""]);
+    }
+
+    #[test]
+    fn test_verbs_subjunctive() {
+        assert_verb_subjunctive(
+            "amō, amāre, amāvī, amātum",
+            &[
+                "amem, amēs, amet, amēmus, amētis, ament",
+                "amārem, amārēs, amāret, amārēmus, amārētis, amārent",
+                "amāverim, amāverīs, amāverit, amāverīmus, amāverītis, amāverint",
+                "amāvissem, amāvissēs, amāvisset, amāvissēmus, amāvissētis, amāvissent",
+            ],
+            &[
+                "amer, amēris, amētur, amēmur, amēminī, amentur",
+                "amārer, amārēris, amārētur, amārēmur, amārēminī, amārentur",
+                "amātus sim, amātus sīs, amātus sit, amātī sīmus, amātī sītis, amātī sint",
+                "amātus essem, amātus essēs, amātus esset, amātī essēmus, amātī essētis, amātī essent",
+            ],
+        );
+        assert_verb_subjunctive(
+            "loquor, loquī, locūtus sum",
+            &[],
+            &[
+                "loquar, loquāris, loquātur, loquāmur, loquāminī, loquantur",
+                "loquerer, loquerēris, loquerētur, loquerēmur, loquerēminī, loquerentur",
+                "locūtus sim, locūtus sīs, locūtus sit, locūtī sīmus, locūtī sītis, locūtī sint",
+                "locūtus essem, locūtus essēs, locūtus esset, locūtī essēmus, locūtī essētis, locūtī essent",
+            ],
+        );
+        assert_verb_subjunctive(
+            "sum, esse, fuī, futūrus",
+            &[
+                "sim, sīs, sit, sīmus, sītis, sint",
+                "essem, essēs, esset, essēmus, essētis, essent",
+                "fuerim, fuerīs, fuerit, fuerīmus, fuerītis, fuerint",
+                "fuissem, fuissēs, fuisset, fuissēmus, fuissētis, fuissent",
+            ],
+            &[],
+        );
+        assert_verb_subjunctive(
+            "volō, velle, voluī",
+            &[
+                "velim, velīs, velit, velīmus, velītis, velint",
+                "vellem, vellēs, vellet, vellēmus, vellētis, vellent",
+                "voluerim, voluerīs, voluerit, voluerīmus, voluerītis, voluerint",
+                "voluissem, voluissēs, voluisset, voluissēmus, voluissētis, voluissent",
+            ],
+            &[],
+        );
+        assert_verb_subjunctive(
+            "ferō, ferre, tulī, lātum",
+            &[
+                "feram, ferās, ferat, ferāmus, ferātis, ferant",
+                "ferrem, ferrēs, ferret, ferrēmus, ferrētis, ferrent",
+                "tulerim, tulerīs, tulerit, tulerīmus, tulerītis, tulerint",
+                "tulissem, tulissēs, tulisset, tulissēmus, tulissētis, tulissent",
+            ],
+            &[
+                "ferar, ferāris, ferātur, ferāmur, ferāminī, ferantur",
+                "ferrer, ferrēris, ferrētur, ferrēmur, ferrēminī, ferrentur",
+                "lātus sim, lātus sīs, lātus sit, lātī sīmus, lātī sītis, lātī sint",
+                "lātus essem, lātus essēs, lātus esset, lātī essēmus, lātī essētis, lātī essent",
+            ],
+        );
+    }
+
     #[test]
     fn test_adjectives() {
         assert_adjective_table(
@@ -314,4 +799,619 @@ mod tests {
             "mīlle, mīlia | mīlle, mīlia | mīlle, mīlia | mīlle, mīlium | mīlle, mīlibus | mīlle, mīlibus"
         );
     }
+
+    fn assert_comparison_tables(enunciated: &str, comparative: [&str; 3], superlative: [&str; 3]) {
+        let word = get_word(enunciated);
+        let related = select_related_words(&word).unwrap();
+
+        let tables =
+            get_comparative_table(&word, &related[RelationKind::Comparative as usize - 1]).unwrap();
+        for (table, expected) in tables.iter().zip(comparative) {
+            assert_eq!(stringify_with(&word, table), expected);
+        }
+
+        let tables =
+            get_superlative_table(&word, &related[RelationKind::Superlative as usize - 1]).unwrap();
+        for (table, expected) in tables.iter().zip(superlative) {
+            assert_eq!(stringify_with(&word, table), expected);
+        }
+    }
+
+    #[test]
+    fn test_comparison() {
+        assert_comparison_tables(
+            "altus, alta, altum",
+            [
+                "altior, altiōrēs | altior, altiōrēs | altiōrem, altiōrēs | altiōris, altiōrum | altiōrī, altiōribus | altiōre, altiōribus",
+                "altior, altiōrēs | altior, altiōrēs | altiōrem, altiōrēs | altiōris, altiōrum | altiōrī, altiōribus | altiōre, altiōribus",
+                "altius, altiōra | altius, altiōra | altius, altiōra | altiōris, altiōrum | altiōrī, altiōribus | altiōre, altiōribus",
+            ],
+            [
+                "altissimus, altissimī | altissime, altissimī | altissimum, altissimōs | altissimī, altissimōrum | altissimō, altissimīs | altissimō, altissimīs",
+                "altissima, altissimae | altissima, altissimae | altissimam, altissimās | altissimae, altissimārum | altissimae, altissimīs | altissimā, altissimīs",
+                "altissimum, altissima | altissimum, altissima | altissimum, altissima | altissimī, altissimōrum | altissimō, altissimīs | altissimō, altissimīs",
+            ],
+        );
+        assert_comparison_tables(
+            "bonus, bona, bonum",
+            [
+                "melior, meliōrēs | melior, meliōrēs | meliōrem, meliōrēs | meliōris, meliōrum | meliōrī, meliōribus | meliōre, meliōribus",
+                "melior, meliōrēs | melior, meliōrēs | meliōrem, meliōrēs | meliōris, meliōrum | meliōrī, meliōribus | meliōre, meliōribus",
+                "melius, meliōra | melius, meliōra | melius, meliōra | meliōris, meliōrum | meliōrī, meliōribus | meliōre, meliōribus",
+            ],
+            [
+                "optimus, optimī | optime, optimī | optimum, optimōs | optimī, optimōrum | optimō, optimīs | optimō, optimīs",
+                "optima, optimae | optima, optimae | optimam, optimās | optimae, optimārum | optimae, optimīs | optimā, optimīs",
+                "optimum, optima | optimum, optima | optimum, optima | optimī, optimōrum | optimō, optimīs | optimō, optimīs",
+            ],
+        );
+        assert_comparison_tables(
+            "pulcher, pulchra, pulchrum",
+            [
+                "pulchrior, pulchriōrēs | pulchrior, pulchriōrēs | pulchriōrem, pulchriōrēs | pulchriōris, pulchriōrum | pulchriōrī, pulchriōribus | pulchriōre, pulchriōribus",
+                "pulchrior, pulchriōrēs | pulchrior, pulchriōrēs | pulchriōrem, pulchriōrēs | pulchriōris, pulchriōrum | pulchriōrī, pulchriōribus | pulchriōre, pulchriōribus",
+                "pulchrius, pulchriōra | pulchrius, pulchriōra | pulchrius, pulchriōra | pulchriōris, pulchriōrum | pulchriōrī, pulchriōribus | pulchriōre, pulchriōribus",
+            ],
+            [
+                "pulcherrimus, pulcherrimī | pulcherrime, pulcherrimī | pulcherrimum, pulcherrimōs | pulcherrimī, pulcherrimōrum | pulcherrimō, pulcherrimīs | pulcherrimō, pulcherrimīs",
+                "pulcherrima, pulcherrimae | pulcherrima, pulcherrimae | pulcherrimam, pulcherrimās | pulcherrimae, pulcherrimārum | pulcherrimae, pulcherrimīs | pulcherrimā, pulcherrimīs",
+                "pulcherrimum, pulcherrima | pulcherrimum, pulcherrima | pulcherrimum, pulcherrima | pulcherrimī, pulcherrimōrum | pulcherrimō, pulcherrimīs | pulcherrimō, pulcherrimīs",
+            ],
+        );
+    }
+
+    // Like `stringify_with`, but skipping the vocative, which most pronouns
+    // lack.
+    fn assert_pronoun_table(enunciated: &str, expected: &[&str]) {
+        let word = get_word(enunciated);
+        let tables = get_pronoun_table(&word).unwrap();
+
+        assert_eq!(tables.len(), expected.len());
+        for (table, expected) in tables.iter().zip(expected) {
+            let res = [
+                &table.nominative,
+                &table.accusative,
+                &table.genitive,
+                &table.dative,
+                &table.ablative,
+            ]
+            .iter()
+            .map(|row| get_inflected_from(&word, row))
+            .collect::<Vec<String>>()
+            .join(" | ");
+            assert_eq!(res, *expected);
+        }
+    }
+
+    #[test]
+    fn test_pronouns() {
+        assert_pronoun_table(
+            "ego, nōs",
+            &["ego, nōs | mē, nōs | meī, nostrī/nostrum | mihi, nōbīs | mē, nōbīs"],
+        );
+        assert_pronoun_table(
+            "vōsmet",
+            &["vōsmet | vōsmet | vestrīmet/vestrummet | vōbīsmet | vōbīsmet"],
+        );
+        assert_pronoun_table(
+            "hic, haec, hoc",
+            &[
+                "hic, hī | hunc, hōs | huius, hōrum | huic, hīs | hōc, hīs",
+                "haec, hae | hanc, hās | huius, hārum | huic, hīs | hāc, hīs",
+                "hoc, haec | hoc, haec | huius, hōrum | huic, hīs | hōc, hīs",
+            ],
+        );
+        assert_pronoun_table(
+            "quisque, quisque, quidque",
+            &[
+                "quisque, quīque | quemque, quōsque | cuiusque, quōrumque | cuique, quibusque | quōque, quibusque",
+                "quisque, quaeque | quemque, quāsque | cuiusque, quārumque | cuique, quīsque | quōque, quīsque",
+                "quidque, quaeque | quidque, quaeque | cuiusque, quōrumque | cuique, quibusque | quōque, quibusque",
+            ],
+        );
+        assert_pronoun_table(
+            "ipse, ipsa, ipsum",
+            &[
+                "ipse, ipsī | ipsum, ipsōs | ipsīus, ipsōrum | ipsī, ipsīs | ipsō, ipsīs",
+                "ipsa, ipsae | ipsam, ipsās | ipsīus, ipsārum | ipsī, ipsīs | ipsā, ipsīs",
+                "ipsum, ipsa | ipsum, ipsa | ipsīus, ipsōrum | ipsī, ipsīs | ipsō, ipsīs",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_single_forms() {
+        let domus = get_word("domus, domūs/domī");
+        assert_eq!(
+            inflect(&domus, Case::Locative, Number::Singular, Gender::None).unwrap(),
+            ["domī"]
+        );
+
+        let novus = get_word("novus, nova, novum");
+        assert_eq!(
+            inflect(&novus, Case::Genitive, Number::Plural, Gender::Feminine).unwrap(),
+            ["novārum"]
+        );
+        assert_eq!(
+            inflect(&novus, Case::Nominative, Number::Singular, Gender::Neuter).unwrap(),
+            ["novum"]
+        );
+
+        let ego = get_word("ego, nōs");
+        assert_eq!(
+            inflect(&ego, Case::Genitive, Number::Plural, Gender::Feminine).unwrap(),
+            ["nostrī", "nostrum"]
+        );
+
+        let quisque = get_word("quisque, quisque, quidque");
+        assert_eq!(
+            inflect(
+                &quisque,
+                Case::Accusative,
+                Number::Singular,
+                Gender::Masculine
+            )
+            .unwrap(),
+            ["quemque"]
+        );
+    }
+
+    #[test]
+    fn test_sentences() {
+        let sentence = Sentence {
+            subject: NounPhrase {
+                noun: get_word("rosa, rosae"),
+                adjective: Some(get_word("novus, nova, novum")),
+                number: Number::Singular,
+            },
+            object: NounPhrase {
+                noun: get_word("templum, templī"),
+                adjective: Some(get_word("gravis, grave")),
+                number: Number::Plural,
+            },
+            verb: get_word("amō, amāre, amāvī, amātum"),
+        };
+        assert_eq!(sentence.compose().unwrap(), "rosa nova templa gravia amat");
+        assert_eq!(sentence.words().len(), 5);
+
+        let sentence = Sentence {
+            subject: NounPhrase {
+                noun: get_word("lupus, lupī"),
+                adjective: None,
+                number: Number::Plural,
+            },
+            object: NounPhrase {
+                noun: get_word("rēs, reī"),
+                adjective: None,
+                number: Number::Singular,
+            },
+            verb: get_word("loquor, loquī, locūtus sum"),
+        };
+        assert_eq!(sentence.compose().unwrap(), "lupī rem loquuntur");
+    }
+
+    #[test]
+    fn test_substitutions() {
+        let summarize = |subs: Vec<Substitution>| -> Vec<String> {
+            subs.iter()
+                .map(|sub| format!("{} {} {}", sub.case, sub.number, sub.forms.join("/")))
+                .collect()
+        };
+        let pronoun = get_word("is, ea, id");
+
+        // 'puellae' might be a genitive or a dative singular, but also a
+        // nominative plural.
+        let phrase = NounPhrase {
+            noun: get_word("puella, puellae"),
+            adjective: None,
+            number: Number::Singular,
+        };
+        assert_eq!(
+            summarize(phrase.substitutions(&pronoun, Case::Dative).unwrap()),
+            vec![
+                "nominative plural eae",
+                "genitive singular eius",
+                "dative singular eī",
+            ]
+        );
+
+        // The pronoun agrees in gender with the noun.
+        let phrase = NounPhrase {
+            noun: get_word("templum, templī"),
+            adjective: Some(get_word("gravis, grave")),
+            number: Number::Plural,
+        };
+        assert_eq!(
+            summarize(phrase.substitutions(&pronoun, Case::Accusative).unwrap()),
+            vec!["nominative plural ea", "accusative plural ea"]
+        );
+    }
+
+    fn analyses_for(form: &str) -> Vec<String> {
+        analyze(form)
+            .unwrap()
+            .iter()
+            .map(|analysis| analysis.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_analyze() {
+        assert_eq!(
+            analyze("rosam").unwrap(),
+            [Analysis::Declined {
+                lemma: "rosa, rosae".to_string(),
+                case: Case::Accusative,
+                number: Number::Singular,
+                gender: Gender::Feminine,
+            }]
+        );
+
+        // Without macrons the ablative is also a candidate.
+        let analyses = analyses_for("rosa");
+        assert!(analyses.contains(&"rosa, rosae: nominative singular feminine".to_string()));
+        assert!(analyses.contains(&"rosa, rosae: ablative singular feminine".to_string()));
+        assert!(!analyses_for("rosā")
+            .contains(&"rosa, rosae: nominative singular feminine".to_string()));
+
+        // Strictly, unmarked forms are not taken for marked ones.
+        let mut strict = Analyzer::with_strictness(MacronStrictness::Strict).unwrap();
+        let analyses: Vec<String> = strict
+            .analyze("rosa")
+            .unwrap()
+            .iter()
+            .map(|analysis| analysis.to_string())
+            .collect();
+        assert!(analyses.contains(&"rosa, rosae: nominative singular feminine".to_string()));
+        assert!(!analyses.contains(&"rosa, rosae: ablative singular feminine".to_string()));
+
+        assert!(analyses_for("amat").contains(
+            &"amō, amāre, amāvī, amātum: third person singular present indicative active"
+                .to_string()
+        ));
+        assert!(analyses_for("tulit").contains(
+            &"ferō, ferre, tulī, lātum: third person singular perfect indicative active"
+                .to_string()
+        ));
+        assert!(analyses_for("erat").contains(
+            &"sum, esse, fuī, futūrus: third person singular imperfect indicative active"
+                .to_string()
+        ));
+        assert!(analyses_for("nōbīs").contains(&"ego, nōs: dative plural".to_string()));
+        assert!(analyses_for("domī")
+            .contains(&"domus, domūs/domī: locative singular feminine".to_string()));
+        assert!(analyses_for("xyzzy").is_empty());
+    }
+
+    #[test]
+    fn test_count_lemmas() {
+        let counts = count_lemmas(&["Rosam amat. Rosa, rosam!".to_string()]).unwrap();
+        assert!(counts.contains(&("rosa, rosae".to_string(), 3)));
+        assert!(counts.contains(&("amō, amāre, amāvī, amātum".to_string(), 1)));
+    }
+
+    #[test]
+    fn test_find_example() {
+        let texts = [
+            "Puella cantat.".to_string(),
+            "Rosam amat. Puer rīdet!".to_string(),
+        ];
+        assert_eq!(
+            find_example(&get_word("rosa, rosae"), &texts)
+                .unwrap()
+                .as_deref(),
+            Some("Rosam amat.")
+        );
+        assert_eq!(
+            find_example(&get_word("amō, amāre"), &texts)
+                .unwrap()
+                .as_deref(),
+            Some("Rosam amat.")
+        );
+        assert_eq!(
+            find_example(&get_word("ferō, ferre"), &texts).unwrap(),
+            None
+        );
+    }
+    #[test]
+    fn test_render_tables() {
+        let word = get_word("rosa, rosae");
+        let table = declension_table(
+            &word.enunciated,
+            &word,
+            &get_noun_table(&word).unwrap(),
+            &CaseOrder::English,
+        );
+        assert_eq!(
+            table.render(Format::Markdown),
+            "**rosa, rosae**\n\n\
+             |  | Singular | Plural |\n\
+             |---|---|---|\n\
+             | **Nominative** | rosa | rosae |\n\
+             | **Genitive** | rosae | rosārum |\n\
+             | **Dative** | rosae | rosīs |\n\
+             | **Accusative** | rosam | rosās |\n\
+             | **Ablative** | rosā | rosīs |\n\
+             | **Vocative** | rosa | rosae |\n"
+        );
+        assert!(table
+            .render(Format::Latex)
+            .contains("\\textbf{Genitive} & rosae & rosārum \\\\\n"));
+        assert!(table
+            .render(Format::Html)
+            .contains("<tr><th>Dative</th><td>rosae</td><td>rosīs</td></tr>\n"));
+
+        let word = get_word("loquor, loquī, locūtus sum");
+        let titles: Vec<String> =
+            conjugation_tables(&word.enunciated, &get_verb_table(&word).unwrap())
+                .into_iter()
+                .map(|table| table.title)
+                .collect();
+        assert!(titles.contains(&"loquor, loquī, locūtus sum: indicative passive".to_string()));
+        assert!(!titles.contains(&"loquor, loquī, locūtus sum: indicative active".to_string()));
+    }
+    #[test]
+    fn test_case_order() {
+        let mut word = get_word("rosa, rosae");
+        let table = get_noun_table(&word).unwrap();
+
+        // Iterating a table follows the order in which cases are stored.
+        let cells: Vec<(Case, Number, String)> = table
+            .iter()
+            .map(|(case, number, info)| (case, number, info.inflected.join("/")))
+            .collect();
+        assert_eq!(cells.len(), 14);
+        assert_eq!(
+            cells[..3],
+            [
+                (Case::Nominative, Number::Singular, "rosa".to_string()),
+                (Case::Nominative, Number::Plural, "rosae".to_string()),
+                (Case::Vocative, Number::Singular, "rosa".to_string()),
+            ]
+        );
+        assert_eq!(
+            cells[12..]
+                .iter()
+                .map(|(case, number, _)| (*case, *number))
+                .collect::<Vec<_>>(),
+            [
+                (Case::Locative, Number::Singular),
+                (Case::Locative, Number::Plural)
+            ]
+        );
+
+        // The locative is only shown for the words which have it.
+        assert_eq!(
+            CaseOrder::European.cases_for(&word),
+            Case::ALL[..6].to_vec()
+        );
+        assert_eq!(
+            CaseOrder::English.cases_for(&word),
+            [
+                Case::Nominative,
+                Case::Genitive,
+                Case::Dative,
+                Case::Accusative,
+                Case::Ablative,
+                Case::Vocative,
+            ]
+        );
+        word.locative = true;
+        assert_eq!(
+            CaseOrder::English.cases_for(&word).last(),
+            Some(&Case::Locative)
+        );
+        assert_eq!(CaseOrder::European.cases_for(&word), Case::ALL.to_vec());
+
+        assert_eq!(case_label(Case::Dative), "Dative:\t\t");
+        assert_eq!(case_label(Case::Nominative), "Nominative:\t");
+    }
+    #[test]
+    fn test_synopsis() {
+        let word = get_word("amō, amāre, amāvī, amātum");
+        let synopsis = get_synopsis(&word, 3, Number::Singular).unwrap();
+        let rows: Vec<String> = synopsis
+            .iter()
+            .map(|row| {
+                format!(
+                    "{} {}: {} | {}",
+                    row.tense,
+                    row.mood,
+                    row.forms[0].join("/"),
+                    row.forms[1].join("/")
+                )
+            })
+            .collect();
+
+        assert_eq!(rows.len(), 11);
+        assert_eq!(rows[0], "Present Indicative: amat | amātur");
+        assert_eq!(rows[4], "Pluperfect Indicative: amāverat | amātus erat");
+        assert_eq!(rows[9], "Pluperfect Subjunctive: amāvisset | amātus esset");
+        assert_eq!(rows[10], "Future Imperative: amātō | amātor");
+
+        assert!(get_synopsis(&word, 4, Number::Singular).is_err());
+        assert!(get_synopsis(&get_word("rosa, rosae"), 3, Number::Singular).is_err());
+    }
+
+    #[test]
+    fn test_periphrastic() {
+        let table = get_verb_table(&get_word("amō, amāre, amāvī, amātum")).unwrap();
+        let active = &table.periphrastic.active;
+        let passive = &table.periphrastic.passive;
+
+        assert_eq!(
+            active[0].present.forms[0][2].inflected,
+            vec!["amātūrus est".to_string()]
+        );
+        assert_eq!(
+            active[1].perfect.forms[1][2].inflected,
+            vec!["amātūrī fuerint".to_string()]
+        );
+        assert_eq!(
+            passive[0].imperfect.forms[1][0].inflected,
+            vec!["amandī erāmus".to_string()]
+        );
+        assert!(passive[1].future.is_empty());
+
+        // Verbs with no passive only have the impersonal passive periphrastic.
+        let table = get_verb_table(&get_word("dormiō, dormīre, dormīvī, dormītum")).unwrap();
+        let passive = &table.periphrastic.passive;
+        assert_eq!(
+            passive[0].present.forms[0][2].inflected,
+            vec!["dormiendum est".to_string()]
+        );
+        assert!(passive[0].present.forms[0][0].inflected.is_empty());
+
+        // And 'sum' has no gerundive at all.
+        let table = get_verb_table(&get_word("sum, esse, fuī, futūrus")).unwrap();
+        assert!(table.periphrastic.passive[0].is_empty());
+        assert_eq!(
+            table.periphrastic.active[0].future.forms[0][0].inflected,
+            vec!["futūrus erō".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_verb_overrides() {
+        let mut word = get_word("videō, vidēre, vīdī, vīsum");
+        word.flags = serde_json::json!({
+            "adds": {"indicative": {"active": {"perfect": {"third": {"plural": ["ēre"]}}}}},
+            "sets": {"indicative": {"passive": {"present": {"second": {"singular": ["ēre"]}}}}},
+        });
+        let table = get_verb_table(&word).unwrap();
+
+        assert_eq!(
+            table.indicative[0].perfect.forms[1][2].inflected,
+            vec!["vīdērunt".to_string(), "vīdēre".to_string()]
+        );
+        assert_eq!(
+            table.indicative[1].present.forms[0][1].inflected,
+            vec!["vidēre".to_string()]
+        );
+        assert_eq!(
+            table.indicative[1].present.forms[0][0].inflected,
+            vec!["videor".to_string()]
+        );
+
+        word.flags = serde_json::json!({
+            "sets": {"indicative": {"active": {"aorist": {"third": {"plural": ["ēre"]}}}}},
+        });
+        assert!(get_verb_table(&word).is_err());
+    }
+
+    #[test]
+    fn test_canonical_cells() {
+        let mut word = get_word("avis, avis");
+        word.flags = serde_json::json!({
+            "adds": {"ablative": {"singular": ["ī", "e", "ī"]}},
+        });
+        let table = get_noun_table(&word).unwrap();
+
+        // Repeated forms are only kept once, and the preferred one from the
+        // database goes first.
+        assert_eq!(
+            table.ablative[0].inflected,
+            vec!["ave".to_string(), "avī".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_voice_pairs() {
+        let pairs = get_voice_pairs(&get_word("amō, amāre, amāvī, amātum")).unwrap();
+        let pair = pairs
+            .iter()
+            .find(|pair| {
+                pair.mood == "Indicative"
+                    && pair.tense == "Present"
+                    && pair.person == 3
+                    && pair.number == Number::Singular
+            })
+            .unwrap();
+        assert_eq!(
+            pair.forms,
+            [vec!["amat".to_string()], vec!["amātur".to_string()]]
+        );
+        assert!(pairs
+            .iter()
+            .any(|pair| pair.forms[1] == vec!["amātī essent".to_string()]));
+
+        // Verbs with a single voice cannot be transformed.
+        let deponent = get_word("liceor, licērī, licitus sum");
+        assert!(get_voice_pairs(&deponent).unwrap().is_empty());
+
+        // Impersonal passives only have the third person singular.
+        let pairs = get_voice_pairs(&get_word("vīvō, vīvere, vīxī, vīctum")).unwrap();
+        assert!(!pairs.is_empty());
+        assert!(pairs
+            .iter()
+            .all(|pair| pair.person == 3 && pair.number == Number::Singular));
+    }
+
+    #[test]
+    fn test_alternative_forms() {
+        let word = get_word("amō, amāre, amāvī, amātum");
+        let mut table = get_verb_table(&word).unwrap();
+        push_alternative_forms(&word, &mut table);
+
+        assert_eq!(
+            table.indicative[0].perfect.forms[1][2].inflected,
+            vec![
+                "amāvērunt".to_string(),
+                "amārunt".to_string(),
+                "amāvēre".to_string()
+            ]
+        );
+        assert_eq!(
+            table.infinitive.perfect[0].inflected,
+            vec!["amāvisse".to_string(), "amāsse".to_string()]
+        );
+        assert_eq!(
+            table.subjunctive[0].pluperfect.forms[0][0].inflected,
+            vec!["amāvissem".to_string(), "amāssem".to_string()]
+        );
+        assert_eq!(
+            table.indicative[0].perfect.forms[0][2].inflected,
+            vec!["amāvit".to_string()]
+        );
+
+        let word = get_word("audiō, audīre, audīvī, audītum");
+        let mut table = get_verb_table(&word).unwrap();
+        push_alternative_forms(&word, &mut table);
+
+        assert_eq!(
+            table.indicative[0].perfect.forms[0][1].inflected,
+            vec![
+                "audīvistī".to_string(),
+                "audiistī".to_string(),
+                "audīstī".to_string()
+            ]
+        );
+        assert_eq!(
+            table.indicative[0].pluperfect.forms[0][2].inflected,
+            vec!["audīverat".to_string(), "audierat".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_enclitics() {
+        let que = get_word("-que");
+        assert_eq!(que.enclitic_suffix(), Some("que"));
+        assert_eq!(
+            que.enclitic_example().as_deref(),
+            Some("senātus populusque")
+        );
+        assert_eq!(get_word("rosa, rosae").enclitic_example(), None);
+
+        let analyses = analyses_for("rosamque");
+        assert_eq!(
+            analyses,
+            vec![
+                "rosa, rosae: accusative singular feminine".to_string(),
+                "-que: enclitic".to_string()
+            ]
+        );
+
+        // Enclitics are not stripped if the rest cannot be analyzed.
+        assert!(analyses_for("itaque").is_empty());
+    }
 }
@@ -1,14 +1,54 @@
+use crate::interactive::{can_prompt, Ask};
+use crate::locale::Locale;
+use inquire::{Confirm, Text};
+use mihi::cfg::{MacronStrictness, Scheduler};
+use mihi::{
+    current_profile, database_status, get_database_path, init_database, migrate_database,
+    profile_exists, seed_inflection_data, DatabaseStatus,
+};
+use std::path::PathBuf;
+
+/// Exit status used when a command needs a database but there is none.
+pub const NO_DATABASE_STATUS: i32 = 3;
+
 fn help() {
     println!("mihi init: Initialize 'mihi' for a given language.\n");
     println!("usage: mihi init [OPTIONS]\n");
 
     println!("Options:");
+    println!("   --alternative-forms <on|off>\tAlso accept the syncopated perfects of verbs (e.g. 'amāsse', 'amārunt') and the '-ēre' ending (e.g. 'amāvēre').");
+    println!("   --fallback-locales <CODES>\tComma-separated locales (e.g. 'en') whose translations are used when a word has none in the current one, or 'none' to skip such words (default: 'en').");
+    println!("   -g, --goal <KIND>=<N>\tExpect at least <N> words of the given <KIND> (e.g. 'istem=10'). It can be given multiple times, and a value of 0 removes the goal. See 'mihi stats --coverage'.");
     println!("   -h, --help\t\tPrint this message.");
     println!("   -l, --language\tThe language to be used.");
+    println!("   --macrons <STRICTNESS>\tHow answers are graded on practice sessions: 'strict', 'lenient' to tolerate omitted macrons, 'ignore' to not check macrons at all, or 'typos' to also tolerate small typos on longer answers (default: 'lenient').");
+    println!("   -s, --seed <FILE>\tInitialize the database as a copy of the given one (e.g. 'testdata/test.sqlite3').");
+    println!("   --reading-boost <on|off>\tPractice more often the words which occur in your exercises. See 'mihi words occurrences'.");
+    println!("   --max-items <N>\tOffer to take a break after reviewing <N> items on a practice session (0 for no limit).");
+    println!("   --max-minutes <N>\tOffer to take a break after practicing for <N> minutes (0 for no limit).");
+    println!("   --new-words <N>\tIntroduce at most <N> words which you have never reviewed on each practice session (0 for no limit).");
+    println!("   --scheduler <NAME>\tHow words are scheduled for review: 'sm2' to space them out by how well you know them, 'leitner' to move them between boxes with fixed intervals (1, 2, 4, 8 and 16 days), or 'legacy' to not schedule them at all (default: 'sm2').");
+}
+
+// Preferences which are only written into the configuration if they were
+// given.
+#[derive(Default)]
+struct Preferences {
+    reading_boost: Option<bool>,
+    alternative_forms: Option<bool>,
+    locale_fallbacks: Option<Vec<String>>,
+    macron_strictness: Option<MacronStrictness>,
+    scheduler: Option<Scheduler>,
+    max_new_words: Option<usize>,
 }
 
 pub fn run(args: Vec<String>) {
     let mut given_language: Option<String> = None;
+    let mut max_items: Option<usize> = None;
+    let mut max_minutes: Option<u64> = None;
+    let mut seed: Option<PathBuf> = None;
+    let mut goals: Vec<(String, usize)> = vec![];
+    let mut preferences = Preferences::default();
     let mut it = args.into_iter();
 
     while let Some(arg) = it.next() {
@@ -26,6 +66,120 @@ pub fn run(args: Vec<String>) {
                     std::process::exit(1);
                 }
             },
+            "-s" | "--seed" => match it.next() {
+                Some(path) => seed = Some(PathBuf::from(path)),
+                None => {
+                    println!("error: init: you have to provide a value for the '-s/--seed' flag");
+                    std::process::exit(1);
+                }
+            },
+            "-g" | "--goal" => match it.next().as_deref().and_then(|v| v.split_once('=')) {
+                Some((kind, target)) if !kind.trim().is_empty() => {
+                    match target.trim().parse::<usize>() {
+                        Ok(target) => goals.push((kind.trim().to_string(), target)),
+                        Err(_) => {
+                            println!("error: init: the target for the '-g/--goal' flag has to be a number");
+                            std::process::exit(1);
+                        }
+                    }
+                }
+                _ => {
+                    println!("error: init: you have to provide a '<KIND>=<N>' value for the '-g/--goal' flag");
+                    std::process::exit(1);
+                }
+            },
+            "--reading-boost" => match it.next().as_deref() {
+                Some("on") => preferences.reading_boost = Some(true),
+                Some("off") => preferences.reading_boost = Some(false),
+                _ => {
+                    println!(
+                        "error: init: the '--reading-boost' flag expects either 'on' or 'off'"
+                    );
+                    std::process::exit(1);
+                }
+            },
+            "--alternative-forms" => match it.next().as_deref() {
+                Some("on") => preferences.alternative_forms = Some(true),
+                Some("off") => preferences.alternative_forms = Some(false),
+                _ => {
+                    println!(
+                        "error: init: the '--alternative-forms' flag expects either 'on' or 'off'"
+                    );
+                    std::process::exit(1);
+                }
+            },
+            "--fallback-locales" => match it.next() {
+                Some(codes) if codes.trim() == "none" => {
+                    preferences.locale_fallbacks = Some(vec![]);
+                }
+                Some(codes) => {
+                    let codes: Vec<String> = codes
+                        .split(',')
+                        .map(|code| code.trim().to_string())
+                        .filter(|code| !code.is_empty())
+                        .collect();
+                    if let Some(code) = codes.iter().find(|c| Locale::from_code(c).is_none()) {
+                        println!("error: init: unknown locale '{code}'. Available: en, ca");
+                        std::process::exit(1);
+                    }
+                    preferences.locale_fallbacks = Some(codes);
+                }
+                None => {
+                    println!(
+                        "error: init: you have to provide a value for the '--fallback-locales' flag"
+                    );
+                    std::process::exit(1);
+                }
+            },
+            "--macrons" => match it.next().as_deref().map(MacronStrictness::try_from) {
+                Some(Ok(strictness)) => preferences.macron_strictness = Some(strictness),
+                Some(Err(e)) => {
+                    println!("error: init: {e}");
+                    std::process::exit(1);
+                }
+                None => {
+                    println!("error: init: you have to provide a value for the '--macrons' flag");
+                    std::process::exit(1);
+                }
+            },
+            "--scheduler" => match it.next().as_deref().map(Scheduler::try_from) {
+                Some(Ok(scheduler)) => preferences.scheduler = Some(scheduler),
+                Some(Err(e)) => {
+                    println!("error: init: {e}");
+                    std::process::exit(1);
+                }
+                None => {
+                    println!("error: init: you have to provide a value for the '--scheduler' flag");
+                    std::process::exit(1);
+                }
+            },
+            "--max-items" => match it.next().map(|v| v.parse::<usize>()) {
+                Some(Ok(value)) => max_items = Some(value),
+                _ => {
+                    println!(
+                        "error: init: you have to provide a number for the '--max-items' flag"
+                    );
+                    std::process::exit(1);
+                }
+            },
+            "--max-minutes" => match it.next().map(|v| v.parse::<u64>()) {
+                Some(Ok(value)) => max_minutes = Some(value),
+                _ => {
+                    println!(
+                        "error: init: you have to provide a number for the '--max-minutes' flag"
+                    );
+                    std::process::exit(1);
+                }
+            },
+            "--new-words" => match it.next().map(|v| v.parse::<usize>()) {
+                Some(Ok(value)) => preferences.max_new_words = Some(value),
+                _ => {
+                    println!(
+                        "error: init: you have to provide a number for the '--new-words' flag"
+                    );
+                    std::process::exit(1);
+                }
+            },
             _ => {
                 println!("error: init: unknown flag: '{}'", arg.as_str());
                 std::process::exit(1);
@@ -40,7 +194,7 @@ pub fn run(args: Vec<String>) {
         None => String::from("latin"),
     };
 
-    match init(language) {
+    match init(language, max_items, max_minutes, seed, goals, preferences) {
         Ok(_) => {}
         Err(e) => {
             println!("error: init: {e}");
@@ -49,6 +203,144 @@ pub fn run(args: Vec<String>) {
     }
 }
 
-fn init(language: String) -> Result<(), String> {
-    mihi::cfg::add_language(language)
+fn init(
+    language: String,
+    max_items: Option<usize>,
+    max_minutes: Option<u64>,
+    seed: Option<PathBuf>,
+    goals: Vec<(String, usize)>,
+    preferences: Preferences,
+) -> Result<(), String> {
+    mihi::cfg::add_language(language)?;
+
+    match database_status()? {
+        DatabaseStatus::Ready => {
+            if seed.is_some() {
+                return Err(format!(
+                    "there is already a database in '{}'. Call 'nuke' first if you want to replace it",
+                    get_database_path()?.display()
+                ));
+            }
+            migrate_database()?;
+            seed_inflection_data()?;
+        }
+        DatabaseStatus::Missing | DatabaseStatus::Empty => init_database(seed.as_deref())?,
+    }
+
+    // Given goals are merged into the ones that were already configured.
+    if !goals.is_empty() {
+        let mut merged = mihi::cfg::configuration().coverage_goals;
+        for (kind, target) in goals {
+            merged.retain(|(k, _)| *k != kind);
+            merged.push((kind, target));
+        }
+        mihi::cfg::set_coverage_goals(&merged)?;
+    }
+
+    if let Some(boost) = preferences.reading_boost {
+        mihi::cfg::set_boost_occurrences(boost)?;
+    }
+    if let Some(enabled) = preferences.alternative_forms {
+        mihi::cfg::set_alternative_verb_forms(enabled)?;
+    }
+    if let Some(codes) = preferences.locale_fallbacks {
+        mihi::cfg::set_locale_fallbacks(&codes)?;
+    }
+    if let Some(strictness) = preferences.macron_strictness {
+        mihi::cfg::set_macron_strictness(strictness)?;
+    }
+    if let Some(scheduler) = preferences.scheduler {
+        mihi::cfg::set_scheduler(scheduler)?;
+    }
+    if let Some(words) = preferences.max_new_words {
+        mihi::cfg::set_max_new_words(words)?;
+    }
+
+    // Only touch the session limits if any of them was given, otherwise keep
+    // whatever was already configured.
+    if max_items.is_none() && max_minutes.is_none() {
+        return Ok(());
+    }
+
+    let cfg = mihi::cfg::configuration();
+    mihi::cfg::set_session_limits(
+        max_items.unwrap_or(cfg.max_session_items.unwrap_or(0)),
+        max_minutes.unwrap_or(cfg.max_session_minutes.unwrap_or(0)),
+    )
+}
+
+/// Checks that the database is there before running a command that needs it,
+/// and applies any migration it's still missing. If it's not there, then the
+/// user is offered to initialize it right away. Without a terminal, this exits
+/// with `NO_DATABASE_STATUS` instead.
+pub fn ensure_database() {
+    // Profiles are created through 'mihi profiles create' or 'mihi init', so
+    // a typo does not silently start an empty one.
+    if let Some(profile) = current_profile() {
+        match profile_exists(profile) {
+            Ok(true) => {}
+            Ok(false) => {
+                println!("error: the profile '{profile}' does not exist. Call 'mihi profiles create {profile}' first");
+                std::process::exit(1);
+            }
+            Err(e) => {
+                println!("error: {e}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let status = match database_status() {
+        Ok(status) => status,
+        Err(e) => {
+            println!("error: {e}");
+            std::process::exit(1);
+        }
+    };
+    if status == DatabaseStatus::Ready {
+        if let Err(e) = migrate_database() {
+            println!("error: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let path = get_database_path()
+        .map(|p| p.display().to_string())
+        .unwrap_or_default();
+    if !can_prompt() {
+        println!("error: there is no database in '{path}'. Call 'mihi init' first");
+        std::process::exit(NO_DATABASE_STATUS);
+    }
+
+    println!("There is no database in '{path}' yet.");
+    let Ok(true) = Confirm::new("Do you want to initialize it now?")
+        .with_default(true)
+        .ask()
+    else {
+        std::process::exit(NO_DATABASE_STATUS);
+    };
+    let Ok(seed) =
+        Text::new("Copy an existing database (leave it empty to start from scratch):").ask()
+    else {
+        std::process::exit(NO_DATABASE_STATUS);
+    };
+    let seed = seed.trim();
+    let seed = if seed.is_empty() {
+        None
+    } else {
+        Some(PathBuf::from(seed))
+    };
+
+    if let Err(e) = init(
+        String::from("latin"),
+        None,
+        None,
+        seed,
+        vec![],
+        Preferences::default(),
+    ) {
+        println!("error: init: {e}");
+        std::process::exit(1);
+    }
 }
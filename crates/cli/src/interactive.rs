@@ -0,0 +1,248 @@
+use inquire::{Confirm, Editor, InquireError, MultiSelect, Select, Text};
+use std::fmt::Display;
+use std::io::{stdin, stdout, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::vec::IntoIter;
+
+// Whether '--no-input' was given, so prompts are answered from the standard
+// input instead of a terminal.
+static NO_INPUT: AtomicBool = AtomicBool::new(false);
+
+/// Answer all prompts from the standard input from now on. See `Ask`.
+pub fn set_no_input() {
+    NO_INPUT.store(true, Ordering::Relaxed);
+}
+
+/// Returns true if prompts are answered from the standard input. See `Ask`.
+pub fn no_input() -> bool {
+    NO_INPUT.load(Ordering::Relaxed)
+}
+
+/// Returns true if both the standard input and output are attached to a
+/// terminal, so the user can be prompted. Otherwise we are being piped into
+/// another tool or run from a script.
+pub fn is_interactive() -> bool {
+    stdin().is_terminal() && stdout().is_terminal()
+}
+
+/// Returns true if the user can answer prompts, either from a terminal or
+/// from the standard input because of '--no-input'.
+pub fn can_prompt() -> bool {
+    is_interactive() || no_input()
+}
+
+/// A prompt which is answered on the terminal, or from the standard input if
+/// '--no-input' was given (e.g. on scripts and tests). In the latter case each
+/// prompt takes one line, where an empty line stands for the initial or
+/// default value of the prompt, and running out of lines cancels it.
+pub trait Ask {
+    type Output;
+
+    fn ask(self) -> Result<Self::Output, InquireError>;
+}
+
+// Reads the answer for a prompt from the standard input.
+fn read_answer() -> Result<String, InquireError> {
+    let mut line = String::new();
+    match stdin().read_line(&mut line) {
+        Ok(0) => Err(InquireError::OperationCanceled),
+        Ok(_) => Ok(line.trim_end_matches(['\n', '\r']).to_string()),
+        Err(e) => Err(InquireError::IO(e)),
+    }
+}
+
+// Prints the prompt with the given `message` alongside the `answer` which was
+// taken for it, so the output reads the same as an interactive session.
+fn echo<T: Display>(message: &str, answer: T) -> Result<T, InquireError> {
+    println!("{message} {answer}");
+    Ok(answer)
+}
+
+// Returns the position of the given `answer` on the `options`, which can be
+// given either by its text or by its number starting from 1.
+fn find_option<T: Display>(options: &[T], answer: &str) -> Result<usize, InquireError> {
+    let answer = answer.trim();
+    if let Ok(n) = answer.parse::<usize>() {
+        if n > 0 && n <= options.len() {
+            return Ok(n - 1);
+        }
+    }
+
+    match options
+        .iter()
+        .position(|option| option.to_string() == answer)
+    {
+        Some(idx) => Ok(idx),
+        None => Err(InquireError::InvalidConfiguration(format!(
+            "'{answer}' is not one of the options"
+        ))),
+    }
+}
+
+impl Ask for Text<'_> {
+    type Output = String;
+
+    fn ask(self) -> Result<String, InquireError> {
+        if !no_input() {
+            return self.prompt();
+        }
+
+        let answer = read_answer()?;
+        if answer.is_empty() {
+            echo(
+                self.message,
+                self.initial_value
+                    .or(self.default)
+                    .unwrap_or("")
+                    .to_string(),
+            )
+        } else {
+            echo(self.message, answer)
+        }
+    }
+}
+
+impl Ask for Confirm<'_> {
+    type Output = bool;
+
+    fn ask(self) -> Result<bool, InquireError> {
+        if !no_input() {
+            return self.prompt();
+        }
+
+        let answer = match read_answer()?.trim().to_lowercase().as_str() {
+            "y" | "yes" => true,
+            "n" | "no" => false,
+            "" if self.default.is_some() => self.default.unwrap_or(false),
+            answer => {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "'{answer}' is neither 'yes' nor 'no'"
+                )))
+            }
+        };
+        echo(self.message, if answer { "yes" } else { "no" })?;
+        Ok(answer)
+    }
+}
+
+impl<T: Display> Ask for Select<'_, T> {
+    type Output = T;
+
+    fn ask(mut self) -> Result<T, InquireError> {
+        if !no_input() {
+            return self.prompt();
+        }
+
+        let answer = read_answer()?;
+        let idx = if answer.is_empty() {
+            self.starting_cursor
+        } else {
+            find_option(&self.options, &answer)?
+        };
+        if idx >= self.options.len() {
+            return Err(InquireError::InvalidConfiguration(
+                "there are no options to choose from".to_string(),
+            ));
+        }
+        echo(self.message, self.options.swap_remove(idx))
+    }
+}
+
+// Options are separated by commas on the answer.
+impl<T: Display> Ask for MultiSelect<'_, T> {
+    type Output = Vec<T>;
+
+    fn ask(self) -> Result<Vec<T>, InquireError> {
+        if !no_input() {
+            return self.prompt();
+        }
+
+        let answer = read_answer()?;
+        let mut selected = if answer.trim().is_empty() {
+            self.default.clone().unwrap_or_default()
+        } else {
+            answer
+                .split(',')
+                .map(|option| find_option(&self.options, option))
+                .collect::<Result<Vec<usize>, InquireError>>()?
+        };
+        selected.sort_unstable();
+        selected.dedup();
+
+        let res: Vec<T> = self
+            .options
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| selected.contains(idx))
+            .map(|(_, option)| option)
+            .collect();
+        echo(
+            self.message,
+            res.iter()
+                .map(|option| option.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+        )?;
+        Ok(res)
+    }
+}
+
+// Literal '\n' sequences on the answer are taken as line breaks, as each answer
+// is given in a single line.
+impl Ask for Editor<'_> {
+    type Output = String;
+
+    fn ask(self) -> Result<String, InquireError> {
+        if !no_input() {
+            return self.prompt();
+        }
+
+        // The predefined text is usually too long to be echoed back.
+        let answer = read_answer()?;
+        echo(self.message, &answer)?;
+        if answer.is_empty() {
+            Ok(self.predefined_text.unwrap_or("").to_string())
+        } else {
+            Ok(answer.replace("\\n", "\n"))
+        }
+    }
+}
+
+/// Asks the user to confirm the given `question`, unless the action has already
+/// been `forced` (i.e. '-y/--yes' was given). Without a terminal this returns an
+/// error instead, as destructive actions have to be explicit in scripts.
+pub fn confirm(question: &str, forced: bool) -> Result<bool, String> {
+    if forced {
+        return Ok(true);
+    }
+    if !can_prompt() {
+        return Err(
+            "no terminal to confirm this action, pass '-y/--yes' to do it anyway".to_string(),
+        );
+    }
+
+    match Confirm::new(question).with_default(false).ask() {
+        Ok(answer) => Ok(answer),
+        Err(_) => Err("abort!".to_string()),
+    }
+}
+
+/// Removes the given `flag` from the `args` and returns whether it was there,
+/// alongside the rest of arguments.
+pub fn take_flag(args: IntoIter<String>, flag: &[&str]) -> (bool, IntoIter<String>) {
+    let (found, rest): (Vec<String>, Vec<String>) =
+        args.partition(|arg| flag.contains(&arg.as_str()));
+
+    (!found.is_empty(), rest.into_iter())
+}
+
+/// Prints each of the given `lines`. Unlike `println!`, this stops quietly if
+/// whoever was reading our output went away (e.g. 'mihi words ls | head').
+pub fn print_lines(lines: &[String]) {
+    let mut out = stdout().lock();
+    for line in lines {
+        if writeln!(out, "{line}").is_err() {
+            return;
+        }
+    }
+}
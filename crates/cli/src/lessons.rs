@@ -0,0 +1,327 @@
+use crate::interactive::{confirm, take_flag};
+use mihi::lesson::{
+    create_lesson, delete_lesson, find_lesson, link_to_lesson, select_lesson_contents,
+    select_lessons,
+};
+use std::vec::IntoIter;
+
+// Show the help message.
+fn help(msg: Option<&str>) {
+    if let Some(msg) = msg {
+        println!("{}.\n", msg);
+    }
+
+    println!("mihi lessons: Manage lessons (e.g. the chapters of a book).\n");
+    println!("usage: mihi lessons [OPTIONS] <subcommand>\n");
+
+    println!("Options:");
+    println!("   -e, --exercise <TITLE>\tOnly for 'link': link the exercise with the given title. It can be given multiple times.");
+    println!("   -h, --help\t\tPrint this message.");
+    println!("   --json\t\tOnly for 'ls': print the list as a JSON array.");
+    println!("   --position <N>\tOnly for 'create': position of the lesson among the rest (e.g. the number of the chapter). Defaults to the last one.");
+    println!(
+        "   --source <TEXT>\tOnly for 'create': the book or course from which the lesson comes."
+    );
+    println!("   -w, --word <WORD>\tOnly for 'link': link the word with the given enunciate. It can be given multiple times.");
+    println!("   -y, --yes\t\tOnly for 'rm': do not ask for confirmation. Required when there is no terminal.");
+
+    println!("\nSubcommands:");
+    println!("   create\t\tCreate a new lesson.");
+    println!("   link\t\t\tLink words and exercises to the given lesson. They are moved away from any lesson they were on before.");
+    println!("   ls\t\t\tList lessons sorted by their position.");
+    println!("   rm\t\t\tRemove a lesson from the database. Its words and exercises are kept.");
+    println!("   show\t\t\tShow the words and exercises from a lesson.");
+}
+
+// Returns the only argument left on `args`, which is the name of a lesson.
+fn lesson_name(mut args: IntoIter<String>) -> Result<String, i32> {
+    if args.len() != 1 {
+        let mut msg = "error: lessons: you have to pass exactly one argument, which is the name of the lesson".to_string();
+        if args.len() > 1 {
+            msg.push_str(". You might want to wrap the given arguments in quotes");
+        }
+
+        help(Some(msg.as_str()));
+        return Err(1);
+    }
+    Ok(args.next().unwrap_or_default())
+}
+
+fn create(mut args: IntoIter<String>) -> i32 {
+    let mut names = vec![];
+    let mut source = None;
+    let mut position = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--source" => match args.next() {
+                Some(text) => source = Some(text),
+                None => {
+                    help(Some("error: lessons: you have to provide a source"));
+                    return 1;
+                }
+            },
+            "--position" => match args.next().map(|p| p.parse::<isize>()) {
+                Some(Ok(p)) => position = Some(p),
+                Some(Err(_)) => {
+                    help(Some("error: lessons: the position must be a number"));
+                    return 1;
+                }
+                None => {
+                    help(Some("error: lessons: you have to provide a position"));
+                    return 1;
+                }
+            },
+            _ => names.push(arg),
+        }
+    }
+
+    let name = match lesson_name(names.into_iter()) {
+        Ok(name) => name,
+        Err(code) => return code,
+    };
+    if find_lesson(&name).is_ok() {
+        println!("error: lessons: '{name}' already exists");
+        return 1;
+    }
+
+    match create_lesson(&name, source.as_deref(), position) {
+        Ok(_) => 0,
+        Err(e) => {
+            println!("error: lessons: {e}");
+            1
+        }
+    }
+}
+
+fn link(mut args: IntoIter<String>) -> i32 {
+    let mut names = vec![];
+    let mut words = vec![];
+    let mut exercises = vec![];
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-w" | "--word" => match args.next() {
+                Some(word) => words.push(word),
+                None => {
+                    help(Some("error: lessons: you have to provide a word"));
+                    return 1;
+                }
+            },
+            "-e" | "--exercise" => match args.next() {
+                Some(title) => exercises.push(title),
+                None => {
+                    help(Some("error: lessons: you have to provide an exercise"));
+                    return 1;
+                }
+            },
+            _ if arg.starts_with('-') => {
+                help(Some(
+                    format!("error: lessons: unknown flag '{arg}'").as_str(),
+                ));
+                return 1;
+            }
+            _ => names.push(arg),
+        }
+    }
+
+    let name = match lesson_name(names.into_iter()) {
+        Ok(name) => name,
+        Err(code) => return code,
+    };
+    if words.is_empty() && exercises.is_empty() {
+        help(Some(
+            "error: lessons: you have to provide at least a word or an exercise",
+        ));
+        return 1;
+    }
+
+    let lesson = match find_lesson(&name) {
+        Ok(lesson) => lesson,
+        Err(e) => {
+            println!("error: lessons: {e}.");
+            return 1;
+        }
+    };
+    let missing = match link_to_lesson(&lesson, &words, &exercises) {
+        Ok(missing) => missing,
+        Err(e) => {
+            println!("error: lessons: {e}.");
+            return 1;
+        }
+    };
+    for name in &missing {
+        println!("warning: lessons: '{name}' does not exist; skipping.");
+    }
+
+    let linked = words.len() + exercises.len() - missing.len();
+    println!("Linked {linked} words and exercises to '{}'.", lesson.name);
+    0
+}
+
+fn ls(args: IntoIter<String>) -> i32 {
+    let (json, args) = take_flag(args, &["--json"]);
+    if args.len() > 0 {
+        help(Some("error: lessons: 'ls' does not accept arguments"));
+        return 1;
+    }
+
+    let lessons = match select_lessons() {
+        Ok(lessons) => lessons,
+        Err(e) => {
+            println!("error: lessons: {e}.");
+            return 1;
+        }
+    };
+
+    if json {
+        let list: Vec<serde_json::Value> = lessons
+            .iter()
+            .map(|l| {
+                serde_json::json!({
+                    "name": l.name,
+                    "position": l.position,
+                    "source": l.source,
+                    "words": l.words,
+                    "exercises": l.exercises,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::json!(list));
+        return 0;
+    }
+
+    for lesson in &lessons {
+        let source = match &lesson.source {
+            Some(source) => format!(" [{source}]"),
+            None => String::new(),
+        };
+        println!(
+            "{}. {}{source}: {} words, {} exercises",
+            lesson.position, lesson.name, lesson.words, lesson.exercises
+        );
+    }
+    0
+}
+
+fn rm(args: IntoIter<String>) -> i32 {
+    let (yes, args) = take_flag(args, &["-y", "--yes"]);
+    let name = match lesson_name(args) {
+        Ok(name) => name,
+        Err(code) => return code,
+    };
+    let lesson = match find_lesson(&name) {
+        Ok(lesson) => lesson,
+        Err(e) => {
+            println!("error: lessons: {e}.");
+            return 1;
+        }
+    };
+
+    match confirm(
+        format!(
+            "Do you really want to remove '{}' from the database?",
+            lesson.name
+        )
+        .as_str(),
+        yes,
+    ) {
+        Ok(true) => match delete_lesson(&lesson.name) {
+            Ok(_) => println!("Removed '{}' from the database!", lesson.name),
+            Err(e) => {
+                println!("error: lessons: {e}.");
+                return 1;
+            }
+        },
+        Ok(false) => println!("Doing nothing..."),
+        Err(e) => {
+            println!("error: lessons: {e}.");
+            return 1;
+        }
+    }
+    0
+}
+
+fn show(args: IntoIter<String>) -> i32 {
+    let name = match lesson_name(args) {
+        Ok(name) => name,
+        Err(code) => return code,
+    };
+    let lesson = match find_lesson(&name) {
+        Ok(lesson) => lesson,
+        Err(e) => {
+            println!("error: lessons: {e}.");
+            return 1;
+        }
+    };
+    let (words, exercises) = match select_lesson_contents(&lesson) {
+        Ok(contents) => contents,
+        Err(e) => {
+            println!("error: lessons: {e}.");
+            return 1;
+        }
+    };
+
+    println!("Lesson: {} (position {})", lesson.name, lesson.position);
+    if let Some(source) = &lesson.source {
+        println!("Source: {source}");
+    }
+    for (header, list) in [("Words", &words), ("Exercises", &exercises)] {
+        if list.is_empty() {
+            println!("\n{header}: none");
+            continue;
+        }
+        println!("\n{header}:");
+        for item in list {
+            println!("   {item}");
+        }
+    }
+    0
+}
+
+pub fn run(args: Vec<String>) {
+    if args.is_empty() {
+        help(Some(
+            "error: lessons: you have to provide at least a subcommand",
+        ));
+        std::process::exit(1);
+    }
+
+    let mut it = args.into_iter();
+
+    match it.next() {
+        Some(first) => match first.as_str() {
+            "-h" | "--help" => {
+                help(None);
+                std::process::exit(0);
+            }
+            "create" => {
+                std::process::exit(create(it));
+            }
+            "link" => {
+                std::process::exit(link(it));
+            }
+            "ls" => {
+                std::process::exit(ls(it));
+            }
+            "rm" => {
+                std::process::exit(rm(it));
+            }
+            "show" => {
+                std::process::exit(show(it));
+            }
+            _ => {
+                help(Some(
+                    format!("error: lessons: unknown flag or command '{first}'").as_str(),
+                ));
+                std::process::exit(1);
+            }
+        },
+        None => {
+            help(Some(
+                "error: lessons: you have to provide at least a subcommand",
+            ));
+            std::process::exit(1);
+        }
+    }
+}
@@ -1,15 +1,29 @@
+use mihi::cfg::configuration;
+use mihi::word::Word;
+use serde_json::Value;
+
 // Locale represents the locales accepted for delivering answers on this
 // tool. That is, it's not about i18n on the strings for this application. but
 // rather the different translations accepted in places like
 // `Word.translations`.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Locale {
     English,
     Catalan,
 }
 
 impl Locale {
+    // Returns the locale for the given `code`, if it's supported.
+    pub fn from_code(code: &str) -> Option<Locale> {
+        match code {
+            "en" => Some(Self::English),
+            "ca" => Some(Self::Catalan),
+            _ => None,
+        }
+    }
+
     // Returns the string representation for the locale's code.
-    pub fn to_code(&self) -> &str {
+    pub fn to_code(self) -> &'static str {
         match self {
             Self::English => "en",
             Self::Catalan => "ca",
@@ -26,6 +40,25 @@ impl std::fmt::Display for Locale {
     }
 }
 
+/// Returns the translation of the given `word` in the given `locale`. If there
+/// is none, then the locales from the fallback chain of the configuration are
+/// tried in order. The locale of the returned translation is also given, so
+/// callers can tell the user when it's not the one being asked for.
+pub fn translation_for<'a>(word: &'a Word, locale: &Locale) -> Option<(Locale, &'a Value)> {
+    std::iter::once(*locale)
+        .chain(
+            configuration()
+                .locale_fallbacks
+                .iter()
+                .filter_map(|code| Locale::from_code(code)),
+        )
+        .find_map(|locale| {
+            word.translation
+                .get(locale.to_code())
+                .map(|translation| (locale, translation))
+        })
+}
+
 /// Fetches the Locale object that is suitable for the current environment.
 pub fn current_locale() -> Locale {
     let raw_locale = std::env::var("LC_ALL").unwrap_or("en".to_string());
@@ -1,12 +1,27 @@
+mod debug;
+mod due;
+mod exam;
 mod exercises;
+mod export;
+mod import;
 mod inflection;
 mod init;
+mod interactive;
+mod lessons;
 mod locale;
+mod markdown;
 mod nuke;
+mod profiles;
+mod publish;
 mod run;
+mod serve;
+mod stats;
+mod sync;
 mod tags;
 mod words;
 
+use std::path::PathBuf;
+
 /// Version for this program.
 const VERSION: &str = "0.1.0";
 
@@ -15,23 +30,79 @@ fn help() {
     println!("usage: mihi [OPTIONS] [COMMAND] [COMMAND OPTIONS]\n");
 
     println!("Options:");
+    println!("   --data-dir <DIR>\tKeep the database inside of <DIR> instead of the data directory of the platform (e.g. '~/.local/share/mihi'). It can also be set with the 'MIHI_DATA_DIR' environment variable.");
     println!("   -h, --help\t\tPrint this message.");
+    println!("   --no-input\t\tAnswer prompts from the standard input, one line each, instead of a terminal. An empty line takes the initial value of the prompt.");
+    println!("   --profile <NAME>\tUse the configuration and the database of the given profile. See 'mihi profiles'.");
     println!("   -v, --version\tPrint the version of this program.\n");
 
     println!("Commands:");
+    println!("   debug\t\tTools for developers of this application.");
+    println!("   due\t\t\tShow how many words are due for review today.");
+    println!("   exam\t\t\tTake an exam on the words from the given tags.");
     println!("   exercises\t\tManage the exercises for this application.");
+    println!("   export\t\tWrite the whole database into a JSON file.");
+    println!("   import\t\tRead a JSON file written by 'export' into the database.");
     println!("   init\t\t\tInitialize the configuration for this application.");
+    println!(
+        "   lessons\t\tManage lessons (e.g. the chapters of a book) grouping words and exercises."
+    );
     println!("   nuke\t\t\tRemove all files from this application and its database.");
     println!("   practice\t\tPractice vocabulary/exercises. Default command if none was given.");
+    println!("   profiles\t\tManage profiles, each with its own configuration and database.");
+    println!("   publish\t\tRender the vocabulary as a static HTML site.");
+    println!("   serve\t\tAnswer queries from editor plugins and other programs.");
+    println!("   stats\t\tShow statistics about the words in the database.");
+    println!("   sync\t\t\tKeep the databases from two machines in sync through change files.");
     println!("   words\t\tManage the words for this application.");
 }
 
-fn main() {
-    let mut args = std::env::args();
-    let nargs = args.len();
+// Make sure that the database exists before running a command which needs it,
+// unless the user is only asking for help.
+fn ensure_database(args: &[String]) {
+    if !args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        init::ensure_database();
+    }
+}
 
+fn main() {
     // Skip command name.
-    args.next();
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    // Global flags go before the command.
+    while let Some(flag) = args.first() {
+        match flag.as_str() {
+            "--no-input" => {
+                interactive::set_no_input();
+                args.remove(0);
+            }
+            "--data-dir" => {
+                let Some(dir) = args.get(1) else {
+                    println!("error: you have to provide a value for the '--data-dir' flag");
+                    std::process::exit(1);
+                };
+                if let Err(e) = mihi::set_data_path(PathBuf::from(dir)) {
+                    println!("error: {e}");
+                    std::process::exit(1);
+                }
+                args.drain(..2);
+            }
+            "--profile" => {
+                let Some(name) = args.get(1) else {
+                    println!("error: you have to provide a value for the '--profile' flag");
+                    std::process::exit(1);
+                };
+                if let Err(e) = mihi::set_profile(name) {
+                    println!("error: {e}");
+                    std::process::exit(1);
+                }
+                args.drain(..2);
+            }
+            _ => break,
+        }
+    }
+    let nargs = args.len() + 1;
+    let mut args = args.into_iter();
 
     match args.next() {
         Some(command_flag) => match command_flag.as_str() {
@@ -53,24 +124,82 @@ fn main() {
                 let rest: Vec<String> = args.collect();
                 init::run(rest);
             }
+            "debug" => {
+                let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
+                debug::run(rest);
+            }
+            "due" => {
+                let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
+                due::run(rest);
+            }
+            "exam" => {
+                let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
+                exam::run(rest);
+            }
             "exercises" => {
                 let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
                 exercises::run(rest);
             }
+            "export" => {
+                let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
+                export::run(rest);
+            }
+            "import" => {
+                let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
+                import::run(rest);
+            }
+            "lessons" => {
+                let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
+                lessons::run(rest);
+            }
             "nuke" => {
                 let rest: Vec<String> = args.collect();
                 nuke::run(rest);
             }
+            "profiles" => {
+                let rest: Vec<String> = args.collect();
+                profiles::run(rest);
+            }
+            "publish" => {
+                let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
+                publish::run(rest);
+            }
+            "serve" => {
+                let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
+                serve::run(rest);
+            }
+            "stats" => {
+                let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
+                stats::run(rest);
+            }
+            "sync" => {
+                let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
+                sync::run(rest);
+            }
             "tags" => {
                 let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
                 tags::run(rest);
             }
             "words" => {
                 let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
                 words::run(rest);
             }
             "practice" => {
                 let rest: Vec<String> = args.collect();
+                ensure_database(&rest);
                 run::run(rest);
             }
             _ => {
@@ -78,6 +207,9 @@ fn main() {
                 std::process::exit(1);
             }
         },
-        None => run::run(Vec::new()),
+        None => {
+            ensure_database(&[]);
+            run::run(Vec::new());
+        }
     }
 }
@@ -0,0 +1,176 @@
+// ANSI sequences used when rendering Markdown. Each style is closed with its
+// own sequence instead of a full reset, so styles can be nested.
+const BOLD: &str = "\x1b[1m";
+const NO_BOLD: &str = "\x1b[22m";
+const ITALIC: &str = "\x1b[3m";
+const NO_ITALIC: &str = "\x1b[23m";
+const UNDERLINE: &str = "\x1b[4m";
+const NO_UNDERLINE: &str = "\x1b[24m";
+const DIM: &str = "\x1b[2m";
+const NO_DIM: &str = "\x1b[22m";
+const CODE: &str = "\x1b[96m";
+const NO_CODE: &str = "\x1b[39m";
+
+/// Returns the given Markdown `text` rendered with ANSI sequences for the
+/// terminal. Only a small subset is supported: headings, emphasis, inline
+/// code, code blocks, block quotes and lists. Anything else is left as is, so
+/// for example blanks from a pensum (e.g. '___' or '{marī|mare}') are not
+/// mistaken for emphasis.
+pub fn to_ansi(text: &str) -> String {
+    let mut res = vec![];
+    let mut in_code = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            res.push(format!("{DIM}    {line}{NO_DIM}"));
+            continue;
+        }
+
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+            let heading = inline(trimmed[level..].trim());
+            if level == 1 {
+                res.push(format!("{BOLD}{UNDERLINE}{heading}{NO_UNDERLINE}{NO_BOLD}"));
+            } else {
+                res.push(format!("{BOLD}{heading}{NO_BOLD}"));
+            }
+        } else if let Some(item) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|bullet| trimmed.strip_prefix(bullet))
+        {
+            res.push(format!("{indent}  • {}", inline(item)));
+        } else if let Some(quote) = trimmed.strip_prefix('>') {
+            res.push(format!("{DIM}│{NO_DIM} {}", inline(quote.trim_start())));
+        } else {
+            res.push(inline(line));
+        }
+    }
+
+    res.join("\n")
+}
+
+// Renders the inline elements (i.e. emphasis and code) of the given `line`.
+fn inline(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut res = String::new();
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        let c = chars[idx];
+
+        if c == '`' {
+            if let Some(end) = (idx + 1..chars.len()).find(|&j| chars[j] == '`') {
+                res.push_str(CODE);
+                res.extend(&chars[idx + 1..end]);
+                res.push_str(NO_CODE);
+                idx = end + 1;
+                continue;
+            }
+        } else if c == '*' || c == '_' {
+            let run = chars[idx..].iter().take_while(|&&d| d == c).count();
+            if run <= 2 && opens(&chars, idx, run) {
+                if let Some(end) = closing(&chars, idx + run, run) {
+                    let inner: String = chars[idx + run..end].iter().collect();
+                    let (open, close) = if run == 2 {
+                        (BOLD, NO_BOLD)
+                    } else {
+                        (ITALIC, NO_ITALIC)
+                    };
+                    res.push_str(open);
+                    res.push_str(&inline(&inner));
+                    res.push_str(close);
+                    idx = end + run;
+                    continue;
+                }
+            }
+
+            // Not emphasis, so the whole run is taken literally.
+            res.extend(&chars[idx..idx + run]);
+            idx += run;
+            continue;
+        }
+
+        res.push(c);
+        idx += 1;
+    }
+
+    res
+}
+
+// Returns whether the run of `run` delimiters at `idx` can open emphasis. It
+// has to be followed by some text, and underscores cannot be in the middle of
+// a word (e.g. 'snake_case').
+fn opens(chars: &[char], idx: usize, run: usize) -> bool {
+    let Some(next) = chars.get(idx + run) else {
+        return false;
+    };
+    if next.is_whitespace() {
+        return false;
+    }
+    chars[idx] != '_' || idx == 0 || !chars[idx - 1].is_alphanumeric()
+}
+
+// Returns the position of the run of `run` delimiters closing the emphasis
+// which starts at `start`, if any.
+fn closing(chars: &[char], start: usize, run: usize) -> Option<usize> {
+    let delimiter = chars[start - 1];
+
+    (start + 1..chars.len()).find(|&j| {
+        let end = j + run;
+        end <= chars.len()
+            && chars[j..end].iter().all(|&c| c == delimiter)
+            && chars[j - 1] != delimiter
+            && chars.get(end) != Some(&delimiter)
+            && !chars[j - 1].is_whitespace()
+            && (delimiter != '_' || !chars.get(end).is_some_and(|c| c.is_alphanumeric()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ansi() {
+        assert_eq!(
+            to_ansi("# Rosa"),
+            format!("{BOLD}{UNDERLINE}Rosa{NO_UNDERLINE}{NO_BOLD}")
+        );
+        assert_eq!(to_ansi("## Rosa"), format!("{BOLD}Rosa{NO_BOLD}"));
+        assert_eq!(to_ansi("#Rosa"), "#Rosa");
+        assert_eq!(
+            to_ansi("The **rose** is *red*"),
+            format!("The {BOLD}rose{NO_BOLD} is {ITALIC}red{NO_ITALIC}")
+        );
+        assert_eq!(
+            to_ansi("__bold _and italic_ text__"),
+            format!("{BOLD}bold {ITALIC}and italic{NO_ITALIC} text{NO_BOLD}")
+        );
+        assert_eq!(to_ansi("Use `rosa`"), format!("Use {CODE}rosa{NO_CODE}"));
+        assert_eq!(
+            to_ansi("- one\n  * two\n3. three"),
+            "  • one\n    • two\n3. three"
+        );
+        assert_eq!(
+            to_ansi("> Festina lente"),
+            format!("{DIM}│{NO_DIM} Festina lente")
+        );
+        assert_eq!(
+            to_ansi("```\n**raw**\n```"),
+            format!("{DIM}    **raw**{NO_DIM}")
+        );
+
+        // Things which only look like emphasis are left alone.
+        assert_eq!(to_ansi("Nauta et ___ videt."), "Nauta et ___ videt.");
+        assert_eq!(to_ansi("a * b * c"), "a * b * c");
+        assert_eq!(to_ansi("snake_case_name"), "snake_case_name");
+        assert_eq!(to_ansi("**unclosed"), "**unclosed");
+    }
+}
@@ -1,18 +1,27 @@
+use crate::interactive::confirm;
+use std::path::PathBuf;
+
 fn help() {
     println!("mihi nuke: Nuke the current installation.\n");
     println!("usage: mihi nuke [OPTIONS]\n");
 
     println!("Options:");
     println!("   -h, --help\t\tPrint this message.");
+    println!("   -y, --yes\t\tDo not ask for confirmation. Required when there is no terminal.");
 }
 
 pub fn run(args: Vec<String>) {
-    if let Some(arg) = args.into_iter().next() {
+    let mut yes = false;
+
+    for arg in args {
         match arg.as_str() {
             "-h" | "--help" => {
                 help();
                 std::process::exit(0);
             }
+            "-y" | "--yes" => {
+                yes = true;
+            }
             _ => {
                 println!("error: nuke: unknown flag: '{}'", arg.as_str());
                 std::process::exit(1);
@@ -20,6 +29,26 @@ pub fn run(args: Vec<String>) {
         }
     }
 
+    match confirm(
+        "Do you really want to remove all the files from this application, including its database?",
+        yes,
+    ) {
+        Ok(true) => {}
+        Ok(false) => {
+            println!("Doing nothing...");
+            return;
+        }
+        Err(e) => {
+            println!("error: nuke: {e}");
+            std::process::exit(1);
+        }
+    }
+
+    if let Err(e) = remove_database() {
+        println!("error: nuke: {e}");
+        std::process::exit(1);
+    }
+
     match mihi::cfg::get_config_path() {
         Ok(path) => match std::fs::remove_dir_all(path) {
             Ok(_) => {}
@@ -34,3 +63,21 @@ pub fn run(args: Vec<String>) {
         }
     }
 }
+
+// Removes the database alongside the journal files that SQLite might have left
+// next to it. The data directory is also removed unless it has other files,
+// since it might have been given by the user.
+fn remove_database() -> Result<(), String> {
+    let path = mihi::get_database_path()?;
+    for suffix in ["", "-journal", "-wal", "-shm"] {
+        let file = PathBuf::from(format!("{}{suffix}", path.display()));
+        if file.exists() {
+            if let Err(e) = std::fs::remove_file(&file) {
+                return Err(format!("could not remove '{}': {e}", file.display()));
+            }
+        }
+    }
+
+    let _ = std::fs::remove_dir(mihi::get_data_path()?);
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use crate::interactive::{confirm, take_flag};
+use mihi::{current_profile, delete_profile, get_profile_path, profile_exists, select_profiles};
+use std::vec::IntoIter;
+
+// Show the help message.
+fn help(msg: Option<&str>) {
+    if let Some(msg) = msg {
+        println!("{}.\n", msg);
+    }
+
+    println!("mihi profiles: Manage profiles, each with its own configuration and database.\n");
+    println!("usage: mihi profiles [OPTIONS] <subcommand>\n");
+
+    println!("Options:");
+    println!("   -h, --help\t\tPrint this message.");
+    println!("   -y, --yes\t\tOnly for 'rm': do not ask for confirmation. Required when there is no terminal.");
+
+    println!("\nSubcommands:");
+    println!("   create\t\tCreate a new profile. Use it by passing '--profile <NAME>' before any command (e.g. 'mihi --profile <NAME> init').");
+    println!("   ls\t\t\tList the profiles.");
+    println!("   rm\t\t\tRemove a profile alongside its configuration and its database.");
+}
+
+// Returns the only argument left on `args`, which is the name of a profile.
+fn profile_name(mut args: IntoIter<String>) -> Result<String, i32> {
+    if args.len() != 1 {
+        help(Some(
+            "error: profiles: you have to pass exactly one argument, which is the name of the profile",
+        ));
+        return Err(1);
+    }
+    Ok(args.next().unwrap_or_default())
+}
+
+fn create(args: IntoIter<String>) -> i32 {
+    let name = match profile_name(args) {
+        Ok(name) => name,
+        Err(code) => return code,
+    };
+
+    match profile_exists(&name) {
+        Ok(false) => {}
+        Ok(true) => {
+            println!("error: profiles: the profile '{name}' already exists.");
+            return 1;
+        }
+        Err(e) => {
+            println!("error: profiles: {e}.");
+            return 1;
+        }
+    }
+    match get_profile_path(&name) {
+        Ok(path) => {
+            println!(
+                "Profile '{name}' has been created in '{}'. Call 'mihi --profile {name} init' to initialize it.",
+                path.display()
+            );
+            0
+        }
+        Err(e) => {
+            println!("error: profiles: {e}.");
+            1
+        }
+    }
+}
+
+fn ls(args: IntoIter<String>) -> i32 {
+    if args.len() > 0 {
+        help(Some("error: profiles: 'ls' does not accept arguments"));
+        return 1;
+    }
+
+    let profiles = match select_profiles() {
+        Ok(profiles) => profiles,
+        Err(e) => {
+            println!("error: profiles: {e}.");
+            return 1;
+        }
+    };
+    for profile in profiles {
+        if current_profile() == Some(profile.as_str()) {
+            println!("{profile} (current)");
+        } else {
+            println!("{profile}");
+        }
+    }
+    0
+}
+
+fn rm(args: IntoIter<String>) -> i32 {
+    let (yes, args) = take_flag(args, &["-y", "--yes"]);
+    let name = match profile_name(args) {
+        Ok(name) => name,
+        Err(code) => return code,
+    };
+
+    match profile_exists(&name) {
+        Ok(true) => {}
+        Ok(false) => {
+            println!("error: profiles: the profile '{name}' does not exist.");
+            return 1;
+        }
+        Err(e) => {
+            println!("error: profiles: {e}.");
+            return 1;
+        }
+    }
+
+    match confirm(
+        format!("Do you really want to remove '{name}' alongside its database?").as_str(),
+        yes,
+    ) {
+        Ok(true) => match delete_profile(&name) {
+            Ok(_) => println!("Removed the profile '{name}'!"),
+            Err(e) => {
+                println!("error: profiles: {e}.");
+                return 1;
+            }
+        },
+        Ok(false) => println!("Doing nothing..."),
+        Err(e) => {
+            println!("error: profiles: {e}.");
+            return 1;
+        }
+    }
+    0
+}
+
+pub fn run(args: Vec<String>) {
+    if args.is_empty() {
+        help(Some(
+            "error: profiles: you have to provide at least a subcommand",
+        ));
+        std::process::exit(1);
+    }
+
+    let mut it = args.into_iter();
+
+    match it.next() {
+        Some(first) => match first.as_str() {
+            "-h" | "--help" => {
+                help(None);
+                std::process::exit(0);
+            }
+            "create" => {
+                std::process::exit(create(it));
+            }
+            "ls" => {
+                std::process::exit(ls(it));
+            }
+            "rm" => {
+                std::process::exit(rm(it));
+            }
+            _ => {
+                help(Some(
+                    format!("error: profiles: unknown flag or command '{first}'").as_str(),
+                ));
+                std::process::exit(1);
+            }
+        },
+        None => {
+            help(Some(
+                "error: profiles: you have to provide at least a subcommand",
+            ));
+            std::process::exit(1);
+        }
+    }
+}
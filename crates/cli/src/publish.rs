@@ -0,0 +1,610 @@
+use crate::locale::{current_locale, translation_for};
+use crate::words::title_for_word;
+use mihi::cfg::configuration;
+use mihi::inflection::{
+    get_adjective_table, get_inflected_from, get_noun_table, get_pronoun_table, get_verb_table,
+    DeclensionInfo, MoodTable,
+};
+use mihi::tag::{select_tags_for, Tag};
+use mihi::word::{find_by, select_enunciated, strip_macrons, Category, Word};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+// Styles shared by all the generated pages. They are kept minimal and inline
+// so the site can be browsed from a phone without any extra files.
+static STYLE: &str =
+    "body { font-family: sans-serif; max-width: 48em; margin: 0 auto; padding: 1em; } \
+     table { border-collapse: collapse; margin-bottom: 1em; } \
+     th, td { border: 1px solid #ccc; padding: 0.2em 0.5em; text-align: left; } \
+     ul.inline li { display: inline; margin-right: 0.5em; }";
+
+// All the categories in the order in which they are shown on the index.
+static CATEGORIES: [Category; 10] = [
+    Category::Noun,
+    Category::Adjective,
+    Category::Verb,
+    Category::Pronoun,
+    Category::Adverb,
+    Category::Preposition,
+    Category::Conjunction,
+    Category::Interjection,
+    Category::Determiner,
+    Category::Unknown,
+];
+
+// Show the help message.
+fn help(msg: Option<&str>) {
+    if let Some(msg) = msg {
+        println!("{}.\n", msg);
+    }
+
+    println!("mihi publish: Render the vocabulary as a static HTML site.\n");
+    println!("usage: mihi publish [OPTIONS] <dir>\n");
+
+    println!("Options:");
+    println!("   -h, --help\t\tPrint this message.");
+}
+
+// Returns the given string with all the HTML special characters escaped.
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Wraps the given `body` into a full HTML document. The `root` is the relative
+// path to the top directory of the site from the page being rendered.
+fn page(title: &str, root: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n\
+         <html>\n\
+         <head>\n\
+         <meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{}</title>\n\
+         <style>{STYLE}</style>\n\
+         </head>\n\
+         <body>\n\
+         <nav><a href=\"{root}index.html\">Index</a></nav>\n\
+         <h1>{}</h1>\n\
+         {body}\
+         </body>\n\
+         </html>\n",
+        escape(title),
+        escape(title)
+    )
+}
+
+// Returns the letter under which the given word is to be indexed.
+fn letter_for(word: &Word) -> String {
+    match strip_macrons(&word.enunciated)
+        .chars()
+        .find(|c| c.is_alphabetic())
+    {
+        Some(c) => c.to_uppercase().to_string(),
+        None => "#".to_string(),
+    }
+}
+
+// Returns the path relative to the root of the site for the given word.
+fn word_path(word: &Word) -> String {
+    format!("words/{}.html", word.id)
+}
+
+// Returns an HTML list linking to all the given words. The `root` is the
+// relative path to the top directory of the site.
+fn word_list(words: &[&Word], root: &str) -> String {
+    let mut res = String::from("<ul>\n");
+    for word in words {
+        res.push_str(
+            format!(
+                "<li><a href=\"{root}{}\">{}</a></li>\n",
+                word_path(word),
+                escape(&word.enunciated)
+            )
+            .as_str(),
+        );
+    }
+    res.push_str("</ul>\n");
+    res
+}
+
+// Returns the contents of a table cell for the given inflected forms.
+fn cell(info: &DeclensionInfo) -> String {
+    if info.inflected.is_empty() {
+        "-".to_string()
+    } else {
+        escape(&info.inflected.join("/"))
+    }
+}
+
+fn noun_table(word: &Word) -> Result<String, String> {
+    let table = get_noun_table(word)?;
+    let mut res = String::from("<table>\n<tr><th></th><th>Singular</th><th>Plural</th></tr>\n");
+
+    for case in configuration().case_order.cases_for(word) {
+        let row = table.row(case);
+        res.push_str(
+            format!(
+                "<tr><th>{}</th><td>{}</td><td>{}</td></tr>\n",
+                case.title(),
+                cell(&row[0]),
+                cell(&row[1])
+            )
+            .as_str(),
+        );
+    }
+    res.push_str("</table>\n");
+
+    Ok(res)
+}
+
+fn adjective_table(word: &Word) -> Result<String, String> {
+    let tables = get_adjective_table(word)?;
+    let mut res = String::from(
+        "<table>\n<tr><th></th><th>Masculine</th><th>Feminine</th><th>Neuter</th></tr>\n",
+    );
+
+    for case in configuration().case_order.cases_for(word) {
+        res.push_str(
+            format!(
+                "<tr><th>{}</th><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                case.title(),
+                escape(&get_inflected_from(word, tables[0].row(case))),
+                escape(&get_inflected_from(word, tables[1].row(case))),
+                escape(&get_inflected_from(word, tables[2].row(case)))
+            )
+            .as_str(),
+        );
+    }
+    res.push_str("</table>\n");
+
+    Ok(res)
+}
+
+fn pronoun_table(word: &Word) -> Result<String, String> {
+    let tables = get_pronoun_table(word)?;
+    let mut res = if tables.len() == 1 {
+        String::from("<table>\n<tr><th></th><th>Forms</th></tr>\n")
+    } else {
+        String::from(
+            "<table>\n<tr><th></th><th>Masculine</th><th>Feminine</th><th>Neuter</th></tr>\n",
+        )
+    };
+
+    for case in configuration().case_order.cases_for(word) {
+        let rows: Vec<&[DeclensionInfo; 2]> = tables.iter().map(|table| table.row(case)).collect();
+        if rows
+            .iter()
+            .all(|row| row.iter().all(|info| info.inflected.is_empty()))
+        {
+            continue;
+        }
+
+        res.push_str(format!("<tr><th>{}</th>", case.title()).as_str());
+        for row in rows {
+            res.push_str(format!("<td>{}</td>", escape(&get_inflected_from(word, row))).as_str());
+        }
+        res.push_str("</tr>\n");
+    }
+    res.push_str("</table>\n");
+
+    Ok(res)
+}
+
+fn mood_table(title: &str, mood: &MoodTable) -> String {
+    if mood.is_empty() {
+        return String::new();
+    }
+
+    let mut res = format!(
+        "<h3>{title}</h3>\n<table>\n<tr><th></th>\
+         <th>1st sg.</th><th>2nd sg.</th><th>3rd sg.</th>\
+         <th>1st pl.</th><th>2nd pl.</th><th>3rd pl.</th></tr>\n"
+    );
+    for (name, tense) in mood.tenses() {
+        if tense.is_empty() {
+            continue;
+        }
+
+        res.push_str(format!("<tr><th>{name}</th>").as_str());
+        for info in tense.forms.iter().flat_map(|number| number.iter()) {
+            res.push_str(format!("<td>{}</td>", cell(info)).as_str());
+        }
+        res.push_str("</tr>\n");
+    }
+    res.push_str("</table>\n");
+
+    res
+}
+
+fn verb_table(word: &Word) -> Result<String, String> {
+    let table = get_verb_table(word)?;
+
+    let deponent = word.is_flag_set("deponent") || word.is_flag_set("semideponent");
+    let mut res = String::new();
+    for (name, voices) in [
+        ("Indicative", &table.indicative),
+        ("Subjunctive", &table.subjunctive),
+        ("Imperative", &table.imperative),
+    ] {
+        res.push_str(&mood_table(format!("{name} active").as_str(), &voices[0]));
+        if deponent {
+            res.push_str(&mood_table(
+                format!("{name} (passive forms, active meaning)").as_str(),
+                &voices[1],
+            ));
+        } else {
+            res.push_str(&mood_table(format!("{name} passive").as_str(), &voices[1]));
+        }
+    }
+    for (name, moods) in [
+        ("Active periphrastic", &table.periphrastic.active),
+        ("Passive periphrastic", &table.periphrastic.passive),
+    ] {
+        res.push_str(&mood_table(
+            format!("{name} indicative").as_str(),
+            &moods[0],
+        ));
+        res.push_str(&mood_table(
+            format!("{name} subjunctive").as_str(),
+            &moods[1],
+        ));
+    }
+
+    let infinitive = &table.infinitive;
+    let participle = &table.participle;
+    res.push_str(&nonfinite_table(
+        "Infinitives",
+        &[
+            ("Present", &infinitive.present[0], &infinitive.present[1]),
+            ("Perfect", &infinitive.perfect[0], &infinitive.perfect[1]),
+            ("Future", &infinitive.future[0], &infinitive.future[1]),
+        ],
+    ));
+    res.push_str(&nonfinite_table(
+        "Participles",
+        &[
+            (
+                "Present",
+                &participle.present_active,
+                &DeclensionInfo::default(),
+            ),
+            (
+                "Perfect",
+                &DeclensionInfo::default(),
+                &participle.perfect_passive,
+            ),
+            ("Future", &participle.future_active, &participle.gerundive),
+        ],
+    ));
+
+    let gerund = &table.gerund;
+    let supine = &table.supine;
+    res.push_str(&cases_table(
+        "Gerund",
+        &[
+            ("Genitive", &gerund.genitive),
+            ("Dative", &gerund.dative),
+            ("Accusative", &gerund.accusative),
+            ("Ablative", &gerund.ablative),
+        ],
+    ));
+    res.push_str(&cases_table(
+        "Supine",
+        &[
+            ("Accusative", &supine.accusative),
+            ("Ablative", &supine.ablative),
+        ],
+    ));
+
+    Ok(res)
+}
+
+// Returns a table with a single row for forms which are only given on some
+// cases (e.g. the gerund).
+fn cases_table(title: &str, cases: &[(&str, &DeclensionInfo)]) -> String {
+    if cases.iter().all(|(_, info)| info.inflected.is_empty()) {
+        return String::new();
+    }
+
+    let mut res = format!("<h3>{title}</h3>\n<table>\n<tr>");
+    for (name, _) in cases {
+        res.push_str(format!("<th>{name}</th>").as_str());
+    }
+    res.push_str("</tr>\n<tr>");
+    for (_, info) in cases {
+        res.push_str(format!("<td>{}</td>", cell(info)).as_str());
+    }
+    res.push_str("</tr>\n</table>\n");
+
+    res
+}
+
+// Returns a table for infinitives or participles, where each row has the name
+// of the tense and then the active and passive forms.
+fn nonfinite_table(title: &str, rows: &[(&str, &DeclensionInfo, &DeclensionInfo)]) -> String {
+    if rows
+        .iter()
+        .all(|(_, active, passive)| active.inflected.is_empty() && passive.inflected.is_empty())
+    {
+        return String::new();
+    }
+
+    let mut res =
+        format!("<h3>{title}</h3>\n<table>\n<tr><th></th><th>Active</th><th>Passive</th></tr>\n");
+    for (name, active, passive) in rows {
+        res.push_str(
+            format!(
+                "<tr><th>{name}</th><td>{}</td><td>{}</td></tr>\n",
+                cell(active),
+                cell(passive)
+            )
+            .as_str(),
+        );
+    }
+    res.push_str("</table>\n");
+
+    res
+}
+
+// Returns the HTML for the inflection tables of the given `word`, which might
+// be empty if the word cannot be inflected.
+fn inflection_for(word: &Word) -> Result<String, String> {
+    if word.is_flag_set("indeclinable") {
+        return Ok(String::new());
+    }
+
+    let tables = match word.category {
+        Category::Noun => noun_table(word)?,
+        Category::Adjective => adjective_table(word)?,
+        Category::Verb => verb_table(word)?,
+        Category::Pronoun => pronoun_table(word)?,
+        _ => String::new(),
+    };
+
+    if tables.is_empty() {
+        Ok(tables)
+    } else {
+        Ok(format!("<h2>Inflection</h2>\n{tables}"))
+    }
+}
+
+fn word_page(word: &Word, tags: &[Tag]) -> Result<String, String> {
+    let mut body = format!("<p>{}</p>\n", escape(&title_for_word(word)));
+
+    if let Some((locale, translation)) = translation_for(word, &current_locale()) {
+        let s = translation.as_str().unwrap_or("");
+        if !s.is_empty() {
+            body.push_str(
+                format!(
+                    "<p>Translation ({}): {}.</p>\n",
+                    locale.to_code(),
+                    escape(s)
+                )
+                .as_str(),
+            );
+        }
+    }
+
+    if !tags.is_empty() {
+        body.push_str("<p>Tags: ");
+        body.push_str(
+            tags.iter()
+                .map(|t| format!("<a href=\"../tags/{}.html\">{}</a>", t.id, escape(&t.name)))
+                .collect::<Vec<_>>()
+                .join(", ")
+                .as_str(),
+        );
+        body.push_str("</p>\n");
+    }
+
+    body.push_str(&inflection_for(word)?);
+
+    Ok(page(&word.enunciated, "../", &body))
+}
+
+fn write_file(dir: &Path, name: &str, contents: &str) -> Result<(), String> {
+    let path = dir.join(name);
+    match fs::write(&path, contents) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not write '{}': {e}", path.display())),
+    }
+}
+
+fn create_dir(dir: &Path) -> Result<(), String> {
+    match fs::create_dir_all(dir) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not create '{}': {e}", dir.display())),
+    }
+}
+
+// Renders the whole site into the given directory, and returns the number of
+// words that have been published.
+fn publish(dir: &Path) -> Result<usize, String> {
+    for sub in ["", "words", "letters", "categories", "tags"] {
+        create_dir(&dir.join(sub))?;
+    }
+
+    let mut words = vec![];
+    for enunciated in select_enunciated(None, &[])? {
+        words.push(find_by(&enunciated)?);
+    }
+
+    // Word pages, while grouping words by letter and by tag.
+    let mut letters: BTreeMap<String, Vec<&Word>> = BTreeMap::new();
+    let mut tags: BTreeMap<String, (i32, Vec<&Word>)> = BTreeMap::new();
+    for word in &words {
+        let word_tags = select_tags_for(Some(word.id))?;
+        write_file(dir, &word_path(word), &word_page(word, &word_tags)?)?;
+
+        letters.entry(letter_for(word)).or_default().push(word);
+        for tag in word_tags {
+            tags.entry(tag.name)
+                .or_insert((tag.id, vec![]))
+                .1
+                .push(word);
+        }
+    }
+
+    let mut index = String::from("<h2>By letter</h2>\n<ul class=\"inline\">\n");
+    for (letter, list) in &letters {
+        let name = if letter == "#" {
+            "letters/other.html".to_string()
+        } else {
+            format!("letters/{}.html", letter.to_lowercase())
+        };
+        write_file(dir, &name, &page(letter, "../", &word_list(list, "../")))?;
+        index.push_str(format!("<li><a href=\"{name}\">{}</a></li>\n", escape(letter)).as_str());
+    }
+    index.push_str("</ul>\n");
+
+    index.push_str("<h2>By category</h2>\n<ul>\n");
+    for category in CATEGORIES {
+        let list = words
+            .iter()
+            .filter(|w| w.category as usize == category as usize)
+            .collect::<Vec<_>>();
+        if list.is_empty() {
+            continue;
+        }
+
+        let name = format!("categories/{category}.html");
+        let title = format!("Category: {category}");
+        write_file(dir, &name, &page(&title, "../", &word_list(&list, "../")))?;
+        index.push_str(
+            format!(
+                "<li><a href=\"{name}\">{category}</a> ({})</li>\n",
+                list.len()
+            )
+            .as_str(),
+        );
+    }
+    index.push_str("</ul>\n");
+
+    if !tags.is_empty() {
+        index.push_str("<h2>By tag</h2>\n<ul>\n");
+        for (tag, (id, list)) in &tags {
+            let name = format!("tags/{id}.html");
+            let title = format!("Tag: {tag}");
+            write_file(dir, &name, &page(&title, "../", &word_list(list, "../")))?;
+            index.push_str(
+                format!(
+                    "<li><a href=\"{name}\">{}</a> ({})</li>\n",
+                    escape(tag),
+                    list.len()
+                )
+                .as_str(),
+            );
+        }
+        index.push_str("</ul>\n");
+    }
+
+    write_file(dir, "index.html", &page("Vocabulary", "", &index))?;
+
+    Ok(words.len())
+}
+
+pub fn run(args: Vec<String>) {
+    let mut dir = None;
+
+    for arg in args {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                help(None);
+                std::process::exit(0);
+            }
+            _ => {
+                if arg.starts_with('-') {
+                    help(Some(
+                        format!("error: publish: unknown flag '{arg}'").as_str(),
+                    ));
+                    std::process::exit(1);
+                }
+                if dir.is_some() {
+                    help(Some("error: publish: only one directory can be given"));
+                    std::process::exit(1);
+                }
+                dir = Some(arg);
+            }
+        }
+    }
+
+    let Some(dir) = dir else {
+        help(Some(
+            "error: publish: you have to provide the directory where the site will be rendered",
+        ));
+        std::process::exit(1);
+    };
+
+    match publish(Path::new(&dir)) {
+        Ok(count) => println!("Published {count} words into '{dir}'."),
+        Err(e) => {
+            println!("error: publish: {e}.");
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_html() {
+        assert_eq!(escape("rosa"), "rosa");
+        assert_eq!(
+            escape("<a href=\"x\">Tom & Jerry</a>"),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn list_of_words() {
+        let rosa = find_by("rosa, rosae").unwrap();
+        let mut other = rosa.clone();
+        other.id = 0;
+        other.enunciated = "<b>".to_string();
+
+        assert_eq!(word_list(&[], ""), "<ul>\n</ul>\n");
+        assert_eq!(
+            word_list(&[&rosa, &other], "../"),
+            format!(
+                "<ul>\n<li><a href=\"../words/{}.html\">rosa, rosae</a></li>\n\
+                 <li><a href=\"../words/0.html\">&lt;b&gt;</a></li>\n</ul>\n",
+                rosa.id
+            )
+        );
+    }
+
+    #[test]
+    fn page_for_word() {
+        let rosa = find_by("rosa, rosae").unwrap();
+        let tag = Tag {
+            id: 3,
+            name: "llpsi & co".to_string(),
+            description: None,
+            color: None,
+        };
+
+        let html = word_page(&rosa, &[tag]).unwrap();
+        assert!(html.contains("<title>rosa, rosae</title>"), "{html}");
+        assert!(html.contains("<nav><a href=\"../index.html\">"), "{html}");
+        assert!(html.contains("<p>Translation ("), "{html}");
+        assert!(
+            html.contains("<p>Tags: <a href=\"../tags/3.html\">llpsi &amp; co</a></p>"),
+            "{html}"
+        );
+        assert!(html.contains("<h2>Inflection</h2>"), "{html}");
+        assert!(html.contains("<td>rosae</td><td>rosārum</td>"), "{html}");
+
+        // Words without any translation nor tags have none of them.
+        let mut bare = rosa.clone();
+        bare.translation = serde_json::json!({});
+        let html = word_page(&bare, &[]).unwrap();
+        assert!(!html.contains("Translation"), "{html}");
+        assert!(!html.contains("Tags:"), "{html}");
+    }
+}
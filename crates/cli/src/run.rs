@@ -1,26 +1,114 @@
 extern crate rand;
-use inquire::{Confirm, Editor, Text};
-use mihi::cfg::configuration;
-use mihi::exercise::{select_relevant_exercises, touch_exercise, Exercise, ExerciseKind};
-use mihi::inflection::{get_adjective_table, get_inflected_from, get_noun_table, DeclensionTable};
-use mihi::tag::{select_tag_names, update_success};
+use inquire::{Confirm, Editor, Select, Text};
+use mihi::analysis::find_example;
+use mihi::cfg::{configuration, MacronStrictness};
+use mihi::drill::{is_right_gender, record_drill_attempt, Drill};
+use mihi::exam::{save_exam, ExamSummary, Question};
+use mihi::exercise::{
+    diff_lines, grade_exercise, record_attempt, select_exercise_texts, select_lesson_exercises,
+    select_relevant_exercises, DiffLine, Exercise, ExerciseKind, MAX_SCORE, PASSING_SCORE,
+};
+use mihi::history::{save_pending_session, save_session, take_pending_session, SessionFilters};
+use mihi::inflection::{
+    get_adjective_table, get_inflected_from, get_noun_table, get_voice_pairs, Case,
+    DeclensionTable, Number, VoicePair,
+};
+use mihi::lesson::{find_lesson, select_lesson_words, Lesson};
+use mihi::note::{create_note, select_last_session_notes, select_notes_for};
+use mihi::numeral::{from_roman, to_latin, to_roman, MAX_LATIN, MAX_ROMAN};
+use mihi::pensum::Pensum;
+use mihi::schedule::{count_due_words, record_review};
+use mihi::sentence::{NounPhrase, Sentence, Substitution, SUBSTITUTE_PRONOUNS};
+use mihi::session::{SessionBuilder, SessionItem, SessionLimits, SessionSummary};
+use mihi::tag::select_tag_names;
+use mihi::template::{Agreement, Placeholder, Template};
 use mihi::word::{
-    adverb, comparative, is_valid_word_flag, joint_related_words, select_related_words,
-    select_relevant_words, select_words_except, superlative, Category, RelationKind, Word,
-    BOOLEAN_FLAGS,
+    adverb, comparative, find_by, find_by_normalized, is_valid_word_flag, joint_related_words,
+    matches_enunciated, matches_form, matches_translation, select_known_words, select_new_word_ids,
+    select_related_words, select_relevant_words, select_words_except, strip_macrons, superlative,
+    Category, Gender, Novelty, RelationKind, Word, BOOLEAN_FLAGS,
 };
 use rand::prelude::*;
 use std::env;
 use std::fs;
 use std::io::Write;
 use std::process::Command;
+use std::time::{Duration, Instant};
 use tempfile::NamedTempFile;
 
-use crate::locale::{current_locale, Locale};
+use crate::inflection::print_full_inflection_for;
+use crate::interactive::{can_prompt, Ask};
+use crate::locale::{current_locale, translation_for, Locale};
+use crate::words::{paradigm_for_word, title_for_word};
+
+// Maximum number of exercises to be interleaved on a '--mixed' session.
+const MIXED_EXERCISES: usize = 2;
+
+// Number of questions of an exam when '--count' was not given.
+const DEFAULT_EXAM_COUNT: usize = 20;
+
+// Number of questions asked on each numerical exercise.
+const NUMERICAL_QUESTIONS: usize = 5;
+
+// Number of questions asked on each transformation exercise.
+const TRANSFORMATION_QUESTIONS: usize = 5;
+
+// Maximum number of known nouns and verbs from which transformations are
+// picked.
+const TRANSFORMATION_WORDS: isize = 20;
+
+// Number of questions asked on each parsing exercise.
+const PARSING_QUESTIONS: usize = 5;
+
+// Commands which can read a dictation out loud, in order of preference.
+const SPEECH_TOOLS: [&str; 3] = ["espeak-ng", "espeak", "say"];
+
+// Number of questions asked on each template exercise.
+const TEMPLATE_QUESTIONS: usize = 5;
+
+// Number of random picks of words to try for each question of a template
+// exercise before giving up (e.g. because the picked words lack the needed
+// forms).
+const TEMPLATE_ATTEMPTS: usize = 10;
+
+// Options for the user to grade their own solution to an exercise, from zero up
+// to 'MAX_SCORE'.
+const SCORES: [&str; MAX_SCORE + 1] = [
+    "0: no idea at all",
+    "1: wrong, but the solution rings a bell",
+    "2: wrong, but close",
+    "3: right, with some mistakes",
+    "4: right, after some hesitation",
+    "5: perfect",
+];
+
+// Prefixes for answers which are to be taken as a note about the current word
+// or the session as a whole instead.
+const WORD_NOTE_PREFIX: &str = ":note ";
+const SESSION_NOTE_PREFIX: &str = ":session ";
+
+// Genders which can be picked on the gender drill. Nouns which can be either
+// masculine or feminine accept both.
+const DRILL_GENDERS: [Gender; 3] = [Gender::Masculine, Gender::Feminine, Gender::Neuter];
+
+// Number of sentences to be translated on a '--sentences' session.
+const SENTENCES: usize = 5;
+
+// Number of noun phrases to be replaced on a '--pronouns' session.
+const SUBSTITUTIONS: usize = 5;
 
-// Maximum number of times a word has to be run in order to increase the number
-// of successful runs.
-const MAX_STEPS: usize = 5;
+// Cases on which noun phrases are given on a '--pronouns' session.
+const SUBSTITUTION_CASES: [Case; 5] = [
+    Case::Nominative,
+    Case::Accusative,
+    Case::Genitive,
+    Case::Dative,
+    Case::Ablative,
+];
+
+// Number of random combinations of words to try before giving up on composing
+// a sentence (e.g. because the picked words lack the needed forms).
+const SENTENCE_ATTEMPTS: usize = 10;
 
 fn help(msg: Option<&str>) {
     if let Some(msg) = msg {
@@ -32,51 +120,487 @@ fn help(msg: Option<&str>) {
 
     println!("Options:");
     println!("   -c, --category <CATEGORY>\tOnly ask for words on the given <CATEGORY>.");
+    println!("   --count <N>\t\t\tOnly for '--exam': number of questions (default: {DEFAULT_EXAM_COUNT}).");
+    println!("   -d, --drill <NAME>\t\tRun a drill which is tracked apart from the translation of words. Available: 'gender' (tell the gender of nouns) and 'comparison' (tell the comparative, superlative and adverb of adjectives). See 'mihi stats --drill'.");
+    println!("   --exam\t\t\tTake an exam on the translation of words: answers are graded strictly, no hints are given and the schedule of words is left untouched. The score is saved with a breakdown by category, see 'mihi stats exams'.");
     println!("   -e, --exercises\t\tOnly practice with exercises.");
-    println!("   -f, --flag\t\t\tFilter words by a boolean flag. Multiple flags can be provided.");
+    println!("   -f, --flags <NAMES>\t\tFilter words which have any of the given comma-separated boolean flags (e.g. 'deponent,impersonal'). It can be given multiple times.");
     println!("   -h, --help\t\t\tPrint this message.");
     println!("   -i, --inflection\t\tOnly practice word inflections (completing enunciates, declensions and conjugations.");
     println!("   -k, --kind <KIND>\t\tOnly ask for exercises for the given <KIND>.");
-    println!("   -t, --tag <NAME>\t\tFilter words which match the given tag NAME. Multiple tags can be provided to match words with any of the tags provided.");
+    println!("   -l, --lesson <NAME>\t\tStudy a whole lesson (see 'mihi lessons'): translate its words, inflect them and then solve its exercises.");
+    println!("   -m, --mixed\t\t\tInterleave word reviews with one or two exercises.");
+    println!("   --new-only\t\t\tOnly practice words which you have never reviewed. See the '--new-words' option from 'mihi init'.");
+    println!("   -p, --pronouns\t\tReplace noun phrases composed out of the words you already know with the right form of 'is, ea, id', 'hic, haec, hoc' or 'ille, illa, illud'.");
+    println!("   --principal-parts\t\tShow the first principal part of verbs and ask for the rest of them, one by one.");
+    println!("   -r, --reverse\t\tShow the translation of words and ask for them in Latin, either the whole enunciate or its first part (e.g. the nominative).");
+    println!("   --review-only\t\tOnly practice words which you have already reviewed.");
+    println!("   --resume\t\t\tPick up the last session which was stopped before going through all of its words, in the same mode and with the same filters.");
+    println!("   -s, --sentences\t\tTranslate simple sentences composed out of the words you already know.");
+    println!("   --strict\t\t\tGrade answers strictly for this session: macrons have to be right and typos are not tolerated. See the '--macrons' option from 'mihi init'.");
+    println!("   -t, --tags <NAMES>\t\tFilter words which match any of the given comma-separated tag NAMES (e.g. 'cap12,bodyparts') or any of their children (e.g. 'llpsi/cap09' for 'llpsi'). It can be given multiple times.");
+    println!("   --timed <SECONDS>\t\tGive <SECONDS> to answer each question. Answers given later count as wrong, and response times are saved so they can be tracked with 'mihi stats history'.");
+    println!("   -v, --voice\t\t\tTurn verb forms from the active voice into the passive one, and vice versa.");
+    println!("   -w, --word <ENUNCIATE>\tOnly practice the given word (e.g. 'leō, leōnis'), regardless of macrons. It can be given multiple times.");
+
+    println!("\nWords which you have never reviewed are introduced with their translation and their inflection before being asked for the first time.");
+    println!("\nWhile answering for a word, type ':note <text>' to attach a note to it, which will be shown the next time it's asked. Type ':session <text>' to take a note about the session, which will be shown when starting the next one.");
 }
 
-// Run the quiz for all the given `words` while expecting answers to be
-// delivered in the given `locale`.
-fn run_words(words: &Vec<Word>, locale: &Locale) -> bool {
-    for word in words {
-        // If the translation cannot be found, skip this word.
-        let Some(translation) = word.translation.get(locale.to_code()) else {
-            continue;
-        };
+/// Options given on the command line which apply to the whole practice
+/// session.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SessionOptions {
+    // Whether '--strict' was given, so answers are graded strictly regardless
+    // of the configuration.
+    strict: bool,
 
-        println!("Word: {}", word.enunciated);
+    // Whether an exam is being taken with '--exam': answers are graded
+    // strictly, no hints are given (e.g. notes or the card after each answer)
+    // and the schedule of words is left untouched.
+    exam: bool,
+}
 
-        let Ok(raw) = Text::new(format!("Translation ({locale}):").as_str()).prompt() else {
-            return false;
-        };
-        let answer = raw.trim();
+impl SessionOptions {
+    // Returns how strictly answers are to be graded: strictly if '--strict' or
+    // '--exam' were given, and as configured otherwise (see the '--macrons'
+    // option from 'mihi init').
+    fn strictness(&self) -> MacronStrictness {
+        if self.strict || self.exam {
+            MacronStrictness::Strict
+        } else {
+            configuration().macron_strictness
+        }
+    }
+}
 
-        let tr = translation.as_str().unwrap_or("");
-        let found = !answer.is_empty() && tr.split(',').any(|tr| tr.trim().contains(answer));
+// The kind of practice session picked on the command line. Only one of them
+// can be picked at a time.
+#[derive(Clone, Debug, Default)]
+enum PracticeMode {
+    // Translate words, inflect them and then solve an exercise.
+    #[default]
+    Translation,
+    // Only solve exercises ('--exercises').
+    Exercises,
+    // Only inflect words ('--inflection').
+    Inflection,
+    // Translate words interleaved with exercises ('--mixed').
+    Mixed,
+    // Translate sentences composed out of known words ('--sentences').
+    Sentences,
+    // Replace noun phrases with pronouns ('--pronouns').
+    Pronouns,
+    // Turn verbal forms from one voice into the other ('--voice').
+    Voice,
+    // Give the Latin word out of its translation ('--reverse').
+    Reverse,
+    // Give the principal parts of verbs ('--principal-parts').
+    PrincipalParts,
+    // Go through the given drill ('--drill').
+    Drill(Drill),
+    // Study the given lesson as a whole ('--lesson').
+    Lesson(Lesson),
+}
 
-        if found {
-            if word.steps as usize == MAX_STEPS - 1 {
-                let _ = update_success(word, word.succeeded + 1, 0);
-            } else {
-                let _ = update_success(word, word.succeeded, word.steps + 1);
+impl PracticeMode {
+    // Returns the command line option which picks this mode.
+    fn option(&self) -> &'static str {
+        match self {
+            Self::Translation => "",
+            Self::Exercises => "--exercises",
+            Self::Inflection => "--inflection",
+            Self::Mixed => "--mixed",
+            Self::Sentences => "--sentences",
+            Self::Pronouns => "--pronouns",
+            Self::Voice => "--voice",
+            Self::Reverse => "--reverse",
+            Self::PrincipalParts => "--principal-parts",
+            Self::Drill(_) => "--drill",
+            Self::Lesson(_) => "--lesson",
+        }
+    }
+
+    // Returns how this mode is referred to on error messages.
+    fn title(&self) -> String {
+        match self {
+            Self::Drill(drill) => format!("the '{drill}' drill"),
+            mode => format!("'{}'", mode.option()),
+        }
+    }
+
+    // Returns the only category of words which can be practiced on this mode,
+    // if there is such a restriction.
+    fn category(&self) -> Option<Category> {
+        match self {
+            Self::Voice | Self::PrincipalParts => Some(Category::Verb),
+            Self::Drill(Drill::Gender) => Some(Category::Noun),
+            Self::Drill(Drill::Comparison) => Some(Category::Adjective),
+            _ => None,
+        }
+    }
+
+    // Returns whether words from the given `category` can be practiced on this
+    // mode.
+    fn allows(&self, category: Category) -> bool {
+        match self {
+            Self::Voice | Self::PrincipalParts => matches!(category, Category::Verb),
+            Self::Drill(Drill::Gender) => matches!(category, Category::Noun),
+            Self::Drill(Drill::Comparison) => matches!(category, Category::Adjective),
+            _ => true,
+        }
+    }
+
+    // Returns the kind of session as saved into the history. Sessions on a
+    // lesson are saved as translation ones, as the words which are left for
+    // later when studying a lesson are the ones being translated.
+    fn kind(&self) -> String {
+        match self {
+            Self::Translation | Self::Lesson(_) => "translation".to_string(),
+            Self::Drill(drill) => drill.to_string(),
+            mode => mode.option().trim_start_matches('-').to_string(),
+        }
+    }
+}
+
+// Returns the mode of the given `kind` of session which was interrupted, where
+// only modes going through a queue of words can be resumed.
+impl TryFrom<&str> for PracticeMode {
+    type Error = String;
+
+    fn try_from(kind: &str) -> Result<Self, Self::Error> {
+        match kind {
+            "translation" => Ok(Self::Translation),
+            "reverse" => Ok(Self::Reverse),
+            "principal-parts" => Ok(Self::PrincipalParts),
+            "voice" => Ok(Self::Voice),
+            other => Drill::try_from(other).map(Self::Drill),
+        }
+    }
+}
+
+// Options which can only be combined with the ones listed alongside them.
+const RESTRICTED_OPTIONS: [(&str, &[&str]); 3] = [
+    ("--resume", &["--endless", "--strict", "--timed"]),
+    ("--lesson", &["--endless", "--strict", "--timed"]),
+    (
+        "--exam",
+        &[
+            "--category",
+            "--flags",
+            "--tags",
+            "--count",
+            "--strict",
+            "--timed",
+        ],
+    ),
+];
+
+// Options which cannot be combined with any of the ones listed alongside them,
+// on top of modes not being combinable with each other.
+const CONFLICTING_OPTIONS: [(&str, &[&str]); 3] = [
+    (
+        "--word",
+        &[
+            "--category",
+            "--kind",
+            "--flags",
+            "--tags",
+            "--new-only",
+            "--review-only",
+            "--pronouns",
+            "--sentences",
+            "--mixed",
+            "--exercises",
+            "--inflection",
+        ],
+    ),
+    ("--new-only", &["--pronouns", "--sentences", "--exercises"]),
+    (
+        "--review-only",
+        &["--pronouns", "--sentences", "--exercises"],
+    ),
+];
+
+// Returns the given `options` quoted and joined as in "'a', 'b' and 'c'".
+fn quote_options(options: &[&str]) -> String {
+    let quoted: Vec<String> = options.iter().map(|opt| format!("'{opt}'")).collect();
+    match quoted.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+        _ => quoted.join(""),
+    }
+}
+
+// Returns an error if the `given` options cannot be combined with each other
+// or with the picked `modes`.
+fn check_options(modes: &[PracticeMode], given: &[&'static str]) -> Result<(), String> {
+    let all: Vec<&str> = given
+        .iter()
+        .copied()
+        .chain(modes.iter().map(PracticeMode::option))
+        .collect();
+
+    for option in &all {
+        if let Some((_, allowed)) = RESTRICTED_OPTIONS.iter().find(|(opt, _)| opt == option) {
+            if all
+                .iter()
+                .any(|opt| opt != option && !allowed.contains(opt))
+            {
+                return Err(format!(
+                    "'{option}' can only be combined with {}",
+                    quote_options(allowed)
+                ));
+            }
+        }
+        if let Some((_, conflicts)) = CONFLICTING_OPTIONS.iter().find(|(opt, _)| opt == option) {
+            if let Some(other) = all.iter().find(|opt| conflicts.contains(opt)) {
+                return Err(format!("'{option}' cannot be combined with '{other}'"));
             }
-            println!("\x1b[92m✓ {tr}\x1b[0m");
+        }
+    }
+
+    let mut picked = modes.iter().map(PracticeMode::option);
+    if let Some(first) = picked.next() {
+        if let Some(other) = picked.find(|opt| *opt != first) {
+            return Err(format!("'{first}' cannot be combined with '{other}'"));
+        }
+    }
+
+    if all.contains(&"--count") && !all.contains(&"--exam") {
+        return Err("'--count' can only be used with '--exam'".to_string());
+    }
+    Ok(())
+}
+
+// Record the `given` answer about `word` into `summary`, where `correct` tells
+// whether the user got it right, and let the user know if it came after the
+// time limit of a timed session. Returns whether the answer was recorded as
+// right.
+fn record_answer(word: &Word, given: &str, correct: bool, summary: &mut SessionSummary) -> bool {
+    let answer = summary.record(word, given, correct);
+    if answer.timed_out {
+        println!(
+            "\x1b[91mTime is up! You took {:.1} seconds.\x1b[0m",
+            answer.response.as_secs_f64()
+        );
+    }
+    answer.correct
+}
+
+// Print the notes that the user took about the given `word` on previous
+// reviews, unless an exam is being taken.
+fn print_notes_for(word: &Word, options: &SessionOptions) {
+    if options.exam {
+        return;
+    }
+    match select_notes_for(word) {
+        Ok(notes) => {
+            for note in notes {
+                println!("\x1b[93mNote {note}\x1b[0m");
+            }
+        }
+        Err(e) => println!("warning: practice: {e}"),
+    }
+}
+
+// Prompt the user with the given `message` for an answer about `word`,
+// starting with the `initial` value if given. Answers starting with ':note' or
+// ':session' are saved as a note for the word or for the session respectively,
+// and then the user is prompted again. Returns None if the user aborted.
+fn prompt_answer(message: &str, initial: Option<&str>, word: &Word) -> Option<String> {
+    loop {
+        let mut text = Text::new(message).with_help_message(
+            "Type ':note <text>' to take a note about this word, or ':session <text>' about this session",
+        );
+        if let Some(initial) = initial {
+            text = text.with_initial_value(initial);
+        }
+        let answer = text.ask().ok()?;
+
+        let note = if let Some(body) = answer.trim_start().strip_prefix(WORD_NOTE_PREFIX) {
+            create_note(Some(word), body)
+        } else if let Some(body) = answer.trim_start().strip_prefix(SESSION_NOTE_PREFIX) {
+            create_note(None, body)
         } else {
-            if word.succeeded > 0 {
-                let _ = update_success(word, word.succeeded - 1, 0);
+            return Some(answer);
+        };
+        match note {
+            Ok(_) => println!("Note saved."),
+            Err(e) => println!("warning: practice: {e}"),
+        }
+    }
+}
+
+// Returns the given `count` of words as text (e.g. '1 word', '2 words').
+fn words_count(count: usize) -> String {
+    if count == 1 {
+        "1 word".to_string()
+    } else {
+        format!("{count} words")
+    }
+}
+
+// Run `ask` on each of the given `words`, which returns whether the word was
+// reviewed (words can be skipped), or None if the user aborted. Breaks are
+// offered after each review as configured on `limits`. If the user stops
+// before going through all of them, the words which were not reviewed are left
+// on `pending` so the session can be resumed. Returns false if the user
+// stopped.
+fn run_queue(
+    words: &[Word],
+    limits: &mut SessionLimits,
+    pending: &mut Vec<Word>,
+    mut ask: impl FnMut(&Word) -> Option<bool>,
+) -> bool {
+    let mut new = select_new_word_ids(words).unwrap_or_else(|e| {
+        println!("warning: practice: {e}");
+        vec![]
+    });
+
+    for (idx, word) in words.iter().enumerate() {
+        // Words are not to be tested cold the first time they come up.
+        if let Some(pos) = new.iter().position(|id| *id == word.id) {
+            new.swap_remove(pos);
+            introduce_word(word);
+        }
+
+        match ask(word) {
+            None => {
+                *pending = words[idx..].to_vec();
+                return false;
             }
-            println!("\x1b[91m❌{tr}\x1b[0m");
+            Some(true) if !keep_going(limits) => {
+                *pending = words[idx + 1..].to_vec();
+                return false;
+            }
+            Some(_) => {}
         }
     }
 
     true
 }
 
+// Show everything about the given `word`, which has never been reviewed, so
+// it's not asked for cold: its enunciate, its translation, its declension or
+// conjugation and its inflection.
+fn introduce_word(word: &Word) {
+    println!("New word: {}", title_for_word(word));
+    if let Some((used, translation)) = translation_for(word, &current_locale()) {
+        println!(
+            "Translation ({used}): {}",
+            translation.as_str().unwrap_or("")
+        );
+    }
+    if let Some(paradigm) = paradigm_for_word(word) {
+        println!("{paradigm}");
+    }
+    if let Err(e) = print_full_inflection_for(word.clone()) {
+        println!("warning: practice: {e}");
+    }
+    println!();
+}
+
+// Run the quiz for all the given `words` while expecting answers to be
+// delivered in the given `locale`. Answers are tallied into `summary`, and
+// words left for later are put into `pending` (see `run_queue`).
+fn run_words(
+    words: &[Word],
+    locale: &Locale,
+    options: &SessionOptions,
+    limits: &mut SessionLimits,
+    summary: &mut SessionSummary,
+    pending: &mut Vec<Word>,
+) -> bool {
+    run_queue(words, limits, pending, |word| {
+        run_word(word, locale, options, summary).then_some(true)
+    })
+}
+
+// Ask for the translation of the given `word` in the given `locale`, and record
+// the result both on the database and on `summary`. On exams the result is only
+// recorded on `summary`. Returns false if the user aborted.
+fn run_word(
+    word: &Word,
+    locale: &Locale,
+    options: &SessionOptions,
+    summary: &mut SessionSummary,
+) -> bool {
+    // If the translation cannot be found, not even on the fallback locales,
+    // skip this word.
+    let Some((used, translation)) = translation_for(word, locale) else {
+        return true;
+    };
+    let prompt = if used == *locale {
+        format!("Translation ({used}):")
+    } else {
+        format!("Translation ({used}, fallback):")
+    };
+
+    match word.enclitic_example() {
+        Some(example) => println!("Word: {} (as in '{example}')", word.enunciated),
+        None => println!("Word: {}", word.enunciated),
+    }
+    print_notes_for(word, options);
+
+    summary.ask();
+    let Some(raw) = prompt_answer(prompt.as_str(), None, word) else {
+        return false;
+    };
+    let answer = raw.trim();
+
+    let tr = translation.as_str().unwrap_or("");
+    let found = record_answer(
+        word,
+        answer,
+        matches_translation(answer, tr, options.strictness()),
+        summary,
+    );
+    if !options.exam {
+        let _ = record_review(word, found);
+    }
+    if found {
+        println!("\x1b[92m✓ {tr}\x1b[0m");
+    } else {
+        println!("\x1b[91m❌{tr}\x1b[0m");
+    }
+    if !options.exam {
+        print_info_card(word);
+    }
+
+    true
+}
+
+// Print a compact card about the given `word` once it has been graded, so each
+// review teaches a bit more about it: its gender and category, its declension
+// or conjugation, its alternatives and gendered forms, and an example from the
+// user's exercises if there's any.
+fn print_info_card(word: &Word) {
+    println!("   {}", title_for_word(word));
+    if let Some(paradigm) = paradigm_for_word(word) {
+        println!("   {paradigm}");
+    }
+
+    match select_related_words(word) {
+        Ok(related) => {
+            let alternatives = &related[RelationKind::Alternative as usize - 1];
+            if !alternatives.is_empty() {
+                println!("   Alternatives: {}", joint_related_words(alternatives));
+            }
+            let gendered = &related[RelationKind::Gendered as usize - 1];
+            if !gendered.is_empty() {
+                println!("   Other gender: {}", joint_related_words(gendered));
+            }
+        }
+        Err(e) => println!("warning: practice: {e}"),
+    }
+
+    match select_exercise_texts().and_then(|texts| find_example(word, &texts)) {
+        Ok(Some(example)) => println!("   Example: {example}"),
+        Ok(None) => {}
+        Err(e) => println!("warning: practice: {e}"),
+    }
+}
+
 fn fill_out_enunciated(word: &Word) -> String {
     match word.category {
         Category::Noun | Category::Adjective | Category::Pronoun => {
@@ -114,9 +638,9 @@ fn fill_out_enunciated(word: &Word) -> String {
     }
 }
 
-// Returns true if both strings are either more or less the same, or the user
-// considers it so.
-fn same_answer(given: &String, expected: &String) -> bool {
+// Returns true if both strings are either more or less the same with the given
+// `strictness` for macrons, or the user considers it so.
+fn same_answer(given: &String, expected: &String, strictness: MacronStrictness) -> bool {
     // If it's literally the same string, then return true.
     if given == expected {
         return true;
@@ -130,11 +654,34 @@ fn same_answer(given: &String, expected: &String) -> bool {
         return true;
     }
 
+    // If each line matches as configured (e.g. omitted macrons), return true
+    // too.
+    let lines = |s: &str| {
+        s.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(str::to_string)
+            .collect::<Vec<String>>()
+    };
+    let (given_lines, expected_lines) = (lines(given), lines(expected));
+    if given_lines.len() == expected_lines.len()
+        && given_lines
+            .iter()
+            .zip(expected_lines.iter())
+            .all(|(g, e)| matches_form(g, e, strictness))
+    {
+        return true;
+    }
+
     // It's something else, then let the user to decide.
     accepted_diff(given, expected)
 }
 
-fn ask_for_table(word: &Word, table: &DeclensionTable, id: Option<&str>) -> bool {
+fn ask_for_table(
+    word: &Word,
+    table: &DeclensionTable,
+    id: Option<&str>,
+    strictness: MacronStrictness,
+) -> bool {
     let added = match id {
         Some(s) => format!(" ({}) ", s),
         None => " ".to_string(),
@@ -142,100 +689,53 @@ fn ask_for_table(word: &Word, table: &DeclensionTable, id: Option<&str>) -> bool
     let mut initial = format!("== {}{}==\n\n", word.enunciated, added);
     let mut expected = format!("== {}{}==\n\n", word.enunciated, added);
 
-    for idx in configuration().case_order.to_usizes() {
-        match idx {
-            0 => {
-                initial.push_str("Nominative: \n");
-                expected.push_str(
-                    format!(
-                        "Nominative: {}\n",
-                        get_inflected_from(word, &table.nominative)
-                    )
-                    .as_str(),
-                );
-            }
-            1 => {
-                initial.push_str("Vocative: \n");
-                expected.push_str(
-                    format!("Vocative: {}\n", get_inflected_from(word, &table.vocative)).as_str(),
-                );
-            }
-            2 => {
-                initial.push_str("Accusative: \n");
-                expected.push_str(
-                    format!(
-                        "Accusative: {}\n",
-                        get_inflected_from(word, &table.accusative)
-                    )
-                    .as_str(),
-                );
-            }
-            3 => {
-                initial.push_str("Genitive: \n");
-                expected.push_str(
-                    format!("Genitive: {}\n", get_inflected_from(word, &table.genitive)).as_str(),
-                );
-            }
-            4 => {
-                initial.push_str("Dative: \n");
-                expected.push_str(
-                    format!("Dative: {}\n", get_inflected_from(word, &table.dative)).as_str(),
-                );
-            }
-            5 => {
-                initial.push_str("Ablative: \n");
-                expected.push_str(
-                    format!("Ablative: {}\n", get_inflected_from(word, &table.ablative)).as_str(),
-                );
-            }
-            6 => {
-                if word.locative {
-                    initial.push_str("Locative: \n");
-                    expected.push_str(
-                        format!("Locative: {}\n", get_inflected_from(word, &table.locative))
-                            .as_str(),
-                    );
-                }
-            }
-            _ => {}
-        }
+    for case in configuration().case_order.cases_for(word) {
+        initial.push_str(format!("{}: \n", case.title()).as_str());
+        expected.push_str(
+            format!(
+                "{}: {}\n",
+                case.title(),
+                get_inflected_from(word, table.row(case))
+            )
+            .as_str(),
+        );
     }
 
     // Inflection time!
     let Ok(solution) = Editor::new("Open the editor to inflect:")
         .with_predefined_text(initial.as_str())
         .with_file_extension(".md")
-        .prompt()
+        .ask()
     else {
         return false;
     };
 
-    same_answer(&solution, &expected)
+    same_answer(&solution, &expected, strictness)
 }
 
 // Ask for alternative forms (gendered or otherwise) about a given word.
-fn ask_for_alternatives(related: &[Vec<Word>; 5]) -> bool {
+fn ask_for_alternatives(related: &[Vec<Word>; 5], strictness: MacronStrictness) -> bool {
     let alternatives = &related[RelationKind::Alternative as usize - 1];
     if !alternatives.is_empty() {
         let Ok(raw) =
-            Text::new("Do you know of any alternative (not asking about a gendered one)?").prompt()
+            Text::new("Do you know of any alternative (not asking about a gendered one)?").ask()
         else {
             return false;
         };
         let expected = joint_related_words(alternatives);
-        if !same_answer(&raw, &expected) {
+        if !same_answer(&raw, &expected, strictness) {
             return false;
         }
     }
 
     let gendered = &related[RelationKind::Gendered as usize - 1];
     if !gendered.is_empty() {
-        let Ok(raw) = Text::new("Do you know of the same word but on the other gender?").prompt()
+        let Ok(raw) = Text::new("Do you know of the same word but on the other gender?").ask()
         else {
             return false;
         };
         let expected = joint_related_words(gendered);
-        if !same_answer(&raw, &expected) {
+        if !same_answer(&raw, &expected, strictness) {
             return false;
         }
     }
@@ -247,49 +747,49 @@ fn ask_for_alternatives(related: &[Vec<Word>; 5]) -> bool {
 // adverbial).
 //
 // NOTE: this word _has_ to be an adjective.
-fn ask_for_others(word: &Word, related: &[Vec<Word>; 5]) -> bool {
+fn ask_for_others(word: &Word, related: &[Vec<Word>; 5], strictness: MacronStrictness) -> bool {
     assert!(matches!(word.category, Category::Adjective));
 
     let comparative = comparative(word, &related[RelationKind::Comparative as usize - 1]);
-    let Ok(raw) = Text::new("Comparative:").prompt() else {
+    let Ok(raw) = Text::new("Comparative:").ask() else {
         return false;
     };
-    if !same_answer(&raw, &comparative) {
+    if !same_answer(&raw, &comparative, strictness) {
         return false;
     }
 
     let superlative = superlative(word, &related[RelationKind::Superlative as usize - 1]);
-    let Ok(raw) = Text::new("Superlative:").prompt() else {
+    let Ok(raw) = Text::new("Superlative:").ask() else {
         return false;
     };
-    if !same_answer(&raw, &superlative) {
+    if !same_answer(&raw, &superlative, strictness) {
         return false;
     }
 
     let adverbial = adverb(word, &related[RelationKind::Adverb as usize - 1]);
-    let Ok(raw) = Text::new("Adverb:").prompt() else {
+    let Ok(raw) = Text::new("Adverb:").ask() else {
         return false;
     };
-    if !same_answer(&raw, &adverbial) {
+    if !same_answer(&raw, &adverbial, strictness) {
         return false;
     }
 
     true
 }
 
-fn good_noun_inflection(word: &Word) -> bool {
+fn good_noun_inflection(word: &Word, strictness: MacronStrictness) -> bool {
     if let Ok(table) = get_noun_table(word) {
-        if !ask_for_table(word, &table, None) {
+        if !ask_for_table(word, &table, None, strictness) {
             return false;
         }
         if let Ok(related) = select_related_words(word) {
-            return ask_for_alternatives(&related);
+            return ask_for_alternatives(&related, strictness);
         }
     }
     true
 }
 
-fn good_adjective_inflection(word: &Word) -> bool {
+fn good_adjective_inflection(word: &Word, strictness: MacronStrictness) -> bool {
     if let Ok(tables) = get_adjective_table(word) {
         // Pick which gender from the adjective table to ask.
         let mut rng = rand::rng();
@@ -300,60 +800,71 @@ fn good_adjective_inflection(word: &Word) -> bool {
             _ => Some("in the masculine"),
         };
 
-        if !ask_for_table(word, &tables[gender], suffix) {
+        if !ask_for_table(word, &tables[gender], suffix, strictness) {
             return false;
         }
         if let Ok(related) = select_related_words(word) {
-            if !ask_for_others(word, &related) {
+            if !ask_for_others(word, &related, strictness) {
                 return false;
             }
-            return ask_for_alternatives(&related);
+            return ask_for_alternatives(&related, strictness);
         }
     }
     true
 }
 
-fn good_inflection(word: &Word) -> bool {
+fn good_inflection(word: &Word, strictness: MacronStrictness) -> bool {
     match word.category {
-        Category::Noun => good_noun_inflection(word),
-        Category::Adjective => good_adjective_inflection(word),
+        Category::Noun => good_noun_inflection(word, strictness),
+        Category::Adjective => good_adjective_inflection(word, strictness),
         cat => panic!("error: practice: trying to inflect {cat}"),
     }
 }
 
-fn run_inflect_words(words: &Vec<Word>, locale: &Locale) -> bool {
+fn run_inflect_words(
+    words: &Vec<Word>,
+    locale: &Locale,
+    options: &SessionOptions,
+    limits: &mut SessionLimits,
+) -> bool {
+    let strictness = options.strictness();
+    let new = select_new_word_ids(words).unwrap_or_else(|e| {
+        println!("warning: practice: {e}");
+        vec![]
+    });
+
     for word in words {
-        // If the translation cannot be found, skip this word.
-        let Some(translation) = word.translation.get(locale.to_code()) else {
+        // If the translation cannot be found, not even on the fallback
+        // locales, skip this word.
+        let Some((used, translation)) = translation_for(word, locale) else {
             continue;
         };
 
+        // Words are not to be tested cold the first time they come up.
+        if new.contains(&word.id) {
+            introduce_word(word);
+        }
+
         // Enunciate.
         println!("Fill out this {}:", word.category);
-        println!("Translation: {}.", translation);
+        if used == *locale {
+            println!("Translation: {}.", translation);
+        } else {
+            println!("Translation ({used}, fallback): {}.", translation);
+        }
+        print_notes_for(word, options);
 
         // Complete the enunciate.
-        let Ok(raw) = Text::new("Enunciated:")
-            .with_initial_value(&fill_out_enunciated(word))
-            .prompt()
-        else {
+        let Some(raw) = prompt_answer("Enunciated:", Some(&fill_out_enunciated(word)), word) else {
             return false;
         };
         let answer = raw.trim();
 
-        // Check the answer and update the success rate on the database if
-        // needed.
-        if same_answer(&answer.to_string(), &word.enunciated) {
-            if word.steps as usize == MAX_STEPS - 1 {
-                let _ = update_success(word, word.succeeded + 1, 0);
-            } else {
-                let _ = update_success(word, word.succeeded, word.steps + 1);
-            }
+        // Check the answer.
+        let mut correct = same_answer(&answer.to_string(), &word.enunciated, strictness);
+        if correct {
             println!("\x1b[92m✓\x1b[0m\n");
         } else {
-            if word.succeeded > 0 {
-                let _ = update_success(word, word.succeeded - 1, 0);
-            }
             println!("\x1b[91m❌\x1b[0m\n");
         }
 
@@ -361,60 +872,193 @@ fn run_inflect_words(words: &Vec<Word>, locale: &Locale) -> bool {
         if matches!(word.category, Category::Noun | Category::Adjective) {
             // Now ask for inflecting the given word in various ways depending on
             // the word category.
-            if good_inflection(word) {
-                if word.steps as usize == MAX_STEPS - 1 {
-                    let _ = update_success(word, word.succeeded + 1, 0);
-                } else {
-                    let _ = update_success(word, word.succeeded, word.steps + 1);
-                }
+            if good_inflection(word, strictness) {
                 println!("\x1b[92m✓\x1b[0m\n");
             } else {
-                if word.succeeded > 0 {
-                    let _ = update_success(word, word.succeeded - 1, 0);
-                }
+                correct = false;
                 println!("\x1b[91m❌\x1b[0m\n");
             }
         }
+
+        // Both the enunciate and the inflection make up a single review of the
+        // word, so it's not scheduled twice.
+        let _ = record_review(word, correct);
+
+        if !keep_going(limits) {
+            return false;
+        }
     }
 
     true
 }
 
+// Study the given `lesson` as a whole: first translating its words, then
+// inflecting them, and finally solving its exercises. Words left for later are
+// put into `pending` (see `run_queue`). Returns false if the user stopped.
+fn study_lesson(
+    lesson: &Lesson,
+    locale: &Locale,
+    options: &SessionOptions,
+    limits: &mut SessionLimits,
+    summary: &mut SessionSummary,
+    pending: &mut Vec<Word>,
+) -> bool {
+    let (words, exercises) = match (
+        select_lesson_words(lesson),
+        select_lesson_exercises(lesson.id),
+    ) {
+        (Ok(words), Ok(exercises)) => (words, exercises),
+        (Err(e), _) | (_, Err(e)) => {
+            println!("error: practice: {e}");
+            std::process::exit(1);
+        }
+    };
+    if words.is_empty() && exercises.is_empty() {
+        println!(
+            "practice: there is nothing to study on '{}'. Link words and exercises to it with 'mihi lessons link'.",
+            lesson.name
+        );
+        return false;
+    }
+
+    if !run_words(&words, locale, options, limits, summary, pending) {
+        return false;
+    }
+    let to_inflect: Vec<Word> = words
+        .into_iter()
+        .filter(|w| {
+            matches!(
+                w.category,
+                Category::Noun | Category::Adjective | Category::Verb | Category::Pronoun
+            )
+        })
+        .collect();
+    if !run_inflect_words(&to_inflect, locale, options, limits) {
+        return false;
+    }
+
+    // Exercises are only proposed once the words they are linked to are
+    // mastered, so it's fine if none of them is ready yet.
+    exercises.is_empty() || run_exercises(exercises, options, limits)
+}
+
 // Returns a vector of words which contain a randomized set of words from
 // different categories.
-fn select_general_words(flags: &[String], tags: &[String]) -> Result<Vec<Word>, String> {
-    let mut res = select_relevant_words(Category::Noun, flags, tags, 4)?;
+fn select_general_words(
+    flags: &[String],
+    tags: &[String],
+    novelty: Novelty,
+) -> Result<Vec<Word>, String> {
+    let mut res = select_relevant_words(Category::Noun, flags, tags, 4, novelty)?;
     res.append(&mut select_relevant_words(
         Category::Adjective,
         flags,
         tags,
         2,
+        novelty,
+    )?);
+    res.append(&mut select_relevant_words(
+        Category::Verb,
+        flags,
+        tags,
+        4,
+        novelty,
     )?);
-    res.append(&mut select_relevant_words(Category::Verb, flags, tags, 4)?);
     res.append(&mut select_relevant_words(
         Category::Pronoun,
         flags,
         tags,
         1,
+        novelty,
     )?);
     res.append(&mut select_relevant_words(
         Category::Adverb,
         flags,
         tags,
         2,
+        novelty,
     )?);
     res.append(&mut select_relevant_words(
         Category::Preposition,
         flags,
         tags,
         1,
+        novelty,
     )?);
     res.append(&mut select_relevant_words(
         Category::Conjunction,
         flags,
         tags,
         1,
+        novelty,
     )?);
+
+    // Otherwise the session would always go through the categories in the
+    // same order.
+    res.shuffle(&mut rand::rng());
+    Ok(res)
+}
+
+// Select the words to be practiced: either from the given `category`, or a few
+// from each category if none was given, filtered by the given `flags`, `tags`
+// and `novelty`. If there's a limit on the words which have never been reviewed
+// which can still be introduced on this session, then `new_left` holds it, and
+// new words beyond it are left out.
+fn select_words(
+    category: Option<Category>,
+    flags: &[String],
+    tags: &[String],
+    novelty: Novelty,
+    new_left: &mut Option<usize>,
+) -> Result<Vec<Word>, String> {
+    let words = match category {
+        Some(cat) => select_relevant_words(cat, flags, tags, 15, novelty)?,
+        None => select_general_words(flags, tags, novelty)?,
+    };
+    cap_new_words(words, new_left)
+}
+
+// Returns the words for the next round of a session: the ones from an
+// interrupted session or given through '--word' if there are any, or a new
+// selection out of the given filters otherwise (see 'select_words'). Exits the
+// program if they cannot be selected.
+fn words_for_round(
+    resumed: &mut Option<Vec<Word>>,
+    category: Option<Category>,
+    flags: &[String],
+    tags: &[String],
+    novelty: Novelty,
+    new_left: &mut Option<usize>,
+) -> Vec<Word> {
+    let words = match resumed.take() {
+        Some(words) => Ok(words),
+        None => select_words(category, flags, tags, novelty, new_left),
+    };
+    words.unwrap_or_else(|e| {
+        println!("error: practice: {e}");
+        std::process::exit(1);
+    })
+}
+
+// Returns the given `words` without the ones which have never been reviewed
+// beyond the `new_left` ones which can still be introduced on this session, if
+// there's such a limit.
+fn cap_new_words(words: Vec<Word>, new_left: &mut Option<usize>) -> Result<Vec<Word>, String> {
+    let Some(left) = new_left.as_mut() else {
+        return Ok(words);
+    };
+
+    let new = select_new_word_ids(&words)?;
+    let mut res = Vec::with_capacity(words.len());
+    for word in words {
+        if new.contains(&word.id) {
+            if *left == 0 {
+                continue;
+            }
+            *left -= 1;
+        }
+        res.push(word);
+    }
     Ok(res)
 }
 
@@ -460,14 +1104,25 @@ fn diff_tool() -> Option<&'static str> {
         .find(|&cmd| is_executable(cmd))
 }
 
-// Perform a diff with the `given` and the `expected` answers for an exercise
-// and interactively ask the user if things are ok. Returns a boolean depending
-// on the user's answer to that final question, or false if something went
-// wrong.
-fn accepted_diff(given: &String, expected: &String) -> bool {
-    // If a diff tool could be fetched, then write into temporary files and call
-    // the diff tool against both temporary files; otherwise just print things
-    // out into the stdout.
+// Print the difference between the `given` and the `expected` answers for an
+// exercise, with removed lines in red and added ones in green.
+fn print_colored_diff(given: &str, expected: &str) {
+    println!("---Given (-) vs expected (+):");
+    for line in diff_lines(given, expected) {
+        match line {
+            DiffLine::Same(line) => println!("  {line}"),
+            DiffLine::Removed(line) => println!("\x1b[91m- {line}\x1b[0m"),
+            DiffLine::Added(line) => println!("\x1b[92m+ {line}\x1b[0m"),
+        }
+    }
+    println!("---");
+}
+
+// Show the diff between the `given` and the `expected` answers. If a diff tool
+// could be fetched, then write into temporary files and call the diff tool
+// against both temporary files; otherwise just print a colored diff into the
+// stdout. Returns false if something went wrong.
+fn show_diff(given: &String, expected: &String) -> bool {
     match diff_tool() {
         Some(cmd) => {
             let Ok(mut given_file) = NamedTempFile::new() else {
@@ -489,73 +1144,1494 @@ fn accepted_diff(given: &String, expected: &String) -> bool {
             cmd.status().expect("process failed to execute");
             println!();
         }
-        None => {
-            println!("---Given:\n{given}\n---Expected:\n{expected}");
-        }
+        None => print_colored_diff(given, expected),
+    }
+
+    true
+}
+
+// Perform a diff with the `given` and the `expected` answers and interactively
+// ask the user if things are ok. Returns a boolean depending on the user's
+// answer to that final question, or false if something went wrong.
+fn accepted_diff(given: &String, expected: &String) -> bool {
+    if !show_diff(given, expected) {
+        return false;
     }
 
     Confirm::new("Do you think that you did well?")
         .with_default(false)
-        .prompt()
+        .ask()
         .unwrap_or(false)
 }
 
+// Perform a diff with the `given` and the `expected` answers for an exercise
+// and interactively ask the user to grade the given answer. Returns the score
+// given by the user, which is zero if something went wrong, or None if the user
+// aborted.
+fn graded_diff(given: &String, expected: &String) -> Option<usize> {
+    if !show_diff(given, expected) {
+        return Some(0);
+    }
+
+    let score = Select::new("How well did you do?", SCORES.to_vec())
+        .ask()
+        .ok()?;
+    SCORES.iter().position(|s| *s == score)
+}
+
 // Run the quiz for all the given `exercises`.
-fn run_exercises(exercises: Vec<Exercise>) -> bool {
+fn run_exercises(
+    exercises: Vec<Exercise>,
+    options: &SessionOptions,
+    limits: &mut SessionLimits,
+) -> bool {
     if exercises.is_empty() {
         println!("practice: no exercises!");
         return true;
     }
 
     for exercise in exercises {
-        let Ok(solution) = Editor::new(format!("Exercise '{}':", exercise.title).as_str())
-            .with_predefined_text(
-                format!(
-                    "---Enunciate: {}\n{}\n---!",
-                    exercise.title, exercise.enunciate
-                )
-                .as_str(),
-            )
-            .with_file_extension(".md")
-            .prompt()
-        else {
+        if !run_exercise(&exercise, options) || !keep_going(limits) {
             return false;
-        };
-
-        let mut solution = remove_exercise_enunciate(solution);
-        if solution.is_empty() {
-            solution = String::from("<no solution given>");
         }
-        println!(
-            "Enunciate for '{}':\n\n{}\n\nGiven:\n",
-            exercise.title, exercise.enunciate
-        );
+    }
 
-        // If the exercise is seen as correct by the user, then "touch"
-        // (i.e. refresh the 'updated_at' date). This way, next time we select
-        // exercises to show the user, we can prevent this one showing up first.
-        if accepted_diff(&solution, &exercise.solution) {
-            let _ = touch_exercise(&exercise);
-        }
+    true
+}
 
-        let lessons = exercise.lessons.trim();
-        if !lessons.is_empty() {
-            println!("The lessons for this exercise were:\n{}", lessons);
-        }
+/// Ask the user to solve the given `exercise` with the given session
+/// `options`, and record the result alongside the time it took. Returns false
+/// if the user aborted.
+pub fn run_exercise(exercise: &Exercise, options: &SessionOptions) -> bool {
+    let strictness = options.strictness();
+    if let Some(score) = exercise.score {
+        println!("Last score for '{}': {score}/{MAX_SCORE}", exercise.title);
+    }
+
+    let result = match exercise.kind {
+        ExerciseKind::Simple => run_simple_exercise(exercise),
+        ExerciseKind::Pensum => run_pensum_exercise(exercise, strictness),
+        ExerciseKind::Numerical => run_numerical_exercise(exercise, strictness),
+        ExerciseKind::Transformation => run_transformation_exercise(exercise, strictness),
+        ExerciseKind::Template => run_template_exercise(exercise, strictness),
+        ExerciseKind::Parsing => run_parsing_exercise(exercise),
+        ExerciseKind::Composition => run_composition_exercise(exercise),
+        ExerciseKind::Dictation => run_dictation_exercise(exercise, strictness),
+    };
+    let Some((seconds, score)) = result else {
+        return false;
+    };
+
+    // If the exercise is seen as correct, then grading it also "touches" it
+    // (i.e. refreshes the 'updated_at' date). This way, next time we select
+    // exercises to show the user, we can prevent this one showing up first.
+    if let Err(e) = grade_exercise(exercise, score) {
+        println!("warning: practice: {e}");
+    }
+    if let Err(e) = record_attempt(exercise, seconds, score >= PASSING_SCORE) {
+        println!("warning: practice: {e}");
+    }
+
+    let lessons = exercise.lessons.trim();
+    if !lessons.is_empty() {
+        println!("The lessons for this exercise were:\n{}", lessons);
     }
 
     true
 }
 
-pub fn run(args: Vec<String>) {
-    let mut it = args.into_iter();
-    let mut category = None;
+// Ask the user to write the solution for the given simple `exercise` on an
+// editor. Returns the seconds it took to write it and the score given by the
+// user after comparing it with the expected solution, or None if the user
+// aborted.
+fn run_simple_exercise(exercise: &Exercise) -> Option<(u64, usize)> {
+    let started = Instant::now();
+    let Ok(solution) = Editor::new(format!("Exercise '{}':", exercise.title).as_str())
+        .with_predefined_text(
+            format!(
+                "---Enunciate: {}\n{}\n---!",
+                exercise.title, exercise.enunciate
+            )
+            .as_str(),
+        )
+        .with_file_extension(".md")
+        .ask()
+    else {
+        return None;
+    };
+
+    let seconds = started.elapsed().as_secs();
+
+    let mut solution = remove_exercise_enunciate(solution);
+    if solution.is_empty() {
+        solution = String::from("<no solution given>");
+    }
+    println!(
+        "Enunciate for '{}':\n\n{}\n\nGiven:\n",
+        exercise.title, exercise.enunciate
+    );
+
+    Some((seconds, graded_diff(&solution, &exercise.solution)?))
+}
+
+// Ask the user to fill in each blank from the given pensum `exercise`. Typing
+// '?' shows the hint for the current blank, if any. Returns the seconds it took
+// to fill all the blanks and the share of them which were right as a score, or
+// None if the user aborted.
+fn run_pensum_exercise(exercise: &Exercise, strictness: MacronStrictness) -> Option<(u64, usize)> {
+    let pensum = match Pensum::with_solution(&exercise.enunciate, &exercise.solution) {
+        Ok(pensum) => pensum,
+        Err(e) => {
+            println!("error: practice: '{}': {e}", exercise.title);
+            return None;
+        }
+    };
+
+    println!(
+        "Exercise '{}':\n\n{}\n",
+        exercise.title,
+        pensum.render(false)
+    );
+
+    let started = Instant::now();
+    let blanks = pensum.blanks();
+    let mut given = Vec::with_capacity(blanks.len());
+    for (idx, blank) in blanks.iter().enumerate() {
+        let help = if blank.hint.is_some() {
+            "Type '?' for a hint"
+        } else {
+            ""
+        };
+        loop {
+            let answer = Text::new(format!("({}):", idx + 1).as_str())
+                .with_help_message(help)
+                .ask()
+                .ok()?;
+            match (answer.trim(), &blank.hint) {
+                ("?", Some(hint)) => println!("Hint: {hint}"),
+                ("?", None) => println!("There is no hint for this blank."),
+                _ => {
+                    given.push(answer);
+                    break;
+                }
+            }
+        }
+    }
+    let seconds = started.elapsed().as_secs();
+
+    let grades = pensum.grade(&given, strictness);
+    for ((idx, blank), ok) in blanks.iter().enumerate().zip(grades.iter()) {
+        if !ok {
+            println!(
+                "\x1b[91m❌({}): '{}'; expected: {}\x1b[0m",
+                idx + 1,
+                given[idx].trim(),
+                blank.answers.join(" | ")
+            );
+        }
+    }
+
+    let right = grades.iter().filter(|ok| **ok).count();
+    if right == grades.len() {
+        println!("\x1b[92m✓ All the blanks are right!\x1b[0m");
+    }
+    println!("\n{}\n", pensum.solution());
+
+    Some((seconds, MAX_SCORE * right / grades.len().max(1)))
+}
+
+// Ask the user to convert random numbers between digits, Roman numerals and
+// Latin words for the given numerical `exercise`. Returns the seconds it took
+// to answer all the questions and the share of them which were right as a
+// score, or None if the user aborted.
+fn run_numerical_exercise(
+    exercise: &Exercise,
+    strictness: MacronStrictness,
+) -> Option<(u64, usize)> {
+    println!("Exercise '{}':\n\n{}\n", exercise.title, exercise.enunciate);
+
+    let mut rng = rand::rng();
+    let started = Instant::now();
+    let mut right = 0;
+
+    for _ in 0..NUMERICAL_QUESTIONS {
+        // Roman numerals go much further than Latin words.
+        let direction = rng.random_range(0..4);
+        let number = if direction < 2 {
+            rng.random_range(1..=MAX_ROMAN)
+        } else {
+            rng.random_range(1..=MAX_LATIN)
+        };
+
+        let (message, expected) = match direction {
+            0 => (format!("Roman numerals for {number}:"), to_roman(number)?),
+            1 => (
+                format!("Number for {}:", to_roman(number)?),
+                number.to_string(),
+            ),
+            2 => (format!("Latin for {number}:"), to_latin(number)?),
+            _ => (
+                format!("Number for '{}':", to_latin(number)?),
+                number.to_string(),
+            ),
+        };
+
+        let answer = Text::new(&message).ask().ok()?;
+        let correct = match direction {
+            0 => from_roman(&answer) == Some(number),
+            2 => matches_form(&answer, &expected, strictness),
+            _ => answer.trim().parse::<usize>() == Ok(number),
+        };
+        if correct {
+            right += 1;
+            println!("\x1b[92m✓ {expected}\x1b[0m");
+        } else {
+            println!("\x1b[91m❌{expected}\x1b[0m");
+        }
+    }
+
+    Some((
+        started.elapsed().as_secs(),
+        MAX_SCORE * right / NUMERICAL_QUESTIONS,
+    ))
+}
+
+// A form of a word to be transformed into another one.
+struct Transformation<'a> {
+    word: &'a Word,
+    // The form being shown and a description of it (e.g. 'accusative
+    // singular').
+    from: String,
+    description: String,
+    // What the user has to do with the form (e.g. 'Make it plural:').
+    message: &'static str,
+    // Forms accepted as the answer.
+    expected: Vec<String>,
+}
+
+// Returns a random transformation between the singular and the plural of the
+// given noun, or None if it has no case with both numbers.
+fn number_transformation<'a>(word: &'a Word, rng: &mut ThreadRng) -> Option<Transformation<'a>> {
+    let table = get_noun_table(word).ok()?;
+    let cases: Vec<Case> = Case::ALL
+        .into_iter()
+        .filter(|&case| {
+            table
+                .row(case)
+                .iter()
+                .all(|info| !info.inflected.is_empty())
+        })
+        .collect();
+    let case = *cases.choose(rng)?;
+
+    let (from, to, message) = if rng.random_bool(0.5) {
+        (Number::Singular, Number::Plural, "Make it plural:")
+    } else {
+        (Number::Plural, Number::Singular, "Make it singular:")
+    };
+    let row = table.row(case);
+    Some(Transformation {
+        word,
+        from: row[from as usize].inflected.join("/"),
+        description: format!("{} {}", case.title().to_lowercase(), from),
+        message,
+        expected: row[to as usize].inflected.clone(),
+    })
+}
+
+// Returns a random transformation between the active and the passive voice of
+// the given verb, or None if it has no forms on both voices.
+fn voice_transformation<'a>(word: &'a Word, rng: &mut ThreadRng) -> Option<Transformation<'a>> {
+    let pairs = get_voice_pairs(word).ok()?;
+    let pair = pairs.choose(rng)?;
+
+    let (from, to, message) = if rng.random_bool(0.5) {
+        (0, 1, "Make it passive:")
+    } else {
+        (1, 0, "Make it active:")
+    };
+    Some(Transformation {
+        word,
+        from: pair.forms[from].join("/"),
+        description: format!(
+            "{} {}, {} person {}",
+            pair.tense.to_lowercase(),
+            pair.mood.to_lowercase(),
+            ["1st", "2nd", "3rd"][pair.person - 1],
+            pair.number
+        ),
+        message,
+        expected: pair.forms[to].clone(),
+    })
+}
+
+// Run a transformation exercise: forms of known nouns and verbs are to be
+// turned into the other number or voice. Returns the elapsed time and the
+// score, or None if the user aborted or no transformation could be found.
+fn run_transformation_exercise(
+    exercise: &Exercise,
+    strictness: MacronStrictness,
+) -> Option<(u64, usize)> {
+    let mut words = vec![];
+    for category in [Category::Noun, Category::Verb] {
+        match select_known_words(category, TRANSFORMATION_WORDS) {
+            Ok(known) => words.extend(known),
+            Err(e) => println!("warning: practice: {e}"),
+        }
+    }
+
+    let mut rng = rand::rng();
+    let mut transformations = vec![];
+    for _ in 0..TRANSFORMATION_QUESTIONS {
+        // Not all words have forms to be transformed (e.g. nouns flagged as
+        // 'onlysingular'), so give it a few tries.
+        let found = (0..words.len().max(1)).find_map(|_| {
+            let word = words.choose(&mut rng)?;
+            match word.category {
+                Category::Noun => number_transformation(word, &mut rng),
+                _ => voice_transformation(word, &mut rng),
+            }
+        });
+        transformations.extend(found);
+    }
+    if transformations.is_empty() {
+        println!(
+            "warning: practice: not enough known nouns or verbs for exercise '{}'",
+            exercise.title
+        );
+        return None;
+    }
+
+    println!("Exercise '{}':\n\n{}\n", exercise.title, exercise.enunciate);
+
+    let started = Instant::now();
+    let mut right = 0;
+
+    for transformation in &transformations {
+        println!(
+            "{}: {} ({})",
+            transformation.word.enunciated, transformation.from, transformation.description
+        );
+        let answer = Text::new(transformation.message).ask().ok()?;
+        let expected = transformation.expected.join("/");
+        if transformation
+            .expected
+            .iter()
+            .any(|form| matches_form(&answer, form, strictness))
+        {
+            right += 1;
+            println!("\x1b[92m✓ {expected}\x1b[0m");
+        } else {
+            println!("\x1b[91m❌{expected}\x1b[0m");
+        }
+    }
+
+    Some((
+        started.elapsed().as_secs(),
+        MAX_SCORE * right / transformations.len(),
+    ))
+}
+
+// Returns the words from the given Latin `text` in lowercase and without
+// punctuation.
+fn latin_words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|word| !word.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+// Run a parsing exercise: random forms of known nouns are shown and the user
+// has to tell their case and number (e.g. 'acc sg'). Forms which are shared by
+// more than one case or number (e.g. 'rosae') accept any of them. Returns the
+// elapsed time and the score, or None if the user aborted or no nouns are
+// known.
+fn run_parsing_exercise(exercise: &Exercise) -> Option<(u64, usize)> {
+    let words = select_known_words(Category::Noun, TRANSFORMATION_WORDS).unwrap_or_else(|e| {
+        println!("warning: practice: {e}");
+        vec![]
+    });
+
+    let mut rng = rand::rng();
+    let mut questions = vec![];
+    for _ in 0..PARSING_QUESTIONS {
+        // Not all nouns have their forms available, so give it a few tries.
+        let found = (0..words.len().max(1)).find_map(|_| {
+            let word = words.choose(&mut rng)?;
+            let table = get_noun_table(word).ok()?;
+            let forms: Vec<&String> = table
+                .iter()
+                .flat_map(|(_, _, info)| info.inflected.iter())
+                .collect();
+            let form = (*forms.choose(&mut rng)?).clone();
+            let analyses: Vec<(Case, Number)> = table
+                .iter()
+                .filter(|(_, _, info)| info.inflected.contains(&form))
+                .map(|(case, number, _)| (case, number))
+                .collect();
+            Some((word, form, analyses))
+        });
+        questions.extend(found);
+    }
+    if questions.is_empty() {
+        println!(
+            "warning: practice: not enough known nouns for exercise '{}'",
+            exercise.title
+        );
+        return None;
+    }
+
+    println!("Exercise '{}':\n\n{}\n", exercise.title, exercise.enunciate);
+
+    let started = Instant::now();
+    let mut right = 0;
+
+    for (word, form, analyses) in &questions {
+        println!("{}: {form}", word.enunciated);
+        let answer = Text::new("Case and number (e.g. 'acc sg'):").ask().ok()?;
+        let given = match answer.split_whitespace().collect::<Vec<&str>>()[..] {
+            [case, number] => Case::from_name(case).zip(Number::from_name(number)),
+            _ => None,
+        };
+        let expected = analyses
+            .iter()
+            .map(|(case, number)| format!("{case} {number}"))
+            .collect::<Vec<String>>()
+            .join(", ");
+        if given.is_some_and(|given| analyses.contains(&given)) {
+            right += 1;
+            println!("\x1b[92m✓ {expected}\x1b[0m");
+        } else {
+            println!("\x1b[91m❌{expected}\x1b[0m");
+        }
+    }
+
+    Some((
+        started.elapsed().as_secs(),
+        MAX_SCORE * right / questions.len(),
+    ))
+}
+
+// Ask the user to translate the English text from the given composition
+// `exercise` into Latin on an editor. As there are many good translations, the
+// model one is shown alongside the words from it which were not used, and then
+// the user grades their own. Returns the seconds it took to write it and the
+// score, or None if the user aborted.
+fn run_composition_exercise(exercise: &Exercise) -> Option<(u64, usize)> {
+    let started = Instant::now();
+    let Ok(given) = Editor::new(format!("Translate '{}' into Latin:", exercise.title).as_str())
+        .with_predefined_text(
+            format!(
+                "---Enunciate: {}\n{}\n---!",
+                exercise.title, exercise.enunciate
+            )
+            .as_str(),
+        )
+        .with_file_extension(".md")
+        .ask()
+    else {
+        return None;
+    };
+    let seconds = started.elapsed().as_secs();
+
+    let mut given = remove_exercise_enunciate(given);
+    if given.is_empty() {
+        given = String::from("<no translation given>");
+    }
+    println!(
+        "English:\n\n{}\n\nYour translation:\n\n{given}\n\nModel translation:\n\n{}\n",
+        exercise.enunciate, exercise.solution
+    );
+
+    // Macrons are not taken into account, as the point is the vocabulary.
+    let used: Vec<String> = latin_words(&given)
+        .iter()
+        .map(|word| strip_macrons(word))
+        .collect();
+    let mut missing: Vec<String> = vec![];
+    for word in latin_words(&exercise.solution) {
+        if !used.contains(&strip_macrons(&word)) && !missing.contains(&word) {
+            missing.push(word);
+        }
+    }
+    if !missing.is_empty() {
+        println!(
+            "Words from the model translation which you did not use: {}\n",
+            missing.join(", ")
+        );
+    }
+
+    let score = Select::new("How well did you do?", SCORES.to_vec())
+        .ask()
+        .ok()?;
+    Some((seconds, SCORES.iter().position(|s| *s == score)?))
+}
+
+// Read the given `sentence` out loud with the given speech `tool`. Returns
+// false if it could not be done.
+fn speak(tool: &str, sentence: &str) -> bool {
+    let mut cmd = Command::new(tool);
+    if tool != "say" {
+        cmd.args(["-v", "la"]);
+    }
+    cmd.arg(sentence)
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+// Run a dictation exercise: each sentence from its text is read out loud and
+// the user has to write it down. Typing ':again' reads the sentence once more.
+// Returns the elapsed time and the share of sentences which were right as a
+// score, or None if the user aborted or there is no way to read them out loud.
+fn run_dictation_exercise(
+    exercise: &Exercise,
+    strictness: MacronStrictness,
+) -> Option<(u64, usize)> {
+    let Some(tool) = SPEECH_TOOLS.into_iter().find(|&cmd| is_executable(cmd)) else {
+        println!(
+            "warning: practice: install one of {} in order to run dictations like '{}'",
+            SPEECH_TOOLS.join(", "),
+            exercise.title
+        );
+        return None;
+    };
+    let sentences: Vec<&str> = exercise
+        .solution
+        .split_inclusive(['.', '?', '!', ';', '\n'])
+        .map(str::trim)
+        .filter(|sentence| !latin_words(sentence).is_empty())
+        .collect();
+    if sentences.is_empty() {
+        println!(
+            "warning: practice: there is nothing to dictate on '{}'",
+            exercise.title
+        );
+        return None;
+    }
+
+    println!("Dictation '{}':\n", exercise.title);
+
+    let started = Instant::now();
+    let mut right = 0;
+
+    for (idx, sentence) in sentences.iter().enumerate() {
+        let answer = loop {
+            if !speak(tool, sentence) {
+                println!("warning: practice: '{tool}' could not read the dictation");
+                return None;
+            }
+            let answer = Text::new(format!("Sentence {}:", idx + 1).as_str())
+                .with_help_message("Type ':again' to listen to it again")
+                .ask()
+                .ok()?;
+            if answer.trim() != ":again" {
+                break answer;
+            }
+        };
+
+        if matches_form(
+            &latin_words(&answer).join(" "),
+            &latin_words(sentence).join(" "),
+            strictness,
+        ) {
+            right += 1;
+            println!("\x1b[92m✓ {sentence}\x1b[0m");
+        } else {
+            println!("\x1b[91m❌{sentence}\x1b[0m");
+        }
+    }
+    Some((
+        started.elapsed().as_secs(),
+        MAX_SCORE * right / sentences.len(),
+    ))
+}
+
+// Returns the alternative forms for the given `placeholder` from the word
+// picked for its variable on `picked`. Adjectives agreeing with a noun take the
+// gender of the noun picked for it.
+fn template_forms(placeholder: &Placeholder, picked: &[(&str, &Word)]) -> Vec<String> {
+    let word_for = |variable: &str| {
+        picked
+            .iter()
+            .find(|(name, _)| *name == variable)
+            .map(|(_, word)| *word)
+    };
+    let Some(word) = word_for(&placeholder.variable) else {
+        return vec![];
+    };
+
+    let table = match placeholder.category {
+        Category::Noun => get_noun_table(word).ok(),
+        _ => {
+            let gender = match &placeholder.gender {
+                Some(Agreement::Gender(gender)) => *gender,
+                Some(Agreement::Variable(noun)) => {
+                    word_for(noun).map_or(Gender::Masculine, |noun| noun.gender)
+                }
+                None => Gender::Masculine,
+            };
+            let idx = match gender {
+                Gender::Feminine => 1,
+                Gender::Neuter => 2,
+                _ => 0,
+            };
+            get_adjective_table(word)
+                .ok()
+                .and_then(|tables| tables.into_iter().nth(idx))
+        }
+    };
+    table
+        .map(|table| {
+            table
+                .get(placeholder.case, placeholder.number)
+                .inflected
+                .clone()
+        })
+        .unwrap_or_default()
+}
+
+// Run a template exercise: its enunciate and its solution are filled with
+// forms of random known words on each question. Returns the elapsed time and
+// the score, or None if the user aborted or the placeholders could not be
+// filled.
+fn run_template_exercise(
+    exercise: &Exercise,
+    strictness: MacronStrictness,
+) -> Option<(u64, usize)> {
+    let templates = Template::enunciate(&exercise.enunciate).and_then(|enunciate| {
+        Template::solution(&enunciate, &exercise.solution).map(|solution| (enunciate, solution))
+    });
+    let (enunciate, solution) = match templates {
+        Ok(templates) => templates,
+        Err(e) => {
+            println!(
+                "warning: practice: bad template on '{}': {e}",
+                exercise.title
+            );
+            return None;
+        }
+    };
+
+    let mut known = vec![];
+    for category in [Category::Noun, Category::Adjective] {
+        match select_known_words(category, TRANSFORMATION_WORDS) {
+            Ok(words) => known.push((category, words)),
+            Err(e) => println!("warning: practice: {e}"),
+        }
+    }
+    let variables = enunciate.variables();
+
+    let mut rng = rand::rng();
+    let mut questions = vec![];
+    for _ in 0..TEMPLATE_QUESTIONS {
+        // Variables from the same category are filled with different words.
+        let found = (0..TEMPLATE_ATTEMPTS).find_map(|_| {
+            let mut picked: Vec<(&str, &Word)> = vec![];
+            for (category, words) in &known {
+                let names: Vec<&str> = variables
+                    .iter()
+                    .copied()
+                    .filter(|name| {
+                        enunciate.placeholders().any(|p| {
+                            p.variable == *name && p.category as isize == *category as isize
+                        })
+                    })
+                    .collect();
+                let chosen: Vec<&Word> = words.choose_multiple(&mut rng, names.len()).collect();
+                if chosen.len() < names.len() {
+                    return None;
+                }
+                picked.extend(names.into_iter().zip(chosen));
+            }
+
+            // Forms are shown with their alternatives, but any of them is
+            // accepted on the answer.
+            let shown = enunciate.fill(|p| {
+                let forms = template_forms(p, &picked);
+                if forms.is_empty() {
+                    vec![]
+                } else {
+                    vec![forms.join("/")]
+                }
+            });
+            let expected = solution.fill(|p| template_forms(p, &picked));
+            if expected.is_empty() {
+                return None;
+            }
+            let shown = shown.into_iter().next()?;
+            Some((shown, expected))
+        });
+        questions.extend(found);
+    }
+    if questions.is_empty() {
+        println!(
+            "warning: practice: not enough known nouns or adjectives for exercise '{}'",
+            exercise.title
+        );
+        return None;
+    }
+
+    println!("Exercise '{}':\n", exercise.title);
+
+    let started = Instant::now();
+    let mut right = 0;
+
+    for (shown, expected) in &questions {
+        println!("{shown}");
+        let answer = Text::new("Answer:").ask().ok()?;
+        if expected
+            .iter()
+            .any(|solution| matches_form(&answer, solution, strictness))
+        {
+            right += 1;
+            println!("\x1b[92m✓ {}\x1b[0m", expected[0]);
+        } else {
+            println!("\x1b[91m❌{}\x1b[0m", expected[0]);
+        }
+    }
+
+    Some((
+        started.elapsed().as_secs(),
+        MAX_SCORE * right / questions.len(),
+    ))
+}
+
+// Record that one more item has been reviewed. If this goes beyond the session
+// limits from the configuration, then offer the user to take a break. Returns
+// false if the user wants to stop.
+fn keep_going(limits: &mut SessionLimits) -> bool {
+    limits.record();
+    if !limits.exceeded() {
+        return true;
+    }
+
+    let stop = Confirm::new(
+        format!(
+            "You have reviewed {} items in {} minutes. Do you want to take a break?",
+            limits.items(),
+            limits.minutes()
+        )
+        .as_str(),
+    )
+    .with_default(true)
+    .ask()
+    .unwrap_or(true);
+
+    if stop {
+        println!("Items which have not been reviewed will be asked in the next session.");
+        return false;
+    }
+
+    // The user wants to keep going: offer another break after the same amount
+    // of items or time.
+    limits.reset();
+    true
+}
+
+// Print how the translation questions of the session went, if any was asked.
+fn print_summary(summary: &SessionSummary) {
+    if summary.questions() == 0 {
+        return;
+    }
+
+    let elapsed = summary.elapsed().as_secs();
+    println!("\nSession summary:");
+    println!("   Questions: {}", summary.questions());
+    println!("   Correct: {}", summary.correct());
+    println!("   Incorrect: {}", summary.incorrect());
+    println!("   Accuracy: {:.1}%", summary.accuracy());
+    println!("   Time: {}m {:02}s", elapsed / 60, elapsed % 60);
+    if let Some(response) = summary.average_response() {
+        println!("   Average response: {:.1}s", response.as_secs_f64());
+    }
+    if summary.time_limit().is_some() {
+        println!("   Timeouts: {}", summary.timeouts());
+    }
+    if !summary.missed().is_empty() {
+        println!("   Missed:");
+        for enunciated in summary.missed() {
+            println!("      {enunciated}");
+        }
+    }
+}
+
+// Run a session where words and exercises are interleaved.
+fn run_mixed(
+    words: Vec<Word>,
+    exercises: Vec<Exercise>,
+    locale: &Locale,
+    options: &SessionOptions,
+    limits: &mut SessionLimits,
+    summary: &mut SessionSummary,
+) -> bool {
+    let session = SessionBuilder::new()
+        .words(words)
+        .exercises(exercises)
+        .max_exercises(MIXED_EXERCISES)
+        .build();
+
+    for item in session {
+        let ok = match item {
+            SessionItem::Word(word) => run_word(&word, locale, options, summary),
+            SessionItem::Exercise(exercise) => run_exercise(&exercise, options),
+        };
+        if !ok || !keep_going(limits) {
+            return false;
+        }
+    }
+
+    true
+}
+
+// Returns a noun phrase built out of a random noun from `nouns`, which might
+// be qualified by a random adjective from `adjectives`.
+fn random_noun_phrase(
+    nouns: &[Word],
+    adjectives: &[Word],
+    rng: &mut impl Rng,
+) -> Option<NounPhrase> {
+    let noun = nouns.choose(rng)?.clone();
+    let number = if noun.is_flag_set("onlysingular") {
+        Number::Singular
+    } else if noun.is_flag_set("onlyplural") {
+        Number::Plural
+    } else {
+        *Number::ALL.choose(rng)?
+    };
+    let adjective = if rng.random_bool(0.5) {
+        adjectives.choose(rng).cloned()
+    } else {
+        None
+    };
+
+    Some(NounPhrase {
+        noun,
+        adjective,
+        number,
+    })
+}
+
+// Returns a random sentence composed out of the words already known by the
+// user, alongside its Latin text.
+fn random_sentence() -> Result<(Sentence, String), String> {
+    let nouns = select_known_words(Category::Noun, 20)?;
+    let adjectives = select_known_words(Category::Adjective, 10)?;
+    let verbs = select_known_words(Category::Verb, 10)?;
+    let mut rng = rand::rng();
+
+    for _ in 0..SENTENCE_ATTEMPTS {
+        let (Some(subject), Some(object), Some(verb)) = (
+            random_noun_phrase(&nouns, &adjectives, &mut rng),
+            random_noun_phrase(&nouns, &adjectives, &mut rng),
+            verbs.choose(&mut rng),
+        ) else {
+            return Err("you don't know enough nouns and verbs yet".to_string());
+        };
+
+        let sentence = Sentence {
+            subject,
+            object,
+            verb: verb.clone(),
+        };
+        if let Ok(latin) = sentence.compose() {
+            return Ok((sentence, latin));
+        }
+    }
+
+    Err("could not compose a sentence out of the words you know".to_string())
+}
+
+// Ask for the translation of the given `sentence`, and then show the meaning
+// of each of its words in the given `locale` so the user can check the answer.
+// Returns false if the user aborted.
+fn run_sentence(sentence: &Sentence, latin: &str, locale: &Locale) -> bool {
+    println!("Sentence: {latin}");
+
+    if Text::new(format!("Translation ({locale}):").as_str())
+        .ask()
+        .is_err()
+    {
+        return false;
+    }
+
+    for word in sentence.words() {
+        match translation_for(word, locale) {
+            Some((used, tr)) if used != *locale => println!(
+                "\x1b[92m  {}: {} ({used})\x1b[0m",
+                word.enunciated,
+                tr.as_str().unwrap_or("")
+            ),
+            Some((_, tr)) => println!(
+                "\x1b[92m  {}: {}\x1b[0m",
+                word.enunciated,
+                tr.as_str().unwrap_or("")
+            ),
+            None => println!("\x1b[92m  {}: \x1b[0m", word.enunciated),
+        }
+    }
+
+    true
+}
+
+// Run a session where the user has to translate sentences composed out of the
+// words that are already known.
+fn run_sentences(locale: &Locale, limits: &mut SessionLimits) -> bool {
+    for _ in 0..SENTENCES {
+        let (sentence, latin) = match random_sentence() {
+            Ok(res) => res,
+            Err(e) => {
+                println!("practice: {e}");
+                return true;
+            }
+        };
+        if !run_sentence(&sentence, &latin, locale) || !keep_going(limits) {
+            return false;
+        }
+    }
+
+    true
+}
+
+// Ask for the form of `pronoun` which replaces the given `phrase`, where
+// `substitutions` are all the valid answers. Returns false if the user aborted.
+fn ask_for_substitution(
+    phrase: &str,
+    pronoun: &Word,
+    substitutions: &[Substitution],
+    strictness: MacronStrictness,
+) -> bool {
+    let Ok(raw) = Text::new(format!("Replace '{phrase}' with '{}':", pronoun.enunciated).as_str())
+        .with_help_message("Mind the case, the number and the gender of the phrase")
+        .ask()
+    else {
+        return false;
+    };
+
+    let solutions = substitutions
+        .iter()
+        .map(|sub| format!("{} ({} {})", sub.forms.join("/"), sub.case, sub.number))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if substitutions.iter().any(|sub| {
+        sub.forms
+            .iter()
+            .any(|form| matches_form(&raw, form, strictness))
+    }) {
+        println!("\x1b[92m✓ {solutions}\x1b[0m");
+    } else {
+        println!("\x1b[91m❌{solutions}\x1b[0m");
+    }
+
+    true
+}
+
+// Run a session where the user has to replace noun phrases composed out of the
+// words that are already known with the right form of a pronoun.
+fn run_substitutions(options: &SessionOptions, limits: &mut SessionLimits) -> bool {
+    let strictness = options.strictness();
+    let (nouns, adjectives) = match (
+        select_known_words(Category::Noun, 20),
+        select_known_words(Category::Adjective, 10),
+    ) {
+        (Ok(nouns), Ok(adjectives)) => (nouns, adjectives),
+        (Err(e), _) | (_, Err(e)) => {
+            println!("error: practice: {e}");
+            return true;
+        }
+    };
+    let pronouns: Vec<Word> = SUBSTITUTE_PRONOUNS
+        .iter()
+        .filter_map(|enunciated| find_by(enunciated).ok())
+        .collect();
+    if pronouns.is_empty() {
+        println!("practice: none of these pronouns are in the database: {SUBSTITUTE_PRONOUNS:?}");
+        return true;
+    }
+    let mut rng = rand::rng();
+
+    for _ in 0..SUBSTITUTIONS {
+        let Some(phrase) = random_noun_phrase(&nouns, &adjectives, &mut rng) else {
+            println!("practice: you don't know enough nouns yet");
+            return true;
+        };
+        let (Some(case), Some(pronoun)) = (
+            SUBSTITUTION_CASES.choose(&mut rng),
+            pronouns.choose(&mut rng),
+        ) else {
+            return true;
+        };
+
+        // Some nouns might lack the picked form, so just move on to the next
+        // phrase.
+        let (Ok(latin), Ok(substitutions)) =
+            (phrase.decline(*case), phrase.substitutions(pronoun, *case))
+        else {
+            continue;
+        };
+        if substitutions.is_empty() {
+            continue;
+        }
+
+        if !ask_for_substitution(&latin, pronoun, &substitutions, strictness) || !keep_going(limits)
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+// Show one of the forms from the given `pair` and ask for its counterpart on
+// the other voice. Returns None if the user aborted, and whether the answer was
+// right alongside the answer itself otherwise.
+fn ask_for_voice(
+    word: &Word,
+    pair: &VoicePair,
+    to_passive: bool,
+    strictness: MacronStrictness,
+) -> Option<(bool, String)> {
+    let (from, to) = if to_passive { (0, 1) } else { (1, 0) };
+    let ordinal = ["1st", "2nd", "3rd"];
+    let number = match pair.number {
+        Number::Singular => "sg.",
+        Number::Plural => "pl.",
+    };
+
+    println!(
+        "{}: {} ({} {}, {} {number})",
+        word.enunciated,
+        pair.forms[from].join("/"),
+        pair.tense.to_lowercase(),
+        pair.mood.to_lowercase(),
+        ordinal[pair.person - 1]
+    );
+    let message = if to_passive { "Passive:" } else { "Active:" };
+    let answer = prompt_answer(message, None, word)?;
+
+    let found = pair.forms[to]
+        .iter()
+        .any(|form| matches_form(&answer, form, strictness));
+
+    if found {
+        println!("\x1b[92m✓ {}\x1b[0m", pair.forms[to].join("/"));
+    } else {
+        println!("\x1b[91m❌{}\x1b[0m", pair.forms[to].join("/"));
+    }
+    Some((found, answer.trim().to_string()))
+}
+
+// Ask for the gender of the given noun, and record the attempt apart from the
+// reviews of the word. Returns whether the user got it right, or None if the
+// user aborted.
+fn ask_for_gender(
+    word: &Word,
+    options: &SessionOptions,
+    summary: &mut SessionSummary,
+) -> Option<bool> {
+    println!("Noun: {}", word.enunciated);
+    print_notes_for(word, options);
+
+    summary.ask();
+    let given = Select::new("Gender:", DRILL_GENDERS.to_vec()).ask().ok()?;
+    let correct = record_answer(
+        word,
+        &given.to_string(),
+        is_right_gender(word.gender, given),
+        summary,
+    );
+    if correct {
+        println!("\x1b[92m✓ {}\x1b[0m", word.gender);
+    } else {
+        println!("\x1b[91m❌{}\x1b[0m", word.gender);
+    }
+
+    if let Err(e) = record_drill_attempt(
+        word,
+        Drill::Gender,
+        &word.gender.to_string(),
+        &given.to_string(),
+        correct,
+    ) {
+        println!("warning: practice: {e}");
+    }
+    Some(correct)
+}
+
+// Show the given adjective and ask for its comparative, superlative and adverb,
+// grading each of them on its own against the `related` words of the
+// adjective, or against their regular formation if there are none. Each answer
+// is recorded as an attempt on the comparison drill. Returns whether all of
+// them were right, or None if the user aborted.
+fn ask_for_comparison(
+    word: &Word,
+    related: &[Vec<Word>; 5],
+    options: &SessionOptions,
+    summary: &mut SessionSummary,
+) -> Option<bool> {
+    let strictness = options.strictness();
+    println!("Adjective: {}", word.enunciated);
+    print_notes_for(word, options);
+
+    let forms = [
+        (
+            "Comparative:",
+            comparative(word, &related[RelationKind::Comparative as usize - 1]),
+        ),
+        (
+            "Superlative:",
+            superlative(word, &related[RelationKind::Superlative as usize - 1]),
+        ),
+        (
+            "Adverb:",
+            adverb(word, &related[RelationKind::Adverb as usize - 1]),
+        ),
+    ];
+
+    let mut all = true;
+    for (label, expected) in forms {
+        // There is no regular adverb for adjectives outside of the first three
+        // declensions, so there is nothing to ask unless one was related.
+        if expected == "<unknown>" {
+            continue;
+        }
+
+        summary.ask();
+        let given = prompt_answer(label, None, word)?;
+        let right = expected
+            .split(';')
+            .any(|form| matches_enunciated(given.trim(), form.trim(), strictness));
+        let correct = record_answer(word, given.trim(), right, summary);
+        if correct {
+            println!("\x1b[92m✓ {expected}\x1b[0m");
+        } else {
+            println!("\x1b[91m❌{expected}\x1b[0m");
+            all = false;
+        }
+
+        if let Err(e) =
+            record_drill_attempt(word, Drill::Comparison, &expected, given.trim(), correct)
+        {
+            println!("warning: practice: {e}");
+        }
+    }
+    Some(all)
+}
+
+// Run the comparison drill for the given adjectives. Adjectives which cannot be
+// compared or which are comparatives or superlatives themselves are skipped.
+fn run_comparison_drill(
+    words: &[Word],
+    options: &SessionOptions,
+    limits: &mut SessionLimits,
+    summary: &mut SessionSummary,
+    pending: &mut Vec<Word>,
+) -> bool {
+    run_queue(words, limits, pending, |word| {
+        if !matches!(word.category, Category::Adjective)
+            || word.is_flag_set("notcomparable")
+            || word.is_flag_set("nonpositive")
+        {
+            return Some(false);
+        }
+        let related = match select_related_words(word) {
+            Ok(related) => related,
+            Err(e) => {
+                println!("warning: practice: {e}");
+                return Some(false);
+            }
+        };
+        ask_for_comparison(word, &related, options, summary).map(|_| true)
+    })
+}
+
+// Run the gender drill for the given nouns. Nouns without a gender are
+// skipped.
+fn run_gender_drill(
+    words: &[Word],
+    options: &SessionOptions,
+    limits: &mut SessionLimits,
+    summary: &mut SessionSummary,
+    pending: &mut Vec<Word>,
+) -> bool {
+    run_queue(words, limits, pending, |word| {
+        if matches!(word.gender, Gender::None) {
+            return Some(false);
+        }
+        ask_for_gender(word, options, summary).map(|_| true)
+    })
+}
+
+// Show the first principal part of the given verb and ask for the rest of them
+// one by one, grading each of them on its own as given by `options`. Returns whether all of them were right alongside the given answers,
+// or None if the user aborted.
+fn ask_for_principal_parts(word: &Word, options: &SessionOptions) -> Option<(bool, Vec<String>)> {
+    let strictness = options.strictness();
+    let parts = word.principal_parts();
+    println!("Verb: {}", parts[0]);
+    print_notes_for(word, options);
+
+    let mut correct = true;
+    let mut given = Vec::with_capacity(parts.len() - 1);
+    for (idx, part) in parts.iter().enumerate().skip(1) {
+        let answer = prompt_answer(format!("Part {}:", idx + 1).as_str(), None, word)?;
+        given.push(answer.trim().to_string());
+        if matches_form(&answer, part, strictness) {
+            println!("\x1b[92m✓ {part}\x1b[0m");
+        } else {
+            println!("\x1b[91m❌{part}\x1b[0m");
+            correct = false;
+        }
+    }
+
+    Some((correct, given))
+}
+
+// Run the principal parts drill for the given verbs. Verbs with a single
+// principal part are skipped, as there is nothing to ask for them.
+fn run_principal_parts(
+    words: &[Word],
+    options: &SessionOptions,
+    limits: &mut SessionLimits,
+    summary: &mut SessionSummary,
+    pending: &mut Vec<Word>,
+) -> bool {
+    run_queue(words, limits, pending, |word| {
+        if word.principal_parts().len() < 2 {
+            return Some(false);
+        }
+        summary.ask();
+        let (correct, given) = ask_for_principal_parts(word, options)?;
+        let correct = record_answer(word, &given.join(", "), correct, summary);
+        let _ = record_review(word, correct);
+        Some(true)
+    })
+}
+
+// Show the translation of the given `word` in the given `locale` and ask the
+// user for the word itself, and record the result. Returns false if the user
+// aborted.
+fn run_reverse_word(
+    word: &Word,
+    locale: &Locale,
+    options: &SessionOptions,
+    summary: &mut SessionSummary,
+) -> bool {
+    // As with 'run_word', words without a translation are skipped.
+    let Some((used, translation)) = translation_for(word, locale) else {
+        return true;
+    };
+    let tr = translation.as_str().unwrap_or("");
+    if tr.trim().is_empty() {
+        return true;
+    }
+
+    if used == *locale {
+        println!("Translation ({used}): {tr}");
+    } else {
+        println!("Translation ({used}, fallback): {tr}");
+    }
+    print_notes_for(word, options);
+
+    summary.ask();
+    let Some(answer) = prompt_answer("Latin:", None, word) else {
+        return false;
+    };
+    let found = record_answer(
+        word,
+        answer.trim(),
+        matches_enunciated(&answer, &word.enunciated, options.strictness()),
+        summary,
+    );
+    let _ = record_review(word, found);
+    if found {
+        println!("\x1b[92m✓ {}\x1b[0m", word.enunciated);
+    } else {
+        println!("\x1b[91m❌{}\x1b[0m", word.enunciated);
+    }
+
+    true
+}
+
+// Run the reverse quiz for all the given `words` (see 'run_reverse_word').
+fn run_reverse_words(
+    words: &[Word],
+    locale: &Locale,
+    options: &SessionOptions,
+    limits: &mut SessionLimits,
+    summary: &mut SessionSummary,
+    pending: &mut Vec<Word>,
+) -> bool {
+    run_queue(words, limits, pending, |word| {
+        run_reverse_word(word, locale, options, summary).then_some(true)
+    })
+}
+
+// Run a drill where the user has to turn finite forms of the given verbs from
+// one voice into the other. Verbs with a single voice are skipped.
+fn run_voice_drill(
+    words: &[Word],
+    options: &SessionOptions,
+    limits: &mut SessionLimits,
+    summary: &mut SessionSummary,
+    pending: &mut Vec<Word>,
+) -> bool {
+    let strictness = options.strictness();
+    let mut rng = rand::rng();
+
+    run_queue(words, limits, pending, |word| {
+        let pairs = match get_voice_pairs(word) {
+            Ok(pairs) => pairs,
+            Err(e) => {
+                println!("warning: practice: {e}");
+                return Some(false);
+            }
+        };
+        let Some(pair) = pairs.choose(&mut rng) else {
+            return Some(false);
+        };
+
+        summary.ask();
+        let (correct, given) = ask_for_voice(word, pair, rng.random_bool(0.5), strictness)?;
+        let correct = record_answer(word, &given, correct, summary);
+        let _ = record_review(word, correct);
+        Some(true)
+    })
+}
+
+// Returns at most `count` words for an exam, picked at random either from the
+// given `category` or from all of them, and filtered by the given `flags` and
+// `tags`.
+fn select_exam_words(
+    category: Option<Category>,
+    flags: &[String],
+    tags: &[String],
+    count: usize,
+) -> Result<Vec<Word>, String> {
+    let categories = match category {
+        Some(cat) => vec![cat],
+        None => vec![
+            Category::Noun,
+            Category::Adjective,
+            Category::Verb,
+            Category::Pronoun,
+            Category::Adverb,
+            Category::Preposition,
+            Category::Conjunction,
+        ],
+    };
+
+    let mut res = vec![];
+    for cat in categories {
+        res.append(&mut select_relevant_words(
+            cat,
+            flags,
+            tags,
+            count as isize,
+            Novelty::Any,
+        )?);
+    }
+    res.shuffle(&mut rand::rng());
+    res.truncate(count);
+    Ok(res)
+}
+
+// Take an exam of at most `count` words from the given `category`, `flags` and
+// `tags`, with `timed` seconds to answer each question if given. The result is
+// saved with a breakdown by category unless the user aborted it. Returns the
+// exit code for the command.
+fn take_exam(
+    category: Option<Category>,
+    flags: &[String],
+    tags: &[String],
+    count: usize,
+    timed: Option<u64>,
+    options: &SessionOptions,
+) -> i32 {
+    let words = match select_exam_words(category, flags, tags, count) {
+        Ok(words) => words,
+        Err(e) => {
+            println!("error: practice: {e}");
+            return 1;
+        }
+    };
+    if words.is_empty() {
+        println!("error: practice: there are no words for this exam");
+        return 1;
+    }
+    if words.len() < count {
+        println!(
+            "warning: practice: there are only {} available for this exam",
+            words_count(words.len())
+        );
+    }
+
+    let locale = current_locale();
+    let mut summary = match timed {
+        Some(seconds) => SessionSummary::timed(Duration::from_secs(seconds)),
+        None => SessionSummary::new(),
+    };
+    let mut questions = vec![];
+    let mut scores = vec![];
+    for word in &words {
+        let asked = summary.questions();
+        if !run_word(word, &locale, options, &mut summary) {
+            println!("\nExam aborted, nothing was saved.");
+            return 0;
+        }
+
+        // Words without a translation are skipped by 'run_word'.
+        if summary.questions() == asked {
+            continue;
+        }
+        let Some((_, translation)) = translation_for(word, &locale) else {
+            continue;
+        };
+        questions.push(Question {
+            word: word.clone(),
+            group: word.category.to_string(),
+            expected: translation.as_str().unwrap_or("").to_string(),
+        });
+        scores.push(if summary.answers()[asked].correct {
+            1.0
+        } else {
+            0.0
+        });
+        println!();
+    }
+
+    let mut material = vec!["practice".to_string()];
+    if let Some(cat) = category {
+        material.push(cat.to_string());
+    }
+    material.extend(tags.iter().cloned());
+    match save_exam(ExamSummary::new(&material.join(", "), &questions, &scores)) {
+        Ok(exam) => {
+            println!("{exam}");
+            0
+        }
+        Err(e) => {
+            println!("error: practice: {e}");
+            1
+        }
+    }
+}
+
+pub fn run(args: Vec<String>) {
+    let mut it = args.into_iter();
+    let mut modes: Vec<PracticeMode> = vec![];
+    let mut given: Vec<&str> = vec![];
+    let mut category = None;
     let mut kind: Option<ExerciseKind> = None;
-    let mut exercises_only = false;
-    let mut inflection_only = false;
     let mut endless = false;
+    let mut timed: Option<u64> = None;
+    let mut resume = false;
     let mut flags: Vec<String> = vec![];
     let mut tags: Vec<String> = vec![];
+    let mut enunciates: Vec<String> = vec![];
+    let mut novelty = Novelty::Any;
+    let mut options = SessionOptions::default();
+    let mut count: Option<usize> = None;
 
     while let Some(first) = it.next() {
         match first.as_str() {
@@ -570,6 +2646,7 @@ pub fn run(args: Vec<String>) {
                     ));
                     std::process::exit(1);
                 }
+                given.push("--category");
                 match it.next() {
                     Some(cat) => {
                         category = match cat.trim().to_lowercase().as_str() {
@@ -590,34 +2667,104 @@ pub fn run(args: Vec<String>) {
                     }
                 }
             }
-            "-e" | "--exercises" => {
-                exercises_only = true;
+            "-e" | "--exercises" => modes.push(PracticeMode::Exercises),
+            "-i" | "--inflection" => modes.push(PracticeMode::Inflection),
+            "-m" | "--mixed" => modes.push(PracticeMode::Mixed),
+            "-s" | "--sentences" => modes.push(PracticeMode::Sentences),
+            "-v" | "--voice" => modes.push(PracticeMode::Voice),
+            "-p" | "--pronouns" => modes.push(PracticeMode::Pronouns),
+            "-r" | "--reverse" => modes.push(PracticeMode::Reverse),
+            "--principal-parts" => modes.push(PracticeMode::PrincipalParts),
+            "--new-only" | "--review-only" => {
+                let (picked, option) = if first == "--new-only" {
+                    (Novelty::New, "--new-only")
+                } else {
+                    (Novelty::Review, "--review-only")
+                };
+                if novelty != Novelty::Any && novelty != picked {
+                    help(Some(
+                        "error: practice: '--new-only' and '--review-only' cannot be combined",
+                    ));
+                    std::process::exit(1);
+                }
+                novelty = picked;
+                given.push(option);
+            }
+            "--resume" => {
+                resume = true;
+                given.push("--resume");
             }
-            "-i" | "--inflection" => {
-                inflection_only = true;
+            "--strict" => {
+                options.strict = true;
+                given.push("--strict");
             }
+            "--exam" => {
+                options.exam = true;
+                given.push("--exam");
+            }
+            "--count" => match it.next().map(|n| n.trim().parse::<usize>()) {
+                Some(Ok(n)) if n > 0 => {
+                    count = Some(n);
+                    given.push("--count");
+                }
+                _ => {
+                    help(Some(
+                        "error: practice: you have to provide a positive number of questions for '--count'",
+                    ));
+                    std::process::exit(1);
+                }
+            },
+            "--timed" => match it.next().map(|n| n.trim().parse::<u64>()) {
+                Some(Ok(n)) if n > 0 => {
+                    timed = Some(n);
+                    given.push("--timed");
+                }
+                _ => {
+                    help(Some(
+                        "error: practice: you have to provide a positive number of seconds for '--timed'",
+                    ));
+                    std::process::exit(1);
+                }
+            },
+            "-d" | "--drill" => match it.next().as_deref().map(Drill::try_from) {
+                Some(Ok(name)) => modes.push(PracticeMode::Drill(name)),
+                Some(Err(e)) => {
+                    help(Some(format!("error: practice: {e}").as_str()));
+                    std::process::exit(1);
+                }
+                None => {
+                    help(Some("error: practice: you have to provide a drill"));
+                    std::process::exit(1);
+                }
+            },
             "--endless" => {
                 endless = true;
+                given.push("--endless");
             }
-            "-f" | "--flag" => match it.next() {
-                Some(flag) => {
-                    if is_valid_word_flag(flag.as_str()) {
-                        if flags.iter().any(|s| s.as_str() == flag) {
+            "-f" | "--flag" | "--flags" => match it.next() {
+                Some(names) => {
+                    given.push("--flags");
+                    for flag in names.split(',').map(|name| name.trim().to_string()) {
+                        if flag.is_empty() {
+                            continue;
+                        }
+                        if !is_valid_word_flag(flag.as_str()) {
+                            let supported = BOOLEAN_FLAGS.join(", ");
+                            help(Some(
+                                format!(
+                                    "error: practice: unknown flag value '{flag}'. You have to pick between: {supported}"
+                                )
+                                .as_str(),
+                            ));
+                            std::process::exit(1);
+                        }
+                        if flags.contains(&flag) {
                             println!(
                                 "warning: practice: flag '{flag}' was provided multiple times"
                             );
                         } else {
                             flags.push(flag);
                         }
-                    } else {
-                        let supported = BOOLEAN_FLAGS.join(", ");
-                        help(Some(
-                            format!(
-                                "error: practice: unknown flag value '{flag}'. You have to pick between: {supported}"
-                            )
-                                .as_str(),
-                        ));
-                        std::process::exit(1);
                     }
                 }
                 None => {
@@ -634,6 +2781,7 @@ pub fn run(args: Vec<String>) {
                     ));
                     std::process::exit(1);
                 }
+                given.push("--kind");
                 match it.next() {
                     Some(k) => {
                         kind = match k.trim().to_lowercase().as_str().try_into() {
@@ -647,14 +2795,42 @@ pub fn run(args: Vec<String>) {
                     }
                 }
             }
-            "-t" | "--tag" => match it.next() {
+            "-l" | "--lesson" => match it.next() {
+                Some(name) if !name.trim().is_empty() => match find_lesson(&name) {
+                    Ok(lesson) => modes.push(PracticeMode::Lesson(lesson)),
+                    Err(e) => {
+                        println!("error: practice: {e}");
+                        std::process::exit(1);
+                    }
+                },
+                _ => {
+                    help(Some("error: practice: you have to provide a lesson"));
+                    std::process::exit(1);
+                }
+            },
+            "-w" | "--word" => match it.next() {
+                Some(enunciated) if !enunciated.trim().is_empty() => {
+                    enunciates.push(enunciated.trim().to_string());
+                    given.push("--word");
+                }
+                _ => {
+                    help(Some("error: practice: you have to provide a word"));
+                    std::process::exit(1);
+                }
+            },
+            "-t" | "--tag" | "--tags" => match it.next() {
                 Some(t) => {
-                    let name = t.trim().to_string();
-                    if let Ok(results) = select_tag_names(&Some(name.clone())) {
-                        if results.is_empty() {
-                            println!("warning: practice: the tag '{}' does not exist.", name);
-                        } else {
-                            tags.push(name)
+                    given.push("--tags");
+                    for name in t.split(',').map(|name| name.trim().to_string()) {
+                        if name.is_empty() {
+                            continue;
+                        }
+                        if let Ok(results) = select_tag_names(&Some(name.clone())) {
+                            if results.is_empty() {
+                                println!("warning: practice: the tag '{}' does not exist.", name);
+                            } else {
+                                tags.push(name)
+                            }
                         }
                     }
                 }
@@ -672,55 +2848,412 @@ pub fn run(args: Vec<String>) {
         }
     }
 
+    // Practicing is all about answering prompts, so bail out early instead of
+    // garbling the output of whoever is reading from us.
+    if !can_prompt() {
+        println!("error: practice: this command needs an interactive terminal");
+        std::process::exit(1);
+    }
+
+    if let Err(e) = check_options(&modes, &given) {
+        help(Some(format!("error: practice: {e}").as_str()));
+        std::process::exit(1);
+    }
+    let mut mode = modes.pop().unwrap_or_default();
+    if let (Some(only), Some(cat)) = (mode.category(), category) {
+        if !mode.allows(cat) {
+            help(Some(
+                format!(
+                    "error: practice: {} can only be used with {only}s",
+                    mode.title()
+                )
+                .as_str(),
+            ));
+            std::process::exit(1);
+        }
+    }
+
+    if options.exam {
+        std::process::exit(take_exam(
+            category,
+            &flags,
+            &tags,
+            count.unwrap_or(DEFAULT_EXAM_COUNT),
+            timed,
+            &options,
+        ));
+    }
+
+    // Look up the words given through '--word', making sure that they fit the
+    // selected mode.
+    let mut chosen: Vec<Word> = vec![];
+    for enunciated in enunciates {
+        let word = match find_by_normalized(&enunciated) {
+            Ok(Some(word)) => word,
+            Ok(None) => {
+                println!("error: practice: the word '{enunciated}' does not exist");
+                std::process::exit(1);
+            }
+            Err(e) => {
+                println!("error: practice: {e}");
+                std::process::exit(1);
+            }
+        };
+        if let Some(only) = mode.category().filter(|_| !mode.allows(word.category)) {
+            let article = if matches!(only, Category::Adjective) {
+                "an"
+            } else {
+                "a"
+            };
+            println!(
+                "error: practice: {} can only be used with {only}s, but '{}' is not {article} {only}",
+                mode.title(),
+                word.enunciated
+            );
+            std::process::exit(1);
+        }
+        if chosen.iter().any(|w| w.id == word.id) {
+            println!(
+                "warning: practice: the word '{}' was provided multiple times",
+                word.enunciated
+            );
+        } else {
+            chosen.push(word);
+        }
+    }
+
+    // Remind the user of whatever was noted down on the last session.
+    if let Ok(notes) = select_last_session_notes() {
+        if !notes.is_empty() {
+            println!("Notes from your last session:");
+            for note in notes {
+                println!("- {note}");
+            }
+            println!();
+        }
+    }
+
+    match count_due_words() {
+        Ok(0) => {}
+        Ok(1) => println!("You have 1 review due today.\n"),
+        Ok(due) => println!("You have {due} reviews due today.\n"),
+        Err(e) => println!("warning: practice: {e}"),
+    }
+
+    // Pick up the words from the last interrupted session, in the same mode
+    // and with the same filters.
+    let mut resumed = None;
+    if resume {
+        match take_pending_session() {
+            Ok(Some(session)) => {
+                mode = match PracticeMode::try_from(session.kind.as_str()) {
+                    Ok(mode) => mode,
+                    Err(e) => {
+                        println!("error: practice: {e}");
+                        std::process::exit(1);
+                    }
+                };
+                category = session.filters.category;
+                flags = session.filters.flags;
+                tags = session.filters.tags;
+                println!(
+                    "Resuming your last session with {} left.\n",
+                    words_count(session.words.len())
+                );
+                resumed = Some(session.words);
+            }
+            Ok(None) => {
+                println!("error: practice: there is no interrupted session to resume");
+                std::process::exit(1);
+            }
+            Err(e) => {
+                println!("error: practice: {e}");
+                std::process::exit(1);
+            }
+        }
+    }
+
     let locale = current_locale();
+    let mut limits = SessionLimits::from(&configuration());
+    let mut pending = vec![];
+    let mut new_left = match novelty {
+        Novelty::Review => None,
+        _ => configuration().max_new_words,
+    };
+    let mut summary = match timed {
+        Some(seconds) => {
+            println!("You have {seconds}s to answer each question.\n");
+            SessionSummary::timed(Duration::from_secs(seconds))
+        }
+        None => SessionSummary::new(),
+    };
 
     loop {
-        // Select the words depending on the selected category, flags, etc.
-        let words = match category {
-            Some(cat) => select_relevant_words(cat, &flags, &tags, 15),
-            None => select_general_words(&flags, &tags),
-        };
+        // Words given through '--word' are gone through on each round, just
+        // like the ones from an interrupted session.
+        if !chosen.is_empty() && resumed.is_none() {
+            resumed = Some(chosen.clone());
+        }
 
-        if !exercises_only {
-            if let Ok(mut list) = words {
-                if inflection_only {
-                    // If the '-i/--inflection' flag is passed, then don't
-                    // discard the current selection, as that might be all of
-                    // them when picking up a short category like pronouns.
-                    list = vec![];
-                } else if !run_words(&list, &locale) {
-                    break;
+        // There is nothing else to do once all the new words which were
+        // allowed for this session have been introduced.
+        if novelty == Novelty::New && new_left == Some(0) {
+            println!("You have been introduced to all the new words for this session.");
+            break;
+        }
+
+        let ok = match &mode {
+            PracticeMode::Lesson(lesson) => study_lesson(
+                lesson,
+                &locale,
+                &options,
+                &mut limits,
+                &mut summary,
+                &mut pending,
+            ),
+            PracticeMode::Pronouns => run_substitutions(&options, &mut limits),
+            PracticeMode::Sentences => run_sentences(&locale, &mut limits),
+            PracticeMode::Reverse => {
+                let words = words_for_round(
+                    &mut resumed,
+                    category,
+                    &flags,
+                    &tags,
+                    novelty,
+                    &mut new_left,
+                );
+                run_reverse_words(
+                    &words,
+                    &locale,
+                    &options,
+                    &mut limits,
+                    &mut summary,
+                    &mut pending,
+                )
+            }
+            PracticeMode::Drill(Drill::Gender) => {
+                let words = words_for_round(
+                    &mut resumed,
+                    Some(Category::Noun),
+                    &flags,
+                    &tags,
+                    novelty,
+                    &mut new_left,
+                );
+                run_gender_drill(&words, &options, &mut limits, &mut summary, &mut pending)
+            }
+            PracticeMode::Drill(Drill::Comparison) => {
+                let words = words_for_round(
+                    &mut resumed,
+                    Some(Category::Adjective),
+                    &flags,
+                    &tags,
+                    novelty,
+                    &mut new_left,
+                );
+                run_comparison_drill(&words, &options, &mut limits, &mut summary, &mut pending)
+            }
+            PracticeMode::PrincipalParts => {
+                let words = words_for_round(
+                    &mut resumed,
+                    Some(Category::Verb),
+                    &flags,
+                    &tags,
+                    novelty,
+                    &mut new_left,
+                );
+                run_principal_parts(&words, &options, &mut limits, &mut summary, &mut pending)
+            }
+            PracticeMode::Voice => {
+                let words = words_for_round(
+                    &mut resumed,
+                    Some(Category::Verb),
+                    &flags,
+                    &tags,
+                    novelty,
+                    &mut new_left,
+                );
+                run_voice_drill(&words, &options, &mut limits, &mut summary, &mut pending)
+            }
+            PracticeMode::Mixed => {
+                let words = select_words(category, &flags, &tags, novelty, &mut new_left);
+                let exercises = select_relevant_exercises(kind, MIXED_EXERCISES as isize);
+
+                match (words, exercises) {
+                    (Ok(words), Ok(exercises)) => run_mixed(
+                        words,
+                        exercises,
+                        &locale,
+                        &options,
+                        &mut limits,
+                        &mut summary,
+                    ),
+                    (Err(e), _) | (_, Err(e)) => {
+                        println!("error: practice: {e}");
+                        std::process::exit(1);
+                    }
                 }
+            }
+            PracticeMode::Translation | PracticeMode::Exercises | PracticeMode::Inflection => {
+                let exercises_only = matches!(mode, PracticeMode::Exercises);
+                let inflection_only = matches!(mode, PracticeMode::Inflection);
 
-                let cats = match category {
-                    Some(cat) => vec![cat],
-                    None => vec![
-                        Category::Noun,
-                        Category::Adjective,
-                        Category::Verb,
-                        Category::Pronoun,
-                    ],
+                // Select the words depending on the selected category, flags,
+                // etc., unless an interrupted session is being resumed.
+                let words = match (resumed.take(), category) {
+                    (Some(words), _) => Ok(words),
+                    (None, category) => {
+                        select_words(category, &flags, &tags, novelty, &mut new_left)
+                    }
                 };
-                if let Ok(words_to_inflect) = select_words_except(&list, &cats, &flags, &tags) {
-                    if !run_inflect_words(&words_to_inflect, &locale) {
-                        break;
+
+                if !exercises_only {
+                    if let Ok(mut list) = words {
+                        if inflection_only {
+                            // If the '-i/--inflection' flag is passed, then
+                            // don't discard the current selection, as that
+                            // might be all of them when picking up a short
+                            // category like pronouns.
+                            list = vec![];
+                        } else if !run_words(
+                            &list,
+                            &locale,
+                            &options,
+                            &mut limits,
+                            &mut summary,
+                            &mut pending,
+                        ) {
+                            break;
+                        }
+
+                        // Sessions on the words given through '--word' are
+                        // only about them.
+                        if !chosen.is_empty() {
+                            if !endless {
+                                break;
+                            }
+                            continue;
+                        }
+
+                        let cats = match category {
+                            Some(cat) => vec![cat],
+                            None => vec![
+                                Category::Noun,
+                                Category::Adjective,
+                                Category::Verb,
+                                Category::Pronoun,
+                            ],
+                        };
+                        if let Ok(words_to_inflect) =
+                            select_words_except(&list, &cats, &flags, &tags, novelty)
+                                .and_then(|words| cap_new_words(words, &mut new_left))
+                        {
+                            if !run_inflect_words(&words_to_inflect, &locale, &options, &mut limits)
+                            {
+                                break;
+                            }
+                        }
                     }
                 }
+
+                inflection_only
+                    || select_relevant_exercises(kind, if exercises_only { 5 } else { 1 })
+                        .map_or(true, |exercises| {
+                            run_exercises(exercises, &options, &mut limits)
+                        })
             }
+        };
+        if !ok || !endless {
+            break;
         }
+    }
 
-        if !inflection_only {
-            if let Ok(exercises) =
-                select_relevant_exercises(kind, if exercises_only { 5 } else { 1 })
-            {
-                if !run_exercises(exercises) {
-                    break;
-                }
-            }
+    print_summary(&summary);
+
+    let kind = mode.kind();
+    let filters = SessionFilters {
+        category,
+        flags,
+        tags,
+    };
+    if let Err(e) = save_session(&summary, &kind, &filters) {
+        println!("warning: practice: {e}");
+    }
+
+    // Keep whatever was left so it can be resumed later on.
+    if !pending.is_empty() {
+        match save_pending_session(&kind, &filters, &pending) {
+            Ok(_) => println!(
+                "\nYou left {} for later. Run 'mihi practice --resume' to pick up where you left off.",
+                words_count(pending.len())
+            ),
+            Err(e) => println!("warning: practice: {e}"),
         }
+    }
+}
 
-        if !endless {
-            break;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strict_and_exam_options_grade_strictly() {
+        let strict = SessionOptions {
+            strict: true,
+            ..Default::default()
+        };
+        let exam = SessionOptions {
+            exam: true,
+            ..Default::default()
+        };
+
+        assert!(matches!(strict.strictness(), MacronStrictness::Strict));
+        assert!(matches!(exam.strictness(), MacronStrictness::Strict));
+    }
+
+    #[test]
+    fn same_answer_ignores_whitespace() {
+        let given = "rosa\nrosae ".to_string();
+        let expected = "rosa rosae".to_string();
+
+        assert!(same_answer(&given, &expected, MacronStrictness::Strict));
+    }
+
+    #[test]
+    fn practice_modes_cannot_be_combined() {
+        assert!(check_options(&[PracticeMode::Reverse], &["--category", "--endless"]).is_ok());
+        assert_eq!(
+            check_options(&[PracticeMode::Exercises, PracticeMode::Inflection], &[]),
+            Err("'--exercises' cannot be combined with '--inflection'".to_string())
+        );
+        assert_eq!(
+            check_options(
+                &[PracticeMode::Drill(Drill::Gender), PracticeMode::Voice],
+                &[]
+            ),
+            Err("'--drill' cannot be combined with '--voice'".to_string())
+        );
+    }
+
+    #[test]
+    fn restricted_and_conflicting_options() {
+        for modes in [vec![], vec![PracticeMode::Reverse]] {
+            assert_eq!(
+                check_options(&modes, &["--tags", "--resume"]),
+                Err(
+                    "'--resume' can only be combined with '--endless', '--strict' and '--timed'"
+                        .to_string()
+                )
+            );
         }
+        assert_eq!(
+            check_options(&[PracticeMode::Mixed], &["--word"]),
+            Err("'--word' cannot be combined with '--mixed'".to_string())
+        );
+        assert_eq!(
+            check_options(&[], &["--count"]),
+            Err("'--count' can only be used with '--exam'".to_string())
+        );
+        assert!(check_options(&[], &["--exam", "--count", "--strict"]).is_ok());
     }
 }
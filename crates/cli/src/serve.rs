@@ -0,0 +1,411 @@
+use crate::locale::current_locale;
+use crate::words::get_initial_guess;
+use mihi::inflection::get_all_forms;
+use mihi::tag::select_tags_for;
+use mihi::word::{
+    estimate_difficulty, find_by, find_by_normalized, select_enunciated, strip_macrons, Word,
+};
+use mihi::writer::Writer;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{stdin, BufRead};
+
+// Error codes as defined by the JSON-RPC 2.0 specification, plus a generic
+// one for errors coming from the application itself.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const APPLICATION_ERROR: i64 = -32000;
+
+// Show the help message.
+fn help(msg: Option<&str>) {
+    if let Some(msg) = msg {
+        println!("{}.\n", msg);
+    }
+
+    println!("mihi serve: Answer queries from other programs (e.g. editor plugins).\n");
+    println!("usage: mihi serve [OPTIONS] --stdio\n");
+
+    println!("Requests are JSON-RPC 2.0 objects, one per line, and responses are given");
+    println!("the same way. The supported methods are:\n");
+    println!("   lookup\t\tWords which have the given form: {{\"form\": \"amābat\"}}.");
+    println!(
+        "   summary\t\tSummary for a word: {{\"enunciated\": \"amō, amāre, amāvī, amātum\"}}."
+    );
+    println!("   add\t\t\tAdd a regular noun: {{\"enunciated\": \"rosa, rosae\", \"translation\": \"rose\", \"tag\": \"Ch. 1\"}}.");
+    println!("   review\t\tRecord a review of a word: {{\"enunciated\": \"rosa, rosae\", \"correct\": true}}.");
+
+    println!("\nOptions:");
+    println!("   -h, --help\t\tPrint this message.");
+    println!("   --stdio\t\tRead requests from stdin and write responses to stdout.");
+}
+
+// Returns the key to be used on the forms index for the given form.
+fn index_key(form: &str) -> String {
+    strip_macrons(form.trim()).to_lowercase()
+}
+
+// Maps forms into the words they belong to. It's built lazily on the first
+// lookup, as it's costly to go through all the words of the database.
+#[derive(Default)]
+struct FormsIndex {
+    forms: Option<HashMap<String, Vec<String>>>,
+}
+
+impl FormsIndex {
+    fn lookup(&mut self, form: &str) -> Result<Vec<String>, String> {
+        if self.forms.is_none() {
+            self.forms = Some(HashMap::new());
+            for enunciated in select_enunciated(None, &[])? {
+                self.insert(&find_by(&enunciated)?)?;
+            }
+        }
+
+        Ok(self
+            .forms
+            .as_ref()
+            .and_then(|forms| forms.get(&index_key(form)))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    // Adds all the forms from the given `word` into the index, unless it has
+    // not been built yet.
+    fn insert(&mut self, word: &Word) -> Result<(), String> {
+        let Some(forms) = self.forms.as_mut() else {
+            return Ok(());
+        };
+
+        for form in get_all_forms(word)? {
+            let words = forms.entry(index_key(&form)).or_default();
+            if !words.contains(&word.enunciated) {
+                words.push(word.enunciated.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+// Returns the string parameter identified by `key`, or an error suitable for
+// a response.
+fn string_param<'a>(params: &'a Value, key: &str) -> Result<&'a str, (i64, String)> {
+    match params.get(key).and_then(|v| v.as_str()) {
+        Some(value) if !value.trim().is_empty() => Ok(value),
+        _ => Err((INVALID_PARAMS, format!("missing string parameter '{key}'"))),
+    }
+}
+
+fn summary(params: &Value) -> Result<Value, (i64, String)> {
+    let enunciated = string_param(params, "enunciated")?;
+
+    let word = match find_by(enunciated) {
+        Ok(word) => word,
+        Err(_) => match find_by_normalized(enunciated) {
+            Ok(Some(word)) => word,
+            Ok(None) => {
+                return Err((APPLICATION_ERROR, format!("'{enunciated}' not found")));
+            }
+            Err(e) => return Err((APPLICATION_ERROR, e)),
+        },
+    };
+    let tags = select_tags_for(Some(word.id)).map_err(|e| (APPLICATION_ERROR, e))?;
+
+    Ok(json!({
+        "enunciated": word.enunciated,
+        "category": word.category.to_string(),
+        "gender": word.gender.to_string(),
+        "translation": word.translation,
+        "tags": tags.into_iter().map(|t| t.name).collect::<Vec<_>>(),
+    }))
+}
+
+// Creates a regular word through the given `writer`, so it's never interleaved
+// with other writes.
+fn add(params: &Value, index: &mut FormsIndex, writer: &Writer) -> Result<Value, (i64, String)> {
+    let enunciated = string_param(params, "enunciated")?.trim();
+
+    match find_by_normalized(enunciated) {
+        Ok(Some(existing)) => {
+            return Err((
+                APPLICATION_ERROR,
+                format!("'{}' already exists", existing.enunciated),
+            ));
+        }
+        Ok(None) => {}
+        Err(e) => return Err((APPLICATION_ERROR, e)),
+    }
+
+    let mut word: Word = get_initial_guess(enunciated);
+    if word.declension.is_none() {
+        return Err((
+            APPLICATION_ERROR,
+            format!(
+                "could not guess how to inflect '{enunciated}'; use 'mihi words create' instead"
+            ),
+        ));
+    }
+    word.enunciated = enunciated.to_string();
+    if let Some(translation) = params.get("translation").and_then(|v| v.as_str()) {
+        word.translation = json!({ current_locale().to_code(): translation.trim() });
+    }
+    word.weight = estimate_difficulty(&word);
+
+    let tag = params
+        .get("tag")
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
+    let id = writer
+        .create_word(word.clone(), tag)
+        .map_err(|e| (APPLICATION_ERROR, e))?;
+    index.insert(&word).map_err(|e| (APPLICATION_ERROR, e))?;
+
+    Ok(json!({ "id": id, "enunciated": enunciated }))
+}
+
+// Records the review of a word through the given `writer`, so it's never
+// interleaved with other writes.
+fn review(params: &Value, writer: &Writer) -> Result<Value, (i64, String)> {
+    let enunciated = string_param(params, "enunciated")?;
+    let Some(correct) = params.get("correct").and_then(|v| v.as_bool()) else {
+        return Err((
+            INVALID_PARAMS,
+            "missing boolean parameter 'correct'".to_string(),
+        ));
+    };
+    let word = find_by(enunciated).map_err(|e| (APPLICATION_ERROR, e))?;
+
+    let (succeeded, steps) = writer
+        .review(word.id, correct)
+        .map_err(|e| (APPLICATION_ERROR, e))?;
+    Ok(json!({ "enunciated": word.enunciated, "succeeded": succeeded, "steps": steps }))
+}
+
+// Handles a single line from the input and returns the response to be given
+// back.
+fn handle(line: &str, index: &mut FormsIndex, writer: &Writer) -> Value {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(e) => return error_response(Value::Null, PARSE_ERROR, e.to_string()),
+    };
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let Some(method) = request.get("method").and_then(|m| m.as_str()) else {
+        return error_response(id, INVALID_REQUEST, "missing method".to_string());
+    };
+    let params = request.get("params").cloned().unwrap_or(json!({}));
+
+    let result = match method {
+        "lookup" => string_param(&params, "form").and_then(|form| {
+            index
+                .lookup(form)
+                .map(|words| json!({ "words": words }))
+                .map_err(|e| (APPLICATION_ERROR, e))
+        }),
+        "summary" => summary(&params),
+        "add" => add(&params, index, writer),
+        "review" => review(&params, writer),
+        _ => Err((METHOD_NOT_FOUND, format!("unknown method '{method}'"))),
+    };
+
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err((code, message)) => error_response(id, code, message),
+    }
+}
+
+fn error_response(id: Value, code: i64, message: String) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+pub fn run(args: Vec<String>) {
+    let mut stdio = false;
+
+    for arg in args {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                help(None);
+                std::process::exit(0);
+            }
+            "--stdio" => stdio = true,
+            _ => {
+                help(Some(format!("error: serve: unknown flag '{arg}'").as_str()));
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if !stdio {
+        help(Some(
+            "error: serve: you have to pass '--stdio', which is the only transport supported",
+        ));
+        std::process::exit(1);
+    }
+
+    let writer = match Writer::spawn() {
+        Ok(writer) => writer,
+        Err(e) => {
+            println!("error: serve: {e}");
+            std::process::exit(1);
+        }
+    };
+    let mut index = FormsIndex::default();
+    for line in stdin().lock().lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        println!("{}", handle(&line, &mut index, &writer));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mihi::get_connection;
+    use tempfile::TempDir;
+
+    // Returns a writer on a copy of the test database, so writes don't affect
+    // other tests. Reads still go to the test database, which only differs
+    // from the copy on whatever has been written through the writer.
+    fn writer() -> (TempDir, Writer) {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("db.sqlite3");
+        get_connection()
+            .unwrap()
+            .execute("VACUUM INTO ?1", [path.to_str().unwrap()])
+            .unwrap();
+        let writer = Writer::open(&path).unwrap();
+        (dir, writer)
+    }
+
+    // Returns the code of the error from the given `response`.
+    fn error_code(response: &Value) -> i64 {
+        response["error"]["code"].as_i64().unwrap()
+    }
+
+    #[test]
+    fn bad_requests() {
+        let (_dir, writer) = writer();
+        let mut index = FormsIndex::default();
+
+        let response = handle("{\"method\": ", &mut index, &writer);
+        assert_eq!(error_code(&response), PARSE_ERROR);
+        assert_eq!(response["id"], Value::Null);
+
+        let response = handle(r#"{"jsonrpc": "2.0", "id": 1}"#, &mut index, &writer);
+        assert_eq!(error_code(&response), INVALID_REQUEST);
+        assert_eq!(response["id"], 1);
+
+        let response = handle(r#"{"id": 2, "method": "nope"}"#, &mut index, &writer);
+        assert_eq!(error_code(&response), METHOD_NOT_FOUND);
+        assert_eq!(response["error"]["message"], "unknown method 'nope'");
+
+        for request in [
+            r#"{"id": 3, "method": "lookup"}"#,
+            r#"{"id": 3, "method": "lookup", "params": {"form": " "}}"#,
+            r#"{"id": 3, "method": "add", "params": {"translation": "rose"}}"#,
+            r#"{"id": 3, "method": "review", "params": {"enunciated": "rosa, rosae"}}"#,
+        ] {
+            let response = handle(request, &mut index, &writer);
+            assert_eq!(error_code(&response), INVALID_PARAMS, "{request}");
+            assert_eq!(response["id"], 3);
+        }
+    }
+
+    #[test]
+    fn lookup_and_summary() {
+        let (_dir, writer) = writer();
+        let mut index = FormsIndex::default();
+
+        let response = handle(
+            r#"{"id": 1, "method": "lookup", "params": {"form": "Rosām"}}"#,
+            &mut index,
+            &writer,
+        );
+        let words = response["result"]["words"].as_array().unwrap();
+        assert!(words.contains(&json!("rosa, rosae")), "{response}");
+
+        let response = handle(
+            r#"{"id": 2, "method": "summary", "params": {"enunciated": "Rosa,rosae"}}"#,
+            &mut index,
+            &writer,
+        );
+        assert_eq!(response["result"]["enunciated"], "rosa, rosae");
+        assert_eq!(response["result"]["category"], "noun");
+
+        let response = handle(
+            r#"{"id": 3, "method": "summary", "params": {"enunciated": "xyzzy"}}"#,
+            &mut index,
+            &writer,
+        );
+        assert_eq!(error_code(&response), APPLICATION_ERROR);
+    }
+
+    #[test]
+    fn add_and_review() {
+        let (dir, writer) = writer();
+        let mut index = FormsIndex::default();
+        let copy = rusqlite::Connection::open(dir.path().join("db.sqlite3")).unwrap();
+
+        // Build the index first, so adding a word has to update it.
+        let lookup = r#"{"id": 1, "method": "lookup", "params": {"form": "buccam"}}"#;
+        let response = handle(lookup, &mut index, &writer);
+        assert_eq!(response["result"]["words"], json!([]));
+
+        let response = handle(
+            r#"{"id": 2, "method": "add", "params": {"enunciated": "bucca, buccae", "translation": "cheek", "tag": "Ch. 1"}}"#,
+            &mut index,
+            &writer,
+        );
+        assert_eq!(response["result"]["enunciated"], "bucca, buccae");
+        let id = response["result"]["id"].as_i64().unwrap();
+        let (translation, tag): (String, String) = copy
+            .query_row(
+                "SELECT w.translation, t.name FROM words w \
+                 JOIN tag_associations ta ON ta.word_id = w.id \
+                 JOIN tags t ON t.id = ta.tag_id \
+                 WHERE w.id = ?1",
+                [id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert!(translation.contains("cheek"), "{translation}");
+        assert_eq!(tag, "Ch. 1");
+        let response = handle(lookup, &mut index, &writer);
+        assert_eq!(response["result"]["words"], json!(["bucca, buccae"]));
+
+        // Words which already exist or which cannot be guessed are rejected.
+        let response = handle(
+            r#"{"id": 3, "method": "add", "params": {"enunciated": "rosa, rosae"}}"#,
+            &mut index,
+            &writer,
+        );
+        assert_eq!(response["error"]["message"], "'rosa, rosae' already exists");
+        let response = handle(
+            r#"{"id": 4, "method": "add", "params": {"enunciated": "xyzzy"}}"#,
+            &mut index,
+            &writer,
+        );
+        assert_eq!(error_code(&response), APPLICATION_ERROR);
+
+        let word = find_by("rosa, rosae").unwrap();
+        let response = handle(
+            r#"{"id": 5, "method": "review", "params": {"enunciated": "rosa, rosae", "correct": true}}"#,
+            &mut index,
+            &writer,
+        );
+        assert_eq!(response["result"]["steps"], word.steps + 1, "{response}");
+        let steps: isize = copy
+            .query_row("SELECT steps FROM words WHERE id = ?1", [word.id], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(steps, word.steps + 1);
+    }
+}
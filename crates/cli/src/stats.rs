@@ -0,0 +1,450 @@
+use crate::interactive::print_lines;
+use crate::words::humanize_kind;
+use mihi::cfg::configuration;
+use mihi::drill::{select_drill_stats, Drill};
+use mihi::exam::select_exams;
+use mihi::history::{select_history_stats, select_word_accuracies, Accuracy};
+use mihi::word::{
+    count_suspended_words, count_words_by_category, count_words_by_kind, estimate_difficulty,
+    select_all_words,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn help() {
+    println!("mihi stats: Show statistics about the words in the database.\n");
+    println!("usage: mihi stats [OPTIONS] [<subcommand>]\n");
+
+    println!("Options:");
+    println!("   --anonymous\t\tOnly for 'export': required to confirm that the statistics are to be shared.");
+    println!("   --drill <NAME>\tShow how the attempts on the given drill went (see 'mihi practice --drill').");
+    println!("   --coverage\t\tShow how far the database is from the coverage goals set with 'mihi init --goal'.");
+    println!("   --csv\t\tOnly for 'history': print the statistics as CSV.");
+    println!("   -h, --help\t\tPrint this message.");
+    println!("   --json\t\tOnly for 'history': print the statistics as a JSON object.");
+
+    println!("\nSubcommands:");
+    println!("   exams\t\tShow the scores of the exams taken so far (see 'mihi practice --exam' and 'mihi exam').");
+    println!("   export\t\tPrint the difficulty statistics of each word as a JSON array. Only lemmas, the estimated difficulty and aggregates over practice sessions and drills (attempts, error rate and mean response time) are given: no translations, notes nor anything else you wrote.");
+    println!("   history\t\tShow how the practice sessions went: the hardest words, the mastery of each category and tag, the reviews per day and week alongside the average response time, and the current streak. The accuracy of every word is only given with '--json' or '--csv'.");
+}
+
+// Print how many words there are for each category.
+fn categories() -> i32 {
+    let counts = match count_words_by_category() {
+        Ok(counts) => counts,
+        Err(e) => {
+            println!("error: stats: {e}");
+            return 1;
+        }
+    };
+
+    let total: usize = counts.iter().map(|(_, count)| count).sum();
+    for (category, count) in counts {
+        println!("{category}: {count}");
+    }
+    println!("\nTotal: {total}");
+
+    match count_suspended_words() {
+        Ok(0) => {}
+        Ok(suspended) => println!("Suspended: {suspended}"),
+        Err(e) => println!("warning: stats: {e}"),
+    }
+
+    0
+}
+
+// Print the coverage goals from the configuration alongside how many words
+// are still missing to reach them.
+fn coverage() -> i32 {
+    let goals = configuration().coverage_goals;
+    if goals.is_empty() {
+        println!("stats: there are no coverage goals. Set them with 'mihi init --goal <KIND>=<N>'");
+        return 0;
+    }
+
+    let counts = match count_words_by_kind() {
+        Ok(counts) => counts,
+        Err(e) => {
+            println!("error: stats: {e}");
+            return 1;
+        }
+    };
+
+    let mut gaps = 0;
+    for (kind, target) in goals {
+        let count = counts
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map_or(0, |(_, count)| *count);
+        let name = match humanize_kind(&kind) {
+            Some(human) => format!("{kind} ({human})"),
+            None => kind,
+        };
+
+        if count >= target {
+            println!("\x1b[92m✓ {name}: {count}/{target}\x1b[0m");
+        } else {
+            gaps += 1;
+            println!(
+                "\x1b[91m❌{name}: {count}/{target} ({} missing)\x1b[0m",
+                target - count
+            );
+        }
+    }
+
+    if gaps > 0 {
+        println!(
+            "\nUse 'mihi words sample --fill-gaps --from <FILE>' to get candidates for these gaps."
+        );
+    }
+
+    0
+}
+
+// Print how the attempts on the given `drill` went, alongside the most common
+// errors.
+fn drill_stats(drill: Drill) -> i32 {
+    let stats = match select_drill_stats(drill) {
+        Ok(stats) => stats,
+        Err(e) => {
+            println!("error: stats: {e}");
+            return 1;
+        }
+    };
+    if stats.attempts == 0 {
+        println!("stats: there are no attempts on the '{drill}' drill yet. Try 'mihi practice --drill {drill}'");
+        return 0;
+    }
+
+    println!(
+        "Attempts: {}\nSucceeded: {} ({:.1}%)",
+        stats.attempts,
+        stats.succeeded,
+        stats.succeeded as f64 * 100.0 / stats.attempts as f64
+    );
+    if !stats.errors.is_empty() {
+        println!("\nErrors:");
+        for (expected, given, count) in stats.errors {
+            println!("   {expected} taken as {given}: {count}");
+        }
+    }
+
+    0
+}
+
+// Number of hardest words, days and weeks shown on the human-readable output of
+// 'history'.
+const HARDEST_WORDS: usize = 10;
+const LAST_DAYS: usize = 7;
+const LAST_WEEKS: usize = 8;
+
+// The output format for 'history'.
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Human,
+    Json,
+    Csv,
+}
+
+// Returns the given `accuracy` as a JSON object.
+fn accuracy_json(accuracy: &Accuracy) -> serde_json::Value {
+    json!({
+        "name": accuracy.name,
+        "asked": accuracy.asked,
+        "correct": accuracy.correct,
+        "accuracy": accuracy.percentage(),
+        "response_ms": accuracy.response.map(|response| response.as_millis() as u64),
+    })
+}
+
+// Returns the given CSV `field`, quoted if needed.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// Prints the given `accuracies` under the given `title`, skipping it
+// altogether if there is nothing to show.
+fn print_accuracies<'a>(title: &str, accuracies: impl IntoIterator<Item = &'a Accuracy>) {
+    let mut accuracies = accuracies.into_iter().peekable();
+    if accuracies.peek().is_none() {
+        return;
+    }
+
+    println!("\n{title}:");
+    for accuracy in accuracies {
+        let response = accuracy
+            .response
+            .map(|response| format!(", {:.1}s per answer", response.as_secs_f64()))
+            .unwrap_or_default();
+        println!(
+            "   {}: {}/{} ({:.1}%{response})",
+            accuracy.name,
+            accuracy.correct,
+            accuracy.asked,
+            accuracy.percentage()
+        );
+    }
+}
+
+// Print the statistics over the history of practice sessions in the given
+// `format`.
+fn history(format: Format) -> i32 {
+    let stats = match select_history_stats() {
+        Ok(stats) => stats,
+        Err(e) => {
+            println!("error: stats: {e}");
+            return 1;
+        }
+    };
+
+    match format {
+        Format::Json => {
+            let list = |accuracies: &[Accuracy]| -> Vec<_> {
+                accuracies.iter().map(accuracy_json).collect()
+            };
+            let hardest: Vec<_> = stats.hardest().into_iter().map(accuracy_json).collect();
+            println!(
+                "{}",
+                json!({
+                    "streak": stats.streak,
+                    "words": list(&stats.words),
+                    "hardest": hardest,
+                    "categories": list(&stats.categories),
+                    "tags": list(&stats.tags),
+                    "days": list(&stats.days),
+                    "weeks": list(&stats.weeks),
+                })
+            );
+        }
+        Format::Csv => {
+            let mut lines = vec![
+                "section,name,asked,correct,accuracy,response_seconds".to_string(),
+                format!("streak,days,{},,,", stats.streak),
+            ];
+            let sections = [
+                ("word", stats.words.iter().collect::<Vec<_>>()),
+                ("hardest", stats.hardest()),
+                ("category", stats.categories.iter().collect()),
+                ("tag", stats.tags.iter().collect()),
+                ("day", stats.days.iter().collect()),
+                ("week", stats.weeks.iter().collect()),
+            ];
+            for (section, accuracies) in sections {
+                for accuracy in accuracies {
+                    lines.push(format!(
+                        "{section},{},{},{},{:.1},{}",
+                        csv_field(&accuracy.name),
+                        accuracy.asked,
+                        accuracy.correct,
+                        accuracy.percentage(),
+                        accuracy
+                            .response
+                            .map(|response| format!("{:.1}", response.as_secs_f64()))
+                            .unwrap_or_default()
+                    ));
+                }
+            }
+            print_lines(&lines);
+        }
+        Format::Human => {
+            if stats.words.is_empty() {
+                println!("stats: there are no practice sessions yet. Try 'mihi practice'");
+                return 0;
+            }
+
+            match stats.streak {
+                1 => println!("Streak: 1 day"),
+                n => println!("Streak: {n} days"),
+            }
+            print_accuracies(
+                "Hardest words",
+                stats.hardest().into_iter().take(HARDEST_WORDS),
+            );
+            print_accuracies("Categories", &stats.categories);
+            print_accuracies("Tags", &stats.tags);
+            print_accuracies(
+                "Reviews per day",
+                stats
+                    .days
+                    .iter()
+                    .skip(stats.days.len().saturating_sub(LAST_DAYS)),
+            );
+            print_accuracies(
+                "Reviews per week",
+                stats
+                    .weeks
+                    .iter()
+                    .skip(stats.weeks.len().saturating_sub(LAST_WEEKS)),
+            );
+        }
+    }
+
+    0
+}
+
+// Print the summaries of all the exams taken so far.
+fn exams() -> i32 {
+    let exams = match select_exams() {
+        Ok(exams) => exams,
+        Err(e) => {
+            println!("error: stats: {e}");
+            return 1;
+        }
+    };
+
+    if exams.is_empty() {
+        println!("stats: there are no exams yet. Try 'mihi practice --exam'");
+        return 0;
+    }
+    for (idx, exam) in exams.iter().enumerate() {
+        if idx > 0 {
+            println!();
+        }
+        println!("{exam}");
+    }
+
+    0
+}
+
+// Print the difficulty statistics of all the words as a JSON array, leaving
+// out anything personal: only the lemma, its category, the estimated difficulty
+// and aggregates over the answers given on practice sessions and drills.
+fn export() -> i32 {
+    let words = match select_all_words() {
+        Ok(words) => words,
+        Err(e) => {
+            println!("error: stats: {e}");
+            return 1;
+        }
+    };
+    let accuracies: HashMap<String, Accuracy> = match select_word_accuracies() {
+        Ok(accuracies) => accuracies
+            .into_iter()
+            .map(|accuracy| (accuracy.name.clone(), accuracy))
+            .collect(),
+        Err(e) => {
+            println!("error: stats: {e}");
+            return 1;
+        }
+    };
+
+    let stats: Vec<_> = words
+        .iter()
+        .map(|word| {
+            let accuracy = accuracies
+                .get(&word.enunciated)
+                .cloned()
+                .unwrap_or_default();
+            let error_rate = (accuracy.asked > 0)
+                .then(|| (accuracy.asked - accuracy.correct) as f64 / accuracy.asked as f64);
+            json!({
+                "lemma": word.enunciated,
+                "category": word.category.to_string(),
+                "estimated_difficulty": estimate_difficulty(word),
+                "attempts": accuracy.asked,
+                "error_rate": error_rate,
+                "mean_response_ms": accuracy.response.map(|r| r.as_millis() as u64),
+            })
+        })
+        .collect();
+    println!("{}", json!(stats));
+
+    0
+}
+
+pub fn run(args: Vec<String>) {
+    let mut show_coverage = false;
+    let mut exporting = false;
+    let mut showing_history = false;
+    let mut showing_exams = false;
+    let mut format = Format::Human;
+    let mut anonymous = false;
+    let mut drill = None;
+    let mut it = args.into_iter();
+
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                help();
+                std::process::exit(0);
+            }
+            "--coverage" => {
+                show_coverage = true;
+            }
+            "--anonymous" => {
+                anonymous = true;
+            }
+            "--drill" => match it.next().as_deref().map(Drill::try_from) {
+                Some(Ok(name)) => drill = Some(name),
+                Some(Err(e)) => {
+                    println!("error: stats: {e}");
+                    std::process::exit(1);
+                }
+                None => {
+                    println!("error: stats: you have to provide a value for the '--drill' flag");
+                    std::process::exit(1);
+                }
+            },
+            "--json" | "--csv" => {
+                if format != Format::Human {
+                    println!("error: stats: '--json' and '--csv' cannot be combined");
+                    std::process::exit(1);
+                }
+                format = if arg == "--json" {
+                    Format::Json
+                } else {
+                    Format::Csv
+                };
+            }
+            "export" => {
+                exporting = true;
+            }
+            "history" => {
+                showing_history = true;
+            }
+            "exams" => {
+                showing_exams = true;
+            }
+            _ => {
+                println!("error: stats: unknown flag: '{}'", arg.as_str());
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if exporting {
+        // Sharing is opt-in, so make sure that the user knows what this is
+        // about before printing anything.
+        if !anonymous {
+            println!("error: stats: 'export' only supports anonymous statistics, pass '--anonymous' to confirm");
+            std::process::exit(1);
+        }
+        std::process::exit(export());
+    }
+    if anonymous {
+        println!("error: stats: '--anonymous' can only be used with 'export'");
+        std::process::exit(1);
+    }
+    if showing_history {
+        std::process::exit(history(format));
+    }
+    if format != Format::Human {
+        println!("error: stats: '--json' and '--csv' can only be used with 'history'");
+        std::process::exit(1);
+    }
+    if showing_exams {
+        std::process::exit(exams());
+    }
+    if let Some(drill) = drill {
+        std::process::exit(drill_stats(drill));
+    }
+    if show_coverage {
+        std::process::exit(coverage());
+    }
+    std::process::exit(categories());
+}
@@ -0,0 +1,128 @@
+use crate::export::{describe_archive, write_archive};
+use crate::import::read_archive;
+use crate::interactive::take_flag;
+use mihi::sync::{export_since_last_sync, import_changes, record_export};
+use std::vec::IntoIter;
+
+// Show the help message.
+fn help(msg: Option<&str>) {
+    if let Some(msg) = msg {
+        println!("{}.\n", msg);
+    }
+
+    println!("mihi sync: Keep the databases from two machines in sync through change files.\n");
+    println!("usage: mihi sync [OPTIONS] <subcommand> <file>\n");
+
+    println!("Change files have the same format as the ones from 'mihi export', but");
+    println!("they only have the tags, lessons, words and exercises which changed since");
+    println!("the last 'sync export'. On import, whichever side was updated last is");
+    println!("kept. Removals are synced as well, unless the other side changed the");
+    println!("removed item afterwards.\n");
+
+    println!("Options:");
+    println!("   --all\t\tOnly for 'export': write everything instead of only the changes since the last export.");
+    println!("   -h, --help\t\tPrint this message.");
+
+    println!("\nSubcommands:");
+    println!("   export\t\tWrite the changes since the last export into the given file.");
+    println!("   import\t\tMerge the changes from the given file into the database.");
+}
+
+// Returns the only argument left on `args`, which is the change file.
+fn file_name(mut args: IntoIter<String>) -> Result<String, i32> {
+    if args.len() != 1 {
+        help(Some("error: sync: you have to provide exactly one file"));
+        return Err(1);
+    }
+    Ok(args.next().unwrap_or_default())
+}
+
+fn export(args: IntoIter<String>) -> i32 {
+    let (all, args) = take_flag(args, &["--all"]);
+    let file = match file_name(args) {
+        Ok(file) => file,
+        Err(code) => return code,
+    };
+
+    let changes = match export_since_last_sync(all) {
+        Ok(changes) => changes,
+        Err(e) => {
+            println!("error: sync: {e}");
+            return 1;
+        }
+    };
+    if let Err(e) =
+        write_archive(&file, &changes.archive).and_then(|_| record_export(&changes, &file))
+    {
+        println!("error: sync: {e}");
+        return 1;
+    }
+
+    match &changes.since {
+        Some(since) => println!(
+            "Exported {} changed since {since} into '{file}'.",
+            describe_archive(&changes.archive)
+        ),
+        None => println!(
+            "Exported {} into '{file}'.",
+            describe_archive(&changes.archive)
+        ),
+    }
+    0
+}
+
+fn import(args: IntoIter<String>) -> i32 {
+    let file = match file_name(args) {
+        Ok(file) => file,
+        Err(code) => return code,
+    };
+
+    match read_archive(&file).and_then(|archive| import_changes(&archive)) {
+        Ok(summary) => {
+            println!("{summary}");
+            0
+        }
+        Err(e) => {
+            println!("error: sync: {e}");
+            1
+        }
+    }
+}
+
+pub fn run(args: Vec<String>) {
+    if args.is_empty() {
+        help(Some(
+            "error: sync: you have to provide at least a subcommand",
+        ));
+        std::process::exit(1);
+    }
+
+    let mut it = args.into_iter();
+
+    match it.next() {
+        Some(first) => match first.as_str() {
+            "-h" | "--help" => {
+                help(None);
+                std::process::exit(0);
+            }
+            "export" => {
+                std::process::exit(export(it));
+            }
+            "import" => {
+                std::process::exit(import(it));
+            }
+            _ => {
+                help(Some(
+                    format!("error: sync: unknown flag or command '{first}'").as_str(),
+                ));
+                std::process::exit(1);
+            }
+        },
+        None => {
+            help(Some(
+                "error: sync: you have to provide at least a subcommand",
+            ));
+            std::process::exit(1);
+        }
+    }
+}
@@ -1,5 +1,11 @@
-use inquire::{Confirm, Select};
-use mihi::tag::{create_tag, delete_tag, select_tag_names};
+use crate::interactive::{can_prompt, confirm, is_interactive, print_lines, take_flag, Ask};
+use inquire::{Select, Text};
+use mihi::history::{mastery, select_tag_stats};
+use mihi::tag::{
+    attach_tag_to_matching_words, create_tag, delete_tag, select_tag_names, select_tags_for, Tag,
+    TagColor, TAG_COLORS,
+};
+use mihi::word::Category;
 use std::vec::IntoIter;
 
 // Show the help message.
@@ -12,21 +18,133 @@ fn help(msg: Option<&str>) {
     println!("usage: mihi tags [OPTIONS] <subcommand>\n");
 
     println!("Options:");
+    println!("   -c, --category <CAT>\tOnly for 'attach': only tag words from the given category (e.g. 'noun').");
+    println!("   --color <COLOR>\tOnly for 'create': the color in which the tag is shown (one of: {}). It will be asked interactively otherwise.", TAG_COLORS.join(", "));
+    println!("   --description <TEXT>\tOnly for 'create': a description of the tag. It will be asked interactively otherwise.");
+    println!("   --dry-run\t\tOnly for 'attach': show the words which would be tagged without tagging them.");
+    println!("   -f, --filter <TEXT>\tOnly for 'attach': only tag words whose enunciate contains the given TEXT.");
     println!("   -h, --help\t\tPrint this message.");
+    println!("   --json\t\tOnly for 'ls': print the list as a JSON array.");
+    println!("   -y, --yes\t\tOnly for 'rm': do not ask for confirmation. Required when there is no terminal.");
 
     println!("\nSubcommands:");
-    println!("   create\t\tCreate a new tag.");
+    println!("   attach\t\tAttach the given tag to every word matching '--filter' and '--category' at once.");
+    println!("   create\t\tCreate a new tag. Tags can be nested by naming them after their parent (e.g. 'llpsi/cap09' is a child of 'llpsi', which is created if needed), and filtering by a tag also includes the words from its children.");
     println!("   ls\t\t\tList tags from the database.");
     println!("   rm\t\t\tRemove a tag from the database.");
+    println!("   show\t\t\tShow the words under a tag (including its children) with how often each of them was answered right, and the overall mastery of the tag.");
+}
+
+fn attach(mut args: IntoIter<String>) -> i32 {
+    let mut name = None;
+    let mut filter = None;
+    let mut category = None;
+    let mut dry_run = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-f" | "--filter" => match args.next() {
+                Some(f) => filter = Some(f),
+                None => {
+                    help(Some("error: tags: you have to provide a filter"));
+                    return 1;
+                }
+            },
+            "-c" | "--category" => {
+                let Some(cat) = args.next() else {
+                    help(Some("error: tags: you have to provide a category"));
+                    return 1;
+                };
+                let cat = cat.trim().to_lowercase();
+                match (1..=9)
+                    .filter_map(|value| Category::try_from(value).ok())
+                    .find(|c| c.to_string() == cat)
+                {
+                    Some(c) => category = Some(c),
+                    None => {
+                        help(Some(
+                            format!("error: tags: unknown category '{cat}'").as_str(),
+                        ));
+                        return 1;
+                    }
+                }
+            }
+            "--dry-run" => dry_run = true,
+            _ if arg.starts_with('-') => {
+                help(Some(format!("error: tags: unknown flag '{arg}'").as_str()));
+                return 1;
+            }
+            _ => {
+                if name.is_some() {
+                    help(Some(
+                        "error: tags: only one tag can be attached at a time. You might want to wrap the given arguments in quotes",
+                    ));
+                    return 1;
+                }
+                name = Some(arg);
+            }
+        }
+    }
+    let Some(name) = name else {
+        help(Some("error: tags: you have to provide the name of the tag"));
+        return 1;
+    };
+
+    let words = match attach_tag_to_matching_words(&name, filter.as_deref(), category, dry_run) {
+        Ok(words) => words,
+        Err(e) => {
+            println!("error: tags: {e}.");
+            return 1;
+        }
+    };
+
+    match (words.len(), dry_run) {
+        (0, _) => println!("No words to be tagged with '{name}'."),
+        (n, true) => println!("Would tag {n} words with '{name}':"),
+        (n, false) => println!("Tagged {n} words with '{name}':"),
+    }
+    for word in &words {
+        println!("   {word}");
+    }
+
+    0
 }
 
 fn create(mut args: IntoIter<String>) -> i32 {
+    let mut names = vec![];
+    let mut description = None;
+    let mut color = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--description" => match args.next() {
+                Some(text) => description = Some(text),
+                None => {
+                    help(Some("error: tags: you have to provide a description"));
+                    return 1;
+                }
+            },
+            "--color" => match args.next().as_deref().map(TagColor::try_from) {
+                Some(Ok(c)) => color = Some(c),
+                Some(Err(e)) => {
+                    println!("error: tags: {e}");
+                    return 1;
+                }
+                None => {
+                    help(Some("error: tags: you have to provide a color"));
+                    return 1;
+                }
+            },
+            _ => names.push(arg),
+        }
+    }
+
     // We expect exactly one argument, which is the name of the tag. Note that
     // this is wholly different to what's in for words/exercises, as the
     // expected workflow on those is different as well.
-    if args.len() != 1 {
+    if names.len() != 1 {
         let mut msg = "error: tags: you have to pass exactly one argument, which is the name of the tag to be created".to_string();
-        if args.len() > 1 {
+        if names.len() > 1 {
             msg.push_str(". You might want to wrap the given arguments in quotes");
         }
 
@@ -35,7 +153,7 @@ fn create(mut args: IntoIter<String>) -> i32 {
     }
 
     // Fetch the name and guarantee it's unique.
-    let name = args.next().unwrap_or("".to_string());
+    let name = names.pop().unwrap_or_default();
     if let Ok(tags) = select_tag_names(&Some(name.clone())) {
         for tag in tags {
             if tag == name {
@@ -45,14 +163,46 @@ fn create(mut args: IntoIter<String>) -> i32 {
         }
     }
 
-    if create_tag(&name).is_ok() {
-        0
-    } else {
-        1
+    // Ask for whatever metadata was not given through flags. Both are
+    // optional, so an empty answer leaves them unset.
+    if can_prompt() {
+        if description.is_none() {
+            description = Text::new("Description (optional):").ask().ok();
+        }
+        if color.is_none() {
+            let message = format!("Color (optional, one of: {}):", TAG_COLORS.join(", "));
+            let answer = Text::new(message.as_str()).ask().unwrap_or_default();
+            if !answer.trim().is_empty() {
+                match TagColor::try_from(answer.as_str()) {
+                    Ok(c) => color = Some(c),
+                    Err(e) => {
+                        println!("error: tags: {e}");
+                        return 1;
+                    }
+                }
+            }
+        }
+    }
+
+    match create_tag(&name, description.as_deref(), color) {
+        Ok(_) => 0,
+        Err(e) => {
+            println!("error: tags: {e}");
+            1
+        }
+    }
+}
+
+/// Returns the name of the given `tag` rendered in its color, if it has any.
+pub fn paint_tag(tag: &Tag) -> String {
+    match tag.color {
+        Some(color) => format!("{}{}\x1b[0m", color.ansi(), tag.name),
+        None => tag.name.clone(),
     }
 }
 
-fn ls(mut args: IntoIter<String>) -> i32 {
+fn ls(args: IntoIter<String>) -> i32 {
+    let (json, mut args) = take_flag(args, &["--json"]);
     if args.len() > 1 {
         help(Some("error: tags: too many filters"));
         return 1;
@@ -66,9 +216,11 @@ fn ls(mut args: IntoIter<String>) -> i32 {
         }
     };
 
-    for tag in tags {
-        println!("{tag}");
+    if json {
+        println!("{}", serde_json::json!(tags));
+        return 0;
     }
+    print_lines(&tags);
 
     0
 }
@@ -76,17 +228,26 @@ fn ls(mut args: IntoIter<String>) -> i32 {
 fn select_single_tag(search: Option<String>) -> Result<String, String> {
     let tags = select_tag_names(&search)?;
 
+    // A parent matches the search for all of its children, so an exact match
+    // is taken right away.
+    if let Some(exact) = tags.iter().find(|t| Some(*t) == search.as_ref()) {
+        return Ok(exact.to_owned());
+    }
+
     match tags.len() {
         0 => Err("not found".to_string()),
         1 => Ok(tags.first().unwrap().to_owned()),
-        _ => match Select::new("Which tag?", tags).with_page_size(20).prompt() {
+        n if !is_interactive() => Err(format!("{n} tags match, be more specific")),
+        _ => match Select::new("Which tag?", tags).with_page_size(20).ask() {
             Ok(choice) => Ok(choice),
             Err(_) => Err("abort!".to_string()),
         },
     }
 }
 
-fn rm(mut args: IntoIter<String>) -> i32 {
+fn rm(args: IntoIter<String>) -> i32 {
+    let (yes, mut args) = take_flag(args, &["-y", "--yes"]);
+
     // We expect exactly one argument, which is the name of the tag. Note that
     // this is wholly different to what's in for words/exercises, as the
     // expected workflow on those is different as well.
@@ -110,11 +271,10 @@ fn rm(mut args: IntoIter<String>) -> i32 {
             return 1;
         }
     };
-    let ans = Confirm::new(
+    let ans = confirm(
         format!("Do you really want to remove '{selection}' from the database?").as_str(),
-    )
-    .with_default(false)
-    .prompt();
+        yes,
+    );
 
     // We have a selected tag and the user confirmed its selection, go for it!
     match ans {
@@ -128,12 +288,81 @@ fn rm(mut args: IntoIter<String>) -> i32 {
         Ok(false) => {
             println!("Doing nothing...");
         }
-        Err(_) => return 1,
+        Err(e) => {
+            println!("error: tags: {e}.");
+            return 1;
+        }
     }
 
     0
 }
 
+fn show(mut args: IntoIter<String>) -> i32 {
+    if args.len() > 1 {
+        help(Some(
+            "error: tags: only one argument. You might want to wrap the given arguments in quotes",
+        ));
+        return 1;
+    }
+
+    let selection = match select_single_tag(args.next()) {
+        Ok(tag) => tag,
+        Err(e) => {
+            println!("error: tags: {e}.");
+            return 1;
+        }
+    };
+    let tag = match select_tags_for(None) {
+        Ok(tags) => tags.into_iter().find(|t| t.name == selection),
+        Err(e) => {
+            println!("error: tags: {e}.");
+            return 1;
+        }
+    };
+    let Some(tag) = tag else {
+        println!("error: tags: '{selection}' does not exist.");
+        return 1;
+    };
+    let words = match select_tag_stats(&tag.name) {
+        Ok(words) => words,
+        Err(e) => {
+            println!("error: tags: {e}.");
+            return 1;
+        }
+    };
+
+    match &tag.description {
+        Some(description) => println!("Tag: {} ({description})", paint_tag(&tag)),
+        None => println!("Tag: {}", paint_tag(&tag)),
+    }
+    if words.is_empty() {
+        println!("There are no words under this tag.");
+        return 0;
+    }
+
+    println!("\nWords:");
+    for word in &words {
+        if word.asked == 0 {
+            println!("   {}: never asked", word.name);
+        } else {
+            println!(
+                "   {}: {}/{} ({:.1}%)",
+                word.name,
+                word.correct,
+                word.asked,
+                word.percentage()
+            );
+        }
+    }
+    println!(
+        "\nMastery: {:.1}% of {} words",
+        mastery(&words),
+        words.len()
+    );
+
+    0
+}
+
 pub fn run(args: Vec<String>) {
     if args.is_empty() {
         help(Some(
@@ -150,6 +379,9 @@ pub fn run(args: Vec<String>) {
                 help(None);
                 std::process::exit(0);
             }
+            "attach" => {
+                std::process::exit(attach(it));
+            }
             "create" => {
                 std::process::exit(create(it));
             }
@@ -159,6 +391,9 @@ pub fn run(args: Vec<String>) {
             "rm" => {
                 std::process::exit(rm(it));
             }
+            "show" => {
+                std::process::exit(show(it));
+            }
             _ => {
                 help(Some(
                     format!("error: tags: unknown flag or command '{first}'").as_str(),
@@ -1,11 +1,23 @@
 use crate::inflection::print_full_inflection_for;
+use crate::interactive::{can_prompt, confirm, is_interactive, print_lines, take_flag, Ask};
 use crate::locale::current_locale;
-use std::io::{stdin, IsTerminal};
+use crate::tags::paint_tag;
+use std::io::{stdin, stdout, IsTerminal};
 
 use inquire::{Confirm, Editor, MultiSelect, Select, Text};
-use mihi::cfg::Language;
-use mihi::tag::{attach_tag_to_word, dettach_tags_from_word, select_tag_names, select_tags_for};
+use mihi::analysis::count_lemmas;
+use mihi::cfg::{configuration, Language};
+use mihi::exercise::{select_exercise_texts, select_exercises_for_word};
+use mihi::inflection::Number;
+use mihi::note::select_notes_for;
+use mihi::render::{render_word, synopsis_table, Format};
+use mihi::tag::{
+    attach_tag_to_word, dettach_tags_from_word, find_or_create_tag, select_tag_names,
+    select_tags_by_enunciated, select_tags_for,
+};
 use mihi::word::*;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
 use std::vec::IntoIter;
 
 static NEW_MESSAGE: &str = "New word";
@@ -29,6 +41,7 @@ static FLAGS_TEXT: &str = r#"# Write a JSON blob with the following allowed keys
 # nosupine:            Verb has no supine form.
 # noperfect:           Verb has no perfect forms.
 # nogerundive:         Verb has no gerundive.
+# nogerund:            Verb has no gerund.
 # impersonal:          Verb is impersonal (only third person available).
 # impersonalpassive:   Verb is impersonal only on its passive forms.
 # noimperative:        Verb has no imperative forms.
@@ -59,6 +72,25 @@ static FLAGS_TEXT: &str = r#"# Write a JSON blob with the following allowed keys
 #
 # That is, this word only has singular forms and the accusative one should be
 # '-im' instead of the regular form.
+#
+# Verbs follow the same idea, but forms are keyed by mood, voice, tense, person
+# and number instead. For example:
+#
+# {
+#   "adds": {
+#     "indicative": {
+#       "active": {
+#         "perfect": {
+#           "third": {
+#             "plural": ["ēre"]
+#           }
+#         }
+#       }
+#     }
+#   }
+# }
+#
+# That is, 'vīdēre' is also accepted besides 'vīdērunt'.
 "#;
 
 // Show the help message.
@@ -72,22 +104,37 @@ fn help(msg: Option<&str>) {
 
     println!("Options:");
     println!("   -h, --help\t\tPrint this message.");
-    println!("   -t, --tag <NAME>\tFilter words which match the given tag NAME. Multiple tags can be provided to match words with any of the tags provided. This will only be accounted in the 'ls' command.");
+    println!("   --group-by <FIELD>\tOnly for 'ls': group the words by one of: declension, conjugation, gender, category. Each group shows how many words it has.");
+    println!("   --json\t\tOnly for 'ls': print the list as a JSON array, or as a JSON object if '--group-by' was given.");
+    println!("   -y, --yes\t\tOnly for 'rm': do not ask for confirmation. Required when there is no terminal.");
+    println!("   --from <FILE>\tOnly for 'sample': the database from which candidates are picked (e.g. 'testdata/test.sqlite3').");
+    println!("   --fill-gaps\t\tOnly for 'sample': only pick words for the kinds which are below their coverage goal (see 'mihi stats --coverage').");
+    println!("   --format <FORMAT>\tOnly for 'table' and 'synopsis': one of: md, latex, html. Defaults to 'md'.");
+    println!("   --person <N>\t\tOnly for 'synopsis': the person, from 1 to 3. Defaults to 3.");
+    println!("   --number <NUMBER>\tOnly for 'synopsis': either 'sg' or 'pl'. Defaults to 'sg'.");
+    println!("   --on-conflict <POLICY>\tOnly for 'create' with piped input: what to do with words that already exist. One of: skip, theirs, merge. It will be asked interactively otherwise.");
+    println!("   --tag-prefix <PREFIX>\tOnly for 'create' with piped input: prefix for the tags created out of the chapter/source column.");
+    println!("   -t, --tag <NAME>\tFilter words which match the given tag NAME or any of its children (e.g. 'llpsi/cap09' for 'llpsi'). Multiple tags can be provided to match words with any of the tags provided. This will only be accounted in the 'ls' command.");
 
     println!("\nSubcommands:");
-    println!("   create\t\tCreate a new word. It accepts word enunciates given into a pipe (an enunciate per line), otherwise this command is interactive.");
+    println!("   create\t\tCreate a new word. It accepts word enunciates given into a pipe (an enunciate per line), otherwise this command is interactive. Piped lines may carry a chapter/source column separated by a tab or a semicolon, which is attached as a tag.");
     println!("   dup\t\t\tCreate a word which is an alternative of another one. Short version of 'rel' for alternative words.");
     println!("   edit\t\t\tEdit information from a word.");
     println!("   ls\t\t\tList the words from the database.");
+    println!("   occurrences\t\tCount how many times each word occurs in the exercises, and show the most frequent ones. See 'mihi init --reading-boost'.");
     println!("   poke\t\t\tUpdate the timestamp for a word.");
     println!("   rel\t\t\tEstablish a relationship between two words.");
     println!("   rm\t\t\tRemove a word from the database.");
+    println!("   sample\t\tSuggest words from another database which are not in ours. The output can be piped into 'mihi words create'.");
     println!("   show\t\t\tShow information from a word.");
+    println!("   suspend\t\tSuspend a word so it's no longer asked on practice sessions (e.g. words you will never forget like 'et'), without removing it. Running it again on a suspended word lifts its suspension.");
+    println!("   synopsis\t\tPrint the synopsis of a verb: the forms for a single person and number across all moods, tenses and voices.");
+    println!("   table\t\tPrint the inflection tables of a word in a format ready to be pasted into notes.");
 }
 
 // Given an enunciated value, try to guess a word from it. If that's not
 // possible then an empty word is given.
-fn get_initial_guess(value: &str) -> Word {
+pub fn get_initial_guess(value: &str) -> Word {
     let parts = value.trim().split(',').collect::<Vec<_>>();
 
     if parts.len() == 2 {
@@ -236,7 +283,7 @@ fn prompt_declension(cat: &Category, declension: Declension) -> Result<Declensio
 
     let Ok(result) = Select::new("Declension:", options)
         .with_starting_cursor(idx)
-        .prompt()
+        .ask()
     else {
         return Err("abort!".to_string());
     };
@@ -257,7 +304,7 @@ fn prompt_conjugation(conjugation: Conjugation) -> Result<Conjugation, String> {
 
     let Ok(result) = Select::new("Conjugation:", options)
         .with_starting_cursor(idx)
-        .prompt()
+        .ask()
     else {
         return Err("abort!".to_string());
     };
@@ -270,7 +317,7 @@ fn prompt_conjugation(conjugation: Conjugation) -> Result<Conjugation, String> {
 fn ask_for_word_based_on(enunciated: String, word: Word) -> Result<Word, String> {
     let Ok(particle) = Text::new("Particle:")
         .with_initial_value(&word.particle)
-        .prompt()
+        .ask()
     else {
         return Err("abort!".to_string());
     };
@@ -290,7 +337,7 @@ fn ask_for_word_based_on(enunciated: String, word: Word) -> Result<Word, String>
     ];
     let Ok(category) = Select::new("Category:", categories)
         .with_starting_cursor((word.category as isize).try_into().unwrap())
-        .prompt()
+        .ask()
     else {
         return Err("abort!".to_string());
     };
@@ -306,7 +353,7 @@ fn ask_for_word_based_on(enunciated: String, word: Word) -> Result<Word, String>
         Category::Noun => {
             match Select::new("Gender:", genders)
                 .with_starting_cursor((word.gender as isize).try_into().unwrap())
-                .prompt()
+                .ask()
             {
                 Ok(selection) => selection,
                 Err(_) => return Err("abort!".to_string()),
@@ -340,7 +387,7 @@ fn ask_for_word_based_on(enunciated: String, word: Word) -> Result<Word, String>
     let kind = match category {
         Category::Noun => {
             let options = match declension {
-                Some(Declension::First) => vec!["a"],
+                Some(Declension::First) => vec!["a", "greekas", "greekes", "greeke"],
                 Some(Declension::Second) => vec!["us", "um", "ius", "er/ir"],
                 Some(Declension::Third) => vec![
                     "is",
@@ -354,8 +401,10 @@ fn ask_for_word_based_on(enunciated: String, word: Word) -> Result<Word, String>
                     "sussuis",
                     "bosbovis",
                     "iuppiteriovis",
+                    "greeko",
+                    "greekthird",
                 ],
-                Some(Declension::Fourth) => vec!["fus"],
+                Some(Declension::Fourth) => vec!["fus", "domusdomus"],
                 Some(Declension::Fifth) => vec!["ies", "es"],
                 // NOTE: for the 'other' declension we only allow to enter
                 // 'indeclinable' words, as that's the only thing that can be
@@ -367,7 +416,7 @@ fn ask_for_word_based_on(enunciated: String, word: Word) -> Result<Word, String>
             if options.len() == 1 {
                 options.first().unwrap().to_string()
             } else {
-                match Select::new("Kind:", options).prompt() {
+                match Select::new("Kind:", options).ask() {
                     Ok(kind) => kind.to_string(),
                     Err(_) => return Err("abort!".to_string()),
                 }
@@ -378,7 +427,7 @@ fn ask_for_word_based_on(enunciated: String, word: Word) -> Result<Word, String>
                 Some(Declension::First) => vec!["us", "er/ir"],
                 _ => vec!["one", "onenonistem", "two", "three"],
             };
-            match Select::new("Kind:", options).prompt() {
+            match Select::new("Kind:", options).ask() {
                 Ok(kind) => kind.to_string(),
                 Err(_) => return Err("abort!".to_string()),
             }
@@ -389,7 +438,7 @@ fn ask_for_word_based_on(enunciated: String, word: Word) -> Result<Word, String>
                     "sum", "possum", "eo", "volo", "nolo", "malo", "fero", "facio", "do", "inquam",
                     "aio",
                 ];
-                match Select::new("Kind:", options).prompt() {
+                match Select::new("Kind:", options).ask() {
                     Ok(kind) => kind.to_string(),
                     Err(_) => return Err("abort!".to_string()),
                 }
@@ -404,7 +453,7 @@ fn ask_for_word_based_on(enunciated: String, word: Word) -> Result<Word, String>
         category,
         Category::Noun | Category::Adjective | Category::Verb
     ) {
-        let Ok(regular) = Confirm::new("Regular:").with_default(word.regular).prompt() else {
+        let Ok(regular) = Confirm::new("Regular:").with_default(word.regular).ask() else {
             return Err("abort!".to_string());
         };
         regular
@@ -413,10 +462,7 @@ fn ask_for_word_based_on(enunciated: String, word: Word) -> Result<Word, String>
     };
 
     let locative = if matches!(category, Category::Noun) {
-        let Ok(locative) = Confirm::new("Locative:")
-            .with_default(word.locative)
-            .prompt()
-        else {
+        let Ok(locative) = Confirm::new("Locative:").with_default(word.locative).ask() else {
             return Err("abort!".to_string());
         };
         locative
@@ -424,26 +470,11 @@ fn ask_for_word_based_on(enunciated: String, word: Word) -> Result<Word, String>
         false
     };
 
-    let Ok(raw_weight) = Text::new("Weight:")
-        .with_initial_value(word.weight.to_string().as_str())
-        .prompt()
-    else {
-        return Err("abort!".to_string());
-    };
-    let Ok(weight) = raw_weight.parse::<isize>() else {
-        return Err("bad value".to_string());
-    };
-    if weight > 10 {
-        return Err(format!(
-            "weight has to be an integer between 0 and 10, but {weight} was given"
-        ));
-    }
-
     let raw_flags = serde_json::to_string(&word.flags).unwrap();
 
     let Ok(flags) = Editor::new("Flags:")
         .with_predefined_text(format!("{FLAGS_TEXT}\n{raw_flags}").as_str())
-        .prompt()
+        .ask()
     else {
         return Err("abort!".to_string());
     };
@@ -451,18 +482,18 @@ fn ask_for_word_based_on(enunciated: String, word: Word) -> Result<Word, String>
 
     let Ok(translation_en) = Text::new("Translation (english):")
         .with_initial_value(get_translated(&word, "en").unwrap_or(&String::from("")))
-        .prompt()
+        .ask()
     else {
         return Err("abort!".to_string());
     };
     let Ok(translation_ca) = Text::new("Translation (catalan):")
         .with_initial_value(get_translated(&word, "ca").unwrap_or(&String::from("")))
-        .prompt()
+        .ask()
     else {
         return Err("abort!".to_string());
     };
 
-    Ok(Word {
+    let mut res = Word {
         id: word.id,
         enunciated,
         particle,
@@ -495,13 +526,36 @@ fn ask_for_word_based_on(enunciated: String, word: Word) -> Result<Word, String>
         flags: serde_json::from_str(&trimmed_flags).unwrap(),
         succeeded: 0,
         steps: 0,
-        weight,
-    })
+        weight: word.weight,
+    };
+
+    // The weight is asked last so new words can be given an estimation based
+    // on everything else as the initial value.
+    if res.id == 0 {
+        res.weight = estimate_difficulty(&res);
+    }
+    let Ok(raw_weight) = Text::new("Weight:")
+        .with_initial_value(res.weight.to_string().as_str())
+        .ask()
+    else {
+        return Err("abort!".to_string());
+    };
+    let Ok(weight) = raw_weight.parse::<isize>() else {
+        return Err("bad value".to_string());
+    };
+    if weight > MAX_WEIGHT {
+        return Err(format!(
+            "weight has to be an integer between 0 and {MAX_WEIGHT}, but {weight} was given"
+        ));
+    }
+    res.weight = weight;
+
+    Ok(res)
 }
 
 // Interactively ask the user for the given `enunciated`, build up a Word object
-// from it, and insert it into the database.
-fn do_create(enunciated: String) -> Result<(), String> {
+// from it, and insert it into the database. Returns the ID of the new word.
+fn do_create(enunciated: String) -> Result<i64, String> {
     let mut guess = get_initial_guess(enunciated.as_str());
     guess.enunciated = enunciated.trim().to_string();
 
@@ -509,7 +563,7 @@ fn do_create(enunciated: String) -> Result<(), String> {
     let word = ask_for_word_based_on(enunciated.clone(), guess)?;
     let Ok(selected_tags) = MultiSelect::new("Tags:", tags)
         .with_starting_cursor(0)
-        .prompt()
+        .ask()
     else {
         return Err("abort!".to_string());
     };
@@ -522,18 +576,195 @@ fn do_create(enunciated: String) -> Result<(), String> {
                 }
             }
             println!("Word '{enunciated}' has been successfully created!");
-            Ok(())
+            Ok(word_id)
         }
         Err(e) => Err(e),
     }
 }
 
-fn create(args: IntoIter<String>) -> i32 {
-    if args.len() > 0 {
-        help(Some(
-            "error: words: no arguments were expected for this command",
-        ));
-        return 1;
+// Split a line as given on an import (e.g. piped into `words create`) into the
+// enunciate proper and an optional chapter/source column. Columns are separated
+// either by a tab or a semicolon, as the comma is already taken by enunciates.
+fn split_import_line(line: &str) -> (String, Option<String>) {
+    match line.split_once(['\t', ';']) {
+        Some((enunciated, chapter)) => {
+            let chapter = chapter.trim();
+            if chapter.is_empty() {
+                (enunciated.trim().to_string(), None)
+            } else {
+                (enunciated.trim().to_string(), Some(chapter.to_string()))
+            }
+        }
+        None => (line.trim().to_string(), None),
+    }
+}
+
+// Attach the tag named `name` (if any) into the word identified by `word_id`,
+// creating the tag on the fly if needed. Nothing is done if the word already had
+// this tag, and failures are only reported as warnings.
+fn attach_import_tag(name: &Option<String>, word_id: i64) {
+    let Some(name) = name else {
+        return;
+    };
+
+    let tag_id = match find_or_create_tag(name) {
+        Ok(id) => id,
+        Err(e) => {
+            println!("warning: words: {e}");
+            return;
+        }
+    };
+    if let Ok(tags) = select_tags_for(Some(word_id as i32)) {
+        if tags.iter().any(|t| t.id as i64 == tag_id) {
+            return;
+        }
+    }
+    if let Err(e) = attach_tag_to_word(tag_id, word_id) {
+        println!("warning: words: {e}");
+    }
+}
+
+// How to resolve an imported word which collides with an existing one.
+#[derive(Clone, Copy, Debug)]
+enum ConflictPolicy {
+    // Leave the existing word untouched.
+    Skip,
+
+    // Replace the enunciate of the existing word with the imported one.
+    Theirs,
+
+    // Keep the existing word but add anything new from the import (e.g. the
+    // chapter tag).
+    Merge,
+}
+
+impl std::fmt::Display for ConflictPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Skip => write!(f, "Keep mine"),
+            Self::Theirs => write!(f, "Take theirs"),
+            Self::Merge => write!(f, "Merge fields"),
+        }
+    }
+}
+
+impl TryFrom<&str> for ConflictPolicy {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "skip" => Ok(Self::Skip),
+            "theirs" => Ok(Self::Theirs),
+            "merge" => Ok(Self::Merge),
+            _ => Err(format!(
+                "unknown conflict policy '{value}'. Available: skip, theirs, merge"
+            )),
+        }
+    }
+}
+
+// Resolve the conflict between the `existing` word and the `imported` enunciate
+// (which may also come with a chapter tag named `tag_name`). If no `policy` is
+// given, the user is asked interactively. Returns the enunciate of the word
+// after the conflict has been resolved.
+fn resolve_conflict(
+    mut existing: Word,
+    imported: &str,
+    tag_name: &Option<String>,
+    policy: Option<ConflictPolicy>,
+) -> Result<String, String> {
+    let policy = match policy {
+        Some(policy) => policy,
+        None => {
+            println!(
+                "'{imported}' collides with the existing word '{}'.",
+                existing.enunciated
+            );
+            let options = vec![
+                ConflictPolicy::Skip,
+                ConflictPolicy::Theirs,
+                ConflictPolicy::Merge,
+            ];
+            match Select::new("What do you want to do?", options).ask() {
+                Ok(choice) => choice,
+                Err(_) => return Err("abort!".to_string()),
+            }
+        }
+    };
+
+    let word_id = existing.id as i64;
+    match policy {
+        ConflictPolicy::Skip => {
+            println!("Skipping '{imported}'.");
+            Ok(existing.enunciated)
+        }
+        ConflictPolicy::Theirs => {
+            existing.enunciated = imported.to_string();
+            update_word(existing)?;
+            attach_import_tag(tag_name, word_id);
+            println!("Word '{imported}' has been updated!");
+            Ok(imported.to_string())
+        }
+        ConflictPolicy::Merge => {
+            attach_import_tag(tag_name, word_id);
+            println!("Word '{}' has been merged.", existing.enunciated);
+            Ok(existing.enunciated)
+        }
+    }
+}
+
+// Add the given newly created `enunciated` into the `known` normalized
+// enunciates, if these have already been fetched.
+fn remember_enunciate(known: &mut Option<HashMap<String, String>>, enunciated: &str) {
+    if let Some(k) = known.as_mut() {
+        let enunciated = enunciated.trim();
+        k.entry(normalize_enunciated(enunciated))
+            .or_insert(enunciated.to_string());
+    }
+}
+
+fn create(mut args: IntoIter<String>) -> i32 {
+    let mut tag_prefix = String::new();
+    let mut policy = None;
+
+    // Normalized enunciates of the words known so far, so collisions can be
+    // detected without going through all the words for each imported line. It
+    // is only built once the first line has been imported.
+    let mut known: Option<HashMap<String, String>> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--on-conflict" => match args.next() {
+                Some(value) => match ConflictPolicy::try_from(value.as_str()) {
+                    Ok(p) => policy = Some(p),
+                    Err(e) => {
+                        help(Some(format!("error: words: {e}").as_str()));
+                        return 1;
+                    }
+                },
+                None => {
+                    help(Some(
+                        "error: words: you have to provide a value for '--on-conflict'",
+                    ));
+                    return 1;
+                }
+            },
+            "--tag-prefix" => match args.next() {
+                Some(prefix) => tag_prefix = prefix,
+                None => {
+                    help(Some(
+                        "error: words: you have to provide a value for '--tag-prefix'",
+                    ));
+                    return 1;
+                }
+            },
+            _ => {
+                help(Some(
+                    format!("error: words: unknown argument '{arg}' for this command").as_str(),
+                ));
+                return 1;
+            }
+        }
     }
 
     loop {
@@ -542,22 +773,64 @@ fn create(args: IntoIter<String>) -> i32 {
         // initial value for our prompt. If no more input is given into the
         // pipe, then we quit altogether.
         let mut guess = String::new();
-        let mut guess_str = "";
+        let mut guess_str = String::new();
+        let mut chapter = None;
         if !stdin().is_terminal() {
             if stdin().read_line(&mut guess).unwrap_or(0) == 0 {
                 // No more input, quit.
                 return 0;
             }
-            guess_str = guess.trim();
+            (guess_str, chapter) = split_import_line(&guess);
 
             // Blank line, quit as well.
             if guess_str.is_empty() {
                 return 0;
             }
         }
+        let tag_name = chapter.map(|c| format!("{tag_prefix}{c}"));
+
+        // Imported words which collide with existing ones are resolved right
+        // away, without going through the creation flow.
+        if !guess_str.is_empty() {
+            if known.is_none() {
+                match select_normalized_enunciates() {
+                    Ok(enunciates) => known = Some(enunciates),
+                    Err(e) => {
+                        println!("error: words: {e}");
+                        return 1;
+                    }
+                }
+            }
+            let normalized = normalize_enunciated(&guess_str);
+            let collision = known.as_ref().and_then(|k| k.get(&normalized)).cloned();
+
+            if let Some(enunciated) = collision {
+                let res = find_by(&enunciated)
+                    .and_then(|existing| resolve_conflict(existing, &guess_str, &tag_name, policy));
+                match res {
+                    Ok(enunciated) => {
+                        if let Some(k) = known.as_mut() {
+                            k.insert(normalized, enunciated);
+                        }
+                    }
+                    Err(e) => {
+                        println!("error: words: {e}");
+                        return 1;
+                    }
+                }
+                continue;
+            }
+        }
+
+        // Anything other than resolving collisions needs prompts, which
+        // would otherwise fail on a pipe without saying why.
+        if !can_prompt() {
+            println!("error: words: this command needs an interactive terminal");
+            return 1;
+        }
         let Ok(enunciated) = Text::new("Enunciated:")
-            .with_initial_value(guess_str)
-            .prompt()
+            .with_initial_value(&guess_str)
+            .ask()
         else {
             return 1;
         };
@@ -583,20 +856,37 @@ fn create(args: IntoIter<String>) -> i32 {
             // "messages" which are part of the interface. Hence, if only three
             // "words" exist, then it's just the interface and we can go right
             // into creating the word.
-            3 => {
-                if let Err(e) = do_create(enunciated) {
+            3 => match do_create(enunciated.clone()) {
+                Ok(word_id) => {
+                    attach_import_tag(&tag_name, word_id);
+                    remember_enunciate(&mut known, &enunciated);
+                }
+                Err(e) => {
                     println!("error: words: {e}");
                     return 1;
                 }
-            }
-            _ => match Select::new("Is your word on this list?", words).prompt() {
+            },
+            _ => match Select::new("Is your word on this list?", words).ask() {
                 Ok(choice) => {
                     if choice == QUIT_MESSAGE {
                         return 0;
                     } else if choice == NEW_MESSAGE {
-                        if let Err(e) = do_create(enunciated) {
-                            println!("error: words: {e}");
-                            return 1;
+                        match do_create(enunciated.clone()) {
+                            Ok(word_id) => {
+                                attach_import_tag(&tag_name, word_id);
+                                remember_enunciate(&mut known, &enunciated);
+                            }
+                            Err(e) => {
+                                println!("error: words: {e}");
+                                return 1;
+                            }
+                        }
+                    } else if choice != NEXT_MESSAGE && tag_name.is_some() {
+                        // The word already existed: still associate it with the
+                        // chapter/source that came along with the import.
+                        match find_by(&choice) {
+                            Ok(word) => attach_import_tag(&tag_name, word.id as i64),
+                            Err(e) => println!("warning: words: {e}"),
                         }
                     }
                 }
@@ -606,13 +896,93 @@ fn create(args: IntoIter<String>) -> i32 {
     }
 }
 
-fn ls(mut args: IntoIter<String>, tags: &[String]) -> i32 {
-    if args.len() > 1 {
+// Fields by which 'words ls' can group its results.
+static GROUP_BY_FIELDS: [&str; 4] = ["declension", "conjugation", "gender", "category"];
+
+// Returns the name of the group in which the given `word` falls when grouping
+// by the given `field`.
+fn group_name(word: &Word, field: &str) -> String {
+    match field {
+        "declension" => word
+            .declension
+            .as_ref()
+            .map_or("none".to_string(), |d| d.to_string()),
+        "conjugation" => word
+            .conjugation
+            .as_ref()
+            .map_or("none".to_string(), |c| c.to_string()),
+        "gender" => word.gender.to_string(),
+        _ => word.category.to_string(),
+    }
+}
+
+// Returns the given `words` grouped by the given `field`. Groups are sorted
+// alphabetically, while words keep the order in which they were given.
+fn group_words(words: &[String], field: &str) -> Result<BTreeMap<String, Vec<String>>, String> {
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for enunciated in words {
+        let word = find_by(enunciated)?;
+        groups
+            .entry(group_name(&word, field))
+            .or_default()
+            .push(enunciated.to_owned());
+    }
+
+    Ok(groups)
+}
+
+// Returns the given `words` followed by their tags in their colors when the
+// output goes into a terminal. Otherwise they are left as they are, so they can
+// still be piped into other commands (e.g. 'mihi words create').
+fn with_tags(words: &[String]) -> Result<Vec<String>, String> {
+    if !stdout().is_terminal() {
+        return Ok(words.to_vec());
+    }
+
+    let tags = select_tags_by_enunciated()?;
+    Ok(words
+        .iter()
+        .map(|word| match tags.get(word) {
+            Some(tags) => format!(
+                "{word} [{}]",
+                tags.iter().map(paint_tag).collect::<Vec<_>>().join(", ")
+            ),
+            None => word.clone(),
+        })
+        .collect())
+}
+
+fn ls(args: IntoIter<String>, tags: &[String]) -> i32 {
+    let (json, mut args) = take_flag(args, &["--json"]);
+
+    let mut group_by = None;
+    let mut filters = vec![];
+    while let Some(arg) = args.next() {
+        if arg != "--group-by" {
+            filters.push(arg);
+            continue;
+        }
+        match args.next() {
+            Some(field) if GROUP_BY_FIELDS.contains(&field.as_str()) => group_by = Some(field),
+            _ => {
+                help(Some(
+                    format!(
+                        "error: words: '--group-by' expects one of: {}",
+                        GROUP_BY_FIELDS.join(", ")
+                    )
+                    .as_str(),
+                ));
+                return 1;
+            }
+        }
+    }
+    if filters.len() > 1 {
         help(Some("error: words: too many filters"));
         return 1;
     }
 
-    let words = match select_enunciated(args.next(), tags) {
+    let words = match select_enunciated(filters.pop(), tags) {
         Ok(words) => words,
         Err(e) => {
             println!("error: words: {e}");
@@ -620,10 +990,160 @@ fn ls(mut args: IntoIter<String>, tags: &[String]) -> i32 {
         }
     };
 
-    for enunciated in words {
-        println!("{enunciated}");
+    let Some(field) = group_by else {
+        if json {
+            println!("{}", serde_json::json!(words));
+            return 0;
+        }
+        match with_tags(&words) {
+            Ok(lines) => print_lines(&lines),
+            Err(e) => {
+                println!("error: words: {e}");
+                return 1;
+            }
+        }
+        return 0;
+    };
+
+    let groups = match group_words(&words, &field) {
+        Ok(groups) => groups,
+        Err(e) => {
+            println!("error: words: {e}");
+            return 1;
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::json!(groups));
+        return 0;
+    }
+
+    let mut lines = vec![];
+    for (name, words) in groups {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(format!("== {name} ({}) ==", words.len()));
+        match with_tags(&words) {
+            Ok(words) => lines.extend(words),
+            Err(e) => {
+                println!("error: words: {e}");
+                return 1;
+            }
+        }
+    }
+    lines.push(format!("\nTotal: {}", words.len()));
+    print_lines(&lines);
+
+    0
+}
+
+// Number of words to be shown by 'occurrences'.
+const OCCURRENCES_SHOWN: usize = 10;
+
+fn occurrences(args: IntoIter<String>) -> i32 {
+    if args.len() > 0 {
+        help(Some(
+            "error: words: 'occurrences' does not accept arguments",
+        ));
+        return 1;
+    }
+
+    let counts = match select_exercise_texts().and_then(|texts| count_lemmas(&texts)) {
+        Ok(counts) => counts,
+        Err(e) => {
+            println!("error: words: {e}");
+            return 1;
+        }
+    };
+    if let Err(e) = update_occurrences(&counts) {
+        println!("error: words: {e}");
+        return 1;
     }
 
+    let mut sorted = counts.iter().collect::<Vec<_>>();
+    sorted.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    let lines = sorted
+        .iter()
+        .take(OCCURRENCES_SHOWN)
+        .map(|(enunciated, count)| format!("{count}\t{enunciated}"))
+        .collect::<Vec<_>>();
+    print_lines(&lines);
+    println!("\nWords found in your exercises: {}", counts.len());
+
+    0
+}
+
+// Number of words to be suggested by 'sample' when not filling gaps.
+const SAMPLE_SIZE: usize = 10;
+
+fn sample(mut args: IntoIter<String>) -> i32 {
+    let mut from = None;
+    let mut fill_gaps = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--from" => match args.next() {
+                Some(path) => from = Some(PathBuf::from(path)),
+                None => {
+                    help(Some(
+                        "error: words: you have to provide a file for '--from'",
+                    ));
+                    return 1;
+                }
+            },
+            "--fill-gaps" => fill_gaps = true,
+            _ => {
+                help(Some(
+                    format!("error: words: unknown flag for 'sample': '{arg}'").as_str(),
+                ));
+                return 1;
+            }
+        }
+    }
+    let Some(from) = from else {
+        help(Some("error: words: 'sample' requires the '--from' flag"));
+        return 1;
+    };
+
+    let words = if fill_gaps {
+        let counts = match count_words_by_kind() {
+            Ok(counts) => counts,
+            Err(e) => {
+                println!("error: words: {e}");
+                return 1;
+            }
+        };
+
+        let mut words = vec![];
+        for (kind, target) in configuration().coverage_goals {
+            let count = counts
+                .iter()
+                .find(|(k, _)| *k == kind)
+                .map_or(0, |(_, count)| *count);
+            if count >= target {
+                continue;
+            }
+            match select_missing_from(&from, Some(&kind), target - count) {
+                Ok(mut candidates) => words.append(&mut candidates),
+                Err(e) => {
+                    println!("error: words: {e}");
+                    return 1;
+                }
+            }
+        }
+        words
+    } else {
+        match select_missing_from(&from, None, SAMPLE_SIZE) {
+            Ok(words) => words,
+            Err(e) => {
+                println!("error: words: {e}");
+                return 1;
+            }
+        }
+    };
+
+    print_lines(&words);
     0
 }
 
@@ -636,10 +1156,8 @@ fn select_single_word(search: Option<String>) -> Result<String, String> {
     match words.len() {
         0 => Err("not found".to_string()),
         1 => Ok(words.first().unwrap().to_owned()),
-        _ => match Select::new("Which word?", words)
-            .with_page_size(20)
-            .prompt()
-        {
+        n if !is_interactive() => Err(format!("{n} words match, be more specific")),
+        _ => match Select::new("Which word?", words).with_page_size(20).ask() {
             Ok(choice) => Ok(choice),
             Err(_) => Err("abort!".to_string()),
         },
@@ -677,7 +1195,7 @@ fn dup(mut args: IntoIter<String>) -> i32 {
     // same experience as with the 'create' command.
     let Ok(enunciated) = Text::new("Enunciated:")
         .with_initial_value(&word.enunciated)
-        .prompt()
+        .ask()
     else {
         return 1;
     };
@@ -727,7 +1245,7 @@ fn dup(mut args: IntoIter<String>) -> i32 {
     let Ok(selected_tags) = MultiSelect::new("Tags:", all_tags)
         .with_starting_cursor(0)
         .with_default(&default_indices)
-        .prompt()
+        .ask()
     else {
         return 1;
     };
@@ -795,7 +1313,7 @@ fn edit(mut args: IntoIter<String>) -> i32 {
     // same experience as with the 'create' command.
     let Ok(enunciated) = Text::new("Enunciated:")
         .with_initial_value(&word.enunciated)
-        .prompt()
+        .ask()
     else {
         return 1;
     };
@@ -843,7 +1361,7 @@ fn edit(mut args: IntoIter<String>) -> i32 {
     let Ok(selected_tags) = MultiSelect::new("Tags:", all_tags)
         .with_starting_cursor(0)
         .with_default(&default_indices)
-        .prompt()
+        .ask()
     else {
         return 1;
     };
@@ -893,10 +1411,13 @@ fn edit(mut args: IntoIter<String>) -> i32 {
 // Returns a string with a more human-readable declension kind. If the kind is
 // self-explanatory, then None is returned (e.g. "a" is the only kind for the
 // first declension, so it's redundant).
-fn humanize_kind(kind: &str) -> Option<&str> {
+pub fn humanize_kind(kind: &str) -> Option<&str> {
     match kind {
         // Noun
         "a" => None,
+        "greekas" => Some("Greek -ās; like 'Aenēās, Aenēae'"),
+        "greekes" => Some("Greek -ēs; like 'Anchīsēs, Anchīsae'"),
+        "greeke" => Some("Greek -ē; like 'epitomē, epitomēs'"),
         "us" => Some("regular -us"),
         "er/ir" => Some("-er/-ir"),
         "um" => Some("neuter -um"),
@@ -908,6 +1429,8 @@ fn humanize_kind(kind: &str) -> Option<&str> {
         "sussuis" => Some("irregular 'sūs, suis'"),
         "bosbovis" => Some("irregular 'bōs, bovis'"),
         "iuppiteriovis" => Some("irregular 'Iuppiter, Iovis'"),
+        "greeko" => Some("Greek -ō; like 'Dīdō, Dīdōnis'"),
+        "greekthird" => Some("Greek accusative in -a; like 'hērōs, hērōis'"),
         "fus" => None,
         "domusdomus" => Some("irregular 'domus, domūs/domī'"),
         "ies" => Some("-iēs; like 'diēs, diēī'"),
@@ -952,6 +1475,7 @@ fn humanize_flag(s: &str) -> String {
         "nosupine" => "no supine form",
         "noperfect" => "no perfect forms",
         "nogerundive" => "no gerundive",
+        "nogerund" => "no gerund",
         "impersonal" => "impersonal",
         "impersonalpassive" => "impersonal only on its passive forms",
         "noimperative" => "no imperative forms",
@@ -961,6 +1485,7 @@ fn humanize_flag(s: &str) -> String {
         "notcomparable" => "not comparable",
         "onlyperfect" => "only perfect forms",
         "contracted_vocative" => "contracted vocative, as in filī, not filiī*",
+        "enclitic" => "enclitic",
         _ => "",
     }
     .to_string()
@@ -971,8 +1496,9 @@ fn humanize_flags(word: &Word) -> String {
 
     if let Some(obj) = word.flags.as_object() {
         for (key, value) in obj {
-            if value.as_bool().unwrap_or_default() {
-                flags.push(humanize_flag(key));
+            let flag = humanize_flag(key);
+            if value.as_bool().unwrap_or_default() && !flag.is_empty() {
+                flags.push(flag);
             }
         }
     }
@@ -980,7 +1506,7 @@ fn humanize_flags(word: &Word) -> String {
     flags.join("; ")
 }
 
-fn title_for_word(word: &Word) -> String {
+pub fn title_for_word(word: &Word) -> String {
     let s = match word.gender {
         Gender::None => format!("{} ({}", word.enunciated, word.category),
         _ => format!(
@@ -991,35 +1517,45 @@ fn title_for_word(word: &Word) -> String {
         ),
     };
 
-    let flags = humanize_flags(word);
+    let mut flags = humanize_flags(word);
+    if let Some(example) = word.enclitic_example() {
+        flags.push_str(format!(", as in '{example}'").as_str());
+    }
     if flags.is_empty() {
         return format!("{})", s);
     }
     format!("{}; {})", s, flags)
 }
 
+/// Returns a line with the conjugation or the declension of the given `word`,
+/// alongside its kind, or None if it has neither of them.
+pub fn paradigm_for_word(word: &Word) -> Option<String> {
+    match word.conjugation {
+        Some(ref conjugation) => Some(format!(
+            "Conjugation: {}",
+            conjugation.display_with_kind(&word.kind)
+        )),
+        None => word.declension.as_ref().map(|d| {
+            if matches!(d, Declension::Other) {
+                format!("Declension: {}.", humanize_kind(&word.kind).unwrap_or("-"))
+            } else {
+                match humanize_kind(&word.kind) {
+                    Some(k) => format!("Declension: {}; kind: {}.", d, k),
+                    None => format!("Declension: {}", d),
+                }
+            }
+        }),
+    }
+}
+
 fn show_info(word: Word) -> Result<(), String> {
     // Title.
     println!("Word: {}", title_for_word(&word));
 
     // Conjugation, declension + kind.
-    match word.conjugation {
-        Some(ref conjugation) => {
-            println!("Conjugation: {}", conjugation.display_with_kind(&word.kind))
-        }
-        None => {
-            if let Some(ref d) = word.declension {
-                if matches!(d, Declension::Other) {
-                    println!("Declension: {}.", humanize_kind(&word.kind).unwrap_or("-"));
-                } else {
-                    match humanize_kind(&word.kind) {
-                        Some(k) => println!("Declension: {}; kind: {}.", d, k),
-                        None => println!("Declension: {}", d),
-                    }
-                }
-            }
-        }
-    };
+    if let Some(paradigm) = paradigm_for_word(&word) {
+        println!("{paradigm}");
+    }
 
     // Show relationships with other words.
 
@@ -1067,6 +1603,36 @@ fn show_info(word: Word) -> Result<(), String> {
         }
     }
 
+    // Show the tags in their colors, alongside their descriptions.
+    let tags = select_tags_for(Some(word.id))?;
+    if !tags.is_empty() {
+        println!("Tags:");
+        for tag in tags {
+            match &tag.description {
+                Some(description) => println!("- {}: {description}", paint_tag(&tag)),
+                None => println!("- {}", paint_tag(&tag)),
+            }
+        }
+    }
+
+    // Show the exercises which feature this word.
+    let exercises = select_exercises_for_word(word.id)?;
+    if !exercises.is_empty() {
+        println!("Exercises:");
+        for exercise in exercises {
+            println!("- {exercise}");
+        }
+    }
+
+    // Show the notes taken during practice sessions.
+    let notes = select_notes_for(&word)?;
+    if !notes.is_empty() {
+        println!("Notes:");
+        for note in notes {
+            println!("- {note}");
+        }
+    }
+
     print_full_inflection_for(word)?;
 
     Ok(())
@@ -1095,6 +1661,40 @@ fn poke(mut args: IntoIter<String>) -> i32 {
     }
 }
 
+fn suspend(mut args: IntoIter<String>) -> i32 {
+    if args.len() > 1 {
+        help(Some(
+            "error: words: only one argument. If it's an enunciate, wrap it in double quotes",
+        ));
+        return 1;
+    }
+
+    let enunciated = match select_single_word(args.next()) {
+        Ok(word) => word,
+        Err(e) => {
+            println!("error: words: {e}.");
+            return 1;
+        }
+    };
+    let word = match find_by(enunciated.as_str()) {
+        Ok(word) => word,
+        Err(e) => {
+            println!("error: words: {e}");
+            return 1;
+        }
+    };
+
+    match toggle_suspended(&word) {
+        Ok(true) => println!("Suspended '{enunciated}': it will no longer be asked."),
+        Ok(false) => println!("'{enunciated}' is no longer suspended."),
+        Err(e) => {
+            println!("error: words: {e}");
+            return 1;
+        }
+    }
+    0
+}
+
 fn rel(args: IntoIter<String>) -> i32 {
     if args.len() > 0 {
         help(Some(
@@ -1126,7 +1726,7 @@ fn rel(args: IntoIter<String>) -> i32 {
         RelationKind::Alternative,
         RelationKind::Gendered,
     ];
-    let Ok(relation) = Select::new("has a...", kinds).prompt() else {
+    let Ok(relation) = Select::new("has a...", kinds).ask() else {
         return 1;
     };
 
@@ -1204,7 +1804,119 @@ fn show(mut args: IntoIter<String>) -> i32 {
     0
 }
 
-fn rm(mut args: IntoIter<String>) -> i32 {
+fn table(mut args: IntoIter<String>) -> i32 {
+    let mut format = Format::default();
+    let mut search = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => match args.next().as_deref().map(Format::try_from) {
+                Some(Ok(f)) => format = f,
+                Some(Err(e)) => {
+                    help(Some(format!("error: words: {e}").as_str()));
+                    return 1;
+                }
+                None => {
+                    help(Some("error: words: you have to provide a format"));
+                    return 1;
+                }
+            },
+            _ if search.is_none() => search = Some(arg),
+            _ => {
+                help(Some(
+                    "error: words: only one argument. If it's an enunciate, wrap it in double quotes",
+                ));
+                return 1;
+            }
+        }
+    }
+
+    let enunciated = match select_single_word(search) {
+        Ok(word) => word,
+        Err(e) => {
+            println!("error: words: {e}.");
+            return 1;
+        }
+    };
+
+    match find_by(enunciated.as_str()).and_then(|word| render_word(&word, format)) {
+        Ok(tables) => {
+            print!("{tables}");
+            0
+        }
+        Err(e) => {
+            println!("error: words: {e}.");
+            1
+        }
+    }
+}
+
+fn synopsis(mut args: IntoIter<String>) -> i32 {
+    let mut format = Format::default();
+    let mut person = 3;
+    let mut number = Number::Singular;
+    let mut search = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => match args.next().as_deref().map(Format::try_from) {
+                Some(Ok(f)) => format = f,
+                Some(Err(e)) => {
+                    help(Some(format!("error: words: {e}").as_str()));
+                    return 1;
+                }
+                None => {
+                    help(Some("error: words: you have to provide a format"));
+                    return 1;
+                }
+            },
+            "--person" => match args.next().map(|p| p.parse::<usize>()) {
+                Some(Ok(p)) if (1..=3).contains(&p) => person = p,
+                _ => {
+                    help(Some("error: words: '--person' expects 1, 2 or 3"));
+                    return 1;
+                }
+            },
+            "--number" => match args.next().as_deref() {
+                Some("sg" | "singular") => number = Number::Singular,
+                Some("pl" | "plural") => number = Number::Plural,
+                _ => {
+                    help(Some("error: words: '--number' expects either 'sg' or 'pl'"));
+                    return 1;
+                }
+            },
+            _ if search.is_none() => search = Some(arg),
+            _ => {
+                help(Some(
+                    "error: words: only one argument. If it's an enunciate, wrap it in double quotes",
+                ));
+                return 1;
+            }
+        }
+    }
+
+    let enunciated = match select_single_word(search) {
+        Ok(word) => word,
+        Err(e) => {
+            println!("error: words: {e}.");
+            return 1;
+        }
+    };
+
+    match find_by(enunciated.as_str()).and_then(|word| synopsis_table(&word, person, number)) {
+        Ok(table) => {
+            print!("{}", table.render(format));
+            0
+        }
+        Err(e) => {
+            println!("error: words: {e}.");
+            1
+        }
+    }
+}
+
+fn rm(args: IntoIter<String>) -> i32 {
+    let (yes, mut args) = take_flag(args, &["-y", "--yes"]);
     if args.len() > 1 {
         help(Some("error: words: too many filters"));
         return 1;
@@ -1227,11 +1939,10 @@ fn rm(mut args: IntoIter<String>) -> i32 {
         }
     };
 
-    let ans = Confirm::new(
+    let ans = confirm(
         format!("Do you really want to remove '{selection}' from the database?").as_str(),
-    )
-    .with_default(false)
-    .prompt();
+        yes,
+    );
 
     match ans {
         Ok(true) => match delete_word(&word) {
@@ -1244,7 +1955,10 @@ fn rm(mut args: IntoIter<String>) -> i32 {
         Ok(false) => {
             println!("Doing nothing...");
         }
-        Err(_) => return 1,
+        Err(e) => {
+            println!("error: words: {e}");
+            return 1;
+        }
     }
 
     0
@@ -1260,6 +1974,7 @@ pub fn run(args: Vec<String>) {
 
     let mut it = args.into_iter();
     let mut do_ls = false;
+    let mut ls_args = vec![];
     let mut tags = vec![];
 
     while let Some(first) = it.next() {
@@ -1273,7 +1988,7 @@ pub fn run(args: Vec<String>) {
                     let name = t.trim().to_string();
                     if let Ok(results) = select_tag_names(&Some(name.clone())) {
                         if results.is_empty() {
-                            println!("warning: words: the tag '{}' does not exist.", name);
+                            eprintln!("warning: words: the tag '{}' does not exist.", name);
                         } else {
                             tags.push(name)
                         }
@@ -1298,6 +2013,9 @@ pub fn run(args: Vec<String>) {
                 // parameters to it.
                 do_ls = true;
             }
+            "occurrences" => {
+                std::process::exit(occurrences(it));
+            }
             "poke" => {
                 std::process::exit(poke(it));
             }
@@ -1307,9 +2025,23 @@ pub fn run(args: Vec<String>) {
             "rm" => {
                 std::process::exit(rm(it));
             }
+            "sample" => {
+                std::process::exit(sample(it));
+            }
             "show" => {
                 std::process::exit(show(it));
             }
+            "suspend" => {
+                std::process::exit(suspend(it));
+            }
+            "synopsis" => {
+                std::process::exit(synopsis(it));
+            }
+            "table" => {
+                std::process::exit(table(it));
+            }
+            // Arguments given after 'ls' belong to it (e.g. a filter).
+            _ if do_ls => ls_args.push(first),
             _ => {
                 help(Some(
                     format!("error: words: unknown flag or command '{first}'").as_str(),
@@ -1323,7 +2055,7 @@ pub fn run(args: Vec<String>) {
     // were provided by the user. Otherwise, the above loop did not result in a
     // valid subcommand (it was not even provided).
     if do_ls {
-        std::process::exit(ls(it, &tags));
+        std::process::exit(ls(ls_args.into_iter(), &tags));
     } else {
         help(Some(
             "error: words: you need to provide a command"
@@ -1377,4 +2109,147 @@ mod tests {
         );
         assert_eq!(related_for("victor, victōris"), "----victrīx, victrīcis");
     }
+
+    #[test]
+    fn group_by_fields() {
+        // Sorted, as given by 'select_enunciated'.
+        let words: Vec<String> = [
+            "amō, amāre, amāvī, amātum",
+            "parvus, parva, parvum",
+            "puella, puellae",
+            "rosa, rosae",
+            "victor, victōris",
+        ]
+        .map(String::from)
+        .to_vec();
+        let groups = |field: &str| -> Vec<(String, Vec<String>)> {
+            group_words(&words, field).unwrap().into_iter().collect()
+        };
+        let group = |name: &str, words: &[&str]| -> (String, Vec<String>) {
+            (
+                name.to_string(),
+                words.iter().map(|w| w.to_string()).collect(),
+            )
+        };
+
+        assert_eq!(
+            groups("declension"),
+            [
+                group(
+                    "1st (-ae)",
+                    &["parvus, parva, parvum", "puella, puellae", "rosa, rosae"]
+                ),
+                group("3rd (-is)", &["victor, victōris"]),
+                group("none", &["amō, amāre, amāvī, amātum"]),
+            ]
+        );
+        assert_eq!(
+            groups("conjugation"),
+            [
+                group("1st (ā stems)", &["amō, amāre, amāvī, amātum"]),
+                group(
+                    "none",
+                    &[
+                        "parvus, parva, parvum",
+                        "puella, puellae",
+                        "rosa, rosae",
+                        "victor, victōris"
+                    ]
+                ),
+            ]
+        );
+        assert_eq!(
+            groups("gender"),
+            [
+                group("feminine", &["puella, puellae", "rosa, rosae"]),
+                group("masculine", &["victor, victōris"]),
+                group(
+                    "none",
+                    &["amō, amāre, amāvī, amātum", "parvus, parva, parvum"]
+                ),
+            ]
+        );
+        assert_eq!(
+            groups("category"),
+            [
+                group("adjective", &["parvus, parva, parvum"]),
+                group(
+                    "noun",
+                    &["puella, puellae", "rosa, rosae", "victor, victōris"]
+                ),
+                group("verb", &["amō, amāre, amāvī, amātum"]),
+            ]
+        );
+
+        assert!(group_words(&["xyzzy".to_string()], "gender").is_err());
+    }
+
+    #[test]
+    fn split_import_lines() {
+        assert_eq!(
+            split_import_line("rosa, rosae\n"),
+            ("rosa, rosae".to_string(), None)
+        );
+        assert_eq!(
+            split_import_line("rosa, rosae\tcap01\n"),
+            ("rosa, rosae".to_string(), Some("cap01".to_string()))
+        );
+        assert_eq!(
+            split_import_line(" rosa, rosae ;  Wheelock 2 \n"),
+            ("rosa, rosae".to_string(), Some("Wheelock 2".to_string()))
+        );
+        assert_eq!(
+            split_import_line("rosa, rosae\t \n"),
+            ("rosa, rosae".to_string(), None)
+        );
+        assert_eq!(
+            split_import_line("rosa, rosae;\n"),
+            ("rosa, rosae".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn conflict_policy() {
+        assert!(matches!(
+            ConflictPolicy::try_from("skip"),
+            Ok(ConflictPolicy::Skip)
+        ));
+        assert!(matches!(
+            ConflictPolicy::try_from("theirs"),
+            Ok(ConflictPolicy::Theirs)
+        ));
+        assert!(matches!(
+            ConflictPolicy::try_from("merge"),
+            Ok(ConflictPolicy::Merge)
+        ));
+        assert_eq!(
+            ConflictPolicy::try_from("Skip").unwrap_err(),
+            "unknown conflict policy 'Skip'. Available: skip, theirs, merge"
+        );
+    }
+
+    #[test]
+    fn resolve_conflict_keeps_existing_word() {
+        let existing = find_by("parvus, parva, parvum").unwrap();
+        assert_eq!(
+            resolve_conflict(
+                existing.clone(),
+                "parvus, parva, parvum",
+                &None,
+                Some(ConflictPolicy::Skip)
+            )
+            .unwrap(),
+            "parvus, parva, parvum"
+        );
+        assert_eq!(
+            resolve_conflict(
+                existing,
+                "parvus, parva, parvum",
+                &None,
+                Some(ConflictPolicy::Merge)
+            )
+            .unwrap(),
+            "parvus, parva, parvum"
+        );
+    }
 }
@@ -0,0 +1,2794 @@
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use tempfile::TempDir;
+
+// Answers for 'words create' when given 'rosa, rosae' through a pipe. Empty
+// lines take the guess for each prompt.
+const CREATE_ROSA: &str = "rosa, rosae\n\n\n\n\n\n\n\n\n\nrose\nrosa\n\n\n";
+
+// Answers for 'words create' when given 'amō, amāre, amāvī, amātum' through a
+// pipe.
+const CREATE_AMO: &str = "amō, amāre, amāvī, amātum\n\nam\nverb\n\n\n\nto love\namar\n\n\n";
+
+// Runs the binary with the given `args` and `input` piped into it. The
+// configuration and the database live inside of the given `home` (see
+// `database_path`), and no external tools (e.g. diff tools) are to be found.
+// Returns whether it succeeded alongside its output.
+fn mihi(home: &Path, args: &[&str], input: &str) -> (bool, String) {
+    mihi_with_env(home, &[], args, input)
+}
+
+// Same as `mihi`, but with the given `env` variables set on top of the ones
+// which point to the given `home`.
+fn mihi_with_env(home: &Path, env: &[(&str, &str)], args: &[&str], input: &str) -> (bool, String) {
+    let (status, out) = mihi_status(home, env, args, input);
+    (status == Some(0), out)
+}
+
+// Same as `mihi_with_env`, but it returns the exit status of the binary instead
+// of whether it succeeded.
+fn mihi_status(
+    home: &Path,
+    env: &[(&str, &str)],
+    args: &[&str],
+    input: &str,
+) -> (Option<i32>, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_mihi"))
+        .args(args)
+        .env("HOME", home)
+        .env("XDG_CONFIG_HOME", home)
+        .env("XDG_DATA_HOME", home.join("data"))
+        .env_remove("MIHI_DATA_DIR")
+        .env("MIHI_DATABASE", "db.sqlite3")
+        .env("PATH", "")
+        .envs(env.iter().copied())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // The binary might exit before reading all of its input (e.g. on a bad
+    // argument), so a broken pipe is not an error.
+    if let Err(e) = child.stdin.take().unwrap().write_all(input.as_bytes()) {
+        assert_eq!(e.kind(), ErrorKind::BrokenPipe, "{e}");
+    }
+    let output = child.wait_with_output().unwrap();
+
+    (
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    )
+}
+
+// Returns the path to the database used by `mihi` for the given `home`.
+fn database_path(home: &Path) -> PathBuf {
+    home.join("data").join("mihi").join("db.sqlite3")
+}
+
+// Returns a new home with an initialized database.
+fn init() -> TempDir {
+    let home = TempDir::new().unwrap();
+    let (ok, out) = mihi(home.path(), &["init", "-l", "latin"], "");
+    assert!(ok, "{out}");
+    home
+}
+
+#[test]
+fn test_init() {
+    let home = init();
+    assert!(home.path().join("mihi").join("languages.txt").exists());
+    assert!(database_path(home.path()).exists());
+
+    let (ok, out) = mihi(home.path(), &["stats"], "");
+    assert!(ok);
+    assert!(out.contains("Total: 0"), "{out}");
+
+    // The configuration is kept apart from the data.
+    assert!(!home.path().join("mihi").join("db.sqlite3").exists());
+
+    // Commands other than 'init' refuse to create the database on their own
+    // without a terminal.
+    let empty = TempDir::new().unwrap();
+    let (ok, out) = mihi(empty.path(), &["stats"], "");
+    assert!(!ok);
+    assert!(out.contains("Call 'mihi init' first"), "{out}");
+}
+
+#[test]
+fn test_missing_database() {
+    // Exit status for a missing database, as in 'init::NO_DATABASE_STATUS'.
+    const NO_DATABASE_STATUS: Option<i32> = Some(3);
+
+    let home = TempDir::new().unwrap();
+    let (status, out) = mihi_status(home.path(), &[], &["stats"], "");
+    assert_eq!(status, NO_DATABASE_STATUS, "{out}");
+    assert_eq!(
+        out.trim(),
+        format!(
+            "error: there is no database in '{}'. Call 'mihi init' first",
+            database_path(home.path()).display()
+        )
+    );
+    assert!(!database_path(home.path()).exists());
+
+    // Asking for help does not need a database.
+    let (status, out) = mihi_status(home.path(), &[], &["stats", "--help"], "");
+    assert_eq!(status, Some(0), "{out}");
+
+    // When prompts can be answered, initializing the database is offered.
+    let (status, out) = mihi_status(home.path(), &[], &["--no-input", "stats"], "n\n");
+    assert_eq!(status, NO_DATABASE_STATUS, "{out}");
+    assert!(
+        out.contains("Do you want to initialize it now? no"),
+        "{out}"
+    );
+    assert!(!database_path(home.path()).exists());
+    let (status, out) = mihi_status(home.path(), &[], &["--no-input", "stats"], "y\n\n");
+    assert_eq!(status, Some(0), "{out}");
+    assert!(out.contains("Total: 0"), "{out}");
+    assert!(database_path(home.path()).exists());
+}
+
+#[test]
+fn test_data_dir() {
+    // Databases from older versions lived with the configuration, and they
+    // are moved into the data directory the first time they are used.
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let legacy = home.path().join("mihi").join("db.sqlite3");
+    std::fs::rename(database_path(home.path()), &legacy).unwrap();
+    let (ok, out) = mihi(home.path(), &["stats"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Total: 1"), "{out}");
+    assert!(database_path(home.path()).exists());
+    assert!(!legacy.exists());
+
+    // The data directory can be given explicitly.
+    let dir = home.path().join("elsewhere");
+    let dir = dir.to_str().unwrap();
+    let (ok, out) = mihi(home.path(), &["--data-dir", dir, "init"], "");
+    assert!(ok, "{out}");
+    assert!(home.path().join("elsewhere").join("db.sqlite3").exists());
+    let (ok, out) = mihi(home.path(), &["--data-dir", dir, "stats"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Total: 0"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["--data-dir"], "");
+    assert!(!ok);
+    assert!(
+        out.contains("provide a value for the '--data-dir' flag"),
+        "{out}"
+    );
+
+    // Nuking removes both the configuration and the data.
+    let (ok, out) = mihi(home.path(), &["nuke", "-y"], "");
+    assert!(ok, "{out}");
+    assert!(!home.path().join("mihi").exists());
+    assert!(!database_path(home.path()).exists());
+}
+
+#[test]
+fn test_profiles() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+
+    // Profiles have to be created before they are used.
+    let (ok, out) = mihi(home.path(), &["--profile", "partner", "stats"], "");
+    assert!(!ok);
+    assert!(
+        out.contains("Call 'mihi profiles create partner' first"),
+        "{out}"
+    );
+    let (ok, out) = mihi(home.path(), &["profiles", "create", "partner"], "");
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["profiles", "create", "partner"], "");
+    assert!(!ok);
+    assert!(out.contains("'partner' already exists"), "{out}");
+    let (ok, out) = mihi(home.path(), &["profiles", "create", "../up"], "");
+    assert!(!ok);
+    assert!(out.contains("bad name '../up'"), "{out}");
+
+    // Each profile has its own configuration and database.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--profile", "partner", "init", "--max-items", "3"],
+        "",
+    );
+    assert!(ok, "{out}");
+    let profile = home
+        .path()
+        .join("data")
+        .join("mihi")
+        .join("profiles")
+        .join("partner");
+    assert!(profile.join("languages.txt").exists());
+    assert!(profile.join("db.sqlite3").exists());
+    let (ok, out) = mihi(home.path(), &["--profile", "partner", "stats"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Total: 0"), "{out}");
+    let (ok, out) = mihi(home.path(), &["stats"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Total: 1"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["--profile", "partner", "profiles", "ls"], "");
+    assert!(ok, "{out}");
+    assert_eq!(out.trim(), "partner (current)");
+
+    // An absolute database path would be shared by all profiles, so it is
+    // only honored without one.
+    let shared = home.path().join("shared.sqlite3");
+    let env = [("MIHI_DATABASE", shared.to_str().unwrap())];
+    let (ok, out) = mihi_with_env(home.path(), &env, &["--profile", "partner", "stats"], "");
+    assert!(!ok);
+    assert!(
+        out.contains("'MIHI_DATABASE' cannot be an absolute path"),
+        "{out}"
+    );
+    assert!(!shared.exists());
+    let (ok, out) = mihi_with_env(home.path(), &env, &["init"], "");
+    assert!(ok, "{out}");
+    assert!(shared.exists());
+
+    let (ok, out) = mihi(home.path(), &["profiles", "rm", "-y", "partner"], "");
+    assert!(ok, "{out}");
+    assert!(!profile.exists());
+    let (ok, out) = mihi(home.path(), &["profiles", "ls"], "");
+    assert!(ok, "{out}");
+    assert!(out.trim().is_empty(), "{out}");
+    let (ok, out) = mihi(home.path(), &["profiles", "rm", "-y", "partner"], "");
+    assert!(!ok);
+    assert!(out.contains("'partner' does not exist"), "{out}");
+}
+
+#[test]
+fn test_create_practice_and_stats() {
+    let home = init();
+
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    assert!(out.contains("Category: noun"), "{out}");
+    assert!(out.contains("Gender: feminine"), "{out}");
+    assert!(out.contains("Word 'rosa, rosae' has been successfully created!"));
+
+    // Piping it again does not create a duplicate.
+    let (ok, _) = mihi(
+        home.path(),
+        &["--no-input", "words", "create", "--on-conflict", "skip"],
+        "rosa, rosae\n",
+    );
+    assert!(ok);
+
+    let (ok, out) = mihi(home.path(), &["--no-input", "practice"], "rose\n");
+    assert!(ok, "{out}");
+    assert!(out.contains("Word: rosa, rosae"), "{out}");
+    assert!(out.contains("✓ rose"), "{out}");
+    assert!(out.contains("Accuracy: 100.0%"), "{out}");
+    assert!(!out.contains("Missed:"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["--no-input", "practice"], "thorn\n");
+    assert!(ok, "{out}");
+    assert!(out.contains("❌rose"), "{out}");
+    assert!(out.contains("Incorrect: 1"), "{out}");
+    assert!(out.contains("Missed:\n      rosa, rosae"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--reverse"],
+        "Rosa\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Translation (english): rose"), "{out}");
+    assert!(out.contains("✓ rosa, rosae"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["stats"], "");
+    assert!(ok);
+    assert!(out.contains("noun: 1"), "{out}");
+    assert!(out.contains("Total: 1"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["stats", "history"], "");
+    assert!(ok);
+    assert!(out.contains("Streak: 1 day"), "{out}");
+    assert!(out.contains("rosa, rosae: 2/3 (66.7%, "), "{out}");
+    let (ok, out) = mihi(home.path(), &["stats", "history", "--csv"], "");
+    assert!(ok);
+    assert!(out.contains("word,\"rosa, rosae\",3,2,66.7"), "{out}");
+    let (ok, out) = mihi(home.path(), &["stats", "history", "--json"], "");
+    assert!(ok);
+    assert!(out.contains("\"streak\":1"), "{out}");
+
+    // Timed sessions also count the answers given after the time limit.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--timed", "1"],
+        "rose\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("You have 1s to answer"), "{out}");
+    assert!(out.contains("Average response: "), "{out}");
+    assert!(out.contains("Timeouts: "), "{out}");
+    let (ok, out) = mihi(home.path(), &["practice", "--timed", "0"], "");
+    assert!(!ok);
+    assert!(out.contains("positive number of seconds"), "{out}");
+}
+
+#[test]
+fn test_coverage() {
+    let home = init();
+
+    let (ok, out) = mihi(home.path(), &["stats", "--coverage"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("there are no coverage goals"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["init", "-l", "latin", "-g", "a=1", "--goal", "istem=2"],
+        "",
+    );
+    assert!(ok, "{out}");
+
+    let (ok, out) = mihi(home.path(), &["stats", "--coverage"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("✓ a: 1/1"), "{out}");
+    assert!(
+        out.contains("istem (i-stem; '-i-' also in the genitive plural): 0/2 (2 missing)"),
+        "{out}"
+    );
+    assert!(out.contains("mihi words sample --fill-gaps"), "{out}");
+
+    // Goals are merged, and a target of zero removes them.
+    let (ok, out) = mihi(
+        home.path(),
+        &["init", "-l", "latin", "-g", "istem=0", "-g", "a=3"],
+        "",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["stats", "--coverage"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("a: 1/3 (2 missing)"), "{out}");
+    assert!(!out.contains("istem"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["init", "-l", "latin", "-g", "a=many"], "");
+    assert!(!ok);
+    assert!(out.contains("has to be a number"), "{out}");
+    let (ok, out) = mihi(home.path(), &["init", "-l", "latin", "-g", "=1"], "");
+    assert!(!ok);
+    assert!(out.contains("'<KIND>=<N>' value"), "{out}");
+}
+
+#[test]
+fn test_piped_input_without_no_input() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+
+    // Prompts cannot be answered from a pipe unless '--no-input' is given.
+    let (ok, out) = mihi(home.path(), &["practice"], "rose\n");
+    assert!(!ok);
+    assert_eq!(
+        out.trim(),
+        "error: practice: this command needs an interactive terminal"
+    );
+    let (ok, out) = mihi(home.path(), &["exercises", "run", "--minutes", "5"], "\n");
+    assert!(!ok);
+    assert_eq!(
+        out.trim(),
+        "error: exercises: this command needs an interactive terminal"
+    );
+    let (ok, out) = mihi(home.path(), &["words", "create"], CREATE_AMO);
+    assert!(!ok);
+    assert_eq!(
+        out.trim(),
+        "error: words: this command needs an interactive terminal"
+    );
+
+    // Destructive actions have to be explicit.
+    let (ok, out) = mihi(home.path(), &["words", "rm", "rosa"], "y\n");
+    assert!(!ok);
+    assert!(out.contains("pass '-y/--yes' to do it anyway"), "{out}");
+
+    // Nothing was changed, and whatever needs no prompts still works.
+    let (ok, out) = mihi(
+        home.path(),
+        &["words", "create", "--on-conflict", "skip"],
+        "rosa, rosae\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Skipping 'rosa, rosae'."), "{out}");
+    let (ok, out) = mihi(home.path(), &["words", "ls"], "");
+    assert!(ok, "{out}");
+    assert_eq!(out.trim(), "rosa, rosae");
+}
+
+#[test]
+fn test_create_with_chapter() {
+    let home = init();
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "words", "create", "--tag-prefix", "ch-"],
+        &CREATE_ROSA.replacen("rosa, rosae", "rosa, rosae\t01", 1),
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Word 'rosa, rosae' has been successfully created!"));
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "words", "create"],
+        &CREATE_AMO.replacen("amātum", "amātum;  ", 1),
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("has been successfully created!"), "{out}");
+
+    // Only the line with a chapter column got a tag, which is prefixed.
+    let (ok, out) = mihi(home.path(), &["tags", "ls"], "");
+    assert!(ok, "{out}");
+    assert_eq!(out.trim(), "ch-01");
+    let (ok, out) = mihi(home.path(), &["words", "ls", "-t", "ch-01"], "");
+    assert!(ok, "{out}");
+    assert_eq!(out.trim(), "rosa, rosae");
+
+    // Importing into an existing tag attaches to it without duplicating it,
+    // and words which already have it are left alone.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "words", "create", "--on-conflict", "merge"],
+        "amō, amāre, amāvī, amātum;ch-01\nrosa, rosae\tch-01\n",
+    );
+    assert!(ok, "{out}");
+    assert!(!out.contains("warning"), "{out}");
+    let (ok, out) = mihi(home.path(), &["tags", "ls"], "");
+    assert!(ok, "{out}");
+    assert_eq!(out.trim(), "ch-01");
+    let (ok, out) = mihi(home.path(), &["words", "ls", "-t", "ch-01"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("rosa, rosae") && out.contains("amō"), "{out}");
+}
+
+#[test]
+fn test_create_on_conflict() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "words", "create", "--on-conflict", "skip"],
+        "Rosa,  rosae\tcap01\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Skipping 'Rosa,  rosae'."), "{out}");
+    let (ok, out) = mihi(home.path(), &["tags", "ls"], "");
+    assert!(ok, "{out}");
+    assert!(!out.contains("cap01"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &[
+            "--no-input",
+            "words",
+            "create",
+            "--on-conflict",
+            "merge",
+            "--tag-prefix",
+            "ch-",
+        ],
+        "rosa, rosae;01\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Word 'rosa, rosae' has been merged."), "{out}");
+    let (ok, out) = mihi(home.path(), &["words", "ls", "-t", "ch-01"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("rosa, rosae"), "{out}");
+
+    // Collisions are also detected against words renamed on the same import.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "words", "create", "--on-conflict", "theirs"],
+        "rosā, rosae\nROSA, ROSAE\n",
+    );
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Word 'rosā, rosae' has been updated!"),
+        "{out}"
+    );
+    assert!(
+        out.contains("Word 'ROSA, ROSAE' has been updated!"),
+        "{out}"
+    );
+    let (ok, out) = mihi(home.path(), &["words", "ls"], "");
+    assert!(ok, "{out}");
+    assert_eq!(out.trim(), "ROSA, ROSAE");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "words", "create", "--on-conflict", "nope"],
+        "rosa, rosae\n",
+    );
+    assert!(!ok);
+    assert!(out.contains("unknown conflict policy 'nope'"), "{out}");
+}
+
+#[test]
+fn test_ls_group_by() {
+    let home = init();
+    for input in [CREATE_ROSA, CREATE_AMO] {
+        let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], input);
+        assert!(ok, "{out}");
+    }
+
+    let (ok, out) = mihi(home.path(), &["words", "ls", "--group-by", "category"], "");
+    assert!(ok, "{out}");
+    assert_eq!(
+        out.trim(),
+        "== noun (1) ==\nrosa, rosae\n\n== verb (1) ==\namō, amāre, amāvī, amātum\n\nTotal: 2"
+    );
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["words", "ls", "--group-by", "gender", "--json"],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert_eq!(
+        out.trim(),
+        r#"{"feminine":["rosa, rosae"],"none":["amō, amāre, amāvī, amātum"]}"#
+    );
+
+    // Filters apply before grouping.
+    let (ok, out) = mihi(
+        home.path(),
+        &["words", "ls", "--group-by", "conjugation", "amō"],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert_eq!(
+        out.trim(),
+        "== 1st (ā stems) (1) ==\namō, amāre, amāvī, amātum\n\nTotal: 1"
+    );
+
+    let (ok, out) = mihi(home.path(), &["words", "ls", "--group-by", "case"], "");
+    assert!(!ok);
+    assert!(
+        out.contains("'--group-by' expects one of: declension, conjugation, gender, category"),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_principal_parts() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_AMO);
+    assert!(ok, "{out}");
+
+    // Translations have to match as a whole, but leading particles are
+    // optional.
+    let (ok, out) = mihi(home.path(), &["--no-input", "practice"], "o\n");
+    assert!(ok, "{out}");
+    assert!(out.contains("❌to love"), "{out}");
+    let (ok, out) = mihi(home.path(), &["--no-input", "practice"], "Love\n");
+    assert!(ok, "{out}");
+    assert!(out.contains("✓ to love"), "{out}");
+
+    // Omitted macrons are tolerated by default, but not misplaced ones.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--principal-parts"],
+        "amare\nāmavi\namātum\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Verb: amō"), "{out}");
+    assert!(out.contains("✓ amāre"), "{out}");
+    assert!(out.contains("❌amāvī"), "{out}");
+    assert!(out.contains("✓ amātum"), "{out}");
+
+    // '--strict' overrides the configuration for a single session.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--principal-parts", "--strict"],
+        "amare\namāvī\namātum\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("❌amāre"), "{out}");
+
+    let (ok, _) = mihi(home.path(), &["init", "--macrons", "strict"], "");
+    assert!(ok);
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--principal-parts"],
+        "amare\namāvī\namātum\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("❌amāre"), "{out}");
+    assert!(out.contains("✓ amāvī"), "{out}");
+}
+
+#[test]
+fn test_resume() {
+    let home = init();
+    for input in [CREATE_ROSA, CREATE_AMO] {
+        let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], input);
+        assert!(ok, "{out}");
+    }
+
+    // Running out of answers stops the session with one word left.
+    let (ok, out) = mihi(home.path(), &["--no-input", "practice", "--reverse"], "x\n");
+    assert!(ok, "{out}");
+    assert!(out.contains("You left 1 word for later"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["--no-input", "practice", "--resume"], "x\n");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Resuming your last session with 1 word left"),
+        "{out}"
+    );
+    assert!(out.contains("Latin:"), "{out}");
+    assert!(!out.contains("for later"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["--no-input", "practice", "--resume"], "");
+    assert!(!ok);
+    assert!(out.contains("no interrupted session"), "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--resume", "--reverse"],
+        "",
+    );
+    assert!(!ok);
+    assert!(out.contains("can only be combined with"), "{out}");
+}
+
+#[test]
+fn test_resume_with_flags() {
+    let home = init();
+    for input in [
+        CREATE_ROSA,
+        CREATE_AMO,
+        &CREATE_ROSA
+            .replacen("rosa, rosae", "puella, puellae", 1)
+            .replacen("rose\nrosa", "girl\nnoia", 1),
+    ] {
+        let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], input);
+        assert!(ok, "{out}");
+    }
+    let conn = rusqlite::Connection::open(database_path(home.path())).unwrap();
+    conn.execute(
+        "UPDATE words SET flags = '{\"onlysingular\":true}' WHERE enunciated NOT LIKE 'am%'",
+        [],
+    )
+    .unwrap();
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--flags", "onlysingular"],
+        "x\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("You left 1 word for later"), "{out}");
+    assert!(!out.contains("amō"), "{out}");
+
+    // The flags are kept when the session is interrupted again.
+    let (ok, out) = mihi(home.path(), &["--no-input", "practice", "--resume"], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Resuming your last session with 1 word left"),
+        "{out}"
+    );
+    assert!(!out.contains("amō"), "{out}");
+    let (flags, tags): (String, String) = conn
+        .query_row("SELECT flags, tags FROM pending_sessions", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!(flags, "onlysingular");
+    assert_eq!(tags, "");
+}
+
+#[test]
+fn test_voice_drill() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_AMO);
+    assert!(ok, "{out}");
+
+    // Answers go into the summary and into the history, as with any other
+    // drill.
+    let (ok, out) = mihi(home.path(), &["--no-input", "practice", "--voice"], "x\n");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Active: x") || out.contains("Passive: x"),
+        "{out}"
+    );
+    assert!(out.contains("Questions: 1"), "{out}");
+    assert!(out.contains("Incorrect: 1"), "{out}");
+    let (ok, out) = mihi(home.path(), &["stats", "history"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("verb: 0/1 (0.0%, "), "{out}");
+    let (kind, given): (String, String) = rusqlite::Connection::open(database_path(home.path()))
+        .unwrap()
+        .query_row(
+            "SELECT s.kind, a.given FROM sessions s \
+             JOIN session_answers a ON a.session_id = s.id",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(kind, "voice");
+    assert_eq!(given, "x");
+}
+
+#[test]
+fn test_practice_words() {
+    let home = init();
+    for input in [CREATE_ROSA, CREATE_AMO] {
+        let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], input);
+        assert!(ok, "{out}");
+    }
+
+    // Only the given word is asked, and macrons can be omitted.
+    let (ok, out) = mihi(
+        home.path(),
+        &[
+            "--no-input",
+            "practice",
+            "--word",
+            "amo, amare, amavi, amatum",
+        ],
+        "to love\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Word: amō, amāre, amāvī, amātum"), "{out}");
+    assert!(out.contains("Questions: 1"), "{out}");
+    assert!(!out.contains("rosa"), "{out}");
+
+    // Each answer is followed by a card about the word.
+    assert!(out.contains("   Conjugation: "), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--word", "leō, leōnis"],
+        "",
+    );
+    assert!(!ok);
+    assert!(out.contains("'leō, leōnis' does not exist"), "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &[
+            "--no-input",
+            "practice",
+            "--principal-parts",
+            "--word",
+            "rosa, rosae",
+        ],
+        "",
+    );
+    assert!(!ok);
+    assert!(out.contains("'rosa, rosae' is not a verb"), "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &[
+            "--no-input",
+            "practice",
+            "--word",
+            "rosa, rosae",
+            "--category",
+            "noun",
+        ],
+        "",
+    );
+    assert!(!ok);
+    assert!(out.contains("'--word' cannot be combined"), "{out}");
+}
+
+#[test]
+fn test_practice_notes() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+
+    // Notes are taken instead of answering, and then the word is asked again.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--word", "rosa, rosae"],
+        ":note first declension\n:session review the genitive\n:note \nrose\n",
+    );
+    assert!(ok, "{out}");
+    assert_eq!(out.matches("Note saved.").count(), 2, "{out}");
+    assert!(
+        out.contains("warning: practice: the note is empty"),
+        "{out}"
+    );
+    assert!(out.contains("Questions: 1"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["words", "show", "rosa, rosae"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Notes:\n- ["), "{out}");
+    assert!(out.contains("] first declension"), "{out}");
+    assert!(!out.contains("review the genitive"), "{out}");
+
+    // Sessions are told apart by when they started, so move the last one back
+    // in time instead of waiting for the next one.
+    rusqlite::Connection::open(database_path(home.path()))
+        .unwrap()
+        .execute("UPDATE notes SET session = session - 60", [])
+        .unwrap();
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--word", "rosa, rosae"],
+        "rose\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Notes from your last session:"), "{out}");
+    assert!(out.contains("] review the genitive"), "{out}");
+    assert!(
+        out.contains("Note [") && out.contains("] first declension"),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_locale_fallbacks() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    rusqlite::Connection::open(database_path(home.path()))
+        .unwrap()
+        .execute("UPDATE words SET translation = '{\"en\":\"rose\"}'", [])
+        .unwrap();
+    let catalan = [("LC_ALL", "ca_ES.UTF-8")];
+
+    // By default words with no translation in the current locale are asked in
+    // English.
+    let (ok, out) = mihi_with_env(
+        home.path(),
+        &catalan,
+        &["--no-input", "practice", "--word", "rosa, rosae"],
+        "rose\n",
+    );
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Translation (english, fallback): rose"),
+        "{out}"
+    );
+    assert!(out.contains("Questions: 1"), "{out}");
+
+    // Without fallbacks these words are skipped.
+    let (ok, out) = mihi(
+        home.path(),
+        &["init", "-l", "latin", "--fallback-locales", "none"],
+        "",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi_with_env(
+        home.path(),
+        &catalan,
+        &["--no-input", "practice", "--word", "rosa, rosae"],
+        "rose\n",
+    );
+    assert!(ok, "{out}");
+    assert!(!out.contains("Translation ("), "{out}");
+
+    // Words which have it are still asked in the current locale.
+    let (ok, out) = mihi_with_env(
+        home.path(),
+        &[("LC_ALL", "en_US.UTF-8")],
+        &["--no-input", "practice", "--word", "rosa, rosae"],
+        "rose\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Translation (english): rose"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["init", "-l", "latin", "--fallback-locales", "en,fr"],
+        "",
+    );
+    assert!(!ok);
+    assert!(
+        out.contains("unknown locale 'fr'. Available: en, ca"),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_practice_tags() {
+    let home = init();
+    for input in [
+        CREATE_ROSA,
+        CREATE_AMO,
+        &CREATE_ROSA
+            .replacen("rosa, rosae", "puella, puellae", 1)
+            .replacen("rose\nrosa", "girl\nnoia", 1),
+    ] {
+        let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], input);
+        assert!(ok, "{out}");
+    }
+    for (tag, filter) in [("flowers", "rosa"), ("verbs", "amō")] {
+        let (ok, out) = mihi(home.path(), &["tags", "create", tag], "");
+        assert!(ok, "{out}");
+        let (ok, out) = mihi(
+            home.path(),
+            &["tags", "attach", tag, "--filter", filter],
+            "",
+        );
+        assert!(ok, "{out}");
+    }
+
+    // Words with any of the given tags are asked, and unknown tags are
+    // skipped.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--tags", "flowers, verbs,,nope"],
+        "rose\nto love\n",
+    );
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("warning: practice: the tag 'nope' does not exist."),
+        "{out}"
+    );
+    assert!(out.contains("Word: rosa, rosae"), "{out}");
+    assert!(out.contains("Word: amō, amāre, amāvī, amātum"), "{out}");
+    assert!(!out.contains("puella"), "{out}");
+
+    // The flag can also be given multiple times.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "-t", "flowers", "--tag", "verbs"],
+        "rose\nto love\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Word: rosa, rosae"), "{out}");
+    assert!(out.contains("Word: amō, amāre, amāvī, amātum"), "{out}");
+    assert!(!out.contains("puella"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "-t", "flowers"],
+        "rose\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Word: rosa, rosae"), "{out}");
+    assert!(!out.contains("amō") && !out.contains("puella"), "{out}");
+}
+
+#[test]
+fn test_new_words() {
+    let home = TempDir::new().unwrap();
+    let (ok, out) = mihi(
+        home.path(),
+        &["init", "-l", "latin", "--new-words", "1"],
+        "",
+    );
+    assert!(ok, "{out}");
+    for input in [CREATE_ROSA, CREATE_AMO] {
+        let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], input);
+        assert!(ok, "{out}");
+    }
+
+    // Nothing has been reviewed yet.
+    let (ok, out) = mihi(
+        home.path(),
+        &[
+            "--no-input",
+            "practice",
+            "--review-only",
+            "--category",
+            "noun",
+        ],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert!(!out.contains("rosa"), "{out}");
+
+    // New words are shown before being asked, and only one of them is
+    // introduced on each session.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--new-only", "--endless"],
+        "rose\nto love\n",
+    );
+    assert!(ok, "{out}");
+    assert_eq!(out.matches("New word: ").count(), 1, "{out}");
+    assert!(out.contains("introduced to all the new words"), "{out}");
+    assert!(out.contains("Questions: 1"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--review-only", "--reverse"],
+        "rosa\n",
+    );
+    assert!(ok, "{out}");
+    assert!(!out.contains("New word: "), "{out}");
+    assert!(out.contains("Questions: 1"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--new-only", "--review-only"],
+        "",
+    );
+    assert!(!ok);
+    assert!(out.contains("cannot be combined"), "{out}");
+}
+
+#[test]
+fn test_exam() {
+    let home = init();
+    for input in [CREATE_ROSA, CREATE_AMO] {
+        let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], input);
+        assert!(ok, "{out}");
+    }
+
+    let (ok, out) = mihi(home.path(), &["stats", "exams"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("there are no exams yet"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &[
+            "--no-input",
+            "practice",
+            "--exam",
+            "--count",
+            "1",
+            "-c",
+            "noun",
+        ],
+        "rose\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Word: rosa, rosae"), "{out}");
+    assert!(out.contains("Score: 1/1 (100%)"), "{out}");
+    assert!(out.contains("noun: 1/1"), "{out}");
+
+    // Exams do not count as reviews.
+    let reviewed: i64 = rusqlite::Connection::open(database_path(home.path()))
+        .unwrap()
+        .query_row(
+            "SELECT COUNT(*) FROM words WHERE steps > 0 OR interval_days > 0",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(reviewed, 0);
+
+    let (ok, out) = mihi(home.path(), &["stats", "exams"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Material: practice, noun"), "{out}");
+    assert!(out.contains("Score: 1/1 (100%)"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--exam", "--reverse"],
+        "",
+    );
+    assert!(!ok);
+    assert!(out.contains("'--exam' can only be combined"), "{out}");
+    let (ok, out) = mihi(home.path(), &["--no-input", "practice", "--count", "3"], "");
+    assert!(!ok);
+    assert!(
+        out.contains("'--count' can only be used with '--exam'"),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_suspend() {
+    let home = init();
+    for input in [CREATE_ROSA, CREATE_AMO] {
+        let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], input);
+        assert!(ok, "{out}");
+    }
+
+    let (ok, out) = mihi(home.path(), &["words", "suspend", "rosa, rosae"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Suspended 'rosa, rosae'"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["stats"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Total: 2"), "{out}");
+    assert!(out.contains("Suspended: 1"), "{out}");
+
+    // Suspended words are never picked.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--category", "noun"],
+        "rose\n",
+    );
+    assert!(ok, "{out}");
+    assert!(!out.contains("rosa"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["words", "suspend", "rosa, rosae"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("no longer suspended"), "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--category", "noun"],
+        "rose\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Word: rosa, rosae"), "{out}");
+}
+
+#[test]
+fn test_recent_words_go_last() {
+    let home = TempDir::new().unwrap();
+    let (ok, out) = mihi(
+        home.path(),
+        &["init", "-l", "latin", "--scheduler", "legacy"],
+        "",
+    );
+    assert!(ok, "{out}");
+    for input in [
+        CREATE_ROSA,
+        "puella, puellae\n\n\n\n\n\n\n\n\n\ngirl\nniña\n\n\n",
+    ] {
+        let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], input);
+        assert!(ok, "{out}");
+    }
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--word", "rosa, rosae"],
+        "rose\n",
+    );
+    assert!(ok, "{out}");
+
+    // 'rosa' was just asked, so it's only asked again after 'puella'.
+    for _ in 0..5 {
+        let (ok, out) = mihi(
+            home.path(),
+            &["--no-input", "practice", "--category", "noun"],
+            "girl\nrose\n",
+        );
+        assert!(ok, "{out}");
+        let puella = out.find("Word: puella").unwrap();
+        assert!(puella < out.find("Word: rosa").unwrap(), "{out}");
+
+        // Now both of them were asked recently, so start over.
+        rusqlite::Connection::open(database_path(home.path()))
+            .unwrap()
+            .execute_batch("UPDATE words SET last_asked_at = NULL WHERE enunciated LIKE 'puella%'")
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_nested_tags() {
+    let home = init();
+    for input in [CREATE_ROSA, CREATE_AMO] {
+        let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], input);
+        assert!(ok, "{out}");
+    }
+
+    // Parents are created along with their children.
+    let (ok, out) = mihi(home.path(), &["tags", "create", "llpsi/cap09"], "");
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["tags", "ls"], "");
+    assert!(ok, "{out}");
+    assert_eq!(out.lines().collect::<Vec<_>>(), ["llpsi", "llpsi/cap09"]);
+
+    rusqlite::Connection::open(database_path(home.path()))
+        .unwrap()
+        .execute_batch(
+            "INSERT INTO tag_associations (word_id, tag_id, created_at, updated_at) \
+                 SELECT w.id, t.id, datetime('now'), datetime('now') FROM words w, tags t \
+                 WHERE w.enunciated LIKE 'rosa%' AND t.name = 'llpsi/cap09';",
+        )
+        .unwrap();
+
+    // Filtering by the parent includes the words from its children.
+    let (ok, out) = mihi(home.path(), &["words", "-t", "llpsi", "ls"], "");
+    assert!(ok, "{out}");
+    assert_eq!(out.trim(), "rosa, rosae");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--reverse", "--tags", "llpsi"],
+        "rosa\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Questions: 1"), "{out}");
+    assert!(!out.contains("to love"), "{out}");
+
+    // The stats of the parent include the words from its children.
+    rusqlite::Connection::open(database_path(home.path()))
+        .unwrap()
+        .execute_batch(
+            "INSERT INTO tag_associations (word_id, tag_id, created_at, updated_at) \
+                 SELECT w.id, t.id, datetime('now'), datetime('now') FROM words w, tags t \
+                 WHERE w.enunciated LIKE 'amō%' AND t.name = 'llpsi';",
+        )
+        .unwrap();
+    let (ok, out) = mihi(home.path(), &["tags", "show", "llpsi"], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("amō, amāre, amāvī, amātum: never asked"),
+        "{out}"
+    );
+    assert!(out.contains("rosa, rosae: 1/1 (100.0%)"), "{out}");
+    assert!(out.contains("Mastery: 50.0% of 2 words"), "{out}");
+}
+
+#[test]
+fn test_attach_tag_to_matching_words() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_AMO);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["tags", "create", "basics"], "");
+    assert!(ok, "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &[
+            "tags",
+            "attach",
+            "basics",
+            "--category",
+            "noun",
+            "--dry-run",
+        ],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Would tag 1 words with 'basics'"), "{out}");
+    assert!(out.contains("rosa"), "{out}");
+    let (ok, out) = mihi(home.path(), &["words", "ls", "-t", "basics"], "");
+    assert!(ok, "{out}");
+    assert!(out.trim().is_empty(), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["tags", "attach", "basics", "--filter", "a"],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Tagged 2 words with 'basics'"), "{out}");
+    let (ok, out) = mihi(home.path(), &["words", "ls", "-t", "basics"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("rosa") && out.contains("amō"), "{out}");
+
+    // Words which already have the tag are left alone.
+    let (ok, out) = mihi(home.path(), &["tags", "attach", "basics"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("No words to be tagged"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["tags", "attach", "nope"], "");
+    assert!(!ok);
+    assert!(out.contains("the tag 'nope' does not exist"), "{out}");
+}
+
+#[test]
+fn test_tags_with_quotes() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["tags", "create", "Cicero's"], "");
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["tags", "attach", "Cicero's"], "");
+    assert!(ok, "{out}");
+
+    let (ok, out) = mihi(home.path(), &["words", "ls", "-t", "Cicero's"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("rosa"), "{out}");
+    let (ok, out) = mihi(home.path(), &["tags", "show", "Cicero's"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("rosa, rosae: never asked"), "{out}");
+}
+
+#[test]
+fn test_tag_metadata() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &[
+            "tags",
+            "create",
+            "llpsi",
+            "--description",
+            "Lingua Latina per se illustrata",
+            "--color",
+            "blue",
+        ],
+        "",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "tags", "create", "flowers"],
+        "\ngreen\n",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["tags", "create", "animals", "--color", "purple"],
+        "",
+    );
+    assert!(!ok);
+    assert!(out.contains("unknown color 'purple'"), "{out}");
+
+    rusqlite::Connection::open(database_path(home.path()))
+        .unwrap()
+        .execute_batch(
+            "INSERT INTO tag_associations (word_id, tag_id, created_at, updated_at) \
+                 SELECT w.id, t.id, datetime('now'), datetime('now') FROM words w, tags t;",
+        )
+        .unwrap();
+
+    let (ok, out) = mihi(home.path(), &["words", "show", "rosa, rosae"], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains(
+            "- \x1b[92mflowers\x1b[0m\n- \x1b[94mllpsi\x1b[0m: Lingua Latina per se illustrata"
+        ),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_due() {
+    let home = init();
+    for input in [CREATE_ROSA, CREATE_AMO] {
+        let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], input);
+        assert!(ok, "{out}");
+    }
+
+    let (ok, out) = mihi(home.path(), &["due"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Nothing is due"), "{out}");
+
+    // Pretend that both words were reviewed a while ago, with only 'rosa'
+    // being tagged.
+    rusqlite::Connection::open(database_path(home.path()))
+        .unwrap()
+        .execute_batch(
+            "UPDATE words SET due_at = datetime('now', '-1 day'); \
+             INSERT INTO tags (name, created_at, updated_at) \
+                 VALUES ('prima', datetime('now'), datetime('now')); \
+             INSERT INTO tag_associations (word_id, tag_id, created_at, updated_at) \
+                 SELECT id, 1, datetime('now'), datetime('now') FROM words \
+                 WHERE enunciated LIKE 'rosa%';",
+        )
+        .unwrap();
+
+    let (ok, out) = mihi(home.path(), &["due"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("2 words are due"), "{out}");
+    assert!(out.contains("noun: 1"), "{out}");
+    assert!(out.contains("prima: 1"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["due", "--json"], "");
+    assert!(ok, "{out}");
+    let value: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+    assert_eq!(value["total"], 2);
+    assert_eq!(value["categories"]["verb"], 1);
+    assert_eq!(value["tags"]["prima"], 1);
+}
+
+#[test]
+fn test_gender_drill() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--drill", "gender"],
+        "masculine\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Noun: rosa, rosae"), "{out}");
+    assert!(out.contains("❌feminine"), "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--drill", "gender"],
+        "2\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("✓ feminine"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["stats", "--drill", "gender"], "");
+    assert!(ok);
+    assert!(out.contains("Attempts: 2"), "{out}");
+    assert!(out.contains("feminine taken as masculine: 1"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["practice", "--drill", "case"], "");
+    assert!(!ok);
+    assert!(out.contains("unknown drill 'case'"), "{out}");
+}
+
+#[test]
+fn test_comparison_drill() {
+    let home = init();
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "words", "create"],
+        "altus, alta, altum\n\nalt\nadjective\n\n\n\n\nhigh\nalt\n\n\n",
+    );
+    assert!(ok, "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--drill", "comparison"],
+        "altior\naltisimus\naltē\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Adjective: altus, alta, altum"), "{out}");
+    assert!(out.contains("✓ altior, altius"), "{out}");
+    assert!(out.contains("❌altissimus, altissima, altissimum"), "{out}");
+    assert!(out.contains("✓ altē"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["stats", "--drill", "comparison"], "");
+    assert!(ok);
+    assert!(out.contains("Attempts: 3"), "{out}");
+    assert!(
+        out.contains("altissimus, altissima, altissimum taken as altisimus: 1"),
+        "{out}"
+    );
+
+    let (ok, out) = mihi(
+        home.path(),
+        &[
+            "--no-input",
+            "practice",
+            "--drill",
+            "comparison",
+            "--category",
+            "noun",
+        ],
+        "",
+    );
+    assert!(!ok);
+    assert!(out.contains("can only be used with adjectives"), "{out}");
+}
+
+#[test]
+fn test_exercises() {
+    let home = init();
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Rose\n\nTranslate: the rose and the girl\nrosa\\npuella\n\n\n",
+    );
+    assert!(ok, "{out}");
+
+    // The answer goes after the '---!' line from the editor.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "ros"],
+        "---!\\nrosam\\npuella\n4\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("- rosam"), "{out}");
+    assert!(out.contains("+ rosa"), "{out}");
+    assert!(out.contains("  puella"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "Rose"],
+        "---!\\nrosa\\npuella\n6\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Last score for 'Rose': 3/5"), "{out}");
+    let (ok, out) = mihi(home.path(), &["exercises", "show", "Rose"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Attempts: 2 (last on "), "{out}");
+
+    // Exercises which were never attempted go before the ones with good
+    // scores.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Girl\n\nTranslate: the girl\npuella\n\n\n",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "--minutes", "5"],
+        "---!\\npuella\n5\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Enunciate for 'Girl'"), "{out}");
+    assert!(!out.contains("Enunciate for 'Rose'"), "{out}");
+
+    // Blanks written as '___' take their answers from the solution.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Sailor\n2\nNauta in [marī|mare] nāvigat et ___ videt.\nNauta in marī nāvigat et rosam videt.\n\n\n",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "Sailor"],
+        "mare\nrosās\n",
+    );
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Nauta in ___(1) nāvigat et ___(2) videt."),
+        "{out}"
+    );
+    assert!(out.contains("❌(2): 'rosās'; expected: rosam"), "{out}");
+
+    // Numerical exercises are generated, so only their kind has to be given.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Numbers\n3\n\n\n",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "Numbers"],
+        "0\n0\n0\n0\n0\n",
+    );
+    assert!(ok, "{out}");
+    assert_eq!(out.matches("❌").count(), 5, "{out}");
+
+    let (ok, out) = mihi(home.path(), &["exercises", "run"], "");
+    assert!(!ok);
+    assert!(out.contains("you have to provide '--minutes'"), "{out}");
+}
+
+#[test]
+fn test_timed_exercises() {
+    let home = TempDir::new().unwrap();
+    let (ok, out) = mihi(
+        home.path(),
+        &["init", "-l", "latin", "--scheduler", "legacy"],
+        "",
+    );
+    assert!(ok, "{out}");
+    for input in [
+        "Long\n\nTranslate: the rose\nrosa\n\n10\n",
+        "Short\n\nTranslate: the girl\npuella\n\n2\n",
+    ] {
+        let (ok, out) = mihi(home.path(), &["--no-input", "exercises", "create"], input);
+        assert!(ok, "{out}");
+    }
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "--minutes", "1"],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("no exercises fit in 1 minutes"), "{out}");
+
+    // Exercises which do not fit are skipped in favor of shorter ones.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "--minutes", "5"],
+        "---!\\npuella\n6\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Enunciate for 'Short'"), "{out}");
+    assert!(!out.contains("Enunciate for 'Long'"), "{out}");
+
+    // Once attempted, exercises are estimated by how long they took.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "Long"],
+        "---!\\nrosa\n6\n",
+    );
+    assert!(ok, "{out}");
+    let (attempts, seconds): (i64, i64) = rusqlite::Connection::open(database_path(home.path()))
+        .unwrap()
+        .query_row(
+            "SELECT COUNT(*), MAX(seconds) FROM exercise_attempts",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(attempts, 2);
+    assert!(seconds < 60, "{seconds}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "--minutes", "1"],
+        "---!\\nrosa\n6\n---!\\npuella\n6\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Enunciate for 'Long'"), "{out}");
+    assert!(out.contains("Enunciate for 'Short'"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["exercises", "run", "--minutes", "0"], "");
+    assert!(!ok);
+    assert!(
+        out.contains("'--minutes' expects a positive number"),
+        "{out}"
+    );
+    let (ok, out) = mihi(
+        home.path(),
+        &["exercises", "run", "Long", "--minutes", "5"],
+        "",
+    );
+    assert!(!ok);
+    assert!(
+        out.contains("a title cannot be combined with '--minutes'"),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_mixed_practice() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Sailor\n2\nNauta in [marī|mare] nāvigat.\nNauta in marī nāvigat.\n\n\n",
+    );
+    assert!(ok, "{out}");
+
+    // The exercise goes after the word, and both results are recorded.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--mixed"],
+        "rose\nmari\n",
+    );
+    assert!(ok, "{out}");
+    let word = out.find("Word: rosa, rosae").expect(&out);
+    let exercise = out.find("Nauta in ___(1) nāvigat.").expect(&out);
+    assert!(word < exercise, "{out}");
+    assert!(out.contains("✓ rose"), "{out}");
+    assert!(out.contains("✓ All the blanks are right!"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["stats", "history"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("noun: 1/1 (100.0%, "), "{out}");
+    let (ok, out) = mihi(home.path(), &["exercises", "show", "Sailor"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Attempts: 1 (last on "), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--mixed", "--exercises"],
+        "",
+    );
+    assert!(!ok);
+    assert!(
+        out.contains("'--mixed' cannot be combined with '--exercises'"),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_exercise_schedule() {
+    let home = init();
+    for title in ["Rose", "Girl"] {
+        let (ok, out) = mihi(
+            home.path(),
+            &["--no-input", "exercises", "create"],
+            format!("{title}\n\nTranslate: the {title}\nrosa\n\n\n").as_str(),
+        );
+        assert!(ok, "{out}");
+    }
+
+    // Once graded, an exercise is not due again until its interval passes.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "Rose"],
+        "---!\\nrosa\n6\n",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["exercises", "show", "Rose"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Due on: "), "{out}");
+    let (ok, out) = mihi(home.path(), &["exercises", "show", "Girl"], "");
+    assert!(ok, "{out}");
+    assert!(!out.contains("Due on: "), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "--minutes", "60"],
+        "---!\\nrosa\n6\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Enunciate for 'Girl'"), "{out}");
+    assert!(!out.contains("Enunciate for 'Rose'"), "{out}");
+}
+
+#[test]
+fn test_exercise_clone() {
+    let home = init();
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Rose\n1\nMake it plural: rosa\nrosae\n\n\n",
+    );
+    assert!(ok, "{out}");
+
+    // The suggested title is the original one with '(copy)', and everything
+    // else is kept unless edited.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "clone", "Rose"],
+        "\n\nMake it plural: puella\npuellae\n\n\n",
+    );
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Exercise 'Rose (copy)' has been successfully created!"),
+        "{out}"
+    );
+    let (ok, out) = mihi(home.path(), &["exercises", "show", "Rose (copy)"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Make it plural: puella"), "{out}");
+    assert!(out.contains("Attempts: 0"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "clone", "copy"],
+        "Rose (copy)\n\n\n\n\n\n",
+    );
+    assert!(!ok);
+    assert!(out.contains("'Rose (copy)' already exists"), "{out}");
+}
+
+#[test]
+fn test_exercise_tags() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["tags", "create", "llpsi/cap09"], "");
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["tags", "create", "grammar"], "");
+    assert!(ok, "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Rose\n\nTranslate: the rose\nrosa\n\n\nllpsi/cap09\n",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Numbers\n3\n\n\ngrammar\n",
+    );
+    assert!(ok, "{out}");
+
+    // Parent tags include the exercises from their children.
+    let (ok, out) = mihi(home.path(), &["exercises", "ls", "--tags", "llpsi"], "");
+    assert!(ok, "{out}");
+    assert_eq!(out.trim(), "Rose");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "edit", "Rose"],
+        "\n\n\n\n\n\ngrammar\n",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["exercises", "ls", "--tags", "grammar"], "");
+    assert!(ok, "{out}");
+    assert_eq!(out.trim(), "Numbers\nRose");
+    let (ok, out) = mihi(home.path(), &["exercises", "ls", "-t", "llpsi"], "");
+    assert!(ok, "{out}");
+    assert!(out.trim().is_empty(), "{out}");
+}
+
+#[test]
+fn test_show_exercise() {
+    let home = init();
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Rose\n\n# Translate\\nThe **rose**\nrosa\n- Nominative\n\n\n",
+    );
+    assert!(ok, "{out}");
+
+    // Markdown is only rendered on a terminal.
+    let (ok, out) = mihi(home.path(), &["exercises", "show", "Rose"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Title: Rose"), "{out}");
+    assert!(out.contains("Kind: Simple"), "{out}");
+    assert!(
+        out.contains("Enunciate:\n# Translate\nThe **rose**"),
+        "{out}"
+    );
+    assert!(out.contains("Solution:\nrosa"), "{out}");
+    assert!(out.contains("Lessons:\n- Nominative"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "show", "Rose", "--hide-solution"],
+        "n\n",
+    );
+    assert!(ok, "{out}");
+    assert!(!out.contains("Solution:"), "{out}");
+    assert!(out.contains("Lessons:"), "{out}");
+}
+
+#[test]
+fn test_export_and_import_exercises() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["tags", "create", "grammar"], "");
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Rose & Girl\n\nTranslate: the rose\nrosa\nFirst\\n\\nSecond\n\ngrammar\n",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Sailor\n2\nNauta in [marī|mare] nāvigat.\n\n\n\n",
+    );
+    assert!(ok, "{out}");
+
+    let dir = home.path().join("exported");
+    let dir_arg = dir.to_str().unwrap();
+    let (ok, out) = mihi(home.path(), &["exercises", "export", dir_arg], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Exported 2 exercises"), "{out}");
+    let rose = std::fs::read_to_string(dir.join("rose-girl.md")).unwrap();
+    assert!(
+        rose.starts_with("---\ntitle: Rose & Girl\nkind: simple\nminutes: 5\ntags: grammar\n"),
+        "{rose}"
+    );
+    assert!(
+        rose.contains("lessons: |\n  First\n\n  Second\n---\n"),
+        "{rose}"
+    );
+
+    // Exercises are created or updated depending on their title.
+    let (ok, out) = mihi(home.path(), &["exercises", "rm", "-y", "Rose"], "");
+    assert!(ok, "{out}");
+    let sailor = std::fs::read_to_string(dir.join("sailor.md")).unwrap();
+    std::fs::write(
+        dir.join("sailor.md"),
+        sailor.replace("minutes: 5", "minutes: 7"),
+    )
+    .unwrap();
+    std::fs::write(dir.join("broken.md"), "title: nope\n").unwrap();
+
+    let (ok, out) = mihi(home.path(), &["exercises", "import", dir_arg], "");
+    assert!(!ok);
+    assert!(out.contains("1 created, 1 updated"), "{out}");
+    assert!(
+        out.contains("broken.md: the front matter is missing"),
+        "{out}"
+    );
+    let (ok, out) = mihi(home.path(), &["exercises", "ls", "--tags", "grammar"], "");
+    assert!(ok, "{out}");
+    assert_eq!(out.trim(), "Rose & Girl");
+    let (ok, out) = mihi(home.path(), &["exercises", "show", "Sailor"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Expected minutes: 7"), "{out}");
+}
+
+#[test]
+fn test_exercise_words() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Rose\n\nTranslate: the rose\nrosa\n\n\n1\n",
+    );
+    assert!(ok, "{out}");
+
+    let (ok, out) = mihi(home.path(), &["words", "show", "rosa"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Exercises:\n- Rose"), "{out}");
+
+    // The exercise is only proposed once its words have been mastered.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "--minutes", "5"],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("no exercises fit in 5 minutes"), "{out}");
+
+    let conn = rusqlite::Connection::open(database_path(home.path())).unwrap();
+    conn.execute("UPDATE words SET succeeded = 1", []).unwrap();
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "--minutes", "5"],
+        "---!\\nrosa\n5\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Enunciate for 'Rose'"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["exercises", "show", "Rose"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Words: rosa, rosae"), "{out}");
+}
+
+#[test]
+fn test_cascading_deletes() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    for tag in ["llpsi", "grammar"] {
+        let (ok, out) = mihi(home.path(), &["tags", "create", tag], "");
+        assert!(ok, "{out}");
+        let (ok, out) = mihi(home.path(), &["tags", "attach", tag], "");
+        assert!(ok, "{out}");
+    }
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Rose\n\nTranslate: the rose\nrosa\n\n\nllpsi,grammar\n\n",
+    );
+    assert!(ok, "{out}");
+
+    let count = |table: &str| -> i64 {
+        rusqlite::Connection::open(database_path(home.path()))
+            .unwrap()
+            .query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| {
+                row.get(0)
+            })
+            .unwrap()
+    };
+    assert_eq!(count("tag_associations"), 2);
+    assert_eq!(count("exercise_tag_associations"), 2);
+
+    // Answers go away with their session.
+    let conn = rusqlite::Connection::open(database_path(home.path())).unwrap();
+    conn.execute_batch(
+        "PRAGMA foreign_keys = ON; \
+         INSERT INTO sessions (kind, asked, correct, tags, started_at, finished_at) \
+             VALUES ('words', 1, 1, '', datetime('now'), datetime('now')), \
+                    ('words', 1, 0, '', datetime('now'), datetime('now')); \
+         INSERT INTO session_answers (session_id, word_id, given, succeeded, created_at) \
+             VALUES (1, 1, 'rose', 1, datetime('now')), (2, 1, 'girl', 0, datetime('now')); \
+         INSERT INTO drill_attempts (word_id, drill, expected, given, succeeded, created_at) \
+             VALUES (1, 'cases', 'rosae', 'rosa', 0, datetime('now')); \
+         DELETE FROM sessions WHERE id = 2;",
+    )
+    .unwrap();
+    assert_eq!(count("session_answers"), 1);
+
+    // Removing rows also removes whatever was pointing to them.
+    let (ok, out) = mihi(home.path(), &["tags", "rm", "-y", "grammar"], "");
+    assert!(ok, "{out}");
+    assert_eq!(count("tag_associations"), 1);
+    assert_eq!(count("exercise_tag_associations"), 1);
+
+    let (ok, out) = mihi(home.path(), &["words", "rm", "-y", "rosa"], "");
+    assert!(ok, "{out}");
+    assert_eq!(count("tag_associations"), 0);
+    assert_eq!(count("session_answers"), 0);
+    assert_eq!(count("drill_attempts"), 0);
+    assert_eq!(count("sessions"), 1);
+
+    let (ok, out) = mihi(home.path(), &["exercises", "rm", "-y", "Rose"], "");
+    assert!(ok, "{out}");
+    assert_eq!(count("exercise_tag_associations"), 0);
+    assert_eq!(count("tags"), 1);
+}
+
+#[test]
+fn test_export_and_import() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["tags", "create", "llpsi"], "");
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["tags", "attach", "llpsi"], "");
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Rose\n\nTranslate: the rose\nrosa\n\n\nllpsi\n\n",
+    );
+    assert!(ok, "{out}");
+
+    let file = home.path().join("mihi.json");
+    let file = file.to_str().unwrap();
+    let (ok, out) = mihi(home.path(), &["export", file], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Exported 1 words, 1 exercises, 1 tags and 0 lessons"),
+        "{out}"
+    );
+
+    // Everything is imported into a brand new database, with words still
+    // tagged.
+    let other = init();
+    let (ok, out) = mihi(other.path(), &["import", file], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Words: 1 created, 0 updated, 0 skipped"),
+        "{out}"
+    );
+    assert!(out.contains("Exercises: 1 created"), "{out}");
+    let (ok, out) = mihi(other.path(), &["tags", "show", "llpsi"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("rosa, rosae"), "{out}");
+
+    // Existing items are skipped by default, and with 'merge' only the ones
+    // which were updated later are taken.
+    let (ok, out) = mihi(other.path(), &["import", file], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Words: 0 created, 0 updated, 1 skipped"),
+        "{out}"
+    );
+    let (ok, out) = mihi(
+        other.path(),
+        &["import", "--on-conflict", "merge", file],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Words: 0 created, 0 updated, 1 skipped"),
+        "{out}"
+    );
+    let (ok, out) = mihi(
+        other.path(),
+        &["import", "--on-conflict", "overwrite", file],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Words: 0 created, 1 updated, 0 skipped"),
+        "{out}"
+    );
+
+    let (ok, out) = mihi(
+        other.path(),
+        &["import", "--on-conflict", "theirs", file],
+        "",
+    );
+    assert!(!ok);
+    assert!(out.contains("unknown conflict policy 'theirs'"), "{out}");
+}
+
+#[test]
+fn test_export_and_import_history() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Rose\n\nTranslate: the rose\nrosa\n\n\n\n\n",
+    );
+    assert!(ok, "{out}");
+    rusqlite::Connection::open(database_path(home.path()))
+        .unwrap()
+        .execute_batch(
+            "INSERT INTO notes (word_id, session, body, created_at) \
+                 VALUES (1, 1, 'first declension', '2025-01-01 10:00:00'); \
+             INSERT INTO sessions (kind, asked, correct, tags, started_at, finished_at) \
+                 VALUES ('words', 1, 1, '', '2025-01-01 10:00:00', '2025-01-01 10:05:00'); \
+             INSERT INTO session_answers \
+                 (session_id, word_id, given, succeeded, response_ms, created_at) \
+                 VALUES (1, 1, 'rose', 1, 1200, '2025-01-01 10:01:00'); \
+             INSERT INTO drill_attempts (word_id, drill, expected, given, succeeded, created_at) \
+                 VALUES (1, 'cases', 'rosae', 'rosa', 0, '2025-01-01 11:00:00'); \
+             INSERT INTO exercise_attempts (exercise_id, seconds, succeeded, created_at) \
+                 VALUES (1, 90, 1, '2025-01-01 12:00:00'); \
+             INSERT INTO exams (tags, questions, score, breakdown, created_at) \
+                 VALUES ('', 10, 0.8, '[]', '2025-01-02 10:00:00'); \
+             UPDATE forms SET succeeded = 3, failed = 1, asked_at = '2025-01-01 10:00:00', \
+                              updated_at = '2025-01-01 10:00:00' \
+                 WHERE id = 1;",
+        )
+        .unwrap();
+
+    let file = home.path().join("mihi.json");
+    let file = file.to_str().unwrap();
+    let (ok, out) = mihi(home.path(), &["export", file], "");
+    assert!(ok, "{out}");
+
+    // The whole history makes it into another database, and importing it
+    // again does not duplicate it.
+    let other = init();
+    let (ok, out) = mihi(other.path(), &["import", file], "");
+    assert!(ok, "{out}");
+    for line in [
+        "Forms: 0 created, 1 updated, 0 skipped",
+        "Notes: 1 created",
+        "Sessions: 1 created",
+        "Session answers: 1 created, 0 updated, 0 skipped",
+        "Drill attempts: 1 created",
+        "Exercise attempts: 1 created",
+        "Exams: 1 created",
+    ] {
+        assert!(out.contains(line), "{out}");
+    }
+    let (ok, out) = mihi(other.path(), &["import", file], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Forms: 0 created, 0 updated, 1 skipped"),
+        "{out}"
+    );
+    assert!(
+        out.contains("Sessions: 0 created, 0 updated, 1 skipped"),
+        "{out}"
+    );
+
+    let conn = rusqlite::Connection::open(database_path(other.path())).unwrap();
+    let count = |query: &str| {
+        conn.query_row(query, [], |row| row.get::<usize, i64>(0))
+            .unwrap()
+    };
+    assert_eq!(
+        count(
+            "SELECT COUNT(*) FROM notes n JOIN words w ON w.id = n.word_id \
+             WHERE w.enunciated = 'rosa, rosae' AND n.body = 'first declension'"
+        ),
+        1
+    );
+    assert_eq!(
+        count(
+            "SELECT COUNT(*) FROM session_answers a JOIN sessions s ON s.id = a.session_id \
+             WHERE a.given = 'rose' AND a.response_ms = 1200 AND s.asked = 1"
+        ),
+        1
+    );
+    assert_eq!(count("SELECT COUNT(*) FROM drill_attempts"), 1);
+    assert_eq!(
+        count(
+            "SELECT COUNT(*) FROM exercise_attempts a JOIN exercises e ON e.id = a.exercise_id \
+             WHERE e.title = 'Rose' AND a.seconds = 90"
+        ),
+        1
+    );
+    assert_eq!(count("SELECT COUNT(*) FROM exams WHERE questions = 10"), 1);
+    assert_eq!(
+        count("SELECT succeeded * 10 + failed FROM forms WHERE id = 1"),
+        31
+    );
+
+    // Answers for words which are not in the database are reported as
+    // skipped.
+    let mut archive: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(file).unwrap()).unwrap();
+    let answers = archive["sessions"][0]["answers"].as_array_mut().unwrap();
+    let mut lost = answers[0].clone();
+    lost["word"] = serde_json::json!("bucca, buccae");
+    answers.push(lost);
+    let file = home.path().join("lost.json");
+    std::fs::write(&file, archive.to_string()).unwrap();
+    let third = init();
+    let (ok, out) = mihi(third.path(), &["import", file.to_str().unwrap()], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Session answers: 1 created, 0 updated, 1 skipped"),
+        "{out}"
+    );
+}
+
+// Inserts the noun with the given attributes straight into the database of the
+// given `home`, bypassing the prompts from 'words create'.
+fn insert_noun(home: &Path, (particle, enunciated): (&str, &str), declension: i64, kind: &str) {
+    rusqlite::Connection::open(database_path(home))
+        .unwrap()
+        .execute(
+            "INSERT INTO words (particle, enunciated, declension_id, kind, category, gender, \
+                                language_id, succeeded, translation, created_at, updated_at) \
+             VALUES (?1, ?2, ?3, ?4, 1, 1, 1, 0, '{\"en\":\"\"}', \
+                     datetime('now'), datetime('now'))",
+            rusqlite::params![particle, enunciated, declension, kind],
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_decline_domus() {
+    // The endings come from the seed of new databases.
+    let home = init();
+    insert_noun(home.path(), ("dom", "domus, domūs/domī"), 4, "domusdomus");
+    let (ok, out) = mihi(home.path(), &["words", "table", "domus"], "");
+    assert!(ok, "{out}");
+    for row in [
+        "| **Accusative** | domum | domōs/domūs |",
+        "| **Genitive** | domūs/domī | domuum/domōrum |",
+        "| **Ablative** | domō/domū | domibus |",
+    ] {
+        assert!(out.contains(row), "{out}");
+    }
+
+    // Databases seeded before 'domus' had its own endings get them.
+    let conn = rusqlite::Connection::open(database_path(home.path())).unwrap();
+    conn.execute_batch(
+        "DELETE FROM forms WHERE kind = 'domusdomus'; \
+         DELETE FROM schema_version WHERE version >= 23;",
+    )
+    .unwrap();
+    let (ok, out) = mihi(home.path(), &["words", "table", "domus"], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("| **Accusative** | domum | domōs/domūs |"),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_decline_greek_nouns() {
+    // As with 'domus', the endings come from the seed of new databases.
+    let home = init();
+    insert_noun(home.path(), ("epitom", "epitomē, epitomēs"), 1, "greeke");
+    insert_noun(home.path(), ("hērō", "hērōs, hērōis"), 3, "greekthird");
+    let (ok, out) = mihi(home.path(), &["words", "table", "epitomē"], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("| **Accusative** | epitomēn | epitomās |"),
+        "{out}"
+    );
+    assert!(
+        out.contains("| **Genitive** | epitomēs | epitomārum |"),
+        "{out}"
+    );
+    let (ok, out) = mihi(home.path(), &["words", "table", "hērōs"], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("| **Accusative** | hērōa/hērōem | hērōas/hērōēs |"),
+        "{out}"
+    );
+
+    // Databases seeded before Greek nouns were supported get their endings.
+    rusqlite::Connection::open(database_path(home.path()))
+        .unwrap()
+        .execute_batch(
+            "DELETE FROM forms WHERE kind LIKE 'greek%'; \
+             DELETE FROM schema_version WHERE version >= 23;",
+        )
+        .unwrap();
+    let (ok, out) = mihi(home.path(), &["words", "table", "hērōs"], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("| **Accusative** | hērōa/hērōem | hērōas/hērōēs |"),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_stats_export() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    rusqlite::Connection::open(database_path(home.path()))
+        .unwrap()
+        .execute_batch(
+            "INSERT INTO notes (word_id, session, body, created_at) \
+                 VALUES (1, 1, 'my secret mnemonic', datetime('now')); \
+             INSERT INTO sessions (kind, asked, correct, tags, started_at, finished_at) \
+                 VALUES ('words', 2, 1, '', datetime('now'), datetime('now')); \
+             INSERT INTO session_answers \
+                 (session_id, word_id, given, succeeded, response_ms, created_at) \
+                 VALUES (1, 1, 'rose', 1, 1000, datetime('now')), \
+                        (1, 1, 'girl', 0, 3000, datetime('now')); \
+             INSERT INTO drill_attempts (word_id, drill, expected, given, succeeded, created_at) \
+                 VALUES (1, 'cases', 'rosae', 'rosa', 0, datetime('now'));",
+        )
+        .unwrap();
+
+    let (ok, out) = mihi(home.path(), &["stats", "export"], "");
+    assert!(!ok);
+    assert!(out.contains("pass '--anonymous'"), "{out}");
+
+    // Only aggregates over the answers are given, and nothing personal.
+    let (ok, out) = mihi(home.path(), &["stats", "export", "--anonymous"], "");
+    assert!(ok, "{out}");
+    let stats: serde_json::Value = serde_json::from_str(&out).unwrap();
+    let rosa = &stats[0];
+    assert_eq!(rosa["lemma"], "rosa, rosae");
+    assert_eq!(rosa["attempts"], 3);
+    assert!((rosa["error_rate"].as_f64().unwrap() - 2.0 / 3.0).abs() < 1e-9);
+    assert_eq!(rosa["mean_response_ms"], 2000);
+    assert!(rosa["estimated_difficulty"].is_number());
+    for personal in [
+        "rose",
+        "mnemonic",
+        "translation",
+        "notes",
+        "weight",
+        "steps",
+    ] {
+        assert!(!out.contains(personal), "{out}");
+    }
+}
+
+#[test]
+fn test_publish() {
+    let home = TempDir::new().unwrap();
+    let (ok, out) = mihi(
+        home.path(),
+        &["init", "-l", "latin", "--fallback-locales", "ca"],
+        "",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["tags", "create", "llpsi"], "");
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["tags", "attach", "llpsi"], "");
+    assert!(ok, "{out}");
+
+    // Only the Catalan translation is there, which is taken as a fallback.
+    rusqlite::Connection::open(database_path(home.path()))
+        .unwrap()
+        .execute("UPDATE words SET translation = '{\"ca\":\"flor\"}'", [])
+        .unwrap();
+
+    let site = home.path().join("site");
+    let (ok, out) = mihi(home.path(), &["publish", site.to_str().unwrap()], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Published 1 words"), "{out}");
+
+    let index = std::fs::read_to_string(site.join("index.html")).unwrap();
+    assert!(
+        index.contains("<a href=\"letters/r.html\">R</a>"),
+        "{index}"
+    );
+    assert!(
+        index.contains("<a href=\"categories/noun.html\">noun</a> (1)"),
+        "{index}"
+    );
+    assert!(index.contains("llpsi</a> (1)"), "{index}");
+    let letter = std::fs::read_to_string(site.join("letters").join("r.html")).unwrap();
+    assert!(letter.contains("rosa, rosae</a>"), "{letter}");
+    let word = std::fs::read_to_string(site.join("words").join("1.html")).unwrap();
+    assert!(word.contains("<p>Translation (ca): flor.</p>"), "{word}");
+    assert!(word.contains("<h2>Inflection</h2>"), "{word}");
+
+    let (ok, out) = mihi(home.path(), &["publish"], "");
+    assert!(!ok);
+    assert!(out.contains("you have to provide the directory"), "{out}");
+}
+
+#[test]
+fn test_sync() {
+    let laptop = init();
+    let desktop = init();
+    let (ok, out) = mihi(
+        laptop.path(),
+        &["--no-input", "words", "create"],
+        CREATE_ROSA,
+    );
+    assert!(ok, "{out}");
+    rusqlite::Connection::open(database_path(laptop.path()))
+        .unwrap()
+        .execute("UPDATE words SET updated_at = '2000-01-01 00:00:00'", [])
+        .unwrap();
+
+    // The first export has everything, and later ones only what changed.
+    let first = laptop.path().join("first.json");
+    let first = first.to_str().unwrap();
+    let (ok, out) = mihi(laptop.path(), &["sync", "export", first], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Exported 1 words, 0 exercises"), "{out}");
+    let (ok, out) = mihi(desktop.path(), &["sync", "import", first], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Words: 1 created, 0 updated, 0 skipped"),
+        "{out}"
+    );
+
+    let (ok, out) = mihi(
+        laptop.path(),
+        &["--no-input", "words", "create"],
+        CREATE_AMO,
+    );
+    assert!(ok, "{out}");
+    let second = laptop.path().join("second.json");
+    let second = second.to_str().unwrap();
+    let (ok, out) = mihi(laptop.path(), &["sync", "export", second], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Exported 1 words, 0 exercises"), "{out}");
+    assert!(out.contains("changed since"), "{out}");
+    let (ok, out) = mihi(desktop.path(), &["sync", "import", second], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Words: 1 created, 0 updated, 0 skipped"),
+        "{out}"
+    );
+
+    // Changes made on the other side are merged back, and whatever is the
+    // same on both sides is left alone.
+    rusqlite::Connection::open(database_path(desktop.path()))
+        .unwrap()
+        .execute(
+            "UPDATE words SET weight = 7, updated_at = datetime('now', '+1 minutes') \
+             WHERE enunciated = 'rosa, rosae'",
+            [],
+        )
+        .unwrap();
+    let back = desktop.path().join("back.json");
+    let back = back.to_str().unwrap();
+    let (ok, out) = mihi(desktop.path(), &["sync", "export", "--all", back], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Exported 2 words"), "{out}");
+    let (ok, out) = mihi(laptop.path(), &["sync", "import", back], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Words: 0 created, 1 updated, 1 skipped"),
+        "{out}"
+    );
+    let weight: i64 = rusqlite::Connection::open(database_path(laptop.path()))
+        .unwrap()
+        .query_row(
+            "SELECT weight FROM words WHERE enunciated = 'rosa, rosae'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(weight, 7);
+
+    let (ok, out) = mihi(laptop.path(), &["sync", "export"], "");
+    assert!(!ok);
+    assert!(out.contains("provide exactly one file"), "{out}");
+}
+
+#[test]
+fn test_sync_associations_and_removals() {
+    let laptop = init();
+    let desktop = init();
+    for input in [CREATE_ROSA, CREATE_AMO] {
+        let (ok, out) = mihi(laptop.path(), &["--no-input", "words", "create"], input);
+        assert!(ok, "{out}");
+    }
+    let (ok, out) = mihi(laptop.path(), &["tags", "create", "llpsi"], "");
+    assert!(ok, "{out}");
+    let file = laptop.path().join("first.json");
+    let file = file.to_str().unwrap();
+    let (ok, out) = mihi(laptop.path(), &["sync", "export", file], "");
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(desktop.path(), &["sync", "import", file], "");
+    assert!(ok, "{out}");
+    let old = desktop.path().join("old.json");
+    let old = old.to_str().unwrap();
+    let (ok, out) = mihi(desktop.path(), &["sync", "export", old], "");
+    assert!(ok, "{out}");
+
+    // Tagging a word on one side counts as a change on the word, so an older
+    // change on the other side does not take the tags away.
+    rusqlite::Connection::open(database_path(laptop.path()))
+        .unwrap()
+        .execute(
+            "UPDATE words SET weight = 3, updated_at = datetime('now', '+1 minutes') \
+             WHERE enunciated = 'rosa, rosae'",
+            [],
+        )
+        .unwrap();
+    rusqlite::Connection::open(database_path(desktop.path()))
+        .unwrap()
+        .execute(
+            "INSERT INTO tag_associations (word_id, tag_id, created_at, updated_at) \
+             SELECT w.id, t.id, datetime('now', '+2 minutes'), datetime('now', '+2 minutes') \
+             FROM words w, tags t WHERE w.enunciated = 'rosa, rosae' AND t.name = 'llpsi'",
+            [],
+        )
+        .unwrap();
+
+    // Removals are synced too.
+    let (ok, out) = mihi(laptop.path(), &["words", "rm", "-y", "amō"], "");
+    assert!(ok, "{out}");
+
+    let file = laptop.path().join("second.json");
+    let file = file.to_str().unwrap();
+    let (ok, out) = mihi(laptop.path(), &["sync", "export", file], "");
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(desktop.path(), &["sync", "import", file], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Words: 0 created, 0 updated, 1 skipped"),
+        "{out}"
+    );
+    assert!(out.contains("Deleted: 1"), "{out}");
+    let (ok, out) = mihi(desktop.path(), &["tags", "show", "llpsi"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("rosa, rosae"), "{out}");
+    let (ok, out) = mihi(desktop.path(), &["words", "show", "amō"], "");
+    assert!(!ok, "{out}");
+
+    // Removed words do not come back from older change files.
+    let (ok, out) = mihi(laptop.path(), &["sync", "import", old], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Words: 0 created, 0 updated, 2 skipped"),
+        "{out}"
+    );
+    let (ok, out) = mihi(laptop.path(), &["words", "show", "amō"], "");
+    assert!(!ok, "{out}");
+}
+
+#[test]
+fn test_lessons() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Rose\n\nTranslate: the rose\nrosa\n\n\n\n",
+    );
+    assert!(ok, "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &[
+            "lessons",
+            "create",
+            "cap01",
+            "--source",
+            "LLPSI",
+            "--position",
+            "1",
+        ],
+        "",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["lessons", "create", "cap02"], "");
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["lessons", "create", "cap01"], "");
+    assert!(!ok);
+    assert!(out.contains("'cap01' already exists"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &[
+            "lessons",
+            "link",
+            "cap01",
+            "-w",
+            "rosa, rosae",
+            "-e",
+            "Rose",
+            "-w",
+            "nauta",
+        ],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("'nauta' does not exist"), "{out}");
+    assert!(
+        out.contains("Linked 2 words and exercises to 'cap01'."),
+        "{out}"
+    );
+
+    let (ok, out) = mihi(home.path(), &["lessons", "ls"], "");
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("1. cap01 [LLPSI]: 1 words, 1 exercises\n2. cap02: 0 words, 0 exercises"),
+        "{out}"
+    );
+    let (ok, out) = mihi(home.path(), &["lessons", "show", "cap01"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Words:\n   rosa, rosae"), "{out}");
+    assert!(out.contains("Exercises:\n   Rose"), "{out}");
+
+    // Studying a lesson goes through its words first.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--lesson", "cap01"],
+        "rose\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("New word: rosa, rosae"), "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--lesson", "cap02"],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("there is nothing to study on 'cap02'"),
+        "{out}"
+    );
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--lesson", "cap01", "--reverse"],
+        "",
+    );
+    assert!(!ok);
+    assert!(out.contains("'--lesson' can only be combined"), "{out}");
+
+    // Removing a lesson keeps its words and exercises.
+    let (ok, out) = mihi(home.path(), &["lessons", "rm", "-y", "cap01"], "");
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["lessons", "show", "cap01"], "");
+    assert!(!ok);
+    assert!(out.contains("the lesson 'cap01' does not exist"), "{out}");
+    let (ok, out) = mihi(home.path(), &["exercises", "show", "Rose"], "");
+    assert!(ok, "{out}");
+}
+
+#[test]
+fn test_transformation_exercise() {
+    let home = init();
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Forms\n4\n\n\n",
+    );
+    assert!(ok, "{out}");
+
+    // Transformations are only taken from known words.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "Forms"],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("not enough known nouns or verbs"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["--no-input", "practice"], "rose\n");
+    assert!(ok, "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "Forms"],
+        "x\nx\nx\nx\nx\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("rosa, rosae: "), "{out}");
+    assert!(out.contains("Make it "), "{out}");
+    assert_eq!(out.matches("❌").count(), 5, "{out}");
+}
+
+#[test]
+fn test_template_exercise() {
+    let home = init();
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Bad\n5\nMake it plural: {{verb:acc:sg}}\n",
+    );
+    assert!(!ok);
+    assert!(out.contains("unknown word 'verb'"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Plural\n5\nMake it plural: {{noun:acc:sg}}\n{{noun:acc:pl}}\n\n\n",
+    );
+    assert!(ok, "{out}");
+
+    // Placeholders are only filled with known words.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "Plural"],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("not enough known nouns or adjectives"),
+        "{out}"
+    );
+
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["--no-input", "practice"], "rose\n");
+    assert!(ok, "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "Plural"],
+        "rosās\nrosas\nrosās\nrosam\nrosās\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("Make it plural: rosam"), "{out}");
+    assert_eq!(out.matches("✓ rosās").count(), 4, "{out}");
+    assert_eq!(out.matches("❌rosās").count(), 1, "{out}");
+}
+
+#[test]
+fn test_parsing_composition_and_dictation() {
+    let home = init();
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["--no-input", "practice"], "rose\n");
+    assert!(ok, "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Parse\n6\n\n\n\n",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "Parse"],
+        "x\nx\nx\nx\nx\n",
+    );
+    assert!(ok, "{out}");
+    assert!(out.contains("rosa, rosae: "), "{out}");
+    assert_eq!(out.matches("❌").count(), 5, "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Beauty\n7\nThe rose is beautiful.\nRosa pulchra est.\n\n\n\n",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "Beauty"],
+        "---!\\nRosa est.\n4\n",
+    );
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("Model translation:\n\nRosa pulchra est."),
+        "{out}"
+    );
+    assert!(
+        out.contains("Words from the model translation which you did not use: pulchra"),
+        "{out}"
+    );
+
+    // The solution of a dictation is its own text, which cannot be read out
+    // loud without a speech synthesizer.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "create"],
+        "Dictation\n8\nRosa pulchra est.\n\n\n\n",
+    );
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(home.path(), &["exercises", "show", "Dictation"], "");
+    assert!(ok, "{out}");
+    assert!(out.contains("Kind: Dictation"), "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "exercises", "run", "Dictation"],
+        "",
+    );
+    assert!(ok, "{out}");
+    assert!(
+        out.contains("install one of espeak-ng, espeak, say"),
+        "{out}"
+    );
+}
+
+#[test]
+fn test_arguments() {
+    let home = init();
+
+    let (ok, out) = mihi(home.path(), &["unknown"], "");
+    assert!(!ok);
+    assert!(out.contains("unknown flag or command: 'unknown'"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["practice", "--unknown"], "");
+    assert!(!ok);
+    assert!(out.contains("unknown flag or command '--unknown'"), "{out}");
+
+    let (ok, out) = mihi(home.path(), &["practice", "--category"], "");
+    assert!(!ok);
+    assert!(out.contains("you have to provide a category"), "{out}");
+
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--flags", "deponent,unknown"],
+        "",
+    );
+    assert!(!ok);
+    assert!(out.contains("unknown flag value 'unknown'"), "{out}");
+
+    // Prompting needs either a terminal or '--no-input'.
+    let (ok, out) = mihi(home.path(), &["practice"], "rose\n");
+    assert!(!ok);
+    assert!(out.contains("needs an interactive terminal"), "{out}");
+
+    // Answers which are not among the options of a prompt abort it.
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "words", "create"],
+        "rosa, rosae\n\n\nplant\n",
+    );
+    assert!(!ok);
+    assert!(!out.contains("successfully created"), "{out}");
+
+    // Only words with any of the given flags are asked for.
+    let (ok, out) = mihi(home.path(), &["--no-input", "words", "create"], CREATE_ROSA);
+    assert!(ok, "{out}");
+    let (ok, out) = mihi(
+        home.path(),
+        &["--no-input", "practice", "--flags", "deponent,impersonal"],
+        "rose\n",
+    );
+    assert!(ok, "{out}");
+    assert!(!out.contains("Word: rosa, rosae"), "{out}");
+}
@@ -0,0 +1,325 @@
+/// The grammatical cases, identified as they are stored in the database.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Case {
+    Nominative = 0,
+    Vocative,
+    Accusative,
+    Genitive,
+    Dative,
+    Ablative,
+    Locative,
+}
+
+impl Case {
+    /// All the cases, in the order in which they are stored in the database.
+    pub const ALL: [Case; 7] = [
+        Case::Nominative,
+        Case::Vocative,
+        Case::Accusative,
+        Case::Genitive,
+        Case::Dative,
+        Case::Ablative,
+        Case::Locative,
+    ];
+
+    /// Returns the name of the case as it's shown to the user.
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Nominative => "Nominative",
+            Self::Vocative => "Vocative",
+            Self::Accusative => "Accusative",
+            Self::Genitive => "Genitive",
+            Self::Dative => "Dative",
+            Self::Ablative => "Ablative",
+            Self::Locative => "Locative",
+        }
+    }
+
+    /// Returns the case named by the given `value`, either in full (e.g.
+    /// 'accusative') or abbreviated to its first three letters (e.g. 'acc').
+    pub fn from_name(value: &str) -> Option<Case> {
+        let value = value.trim().to_lowercase();
+        Self::ALL.into_iter().find(|case| {
+            let name = case.to_string();
+            value == name || (value.len() == 3 && name.starts_with(&value))
+        })
+    }
+}
+
+impl TryFrom<isize> for Case {
+    type Error = &'static str;
+
+    fn try_from(value: isize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Nominative),
+            1 => Ok(Self::Vocative),
+            2 => Ok(Self::Accusative),
+            3 => Ok(Self::Genitive),
+            4 => Ok(Self::Dative),
+            5 => Ok(Self::Ablative),
+            6 => Ok(Self::Locative),
+            _ => Err("unknown case!"),
+        }
+    }
+}
+
+impl TryFrom<&str> for Case {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "nominative" => Ok(Self::Nominative),
+            "vocative" => Ok(Self::Vocative),
+            "accusative" => Ok(Self::Accusative),
+            "genitive" => Ok(Self::Genitive),
+            "dative" => Ok(Self::Dative),
+            "ablative" => Ok(Self::Ablative),
+            "locative" => Ok(Self::Locative),
+            _ => Err(format!("bad key '{}' for a case", value)),
+        }
+    }
+}
+
+impl std::fmt::Display for Case {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Nominative => write!(f, "nominative"),
+            Self::Vocative => write!(f, "vocative"),
+            Self::Accusative => write!(f, "accusative"),
+            Self::Genitive => write!(f, "genitive"),
+            Self::Dative => write!(f, "dative"),
+            Self::Ablative => write!(f, "ablative"),
+            Self::Locative => write!(f, "locative"),
+        }
+    }
+}
+
+/// The grammatical numbers, identified as they are stored in the database.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Number {
+    Singular = 0,
+    Plural,
+}
+
+impl Number {
+    /// All the numbers, in the order in which they are stored in the database.
+    pub const ALL: [Number; 2] = [Number::Singular, Number::Plural];
+
+    /// Returns the number named by the given `value`, either in full (e.g.
+    /// 'plural') or abbreviated (e.g. 'pl').
+    pub fn from_name(value: &str) -> Option<Number> {
+        match value.trim().to_lowercase().as_str() {
+            "sg" | "singular" => Some(Self::Singular),
+            "pl" | "plural" => Some(Self::Plural),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<isize> for Number {
+    type Error = &'static str;
+
+    fn try_from(value: isize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Singular),
+            1 => Ok(Self::Plural),
+            _ => Err("unknown number!"),
+        }
+    }
+}
+
+impl std::fmt::Display for Number {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Singular => write!(f, "singular"),
+            Self::Plural => write!(f, "plural"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Category {
+    #[default]
+    Unknown = 0,
+    Noun,
+    Adjective,
+    Verb,
+    Pronoun,
+    Adverb,
+    Preposition,
+    Conjunction,
+    Interjection,
+    Determiner,
+}
+
+impl std::fmt::Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "unknown"),
+            Self::Noun => write!(f, "noun"),
+            Self::Adjective => write!(f, "adjective"),
+            Self::Verb => write!(f, "verb"),
+            Self::Pronoun => write!(f, "pronoun"),
+            Self::Adverb => write!(f, "adverb"),
+            Self::Preposition => write!(f, "preposition"),
+            Self::Conjunction => write!(f, "conjunction"),
+            Self::Interjection => write!(f, "interjection"),
+            Self::Determiner => write!(f, "determiner"),
+        }
+    }
+}
+
+impl TryFrom<isize> for Category {
+    type Error = &'static str;
+
+    fn try_from(value: isize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Unknown),
+            1 => Ok(Self::Noun),
+            2 => Ok(Self::Adjective),
+            3 => Ok(Self::Verb),
+            4 => Ok(Self::Pronoun),
+            5 => Ok(Self::Adverb),
+            6 => Ok(Self::Preposition),
+            7 => Ok(Self::Conjunction),
+            8 => Ok(Self::Interjection),
+            9 => Ok(Self::Determiner),
+            _ => Err("unknonwn category!"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Gender {
+    Masculine = 0,
+    Feminine,
+    MasculineOrFeminine,
+    Neuter,
+    #[default]
+    None,
+}
+
+impl Gender {
+    /// Returns a string containing the abbreviation for this gender.
+    pub fn abbrev(&self) -> &str {
+        match self {
+            Self::Masculine => "m.",
+            Self::Feminine => "f.",
+            Self::MasculineOrFeminine => "m./f.",
+            Self::Neuter => "n.",
+            Self::None => "(genderless)",
+        }
+    }
+}
+
+impl TryFrom<isize> for Gender {
+    type Error = &'static str;
+
+    fn try_from(value: isize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Masculine),
+            1 => Ok(Self::Feminine),
+            2 => Ok(Self::MasculineOrFeminine),
+            3 => Ok(Self::Neuter),
+            4 => Ok(Self::None),
+            _ => Err("unknonwn gender!"),
+        }
+    }
+}
+
+impl std::fmt::Display for Gender {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Masculine => write!(f, "masculine"),
+            Self::Feminine => write!(f, "feminine"),
+            Self::MasculineOrFeminine => write!(f, "masculine or feminine"),
+            Self::Neuter => write!(f, "neuter"),
+            Self::None => write!(f, "none"),
+        }
+    }
+}
+
+/// Identifies the declension for a given word, and it allows to do SQL to/from
+/// conversions when the 'rusqlite' feature is enabled.
+#[derive(Clone, Debug)]
+pub enum Declension {
+    First = 1,
+    Second,
+    Third,
+    Fourth,
+    Fifth,
+
+    // Used for highly irregular words (e.g. 'īnstar').
+    Other,
+}
+
+impl std::fmt::Display for Declension {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Declension::First => write!(f, "1st (-ae)"),
+            Declension::Second => write!(f, "2nd (-ī)"),
+            Declension::Third => write!(f, "3rd (-is)"),
+            Declension::Fourth => write!(f, "4th (-ūs)"),
+            Declension::Fifth => write!(f, "5th (-eī/-ēī)"),
+            Declension::Other => write!(f, "other"),
+        }
+    }
+}
+
+/// Identifies the conjugation for a given verb, and it allows to do SQL to/from
+/// conversions when the 'rusqlite' feature is enabled.
+#[derive(Clone, Debug)]
+pub enum Conjugation {
+    First = 1,
+    Second,
+    Third,
+    ThirdIo,
+    Fourth,
+
+    // The 'Other' conjugation is a container for a bunch of verbs like 'sum',
+    // 'volō', etc. That is, we expect the user to realize this is an irregular
+    // verb, and then we rely on the 'kind' column to determine which kind of
+    // irregular verb that is. This is better than enlarging a list of kinds of
+    // irregular verbs as it was done in the past.
+    Other,
+}
+
+impl std::fmt::Display for Conjugation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Conjugation::First => write!(f, "1st (ā stems)"),
+            Conjugation::Second => write!(f, "2nd (ē stems)"),
+            Conjugation::Third => write!(f, "3rd (ĕ stems)"),
+            Conjugation::ThirdIo => write!(f, "3rd (-iō variants)"),
+            Conjugation::Fourth => write!(f, "4th (ī stems)"),
+            Conjugation::Other => write!(f, "other"),
+        }
+    }
+}
+
+impl Conjugation {
+    /// Returns a String containing how a conjugation would be displayed by also
+    /// considering the given 'kind' identifier. This way we can display 'other'
+    /// conjugations in a more natural way.
+    pub fn display_with_kind(&self, kind: &str) -> String {
+        if !matches!(self, Conjugation::Other) {
+            return format!("{}", self);
+        }
+
+        match kind {
+            "sum" => "irregular; like 'sum, esse, fuī, futūrus'",
+            "possum" => "irregular; like 'possum, posse, potuī'",
+            "eo" => "irregular; like 'eō, īre, iī, itum'",
+            "volo" => "irregular; like 'volō, velle, voluī'",
+            "nolo" => "irregular; like 'nōlō, nōlle, nōluī'",
+            "malo" => "irregular; like 'mālō, mālle, māluī'",
+            "fero" => "irregular; like 'ferō, ferre, tulī, lātum'",
+            "facio" => "3rd (-iō variants) and suppletive; like 'faciō, facere, fēcī, factum'",
+            "do" => "1st; irregular short ă in most forms",
+            "inquam" => "irregular, highly defective",
+            "aio" => "3rd (-iō variants); highly defective",
+            _ => "other",
+        }
+        .to_string()
+    }
+}
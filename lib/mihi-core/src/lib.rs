@@ -0,0 +1,15 @@
+//! The linguistic core of 'mihi': grammatical categories, numerals, exercises
+//! on fixed texts and on templates, and the matching of answers. Nothing in
+//! here knows about the database of the application, so it can be used by any
+//! other Latin tool.
+//! Enable the 'rusqlite' feature in order to store grammatical categories into
+//! SQLite.
+
+pub mod grammar;
+pub mod matching;
+pub mod numeral;
+pub mod pensum;
+#[cfg(feature = "rusqlite")]
+mod sql;
+pub mod template;
+pub mod text;
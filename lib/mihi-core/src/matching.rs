@@ -0,0 +1,286 @@
+use crate::text::{strip_macron, strip_macrons};
+
+// Returns the given `value` ready to be compared against other answers.
+fn normalize(value: &str) -> String {
+    strip_macrons(
+        &value
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+            .to_lowercase(),
+    )
+}
+
+// Number of characters of an expected answer for each typo which is tolerated
+// when grading with `MacronStrictness::Typos`. Short forms have to be exact, as
+// a single letter often makes for another form (e.g. 'rosa' and 'rosae').
+const CHARS_PER_TYPO: usize = 6;
+
+// Words which are dropped from the start of translations before comparing them
+// (e.g. 'to love' and 'love').
+const GLOSS_PARTICLES: [&str; 4] = ["to", "a", "an", "the"];
+
+// Returns the given translation `gloss` without notes in parentheses (e.g. 'to
+// go (away)') and without a leading particle from `GLOSS_PARTICLES`, unless
+// it's the only word.
+fn normalize_gloss(gloss: &str) -> String {
+    let mut text = String::new();
+    let mut depth: usize = 0;
+    for c in gloss.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if depth == 0 => text.push(c),
+            _ => {}
+        }
+    }
+
+    let mut words: Vec<&str> = text.split_whitespace().collect();
+    if words.len() > 1 && GLOSS_PARTICLES.contains(&words[0].to_lowercase().as_str()) {
+        words.remove(0);
+    }
+    words.join(" ")
+}
+
+// Returns the edit distance between `a` and `b`.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            current.push(
+                (previous[j] + cost)
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1),
+            );
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Returns the credit for the `given` answer when any of the comma-separated
+/// translations from `expected` is accepted. A full point is given when it
+/// matches one of them (case, extra whitespace and macrons are ignored), and
+/// half a point when it's close enough to be a typo or when it's only part of
+/// a longer translation (e.g. 'sea' for 'the sea').
+pub fn partial_credit(given: &str, expected: &str) -> f64 {
+    let given = normalize(given);
+    if given.is_empty() {
+        return 0.0;
+    }
+
+    let mut res: f64 = 0.0;
+    for translation in expected.split(',').map(normalize) {
+        if translation.is_empty() {
+            continue;
+        }
+        if translation == given {
+            return 1.0;
+        }
+
+        let longest = translation.chars().count().max(given.chars().count());
+        let close = distance(&translation, &given) * 3 <= longest;
+        let partial = translation.split(' ').any(|w| w == given)
+            || given.split(' ').any(|w| w == translation);
+        if close || partial {
+            res = 0.5;
+        }
+    }
+    res
+}
+
+/// Returns true if the `given` answer names the word with the given
+/// `enunciated`, either in full or by its first part (e.g. 'rosa' for 'rosa,
+/// rosae'). Each part is checked as in `matches_form` with the given
+/// `strictness`, while the spacing around commas is ignored.
+pub fn matches_enunciated(given: &str, enunciated: &str, strictness: MacronStrictness) -> bool {
+    let parts = |value: &str| {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<String>>()
+    };
+    let given = parts(given);
+    let expected = parts(enunciated);
+    let matches = |len: usize| {
+        given.len() == len
+            && given
+                .iter()
+                .zip(expected.iter())
+                .all(|(g, e)| matches_form(g, e, strictness))
+    };
+
+    !given.is_empty() && (matches(expected.len()) || matches(1))
+}
+
+/// Returns true if the `given` answer matches any of the comma-separated
+/// translations from `expected` as a whole, as in `matches_form` with the given
+/// `strictness`. Notes in parentheses and leading particles are not taken into
+/// account on either side (e.g. 'love' for 'to love', or 'the sea' for 'sea
+/// (the)').
+pub fn matches_translation(given: &str, expected: &str, strictness: MacronStrictness) -> bool {
+    let given = normalize_gloss(given);
+
+    expected
+        .split(',')
+        .any(|gloss| matches_form(&given, &normalize_gloss(gloss), strictness))
+}
+
+/// How strictly answers are graded.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum MacronStrictness {
+    /// Macrons have to be exactly where they belong.
+    Strict,
+    /// Macrons can be left out, but the ones given have to be right (e.g.
+    /// 'amavi' or 'amāvī' for 'amāvī', but not 'āmavi').
+    #[default]
+    Lenient,
+    /// Macrons are not taken into account at all.
+    Ignore,
+    /// Macrons are not taken into account, and a typo is tolerated for every
+    /// six characters of the expected answer (e.g. 'amavise' for 'amāvisse').
+    Typos,
+}
+
+impl TryFrom<&str> for MacronStrictness {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "strict" => Ok(Self::Strict),
+            "lenient" => Ok(Self::Lenient),
+            "ignore" => Ok(Self::Ignore),
+            "typos" => Ok(Self::Typos),
+            _ => Err(format!(
+                "unknown strictness '{value}'. Available: strict, lenient, ignore, typos"
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for MacronStrictness {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Strict => write!(f, "strict"),
+            Self::Lenient => write!(f, "lenient"),
+            Self::Ignore => write!(f, "ignore"),
+            Self::Typos => write!(f, "typos"),
+        }
+    }
+}
+
+/// Returns true if the `given` form matches the `expected` one with the given
+/// `strictness` for macrons. Casing and extra white spacing are ignored.
+pub fn matches_form(given: &str, expected: &str, strictness: MacronStrictness) -> bool {
+    let words = |value: &str| value.split_whitespace().collect::<Vec<&str>>().join(" ");
+    let given = words(given).to_lowercase();
+    let expected = words(expected).to_lowercase();
+    if given.is_empty() {
+        return false;
+    }
+
+    match strictness {
+        MacronStrictness::Strict => given == expected,
+        MacronStrictness::Lenient => {
+            given.chars().count() == expected.chars().count()
+                && given
+                    .chars()
+                    .zip(expected.chars())
+                    .all(|(g, e)| g == e || g == strip_macron(e))
+        }
+        MacronStrictness::Ignore => strip_macrons(&given) == strip_macrons(&expected),
+        MacronStrictness::Typos => {
+            distance(&strip_macrons(&given), &strip_macrons(&expected))
+                <= expected.chars().count() / CHARS_PER_TYPO
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_credit() {
+        assert_eq!(partial_credit("Sea", "the sea, sea"), 1.0);
+        assert_eq!(partial_credit("rōsa", "rosa"), 1.0);
+        assert_eq!(partial_credit("sea", "the sea"), 0.5);
+        assert_eq!(partial_credit("to lvoe", "to love"), 0.5);
+        assert_eq!(partial_credit("to hate", "to love"), 0.0);
+        assert_eq!(partial_credit("  ", "to love"), 0.0);
+    }
+
+    #[test]
+    fn test_matches_enunciated() {
+        let lenient = MacronStrictness::Lenient;
+
+        assert!(matches_enunciated("rosa", "rosa, rosae", lenient));
+        assert!(matches_enunciated("Rosa,rosae", "rosa, rosae", lenient));
+        assert!(matches_enunciated("amo, amare", "amō, amāre", lenient));
+        assert!(matches_enunciated(
+            "amō",
+            "amō, amāre, amāvī, amātum",
+            lenient
+        ));
+        assert!(!matches_enunciated("rosae", "rosa, rosae", lenient));
+        assert!(!matches_enunciated(" ", "rosa, rosae", lenient));
+        assert!(!matches_enunciated(
+            "amo",
+            "amō, amāre",
+            MacronStrictness::Strict
+        ));
+        assert!(matches_enunciated(
+            "āmo",
+            "amō, amāre",
+            MacronStrictness::Ignore
+        ));
+    }
+
+    #[test]
+    fn test_matches_translation() {
+        let strict = MacronStrictness::Strict;
+        let lenient = MacronStrictness::Lenient;
+        let typos = MacronStrictness::Typos;
+
+        assert!(matches_translation("Sea", "the sea, sea", strict));
+        assert!(matches_translation("sea", "the sea", strict));
+        assert!(matches_translation("The sea", "sea (salty)", strict));
+        assert!(matches_translation("love", "to love", lenient));
+        assert!(!matches_translation("o", "to go", lenient));
+        assert!(!matches_translation("to", "to go", lenient));
+        assert!(!matches_translation("fact", "indeed, in fact", lenient));
+        assert!(!matches_translation("to remembr", "to remember", lenient));
+        assert!(matches_translation("to remembr", "to remember", typos));
+        assert!(!matches_translation("", "to love", typos));
+    }
+
+    #[test]
+    fn test_matches_form() {
+        let strict = MacronStrictness::Strict;
+        let lenient = MacronStrictness::Lenient;
+        let ignore = MacronStrictness::Ignore;
+
+        assert!(matches_form("Amāvī", "amāvī", strict));
+        assert!(!matches_form("amavi", "amāvī", strict));
+        assert!(matches_form("amavi", "amāvī", lenient));
+        assert!(matches_form("amāvi", "amāvī", lenient));
+        assert!(matches_form("locūtus  sum", "locūtus sum", lenient));
+        assert!(!matches_form("āmavi", "amāvī", lenient));
+        assert!(matches_form("āmavi", "amāvī", ignore));
+        assert!(!matches_form("amavit", "amāvī", ignore));
+        assert!(!matches_form("", "amāvī", ignore));
+
+        let typos = MacronStrictness::Typos;
+        assert!(matches_form("amavise", "amāvisse", typos));
+        assert!(!matches_form("amavse", "amāvisse", typos));
+        assert!(!matches_form("rosae", "rosa", typos));
+        assert!(!matches_form("", "amāvī", typos));
+    }
+}
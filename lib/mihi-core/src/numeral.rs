@@ -0,0 +1,188 @@
+/// Highest number which can be written in Roman numerals without resorting to
+/// the vinculum or to other extensions.
+pub const MAX_ROMAN: usize = 3999;
+
+/// Highest number which can be written as a Latin word by `to_latin`.
+pub const MAX_LATIN: usize = 1000;
+
+// Values for each Roman numeral, including the subtractive pairs, from the
+// highest to the lowest.
+const ROMAN: [(usize, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+const UNITS: [&str; 10] = [
+    "", "ūnus", "duo", "trēs", "quattuor", "quīnque", "sex", "septem", "octō", "novem",
+];
+
+const TEENS: [&str; 10] = [
+    "decem",
+    "ūndecim",
+    "duodecim",
+    "tredecim",
+    "quattuordecim",
+    "quīndecim",
+    "sēdecim",
+    "septendecim",
+    "duodēvīgintī",
+    "ūndēvīgintī",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "decem",
+    "vīgintī",
+    "trīgintā",
+    "quadrāgintā",
+    "quīnquāgintā",
+    "sexāgintā",
+    "septuāgintā",
+    "octōgintā",
+    "nōnāgintā",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "centum",
+    "ducentī",
+    "trecentī",
+    "quadringentī",
+    "quīngentī",
+    "sescentī",
+    "septingentī",
+    "octingentī",
+    "nōngentī",
+];
+
+/// Returns the given `number` in Roman numerals (e.g. 1984 -> 'MCMLXXXIV'), or
+/// None if it's either zero or above `MAX_ROMAN`.
+pub fn to_roman(number: usize) -> Option<String> {
+    if number == 0 || number > MAX_ROMAN {
+        return None;
+    }
+
+    let mut rest = number;
+    let mut res = String::new();
+    for (value, numeral) in ROMAN {
+        while rest >= value {
+            res.push_str(numeral);
+            rest -= value;
+        }
+    }
+    Some(res)
+}
+
+/// Returns the number written in the given Roman `numerals`, regardless of
+/// casing. Only the standard notation is accepted (e.g. 'IV' but not 'IIII'),
+/// so None is returned for anything else.
+pub fn from_roman(numerals: &str) -> Option<usize> {
+    let numerals = numerals.trim().to_uppercase();
+    let mut rest = numerals.as_str();
+    let mut res = 0;
+
+    for (value, numeral) in ROMAN {
+        while let Some(after) = rest.strip_prefix(numeral) {
+            res += value;
+            rest = after;
+        }
+    }
+    if !rest.is_empty() || res == 0 {
+        return None;
+    }
+
+    // Numbers such as 'IIII' or 'VV' are parsed fine by the loop above, but
+    // they are not how the number is written.
+    match to_roman(res) {
+        Some(canonical) if canonical == numerals => Some(res),
+        _ => None,
+    }
+}
+
+/// Returns the given `number` as a Latin cardinal in the masculine nominative
+/// (e.g. 38 -> 'duodēquadrāgintā', 121 -> 'centum vīgintī ūnus'), or None if
+/// it's either zero or above `MAX_LATIN`. As it was usual, numbers ending in
+/// eight or nine below one hundred are written by subtraction from the next
+/// ten, except for 98 and 99.
+pub fn to_latin(number: usize) -> Option<String> {
+    if number == 0 || number > MAX_LATIN {
+        return None;
+    }
+    if number == MAX_LATIN {
+        return Some("mīlle".to_string());
+    }
+
+    let mut words = vec![];
+    if number >= 100 {
+        words.push(HUNDREDS[number / 100].to_string());
+    }
+
+    let (tens, units) = ((number % 100) / 10, number % 10);
+    match (tens, units) {
+        (0, 0) => {}
+        (0, units) => words.push(UNITS[units].to_string()),
+        (1, units) => words.push(TEENS[units].to_string()),
+        (2..=8, 8) => words.push(format!("duodē{}", TENS[tens + 1])),
+        (2..=8, 9) => words.push(format!("ūndē{}", TENS[tens + 1])),
+        (tens, 0) => words.push(TENS[tens].to_string()),
+        (tens, units) => {
+            words.push(TENS[tens].to_string());
+            words.push(UNITS[units].to_string());
+        }
+    }
+
+    Some(words.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roman() {
+        assert_eq!(to_roman(0), None);
+        assert_eq!(to_roman(4).as_deref(), Some("IV"));
+        assert_eq!(to_roman(1984).as_deref(), Some("MCMLXXXIV"));
+        assert_eq!(to_roman(MAX_ROMAN).as_deref(), Some("MMMCMXCIX"));
+        assert_eq!(to_roman(MAX_ROMAN + 1), None);
+
+        assert_eq!(from_roman("mcmlxxxiv"), Some(1984));
+        assert_eq!(from_roman(" XLII "), Some(42));
+        assert_eq!(from_roman("IIII"), None);
+        assert_eq!(from_roman("VX"), None);
+        assert_eq!(from_roman("ABC"), None);
+        assert_eq!(from_roman(""), None);
+
+        for number in 1..=MAX_ROMAN {
+            assert_eq!(from_roman(&to_roman(number).unwrap()), Some(number));
+        }
+    }
+
+    #[test]
+    fn test_latin() {
+        assert_eq!(to_latin(0), None);
+        assert_eq!(to_latin(1).as_deref(), Some("ūnus"));
+        assert_eq!(to_latin(17).as_deref(), Some("septendecim"));
+        assert_eq!(to_latin(18).as_deref(), Some("duodēvīgintī"));
+        assert_eq!(to_latin(21).as_deref(), Some("vīgintī ūnus"));
+        assert_eq!(to_latin(38).as_deref(), Some("duodēquadrāgintā"));
+        assert_eq!(to_latin(89).as_deref(), Some("ūndēnōnāgintā"));
+        assert_eq!(to_latin(99).as_deref(), Some("nōnāgintā novem"));
+        assert_eq!(to_latin(100).as_deref(), Some("centum"));
+        assert_eq!(to_latin(119).as_deref(), Some("centum ūndēvīgintī"));
+        assert_eq!(to_latin(605).as_deref(), Some("sescentī quīnque"));
+        assert_eq!(to_latin(MAX_LATIN).as_deref(), Some("mīlle"));
+        assert_eq!(to_latin(MAX_LATIN + 1), None);
+    }
+}
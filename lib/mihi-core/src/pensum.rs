@@ -0,0 +1,378 @@
+use crate::matching::{matches_form, MacronStrictness};
+
+/// A blank to be filled on a pensum exercise. On the enunciate of the exercise
+/// it's written between curly braces or square brackets with its accepted
+/// answers separated by a '|', optionally followed by a hint after a '?' (e.g.
+/// `{marī|mare?ablative of 'mare'}` or `[rosam]`). It can also be written as
+/// three or more underscores (i.e. `___`), and then its answer is taken from
+/// the solution of the exercise (see `Pensum::with_solution`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Blank {
+    pub answers: Vec<String>,
+    pub hint: Option<String>,
+}
+
+impl Blank {
+    /// Returns true if the `given` answer matches any of the accepted answers
+    /// for this blank as in `matches_form` with the given `strictness`.
+    pub fn accepts(&self, given: &str, strictness: MacronStrictness) -> bool {
+        self.answers
+            .iter()
+            .any(|answer| matches_form(given, answer, strictness))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    Text(String),
+    Blank(Blank),
+}
+
+/// The enunciate of a pensum exercise: a text with blanks to be filled in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pensum {
+    segments: Vec<Segment>,
+}
+
+// Minimum number of underscores for a blank whose answer is given by the
+// solution of the exercise.
+const UNDERSCORES: &str = "___";
+
+// Returns an error if the given `text` from the enunciate closes a blank which
+// was never opened.
+fn check_unopened(text: &str) -> Result<(), String> {
+    match text.chars().find(|c| *c == '}' || *c == ']') {
+        Some(c) => Err(format!(
+            "a blank was closed with '{c}' but it was never opened"
+        )),
+        None => Ok(()),
+    }
+}
+
+impl TryFrom<&str> for Pensum {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut segments = vec![];
+        let mut rest = value;
+
+        while let Some(start) = [rest.find('{'), rest.find('['), rest.find(UNDERSCORES)]
+            .into_iter()
+            .flatten()
+            .min()
+        {
+            if start > 0 {
+                check_unopened(&rest[..start])?;
+                segments.push(Segment::Text(rest[..start].to_string()));
+            }
+
+            if rest[start..].starts_with(UNDERSCORES) {
+                let len = rest[start..]
+                    .find(|c| c != '_')
+                    .unwrap_or(rest.len() - start);
+                segments.push(Segment::Blank(Blank {
+                    answers: vec![],
+                    hint: None,
+                }));
+                rest = &rest[start + len..];
+                continue;
+            }
+
+            let (open, close) = if rest[start..].starts_with('{') {
+                ('{', '}')
+            } else {
+                ('[', ']')
+            };
+            let Some(len) = rest[start..].find(close) else {
+                return Err(format!(
+                    "a blank was opened with '{open}' but it was never closed"
+                ));
+            };
+            let inner = &rest[start + 1..start + len];
+            if inner.contains(['{', '[']) {
+                return Err(format!("blanks cannot be nested: '{open}{inner}{close}'"));
+            }
+
+            let (answers, hint) = match inner.split_once('?') {
+                Some((answers, hint)) => (answers, Some(hint.trim().to_string())),
+                None => (inner, None),
+            };
+            let answers: Vec<String> = answers
+                .split('|')
+                .map(|answer| answer.trim().to_string())
+                .filter(|answer| !answer.is_empty())
+                .collect();
+            if answers.is_empty() {
+                return Err(format!("the blank '{open}{inner}{close}' has no answers"));
+            }
+
+            segments.push(Segment::Blank(Blank {
+                answers,
+                hint: hint.filter(|hint| !hint.is_empty()),
+            }));
+            rest = &rest[start + len + 1..];
+        }
+        check_unopened(rest)?;
+        if !rest.is_empty() {
+            segments.push(Segment::Text(rest.to_string()));
+        }
+
+        if !segments.iter().any(|s| matches!(s, Segment::Blank(_))) {
+            return Err("there are no blanks to be filled in".to_string());
+        }
+        Ok(Self { segments })
+    }
+}
+
+impl Pensum {
+    /// Parses the given `enunciate` as in `Pensum::try_from`, and then takes
+    /// the answers for the blanks written as `___` from the given `solution`.
+    /// That is, the solution is expected to be the enunciate with its blanks
+    /// filled in, and the answer for each blank goes up to the first occurrence
+    /// of the text that follows it.
+    pub fn with_solution(enunciate: &str, solution: &str) -> Result<Self, String> {
+        let mut pensum = Self::try_from(enunciate)?;
+        if !pensum.needs_solution() {
+            return Ok(pensum);
+        }
+
+        let mut rest = solution.trim();
+        let mut idx = 0;
+        while idx < pensum.segments.len() {
+            let next = pensum.segments.get(idx + 1).cloned();
+
+            match &mut pensum.segments[idx] {
+                Segment::Text(text) => {
+                    let Some(after) = rest.strip_prefix(text.as_str()) else {
+                        return Err(format!(
+                            "the solution does not match the text '{}'",
+                            text.trim()
+                        ));
+                    };
+                    rest = after;
+                }
+                Segment::Blank(blank) => {
+                    let answer =
+                        match next {
+                            Some(Segment::Text(text)) => match rest.find(text.as_str()) {
+                                Some(end) => &rest[..end],
+                                None => {
+                                    return Err(format!(
+                                        "the solution does not match the text '{}'",
+                                        text.trim()
+                                    ))
+                                }
+                            },
+                            // Without any text in between, there is no way to tell
+                            // where the answer for a blank ends, unless it's
+                            // already known.
+                            Some(Segment::Blank(_)) => {
+                                match blank.answers.iter().find(|a| rest.starts_with(a.as_str())) {
+                                    Some(answer) => &rest[..answer.len()],
+                                    None => return Err(
+                                        "blanks written as '___' have to be separated by some text"
+                                            .to_string(),
+                                    ),
+                                }
+                            }
+                            None => rest,
+                        };
+                    rest = &rest[answer.len()..];
+
+                    if blank.answers.is_empty() {
+                        let answer = answer.trim();
+                        if answer.is_empty() {
+                            return Err(format!(
+                                "the solution has no answer for the blank number {}",
+                                pensum.segments[..idx]
+                                    .iter()
+                                    .filter(|s| matches!(s, Segment::Blank(_)))
+                                    .count()
+                                    + 1
+                            ));
+                        }
+                        blank.answers.push(answer.to_string());
+                    }
+                }
+            }
+            idx += 1;
+        }
+        if !rest.trim().is_empty() {
+            return Err(format!(
+                "the solution has some extra text: '{}'",
+                rest.trim()
+            ));
+        }
+
+        Ok(pensum)
+    }
+
+    /// Returns true if some of the blanks were written as `___`, and so their
+    /// answers have to be taken from the solution of the exercise.
+    pub fn needs_solution(&self) -> bool {
+        self.blanks().iter().any(|blank| blank.answers.is_empty())
+    }
+
+    /// Returns the blanks of this pensum in the order in which they appear.
+    pub fn blanks(&self) -> Vec<&Blank> {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Blank(blank) => Some(blank),
+                Segment::Text(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns the text to be shown to the user, where each blank is replaced
+    /// by its number (e.g. '___(1)'). If `hints` is true, then the hint of each
+    /// blank is shown as well.
+    pub fn render(&self, hints: bool) -> String {
+        let mut res = String::new();
+        let mut idx = 0;
+
+        for segment in self.segments.iter() {
+            match segment {
+                Segment::Text(text) => res.push_str(text),
+                Segment::Blank(blank) => {
+                    idx += 1;
+                    match &blank.hint {
+                        Some(hint) if hints => res.push_str(&format!("___({idx}: {hint})")),
+                        _ => res.push_str(&format!("___({idx})")),
+                    }
+                }
+            }
+        }
+
+        res
+    }
+
+    /// Returns the text with each blank filled with its first accepted answer.
+    pub fn solution(&self) -> String {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Text(text) => text.as_str(),
+                Segment::Blank(blank) => blank.answers.first().map_or(UNDERSCORES, |a| a),
+            })
+            .collect()
+    }
+
+    /// Returns whether each of the `given` answers is accepted by the blank on
+    /// the same position with the given `strictness` (see `Blank::accepts`).
+    /// Missing answers are taken as wrong.
+    pub fn grade(&self, given: &[String], strictness: MacronStrictness) -> Vec<bool> {
+        self.blanks()
+            .iter()
+            .enumerate()
+            .map(|(idx, blank)| {
+                given
+                    .get(idx)
+                    .is_some_and(|answer| blank.accepts(answer, strictness))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_grade() {
+        let pensum =
+            Pensum::try_from("Nauta in {marī|mare?ablative of 'mare'} nāvigat et {rosam} videt.")
+                .unwrap();
+        let blanks = pensum.blanks();
+
+        assert_eq!(blanks.len(), 2);
+        assert_eq!(blanks[0].answers, ["marī", "mare"]);
+        assert_eq!(blanks[0].hint.as_deref(), Some("ablative of 'mare'"));
+        assert_eq!(blanks[1].hint, None);
+
+        assert_eq!(
+            pensum.render(false),
+            "Nauta in ___(1) nāvigat et ___(2) videt."
+        );
+        assert_eq!(
+            pensum.render(true),
+            "Nauta in ___(1: ablative of 'mare') nāvigat et ___(2) videt."
+        );
+        assert_eq!(pensum.solution(), "Nauta in marī nāvigat et rosam videt.");
+
+        // Any alternative is accepted, and macrons are checked as configured.
+        let lenient = MacronStrictness::Lenient;
+        assert_eq!(
+            pensum.grade(&["mare".to_string(), " Rosam ".to_string()], lenient),
+            [true, true]
+        );
+        assert_eq!(
+            pensum.grade(&["mari".to_string(), "rosām".to_string()], lenient),
+            [true, false]
+        );
+        assert_eq!(pensum.grade(&["marī".to_string()], lenient), [true, false]);
+        assert_eq!(
+            pensum.grade(
+                &["mari".to_string(), "rosam".to_string()],
+                MacronStrictness::Strict
+            ),
+            [false, true]
+        );
+        assert_eq!(
+            pensum.grade(
+                &["mārī".to_string(), "rosām".to_string()],
+                MacronStrictness::Ignore
+            ),
+            [true, true]
+        );
+    }
+
+    #[test]
+    fn test_bad_syntax() {
+        assert!(Pensum::try_from("Nauta in marī nāvigat.").is_err());
+        assert!(Pensum::try_from("Nauta in {marī nāvigat.").is_err());
+        assert!(Pensum::try_from("Nauta in marī} nāvigat.").is_err());
+        assert!(Pensum::try_from("Nauta in {|?hint} nāvigat.").is_err());
+        assert!(Pensum::try_from("Nauta in {ma{rī}} nāvigat.").is_err());
+        assert!(Pensum::try_from("Nauta in [marī nāvigat.").is_err());
+        assert!(Pensum::try_from("Nauta] in {marī} nāvigat.").is_err());
+        assert!(Pensum::try_from("Nauta in [ma{rī}] nāvigat.").is_err());
+        assert!(Pensum::try_from("Nauta in [] nāvigat.").is_err());
+    }
+
+    #[test]
+    fn test_brackets_and_underscores() {
+        let pensum = Pensum::try_from("Nauta in [marī|mare] nāvigat et ___ videt.").unwrap();
+        assert!(pensum.needs_solution());
+        assert_eq!(
+            pensum.render(false),
+            "Nauta in ___(1) nāvigat et ___(2) videt."
+        );
+
+        let pensum = Pensum::with_solution(
+            "Nauta in [marī|mare] nāvigat et _____ videt.",
+            "Nauta in marī nāvigat et rosam pulchram videt.\n",
+        )
+        .unwrap();
+        assert!(!pensum.needs_solution());
+        assert_eq!(pensum.blanks()[1].answers, ["rosam pulchram"]);
+        assert_eq!(
+            pensum.grade(
+                &["mare".to_string(), "Rosam  pulchram".to_string()],
+                MacronStrictness::Lenient
+            ),
+            [true, true]
+        );
+
+        // Blanks at the edges, and right after a known one.
+        let pensum = Pensum::with_solution("___ in {marī}___.", "Nauta in marīque.").unwrap();
+        assert_eq!(pensum.solution(), "Nauta in marīque.");
+
+        assert!(Pensum::with_solution("Nauta in ___ nāvigat.", "Nauta in marī.").is_err());
+        assert!(Pensum::with_solution("Nauta in ___ nāvigat.", "Nauta in  nāvigat.").is_err());
+        assert!(Pensum::with_solution("Nauta ______ nāvigat.", "Nauta in marī nāvigat.").is_ok());
+        assert!(Pensum::with_solution("Nauta ___ ___.", "Nauta in marī.").is_ok());
+        assert!(Pensum::with_solution("Nauta ___{in}.", "Nauta marī in.").is_err());
+        assert!(Pensum::with_solution("Nauta ___.", "Nauta marī. Et").is_err());
+    }
+}
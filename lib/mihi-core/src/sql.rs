@@ -0,0 +1,45 @@
+use crate::grammar::{Conjugation, Declension};
+use rusqlite::types::{FromSql, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use rusqlite::Result;
+
+impl ToSql for Declension {
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.clone() as isize))
+    }
+}
+
+impl FromSql for Declension {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let val = value.as_i64().unwrap_or(0);
+
+        match val {
+            1 => Ok(Declension::First),
+            2 => Ok(Declension::Second),
+            3 => Ok(Declension::Third),
+            4 => Ok(Declension::Fourth),
+            5 => Ok(Declension::Fifth),
+            _ => Ok(Declension::Other),
+        }
+    }
+}
+
+impl ToSql for Conjugation {
+    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.clone() as isize))
+    }
+}
+
+impl FromSql for Conjugation {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let val = value.as_i64().unwrap_or(0);
+
+        match val {
+            1 => Ok(Conjugation::First),
+            2 => Ok(Conjugation::Second),
+            3 => Ok(Conjugation::Third),
+            4 => Ok(Conjugation::ThirdIo),
+            5 => Ok(Conjugation::Fourth),
+            _ => Ok(Conjugation::Other),
+        }
+    }
+}
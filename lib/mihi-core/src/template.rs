@@ -0,0 +1,306 @@
+use crate::grammar::{Case, Category, Gender, Number};
+
+/// The gender in which an adjective is to be inflected on a placeholder.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Agreement {
+    /// A fixed gender (e.g. `{{adjective:nom:sg:f}}`).
+    Gender(Gender),
+    /// The gender of the noun picked for the given variable (e.g.
+    /// `{{adjective:nom:sg:noun}}`), so both agree.
+    Variable(String),
+}
+
+/// A placeholder on a template, which is written between double curly braces
+/// as the variable, the case, the number and optionally the gender separated
+/// by colons (e.g. `{{noun:acc:sg}}` or `{{adjective2:abl:pl:noun}}`). The
+/// variable is named after the category of the word (i.e. 'noun' or
+/// 'adjective'), optionally followed by a number so more than one word from the
+/// same category can be used. Placeholders with the same variable are filled
+/// with forms from the same word.
+#[derive(Clone, Debug)]
+pub struct Placeholder {
+    pub variable: String,
+    pub category: Category,
+    pub case: Case,
+    pub number: Number,
+    /// Only for adjectives. When not given, adjectives are inflected in the
+    /// masculine.
+    pub gender: Option<Agreement>,
+}
+
+#[derive(Clone, Debug)]
+enum Segment {
+    Text(String),
+    Placeholder(Placeholder),
+}
+
+/// The enunciate or the solution of a template exercise: a text with
+/// placeholders which are filled with forms of random words each time.
+#[derive(Clone, Debug)]
+pub struct Template {
+    segments: Vec<Segment>,
+}
+
+// Returns the category for the given `variable` if it's well formed.
+fn category_for(variable: &str) -> Option<Category> {
+    let (category, prefix) = if variable.starts_with("noun") {
+        (Category::Noun, "noun")
+    } else if variable.starts_with("adjective") {
+        (Category::Adjective, "adjective")
+    } else {
+        return None;
+    };
+
+    variable[prefix.len()..]
+        .chars()
+        .all(|c| c.is_ascii_digit())
+        .then_some(category)
+}
+
+// Parses the inner text of a placeholder (i.e. without the curly braces).
+fn parse_placeholder(inner: &str) -> Result<Placeholder, String> {
+    let parts: Vec<&str> = inner.split(':').map(str::trim).collect();
+    if !(3..=4).contains(&parts.len()) {
+        return Err(format!(
+            "the placeholder '{{{{{inner}}}}}' has to be written as 'word:case:number' (e.g. 'noun:acc:sg')"
+        ));
+    }
+
+    let Some(category) = category_for(parts[0]) else {
+        return Err(format!(
+            "unknown word '{}' on '{{{{{inner}}}}}'. Available: noun, adjective (optionally followed by a number, e.g. 'noun2')",
+            parts[0]
+        ));
+    };
+    let Some(case) = Case::from_name(parts[1]) else {
+        return Err(format!("unknown case '{}' on '{{{{{inner}}}}}'", parts[1]));
+    };
+    let Some(number) = Number::from_name(parts[2]) else {
+        return Err(format!(
+            "unknown number '{}' on '{{{{{inner}}}}}'",
+            parts[2]
+        ));
+    };
+
+    let gender = match parts.get(3) {
+        None => None,
+        Some(_) if matches!(category, Category::Noun) => {
+            return Err(format!(
+                "nouns cannot be given a gender on '{{{{{inner}}}}}'"
+            ));
+        }
+        Some(&"m") => Some(Agreement::Gender(Gender::Masculine)),
+        Some(&"f") => Some(Agreement::Gender(Gender::Feminine)),
+        Some(&"n") => Some(Agreement::Gender(Gender::Neuter)),
+        Some(variable) if matches!(category_for(variable), Some(Category::Noun)) => {
+            Some(Agreement::Variable(variable.to_string()))
+        }
+        Some(other) => {
+            return Err(format!(
+                "unknown gender '{other}' on '{{{{{inner}}}}}'. Use 'm', 'f', 'n' or a noun to agree with (e.g. 'noun')"
+            ))
+        }
+    };
+
+    Ok(Placeholder {
+        variable: parts[0].to_string(),
+        category,
+        case,
+        number,
+        gender,
+    })
+}
+
+impl TryFrom<&str> for Template {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut segments = vec![];
+        let mut rest = value;
+
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                segments.push(Segment::Text(rest[..start].to_string()));
+            }
+            let Some(len) = rest[start + 2..].find("}}") else {
+                return Err(
+                    "a placeholder was opened with '{{' but it was never closed".to_string()
+                );
+            };
+            let inner = &rest[start + 2..start + 2 + len];
+            segments.push(Segment::Placeholder(parse_placeholder(inner)?));
+            rest = &rest[start + 2 + len + 2..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Text(rest.to_string()));
+        }
+
+        Ok(Template { segments })
+    }
+}
+
+impl Template {
+    /// Returns the template for the enunciate of an exercise, which needs at
+    /// least one placeholder.
+    pub fn enunciate(value: &str) -> Result<Self, String> {
+        let template = Self::try_from(value)?;
+        if template.placeholders().next().is_none() {
+            return Err(
+                "there are no placeholders like '{{noun:acc:sg}}' to be filled".to_string(),
+            );
+        }
+        template.check_agreements(&template.variables())?;
+        Ok(template)
+    }
+
+    /// Returns the template for the `solution` of an exercise, which can only
+    /// use the variables from the given `enunciate`.
+    pub fn solution(enunciate: &Template, solution: &str) -> Result<Self, String> {
+        let template = Self::try_from(solution)?;
+        let known = enunciate.variables();
+        for variable in template.variables() {
+            if !known.contains(&variable) {
+                return Err(format!(
+                    "the solution uses '{variable}', which is not on the enunciate"
+                ));
+            }
+        }
+        template.check_agreements(&known)?;
+        Ok(template)
+    }
+
+    // Returns an error if an adjective agrees with a noun which is not among
+    // the `known` variables.
+    fn check_agreements(&self, known: &[&str]) -> Result<(), String> {
+        for placeholder in self.placeholders() {
+            if let Some(Agreement::Variable(variable)) = &placeholder.gender {
+                if !known.contains(&variable.as_str()) {
+                    return Err(format!(
+                        "'{}' agrees with '{variable}', but there is no placeholder for it",
+                        placeholder.variable
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns all the placeholders from this template.
+    pub fn placeholders(&self) -> impl Iterator<Item = &Placeholder> {
+        self.segments.iter().filter_map(|segment| match segment {
+            Segment::Placeholder(placeholder) => Some(placeholder),
+            Segment::Text(_) => None,
+        })
+    }
+
+    /// Returns the variables used by this template without repetitions, in the
+    /// order in which they first appear.
+    pub fn variables(&self) -> Vec<&str> {
+        let mut res = vec![];
+        for placeholder in self.placeholders() {
+            if !res.contains(&placeholder.variable.as_str()) {
+                res.push(placeholder.variable.as_str());
+            }
+        }
+        res
+    }
+
+    /// Returns every text resulting from filling the placeholders with the
+    /// alternative forms returned by `forms` for each of them. That is, a
+    /// placeholder with two alternative forms (e.g. 'domī' and 'domō') doubles
+    /// the number of texts. Returns an empty vector if any placeholder has no
+    /// forms at all.
+    pub fn fill(&self, forms: impl Fn(&Placeholder) -> Vec<String>) -> Vec<String> {
+        let mut res = vec![String::new()];
+
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => res.iter_mut().for_each(|filled| filled.push_str(text)),
+                Segment::Placeholder(placeholder) => {
+                    let alternatives = forms(placeholder);
+                    res = res
+                        .iter()
+                        .flat_map(|filled| {
+                            alternatives
+                                .iter()
+                                .map(move |form| format!("{filled}{form}"))
+                        })
+                        .collect();
+                }
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_fill() {
+        let enunciate = Template::enunciate(
+            "Make it plural: {{noun:acc:sg}} {{adjective:accusative:singular:noun}}",
+        )
+        .unwrap();
+        let placeholders: Vec<&Placeholder> = enunciate.placeholders().collect();
+        assert_eq!(placeholders.len(), 2);
+        assert_eq!(placeholders[0].case, Case::Accusative);
+        assert_eq!(placeholders[0].number, Number::Singular);
+        assert_eq!(
+            placeholders[1].gender,
+            Some(Agreement::Variable("noun".to_string()))
+        );
+        assert_eq!(enunciate.variables(), ["noun", "adjective"]);
+
+        let solution =
+            Template::solution(&enunciate, "{{noun:acc:pl}} {{adjective:acc:pl:noun}}").unwrap();
+        let filled = solution.fill(|placeholder| match placeholder.category {
+            Category::Noun => vec!["rosās".to_string()],
+            _ => vec!["pulchrās".to_string(), "bellās".to_string()],
+        });
+        assert_eq!(filled, ["rosās pulchrās", "rosās bellās"]);
+
+        assert_eq!(
+            Template::try_from("{{noun:abl:sg}} and {{noun2:abl:sg}}")
+                .unwrap()
+                .fill(|placeholder| vec![placeholder.variable.clone()]),
+            ["noun and noun2"]
+        );
+        assert!(Template::try_from("{{noun:acc:sg}}")
+            .unwrap()
+            .fill(|_| vec![])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_bad_syntax() {
+        for (text, error) in [
+            ("Nothing to fill", "there are no placeholders"),
+            ("{{noun:acc:sg", "never closed"),
+            ("{{noun:acc}}", "has to be written as"),
+            ("{{verb:acc:sg}}", "unknown word 'verb'"),
+            ("{{nouns:acc:sg}}", "unknown word 'nouns'"),
+            ("{{noun:ac:sg}}", "unknown case 'ac'"),
+            ("{{noun:acc:du}}", "unknown number 'du'"),
+            ("{{noun:acc:sg:f}}", "nouns cannot be given a gender"),
+            ("{{adjective:acc:sg:x}}", "unknown gender 'x'"),
+            (
+                "{{adjective:acc:sg:noun2}}",
+                "there is no placeholder for it",
+            ),
+        ] {
+            match Template::enunciate(text) {
+                Ok(_) => panic!("'{text}' should not be accepted"),
+                Err(e) => assert!(e.contains(error), "{text}: {e}"),
+            }
+        }
+
+        let enunciate = Template::enunciate("{{noun:nom:sg}}").unwrap();
+        assert!(Template::solution(&enunciate, "{{noun2:nom:pl}}")
+            .unwrap_err()
+            .contains("not on the enunciate"));
+        assert!(Template::solution(&enunciate, "{{noun:nom:pl}}").is_ok());
+    }
+}
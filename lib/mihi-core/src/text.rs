@@ -0,0 +1,113 @@
+/// Returns the given character without its macron, if it has any (e.g. 'ā' ->
+/// 'a').
+pub fn strip_macron(c: char) -> char {
+    match c {
+        'ā' => 'a',
+        'ē' => 'e',
+        'ī' => 'i',
+        'ō' => 'o',
+        'ū' => 'u',
+        'ȳ' => 'y',
+        'Ā' => 'A',
+        'Ē' => 'E',
+        'Ī' => 'I',
+        'Ō' => 'O',
+        'Ū' => 'U',
+        'Ȳ' => 'Y',
+        _ => c,
+    }
+}
+
+/// Returns the given string without any macrons (e.g. 'rēgīna' -> 'regina').
+pub fn strip_macrons(s: &str) -> String {
+    s.chars().map(strip_macron).collect()
+}
+
+/// Returns a normalized version of the given `enunciated` string, so two
+/// enunciates can be compared regardless of macrons, casing or white spacing.
+pub fn normalize_enunciated(enunciated: &str) -> String {
+    strip_macrons(enunciated)
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect()
+}
+
+/// A line on the difference between two texts, as returned by `diff_lines`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DiffLine<'a> {
+    /// The line is on both texts.
+    Same(&'a str),
+    /// The line is only on the old text.
+    Removed(&'a str),
+    /// The line is only on the new text.
+    Added(&'a str),
+}
+
+/// Returns the lines which have to be removed from `old` and added to it in
+/// order to get `new`, alongside the lines they share, in the order in which
+/// they appear. Lines are compared as they are, trailing white space included.
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Length of the longest common subsequence between the suffixes of both
+    // texts starting at each pair of lines.
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut res = Vec::with_capacity(old.len().max(new.len()));
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            res.push(DiffLine::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            res.push(DiffLine::Removed(old[i]));
+            i += 1;
+        } else {
+            res.push(DiffLine::Added(new[j]));
+            j += 1;
+        }
+    }
+    res.extend(old[i..].iter().map(|line| DiffLine::Removed(line)));
+    res.extend(new[j..].iter().map(|line| DiffLine::Added(line)));
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines() {
+        assert_eq!(diff_lines("", ""), vec![]);
+        assert_eq!(
+            diff_lines("a\nb", "a\nb"),
+            vec![DiffLine::Same("a"), DiffLine::Same("b")]
+        );
+        assert_eq!(
+            diff_lines("rosa\nrosam\nrosae", "rosa\nrosae\nrosā"),
+            vec![
+                DiffLine::Same("rosa"),
+                DiffLine::Removed("rosam"),
+                DiffLine::Same("rosae"),
+                DiffLine::Added("rosā"),
+            ]
+        );
+        assert_eq!(
+            diff_lines("a", "b"),
+            vec![DiffLine::Removed("a"), DiffLine::Added("b")]
+        );
+    }
+}
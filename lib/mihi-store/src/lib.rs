@@ -0,0 +1,553 @@
+//! Persistence for 'mihi': where the configuration and the database live, and
+//! how the database is created, migrated and seeded with the inflection
+//! endings.
+
+mod migrations;
+mod platform;
+
+use platform::{env_var, Env, Platform};
+
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+// The data directory given explicitly (e.g. through the '--data-dir' flag),
+// which takes precedence over the environment.
+static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+// The profile given explicitly (e.g. through the '--profile' flag), if any.
+static PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Returns the configuration path for the application, and it even creates it
+/// if it doesn't exist already. The 'XDG_CONFIG_HOME' environment variable is
+/// honored on all platforms, so tests and scripts can point the application
+/// somewhere else. If a profile was set (see `set_profile`), then this is the
+/// path of the profile instead.
+pub fn get_config_path() -> Result<PathBuf, String> {
+    if let Some(name) = PROFILE.get() {
+        return get_profile_path(name);
+    }
+
+    let dir = config_dir(Platform::current(), &env_var)?;
+    match std::fs::create_dir_all(&dir) {
+        Ok(_) => {}
+        Err(e) => return Err(e.to_string()),
+    };
+
+    Ok(dir)
+}
+
+// Returns the configuration path for the given `env` on the `target` platform,
+// without creating it. See `get_config_path`.
+fn config_dir(target: Platform, env: Env) -> Result<PathBuf, String> {
+    let dir = match env("XDG_CONFIG_HOME") {
+        Some(path) => PathBuf::from(path),
+        None => platform::config_home(target, env)?,
+    };
+    Ok(dir.join("mihi"))
+}
+
+// Returns the data path for the given `env` on the `target` platform, without
+// creating it, when none was set explicitly. See `get_data_path`.
+fn data_dir(target: Platform, env: Env) -> Result<PathBuf, String> {
+    if let Some(path) = env("MIHI_DATA_DIR").filter(|path| !path.is_empty()) {
+        return Ok(PathBuf::from(path));
+    }
+
+    let dir = match env("XDG_DATA_HOME") {
+        Some(path) => PathBuf::from(path),
+        None => platform::data_home(target, env)?,
+    };
+    Ok(dir.join("mihi"))
+}
+
+/// Sets the directory where the database lives for the rest of the execution,
+/// regardless of the environment. This can only be done once, and it has to be
+/// done before anything touches the database.
+pub fn set_data_path(dir: PathBuf) -> Result<(), String> {
+    DATA_DIR
+        .set(dir)
+        .map_err(|_| String::from("the data directory has already been set"))
+}
+
+/// Returns the data path for the application, where the database lives, and it
+/// even creates it if it doesn't exist already. This is the directory given to
+/// `set_data_path`, or the one from the 'MIHI_DATA_DIR' environment variable.
+/// Otherwise it's 'mihi' inside of 'XDG_DATA_HOME' (honored on all platforms
+/// as with `get_config_path`) or of the data directory of the platform.
+pub fn get_data_path() -> Result<PathBuf, String> {
+    let dir = match DATA_DIR.get() {
+        Some(dir) => dir.clone(),
+        None => data_dir(Platform::current(), &env_var)?,
+    };
+
+    match std::fs::create_dir_all(&dir) {
+        Ok(_) => {}
+        Err(e) => return Err(format!("could not create '{}': {e}", dir.display())),
+    };
+
+    Ok(dir)
+}
+
+/// Switches to the profile with the given `name` for the rest of the execution,
+/// so both the configuration and the database are taken from its path (see
+/// `get_profile_path`). As with `set_data_path`, this can only be done once and
+/// before anything touches the database.
+pub fn set_profile(name: &str) -> Result<(), String> {
+    check_profile_name(name)?;
+    PROFILE
+        .set(name.to_string())
+        .map_err(|_| String::from("the profile has already been set"))
+}
+
+/// Returns the name of the profile that was set with `set_profile`, if any.
+pub fn current_profile() -> Option<&'static str> {
+    PROFILE.get().map(String::as_str)
+}
+
+// Returns an error unless the given `name` can be used for a profile, which has
+// to be safe to be used as the name of a directory.
+fn check_profile_name(name: &str) -> Result<(), String> {
+    if name.is_empty()
+        || name.starts_with('-')
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!(
+            "bad name '{name}' for a profile: only letters, digits, '-' and '_' are allowed"
+        ));
+    }
+    Ok(())
+}
+
+// Returns the directory holding all the profiles, without creating it.
+fn profiles_path() -> Result<PathBuf, String> {
+    Ok(get_data_path()?.join("profiles"))
+}
+
+/// Returns the path for the profile with the given `name`, which holds both
+/// its configuration and its database, and it even creates it if it doesn't
+/// exist already. Profiles live inside of the data path (see `get_data_path`).
+pub fn get_profile_path(name: &str) -> Result<PathBuf, String> {
+    check_profile_name(name)?;
+    let dir = profiles_path()?.join(name);
+
+    match std::fs::create_dir_all(&dir) {
+        Ok(_) => Ok(dir),
+        Err(e) => Err(format!("could not create '{}': {e}", dir.display())),
+    }
+}
+
+/// Returns the names of all the profiles, sorted alphabetically.
+pub fn select_profiles() -> Result<Vec<String>, String> {
+    let dir = profiles_path()?;
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) => return Err(format!("could not read '{}': {e}", dir.display())),
+    };
+    let mut res: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    res.sort();
+    Ok(res)
+}
+
+/// Returns true if there is a profile with the given `name`.
+pub fn profile_exists(name: &str) -> Result<bool, String> {
+    check_profile_name(name)?;
+    Ok(profiles_path()?.join(name).is_dir())
+}
+
+/// Removes the profile with the given `name`, alongside its configuration and
+/// its database.
+pub fn delete_profile(name: &str) -> Result<(), String> {
+    if !profile_exists(name)? {
+        return Err(format!("the profile '{name}' does not exist"));
+    }
+    if current_profile() == Some(name) {
+        close_connection();
+    }
+
+    let dir = profiles_path()?.join(name);
+    match std::fs::remove_dir_all(&dir) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not remove '{}': {e}", dir.display())),
+    }
+}
+
+/// Schema for a brand new database.
+const SCHEMA: &str = include_str!("schema.sql");
+
+/// Endings for the inflection of words, which every database needs.
+const INFLECTION_DATA: &str = include_str!("seed.sql");
+
+/// Returns the path to the database inside of the data path (see
+/// `get_data_path`), or inside of the path of the current profile if one was
+/// set (see `set_profile`). Note that you can set the 'MIHI_DATABASE' environment
+/// variable to define an alternative path, which is relative to the data path
+/// (or to the path of the profile). It can only be absolute if no profile was
+/// set, as otherwise profiles would end up sharing the same database. Databases
+/// from older versions of the application, which lived inside of the
+/// configuration path, are moved into the data path the first time this is
+/// called.
+pub fn get_database_path() -> Result<PathBuf, String> {
+    let name = &std::env::var("MIHI_DATABASE").unwrap_or("database.sqlite3".to_string());
+    if let Some(profile) = PROFILE.get() {
+        if Path::new(name).is_absolute() {
+            return Err(format!(
+                "'MIHI_DATABASE' cannot be an absolute path ('{name}') when using a profile"
+            ));
+        }
+        return Ok(get_profile_path(profile)?.join(name));
+    }
+    let path = get_data_path()?.join(name);
+
+    if !path.exists() {
+        let legacy = get_config_path()?.join(name);
+        if legacy != path && legacy.exists() {
+            move_database(&legacy, &path)?;
+        }
+    }
+
+    Ok(path)
+}
+
+// Moves the database from `from` into `to`, alongside the journal files which
+// SQLite might have left next to it.
+fn move_database(from: &Path, to: &Path) -> Result<(), String> {
+    for suffix in ["", "-journal", "-wal", "-shm"] {
+        let source = PathBuf::from(format!("{}{suffix}", from.display()));
+        if !source.exists() {
+            continue;
+        }
+        let target = PathBuf::from(format!("{}{suffix}", to.display()));
+
+        // Renaming fails across file systems, so fall back to a copy.
+        if std::fs::rename(&source, &target).is_err() {
+            if let Err(e) = std::fs::copy(&source, &target) {
+                return Err(format!(
+                    "could not move '{}' into '{}': {e}",
+                    source.display(),
+                    target.display()
+                ));
+            }
+            if let Err(e) = std::fs::remove_file(&source) {
+                return Err(format!("could not remove '{}': {e}", source.display()));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The state in which the database can be found.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DatabaseStatus {
+    /// There is no file for the database.
+    Missing,
+    /// The file exists but it has no tables on it.
+    Empty,
+    /// The database is ready to be used.
+    Ready,
+}
+
+/// Returns the state of the database, without creating it if it's missing.
+pub fn database_status() -> Result<DatabaseStatus, String> {
+    let path = get_database_path()?;
+    if !path.exists() {
+        return Ok(DatabaseStatus::Missing);
+    }
+
+    let conn = match rusqlite::Connection::open_with_flags(
+        &path,
+        rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY,
+    ) {
+        Ok(conn) => conn,
+        Err(e) => return Err(format!("could not open '{}': {e}", path.display())),
+    };
+    match conn.query_row(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'words'",
+        [],
+        |row| row.get::<usize, isize>(0),
+    ) {
+        Ok(0) => Ok(DatabaseStatus::Empty),
+        Ok(_) => Ok(DatabaseStatus::Ready),
+        Err(e) => Err(format!("could not read '{}': {e}", path.display())),
+    }
+}
+
+/// Initializes the database. If a `seed` is given, then it's taken as an
+/// existing database to be copied over. Otherwise the database is created with
+/// no words on it, but with the data needed to inflect them (see
+/// `seed_inflection_data`).
+pub fn init_database(seed: Option<&Path>) -> Result<(), String> {
+    let path = get_database_path()?;
+
+    if let Some(seed) = seed {
+        close_connection();
+        if let Err(e) = std::fs::copy(seed, &path) {
+            return Err(format!("could not copy '{}': {e}", seed.display()));
+        }
+        return migrate_database().map(|_| ());
+    }
+
+    if let Err(e) = get_connection()?.execute_batch(SCHEMA) {
+        return Err(format!("could not create the database: {e}"));
+    }
+    migrate_database()?;
+    seed_inflection_data()
+}
+
+/// Applies the migrations which the database is still missing, so databases
+/// created by older versions of the application are brought up to date. This
+/// is meant to be called on every startup, as it does nothing once the
+/// database is up to date. Returns the number of migrations that were applied.
+pub fn migrate_database() -> Result<usize, String> {
+    migrations::migrate(&*get_connection()?)
+}
+
+/// Populates the 'forms', 'declensions' and 'conjugations' tables with the
+/// endings from which words are inflected. This is needed for databases
+/// created before these were bundled into the application, which could not
+/// inflect anything. Databases which already have endings only get the ones
+/// for the kinds of words they are still missing.
+pub fn seed_inflection_data() -> Result<(), String> {
+    let conn = get_connection()?;
+
+    let empty = match conn.query_row("SELECT COUNT(*) = 0 FROM forms", [], |row| {
+        row.get::<usize, bool>(0)
+    }) {
+        Ok(empty) => empty,
+        Err(e) => return Err(format!("could not read the inflection data: {e}")),
+    };
+
+    let tx = match conn.unchecked_transaction() {
+        Ok(tx) => tx,
+        Err(e) => return Err(format!("could not seed the inflection data: {e}")),
+    };
+    let seeded = if empty {
+        tx.execute_batch(INFLECTION_DATA)
+    } else {
+        migrations::add_missing_endings(&tx)
+    };
+    if let Err(e) = seeded {
+        return Err(format!("could not seed the inflection data: {e}"));
+    }
+    match tx.commit() {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not seed the inflection data: {e}")),
+    }
+}
+
+/// A handle to the connection to the database, which is opened once and then
+/// shared by every caller on the same thread. Cloning it is cheap.
+pub type Database = Rc<rusqlite::Connection>;
+
+/// Number of prepared statements kept by each connection, so the queries that
+/// are run over and over on a session are only compiled once.
+const STATEMENT_CACHE_CAPACITY: usize = 64;
+
+/// How long to wait for other connections to release the database before
+/// giving up on a query.
+pub const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+thread_local! {
+    // The connection returned by `get_connection`, if it was already opened.
+    static CONNECTION: RefCell<Option<Database>> = const { RefCell::new(None) };
+}
+
+/// Get a connection to the database. The database is only opened the first
+/// time this is called from each thread, and later calls return the same
+/// connection. Note that you can set the 'MIHI_DATABASE' environment variable
+/// to define an alternative path.
+///
+/// Since the connection is shared, transactions have to be opened with
+/// `unchecked_transaction`, and callers must not hold one while calling
+/// functions which open theirs.
+pub fn get_connection() -> Result<Database, String> {
+    if let Some(conn) = CONNECTION.with_borrow(|conn| conn.clone()) {
+        return Ok(conn);
+    }
+
+    let conn = Rc::new(open_connection(&get_database_path()?)?);
+    conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
+
+    CONNECTION.set(Some(conn.clone()));
+    Ok(conn)
+}
+
+/// Opens a new connection to the database on the given `path`, set up so it
+/// can be used while other processes (e.g. a practice session on another
+/// terminal) are also using the database: the database is put in WAL mode, so
+/// readers and a writer don't block each other, and writes wait for up to
+/// `BUSY_TIMEOUT` for other writers to finish instead of failing right away.
+/// Foreign keys are also enforced, so deleting a row cascades into the rows
+/// which depend on it.
+pub fn open_connection(path: &Path) -> Result<rusqlite::Connection, String> {
+    let conn = match rusqlite::Connection::open(path) {
+        Ok(handle) => handle,
+        Err(_) => {
+            return Err(format!(
+                "could not fetch the database in '{}'",
+                path.display()
+            ))
+        }
+    };
+
+    if let Err(e) = conn
+        .busy_timeout(BUSY_TIMEOUT)
+        .and_then(|_| conn.pragma_update(None, "journal_mode", "WAL"))
+        .and_then(|_| conn.pragma_update(None, "synchronous", "NORMAL"))
+        .and_then(|_| conn.pragma_update(None, "foreign_keys", "ON"))
+    {
+        return Err(format!("could not set up '{}': {e}", path.display()));
+    }
+    Ok(conn)
+}
+
+// Drops the connection shared by the current thread, so the next call to
+// `get_connection` opens the database again. Needed before the file of the
+// database is replaced.
+fn close_connection() {
+    CONNECTION.set(None);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_and_data_dirs() {
+        let home = |name: &str| match name {
+            "HOME" => Some("/home/marcus".to_string()),
+            _ => None,
+        };
+        assert_eq!(
+            config_dir(Platform::Other, &home).unwrap(),
+            Path::new("/home/marcus/.config/mihi")
+        );
+        assert_eq!(
+            data_dir(Platform::Other, &home).unwrap(),
+            Path::new("/home/marcus/.local/share/mihi")
+        );
+        assert_eq!(
+            data_dir(Platform::MacOs, &home).unwrap(),
+            Path::new("/home/marcus/Library/Application Support/mihi")
+        );
+
+        // XDG variables take precedence on all platforms.
+        let xdg = |name: &str| match name {
+            "XDG_CONFIG_HOME" => Some("/xdg/config".to_string()),
+            "XDG_DATA_HOME" => Some("/xdg/data".to_string()),
+            "MIHI_DATA_DIR" => Some(String::new()),
+            _ => home(name),
+        };
+        for target in [Platform::Windows, Platform::MacOs, Platform::Other] {
+            assert_eq!(
+                config_dir(target, &xdg).unwrap(),
+                Path::new("/xdg/config/mihi")
+            );
+            assert_eq!(data_dir(target, &xdg).unwrap(), Path::new("/xdg/data/mihi"));
+        }
+
+        // Unless the data directory is given explicitly, which is taken as is.
+        let explicit = |name: &str| match name {
+            "MIHI_DATA_DIR" => Some("/somewhere/else".to_string()),
+            _ => xdg(name),
+        };
+        assert_eq!(
+            data_dir(Platform::Other, &explicit).unwrap(),
+            Path::new("/somewhere/else")
+        );
+
+        let empty = |_: &str| None;
+        assert!(config_dir(Platform::Other, &empty).is_err());
+        assert!(data_dir(Platform::Other, &empty).is_err());
+    }
+
+    #[test]
+    fn test_inflection_data() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(SCHEMA).unwrap();
+        conn.execute_batch(INFLECTION_DATA).unwrap();
+
+        let count = |sql: &str| conn.query_row(sql, [], |row| row.get::<usize, isize>(0));
+        let forms = count("SELECT COUNT(*) FROM forms").unwrap();
+        assert!(forms > 0);
+        assert_eq!(
+            count("SELECT COUNT(*) FROM forms WHERE kind = 'a' AND \"case\" = 3 AND gender = 0")
+                .unwrap(),
+            2
+        );
+
+        // Seeding twice leaves the data as it was.
+        conn.execute_batch(INFLECTION_DATA).unwrap();
+        assert_eq!(count("SELECT COUNT(*) FROM forms").unwrap(), forms);
+    }
+
+    #[test]
+    fn test_open_connection() {
+        let path = std::env::temp_dir().join(format!("mihi-store-{}.sqlite3", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let conn = open_connection(&path).unwrap();
+        let pragma = |name: &str| {
+            conn.pragma_query_value(None, name, |row| {
+                row.get::<usize, rusqlite::types::Value>(0)
+            })
+            .unwrap()
+        };
+        assert_eq!(pragma("journal_mode"), "wal".to_string().into());
+        // NORMAL is 1.
+        assert_eq!(pragma("synchronous"), 1.into());
+        assert_eq!(pragma("foreign_keys"), 1.into());
+        assert_eq!(
+            pragma("busy_timeout"),
+            (BUSY_TIMEOUT.as_millis() as i64).into()
+        );
+
+        drop(conn);
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{suffix}", path.display()));
+        }
+    }
+
+    #[test]
+    fn test_shared_connection() {
+        // Keep everything inside of a temporary directory, so neither the real
+        // database nor a legacy one from the configuration path are touched.
+        let dir = std::env::temp_dir().join(format!("mihi-shared-{}", std::process::id()));
+        std::env::set_var("MIHI_DATABASE", "shared.sqlite3");
+        std::env::set_var("XDG_CONFIG_HOME", dir.join("config"));
+        set_data_path(dir.join("data")).unwrap();
+        assert_eq!(
+            get_database_path().unwrap(),
+            dir.join("data").join("shared.sqlite3")
+        );
+
+        let conn = get_connection().unwrap();
+        assert!(Rc::ptr_eq(&conn, &get_connection().unwrap()));
+
+        // Each thread has its own connection.
+        let other = std::thread::spawn(|| {
+            let conn = get_connection().unwrap();
+            Rc::ptr_eq(&conn, &get_connection().unwrap())
+        });
+        assert!(other.join().unwrap());
+
+        close_connection();
+        assert!(!Rc::ptr_eq(&conn, &get_connection().unwrap()));
+
+        close_connection();
+        drop(conn);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,1050 @@
+//! Numbered migrations which bring databases created by older versions of the
+//! application up to date. The 'schema_version' table records which ones have
+//! already been applied, so each of them runs only once. Even so, every
+//! migration checks what is already there before changing anything: brand new
+//! databases are created from the latest schema, and databases from before
+//! migrations existed may have gotten some of these changes already.
+
+use rusqlite::{Connection, Result};
+
+/// A change to the schema of the database.
+struct Migration {
+    /// Number of the migration. Migrations are applied in increasing order,
+    /// and numbers must never be reused.
+    version: usize,
+    /// What the migration does, for error messages.
+    description: &'static str,
+    apply: fn(&Connection) -> Result<()>,
+}
+
+/// All the migrations, in the order in which they have to be applied. New
+/// migrations go at the end, and existing ones must never be changed.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "create the inflection, relation and tag tables",
+        apply: create_base_tables,
+    },
+    Migration {
+        version: 2,
+        description: "add the hierarchy of tags",
+        apply: add_tag_hierarchy,
+    },
+    Migration {
+        version: 3,
+        description: "add descriptions and colors to tags",
+        apply: add_tag_metadata,
+    },
+    Migration {
+        version: 4,
+        description: "add occurrences to words",
+        apply: add_word_occurrences,
+    },
+    Migration {
+        version: 5,
+        description: "add the SM-2 schedule to words",
+        apply: add_word_schedule,
+    },
+    Migration {
+        version: 6,
+        description: "add Leitner boxes and suspensions to words",
+        apply: add_word_boxes,
+    },
+    Migration {
+        version: 7,
+        description: "create the notes table",
+        apply: create_notes,
+    },
+    Migration {
+        version: 8,
+        description: "create the exams table",
+        apply: create_exams,
+    },
+    Migration {
+        version: 9,
+        description: "create the drill attempts table",
+        apply: create_drill_attempts,
+    },
+    Migration {
+        version: 10,
+        description: "create the session tables",
+        apply: create_sessions,
+    },
+    Migration {
+        version: 11,
+        description: "add time limits to sessions",
+        apply: add_session_time_limits,
+    },
+    Migration {
+        version: 12,
+        description: "create the pending sessions table",
+        apply: create_pending_sessions,
+    },
+    Migration {
+        version: 13,
+        description: "create the exercise tags table",
+        apply: create_exercise_tags,
+    },
+    Migration {
+        version: 14,
+        description: "add expected minutes and scores to exercises",
+        apply: add_exercise_scores,
+    },
+    Migration {
+        version: 15,
+        description: "track attempts on exercises",
+        apply: add_exercise_attempts,
+    },
+    Migration {
+        version: 16,
+        description: "link exercises to words",
+        apply: create_exercise_words,
+    },
+    Migration {
+        version: 17,
+        description: "create the lessons table",
+        apply: create_lessons,
+    },
+    Migration {
+        version: 18,
+        description: "add the SM-2 schedule to exercises",
+        apply: add_exercise_schedule,
+    },
+    Migration {
+        version: 19,
+        description: "add foreign keys to the inflection, relation and tag tables",
+        apply: add_foreign_keys,
+    },
+    Migration {
+        version: 20,
+        description: "cascade deletes into associations, relations, attempts and notes",
+        apply: add_cascades,
+    },
+    Migration {
+        version: 21,
+        description: "create the sync_points table",
+        apply: create_sync_points,
+    },
+    Migration {
+        version: 22,
+        description: "create the deletions table",
+        apply: create_deletions,
+    },
+    Migration {
+        version: 23,
+        description: "add the endings for domus and Greek nouns",
+        apply: add_missing_endings,
+    },
+    Migration {
+        version: 24,
+        description: "cascade deletes into drill attempts and session answers",
+        apply: add_attempt_and_answer_cascades,
+    },
+    Migration {
+        version: 25,
+        description: "add flags to pending sessions",
+        apply: add_pending_session_flags,
+    },
+];
+
+// Returns true if the given `table` has the given `column`.
+fn has_column(conn: &Connection, table: &str, column: &str) -> Result<bool> {
+    conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info(?1) WHERE name = ?2",
+        [table, column],
+        |row| row.get::<usize, isize>(0),
+    )
+    .map(|found| found > 0)
+}
+
+// Adds the given `column` with the given `definition` to the given `table`
+// unless it's already there. Returns true if it was added.
+fn add_column(conn: &Connection, table: &str, column: &str, definition: &str) -> Result<bool> {
+    if has_column(conn, table, column)? {
+        return Ok(false);
+    }
+    conn.execute(
+        format!("ALTER TABLE {table} ADD COLUMN {column} {definition}").as_str(),
+        [],
+    )?;
+    Ok(true)
+}
+
+// Returns true if the given `table` declares any foreign key.
+fn has_foreign_keys(conn: &Connection, table: &str) -> Result<bool> {
+    conn.query_row(
+        "SELECT COUNT(*) FROM pragma_foreign_key_list(?1)",
+        [table],
+        |row| row.get::<usize, isize>(0),
+    )
+    .map(|found| found > 0)
+}
+
+// Returns true if the given `table` declares any foreign key which cascades
+// deletes.
+fn has_cascades(conn: &Connection, table: &str) -> Result<bool> {
+    conn.query_row(
+        "SELECT COUNT(*) FROM pragma_foreign_key_list(?1) WHERE on_delete = 'CASCADE'",
+        [table],
+        |row| row.get::<usize, isize>(0),
+    )
+    .map(|found| found > 0)
+}
+
+// Creates the given `table` again with the given `definition` (i.e. what goes
+// between the parentheses of 'CREATE TABLE'), as SQLite cannot add constraints
+// to an existing table. The given `columns` are copied over from the rows
+// matching the given `condition`, and then the given `indexes` are created.
+fn rebuild_table(
+    conn: &Connection,
+    table: &str,
+    definition: &str,
+    columns: &str,
+    condition: &str,
+    indexes: &str,
+) -> Result<()> {
+    conn.execute_batch(
+        format!(
+            "CREATE TABLE {table}_new ({definition}); \
+             INSERT INTO {table}_new ({columns}) SELECT {columns} FROM {table} WHERE {condition}; \
+             DROP TABLE {table}; \
+             ALTER TABLE {table}_new RENAME TO {table}; \
+             {indexes}"
+        )
+        .as_str(),
+    )
+}
+
+fn create_base_tables(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS declensions ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             title varchar, \
+             \"order\" integer, \
+             language_id integer, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL); \
+         CREATE UNIQUE INDEX IF NOT EXISTS index_declensions_on_title ON declensions (title); \
+         CREATE UNIQUE INDEX IF NOT EXISTS index_declensions_on_order ON declensions (\"order\"); \
+         CREATE TABLE IF NOT EXISTS forms ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             number integer, \
+             gender integer, \
+             \"case\" integer, \
+             value varchar, \
+             declension_id integer, \
+             kind varchar, \
+             tense integer, \
+             mood integer, \
+             voice integer, \
+             person integer, \
+             conjugation_id integer, \
+             asked_at datetime, \
+             succeeded integer DEFAULT 0, \
+             failed integer DEFAULT 0, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL); \
+         CREATE TABLE IF NOT EXISTS conjugations ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             \"order\" integer, \
+             language_id integer, \
+             title varchar, \
+             regular boolean DEFAULT 1, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL); \
+         CREATE UNIQUE INDEX IF NOT EXISTS index_conjugations_on_title ON conjugations (title); \
+         CREATE UNIQUE INDEX IF NOT EXISTS index_conjugations_on_order ON conjugations (\"order\"); \
+         CREATE TABLE IF NOT EXISTS word_relations ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             source_id integer, \
+             destination_id integer, \
+             kind integer, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL); \
+         CREATE UNIQUE INDEX IF NOT EXISTS index_word_relations_on_source_id_and_destination_id \
+             ON word_relations (source_id, destination_id); \
+         CREATE TABLE IF NOT EXISTS tags ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             name varchar NOT NULL, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL); \
+         CREATE UNIQUE INDEX IF NOT EXISTS index_tags_on_name ON tags (name); \
+         CREATE TABLE IF NOT EXISTS tag_associations ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             word_id integer NOT NULL, \
+             tag_id integer NOT NULL, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL); \
+         CREATE UNIQUE INDEX IF NOT EXISTS word_tag_unique ON tag_associations (word_id, tag_id);",
+    )
+}
+
+// Tags which were already named after another one (e.g. 'llpsi/cap09' and
+// 'llpsi') get their parent set right away.
+fn add_tag_hierarchy(conn: &Connection) -> Result<()> {
+    if add_column(conn, "tags", "parent_id", "integer REFERENCES tags (id)")? {
+        conn.execute(
+            "UPDATE tags SET parent_id = ( \
+                 SELECT p.id FROM tags p \
+                 WHERE tags.name LIKE p.name || '/%' \
+                       AND instr(substr(tags.name, length(p.name) + 2), '/') = 0)",
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+fn add_tag_metadata(conn: &Connection) -> Result<()> {
+    add_column(conn, "tags", "description", "text")?;
+    add_column(conn, "tags", "color", "varchar")?;
+    Ok(())
+}
+
+fn add_word_occurrences(conn: &Connection) -> Result<()> {
+    add_column(conn, "words", "occurrences", "integer DEFAULT 0 NOT NULL")?;
+    Ok(())
+}
+
+fn add_word_schedule(conn: &Connection) -> Result<()> {
+    add_column(conn, "words", "ease", "real DEFAULT 2.5 NOT NULL")?;
+    add_column(conn, "words", "interval_days", "integer DEFAULT 0 NOT NULL")?;
+    add_column(conn, "words", "due_at", "datetime")?;
+    Ok(())
+}
+
+fn add_word_boxes(conn: &Connection) -> Result<()> {
+    add_column(conn, "words", "box", "integer DEFAULT 1 NOT NULL")?;
+    add_column(conn, "words", "suspended", "boolean DEFAULT 0 NOT NULL")?;
+    add_column(conn, "words", "last_asked_at", "datetime")?;
+    Ok(())
+}
+
+fn create_notes(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS notes ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             word_id integer, \
+             session integer NOT NULL, \
+             body text NOT NULL, \
+             created_at datetime(6) NOT NULL); \
+         CREATE INDEX IF NOT EXISTS index_notes_on_word_id ON notes (word_id);",
+    )
+}
+
+fn create_exams(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS exams ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             tags text NOT NULL, \
+             questions integer NOT NULL, \
+             score real NOT NULL, \
+             breakdown jsonb DEFAULT '[]', \
+             created_at datetime(6) NOT NULL);",
+    )
+}
+
+fn create_drill_attempts(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS drill_attempts ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             word_id integer NOT NULL, \
+             drill varchar NOT NULL, \
+             expected varchar NOT NULL, \
+             given varchar NOT NULL, \
+             succeeded boolean DEFAULT 0 NOT NULL, \
+             created_at datetime(6) NOT NULL); \
+         CREATE INDEX IF NOT EXISTS index_drill_attempts_on_drill \
+             ON drill_attempts (drill);",
+    )
+}
+
+fn create_sessions(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS sessions ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             kind varchar NOT NULL, \
+             asked integer NOT NULL, \
+             correct integer NOT NULL, \
+             category varchar, \
+             tags text DEFAULT '' NOT NULL, \
+             started_at datetime(6) NOT NULL, \
+             finished_at datetime(6) NOT NULL); \
+         CREATE TABLE IF NOT EXISTS session_answers ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             session_id integer NOT NULL, \
+             word_id integer NOT NULL, \
+             given varchar NOT NULL, \
+             succeeded boolean DEFAULT 0 NOT NULL, \
+             created_at datetime(6) NOT NULL); \
+         CREATE INDEX IF NOT EXISTS index_session_answers_on_session_id \
+             ON session_answers (session_id); \
+         CREATE INDEX IF NOT EXISTS index_session_answers_on_word_id \
+             ON session_answers (word_id);",
+    )
+}
+
+fn add_session_time_limits(conn: &Connection) -> Result<()> {
+    add_column(conn, "sessions", "time_limit", "integer")?;
+    add_column(conn, "session_answers", "response_ms", "integer")?;
+    Ok(())
+}
+
+fn create_pending_sessions(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS pending_sessions ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             kind varchar NOT NULL, \
+             category integer, \
+             tags text DEFAULT '' NOT NULL, \
+             word_ids text NOT NULL, \
+             created_at datetime(6) NOT NULL);",
+    )
+}
+
+fn create_exercise_tags(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS exercise_tag_associations ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             exercise_id integer NOT NULL, \
+             tag_id integer NOT NULL, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL); \
+         CREATE UNIQUE INDEX IF NOT EXISTS exercise_tag_unique \
+             ON exercise_tag_associations (exercise_id, tag_id);",
+    )
+}
+
+fn add_exercise_scores(conn: &Connection) -> Result<()> {
+    add_column(conn, "exercises", "minutes", "integer DEFAULT 5 NOT NULL")?;
+    add_column(conn, "exercises", "score", "integer")?;
+    Ok(())
+}
+
+// Attempts were already being recorded before the 'attempts' and the
+// 'last_attempted_at' columns existed, so they are filled in from them.
+fn add_exercise_attempts(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS exercise_attempts ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             exercise_id integer NOT NULL, \
+             seconds integer NOT NULL, \
+             succeeded boolean DEFAULT 0 NOT NULL, \
+             created_at datetime(6) NOT NULL); \
+         CREATE INDEX IF NOT EXISTS index_exercise_attempts_on_exercise_id \
+             ON exercise_attempts (exercise_id);",
+    )?;
+
+    if add_column(conn, "exercises", "attempts", "integer DEFAULT 0 NOT NULL")? {
+        conn.execute(
+            "UPDATE exercises SET attempts = ( \
+                 SELECT COUNT(*) FROM exercise_attempts \
+                 WHERE exercise_id = exercises.id)",
+            [],
+        )?;
+    }
+    if add_column(conn, "exercises", "last_attempted_at", "datetime")? {
+        conn.execute(
+            "UPDATE exercises SET last_attempted_at = ( \
+                 SELECT MAX(created_at) FROM exercise_attempts \
+                 WHERE exercise_id = exercises.id)",
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+fn create_exercise_words(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS exercise_words ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             exercise_id integer NOT NULL, \
+             word_id integer NOT NULL, \
+             created_at datetime(6) NOT NULL); \
+         CREATE UNIQUE INDEX IF NOT EXISTS exercise_word_unique \
+             ON exercise_words (exercise_id, word_id);",
+    )
+}
+
+fn create_lessons(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS lessons ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             name varchar NOT NULL, \
+             position integer NOT NULL, \
+             source varchar, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL); \
+         CREATE UNIQUE INDEX IF NOT EXISTS index_lessons_on_name ON lessons (name);",
+    )?;
+    for table in ["words", "exercises"] {
+        add_column(conn, table, "lesson_id", "integer REFERENCES lessons (id)")?;
+    }
+    Ok(())
+}
+
+fn add_exercise_schedule(conn: &Connection) -> Result<()> {
+    add_column(conn, "exercises", "ease", "real DEFAULT 2.5 NOT NULL")?;
+    add_column(
+        conn,
+        "exercises",
+        "interval_days",
+        "integer DEFAULT 0 NOT NULL",
+    )?;
+    add_column(conn, "exercises", "due_at", "datetime")?;
+    Ok(())
+}
+
+// Associations and relations pointing to words or tags which no longer exist
+// are dropped along the way.
+fn add_foreign_keys(conn: &Connection) -> Result<()> {
+    if !has_foreign_keys(conn, "forms")? {
+        rebuild_table(
+            conn,
+            "forms",
+            "id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             number integer, \
+             gender integer, \
+             \"case\" integer, \
+             value varchar, \
+             declension_id integer, \
+             kind varchar, \
+             tense integer, \
+             mood integer, \
+             voice integer, \
+             person integer, \
+             conjugation_id integer, \
+             asked_at datetime, \
+             succeeded integer DEFAULT 0, \
+             failed integer DEFAULT 0, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL, \
+             FOREIGN KEY (declension_id) REFERENCES declensions (id), \
+             FOREIGN KEY (conjugation_id) REFERENCES conjugations (id)",
+            "id, number, gender, \"case\", value, declension_id, kind, tense, mood, voice, \
+             person, conjugation_id, asked_at, succeeded, failed, created_at, updated_at",
+            "1",
+            "",
+        )?;
+    }
+    if !has_foreign_keys(conn, "word_relations")? {
+        rebuild_table(
+            conn,
+            "word_relations",
+            "id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             source_id integer, \
+             destination_id integer, \
+             kind integer, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL, \
+             FOREIGN KEY (source_id) REFERENCES words (id), \
+             FOREIGN KEY (destination_id) REFERENCES words (id)",
+            "id, source_id, destination_id, kind, created_at, updated_at",
+            "source_id IN (SELECT id FROM words) AND destination_id IN (SELECT id FROM words)",
+            "CREATE UNIQUE INDEX index_word_relations_on_source_id_and_destination_id \
+                 ON word_relations (source_id, destination_id);",
+        )?;
+    }
+    if !has_foreign_keys(conn, "tag_associations")? {
+        rebuild_table(
+            conn,
+            "tag_associations",
+            "id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             word_id integer NOT NULL, \
+             tag_id integer NOT NULL, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL, \
+             FOREIGN KEY (word_id) REFERENCES words (id), \
+             FOREIGN KEY (tag_id) REFERENCES tags (id)",
+            "id, word_id, tag_id, created_at, updated_at",
+            "word_id IN (SELECT id FROM words) AND tag_id IN (SELECT id FROM tags)",
+            "CREATE UNIQUE INDEX word_tag_unique ON tag_associations (word_id, tag_id);",
+        )?;
+    }
+    Ok(())
+}
+
+// Rows which only make sense alongside a word, an exercise or a tag are removed
+// with them. As with `add_foreign_keys`, the ones pointing to rows which no
+// longer exist are dropped along the way.
+fn add_cascades(conn: &Connection) -> Result<()> {
+    if !has_cascades(conn, "word_relations")? {
+        rebuild_table(
+            conn,
+            "word_relations",
+            "id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             source_id integer, \
+             destination_id integer, \
+             kind integer, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL, \
+             FOREIGN KEY (source_id) REFERENCES words (id) ON DELETE CASCADE, \
+             FOREIGN KEY (destination_id) REFERENCES words (id) ON DELETE CASCADE",
+            "id, source_id, destination_id, kind, created_at, updated_at",
+            "source_id IN (SELECT id FROM words) AND destination_id IN (SELECT id FROM words)",
+            "CREATE UNIQUE INDEX index_word_relations_on_source_id_and_destination_id \
+                 ON word_relations (source_id, destination_id);",
+        )?;
+    }
+    if !has_cascades(conn, "tag_associations")? {
+        rebuild_table(
+            conn,
+            "tag_associations",
+            "id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             word_id integer NOT NULL, \
+             tag_id integer NOT NULL, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL, \
+             FOREIGN KEY (word_id) REFERENCES words (id) ON DELETE CASCADE, \
+             FOREIGN KEY (tag_id) REFERENCES tags (id) ON DELETE CASCADE",
+            "id, word_id, tag_id, created_at, updated_at",
+            "word_id IN (SELECT id FROM words) AND tag_id IN (SELECT id FROM tags)",
+            "CREATE UNIQUE INDEX word_tag_unique ON tag_associations (word_id, tag_id);",
+        )?;
+    }
+    if !has_cascades(conn, "exercise_tag_associations")? {
+        rebuild_table(
+            conn,
+            "exercise_tag_associations",
+            "id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             exercise_id integer NOT NULL, \
+             tag_id integer NOT NULL, \
+             created_at datetime(6) NOT NULL, \
+             updated_at datetime(6) NOT NULL, \
+             FOREIGN KEY (exercise_id) REFERENCES exercises (id) ON DELETE CASCADE, \
+             FOREIGN KEY (tag_id) REFERENCES tags (id) ON DELETE CASCADE",
+            "id, exercise_id, tag_id, created_at, updated_at",
+            "exercise_id IN (SELECT id FROM exercises) AND tag_id IN (SELECT id FROM tags)",
+            "CREATE UNIQUE INDEX exercise_tag_unique \
+                 ON exercise_tag_associations (exercise_id, tag_id);",
+        )?;
+    }
+    if !has_cascades(conn, "exercise_words")? {
+        rebuild_table(
+            conn,
+            "exercise_words",
+            "id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             exercise_id integer NOT NULL, \
+             word_id integer NOT NULL, \
+             created_at datetime(6) NOT NULL, \
+             FOREIGN KEY (exercise_id) REFERENCES exercises (id) ON DELETE CASCADE, \
+             FOREIGN KEY (word_id) REFERENCES words (id) ON DELETE CASCADE",
+            "id, exercise_id, word_id, created_at",
+            "exercise_id IN (SELECT id FROM exercises) AND word_id IN (SELECT id FROM words)",
+            "CREATE UNIQUE INDEX exercise_word_unique ON exercise_words (exercise_id, word_id);",
+        )?;
+    }
+    if !has_cascades(conn, "exercise_attempts")? {
+        rebuild_table(
+            conn,
+            "exercise_attempts",
+            "id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             exercise_id integer NOT NULL, \
+             seconds integer NOT NULL, \
+             succeeded boolean DEFAULT 0 NOT NULL, \
+             created_at datetime(6) NOT NULL, \
+             FOREIGN KEY (exercise_id) REFERENCES exercises (id) ON DELETE CASCADE",
+            "id, exercise_id, seconds, succeeded, created_at",
+            "exercise_id IN (SELECT id FROM exercises)",
+            "CREATE INDEX index_exercise_attempts_on_exercise_id \
+                 ON exercise_attempts (exercise_id);",
+        )?;
+    }
+    if !has_cascades(conn, "notes")? {
+        rebuild_table(
+            conn,
+            "notes",
+            "id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             word_id integer, \
+             session integer NOT NULL, \
+             body text NOT NULL, \
+             created_at datetime(6) NOT NULL, \
+             FOREIGN KEY (word_id) REFERENCES words (id) ON DELETE CASCADE",
+            "id, word_id, session, body, created_at",
+            "word_id IS NULL OR word_id IN (SELECT id FROM words)",
+            "CREATE INDEX index_notes_on_word_id ON notes (word_id);",
+        )?;
+    }
+    Ok(())
+}
+
+// Same as `add_cascades`, but for drill attempts and session answers, which
+// were left out of it.
+fn add_attempt_and_answer_cascades(conn: &Connection) -> Result<()> {
+    if !has_cascades(conn, "drill_attempts")? {
+        rebuild_table(
+            conn,
+            "drill_attempts",
+            "id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             word_id integer NOT NULL, \
+             drill varchar NOT NULL, \
+             expected varchar NOT NULL, \
+             given varchar NOT NULL, \
+             succeeded boolean DEFAULT 0 NOT NULL, \
+             created_at datetime(6) NOT NULL, \
+             FOREIGN KEY (word_id) REFERENCES words (id) ON DELETE CASCADE",
+            "id, word_id, drill, expected, given, succeeded, created_at",
+            "word_id IN (SELECT id FROM words)",
+            "CREATE INDEX index_drill_attempts_on_drill ON drill_attempts (drill);",
+        )?;
+    }
+    if !has_cascades(conn, "session_answers")? {
+        rebuild_table(
+            conn,
+            "session_answers",
+            "id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             session_id integer NOT NULL, \
+             word_id integer NOT NULL, \
+             given varchar NOT NULL, \
+             succeeded boolean DEFAULT 0 NOT NULL, \
+             response_ms integer, \
+             created_at datetime(6) NOT NULL, \
+             FOREIGN KEY (session_id) REFERENCES sessions (id) ON DELETE CASCADE, \
+             FOREIGN KEY (word_id) REFERENCES words (id) ON DELETE CASCADE",
+            "id, session_id, word_id, given, succeeded, response_ms, created_at",
+            "session_id IN (SELECT id FROM sessions) AND word_id IN (SELECT id FROM words)",
+            "CREATE INDEX index_session_answers_on_session_id ON session_answers (session_id); \
+             CREATE INDEX index_session_answers_on_word_id ON session_answers (word_id);",
+        )?;
+    }
+    Ok(())
+}
+
+fn create_sync_points(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS sync_points ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             file varchar NOT NULL, \
+             synced_at datetime(6) NOT NULL);",
+    )
+}
+
+// Tables whose removals are recorded into the 'deletions' table, alongside the
+// column which identifies their rows between databases.
+const RECORDED_DELETIONS: [(&str, &str); 4] = [
+    ("words", "enunciated"),
+    ("exercises", "title"),
+    ("tags", "name"),
+    ("lessons", "name"),
+];
+
+// Rows are recorded as deleted when they are removed or renamed, and they stop
+// being so when they come back. This is done with triggers so no path that
+// removes rows can miss it.
+fn create_deletions(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS deletions ( \
+             id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+             kind varchar NOT NULL, \
+             name varchar NOT NULL, \
+             deleted_at datetime(6) NOT NULL); \
+         CREATE UNIQUE INDEX IF NOT EXISTS index_deletions_on_kind_and_name \
+             ON deletions (kind, name);",
+    )?;
+    for (table, key) in RECORDED_DELETIONS {
+        conn.execute_batch(
+            format!(
+                "CREATE TRIGGER IF NOT EXISTS record_{table}_deletion AFTER DELETE ON {table} \
+                 BEGIN \
+                     INSERT OR REPLACE INTO deletions (kind, name, deleted_at) \
+                         VALUES ('{table}', OLD.{key}, datetime('now')); \
+                 END; \
+                 CREATE TRIGGER IF NOT EXISTS record_{table}_rename \
+                     AFTER UPDATE OF {key} ON {table} WHEN OLD.{key} IS NOT NEW.{key} \
+                 BEGIN \
+                     INSERT OR REPLACE INTO deletions (kind, name, deleted_at) \
+                         VALUES ('{table}', OLD.{key}, datetime('now')); \
+                     DELETE FROM deletions WHERE kind = '{table}' AND name = NEW.{key}; \
+                 END; \
+                 CREATE TRIGGER IF NOT EXISTS clear_{table}_deletion AFTER INSERT ON {table} \
+                 BEGIN \
+                     DELETE FROM deletions WHERE kind = '{table}' AND name = NEW.{key}; \
+                 END;"
+            )
+            .as_str(),
+        )?;
+    }
+    Ok(())
+}
+
+// Columns from the 'forms' table but the ID.
+const FORM_COLUMNS: &str = "number, gender, \"case\", value, declension_id, kind, tense, mood, \
+                            voice, person, conjugation_id, created_at, updated_at";
+
+/// Adds the endings from the seed for the kinds of words which are missing on
+/// the given connection (e.g. 'domusdomus' or 'greekas' on databases seeded
+/// before these existed), alongside any missing declension or conjugation.
+/// Databases without any ending are left alone, as they are seeded whole.
+pub(crate) fn add_missing_endings(conn: &Connection) -> Result<()> {
+    let empty = conn.query_row("SELECT COUNT(*) = 0 FROM forms", [], |row| {
+        row.get::<usize, bool>(0)
+    })?;
+    if empty {
+        return Ok(());
+    }
+
+    // Temporary tables shadow the ones from the database, so the seed goes
+    // into them and only what is missing is copied over. The IDs from the
+    // seed may already be taken by other endings, so new ones are given.
+    conn.execute_batch(
+        "CREATE TEMP TABLE declensions AS SELECT * FROM main.declensions WHERE 0; \
+         CREATE TEMP TABLE conjugations AS SELECT * FROM main.conjugations WHERE 0; \
+         CREATE TEMP TABLE forms AS SELECT * FROM main.forms WHERE 0;",
+    )?;
+    conn.execute_batch(crate::INFLECTION_DATA)?;
+    conn.execute_batch(
+        format!(
+            "INSERT OR IGNORE INTO main.declensions SELECT * FROM temp.declensions \
+                 WHERE title NOT IN (SELECT title FROM main.declensions); \
+             INSERT OR IGNORE INTO main.conjugations SELECT * FROM temp.conjugations \
+                 WHERE title NOT IN (SELECT title FROM main.conjugations); \
+             INSERT INTO main.forms ({FORM_COLUMNS}) \
+                 SELECT {FORM_COLUMNS} FROM temp.forms \
+                 WHERE kind NOT IN (SELECT kind FROM main.forms WHERE kind IS NOT NULL) \
+                 ORDER BY id; \
+             DROP TABLE temp.declensions; \
+             DROP TABLE temp.conjugations; \
+             DROP TABLE temp.forms;"
+        )
+        .as_str(),
+    )
+}
+
+fn add_pending_session_flags(conn: &Connection) -> Result<()> {
+    add_column(
+        conn,
+        "pending_sessions",
+        "flags",
+        "text DEFAULT '' NOT NULL",
+    )?;
+    Ok(())
+}
+
+/// Applies on the given connection all the migrations which have not been
+/// applied yet, each of them on its own transaction. Returns the number of
+/// migrations that were applied.
+pub fn migrate(conn: &Connection) -> std::result::Result<usize, String> {
+    let current = match conn
+        .execute(
+            "CREATE TABLE IF NOT EXISTS schema_version ( \
+                 version integer PRIMARY KEY NOT NULL, \
+                 applied_at datetime(6) NOT NULL)",
+            [],
+        )
+        .and_then(|_| {
+            conn.query_row(
+                "SELECT IFNULL(MAX(version), 0) FROM schema_version",
+                [],
+                |row| row.get::<usize, i64>(0),
+            )
+        }) {
+        Ok(version) => version as usize,
+        Err(e) => return Err(format!("could not read the version of the database: {e}")),
+    };
+
+    let mut applied = 0;
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        let tx = match conn.unchecked_transaction() {
+            Ok(tx) => tx,
+            Err(e) => return Err(format!("could not migrate the database: {e}")),
+        };
+        if let Err(e) = (migration.apply)(&tx).and_then(|_| {
+            tx.execute(
+                "INSERT INTO schema_version (version, applied_at) VALUES (?1, datetime('now'))",
+                [migration.version as i64],
+            )
+        }) {
+            return Err(format!(
+                "could not {} (migration {}): {e}",
+                migration.description, migration.version
+            ));
+        }
+        if let Err(e) = tx.commit() {
+            return Err(format!("could not migrate the database: {e}"));
+        }
+        applied += 1;
+    }
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tables whose rows are removed alongside the rows they point to.
+    const CASCADING_TABLES: [&str; 8] = [
+        "word_relations",
+        "tag_associations",
+        "exercise_tag_associations",
+        "exercise_words",
+        "exercise_attempts",
+        "notes",
+        "drill_attempts",
+        "session_answers",
+    ];
+
+    // Returns true if the given connection has all the migrations applied.
+    fn is_up_to_date(conn: &Connection) -> bool {
+        let version = conn
+            .query_row("SELECT MAX(version) FROM schema_version", [], |row| {
+                row.get::<usize, i64>(0)
+            })
+            .unwrap() as usize;
+        version == MIGRATIONS.last().unwrap().version
+    }
+
+    #[test]
+    fn test_versions_are_sorted() {
+        for pair in MIGRATIONS.windows(2) {
+            assert!(pair[0].version < pair[1].version);
+        }
+    }
+
+    #[test]
+    fn test_migrate_old_database() {
+        // Databases from the first versions only had words and exercises.
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE words (id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+                                 enunciated varchar, succeeded integer, \
+                                 created_at datetime(6) NOT NULL, updated_at datetime(6) NOT NULL); \
+             CREATE TABLE exercises (id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+                                     title varchar NOT NULL, \
+                                     created_at datetime(6) NOT NULL, \
+                                     updated_at datetime(6) NOT NULL); \
+             INSERT INTO exercises (title, created_at, updated_at) \
+                 VALUES ('Rose', datetime('now'), datetime('now')); \
+             CREATE TABLE tags (id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+                                name varchar NOT NULL, \
+                                created_at datetime(6) NOT NULL, \
+                                updated_at datetime(6) NOT NULL); \
+             CREATE TABLE tag_associations (id integer PRIMARY KEY AUTOINCREMENT NOT NULL, \
+                                            word_id integer NOT NULL, tag_id integer NOT NULL, \
+                                            created_at datetime(6) NOT NULL, \
+                                            updated_at datetime(6) NOT NULL); \
+             INSERT INTO words (enunciated, created_at, updated_at) \
+                 VALUES ('rosa, rosae', datetime('now'), datetime('now')); \
+             INSERT INTO tags (name, created_at, updated_at) \
+                 VALUES ('llpsi', datetime('now'), datetime('now')); \
+             INSERT INTO tag_associations (word_id, tag_id, created_at, updated_at) \
+                 VALUES (1, 1, datetime('now'), datetime('now')), \
+                        (2, 1, datetime('now'), datetime('now'));",
+        )
+        .unwrap();
+
+        assert_eq!(migrate(&conn).unwrap(), MIGRATIONS.len());
+        assert!(is_up_to_date(&conn));
+        for table in [
+            "forms",
+            "tags",
+            "tag_associations",
+            "word_relations",
+            "lessons",
+        ] {
+            assert!(has_column(&conn, table, "id").unwrap(), "{table}");
+        }
+        assert!(has_column(&conn, "words", "due_at").unwrap());
+        assert!(has_column(&conn, "exercises", "due_at").unwrap());
+        let attempts: i64 = conn
+            .query_row("SELECT attempts FROM exercises", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(attempts, 0);
+
+        // The tables which were already there got their foreign keys, and
+        // associations to words that do not exist were dropped.
+        for table in ["forms", "word_relations", "tag_associations"] {
+            assert!(has_foreign_keys(&conn, table).unwrap(), "{table}");
+        }
+        for table in CASCADING_TABLES {
+            assert!(has_cascades(&conn, table).unwrap(), "{table}");
+        }
+        let associations: i64 = conn
+            .query_row("SELECT COUNT(*) FROM tag_associations", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(associations, 1);
+
+        // Removals are recorded from now on, and they are forgotten once the
+        // row comes back.
+        conn.execute("DELETE FROM exercises WHERE title = 'Rose'", [])
+            .unwrap();
+        let deletions = |conn: &Connection| {
+            conn.query_row(
+                "SELECT COUNT(*) FROM deletions WHERE kind = 'exercises' AND name = 'Rose'",
+                [],
+                |row| row.get::<usize, i64>(0),
+            )
+            .unwrap()
+        };
+        assert_eq!(deletions(&conn), 1);
+        conn.execute(
+            "INSERT INTO exercises (title, created_at, updated_at) \
+             VALUES ('Rose', datetime('now'), datetime('now'))",
+            [],
+        )
+        .unwrap();
+        assert_eq!(deletions(&conn), 0);
+
+        // Migrating again does nothing.
+        assert_eq!(migrate(&conn).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_add_missing_endings() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(crate::SCHEMA).unwrap();
+        conn.execute_batch(crate::INFLECTION_DATA).unwrap();
+        let count = |query: &str| {
+            conn.query_row(query, [], |row| row.get::<usize, i64>(0))
+                .unwrap()
+        };
+        let seeded = count("SELECT COUNT(*) FROM forms");
+
+        // Databases seeded before domus and Greek nouns were supported get
+        // their endings, and nothing else is duplicated.
+        conn.execute(
+            "DELETE FROM forms WHERE kind = 'domusdomus' OR kind LIKE 'greek%'",
+            [],
+        )
+        .unwrap();
+        add_missing_endings(&conn).unwrap();
+        assert_eq!(count("SELECT COUNT(*) FROM forms"), seeded);
+        assert_eq!(
+            count("SELECT COUNT(*) FROM forms WHERE kind = 'domusdomus'"),
+            18
+        );
+        add_missing_endings(&conn).unwrap();
+        assert_eq!(count("SELECT COUNT(*) FROM forms"), seeded);
+
+        // Databases without endings are left for the seed.
+        conn.execute("DELETE FROM forms", []).unwrap();
+        add_missing_endings(&conn).unwrap();
+        assert_eq!(count("SELECT COUNT(*) FROM forms"), 0);
+    }
+
+    #[test]
+    fn test_migrate_new_database() {
+        // Brand new databases already have the latest schema, so migrations
+        // are only recorded.
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(crate::SCHEMA).unwrap();
+        assert_eq!(migrate(&conn).unwrap(), MIGRATIONS.len());
+        assert!(is_up_to_date(&conn));
+        for table in ["forms", "word_relations", "tag_associations"] {
+            assert!(has_foreign_keys(&conn, table).unwrap(), "{table}");
+        }
+        for table in CASCADING_TABLES {
+            assert!(has_cascades(&conn, table).unwrap(), "{table}");
+        }
+
+        // The schema already has the triggers which record removals.
+        let triggers: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'trigger'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(triggers, 3 * RECORDED_DELETIONS.len() as i64);
+    }
+}
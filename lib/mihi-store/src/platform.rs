@@ -0,0 +1,149 @@
+use std::path::PathBuf;
+
+/// Operating systems with their own conventions on where applications store
+/// their configuration and their data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Platform {
+    Windows,
+    MacOs,
+    Other,
+}
+
+impl Platform {
+    /// Returns the operating system this was built for.
+    pub fn current() -> Self {
+        if cfg!(target_os = "windows") {
+            Self::Windows
+        } else if cfg!(target_os = "macos") {
+            Self::MacOs
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// Looks up environment variables, so paths can also be computed for an
+/// environment other than the one of the current process (e.g. on tests).
+pub type Env<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// Returns the value of the given environment variable for the current
+/// process, if it's set.
+pub fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+/// Returns the directory where applications are expected to store their
+/// configuration on the given `platform`: '%APPDATA%' on Windows,
+/// '~/Library/Application Support' on macOS and '~/.config' everywhere else.
+pub fn config_home(platform: Platform, env: Env) -> Result<PathBuf, String> {
+    match platform {
+        Platform::Windows => match env("APPDATA") {
+            Some(path) => Ok(PathBuf::from(path)),
+            None => Ok(home(platform, env)?.join("AppData").join("Roaming")),
+        },
+        Platform::MacOs => Ok(home(platform, env)?
+            .join("Library")
+            .join("Application Support")),
+        Platform::Other => Ok(home(platform, env)?.join(".config")),
+    }
+}
+
+/// Returns the directory where applications are expected to store their data
+/// on the given `platform`: '%LOCALAPPDATA%' on Windows,
+/// '~/Library/Application Support' on macOS and '~/.local/share' everywhere
+/// else.
+pub fn data_home(platform: Platform, env: Env) -> Result<PathBuf, String> {
+    match platform {
+        Platform::Windows => match env("LOCALAPPDATA") {
+            Some(path) => Ok(PathBuf::from(path)),
+            None => Ok(home(platform, env)?.join("AppData").join("Local")),
+        },
+        Platform::MacOs => Ok(home(platform, env)?
+            .join("Library")
+            .join("Application Support")),
+        Platform::Other => Ok(home(platform, env)?.join(".local").join("share")),
+    }
+}
+
+// Returns the home directory of the current user on the given `platform`.
+fn home(platform: Platform, env: Env) -> Result<PathBuf, String> {
+    let var = match platform {
+        Platform::Windows => "USERPROFILE",
+        _ => "HOME",
+    };
+
+    match env(var) {
+        Some(path) => Ok(PathBuf::from(path)),
+        None => Err(String::from(
+            "cannot find a suitable path for the configuration and the data",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::path::Path;
+
+    // Returns an environment with only the given `vars` set.
+    fn env_with(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_unix_like() {
+        let env = env_with(&[("HOME", "/home/marcus")]);
+        assert_eq!(
+            config_home(Platform::Other, &env).unwrap(),
+            Path::new("/home/marcus/.config")
+        );
+        assert_eq!(
+            data_home(Platform::Other, &env).unwrap(),
+            Path::new("/home/marcus/.local/share")
+        );
+
+        let support = Path::new("/home/marcus/Library/Application Support");
+        assert_eq!(config_home(Platform::MacOs, &env).unwrap(), support);
+        assert_eq!(data_home(Platform::MacOs, &env).unwrap(), support);
+
+        let env = env_with(&[]);
+        assert!(config_home(Platform::Other, &env).is_err());
+        assert!(data_home(Platform::MacOs, &env).is_err());
+    }
+
+    #[test]
+    fn test_windows() {
+        let env = env_with(&[
+            ("APPDATA", "C:/Users/marcus/Roaming"),
+            ("LOCALAPPDATA", "C:/Users/marcus/Local"),
+            ("HOME", "/home/marcus"),
+        ]);
+        assert_eq!(
+            config_home(Platform::Windows, &env).unwrap(),
+            Path::new("C:/Users/marcus/Roaming")
+        );
+        assert_eq!(
+            data_home(Platform::Windows, &env).unwrap(),
+            Path::new("C:/Users/marcus/Local")
+        );
+
+        // Without the variables for application data, they are taken from the
+        // profile of the user, and 'HOME' is never looked at.
+        let env = env_with(&[("USERPROFILE", "C:/Users/marcus")]);
+        assert_eq!(
+            config_home(Platform::Windows, &env).unwrap(),
+            Path::new("C:/Users/marcus").join("AppData").join("Roaming")
+        );
+        assert_eq!(
+            data_home(Platform::Windows, &env).unwrap(),
+            Path::new("C:/Users/marcus").join("AppData").join("Local")
+        );
+        let env = env_with(&[("HOME", "/home/marcus")]);
+        assert!(config_home(Platform::Windows, &env).is_err());
+    }
+}
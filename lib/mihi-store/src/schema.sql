@@ -0,0 +1,250 @@
+CREATE TABLE IF NOT EXISTS "declensions" ("id" integer PRIMARY KEY AUTOINCREMENT NOT NULL, "title" varchar, "order" integer, "language_id" integer, "created_at" datetime(6) NOT NULL, "updated_at" datetime(6) NOT NULL);
+CREATE UNIQUE INDEX "index_declensions_on_title" ON "declensions" ("title");
+CREATE UNIQUE INDEX "index_declensions_on_order" ON "declensions" ("order");
+CREATE TABLE IF NOT EXISTS "forms" ("id" integer PRIMARY KEY AUTOINCREMENT NOT NULL, "number" integer, "gender" integer, "case" integer, "value" varchar, "declension_id" integer, "kind" varchar, "tense" integer, "mood" integer, "voice" integer, "person" integer, "conjugation_id" integer, "asked_at" datetime, "succeeded" integer DEFAULT 0, "failed" integer DEFAULT 0, "created_at" datetime(6) NOT NULL, "updated_at" datetime(6) NOT NULL, FOREIGN KEY ("declension_id") REFERENCES "declensions" ("id"), FOREIGN KEY ("conjugation_id") REFERENCES "conjugations" ("id"));
+CREATE TABLE IF NOT EXISTS "conjugations" ("id" integer PRIMARY KEY AUTOINCREMENT NOT NULL, "order" integer, "language_id" integer, "title" varchar, "regular" boolean DEFAULT 1, "created_at" datetime(6) NOT NULL, "updated_at" datetime(6) NOT NULL);
+CREATE UNIQUE INDEX "index_conjugations_on_title" ON "conjugations" ("title");
+CREATE UNIQUE INDEX "index_conjugations_on_order" ON "conjugations" ("order");
+CREATE TABLE IF NOT EXISTS "word_relations" ("id" integer PRIMARY KEY AUTOINCREMENT NOT NULL, "source_id" integer, "destination_id" integer, "kind" integer, "created_at" datetime(6) NOT NULL, "updated_at" datetime(6) NOT NULL, FOREIGN KEY ("source_id") REFERENCES "words" ("id") ON DELETE CASCADE, FOREIGN KEY ("destination_id") REFERENCES "words" ("id") ON DELETE CASCADE);
+CREATE UNIQUE INDEX "index_word_relations_on_source_id_and_destination_id" ON "word_relations" ("source_id", "destination_id");
+CREATE TABLE IF NOT EXISTS "words" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "particle" varchar,
+       "enunciated" varchar,
+       "declension_id" integer,
+       "conjugation_id" integer,
+       "kind" varchar,
+       "category" integer,
+       "regular" boolean DEFAULT 1,
+       "locative" boolean DEFAULT 0,
+       "gender" integer,
+       "created_at" datetime(6) NOT NULL,
+       "updated_at" datetime(6) NOT NULL,
+       "suffix" varchar,
+       "language_id" integer,
+       "succeeded" integer,
+       "steps" integer DEFAULT 0 NOT NULL,
+       "translation" jsonb DEFAULT '{}',
+       "pending" boolean DEFAULT 0,
+       "flags" jsonb DEFAULT '{}',
+       "weight" integer DEFAULT 0 NOT NULL,
+       "occurrences" integer DEFAULT 0 NOT NULL,
+       "ease" real DEFAULT 2.5 NOT NULL,
+       "interval_days" integer DEFAULT 0 NOT NULL,
+       "due_at" datetime,
+       "box" integer DEFAULT 1 NOT NULL,
+       "suspended" boolean DEFAULT 0 NOT NULL,
+       "last_asked_at" datetime,
+       "lesson_id" integer,
+
+       CHECK (weight >= 0 AND weight <= 10),
+
+       FOREIGN KEY ("conjugation_id") REFERENCES "conjugations" ("id"),
+       FOREIGN KEY ("declension_id") REFERENCES "declensions" ("id"),
+       FOREIGN KEY ("lesson_id") REFERENCES "lessons" ("id")
+);
+CREATE UNIQUE INDEX "index_words_on_enunciated" ON "words" ("enunciated");
+CREATE TABLE IF NOT EXISTS "exercises" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "title" varchar NOT NULL,
+       "enunciate" text NOT NULL,
+       "solution" text NOT NULL,
+       "lessons" text NOT NULL,
+       "kind" integer DEFAULT 0,
+       "minutes" integer DEFAULT 5 NOT NULL,
+       "score" integer,
+       "attempts" integer DEFAULT 0 NOT NULL,
+       "last_attempted_at" datetime,
+       "ease" real DEFAULT 2.5 NOT NULL,
+       "interval_days" integer DEFAULT 0 NOT NULL,
+       "due_at" datetime,
+       "lesson_id" integer,
+       "created_at" datetime(6) NOT NULL,
+       "updated_at" datetime(6) NOT NULL,
+
+       FOREIGN KEY ("lesson_id") REFERENCES "lessons" ("id")
+);
+CREATE UNIQUE INDEX "index_exercises_on_title" ON "exercises" ("title");
+CREATE TABLE IF NOT EXISTS "lessons" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "name" varchar NOT NULL,
+       "position" integer NOT NULL,
+       "source" varchar,
+       "created_at" datetime(6) NOT NULL,
+       "updated_at" datetime(6) NOT NULL
+);
+CREATE UNIQUE INDEX "index_lessons_on_name" ON "lessons" ("name");
+CREATE TABLE IF NOT EXISTS "exercise_attempts" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "exercise_id" integer NOT NULL,
+       "seconds" integer NOT NULL,
+       "succeeded" boolean DEFAULT 0 NOT NULL,
+       "created_at" datetime(6) NOT NULL,
+
+       FOREIGN KEY ("exercise_id") REFERENCES "exercises" ("id") ON DELETE CASCADE
+);
+CREATE INDEX "index_exercise_attempts_on_exercise_id" ON "exercise_attempts" ("exercise_id");
+CREATE TABLE IF NOT EXISTS "exercise_words" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "exercise_id" integer NOT NULL,
+       "word_id" integer NOT NULL,
+       "created_at" datetime(6) NOT NULL,
+
+       FOREIGN KEY ("exercise_id") REFERENCES "exercises" ("id") ON DELETE CASCADE,
+       FOREIGN KEY ("word_id") REFERENCES "words" ("id") ON DELETE CASCADE
+);
+CREATE UNIQUE INDEX "exercise_word_unique" ON exercise_words (exercise_id, word_id);
+CREATE TABLE IF NOT EXISTS "tags" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "name" varchar NOT NULL,
+       "created_at" datetime(6) NOT NULL,
+       "updated_at" datetime(6) NOT NULL,
+       "parent_id" integer,
+       "description" text,
+       "color" varchar,
+
+       FOREIGN KEY ("parent_id") REFERENCES "tags" ("id")
+);
+CREATE UNIQUE INDEX "index_tags_on_name" ON "tags" ("name");
+CREATE TABLE IF NOT EXISTS "tag_associations" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "word_id" integer NOT NULL,
+       "tag_id" integer NOT NULL,
+       "created_at" datetime(6) NOT NULL,
+       "updated_at" datetime(6) NOT NULL,
+
+       FOREIGN KEY ("word_id") REFERENCES "words" ("id") ON DELETE CASCADE,
+       FOREIGN KEY ("tag_id") REFERENCES "tags" ("id") ON DELETE CASCADE
+);
+CREATE UNIQUE INDEX "word_tag_unique" ON tag_associations (word_id, tag_id);
+CREATE TABLE IF NOT EXISTS "exercise_tag_associations" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "exercise_id" integer NOT NULL,
+       "tag_id" integer NOT NULL,
+       "created_at" datetime(6) NOT NULL,
+       "updated_at" datetime(6) NOT NULL,
+
+       FOREIGN KEY ("exercise_id") REFERENCES "exercises" ("id") ON DELETE CASCADE,
+       FOREIGN KEY ("tag_id") REFERENCES "tags" ("id") ON DELETE CASCADE
+);
+CREATE UNIQUE INDEX "exercise_tag_unique" ON exercise_tag_associations (exercise_id, tag_id);
+CREATE TABLE IF NOT EXISTS "notes" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "word_id" integer,
+       "session" integer NOT NULL,
+       "body" text NOT NULL,
+       "created_at" datetime(6) NOT NULL,
+
+       FOREIGN KEY ("word_id") REFERENCES "words" ("id") ON DELETE CASCADE
+);
+CREATE INDEX "index_notes_on_word_id" ON "notes" ("word_id");
+CREATE TABLE IF NOT EXISTS "exams" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "tags" text NOT NULL,
+       "questions" integer NOT NULL,
+       "score" real NOT NULL,
+       "breakdown" jsonb DEFAULT '[]',
+       "created_at" datetime(6) NOT NULL
+);
+CREATE TABLE IF NOT EXISTS "drill_attempts" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "word_id" integer NOT NULL,
+       "drill" varchar NOT NULL,
+       "expected" varchar NOT NULL,
+       "given" varchar NOT NULL,
+       "succeeded" boolean DEFAULT 0 NOT NULL,
+       "created_at" datetime(6) NOT NULL,
+
+       FOREIGN KEY ("word_id") REFERENCES "words" ("id") ON DELETE CASCADE
+);
+CREATE INDEX "index_drill_attempts_on_drill" ON "drill_attempts" ("drill");
+CREATE TABLE IF NOT EXISTS "sessions" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "kind" varchar NOT NULL,
+       "asked" integer NOT NULL,
+       "correct" integer NOT NULL,
+       "category" varchar,
+       "tags" text DEFAULT '' NOT NULL,
+       "time_limit" integer,
+       "started_at" datetime(6) NOT NULL,
+       "finished_at" datetime(6) NOT NULL
+);
+CREATE TABLE IF NOT EXISTS "session_answers" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "session_id" integer NOT NULL,
+       "word_id" integer NOT NULL,
+       "given" varchar NOT NULL,
+       "succeeded" boolean DEFAULT 0 NOT NULL,
+       "response_ms" integer,
+       "created_at" datetime(6) NOT NULL,
+
+       FOREIGN KEY ("session_id") REFERENCES "sessions" ("id") ON DELETE CASCADE,
+       FOREIGN KEY ("word_id") REFERENCES "words" ("id") ON DELETE CASCADE
+);
+CREATE INDEX "index_session_answers_on_session_id" ON "session_answers" ("session_id");
+CREATE INDEX "index_session_answers_on_word_id" ON "session_answers" ("word_id");
+CREATE TABLE IF NOT EXISTS "pending_sessions" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "kind" varchar NOT NULL,
+       "category" integer,
+       "flags" text DEFAULT '' NOT NULL,
+       "tags" text DEFAULT '' NOT NULL,
+       "word_ids" text NOT NULL,
+       "created_at" datetime(6) NOT NULL
+);
+CREATE TABLE IF NOT EXISTS "sync_points" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "file" varchar NOT NULL,
+       "synced_at" datetime(6) NOT NULL
+);
+CREATE TABLE IF NOT EXISTS "deletions" (
+       "id" integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+       "kind" varchar NOT NULL,
+       "name" varchar NOT NULL,
+       "deleted_at" datetime(6) NOT NULL
+);
+CREATE UNIQUE INDEX "index_deletions_on_kind_and_name" ON "deletions" ("kind", "name");
+CREATE TRIGGER "record_words_deletion" AFTER DELETE ON "words" BEGIN
+       INSERT OR REPLACE INTO "deletions" ("kind", "name", "deleted_at") VALUES ('words', OLD."enunciated", datetime('now'));
+END;
+CREATE TRIGGER "record_words_rename" AFTER UPDATE OF "enunciated" ON "words" WHEN OLD."enunciated" IS NOT NEW."enunciated" BEGIN
+       INSERT OR REPLACE INTO "deletions" ("kind", "name", "deleted_at") VALUES ('words', OLD."enunciated", datetime('now'));
+       DELETE FROM "deletions" WHERE "kind" = 'words' AND "name" = NEW."enunciated";
+END;
+CREATE TRIGGER "clear_words_deletion" AFTER INSERT ON "words" BEGIN
+       DELETE FROM "deletions" WHERE "kind" = 'words' AND "name" = NEW."enunciated";
+END;
+CREATE TRIGGER "record_exercises_deletion" AFTER DELETE ON "exercises" BEGIN
+       INSERT OR REPLACE INTO "deletions" ("kind", "name", "deleted_at") VALUES ('exercises', OLD."title", datetime('now'));
+END;
+CREATE TRIGGER "record_exercises_rename" AFTER UPDATE OF "title" ON "exercises" WHEN OLD."title" IS NOT NEW."title" BEGIN
+       INSERT OR REPLACE INTO "deletions" ("kind", "name", "deleted_at") VALUES ('exercises', OLD."title", datetime('now'));
+       DELETE FROM "deletions" WHERE "kind" = 'exercises' AND "name" = NEW."title";
+END;
+CREATE TRIGGER "clear_exercises_deletion" AFTER INSERT ON "exercises" BEGIN
+       DELETE FROM "deletions" WHERE "kind" = 'exercises' AND "name" = NEW."title";
+END;
+CREATE TRIGGER "record_tags_deletion" AFTER DELETE ON "tags" BEGIN
+       INSERT OR REPLACE INTO "deletions" ("kind", "name", "deleted_at") VALUES ('tags', OLD."name", datetime('now'));
+END;
+CREATE TRIGGER "record_tags_rename" AFTER UPDATE OF "name" ON "tags" WHEN OLD."name" IS NOT NEW."name" BEGIN
+       INSERT OR REPLACE INTO "deletions" ("kind", "name", "deleted_at") VALUES ('tags', OLD."name", datetime('now'));
+       DELETE FROM "deletions" WHERE "kind" = 'tags' AND "name" = NEW."name";
+END;
+CREATE TRIGGER "clear_tags_deletion" AFTER INSERT ON "tags" BEGIN
+       DELETE FROM "deletions" WHERE "kind" = 'tags' AND "name" = NEW."name";
+END;
+CREATE TRIGGER "record_lessons_deletion" AFTER DELETE ON "lessons" BEGIN
+       INSERT OR REPLACE INTO "deletions" ("kind", "name", "deleted_at") VALUES ('lessons', OLD."name", datetime('now'));
+END;
+CREATE TRIGGER "record_lessons_rename" AFTER UPDATE OF "name" ON "lessons" WHEN OLD."name" IS NOT NEW."name" BEGIN
+       INSERT OR REPLACE INTO "deletions" ("kind", "name", "deleted_at") VALUES ('lessons', OLD."name", datetime('now'));
+       DELETE FROM "deletions" WHERE "kind" = 'lessons' AND "name" = NEW."name";
+END;
+CREATE TRIGGER "clear_lessons_deletion" AFTER INSERT ON "lessons" BEGIN
+       DELETE FROM "deletions" WHERE "kind" = 'lessons' AND "name" = NEW."name";
+END;
+CREATE TABLE IF NOT EXISTS "schema_version" (
+       "version" integer PRIMARY KEY NOT NULL,
+       "applied_at" datetime(6) NOT NULL
+);
@@ -0,0 +1,3115 @@
+-- Canonical Latin endings, together with the declensions and conjugations they
+-- belong to. Words are inflected out of these, so they are inserted into every
+-- new database.
+
+INSERT OR IGNORE INTO "declensions" (id, title, "order", language_id, created_at, updated_at) VALUES
+(1, 'declensions.latin.first', 1, 1, '2025-07-28 10:19:00.676100', '2025-07-28 10:19:00.676100'),
+(2, 'declensions.latin.second', 2, 1, '2025-07-28 10:19:01.058001', '2025-07-28 10:19:01.058001'),
+(3, 'declensions.latin.third', 3, 1, '2025-07-28 10:19:01.249191', '2025-07-28 10:19:01.249191'),
+(4, 'declensions.latin.fourth', 4, 1, '2025-07-28 10:19:02.444593', '2025-07-28 10:19:02.444593'),
+(5, 'declensions.latin.fifth', 5, 1, '2025-07-28 10:19:02.564499', '2025-07-28 10:19:02.564499'),
+(6, 'declensions.indeclinable', 6, 1, '2025-07-28 10:19:02.718923', '2025-07-28 10:19:02.718923'),
+(7, 'declensions.latin.interrogative', 10, 1, '2025-07-28 10:19:08.163051', '2025-07-28 10:19:08.163051'),
+(8, 'declensions.latin.relative', 11, 1, '2025-07-28 10:19:08.243201', '2025-07-28 10:19:08.243201'),
+(9, 'grammar.firstperson', 12, 1, '2025-07-28 10:19:08.330969', '2025-07-28 10:19:08.330969'),
+(10, 'declensions.latin.demonstratives.weak', 13, 1, '2025-07-28 10:19:08.370019', '2025-07-28 10:19:08.370019'),
+(11, 'declensions.latin.demonstratives.proximal', 14, 1, '2025-07-28 10:19:08.462018', '2025-07-28 10:19:08.462018'),
+(12, 'declensions.latin.demonstratives.distal', 15, 1, '2025-07-28 10:19:08.541491', '2025-07-28 10:19:08.541491'),
+(13, 'declensions.latin.demonstratives.medial', 16, 1, '2025-07-28 10:19:08.644994', '2025-07-28 10:19:08.644994'),
+(14, 'declensions.latin.demonstratives.idem', 17, 1, '2025-07-28 10:19:08.744652', '2025-07-28 10:19:08.744652'),
+(15, 'declensions.latin.intensive', 18, 1, '2025-07-28 10:19:08.827753', '2025-07-28 10:19:08.827753'),
+(16, 'grammar.secondperson', 19, 1, '2025-07-28 10:19:08.927603', '2025-07-28 10:19:08.927603'),
+(17, 'grammar.thirdperson', 20, 1, '2025-07-28 10:19:08.960604', '2025-07-28 10:19:08.960604'),
+(18, 'grammar.firstpossessivesingular', 21, 1, '2025-07-28 10:19:08.993712', '2025-07-28 10:19:08.993712'),
+(19, 'grammar.secondpossessivesingular', 22, 1, '2025-07-28 10:19:09.104290', '2025-07-28 10:19:09.104290'),
+(20, 'grammar.thirdpossessivesingular', 23, 1, '2025-07-28 10:19:09.212565', '2025-07-28 10:19:09.212565'),
+(21, 'grammar.firstpossessiveplural', 24, 1, '2025-07-28 10:19:09.326389', '2025-07-28 10:19:09.326389'),
+(22, 'grammar.secondpossessiveplural', 25, 1, '2025-07-28 10:19:09.530183', '2025-07-28 10:19:09.530183');
+
+INSERT OR IGNORE INTO "conjugations" (id, "order", language_id, title, regular, created_at, updated_at) VALUES
+(1, 1, 1, 'conjugations.latin.first', 1, '2025-07-28 10:19:02.759902', '2025-07-28 10:19:02.759902'),
+(2, 2, 1, 'conjugations.latin.second', 1, '2025-07-28 10:19:03.205684', '2025-07-28 10:19:03.205684'),
+(3, 3, 1, 'conjugations.latin.third', 1, '2025-07-28 10:19:03.713983', '2025-07-28 10:19:03.713983'),
+(4, 4, 1, 'conjugations.latin.thirdhybrid', 1, '2025-07-28 10:19:04.137487', '2025-07-28 10:19:04.137487'),
+(5, 5, 1, 'conjugations.latin.fourth', 1, '2025-07-28 10:19:04.574555', '2025-07-28 10:19:04.574555'),
+(6, 10, 1, 'conjugations.latin.sum', 0, '2025-07-28 10:19:05.094491', '2025-07-28 10:19:05.094491'),
+(7, 11, 1, 'conjugations.latin.possum', 0, '2025-07-28 10:19:05.295257', '2025-07-28 10:19:05.295257'),
+(8, 12, 1, 'conjugations.latin.eo', 0, '2025-07-28 10:19:05.476926', '2025-07-28 10:19:05.476926'),
+(9, 13, 1, 'conjugations.latin.volo', 0, '2025-07-28 10:19:05.914109', '2025-07-28 10:19:05.914109'),
+(10, 14, 1, 'conjugations.latin.nolo', 0, '2025-07-28 10:19:06.178034', '2025-07-28 10:19:06.178034'),
+(11, 15, 1, 'conjugations.latin.malo', 0, '2025-07-28 10:19:06.370593', '2025-07-28 10:19:06.370593'),
+(12, 16, 1, 'conjugations.latin.fero', 0, '2025-07-28 10:19:06.594087', '2025-07-28 10:19:06.594087'),
+(13, 17, 1, 'conjugations.latin.facio', 0, '2025-07-28 10:19:07.024654', '2025-07-28 10:19:07.024654'),
+(14, 18, 1, 'conjugations.latin.do', 0, '2025-07-28 10:19:07.464545', '2025-07-28 10:19:07.464545'),
+(15, 19, 1, 'conjugations.latin.inquam', 0, '2025-07-28 10:19:07.968642', '2025-07-28 10:19:07.968642'),
+(16, 20, 1, 'conjugations.latin.aio', 0, '2025-07-28 10:19:08.078865', '2025-07-28 10:19:08.078865');
+
+INSERT OR IGNORE INTO "forms" (id, number, gender, "case", value, declension_id, kind, tense, mood, voice, person, conjugation_id, created_at, updated_at) VALUES
+(1, 0, 0, 0, 'a', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.702986', '2025-07-28 10:19:00.702986'),
+(2, 1, 0, 0, 'ae', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.716001', '2025-07-28 10:19:00.716001'),
+(3, 0, 1, 0, 'a', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.726081', '2025-07-28 10:19:00.726081'),
+(4, 1, 1, 0, 'ae', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.731842', '2025-07-28 10:19:00.731842'),
+(5, 0, 0, 1, 'a', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.743287', '2025-07-28 10:19:00.743287'),
+(6, 1, 0, 1, 'ae', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.748255', '2025-07-28 10:19:00.748255'),
+(7, 0, 1, 1, 'a', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.755204', '2025-07-28 10:19:00.755204'),
+(8, 1, 1, 1, 'ae', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.760414', '2025-07-28 10:19:00.760414'),
+(9, 0, 0, 2, 'am', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.763987', '2025-07-28 10:19:00.763987'),
+(10, 1, 0, 2, 'ās', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.767049', '2025-07-28 10:19:00.767049'),
+(11, 0, 1, 2, 'am', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.770098', '2025-07-28 10:19:00.770098'),
+(12, 1, 1, 2, 'ās', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.777323', '2025-07-28 10:19:00.777323'),
+(13, 0, 0, 3, 'ae', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.785262', '2025-07-28 10:19:00.785262'),
+(14, 1, 0, 3, 'ārum', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.790242', '2025-07-28 10:19:00.790242'),
+(15, 0, 1, 3, 'ae', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.794228', '2025-07-28 10:19:00.794228'),
+(16, 1, 1, 3, 'ārum', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.797069', '2025-07-28 10:19:00.797069'),
+(17, 0, 0, 4, 'ae', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.800084', '2025-07-28 10:19:00.800084'),
+(18, 1, 0, 4, 'īs', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.802483', '2025-07-28 10:19:00.802483'),
+(19, 0, 1, 4, 'ae', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.807639', '2025-07-28 10:19:00.807639'),
+(20, 1, 1, 4, 'īs', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.811677', '2025-07-28 10:19:00.811677'),
+(21, 0, 0, 5, 'ā', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.814472', '2025-07-28 10:19:00.814472'),
+(22, 1, 0, 5, 'īs', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.818286', '2025-07-28 10:19:00.818286'),
+(23, 0, 1, 5, 'ā', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.820890', '2025-07-28 10:19:00.820890'),
+(24, 1, 1, 5, 'īs', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.824731', '2025-07-28 10:19:00.824731'),
+(25, 0, 0, 6, 'ae', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.832735', '2025-07-28 10:19:00.832735'),
+(26, 1, 0, 6, 'īs', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.843648', '2025-07-28 10:19:00.843648'),
+(27, 0, 1, 6, 'ae', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.849302', '2025-07-28 10:19:00.849302'),
+(28, 1, 1, 6, 'īs', 1, 'a', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.851919', '2025-07-28 10:19:00.851919'),
+(29, 0, 0, 0, 'us', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.856265', '2025-07-28 10:19:00.856265'),
+(30, 1, 0, 0, 'ī', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.859921', '2025-07-28 10:19:00.859921'),
+(31, 0, 1, 0, 'a', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.862289', '2025-07-28 10:19:00.862289'),
+(32, 1, 1, 0, 'ae', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.864635', '2025-07-28 10:19:00.864635'),
+(33, 0, 3, 0, 'um', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.866998', '2025-07-28 10:19:00.866998'),
+(34, 1, 3, 0, 'a', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.869322', '2025-07-28 10:19:00.869322'),
+(35, 0, 0, 1, 'e', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.871767', '2025-07-28 10:19:00.871767'),
+(36, 1, 0, 1, 'ī', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.873993', '2025-07-28 10:19:00.873993'),
+(37, 0, 1, 1, 'a', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.876271', '2025-07-28 10:19:00.876271'),
+(38, 1, 1, 1, 'ae', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.878549', '2025-07-28 10:19:00.878549'),
+(39, 0, 3, 1, 'um', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.880829', '2025-07-28 10:19:00.880829'),
+(40, 1, 3, 1, 'a', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.882916', '2025-07-28 10:19:00.882916'),
+(41, 0, 0, 2, 'um', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.885165', '2025-07-28 10:19:00.885165'),
+(42, 1, 0, 2, 'ōs', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.887431', '2025-07-28 10:19:00.887431'),
+(43, 0, 1, 2, 'am', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.889703', '2025-07-28 10:19:00.889703'),
+(44, 1, 1, 2, 'ās', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.891922', '2025-07-28 10:19:00.891922'),
+(45, 0, 3, 2, 'um', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.894140', '2025-07-28 10:19:00.894140'),
+(46, 1, 3, 2, 'a', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.896333', '2025-07-28 10:19:00.896333'),
+(47, 0, 0, 3, 'īus', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.898603', '2025-07-28 10:19:00.898603'),
+(48, 1, 0, 3, 'ōrum', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.900906', '2025-07-28 10:19:00.900906'),
+(49, 0, 1, 3, 'īus', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.903096', '2025-07-28 10:19:00.903096'),
+(50, 1, 1, 3, 'ārum', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.905288', '2025-07-28 10:19:00.905288'),
+(51, 0, 3, 3, 'īus', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.907564', '2025-07-28 10:19:00.907564'),
+(52, 1, 3, 3, 'ōrum', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.909981', '2025-07-28 10:19:00.909981'),
+(53, 0, 0, 4, 'ī', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.912253', '2025-07-28 10:19:00.912253'),
+(54, 1, 0, 4, 'īs', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.914476', '2025-07-28 10:19:00.914476'),
+(55, 0, 1, 4, 'ī', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.916774', '2025-07-28 10:19:00.916774'),
+(56, 1, 1, 4, 'īs', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.919078', '2025-07-28 10:19:00.919078'),
+(57, 0, 3, 4, 'ī', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.921477', '2025-07-28 10:19:00.921477'),
+(58, 1, 3, 4, 'īs', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.923593', '2025-07-28 10:19:00.923593'),
+(59, 0, 0, 5, 'ō', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.936555', '2025-07-28 10:19:00.936555'),
+(60, 1, 0, 5, 'īs', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.938725', '2025-07-28 10:19:00.938725'),
+(61, 0, 1, 5, 'ā', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.941914', '2025-07-28 10:19:00.941914'),
+(62, 1, 1, 5, 'īs', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.943849', '2025-07-28 10:19:00.943849'),
+(63, 0, 3, 5, 'ō', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.946956', '2025-07-28 10:19:00.946956'),
+(64, 1, 3, 5, 'īs', 1, 'unusnauta', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.949061', '2025-07-28 10:19:00.949061'),
+(65, 0, 0, 0, '', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.952518', '2025-07-28 10:19:00.952518'),
+(66, 1, 0, 0, 'ī', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.955624', '2025-07-28 10:19:00.955624'),
+(67, 0, 1, 0, 'a', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.957712', '2025-07-28 10:19:00.957712'),
+(68, 1, 1, 0, 'ae', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.960800', '2025-07-28 10:19:00.960800'),
+(69, 0, 3, 0, '', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.962836', '2025-07-28 10:19:00.962836'),
+(70, 1, 3, 0, 'a', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.964873', '2025-07-28 10:19:00.964873'),
+(71, 0, 0, 1, '', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.968104', '2025-07-28 10:19:00.968104'),
+(72, 1, 0, 1, 'ī', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.970116', '2025-07-28 10:19:00.970116'),
+(73, 0, 1, 1, 'a', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.973255', '2025-07-28 10:19:00.973255'),
+(74, 1, 1, 1, 'ae', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.975219', '2025-07-28 10:19:00.975219'),
+(75, 0, 3, 1, '', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.978317', '2025-07-28 10:19:00.978317'),
+(76, 1, 3, 1, 'a', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.980359', '2025-07-28 10:19:00.980359'),
+(77, 0, 0, 2, 'um', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.983489', '2025-07-28 10:19:00.983489'),
+(78, 1, 0, 2, 'ōs', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.985484', '2025-07-28 10:19:00.985484'),
+(79, 0, 1, 2, 'am', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.988573', '2025-07-28 10:19:00.988573'),
+(80, 1, 1, 2, 'ās', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.991362', '2025-07-28 10:19:00.991362'),
+(81, 0, 3, 2, '', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.993389', '2025-07-28 10:19:00.993389'),
+(82, 1, 3, 2, 'a', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.996146', '2025-07-28 10:19:00.996146'),
+(83, 0, 0, 3, 'īus', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:00.998191', '2025-07-28 10:19:00.998191'),
+(84, 1, 0, 3, 'ōrum', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.000597', '2025-07-28 10:19:01.000597'),
+(85, 0, 1, 3, 'īus', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.002602', '2025-07-28 10:19:01.002602'),
+(86, 1, 1, 3, 'ārum', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.004672', '2025-07-28 10:19:01.004672'),
+(87, 0, 3, 3, 'īus', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.007010', '2025-07-28 10:19:01.007010'),
+(88, 1, 3, 3, 'ōrum', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.009016', '2025-07-28 10:19:01.009016'),
+(89, 0, 0, 4, 'ī', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.011065', '2025-07-28 10:19:01.011065'),
+(90, 1, 0, 4, 'īs', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.013532', '2025-07-28 10:19:01.013532'),
+(91, 0, 1, 4, 'ī', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.015590', '2025-07-28 10:19:01.015590'),
+(92, 1, 1, 4, 'īs', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.017709', '2025-07-28 10:19:01.017709'),
+(93, 0, 3, 4, 'ī', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.020032', '2025-07-28 10:19:01.020032'),
+(94, 1, 3, 4, 'īs', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.022123', '2025-07-28 10:19:01.022123'),
+(95, 0, 0, 5, 'ō', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.024462', '2025-07-28 10:19:01.024462'),
+(96, 1, 0, 5, 'īs', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.026542', '2025-07-28 10:19:01.026542'),
+(97, 0, 1, 5, 'ā', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.028658', '2025-07-28 10:19:01.028658'),
+(98, 1, 1, 5, 'īs', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.030911', '2025-07-28 10:19:01.030911'),
+(99, 0, 3, 5, 'ō', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.033104', '2025-07-28 10:19:01.033104'),
+(100, 1, 3, 5, 'īs', 1, 'unusnautaer/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.035370', '2025-07-28 10:19:01.035370'),
+(101, 0, 0, 0, 'us', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.070388', '2025-07-28 10:19:01.070388'),
+(102, 1, 0, 0, 'ī', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.072883', '2025-07-28 10:19:01.072883'),
+(103, 0, 0, 1, 'e', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.075024', '2025-07-28 10:19:01.075024'),
+(104, 1, 0, 1, 'ī', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.077118', '2025-07-28 10:19:01.077118'),
+(105, 0, 0, 2, 'um', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.079351', '2025-07-28 10:19:01.079351'),
+(106, 1, 0, 2, 'ōs', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.081615', '2025-07-28 10:19:01.081615'),
+(107, 0, 0, 3, 'ī', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.083762', '2025-07-28 10:19:01.083762'),
+(108, 1, 0, 3, 'ōrum', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.085934', '2025-07-28 10:19:01.085934'),
+(109, 0, 0, 4, 'ō', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.088172', '2025-07-28 10:19:01.088172'),
+(110, 1, 0, 4, 'īs', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.090242', '2025-07-28 10:19:01.090242'),
+(111, 0, 0, 5, 'ō', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.092285', '2025-07-28 10:19:01.092285'),
+(112, 1, 0, 5, 'īs', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.094320', '2025-07-28 10:19:01.094320'),
+(113, 0, 0, 6, 'ī', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.096446', '2025-07-28 10:19:01.096446'),
+(114, 1, 0, 6, 'īs', 2, 'us', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.098525', '2025-07-28 10:19:01.098525'),
+(115, 0, 3, 0, 'um', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.100707', '2025-07-28 10:19:01.100707'),
+(116, 1, 3, 0, 'a', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.102902', '2025-07-28 10:19:01.102902'),
+(117, 0, 3, 1, 'um', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.114014', '2025-07-28 10:19:01.114014'),
+(118, 1, 3, 1, 'a', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.116099', '2025-07-28 10:19:01.116099'),
+(119, 0, 3, 2, 'um', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.119582', '2025-07-28 10:19:01.119582'),
+(120, 1, 3, 2, 'a', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.121585', '2025-07-28 10:19:01.121585'),
+(121, 0, 3, 3, 'ī', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.125105', '2025-07-28 10:19:01.125105'),
+(122, 1, 3, 3, 'ōrum', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.128296', '2025-07-28 10:19:01.128296'),
+(123, 0, 3, 4, 'ō', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.130644', '2025-07-28 10:19:01.130644'),
+(124, 1, 3, 4, 'īs', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.134067', '2025-07-28 10:19:01.134067'),
+(125, 0, 3, 5, 'ō', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.136094', '2025-07-28 10:19:01.136094'),
+(126, 1, 3, 5, 'īs', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.139375', '2025-07-28 10:19:01.139375'),
+(127, 0, 0, 6, 'ī', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.141359', '2025-07-28 10:19:01.141359'),
+(128, 1, 0, 6, 'īs', 2, 'um', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.144549', '2025-07-28 10:19:01.144549'),
+(129, 0, 0, 0, 'us', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.146910', '2025-07-28 10:19:01.146910'),
+(130, 1, 0, 0, 'ī', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.149033', '2025-07-28 10:19:01.149033'),
+(131, 0, 0, 1, 'ī', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.152472', '2025-07-28 10:19:01.152472'),
+(132, 1, 0, 1, 'ī', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.154509', '2025-07-28 10:19:01.154509'),
+(133, 0, 0, 2, 'um', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.157800', '2025-07-28 10:19:01.157800'),
+(134, 1, 0, 2, 'ōs', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.160913', '2025-07-28 10:19:01.160913'),
+(135, 0, 0, 3, 'ī', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.163125', '2025-07-28 10:19:01.163125'),
+(136, 1, 0, 3, 'ōrum', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.166311', '2025-07-28 10:19:01.166311'),
+(137, 0, 0, 4, 'ō', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.168331', '2025-07-28 10:19:01.168331'),
+(138, 1, 0, 4, 'īs', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.171439', '2025-07-28 10:19:01.171439'),
+(139, 0, 0, 5, 'ō', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.173584', '2025-07-28 10:19:01.173584'),
+(140, 1, 0, 5, 'īs', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.176499', '2025-07-28 10:19:01.176499'),
+(141, 0, 0, 6, 'ī', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.178721', '2025-07-28 10:19:01.178721'),
+(142, 1, 0, 6, 'īs', 2, 'ius', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.181595', '2025-07-28 10:19:01.181595'),
+(143, 0, 0, 0, '', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.183713', '2025-07-28 10:19:01.183713'),
+(144, 1, 0, 0, 'ī', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.185894', '2025-07-28 10:19:01.185894'),
+(145, 0, 3, 0, '', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.188629', '2025-07-28 10:19:01.188629'),
+(146, 1, 3, 0, 'a', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.190801', '2025-07-28 10:19:01.190801'),
+(147, 0, 0, 1, '', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.193489', '2025-07-28 10:19:01.193489'),
+(148, 1, 0, 1, 'ī', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.195590', '2025-07-28 10:19:01.195590'),
+(149, 0, 3, 1, '', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.197826', '2025-07-28 10:19:01.197826'),
+(150, 1, 3, 1, 'a', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.200670', '2025-07-28 10:19:01.200670'),
+(151, 0, 0, 2, 'um', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.203618', '2025-07-28 10:19:01.203618'),
+(152, 1, 0, 2, 'ōs', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.205743', '2025-07-28 10:19:01.205743'),
+(153, 0, 3, 2, '', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.207845', '2025-07-28 10:19:01.207845'),
+(154, 1, 3, 2, 'a', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.209954', '2025-07-28 10:19:01.209954'),
+(155, 0, 0, 3, 'ī', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.212101', '2025-07-28 10:19:01.212101'),
+(156, 1, 0, 3, 'ōrum', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.214317', '2025-07-28 10:19:01.214317'),
+(157, 0, 3, 3, 'ī', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.216505', '2025-07-28 10:19:01.216505'),
+(158, 1, 3, 3, 'ōrum', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.218707', '2025-07-28 10:19:01.218707'),
+(159, 0, 0, 4, 'ō', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.220889', '2025-07-28 10:19:01.220889'),
+(160, 1, 0, 4, 'īs', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.223064', '2025-07-28 10:19:01.223064'),
+(161, 0, 3, 4, 'ō', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.225246', '2025-07-28 10:19:01.225246'),
+(162, 1, 3, 4, 'īs', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.227406', '2025-07-28 10:19:01.227406'),
+(163, 0, 0, 5, 'ō', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.229582', '2025-07-28 10:19:01.229582'),
+(164, 1, 0, 5, 'īs', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.231747', '2025-07-28 10:19:01.231747'),
+(165, 0, 3, 5, 'ō', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.233905', '2025-07-28 10:19:01.233905'),
+(166, 1, 3, 5, 'īs', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.236124', '2025-07-28 10:19:01.236124'),
+(167, 0, 0, 6, 'ī', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.238271', '2025-07-28 10:19:01.238271'),
+(168, 1, 0, 6, 'īs', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.240461', '2025-07-28 10:19:01.240461'),
+(169, 0, 3, 6, 'ī', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.242598', '2025-07-28 10:19:01.242598'),
+(170, 1, 3, 6, 'īs', 2, 'er/ir', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.244777', '2025-07-28 10:19:01.244777'),
+(171, 0, 3, 0, '', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.251378', '2025-07-28 10:19:01.251378'),
+(172, 1, 3, 0, 'a', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.260976', '2025-07-28 10:19:01.260976'),
+(173, 0, 0, 0, '', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.263164', '2025-07-28 10:19:01.263164'),
+(174, 1, 0, 0, 'ēs', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.266755', '2025-07-28 10:19:01.266755'),
+(175, 0, 1, 0, '', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.268891', '2025-07-28 10:19:01.268891'),
+(176, 1, 1, 0, 'ēs', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.272377', '2025-07-28 10:19:01.272377'),
+(177, 0, 3, 1, '', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.274421', '2025-07-28 10:19:01.274421'),
+(178, 1, 3, 1, 'a', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.277919', '2025-07-28 10:19:01.277919'),
+(179, 0, 0, 1, '', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.280124', '2025-07-28 10:19:01.280124'),
+(180, 1, 0, 1, 'ēs', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.283581', '2025-07-28 10:19:01.283581'),
+(181, 0, 1, 1, '', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.287030', '2025-07-28 10:19:01.287030'),
+(182, 1, 1, 1, 'ēs', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.289261', '2025-07-28 10:19:01.289261'),
+(183, 0, 3, 2, '', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.291300', '2025-07-28 10:19:01.291300'),
+(184, 1, 3, 2, 'a', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.294794', '2025-07-28 10:19:01.294794'),
+(185, 0, 0, 2, 'em', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.296861', '2025-07-28 10:19:01.296861'),
+(186, 1, 0, 2, 'ēs', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.300450', '2025-07-28 10:19:01.300450'),
+(187, 0, 1, 2, 'em', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.302546', '2025-07-28 10:19:01.302546'),
+(188, 1, 1, 2, 'ēs', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.305988', '2025-07-28 10:19:01.305988'),
+(189, 0, 3, 3, 'is', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.307991', '2025-07-28 10:19:01.307991'),
+(190, 1, 3, 3, 'um', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.311049', '2025-07-28 10:19:01.311049'),
+(191, 0, 0, 3, 'is', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.313116', '2025-07-28 10:19:01.313116'),
+(192, 1, 0, 3, 'um', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.316136', '2025-07-28 10:19:01.316136'),
+(193, 0, 1, 3, 'is', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.318230', '2025-07-28 10:19:01.318230'),
+(194, 1, 1, 3, 'um', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.321234', '2025-07-28 10:19:01.321234'),
+(195, 0, 3, 4, 'ī', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.324399', '2025-07-28 10:19:01.324399'),
+(196, 1, 3, 4, 'ibus', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.326466', '2025-07-28 10:19:01.326466'),
+(197, 0, 0, 4, 'ī', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.328561', '2025-07-28 10:19:01.328561'),
+(198, 1, 0, 4, 'ibus', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.331316', '2025-07-28 10:19:01.331316'),
+(199, 0, 1, 4, 'ī', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.333409', '2025-07-28 10:19:01.333409'),
+(200, 1, 1, 4, 'ibus', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.335633', '2025-07-28 10:19:01.335633'),
+(201, 0, 3, 5, 'e', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.338480', '2025-07-28 10:19:01.338480'),
+(202, 1, 3, 5, 'ibus', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.340639', '2025-07-28 10:19:01.340639'),
+(203, 0, 0, 5, 'e', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.342761', '2025-07-28 10:19:01.342761'),
+(204, 1, 0, 5, 'ibus', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.345405', '2025-07-28 10:19:01.345405'),
+(205, 0, 1, 5, 'e', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.347619', '2025-07-28 10:19:01.347619'),
+(206, 1, 1, 5, 'ibus', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.350838', '2025-07-28 10:19:01.350838'),
+(207, 0, 3, 6, 'ī', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.353037', '2025-07-28 10:19:01.353037'),
+(208, 1, 3, 6, 'ibus', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.355139', '2025-07-28 10:19:01.355139'),
+(209, 0, 0, 6, 'ī', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.357264', '2025-07-28 10:19:01.357264'),
+(210, 1, 0, 6, 'ibus', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.359418', '2025-07-28 10:19:01.359418'),
+(211, 0, 1, 6, 'ī', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.361610', '2025-07-28 10:19:01.361610'),
+(212, 1, 1, 6, 'ibus', 3, 'is', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.363809', '2025-07-28 10:19:01.363809'),
+(213, 0, 3, 0, '', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.366010', '2025-07-28 10:19:01.366010'),
+(214, 1, 3, 0, 'ia', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.368220', '2025-07-28 10:19:01.368220'),
+(215, 0, 0, 0, '', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.370398', '2025-07-28 10:19:01.370398'),
+(216, 1, 0, 0, 'ēs', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.372615', '2025-07-28 10:19:01.372615'),
+(217, 0, 1, 0, '', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.374769', '2025-07-28 10:19:01.374769'),
+(218, 1, 1, 0, 'ēs', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.376919', '2025-07-28 10:19:01.376919'),
+(219, 0, 3, 1, '', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.379074', '2025-07-28 10:19:01.379074'),
+(220, 1, 3, 1, 'ia', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.381296', '2025-07-28 10:19:01.381296'),
+(221, 0, 0, 1, '', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.383494', '2025-07-28 10:19:01.383494'),
+(222, 1, 0, 1, 'ēs', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.385732', '2025-07-28 10:19:01.385732'),
+(223, 0, 1, 1, '', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.387925', '2025-07-28 10:19:01.387925'),
+(224, 1, 1, 1, 'ēs', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.390098', '2025-07-28 10:19:01.390098'),
+(225, 0, 3, 2, '', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.396611', '2025-07-28 10:19:01.396611'),
+(226, 1, 3, 2, 'ia', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.398785', '2025-07-28 10:19:01.398785'),
+(227, 0, 0, 2, 'em', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.402367', '2025-07-28 10:19:01.402367'),
+(228, 1, 0, 2, 'ēs', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.404404', '2025-07-28 10:19:01.404404'),
+(229, 0, 1, 2, 'em', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.407780', '2025-07-28 10:19:01.407780'),
+(230, 1, 1, 2, 'ēs', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.409832', '2025-07-28 10:19:01.409832'),
+(231, 0, 3, 3, 'is', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.413119', '2025-07-28 10:19:01.413119'),
+(232, 1, 3, 3, 'ium', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.415269', '2025-07-28 10:19:01.415269'),
+(233, 0, 0, 3, 'is', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.419032', '2025-07-28 10:19:01.419032'),
+(234, 1, 0, 3, 'ium', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.422761', '2025-07-28 10:19:01.422761'),
+(235, 0, 1, 3, 'is', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.425053', '2025-07-28 10:19:01.425053'),
+(236, 1, 1, 3, 'ium', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.427074', '2025-07-28 10:19:01.427074'),
+(237, 0, 3, 4, 'ī', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.430750', '2025-07-28 10:19:01.430750'),
+(238, 1, 3, 4, 'ibus', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.432828', '2025-07-28 10:19:01.432828'),
+(239, 0, 0, 4, 'ī', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.436522', '2025-07-28 10:19:01.436522'),
+(240, 1, 0, 4, 'ibus', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.438546', '2025-07-28 10:19:01.438546'),
+(241, 0, 1, 4, 'ī', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.442024', '2025-07-28 10:19:01.442024'),
+(242, 1, 1, 4, 'ibus', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.444013', '2025-07-28 10:19:01.444013'),
+(243, 0, 3, 5, 'e', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.447221', '2025-07-28 10:19:01.447221'),
+(244, 1, 3, 5, 'ibus', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.450248', '2025-07-28 10:19:01.450248'),
+(245, 0, 0, 5, 'e', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.452426', '2025-07-28 10:19:01.452426'),
+(246, 1, 0, 5, 'ibus', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.454537', '2025-07-28 10:19:01.454537'),
+(247, 0, 1, 5, 'e', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.457552', '2025-07-28 10:19:01.457552'),
+(248, 1, 1, 5, 'ibus', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.459620', '2025-07-28 10:19:01.459620'),
+(249, 0, 3, 6, 'ī', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.462528', '2025-07-28 10:19:01.462528'),
+(250, 1, 3, 6, 'ibus', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.464683', '2025-07-28 10:19:01.464683'),
+(251, 0, 0, 6, 'ī', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.466866', '2025-07-28 10:19:01.466866'),
+(252, 1, 0, 6, 'ibus', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.469754', '2025-07-28 10:19:01.469754'),
+(253, 0, 1, 6, 'ī', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.471931', '2025-07-28 10:19:01.471931'),
+(254, 1, 1, 6, 'ibus', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.474660', '2025-07-28 10:19:01.474660'),
+(255, 0, 3, 0, '', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.476832', '2025-07-28 10:19:01.476832'),
+(256, 1, 3, 0, 'ia', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.478956', '2025-07-28 10:19:01.478956'),
+(257, 0, 0, 0, '', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.482828', '2025-07-28 10:19:01.482828'),
+(258, 1, 0, 0, 'ēs', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.485026', '2025-07-28 10:19:01.485026'),
+(259, 0, 1, 0, '', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.487162', '2025-07-28 10:19:01.487162'),
+(260, 1, 1, 0, 'ēs', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.489281', '2025-07-28 10:19:01.489281'),
+(261, 0, 3, 1, '', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.491407', '2025-07-28 10:19:01.491407'),
+(262, 1, 3, 1, 'ia', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.493554', '2025-07-28 10:19:01.493554'),
+(263, 0, 0, 1, '', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.495752', '2025-07-28 10:19:01.495752'),
+(264, 1, 0, 1, 'ēs', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.498051', '2025-07-28 10:19:01.498051'),
+(265, 0, 1, 1, '', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.500255', '2025-07-28 10:19:01.500255'),
+(266, 1, 1, 1, 'ēs', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.502510', '2025-07-28 10:19:01.502510'),
+(267, 0, 3, 2, '', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.504681', '2025-07-28 10:19:01.504681'),
+(268, 1, 3, 2, 'ia', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.506870', '2025-07-28 10:19:01.506870'),
+(269, 0, 0, 2, 'im', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.509018', '2025-07-28 10:19:01.509018'),
+(270, 1, 0, 2, 'ēs', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.511173', '2025-07-28 10:19:01.511173'),
+(271, 0, 1, 2, 'im', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.513344', '2025-07-28 10:19:01.513344'),
+(272, 1, 1, 2, 'ēs', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.515527', '2025-07-28 10:19:01.515527'),
+(273, 0, 3, 3, 'is', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.517685', '2025-07-28 10:19:01.517685'),
+(274, 1, 3, 3, 'ium', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.523510', '2025-07-28 10:19:01.523510'),
+(275, 0, 0, 3, 'is', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.525761', '2025-07-28 10:19:01.525761'),
+(276, 1, 0, 3, 'ium', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.529211', '2025-07-28 10:19:01.529211'),
+(277, 0, 1, 3, 'is', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.531339', '2025-07-28 10:19:01.531339'),
+(278, 1, 1, 3, 'ium', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.534879', '2025-07-28 10:19:01.534879'),
+(279, 0, 3, 4, 'ī', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.536978', '2025-07-28 10:19:01.536978'),
+(280, 1, 3, 4, 'ibus', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.540315', '2025-07-28 10:19:01.540315'),
+(281, 0, 0, 4, 'ī', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.542348', '2025-07-28 10:19:01.542348'),
+(282, 1, 0, 4, 'ibus', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.545630', '2025-07-28 10:19:01.545630'),
+(283, 0, 1, 4, 'ī', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.547732', '2025-07-28 10:19:01.547732'),
+(284, 1, 1, 4, 'ibus', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.551292', '2025-07-28 10:19:01.551292'),
+(285, 0, 3, 5, 'ī', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.553352', '2025-07-28 10:19:01.553352'),
+(286, 1, 3, 5, 'ibus', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.556638', '2025-07-28 10:19:01.556638'),
+(287, 0, 0, 5, 'ī', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.558745', '2025-07-28 10:19:01.558745'),
+(288, 1, 0, 5, 'ibus', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.561902', '2025-07-28 10:19:01.561902'),
+(289, 0, 1, 5, 'ī', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.564150', '2025-07-28 10:19:01.564150'),
+(290, 1, 1, 5, 'ibus', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.566247', '2025-07-28 10:19:01.566247'),
+(291, 0, 3, 6, 'ī', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.569733', '2025-07-28 10:19:01.569733'),
+(292, 1, 3, 6, 'ibus', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.571813', '2025-07-28 10:19:01.571813'),
+(293, 0, 0, 6, 'ī', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.574774', '2025-07-28 10:19:01.574774'),
+(294, 1, 0, 6, 'ibus', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.576878', '2025-07-28 10:19:01.576878'),
+(295, 0, 1, 6, 'ī', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.579911', '2025-07-28 10:19:01.579911'),
+(296, 1, 1, 6, 'ibus', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.582079', '2025-07-28 10:19:01.582079'),
+(297, 0, 3, 0, '', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.585169', '2025-07-28 10:19:01.585169'),
+(298, 1, 3, 0, 'ia', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.587287', '2025-07-28 10:19:01.587287'),
+(299, 0, 0, 0, '', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.590249', '2025-07-28 10:19:01.590249'),
+(300, 1, 0, 0, 'ēs', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.592353', '2025-07-28 10:19:01.592353'),
+(301, 0, 1, 0, '', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.594503', '2025-07-28 10:19:01.594503'),
+(302, 1, 1, 0, 'ēs', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.597320', '2025-07-28 10:19:01.597320'),
+(303, 0, 3, 1, '', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.599504', '2025-07-28 10:19:01.599504'),
+(304, 1, 3, 1, 'ia', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.603810', '2025-07-28 10:19:01.603810'),
+(305, 0, 0, 1, '', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.605942', '2025-07-28 10:19:01.605942'),
+(306, 1, 0, 1, 'ēs', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.608044', '2025-07-28 10:19:01.608044'),
+(307, 0, 1, 1, '', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.610168', '2025-07-28 10:19:01.610168'),
+(308, 1, 1, 1, 'ēs', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.612317', '2025-07-28 10:19:01.612317'),
+(309, 0, 3, 2, '', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.614521', '2025-07-28 10:19:01.614521'),
+(310, 1, 3, 2, 'ia', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.616730', '2025-07-28 10:19:01.616730'),
+(311, 0, 0, 2, 'em', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.619015', '2025-07-28 10:19:01.619015'),
+(312, 1, 0, 2, 'ēs', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.621196', '2025-07-28 10:19:01.621196'),
+(313, 0, 1, 2, 'em', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.623415', '2025-07-28 10:19:01.623415'),
+(314, 1, 1, 2, 'ēs', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.625590', '2025-07-28 10:19:01.625590'),
+(315, 0, 3, 3, 'is', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.627746', '2025-07-28 10:19:01.627746'),
+(316, 1, 3, 3, 'ium', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.629937', '2025-07-28 10:19:01.629937'),
+(317, 0, 0, 3, 'is', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.632076', '2025-07-28 10:19:01.632076'),
+(318, 1, 0, 3, 'ium', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.634220', '2025-07-28 10:19:01.634220'),
+(319, 0, 1, 3, 'is', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.636489', '2025-07-28 10:19:01.636489'),
+(320, 1, 1, 3, 'ium', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.642019', '2025-07-28 10:19:01.642019'),
+(321, 0, 3, 4, 'ī', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.645292', '2025-07-28 10:19:01.645292'),
+(322, 1, 3, 4, 'ibus', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.647573', '2025-07-28 10:19:01.647573'),
+(323, 0, 0, 4, 'ī', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.650865', '2025-07-28 10:19:01.650865'),
+(324, 1, 0, 4, 'ibus', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.653147', '2025-07-28 10:19:01.653147'),
+(325, 0, 1, 4, 'ī', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.656471', '2025-07-28 10:19:01.656471'),
+(326, 1, 1, 4, 'ibus', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.658488', '2025-07-28 10:19:01.658488'),
+(327, 0, 3, 5, 'ī', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.661732', '2025-07-28 10:19:01.661732'),
+(328, 1, 3, 5, 'ibus', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.663777', '2025-07-28 10:19:01.663777'),
+(329, 0, 0, 5, 'ī', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.667193', '2025-07-28 10:19:01.667193'),
+(330, 1, 0, 5, 'ibus', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.669338', '2025-07-28 10:19:01.669338'),
+(331, 0, 1, 5, 'ī', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.672480', '2025-07-28 10:19:01.672480'),
+(332, 1, 1, 5, 'ibus', 3, 'one', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.674670', '2025-07-28 10:19:01.674670'),
+(333, 0, 3, 0, '', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.676721', '2025-07-28 10:19:01.676721'),
+(334, 1, 3, 0, 'a', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.680097', '2025-07-28 10:19:01.680097'),
+(335, 0, 0, 0, '', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.682228', '2025-07-28 10:19:01.682228'),
+(336, 1, 0, 0, 'ēs', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.685091', '2025-07-28 10:19:01.685091'),
+(337, 0, 1, 0, '', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.687179', '2025-07-28 10:19:01.687179'),
+(338, 1, 1, 0, 'ēs', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.690142', '2025-07-28 10:19:01.690142'),
+(339, 0, 3, 1, '', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.692192', '2025-07-28 10:19:01.692192'),
+(340, 1, 3, 1, 'a', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.694958', '2025-07-28 10:19:01.694958'),
+(341, 0, 0, 1, '', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.697047', '2025-07-28 10:19:01.697047'),
+(342, 1, 0, 1, 'ēs', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.699949', '2025-07-28 10:19:01.699949'),
+(343, 0, 1, 1, '', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.702100', '2025-07-28 10:19:01.702100'),
+(344, 1, 1, 1, 'ēs', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.705017', '2025-07-28 10:19:01.705017'),
+(345, 0, 3, 2, '', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.707122', '2025-07-28 10:19:01.707122'),
+(346, 1, 3, 2, 'a', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.709247', '2025-07-28 10:19:01.709247'),
+(347, 0, 0, 2, 'em', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.714054', '2025-07-28 10:19:01.714054'),
+(348, 1, 0, 2, 'ēs', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.716242', '2025-07-28 10:19:01.716242'),
+(349, 0, 1, 2, 'em', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.718485', '2025-07-28 10:19:01.718485'),
+(350, 1, 1, 2, 'ēs', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.720661', '2025-07-28 10:19:01.720661'),
+(351, 0, 3, 3, 'is', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.722909', '2025-07-28 10:19:01.722909'),
+(352, 1, 3, 3, 'um', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.725108', '2025-07-28 10:19:01.725108'),
+(353, 0, 0, 3, 'is', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.727326', '2025-07-28 10:19:01.727326'),
+(354, 1, 0, 3, 'um', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.729519', '2025-07-28 10:19:01.729519'),
+(355, 0, 1, 3, 'is', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.731774', '2025-07-28 10:19:01.731774'),
+(356, 1, 1, 3, 'um', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.734023', '2025-07-28 10:19:01.734023'),
+(357, 0, 3, 4, 'ī', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.736303', '2025-07-28 10:19:01.736303'),
+(358, 1, 3, 4, 'ibus', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.738527', '2025-07-28 10:19:01.738527'),
+(359, 0, 0, 4, 'ī', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.740725', '2025-07-28 10:19:01.740725'),
+(360, 1, 0, 4, 'ibus', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.742917', '2025-07-28 10:19:01.742917'),
+(361, 0, 1, 4, 'ī', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.745107', '2025-07-28 10:19:01.745107'),
+(362, 1, 1, 4, 'ibus', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.750757', '2025-07-28 10:19:01.750757'),
+(363, 0, 3, 5, 'e', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.754181', '2025-07-28 10:19:01.754181'),
+(364, 1, 3, 5, 'ibus', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.756379', '2025-07-28 10:19:01.756379'),
+(365, 0, 0, 5, 'e', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.759770', '2025-07-28 10:19:01.759770'),
+(366, 1, 0, 5, 'ibus', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.761830', '2025-07-28 10:19:01.761830'),
+(367, 0, 1, 5, 'e', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.765211', '2025-07-28 10:19:01.765211'),
+(368, 1, 1, 5, 'ibus', 3, 'onenonistem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.767278', '2025-07-28 10:19:01.767278'),
+(369, 0, 3, 0, 'e', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.770853', '2025-07-28 10:19:01.770853'),
+(370, 1, 3, 0, 'ia', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.772874', '2025-07-28 10:19:01.772874'),
+(371, 0, 0, 0, 'is', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.776224', '2025-07-28 10:19:01.776224'),
+(372, 1, 0, 0, 'ēs', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.778230', '2025-07-28 10:19:01.778230'),
+(373, 0, 1, 0, 'is', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.781641', '2025-07-28 10:19:01.781641'),
+(374, 1, 1, 0, 'ēs', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.783740', '2025-07-28 10:19:01.783740'),
+(375, 0, 3, 1, 'e', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.785870', '2025-07-28 10:19:01.785870'),
+(376, 1, 3, 1, 'ia', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.788771', '2025-07-28 10:19:01.788771'),
+(377, 0, 0, 1, 'is', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.790821', '2025-07-28 10:19:01.790821'),
+(378, 1, 0, 1, 'ēs', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.793497', '2025-07-28 10:19:01.793497'),
+(379, 0, 1, 1, 'is', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.795579', '2025-07-28 10:19:01.795579'),
+(380, 1, 1, 1, 'ēs', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.798470', '2025-07-28 10:19:01.798470'),
+(381, 0, 3, 2, 'e', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.800612', '2025-07-28 10:19:01.800612'),
+(382, 1, 3, 2, 'ia', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.803476', '2025-07-28 10:19:01.803476'),
+(383, 0, 0, 2, 'em', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.805582', '2025-07-28 10:19:01.805582'),
+(384, 1, 0, 2, 'ēs', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.808399', '2025-07-28 10:19:01.808399'),
+(385, 0, 1, 2, 'em', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.813207', '2025-07-28 10:19:01.813207'),
+(386, 1, 1, 2, 'ēs', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.815517', '2025-07-28 10:19:01.815517'),
+(387, 0, 3, 3, 'is', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.817661', '2025-07-28 10:19:01.817661'),
+(388, 1, 3, 3, 'ium', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.819926', '2025-07-28 10:19:01.819926'),
+(389, 0, 0, 3, 'is', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.822119', '2025-07-28 10:19:01.822119'),
+(390, 1, 0, 3, 'ium', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.824302', '2025-07-28 10:19:01.824302'),
+(391, 0, 1, 3, 'is', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.826477', '2025-07-28 10:19:01.826477'),
+(392, 1, 1, 3, 'ium', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.828627', '2025-07-28 10:19:01.828627'),
+(393, 0, 3, 4, 'ī', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.830889', '2025-07-28 10:19:01.830889'),
+(394, 1, 3, 4, 'ibus', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.833060', '2025-07-28 10:19:01.833060'),
+(395, 0, 0, 4, 'ī', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.835372', '2025-07-28 10:19:01.835372'),
+(396, 1, 0, 4, 'ibus', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.837596', '2025-07-28 10:19:01.837596'),
+(397, 0, 1, 4, 'ī', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.839880', '2025-07-28 10:19:01.839880'),
+(398, 1, 1, 4, 'ibus', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.842095', '2025-07-28 10:19:01.842095'),
+(399, 0, 3, 5, 'ī', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.844315', '2025-07-28 10:19:01.844315'),
+(400, 1, 3, 5, 'ibus', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.850111', '2025-07-28 10:19:01.850111'),
+(401, 0, 0, 5, 'ī', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.852544', '2025-07-28 10:19:01.852544'),
+(402, 1, 0, 5, 'ibus', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.856288', '2025-07-28 10:19:01.856288'),
+(403, 0, 1, 5, 'ī', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.858553', '2025-07-28 10:19:01.858553'),
+(404, 1, 1, 5, 'ibus', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.862169', '2025-07-28 10:19:01.862169'),
+(405, 0, 0, 0, '', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.864506', '2025-07-28 10:19:01.864506'),
+(406, 1, 0, 0, 'ēs', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.868065', '2025-07-28 10:19:01.868065'),
+(407, 0, 1, 0, 'is', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.870424', '2025-07-28 10:19:01.870424'),
+(408, 1, 1, 0, 'ēs', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.874250', '2025-07-28 10:19:01.874250'),
+(409, 0, 3, 0, 'e', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.876480', '2025-07-28 10:19:01.876480'),
+(410, 1, 3, 0, 'ia', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.880271', '2025-07-28 10:19:01.880271'),
+(411, 0, 0, 1, '', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.882524', '2025-07-28 10:19:01.882524'),
+(412, 1, 0, 1, 'ēs', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.886232', '2025-07-28 10:19:01.886232'),
+(413, 0, 1, 1, 'is', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.888496', '2025-07-28 10:19:01.888496'),
+(414, 1, 1, 1, 'ēs', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.891511', '2025-07-28 10:19:01.891511'),
+(415, 0, 3, 1, 'e', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.893710', '2025-07-28 10:19:01.893710'),
+(416, 1, 3, 1, 'ia', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.896629', '2025-07-28 10:19:01.896629'),
+(417, 0, 0, 2, 'em', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.898971', '2025-07-28 10:19:01.898971'),
+(418, 1, 0, 2, 'ēs', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.901888', '2025-07-28 10:19:01.901888'),
+(419, 0, 1, 2, 'em', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.904158', '2025-07-28 10:19:01.904158'),
+(420, 1, 1, 2, 'ēs', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.906468', '2025-07-28 10:19:01.906468'),
+(421, 0, 3, 2, 'e', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.909216', '2025-07-28 10:19:01.909216'),
+(422, 1, 3, 2, 'ia', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.911389', '2025-07-28 10:19:01.911389'),
+(423, 0, 0, 3, 'is', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.916439', '2025-07-28 10:19:01.916439'),
+(424, 1, 0, 3, 'ium', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.918724', '2025-07-28 10:19:01.918724'),
+(425, 0, 1, 3, 'is', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.921014', '2025-07-28 10:19:01.921014'),
+(426, 1, 1, 3, 'ium', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.923321', '2025-07-28 10:19:01.923321'),
+(427, 0, 3, 3, 'is', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.925632', '2025-07-28 10:19:01.925632'),
+(428, 1, 3, 3, 'ium', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.927920', '2025-07-28 10:19:01.927920'),
+(429, 0, 0, 4, 'ī', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.930134', '2025-07-28 10:19:01.930134'),
+(430, 1, 0, 4, 'ibus', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.932388', '2025-07-28 10:19:01.932388'),
+(431, 0, 1, 4, 'ī', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.934612', '2025-07-28 10:19:01.934612'),
+(432, 1, 1, 4, 'ibus', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.936952', '2025-07-28 10:19:01.936952'),
+(433, 0, 3, 4, 'ī', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.939183', '2025-07-28 10:19:01.939183'),
+(434, 1, 3, 4, 'ibus', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.941437', '2025-07-28 10:19:01.941437'),
+(435, 0, 0, 5, 'ī', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.943677', '2025-07-28 10:19:01.943677'),
+(436, 1, 0, 5, 'ibus', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.949642', '2025-07-28 10:19:01.949642'),
+(437, 0, 1, 5, 'ī', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.952093', '2025-07-28 10:19:01.952093'),
+(438, 1, 1, 5, 'ibus', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.955955', '2025-07-28 10:19:01.955955'),
+(439, 0, 3, 5, 'ī', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.958223', '2025-07-28 10:19:01.958223'),
+(440, 1, 3, 5, 'ibus', 3, 'three', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.962042', '2025-07-28 10:19:01.962042'),
+(441, 0, 1, 0, 'vīs', 3, 'visvis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.964354', '2025-07-28 10:19:01.964354'),
+(442, 1, 1, 0, 'vīrēs', 3, 'visvis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.968086', '2025-07-28 10:19:01.968086'),
+(443, 0, 1, 1, 'vīs', 3, 'visvis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.970454', '2025-07-28 10:19:01.970454'),
+(444, 1, 1, 1, 'vīrēs', 3, 'visvis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.974154', '2025-07-28 10:19:01.974154'),
+(445, 0, 1, 2, 'vim', 3, 'visvis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.976384', '2025-07-28 10:19:01.976384'),
+(446, 1, 1, 2, 'vīrēs', 3, 'visvis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.980127', '2025-07-28 10:19:01.980127'),
+(447, 0, 1, 3, 'vīs', 3, 'visvis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.982235', '2025-07-28 10:19:01.982235'),
+(448, 1, 1, 3, 'vīrium', 3, 'visvis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.985617', '2025-07-28 10:19:01.985617'),
+(449, 0, 1, 4, 'vī', 3, 'visvis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.987886', '2025-07-28 10:19:01.987886'),
+(450, 1, 1, 4, 'vīribus', 3, 'visvis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.990918', '2025-07-28 10:19:01.990918'),
+(451, 0, 1, 5, 'vī', 3, 'visvis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.993142', '2025-07-28 10:19:01.993142'),
+(452, 1, 1, 5, 'vīribus', 3, 'visvis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.996097', '2025-07-28 10:19:01.996097'),
+(453, 0, 1, 0, 'sūs', 3, 'sussuis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:01.998375', '2025-07-28 10:19:01.998375'),
+(454, 1, 1, 0, 'suēs', 3, 'sussuis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.001367', '2025-07-28 10:19:02.001367'),
+(455, 0, 1, 1, 'sūs', 3, 'sussuis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.003640', '2025-07-28 10:19:02.003640'),
+(456, 1, 1, 1, 'suēs', 3, 'sussuis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.006491', '2025-07-28 10:19:02.006491'),
+(457, 0, 1, 2, 'suem', 3, 'sussuis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.011035', '2025-07-28 10:19:02.011035'),
+(458, 1, 1, 2, 'suēs', 3, 'sussuis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.013253', '2025-07-28 10:19:02.013253'),
+(459, 0, 1, 3, 'suis', 3, 'sussuis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.015521', '2025-07-28 10:19:02.015521'),
+(460, 1, 1, 3, 'suum', 3, 'sussuis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.017760', '2025-07-28 10:19:02.017760'),
+(461, 0, 1, 4, 'suī', 3, 'sussuis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.020063', '2025-07-28 10:19:02.020063'),
+(462, 1, 1, 4, 'sūbus', 3, 'sussuis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.022265', '2025-07-28 10:19:02.022265'),
+(463, 0, 1, 5, 'sue', 3, 'sussuis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.024461', '2025-07-28 10:19:02.024461'),
+(464, 1, 1, 5, 'sūbus', 3, 'sussuis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.026659', '2025-07-28 10:19:02.026659'),
+(465, 0, 0, 0, 'bōs', 3, 'bosbovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.028899', '2025-07-28 10:19:02.028899'),
+(466, 1, 0, 0, 'bovēs', 3, 'bosbovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.031100', '2025-07-28 10:19:02.031100'),
+(467, 0, 0, 1, 'bōs', 3, 'bosbovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.033263', '2025-07-28 10:19:02.033263'),
+(468, 1, 0, 1, 'bovēs', 3, 'bosbovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.035451', '2025-07-28 10:19:02.035451'),
+(469, 0, 0, 2, 'bovem', 3, 'bosbovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.040823', '2025-07-28 10:19:02.040823'),
+(470, 1, 0, 2, 'bovēs', 3, 'bosbovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.043175', '2025-07-28 10:19:02.043175'),
+(471, 0, 0, 3, 'bovis', 3, 'bosbovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.046727', '2025-07-28 10:19:02.046727'),
+(472, 1, 0, 3, 'boum', 3, 'bosbovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.049035', '2025-07-28 10:19:02.049035'),
+(473, 0, 0, 4, 'bovī', 3, 'bosbovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.052659', '2025-07-28 10:19:02.052659'),
+(474, 1, 0, 4, 'bōbus', 3, 'bosbovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.054972', '2025-07-28 10:19:02.054972'),
+(475, 0, 0, 5, 'bove', 3, 'bosbovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.058418', '2025-07-28 10:19:02.058418'),
+(476, 1, 0, 5, 'bōbus', 3, 'bosbovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.060632', '2025-07-28 10:19:02.060632'),
+(477, 0, 0, 0, 'Iuppiter', 3, 'iuppiteriovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.064155', '2025-07-28 10:19:02.064155'),
+(478, 0, 0, 1, 'Iuppiter', 3, 'iuppiteriovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.086119', '2025-07-28 10:19:02.086119'),
+(479, 0, 0, 2, 'Iovem', 3, 'iuppiteriovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.098661', '2025-07-28 10:19:02.098661'),
+(480, 0, 0, 3, 'Iovis', 3, 'iuppiteriovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.101078', '2025-07-28 10:19:02.101078'),
+(481, 0, 0, 4, 'Iovī', 3, 'iuppiteriovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.104323', '2025-07-28 10:19:02.104323'),
+(482, 0, 0, 5, 'Iove', 3, 'iuppiteriovis', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.106403', '2025-07-28 10:19:02.106403'),
+(483, 0, 0, 0, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.109269', '2025-07-28 10:19:02.109269'),
+(484, 1, 0, 0, 'o', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.111248', '2025-07-28 10:19:02.111248'),
+(485, 0, 1, 0, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.114117', '2025-07-28 10:19:02.114117'),
+(486, 1, 1, 0, 'ae', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.116179', '2025-07-28 10:19:02.116179'),
+(487, 0, 3, 0, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.119059', '2025-07-28 10:19:02.119059'),
+(488, 1, 3, 0, 'o', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.121160', '2025-07-28 10:19:02.121160'),
+(489, 0, 0, 1, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.125945', '2025-07-28 10:19:02.125945'),
+(490, 1, 0, 1, 'o', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.127950', '2025-07-28 10:19:02.127950'),
+(491, 0, 1, 1, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.129970', '2025-07-28 10:19:02.129970'),
+(492, 1, 1, 1, 'ae', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.132061', '2025-07-28 10:19:02.132061'),
+(493, 0, 3, 1, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.134191', '2025-07-28 10:19:02.134191'),
+(494, 1, 3, 1, 'o', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.136379', '2025-07-28 10:19:02.136379'),
+(495, 0, 0, 2, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.138505', '2025-07-28 10:19:02.138505'),
+(496, 1, 0, 2, 'o', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.140614', '2025-07-28 10:19:02.140614'),
+(497, 0, 1, 2, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.142725', '2025-07-28 10:19:02.142725'),
+(498, 1, 1, 2, 'ās', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.144853', '2025-07-28 10:19:02.144853'),
+(499, 0, 3, 2, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.147070', '2025-07-28 10:19:02.147070'),
+(500, 1, 3, 2, 'o', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.152202', '2025-07-28 10:19:02.152202'),
+(501, 0, 0, 3, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.154318', '2025-07-28 10:19:02.154318'),
+(502, 1, 0, 3, 'ōrum', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.157441', '2025-07-28 10:19:02.157441'),
+(503, 0, 1, 3, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.159445', '2025-07-28 10:19:02.159445'),
+(504, 1, 1, 3, 'ārum', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.162870', '2025-07-28 10:19:02.162870'),
+(505, 0, 3, 3, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.164983', '2025-07-28 10:19:02.164983'),
+(506, 1, 3, 3, 'ōrum', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.168239', '2025-07-28 10:19:02.168239'),
+(507, 0, 0, 4, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.170319', '2025-07-28 10:19:02.170319'),
+(508, 1, 0, 4, 'ōbus', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.173741', '2025-07-28 10:19:02.173741'),
+(509, 0, 1, 4, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.175788', '2025-07-28 10:19:02.175788'),
+(510, 1, 1, 4, 'ābus', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.179016', '2025-07-28 10:19:02.179016'),
+(511, 0, 3, 4, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.181083', '2025-07-28 10:19:02.181083'),
+(512, 1, 3, 4, 'ōbus', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.183860', '2025-07-28 10:19:02.183860'),
+(513, 0, 0, 5, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.185970', '2025-07-28 10:19:02.185970'),
+(514, 1, 0, 5, 'ōbus', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.188728', '2025-07-28 10:19:02.188728'),
+(515, 0, 1, 5, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.190800', '2025-07-28 10:19:02.190800'),
+(516, 1, 1, 5, 'ābus', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.193723', '2025-07-28 10:19:02.193723'),
+(517, 0, 3, 5, '', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.195902', '2025-07-28 10:19:02.195902'),
+(518, 1, 3, 5, 'ōbus', 3, 'duo', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.208720', '2025-07-28 10:19:02.208720'),
+(519, 0, 0, 0, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.219015', '2025-07-28 10:19:02.219015'),
+(520, 1, 0, 0, 'ēs', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.228681', '2025-07-28 10:19:02.228681'),
+(521, 0, 1, 0, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.237434', '2025-07-28 10:19:02.237434'),
+(522, 1, 1, 0, 'ēs', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.242782', '2025-07-28 10:19:02.242782'),
+(523, 0, 3, 0, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.250842', '2025-07-28 10:19:02.250842'),
+(524, 1, 3, 0, 'ia', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.259063', '2025-07-28 10:19:02.259063'),
+(525, 0, 0, 1, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.266544', '2025-07-28 10:19:02.266544'),
+(526, 1, 0, 1, 'ēs', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.273423', '2025-07-28 10:19:02.273423'),
+(527, 0, 1, 1, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.279556', '2025-07-28 10:19:02.279556'),
+(528, 1, 1, 1, 'ēs', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.288503', '2025-07-28 10:19:02.288503'),
+(529, 0, 3, 1, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.290608', '2025-07-28 10:19:02.290608'),
+(530, 1, 3, 1, 'ia', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.293976', '2025-07-28 10:19:02.293976'),
+(531, 0, 0, 2, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.296001', '2025-07-28 10:19:02.296001'),
+(532, 1, 0, 2, 'ēs', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.299565', '2025-07-28 10:19:02.299565'),
+(533, 0, 1, 2, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.301705', '2025-07-28 10:19:02.301705'),
+(534, 1, 1, 2, 'ēs', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.305075', '2025-07-28 10:19:02.305075'),
+(535, 0, 3, 2, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.307085', '2025-07-28 10:19:02.307085'),
+(536, 1, 3, 2, 'ia', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.310125', '2025-07-28 10:19:02.310125'),
+(537, 0, 0, 3, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.312093', '2025-07-28 10:19:02.312093'),
+(538, 1, 0, 3, 'ium', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.314987', '2025-07-28 10:19:02.314987'),
+(539, 0, 1, 3, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.317073', '2025-07-28 10:19:02.317073'),
+(540, 1, 1, 3, 'ium', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.319959', '2025-07-28 10:19:02.319959'),
+(541, 0, 3, 3, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.322044', '2025-07-28 10:19:02.322044'),
+(542, 1, 3, 3, 'ium', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.324827', '2025-07-28 10:19:02.324827'),
+(543, 0, 0, 4, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.326978', '2025-07-28 10:19:02.326978'),
+(544, 1, 0, 4, 'ibus', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.329689', '2025-07-28 10:19:02.329689'),
+(545, 0, 1, 4, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.332229', '2025-07-28 10:19:02.332229'),
+(546, 1, 1, 4, 'ibus', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.334639', '2025-07-28 10:19:02.334639'),
+(547, 0, 3, 4, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.337491', '2025-07-28 10:19:02.337491'),
+(548, 1, 3, 4, 'ibus', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.339870', '2025-07-28 10:19:02.339870'),
+(549, 0, 0, 5, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.342099', '2025-07-28 10:19:02.342099'),
+(550, 1, 0, 5, 'ibus', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.344513', '2025-07-28 10:19:02.344513'),
+(551, 0, 1, 5, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.346993', '2025-07-28 10:19:02.346993'),
+(552, 1, 1, 5, 'ibus', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.349605', '2025-07-28 10:19:02.349605'),
+(553, 0, 3, 5, '', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.351706', '2025-07-28 10:19:02.351706'),
+(554, 1, 3, 5, 'ibus', 3, 'tres', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.354427', '2025-07-28 10:19:02.354427'),
+(555, 0, 0, 0, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.356590', '2025-07-28 10:19:02.356590'),
+(556, 1, 0, 0, 'ia', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.358751', '2025-07-28 10:19:02.358751'),
+(557, 0, 1, 0, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.360910', '2025-07-28 10:19:02.360910'),
+(558, 1, 1, 0, 'ia', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.363055', '2025-07-28 10:19:02.363055'),
+(559, 0, 3, 0, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.365193', '2025-07-28 10:19:02.365193'),
+(560, 1, 3, 0, 'ia', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.367348', '2025-07-28 10:19:02.367348'),
+(561, 0, 0, 1, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.369566', '2025-07-28 10:19:02.369566'),
+(562, 1, 0, 1, 'ia', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.371738', '2025-07-28 10:19:02.371738'),
+(563, 0, 1, 1, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.373964', '2025-07-28 10:19:02.373964'),
+(564, 1, 1, 1, 'ia', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.376170', '2025-07-28 10:19:02.376170'),
+(565, 0, 3, 1, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.378363', '2025-07-28 10:19:02.378363'),
+(566, 1, 3, 1, 'ia', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.380592', '2025-07-28 10:19:02.380592'),
+(567, 0, 0, 2, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.382800', '2025-07-28 10:19:02.382800'),
+(568, 1, 0, 2, 'ia', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.384933', '2025-07-28 10:19:02.384933'),
+(569, 0, 1, 2, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.387113', '2025-07-28 10:19:02.387113'),
+(570, 1, 1, 2, 'ia', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.389210', '2025-07-28 10:19:02.389210'),
+(571, 0, 3, 2, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.391403', '2025-07-28 10:19:02.391403'),
+(572, 1, 3, 2, 'ia', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.393563', '2025-07-28 10:19:02.393563'),
+(573, 0, 0, 3, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.395712', '2025-07-28 10:19:02.395712'),
+(574, 1, 0, 3, 'ium', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.397921', '2025-07-28 10:19:02.397921'),
+(575, 0, 1, 3, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.400115', '2025-07-28 10:19:02.400115'),
+(576, 1, 1, 3, 'ium', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.402249', '2025-07-28 10:19:02.402249'),
+(577, 0, 3, 3, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.404498', '2025-07-28 10:19:02.404498'),
+(578, 1, 3, 3, 'ium', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.406661', '2025-07-28 10:19:02.406661'),
+(579, 0, 0, 4, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.408852', '2025-07-28 10:19:02.408852'),
+(580, 1, 0, 4, 'ibus', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.411023', '2025-07-28 10:19:02.411023'),
+(581, 0, 1, 4, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.413145', '2025-07-28 10:19:02.413145'),
+(582, 1, 1, 4, 'ibus', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.415313', '2025-07-28 10:19:02.415313'),
+(583, 0, 3, 4, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.417520', '2025-07-28 10:19:02.417520'),
+(584, 1, 3, 4, 'ibus', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.419706', '2025-07-28 10:19:02.419706'),
+(585, 0, 0, 5, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.422051', '2025-07-28 10:19:02.422051'),
+(586, 1, 0, 5, 'ibus', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.424479', '2025-07-28 10:19:02.424479'),
+(587, 0, 1, 5, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.426747', '2025-07-28 10:19:02.426747'),
+(588, 1, 1, 5, 'ibus', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.429143', '2025-07-28 10:19:02.429143'),
+(589, 0, 3, 5, 'le', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.431509', '2025-07-28 10:19:02.431509'),
+(590, 1, 3, 5, 'ibus', 3, 'mille', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.437858', '2025-07-28 10:19:02.437858'),
+(591, 0, 3, 0, 'ū', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.446441', '2025-07-28 10:19:02.446441'),
+(592, 1, 3, 0, 'ua', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.450871', '2025-07-28 10:19:02.450871'),
+(593, 0, 0, 0, 'us', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.453207', '2025-07-28 10:19:02.453207'),
+(594, 1, 0, 0, 'ūs', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.456949', '2025-07-28 10:19:02.456949'),
+(595, 0, 1, 0, 'us', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.459566', '2025-07-28 10:19:02.459566'),
+(596, 1, 1, 0, 'ūs', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.463377', '2025-07-28 10:19:02.463377'),
+(597, 0, 3, 1, 'ū', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.465724', '2025-07-28 10:19:02.465724'),
+(598, 1, 3, 1, 'ua', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.469731', '2025-07-28 10:19:02.469731'),
+(599, 0, 0, 1, 'us', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.472015', '2025-07-28 10:19:02.472015'),
+(600, 1, 0, 1, 'ūs', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.475913', '2025-07-28 10:19:02.475913'),
+(601, 0, 1, 1, 'us', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.478124', '2025-07-28 10:19:02.478124'),
+(602, 1, 1, 1, 'ūs', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.481705', '2025-07-28 10:19:02.481705'),
+(603, 0, 3, 2, 'ū', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.484286', '2025-07-28 10:19:02.484286'),
+(604, 1, 3, 2, 'ua', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.487705', '2025-07-28 10:19:02.487705'),
+(605, 0, 0, 2, 'um', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.490242', '2025-07-28 10:19:02.490242'),
+(606, 1, 0, 2, 'ūs', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.493867', '2025-07-28 10:19:02.493867'),
+(607, 0, 1, 2, 'um', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.496077', '2025-07-28 10:19:02.496077'),
+(608, 1, 1, 2, 'ūs', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.499489', '2025-07-28 10:19:02.499489'),
+(609, 0, 3, 3, 'ūs', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.501984', '2025-07-28 10:19:02.501984'),
+(610, 1, 3, 3, 'uum', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.505003', '2025-07-28 10:19:02.505003'),
+(611, 0, 0, 3, 'ūs', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.507177', '2025-07-28 10:19:02.507177'),
+(612, 1, 0, 3, 'uum', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.510124', '2025-07-28 10:19:02.510124'),
+(613, 0, 1, 3, 'ūs', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.512276', '2025-07-28 10:19:02.512276'),
+(614, 1, 1, 3, 'uum', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.514530', '2025-07-28 10:19:02.514530'),
+(615, 0, 3, 4, 'uī', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.517377', '2025-07-28 10:19:02.517377'),
+(616, 1, 3, 4, 'ibus', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.519612', '2025-07-28 10:19:02.519612'),
+(617, 0, 0, 4, 'uī', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.522419', '2025-07-28 10:19:02.522419'),
+(618, 1, 0, 4, 'ibus', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.524602', '2025-07-28 10:19:02.524602'),
+(619, 0, 1, 4, 'uī', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.526813', '2025-07-28 10:19:02.526813'),
+(620, 1, 1, 4, 'ibus', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.529567', '2025-07-28 10:19:02.529567'),
+(621, 0, 3, 5, 'ū', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.531793', '2025-07-28 10:19:02.531793'),
+(622, 1, 3, 5, 'ibus', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.534802', '2025-07-28 10:19:02.534802'),
+(623, 0, 0, 5, 'ū', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.537467', '2025-07-28 10:19:02.537467'),
+(624, 1, 0, 5, 'ibus', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.540193', '2025-07-28 10:19:02.540193'),
+(625, 0, 1, 5, 'ū', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.543564', '2025-07-28 10:19:02.543564'),
+(626, 1, 1, 5, 'ibus', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.545906', '2025-07-28 10:19:02.545906'),
+(627, 0, 3, 6, 'ī', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.548353', '2025-07-28 10:19:02.548353'),
+(628, 1, 3, 6, 'ibus', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.550688', '2025-07-28 10:19:02.550688'),
+(629, 0, 0, 6, 'ī', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.553053', '2025-07-28 10:19:02.553053'),
+(630, 1, 0, 6, 'ibus', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.555310', '2025-07-28 10:19:02.555310'),
+(631, 0, 1, 6, 'ī', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.557580', '2025-07-28 10:19:02.557580'),
+(632, 1, 1, 6, 'ibus', 4, 'fus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.559904', '2025-07-28 10:19:02.559904'),
+(633, 0, 0, 0, 'iēs', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.566429', '2025-07-28 10:19:02.566429'),
+(634, 1, 0, 0, 'iēs', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.568677', '2025-07-28 10:19:02.568677'),
+(635, 0, 1, 0, 'iēs', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.570960', '2025-07-28 10:19:02.570960'),
+(636, 1, 1, 0, 'iēs', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.573109', '2025-07-28 10:19:02.573109'),
+(637, 0, 0, 1, 'iēs', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.575249', '2025-07-28 10:19:02.575249'),
+(638, 1, 0, 1, 'iēs', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.577439', '2025-07-28 10:19:02.577439'),
+(639, 0, 1, 1, 'iēs', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.579628', '2025-07-28 10:19:02.579628'),
+(640, 1, 1, 1, 'iēs', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.581962', '2025-07-28 10:19:02.581962'),
+(641, 0, 0, 2, 'iem', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.584106', '2025-07-28 10:19:02.584106'),
+(642, 1, 0, 2, 'iēs', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.586363', '2025-07-28 10:19:02.586363'),
+(643, 0, 1, 2, 'iem', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.588571', '2025-07-28 10:19:02.588571'),
+(644, 1, 1, 2, 'iēs', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.590780', '2025-07-28 10:19:02.590780'),
+(645, 0, 0, 3, 'iēī', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.593005', '2025-07-28 10:19:02.593005'),
+(646, 1, 0, 3, 'iērum', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.599234', '2025-07-28 10:19:02.599234'),
+(647, 0, 1, 3, 'iēī', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.601617', '2025-07-28 10:19:02.601617'),
+(648, 1, 1, 3, 'iērum', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.605649', '2025-07-28 10:19:02.605649'),
+(649, 0, 0, 4, 'iēī', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.609345', '2025-07-28 10:19:02.609345'),
+(650, 1, 0, 4, 'iēbus', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.611978', '2025-07-28 10:19:02.611978'),
+(651, 0, 1, 4, 'iēī', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.614274', '2025-07-28 10:19:02.614274'),
+(652, 1, 1, 4, 'iēbus', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.618126', '2025-07-28 10:19:02.618126'),
+(653, 0, 0, 5, 'iē', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.621797', '2025-07-28 10:19:02.621797'),
+(654, 1, 0, 5, 'iēbus', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.624392', '2025-07-28 10:19:02.624392'),
+(655, 0, 1, 5, 'iē', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.627968', '2025-07-28 10:19:02.627968'),
+(656, 1, 1, 5, 'iēbus', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.630533', '2025-07-28 10:19:02.630533'),
+(657, 0, 0, 6, 'ē', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.634057', '2025-07-28 10:19:02.634057'),
+(658, 1, 0, 6, '', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.636732', '2025-07-28 10:19:02.636732'),
+(659, 0, 1, 6, 'ē', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.640224', '2025-07-28 10:19:02.640224'),
+(660, 1, 1, 6, '', 5, 'ies', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.642791', '2025-07-28 10:19:02.642791'),
+(661, 0, 0, 0, 'ēs', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.646191', '2025-07-28 10:19:02.646191'),
+(662, 1, 0, 0, 'ēs', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.648779', '2025-07-28 10:19:02.648779'),
+(663, 0, 1, 0, 'ēs', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.652343', '2025-07-28 10:19:02.652343'),
+(664, 1, 1, 0, 'ēs', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.654956', '2025-07-28 10:19:02.654956'),
+(665, 0, 0, 1, 'ēs', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.658283', '2025-07-28 10:19:02.658283'),
+(666, 1, 0, 1, 'ēs', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.660658', '2025-07-28 10:19:02.660658'),
+(667, 0, 1, 1, 'ēs', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.663649', '2025-07-28 10:19:02.663649'),
+(668, 1, 1, 1, 'ēs', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.665912', '2025-07-28 10:19:02.665912'),
+(669, 0, 0, 2, 'em', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.669017', '2025-07-28 10:19:02.669017'),
+(670, 1, 0, 2, 'ēs', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.671296', '2025-07-28 10:19:02.671296'),
+(671, 0, 1, 2, 'em', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.673578', '2025-07-28 10:19:02.673578'),
+(672, 1, 1, 2, 'ēs', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.676519', '2025-07-28 10:19:02.676519'),
+(673, 0, 0, 3, 'eī', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.678715', '2025-07-28 10:19:02.678715'),
+(674, 1, 0, 3, 'ērum', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.681630', '2025-07-28 10:19:02.681630'),
+(675, 0, 1, 3, 'eī', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.683867', '2025-07-28 10:19:02.683867'),
+(676, 1, 1, 3, 'ērum', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.686069', '2025-07-28 10:19:02.686069'),
+(677, 0, 0, 4, 'eī', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.689000', '2025-07-28 10:19:02.689000'),
+(678, 1, 0, 4, 'ēbus', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.691147', '2025-07-28 10:19:02.691147'),
+(679, 0, 1, 4, 'eī', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.693846', '2025-07-28 10:19:02.693846'),
+(680, 1, 1, 4, 'ēbus', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.696797', '2025-07-28 10:19:02.696797'),
+(681, 0, 0, 5, 'ē', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.699010', '2025-07-28 10:19:02.699010'),
+(682, 1, 0, 5, 'ēbus', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.701207', '2025-07-28 10:19:02.701207'),
+(683, 0, 1, 5, 'ē', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.703515', '2025-07-28 10:19:02.703515'),
+(684, 1, 1, 5, 'ēbus', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.705756', '2025-07-28 10:19:02.705756'),
+(685, 0, 0, 6, 'ē', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.708003', '2025-07-28 10:19:02.708003'),
+(686, 1, 0, 6, '', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.710186', '2025-07-28 10:19:02.710186'),
+(687, 0, 1, 6, 'ē', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.712347', '2025-07-28 10:19:02.712347'),
+(688, 1, 1, 6, '', 5, 'es', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:02.714525', '2025-07-28 10:19:02.714525'),
+(689, 0, NULL, NULL, 'ō', NULL, NULL, 0, 0, 0, 1, 1, '2025-07-28 10:19:02.769013', '2025-07-28 10:19:02.769013'),
+(690, 1, NULL, NULL, 'āmus', NULL, NULL, 0, 0, 0, 1, 1, '2025-07-28 10:19:02.771341', '2025-07-28 10:19:02.771341'),
+(691, 0, NULL, NULL, 'ās', NULL, NULL, 0, 0, 0, 2, 1, '2025-07-28 10:19:02.773521', '2025-07-28 10:19:02.773521'),
+(692, 1, NULL, NULL, 'ātis', NULL, NULL, 0, 0, 0, 2, 1, '2025-07-28 10:19:02.775647', '2025-07-28 10:19:02.775647'),
+(693, 0, NULL, NULL, 'at', NULL, NULL, 0, 0, 0, 3, 1, '2025-07-28 10:19:02.777826', '2025-07-28 10:19:02.777826'),
+(694, 1, NULL, NULL, 'ant', NULL, NULL, 0, 0, 0, 3, 1, '2025-07-28 10:19:02.780008', '2025-07-28 10:19:02.780008'),
+(695, 0, NULL, NULL, 'ābam', NULL, NULL, 1, 0, 0, 1, 1, '2025-07-28 10:19:02.788712', '2025-07-28 10:19:02.788712'),
+(696, 1, NULL, NULL, 'ābāmus', NULL, NULL, 1, 0, 0, 1, 1, '2025-07-28 10:19:02.792049', '2025-07-28 10:19:02.792049'),
+(697, 0, NULL, NULL, 'ābās', NULL, NULL, 1, 0, 0, 2, 1, '2025-07-28 10:19:02.794175', '2025-07-28 10:19:02.794175'),
+(698, 1, NULL, NULL, 'ābātis', NULL, NULL, 1, 0, 0, 2, 1, '2025-07-28 10:19:02.797496', '2025-07-28 10:19:02.797496'),
+(699, 0, NULL, NULL, 'ābat', NULL, NULL, 1, 0, 0, 3, 1, '2025-07-28 10:19:02.799602', '2025-07-28 10:19:02.799602'),
+(700, 1, NULL, NULL, 'ābant', NULL, NULL, 1, 0, 0, 3, 1, '2025-07-28 10:19:02.802891', '2025-07-28 10:19:02.802891'),
+(701, 0, NULL, NULL, 'ī', NULL, NULL, 2, 0, 0, 1, 1, '2025-07-28 10:19:02.804942', '2025-07-28 10:19:02.804942'),
+(702, 1, NULL, NULL, 'imus', NULL, NULL, 2, 0, 0, 1, 1, '2025-07-28 10:19:02.808177', '2025-07-28 10:19:02.808177'),
+(703, 0, NULL, NULL, 'istī', NULL, NULL, 2, 0, 0, 2, 1, '2025-07-28 10:19:02.810147', '2025-07-28 10:19:02.810147'),
+(704, 1, NULL, NULL, 'istis', NULL, NULL, 2, 0, 0, 2, 1, '2025-07-28 10:19:02.813435', '2025-07-28 10:19:02.813435'),
+(705, 0, NULL, NULL, 'it', NULL, NULL, 2, 0, 0, 3, 1, '2025-07-28 10:19:02.815481', '2025-07-28 10:19:02.815481'),
+(706, 1, NULL, NULL, 'ērunt', NULL, NULL, 2, 0, 0, 3, 1, '2025-07-28 10:19:02.819037', '2025-07-28 10:19:02.819037'),
+(707, 0, NULL, NULL, 'eram', NULL, NULL, 3, 0, 0, 1, 1, '2025-07-28 10:19:02.821198', '2025-07-28 10:19:02.821198'),
+(708, 1, NULL, NULL, 'erāmus', NULL, NULL, 3, 0, 0, 1, 1, '2025-07-28 10:19:02.824798', '2025-07-28 10:19:02.824798'),
+(709, 0, NULL, NULL, 'erās', NULL, NULL, 3, 0, 0, 2, 1, '2025-07-28 10:19:02.826889', '2025-07-28 10:19:02.826889'),
+(710, 1, NULL, NULL, 'erātis', NULL, NULL, 3, 0, 0, 2, 1, '2025-07-28 10:19:02.830365', '2025-07-28 10:19:02.830365'),
+(711, 0, NULL, NULL, 'erat', NULL, NULL, 3, 0, 0, 3, 1, '2025-07-28 10:19:02.832469', '2025-07-28 10:19:02.832469'),
+(712, 1, NULL, NULL, 'erant', NULL, NULL, 3, 0, 0, 3, 1, '2025-07-28 10:19:02.835905', '2025-07-28 10:19:02.835905'),
+(713, 0, NULL, NULL, 'ābō', NULL, NULL, 4, 0, 0, 1, 1, '2025-07-28 10:19:02.838045', '2025-07-28 10:19:02.838045'),
+(714, 1, NULL, NULL, 'ābimus', NULL, NULL, 4, 0, 0, 1, 1, '2025-07-28 10:19:02.841247', '2025-07-28 10:19:02.841247'),
+(715, 0, NULL, NULL, 'ābis', NULL, NULL, 4, 0, 0, 2, 1, '2025-07-28 10:19:02.843413', '2025-07-28 10:19:02.843413'),
+(716, 1, NULL, NULL, 'ābitis', NULL, NULL, 4, 0, 0, 2, 1, '2025-07-28 10:19:02.846417', '2025-07-28 10:19:02.846417'),
+(717, 0, NULL, NULL, 'ābit', NULL, NULL, 4, 0, 0, 3, 1, '2025-07-28 10:19:02.848612', '2025-07-28 10:19:02.848612'),
+(718, 1, NULL, NULL, 'ābunt', NULL, NULL, 4, 0, 0, 3, 1, '2025-07-28 10:19:02.853353', '2025-07-28 10:19:02.853353'),
+(719, 0, NULL, NULL, 'erō', NULL, NULL, 5, 0, 0, 1, 1, '2025-07-28 10:19:02.855641', '2025-07-28 10:19:02.855641'),
+(720, 1, NULL, NULL, 'erimus', NULL, NULL, 5, 0, 0, 1, 1, '2025-07-28 10:19:02.858559', '2025-07-28 10:19:02.858559'),
+(721, 0, NULL, NULL, 'eris', NULL, NULL, 5, 0, 0, 2, 1, '2025-07-28 10:19:02.860718', '2025-07-28 10:19:02.860718'),
+(722, 1, NULL, NULL, 'eritis', NULL, NULL, 5, 0, 0, 2, 1, '2025-07-28 10:19:02.862941', '2025-07-28 10:19:02.862941'),
+(723, 0, NULL, NULL, 'erit', NULL, NULL, 5, 0, 0, 3, 1, '2025-07-28 10:19:02.865834', '2025-07-28 10:19:02.865834'),
+(724, 1, NULL, NULL, 'erint', NULL, NULL, 5, 0, 0, 3, 1, '2025-07-28 10:19:02.868052', '2025-07-28 10:19:02.868052'),
+(725, 0, NULL, NULL, 'or', NULL, NULL, 0, 0, 1, 1, 1, '2025-07-28 10:19:02.871070', '2025-07-28 10:19:02.871070'),
+(726, 1, NULL, NULL, 'āmur', NULL, NULL, 0, 0, 1, 1, 1, '2025-07-28 10:19:02.873225', '2025-07-28 10:19:02.873225'),
+(727, 0, NULL, NULL, 'āris', NULL, NULL, 0, 0, 1, 2, 1, '2025-07-28 10:19:02.876818', '2025-07-28 10:19:02.876818'),
+(728, 1, NULL, NULL, 'āminī', NULL, NULL, 0, 0, 1, 2, 1, '2025-07-28 10:19:02.878976', '2025-07-28 10:19:02.878976'),
+(729, 0, NULL, NULL, 'ātur', NULL, NULL, 0, 0, 1, 3, 1, '2025-07-28 10:19:02.881085', '2025-07-28 10:19:02.881085'),
+(730, 1, NULL, NULL, 'antur', NULL, NULL, 0, 0, 1, 3, 1, '2025-07-28 10:19:02.883280', '2025-07-28 10:19:02.883280'),
+(731, 0, NULL, NULL, 'ābar', NULL, NULL, 1, 0, 1, 1, 1, '2025-07-28 10:19:02.885470', '2025-07-28 10:19:02.885470'),
+(732, 1, NULL, NULL, 'ābāmur', NULL, NULL, 1, 0, 1, 1, 1, '2025-07-28 10:19:02.887718', '2025-07-28 10:19:02.887718'),
+(733, 0, NULL, NULL, 'ābāris', NULL, NULL, 1, 0, 1, 2, 1, '2025-07-28 10:19:02.889956', '2025-07-28 10:19:02.889956'),
+(734, 1, NULL, NULL, 'ābāminī', NULL, NULL, 1, 0, 1, 2, 1, '2025-07-28 10:19:02.892139', '2025-07-28 10:19:02.892139'),
+(735, 0, NULL, NULL, 'ābātur', NULL, NULL, 1, 0, 1, 3, 1, '2025-07-28 10:19:02.894358', '2025-07-28 10:19:02.894358'),
+(736, 1, NULL, NULL, 'ābantur', NULL, NULL, 1, 0, 1, 3, 1, '2025-07-28 10:19:02.896559', '2025-07-28 10:19:02.896559'),
+(737, 0, NULL, NULL, 'ābor', NULL, NULL, 4, 0, 1, 1, 1, '2025-07-28 10:19:02.898720', '2025-07-28 10:19:02.898720'),
+(738, 1, NULL, NULL, 'ābimur', NULL, NULL, 4, 0, 1, 1, 1, '2025-07-28 10:19:02.900905', '2025-07-28 10:19:02.900905'),
+(739, 0, NULL, NULL, 'āberis', NULL, NULL, 4, 0, 1, 2, 1, '2025-07-28 10:19:02.903114', '2025-07-28 10:19:02.903114'),
+(740, 1, NULL, NULL, 'ābiminī', NULL, NULL, 4, 0, 1, 2, 1, '2025-07-28 10:19:02.905397', '2025-07-28 10:19:02.905397'),
+(741, 0, NULL, NULL, 'ābitur', NULL, NULL, 4, 0, 1, 3, 1, '2025-07-28 10:19:02.907670', '2025-07-28 10:19:02.907670'),
+(742, 1, NULL, NULL, 'ābuntur', NULL, NULL, 4, 0, 1, 3, 1, '2025-07-28 10:19:02.909917', '2025-07-28 10:19:02.909917'),
+(743, 0, NULL, NULL, 'sum', NULL, NULL, 2, 0, 1, 1, 1, '2025-07-28 10:19:02.912130', '2025-07-28 10:19:02.912130'),
+(744, 1, NULL, NULL, 'sumus', NULL, NULL, 2, 0, 1, 1, 1, '2025-07-28 10:19:02.914314', '2025-07-28 10:19:02.914314'),
+(745, 0, NULL, NULL, 'es', NULL, NULL, 2, 0, 1, 2, 1, '2025-07-28 10:19:02.921550', '2025-07-28 10:19:02.921550'),
+(746, 1, NULL, NULL, 'estis', NULL, NULL, 2, 0, 1, 2, 1, '2025-07-28 10:19:02.924020', '2025-07-28 10:19:02.924020'),
+(747, 0, NULL, NULL, 'est', NULL, NULL, 2, 0, 1, 3, 1, '2025-07-28 10:19:02.928365', '2025-07-28 10:19:02.928365'),
+(748, 1, NULL, NULL, 'sunt', NULL, NULL, 2, 0, 1, 3, 1, '2025-07-28 10:19:02.930772', '2025-07-28 10:19:02.930772'),
+(749, 0, NULL, NULL, 'eram', NULL, NULL, 3, 0, 1, 1, 1, '2025-07-28 10:19:02.934876', '2025-07-28 10:19:02.934876'),
+(750, 1, NULL, NULL, 'erāmus', NULL, NULL, 3, 0, 1, 1, 1, '2025-07-28 10:19:02.937240', '2025-07-28 10:19:02.937240'),
+(751, 0, NULL, NULL, 'erās', NULL, NULL, 3, 0, 1, 2, 1, '2025-07-28 10:19:02.941027', '2025-07-28 10:19:02.941027'),
+(752, 1, NULL, NULL, 'erātis', NULL, NULL, 3, 0, 1, 2, 1, '2025-07-28 10:19:02.943327', '2025-07-28 10:19:02.943327'),
+(753, 0, NULL, NULL, 'erat', NULL, NULL, 3, 0, 1, 3, 1, '2025-07-28 10:19:02.946998', '2025-07-28 10:19:02.946998'),
+(754, 1, NULL, NULL, 'erant', NULL, NULL, 3, 0, 1, 3, 1, '2025-07-28 10:19:02.950408', '2025-07-28 10:19:02.950408'),
+(755, 0, NULL, NULL, 'erō', NULL, NULL, 5, 0, 1, 1, 1, '2025-07-28 10:19:02.953017', '2025-07-28 10:19:02.953017'),
+(756, 1, NULL, NULL, 'erimus', NULL, NULL, 5, 0, 1, 1, 1, '2025-07-28 10:19:02.956459', '2025-07-28 10:19:02.956459'),
+(757, 0, NULL, NULL, 'eris', NULL, NULL, 5, 0, 1, 2, 1, '2025-07-28 10:19:02.959074', '2025-07-28 10:19:02.959074'),
+(758, 1, NULL, NULL, 'eritis', NULL, NULL, 5, 0, 1, 2, 1, '2025-07-28 10:19:02.962414', '2025-07-28 10:19:02.962414'),
+(759, 0, NULL, NULL, 'erit', NULL, NULL, 5, 0, 1, 3, 1, '2025-07-28 10:19:02.964970', '2025-07-28 10:19:02.964970'),
+(760, 1, NULL, NULL, 'erunt', NULL, NULL, 5, 0, 1, 3, 1, '2025-07-28 10:19:02.968360', '2025-07-28 10:19:02.968360'),
+(761, 0, NULL, NULL, 'em', NULL, NULL, 0, 1, 0, 1, 1, '2025-07-28 10:19:02.971089', '2025-07-28 10:19:02.971089'),
+(762, 1, NULL, NULL, 'ēmus', NULL, NULL, 0, 1, 0, 1, 1, '2025-07-28 10:19:02.974432', '2025-07-28 10:19:02.974432'),
+(763, 0, NULL, NULL, 'ēs', NULL, NULL, 0, 1, 0, 2, 1, '2025-07-28 10:19:02.976664', '2025-07-28 10:19:02.976664'),
+(764, 1, NULL, NULL, 'ētis', NULL, NULL, 0, 1, 0, 2, 1, '2025-07-28 10:19:02.979871', '2025-07-28 10:19:02.979871'),
+(765, 0, NULL, NULL, 'et', NULL, NULL, 0, 1, 0, 3, 1, '2025-07-28 10:19:02.982217', '2025-07-28 10:19:02.982217'),
+(766, 1, NULL, NULL, 'ent', NULL, NULL, 0, 1, 0, 3, 1, '2025-07-28 10:19:02.985357', '2025-07-28 10:19:02.985357'),
+(767, 0, NULL, NULL, 'ārem', NULL, NULL, 1, 1, 0, 1, 1, '2025-07-28 10:19:02.987734', '2025-07-28 10:19:02.987734'),
+(768, 1, NULL, NULL, 'ārēmus', NULL, NULL, 1, 1, 0, 1, 1, '2025-07-28 10:19:02.990912', '2025-07-28 10:19:02.990912'),
+(769, 0, NULL, NULL, 'ārēs', NULL, NULL, 1, 1, 0, 2, 1, '2025-07-28 10:19:02.993158', '2025-07-28 10:19:02.993158'),
+(770, 1, NULL, NULL, 'ārētis', NULL, NULL, 1, 1, 0, 2, 1, '2025-07-28 10:19:02.996109', '2025-07-28 10:19:02.996109'),
+(771, 0, NULL, NULL, 'āret', NULL, NULL, 1, 1, 0, 3, 1, '2025-07-28 10:19:02.998416', '2025-07-28 10:19:02.998416'),
+(772, 1, NULL, NULL, 'ārent', NULL, NULL, 1, 1, 0, 3, 1, '2025-07-28 10:19:03.000757', '2025-07-28 10:19:03.000757'),
+(773, 0, NULL, NULL, 'erim', NULL, NULL, 2, 1, 0, 1, 1, '2025-07-28 10:19:03.003612', '2025-07-28 10:19:03.003612'),
+(774, 1, NULL, NULL, 'erīmus', NULL, NULL, 2, 1, 0, 1, 1, '2025-07-28 10:19:03.005934', '2025-07-28 10:19:03.005934'),
+(775, 0, NULL, NULL, 'erīs', NULL, NULL, 2, 1, 0, 2, 1, '2025-07-28 10:19:03.010076', '2025-07-28 10:19:03.010076'),
+(776, 1, NULL, NULL, 'erītis', NULL, NULL, 2, 1, 0, 2, 1, '2025-07-28 10:19:03.012359', '2025-07-28 10:19:03.012359'),
+(777, 0, NULL, NULL, 'erit', NULL, NULL, 2, 1, 0, 3, 1, '2025-07-28 10:19:03.014576', '2025-07-28 10:19:03.014576'),
+(778, 1, NULL, NULL, 'erint', NULL, NULL, 2, 1, 0, 3, 1, '2025-07-28 10:19:03.016905', '2025-07-28 10:19:03.016905'),
+(779, 0, NULL, NULL, 'issem', NULL, NULL, 3, 1, 0, 1, 1, '2025-07-28 10:19:03.019173', '2025-07-28 10:19:03.019173'),
+(780, 1, NULL, NULL, 'issēmus', NULL, NULL, 3, 1, 0, 1, 1, '2025-07-28 10:19:03.021511', '2025-07-28 10:19:03.021511'),
+(781, 0, NULL, NULL, 'issēs', NULL, NULL, 3, 1, 0, 2, 1, '2025-07-28 10:19:03.023768', '2025-07-28 10:19:03.023768'),
+(782, 1, NULL, NULL, 'issētis', NULL, NULL, 3, 1, 0, 2, 1, '2025-07-28 10:19:03.026020', '2025-07-28 10:19:03.026020'),
+(783, 0, NULL, NULL, 'isset', NULL, NULL, 3, 1, 0, 3, 1, '2025-07-28 10:19:03.028248', '2025-07-28 10:19:03.028248'),
+(784, 1, NULL, NULL, 'issent', NULL, NULL, 3, 1, 0, 3, 1, '2025-07-28 10:19:03.030439', '2025-07-28 10:19:03.030439'),
+(785, 0, NULL, NULL, 'er', NULL, NULL, 0, 1, 1, 1, 1, '2025-07-28 10:19:03.032712', '2025-07-28 10:19:03.032712'),
+(786, 1, NULL, NULL, 'ēmur', NULL, NULL, 0, 1, 1, 1, 1, '2025-07-28 10:19:03.034950', '2025-07-28 10:19:03.034950'),
+(787, 0, NULL, NULL, 'ēris', NULL, NULL, 0, 1, 1, 2, 1, '2025-07-28 10:19:03.037223', '2025-07-28 10:19:03.037223'),
+(788, 1, NULL, NULL, 'ēminī', NULL, NULL, 0, 1, 1, 2, 1, '2025-07-28 10:19:03.039449', '2025-07-28 10:19:03.039449'),
+(789, 0, NULL, NULL, 'ētur', NULL, NULL, 0, 1, 1, 3, 1, '2025-07-28 10:19:03.041711', '2025-07-28 10:19:03.041711'),
+(790, 1, NULL, NULL, 'entur', NULL, NULL, 0, 1, 1, 3, 1, '2025-07-28 10:19:03.043912', '2025-07-28 10:19:03.043912'),
+(791, 0, NULL, NULL, 'ārer', NULL, NULL, 1, 1, 1, 1, 1, '2025-07-28 10:19:03.046073', '2025-07-28 10:19:03.046073'),
+(792, 1, NULL, NULL, 'ārēmur', NULL, NULL, 1, 1, 1, 1, 1, '2025-07-28 10:19:03.053012', '2025-07-28 10:19:03.053012'),
+(793, 0, NULL, NULL, 'ārēris', NULL, NULL, 1, 1, 1, 2, 1, '2025-07-28 10:19:03.055450', '2025-07-28 10:19:03.055450'),
+(794, 1, NULL, NULL, 'ārēminī', NULL, NULL, 1, 1, 1, 2, 1, '2025-07-28 10:19:03.059176', '2025-07-28 10:19:03.059176'),
+(795, 0, NULL, NULL, 'ārētur', NULL, NULL, 1, 1, 1, 3, 1, '2025-07-28 10:19:03.061492', '2025-07-28 10:19:03.061492'),
+(796, 1, NULL, NULL, 'ārentur', NULL, NULL, 1, 1, 1, 3, 1, '2025-07-28 10:19:03.065213', '2025-07-28 10:19:03.065213'),
+(797, 0, NULL, NULL, 'sim', NULL, NULL, 2, 1, 1, 1, 1, '2025-07-28 10:19:03.067581', '2025-07-28 10:19:03.067581'),
+(798, 1, NULL, NULL, 'sīmus', NULL, NULL, 2, 1, 1, 1, 1, '2025-07-28 10:19:03.071367', '2025-07-28 10:19:03.071367'),
+(799, 0, NULL, NULL, 'sīs', NULL, NULL, 2, 1, 1, 2, 1, '2025-07-28 10:19:03.073731', '2025-07-28 10:19:03.073731'),
+(800, 1, NULL, NULL, 'sītis', NULL, NULL, 2, 1, 1, 2, 1, '2025-07-28 10:19:03.077297', '2025-07-28 10:19:03.077297'),
+(801, 0, NULL, NULL, 'sit', NULL, NULL, 2, 1, 1, 3, 1, '2025-07-28 10:19:03.080683', '2025-07-28 10:19:03.080683'),
+(802, 1, NULL, NULL, 'sint', NULL, NULL, 2, 1, 1, 3, 1, '2025-07-28 10:19:03.083258', '2025-07-28 10:19:03.083258'),
+(803, 0, NULL, NULL, 'essem', NULL, NULL, 3, 1, 1, 1, 1, '2025-07-28 10:19:03.086579', '2025-07-28 10:19:03.086579'),
+(804, 1, NULL, NULL, 'essēmus', NULL, NULL, 3, 1, 1, 1, 1, '2025-07-28 10:19:03.089230', '2025-07-28 10:19:03.089230'),
+(805, 0, NULL, NULL, 'essēs', NULL, NULL, 3, 1, 1, 2, 1, '2025-07-28 10:19:03.092515', '2025-07-28 10:19:03.092515'),
+(806, 1, NULL, NULL, 'essētis', NULL, NULL, 3, 1, 1, 2, 1, '2025-07-28 10:19:03.095017', '2025-07-28 10:19:03.095017'),
+(807, 0, NULL, NULL, 'esset', NULL, NULL, 3, 1, 1, 3, 1, '2025-07-28 10:19:03.098577', '2025-07-28 10:19:03.098577'),
+(808, 1, NULL, NULL, 'essent', NULL, NULL, 3, 1, 1, 3, 1, '2025-07-28 10:19:03.100867', '2025-07-28 10:19:03.100867'),
+(809, 0, NULL, NULL, 'ā', NULL, NULL, 0, 2, 0, 2, 1, '2025-07-28 10:19:03.104098', '2025-07-28 10:19:03.104098'),
+(810, 1, NULL, NULL, 'āte', NULL, NULL, 0, 2, 0, 2, 1, '2025-07-28 10:19:03.106355', '2025-07-28 10:19:03.106355'),
+(811, 0, NULL, NULL, 'ātō', NULL, NULL, 4, 2, 0, 2, 1, '2025-07-28 10:19:03.109447', '2025-07-28 10:19:03.109447'),
+(812, 1, NULL, NULL, 'ātōte', NULL, NULL, 4, 2, 0, 2, 1, '2025-07-28 10:19:03.111669', '2025-07-28 10:19:03.111669'),
+(813, 0, NULL, NULL, 'ātō', NULL, NULL, 4, 2, 0, 3, 1, '2025-07-28 10:19:03.114677', '2025-07-28 10:19:03.114677'),
+(814, 1, NULL, NULL, 'antō', NULL, NULL, 4, 2, 0, 3, 1, '2025-07-28 10:19:03.117044', '2025-07-28 10:19:03.117044'),
+(815, 0, NULL, NULL, 'āre', NULL, NULL, 0, 2, 1, 2, 1, '2025-07-28 10:19:03.120447', '2025-07-28 10:19:03.120447'),
+(816, 1, NULL, NULL, 'āminī', NULL, NULL, 0, 2, 1, 2, 1, '2025-07-28 10:19:03.122866', '2025-07-28 10:19:03.122866'),
+(817, 0, NULL, NULL, 'ātor', NULL, NULL, 4, 2, 1, 2, 1, '2025-07-28 10:19:03.126111', '2025-07-28 10:19:03.126111'),
+(818, 1, NULL, NULL, NULL, NULL, NULL, 4, 2, 1, 2, 1, '2025-07-28 10:19:03.130949', '2025-07-28 10:19:03.130949'),
+(819, 0, NULL, NULL, 'ātor', NULL, NULL, 4, 2, 1, 3, 1, '2025-07-28 10:19:03.133409', '2025-07-28 10:19:03.133409'),
+(820, 1, NULL, NULL, 'antor', NULL, NULL, 4, 2, 1, 3, 1, '2025-07-28 10:19:03.139199', '2025-07-28 10:19:03.139199'),
+(821, 0, NULL, NULL, 'āre', NULL, NULL, 0, 6, 0, 1, 1, '2025-07-28 10:19:03.141788', '2025-07-28 10:19:03.141788'),
+(822, 1, NULL, NULL, NULL, NULL, NULL, 0, 6, 0, 1, 1, '2025-07-28 10:19:03.144173', '2025-07-28 10:19:03.144173'),
+(823, 0, NULL, NULL, 'isse', NULL, NULL, 2, 6, 0, 1, 1, '2025-07-28 10:19:03.146524', '2025-07-28 10:19:03.146524'),
+(824, 1, NULL, NULL, NULL, NULL, NULL, 2, 6, 0, 1, 1, '2025-07-28 10:19:03.148961', '2025-07-28 10:19:03.148961'),
+(825, 0, NULL, NULL, 'esse', NULL, NULL, 4, 6, 0, 1, 1, '2025-07-28 10:19:03.151270', '2025-07-28 10:19:03.151270'),
+(826, 1, NULL, NULL, NULL, NULL, NULL, 4, 6, 0, 1, 1, '2025-07-28 10:19:03.153587', '2025-07-28 10:19:03.153587'),
+(827, 0, NULL, NULL, 'ārī', NULL, NULL, 0, 6, 1, 1, 1, '2025-07-28 10:19:03.155989', '2025-07-28 10:19:03.155989'),
+(828, 1, NULL, NULL, NULL, NULL, NULL, 0, 6, 1, 1, 1, '2025-07-28 10:19:03.158311', '2025-07-28 10:19:03.158311'),
+(829, 0, NULL, NULL, 'esse', NULL, NULL, 2, 6, 1, 1, 1, '2025-07-28 10:19:03.160663', '2025-07-28 10:19:03.160663'),
+(830, 1, NULL, NULL, NULL, NULL, NULL, 2, 6, 1, 1, 1, '2025-07-28 10:19:03.163058', '2025-07-28 10:19:03.163058'),
+(831, 0, NULL, NULL, 'īrī', NULL, NULL, 4, 6, 1, 1, 1, '2025-07-28 10:19:03.165402', '2025-07-28 10:19:03.165402'),
+(832, 1, NULL, NULL, NULL, NULL, NULL, 4, 6, 1, 1, 1, '2025-07-28 10:19:03.167735', '2025-07-28 10:19:03.167735'),
+(833, 0, NULL, NULL, 'āns', NULL, NULL, 0, 8, 0, 1, 1, '2025-07-28 10:19:03.170038', '2025-07-28 10:19:03.170038'),
+(834, 1, NULL, NULL, NULL, NULL, NULL, 0, 8, 0, 1, 1, '2025-07-28 10:19:03.172360', '2025-07-28 10:19:03.172360'),
+(835, 0, NULL, NULL, 'ūrus', NULL, NULL, 4, 8, 0, 1, 1, '2025-07-28 10:19:03.180995', '2025-07-28 10:19:03.180995'),
+(836, 1, NULL, NULL, NULL, NULL, NULL, 4, 8, 0, 1, 1, '2025-07-28 10:19:03.184268', '2025-07-28 10:19:03.184268'),
+(837, 0, NULL, NULL, 'us', NULL, NULL, 2, 8, 1, 1, 1, '2025-07-28 10:19:03.188921', '2025-07-28 10:19:03.188921'),
+(838, 1, NULL, NULL, NULL, NULL, NULL, 2, 8, 1, 1, 1, '2025-07-28 10:19:03.191515', '2025-07-28 10:19:03.191515'),
+(839, 0, NULL, NULL, 'andus', NULL, NULL, 4, 8, 1, 1, 1, '2025-07-28 10:19:03.195837', '2025-07-28 10:19:03.195837'),
+(840, 1, NULL, NULL, NULL, NULL, NULL, 4, 8, 1, 1, 1, '2025-07-28 10:19:03.198213', '2025-07-28 10:19:03.198213'),
+(841, 0, NULL, NULL, 'eō', NULL, NULL, 0, 0, 0, 1, 2, '2025-07-28 10:19:03.209339', '2025-07-28 10:19:03.209339'),
+(842, 1, NULL, NULL, 'ēmus', NULL, NULL, 0, 0, 0, 1, 2, '2025-07-28 10:19:03.211888', '2025-07-28 10:19:03.211888'),
+(843, 0, NULL, NULL, 'ēs', NULL, NULL, 0, 0, 0, 2, 2, '2025-07-28 10:19:03.216012', '2025-07-28 10:19:03.216012'),
+(844, 1, NULL, NULL, 'ētis', NULL, NULL, 0, 0, 0, 2, 2, '2025-07-28 10:19:03.218387', '2025-07-28 10:19:03.218387'),
+(845, 0, NULL, NULL, 'et', NULL, NULL, 0, 0, 0, 3, 2, '2025-07-28 10:19:03.222530', '2025-07-28 10:19:03.222530'),
+(846, 1, NULL, NULL, 'ent', NULL, NULL, 0, 0, 0, 3, 2, '2025-07-28 10:19:03.224851', '2025-07-28 10:19:03.224851'),
+(847, 0, NULL, NULL, 'ēbam', NULL, NULL, 1, 0, 0, 1, 2, '2025-07-28 10:19:03.228305', '2025-07-28 10:19:03.228305'),
+(848, 1, NULL, NULL, 'ēbāmus', NULL, NULL, 1, 0, 0, 1, 2, '2025-07-28 10:19:03.230583', '2025-07-28 10:19:03.230583'),
+(849, 0, NULL, NULL, 'ēbās', NULL, NULL, 1, 0, 0, 2, 2, '2025-07-28 10:19:03.233748', '2025-07-28 10:19:03.233748'),
+(850, 1, NULL, NULL, 'ēbātis', NULL, NULL, 1, 0, 0, 2, 2, '2025-07-28 10:19:03.236068', '2025-07-28 10:19:03.236068'),
+(851, 0, NULL, NULL, 'ēbat', NULL, NULL, 1, 0, 0, 3, 2, '2025-07-28 10:19:03.239213', '2025-07-28 10:19:03.239213'),
+(852, 1, NULL, NULL, 'ēbant', NULL, NULL, 1, 0, 0, 3, 2, '2025-07-28 10:19:03.241504', '2025-07-28 10:19:03.241504'),
+(853, 0, NULL, NULL, 'ī', NULL, NULL, 2, 0, 0, 1, 2, '2025-07-28 10:19:03.244550', '2025-07-28 10:19:03.244550'),
+(854, 1, NULL, NULL, 'imus', NULL, NULL, 2, 0, 0, 1, 2, '2025-07-28 10:19:03.247670', '2025-07-28 10:19:03.247670'),
+(855, 0, NULL, NULL, 'istī', NULL, NULL, 2, 0, 0, 2, 2, '2025-07-28 10:19:03.249996', '2025-07-28 10:19:03.249996'),
+(856, 1, NULL, NULL, 'istis', NULL, NULL, 2, 0, 0, 2, 2, '2025-07-28 10:19:03.253221', '2025-07-28 10:19:03.253221'),
+(857, 0, NULL, NULL, 'it', NULL, NULL, 2, 0, 0, 3, 2, '2025-07-28 10:19:03.255587', '2025-07-28 10:19:03.255587'),
+(858, 1, NULL, NULL, 'ērunt', NULL, NULL, 2, 0, 0, 3, 2, '2025-07-28 10:19:03.261027', '2025-07-28 10:19:03.261027'),
+(859, 0, NULL, NULL, 'eram', NULL, NULL, 3, 0, 0, 1, 2, '2025-07-28 10:19:03.263263', '2025-07-28 10:19:03.263263'),
+(860, 1, NULL, NULL, 'erāmus', NULL, NULL, 3, 0, 0, 1, 2, '2025-07-28 10:19:03.265582', '2025-07-28 10:19:03.265582'),
+(861, 0, NULL, NULL, 'erās', NULL, NULL, 3, 0, 0, 2, 2, '2025-07-28 10:19:03.267971', '2025-07-28 10:19:03.267971'),
+(862, 1, NULL, NULL, 'erātis', NULL, NULL, 3, 0, 0, 2, 2, '2025-07-28 10:19:03.270272', '2025-07-28 10:19:03.270272'),
+(863, 0, NULL, NULL, 'erat', NULL, NULL, 3, 0, 0, 3, 2, '2025-07-28 10:19:03.272616', '2025-07-28 10:19:03.272616'),
+(864, 1, NULL, NULL, 'erant', NULL, NULL, 3, 0, 0, 3, 2, '2025-07-28 10:19:03.274946', '2025-07-28 10:19:03.274946'),
+(865, 0, NULL, NULL, 'ēbō', NULL, NULL, 4, 0, 0, 1, 2, '2025-07-28 10:19:03.277166', '2025-07-28 10:19:03.277166'),
+(866, 1, NULL, NULL, 'ēbimus', NULL, NULL, 4, 0, 0, 1, 2, '2025-07-28 10:19:03.279402', '2025-07-28 10:19:03.279402'),
+(867, 0, NULL, NULL, 'ēbis', NULL, NULL, 4, 0, 0, 2, 2, '2025-07-28 10:19:03.281713', '2025-07-28 10:19:03.281713'),
+(868, 1, NULL, NULL, 'ēbitis', NULL, NULL, 4, 0, 0, 2, 2, '2025-07-28 10:19:03.284025', '2025-07-28 10:19:03.284025'),
+(869, 0, NULL, NULL, 'ēbit', NULL, NULL, 4, 0, 0, 3, 2, '2025-07-28 10:19:03.286319', '2025-07-28 10:19:03.286319'),
+(870, 1, NULL, NULL, 'ēbunt', NULL, NULL, 4, 0, 0, 3, 2, '2025-07-28 10:19:03.288660', '2025-07-28 10:19:03.288660'),
+(871, 0, NULL, NULL, 'erō', NULL, NULL, 5, 0, 0, 1, 2, '2025-07-28 10:19:03.290973', '2025-07-28 10:19:03.290973'),
+(872, 1, NULL, NULL, 'erimus', NULL, NULL, 5, 0, 0, 1, 2, '2025-07-28 10:19:03.293214', '2025-07-28 10:19:03.293214'),
+(873, 0, NULL, NULL, 'eris', NULL, NULL, 5, 0, 0, 2, 2, '2025-07-28 10:19:03.298876', '2025-07-28 10:19:03.298876'),
+(874, 1, NULL, NULL, 'eritis', NULL, NULL, 5, 0, 0, 2, 2, '2025-07-28 10:19:03.301514', '2025-07-28 10:19:03.301514'),
+(875, 0, NULL, NULL, 'erit', NULL, NULL, 5, 0, 0, 3, 2, '2025-07-28 10:19:03.305466', '2025-07-28 10:19:03.305466'),
+(876, 1, NULL, NULL, 'erint', NULL, NULL, 5, 0, 0, 3, 2, '2025-07-28 10:19:03.307777', '2025-07-28 10:19:03.307777'),
+(877, 0, NULL, NULL, 'eor', NULL, NULL, 0, 0, 1, 1, 2, '2025-07-28 10:19:03.311574', '2025-07-28 10:19:03.311574'),
+(878, 1, NULL, NULL, 'ēmur', NULL, NULL, 0, 0, 1, 1, 2, '2025-07-28 10:19:03.313909', '2025-07-28 10:19:03.313909'),
+(879, 0, NULL, NULL, 'ēris', NULL, NULL, 0, 0, 1, 2, 2, '2025-07-28 10:19:03.317748', '2025-07-28 10:19:03.317748'),
+(880, 1, NULL, NULL, 'ēminī', NULL, NULL, 0, 0, 1, 2, 2, '2025-07-28 10:19:03.320027', '2025-07-28 10:19:03.320027'),
+(881, 0, NULL, NULL, 'ētur', NULL, NULL, 0, 0, 1, 3, 2, '2025-07-28 10:19:03.323785', '2025-07-28 10:19:03.323785'),
+(882, 1, NULL, NULL, 'entur', NULL, NULL, 0, 0, 1, 3, 2, '2025-07-28 10:19:03.326035', '2025-07-28 10:19:03.326035'),
+(883, 0, NULL, NULL, 'ēbar', NULL, NULL, 1, 0, 1, 1, 2, '2025-07-28 10:19:03.329725', '2025-07-28 10:19:03.329725'),
+(884, 1, NULL, NULL, 'ēbāmur', NULL, NULL, 1, 0, 1, 1, 2, '2025-07-28 10:19:03.331958', '2025-07-28 10:19:03.331958'),
+(885, 0, NULL, NULL, 'ēbāris', NULL, NULL, 1, 0, 1, 2, 2, '2025-07-28 10:19:03.335644', '2025-07-28 10:19:03.335644'),
+(886, 1, NULL, NULL, 'ēbāminī', NULL, NULL, 1, 0, 1, 2, 2, '2025-07-28 10:19:03.337961', '2025-07-28 10:19:03.337961'),
+(887, 0, NULL, NULL, 'ēbātur', NULL, NULL, 1, 0, 1, 3, 2, '2025-07-28 10:19:03.341026', '2025-07-28 10:19:03.341026'),
+(888, 1, NULL, NULL, 'ēbantur', NULL, NULL, 1, 0, 1, 3, 2, '2025-07-28 10:19:03.343238', '2025-07-28 10:19:03.343238'),
+(889, 0, NULL, NULL, 'ēbor', NULL, NULL, 4, 0, 1, 1, 2, '2025-07-28 10:19:03.346133', '2025-07-28 10:19:03.346133'),
+(890, 1, NULL, NULL, 'ēbimur', NULL, NULL, 4, 0, 1, 1, 2, '2025-07-28 10:19:03.349073', '2025-07-28 10:19:03.349073'),
+(891, 0, NULL, NULL, 'ēberis', NULL, NULL, 4, 0, 1, 2, 2, '2025-07-28 10:19:03.351331', '2025-07-28 10:19:03.351331'),
+(892, 1, NULL, NULL, 'ēbiminī', NULL, NULL, 4, 0, 1, 2, 2, '2025-07-28 10:19:03.354400', '2025-07-28 10:19:03.354400'),
+(893, 0, NULL, NULL, 'ēbitur', NULL, NULL, 4, 0, 1, 3, 2, '2025-07-28 10:19:03.356627', '2025-07-28 10:19:03.356627'),
+(894, 1, NULL, NULL, 'ēbuntur', NULL, NULL, 4, 0, 1, 3, 2, '2025-07-28 10:19:03.359519', '2025-07-28 10:19:03.359519'),
+(895, 0, NULL, NULL, 'sum', NULL, NULL, 2, 0, 1, 1, 2, '2025-07-28 10:19:03.361790', '2025-07-28 10:19:03.361790'),
+(896, 1, NULL, NULL, 'sumus', NULL, NULL, 2, 0, 1, 1, 2, '2025-07-28 10:19:03.364665', '2025-07-28 10:19:03.364665'),
+(897, 0, NULL, NULL, 'es', NULL, NULL, 2, 0, 1, 2, 2, '2025-07-28 10:19:03.369242', '2025-07-28 10:19:03.369242'),
+(898, 1, NULL, NULL, 'estis', NULL, NULL, 2, 0, 1, 2, 2, '2025-07-28 10:19:03.371628', '2025-07-28 10:19:03.371628'),
+(899, 0, NULL, NULL, 'est', NULL, NULL, 2, 0, 1, 3, 2, '2025-07-28 10:19:03.373985', '2025-07-28 10:19:03.373985'),
+(900, 1, NULL, NULL, 'sunt', NULL, NULL, 2, 0, 1, 3, 2, '2025-07-28 10:19:03.376242', '2025-07-28 10:19:03.376242'),
+(901, 0, NULL, NULL, 'eram', NULL, NULL, 3, 0, 1, 1, 2, '2025-07-28 10:19:03.378531', '2025-07-28 10:19:03.378531'),
+(902, 1, NULL, NULL, 'erāmus', NULL, NULL, 3, 0, 1, 1, 2, '2025-07-28 10:19:03.380776', '2025-07-28 10:19:03.380776'),
+(903, 0, NULL, NULL, 'erās', NULL, NULL, 3, 0, 1, 2, 2, '2025-07-28 10:19:03.383046', '2025-07-28 10:19:03.383046'),
+(904, 1, NULL, NULL, 'erātis', NULL, NULL, 3, 0, 1, 2, 2, '2025-07-28 10:19:03.385278', '2025-07-28 10:19:03.385278'),
+(905, 0, NULL, NULL, 'erat', NULL, NULL, 3, 0, 1, 3, 2, '2025-07-28 10:19:03.387619', '2025-07-28 10:19:03.387619'),
+(906, 1, NULL, NULL, 'erant', NULL, NULL, 3, 0, 1, 3, 2, '2025-07-28 10:19:03.389904', '2025-07-28 10:19:03.389904'),
+(907, 0, NULL, NULL, 'erō', NULL, NULL, 5, 0, 1, 1, 2, '2025-07-28 10:19:03.392128', '2025-07-28 10:19:03.392128'),
+(908, 1, NULL, NULL, 'erimus', NULL, NULL, 5, 0, 1, 1, 2, '2025-07-28 10:19:03.394378', '2025-07-28 10:19:03.394378'),
+(909, 0, NULL, NULL, 'eris', NULL, NULL, 5, 0, 1, 2, 2, '2025-07-28 10:19:03.396601', '2025-07-28 10:19:03.396601'),
+(910, 1, NULL, NULL, 'eritis', NULL, NULL, 5, 0, 1, 2, 2, '2025-07-28 10:19:03.402470', '2025-07-28 10:19:03.402470'),
+(911, 0, NULL, NULL, 'erit', NULL, NULL, 5, 0, 1, 3, 2, '2025-07-28 10:19:03.404892', '2025-07-28 10:19:03.404892'),
+(912, 1, NULL, NULL, 'erunt', NULL, NULL, 5, 0, 1, 3, 2, '2025-07-28 10:19:03.408563', '2025-07-28 10:19:03.408563'),
+(913, 0, NULL, NULL, 'eam', NULL, NULL, 0, 1, 0, 1, 2, '2025-07-28 10:19:03.410899', '2025-07-28 10:19:03.410899'),
+(914, 1, NULL, NULL, 'eāmus', NULL, NULL, 0, 1, 0, 1, 2, '2025-07-28 10:19:03.414598', '2025-07-28 10:19:03.414598'),
+(915, 0, NULL, NULL, 'eās', NULL, NULL, 0, 1, 0, 2, 2, '2025-07-28 10:19:03.416926', '2025-07-28 10:19:03.416926'),
+(916, 1, NULL, NULL, 'eātis', NULL, NULL, 0, 1, 0, 2, 2, '2025-07-28 10:19:03.420463', '2025-07-28 10:19:03.420463'),
+(917, 0, NULL, NULL, 'eat', NULL, NULL, 0, 1, 0, 3, 2, '2025-07-28 10:19:03.422795', '2025-07-28 10:19:03.422795'),
+(918, 1, NULL, NULL, 'eant', NULL, NULL, 0, 1, 0, 3, 2, '2025-07-28 10:19:03.426414', '2025-07-28 10:19:03.426414'),
+(919, 0, NULL, NULL, 'ērem', NULL, NULL, 1, 1, 0, 1, 2, '2025-07-28 10:19:03.429766', '2025-07-28 10:19:03.429766'),
+(920, 1, NULL, NULL, 'ērēmus', NULL, NULL, 1, 1, 0, 1, 2, '2025-07-28 10:19:03.432279', '2025-07-28 10:19:03.432279'),
+(921, 0, NULL, NULL, 'ērēs', NULL, NULL, 1, 1, 0, 2, 2, '2025-07-28 10:19:03.435831', '2025-07-28 10:19:03.435831'),
+(922, 1, NULL, NULL, 'ērētis', NULL, NULL, 1, 1, 0, 2, 2, '2025-07-28 10:19:03.438100', '2025-07-28 10:19:03.438100'),
+(923, 0, NULL, NULL, 'ēret', NULL, NULL, 1, 1, 0, 3, 2, '2025-07-28 10:19:03.441065', '2025-07-28 10:19:03.441065'),
+(924, 1, NULL, NULL, 'ērent', NULL, NULL, 1, 1, 0, 3, 2, '2025-07-28 10:19:03.443275', '2025-07-28 10:19:03.443275'),
+(925, 0, NULL, NULL, 'erim', NULL, NULL, 2, 1, 0, 1, 2, '2025-07-28 10:19:03.446206', '2025-07-28 10:19:03.446206'),
+(926, 1, NULL, NULL, 'erīmus', NULL, NULL, 2, 1, 0, 1, 2, '2025-07-28 10:19:03.448398', '2025-07-28 10:19:03.448398'),
+(927, 0, NULL, NULL, 'erīs', NULL, NULL, 2, 1, 0, 2, 2, '2025-07-28 10:19:03.451418', '2025-07-28 10:19:03.451418'),
+(928, 1, NULL, NULL, 'erītis', NULL, NULL, 2, 1, 0, 2, 2, '2025-07-28 10:19:03.453713', '2025-07-28 10:19:03.453713'),
+(929, 0, NULL, NULL, 'erit', NULL, NULL, 2, 1, 0, 3, 2, '2025-07-28 10:19:03.456743', '2025-07-28 10:19:03.456743'),
+(930, 1, NULL, NULL, 'erint', NULL, NULL, 2, 1, 0, 3, 2, '2025-07-28 10:19:03.459025', '2025-07-28 10:19:03.459025'),
+(931, 0, NULL, NULL, 'issem', NULL, NULL, 3, 1, 0, 1, 2, '2025-07-28 10:19:03.461914', '2025-07-28 10:19:03.461914'),
+(932, 1, NULL, NULL, 'issēmus', NULL, NULL, 3, 1, 0, 1, 2, '2025-07-28 10:19:03.475315', '2025-07-28 10:19:03.475315'),
+(933, 0, NULL, NULL, 'issēs', NULL, NULL, 3, 1, 0, 2, 2, '2025-07-28 10:19:03.487990', '2025-07-28 10:19:03.487990'),
+(934, 1, NULL, NULL, 'issētis', NULL, NULL, 3, 1, 0, 2, 2, '2025-07-28 10:19:03.495887', '2025-07-28 10:19:03.495887'),
+(935, 0, NULL, NULL, 'isset', NULL, NULL, 3, 1, 0, 3, 2, '2025-07-28 10:19:03.503897', '2025-07-28 10:19:03.503897'),
+(936, 1, NULL, NULL, 'issent', NULL, NULL, 3, 1, 0, 3, 2, '2025-07-28 10:19:03.510526', '2025-07-28 10:19:03.510526'),
+(937, 0, NULL, NULL, 'ear', NULL, NULL, 0, 1, 1, 1, 2, '2025-07-28 10:19:03.518096', '2025-07-28 10:19:03.518096'),
+(938, 1, NULL, NULL, 'eāmur', NULL, NULL, 0, 1, 1, 1, 2, '2025-07-28 10:19:03.527165', '2025-07-28 10:19:03.527165'),
+(939, 0, NULL, NULL, 'eāris', NULL, NULL, 0, 1, 1, 2, 2, '2025-07-28 10:19:03.534426', '2025-07-28 10:19:03.534426'),
+(940, 1, NULL, NULL, 'eāminī', NULL, NULL, 0, 1, 1, 2, 2, '2025-07-28 10:19:03.542444', '2025-07-28 10:19:03.542444'),
+(941, 0, NULL, NULL, 'eātur', NULL, NULL, 0, 1, 1, 3, 2, '2025-07-28 10:19:03.547531', '2025-07-28 10:19:03.547531'),
+(942, 1, NULL, NULL, 'eantur', NULL, NULL, 0, 1, 1, 3, 2, '2025-07-28 10:19:03.554761', '2025-07-28 10:19:03.554761'),
+(943, 0, NULL, NULL, 'ērer', NULL, NULL, 1, 1, 1, 1, 2, '2025-07-28 10:19:03.560683', '2025-07-28 10:19:03.560683'),
+(944, 1, NULL, NULL, 'ērēmur', NULL, NULL, 1, 1, 1, 1, 2, '2025-07-28 10:19:03.563076', '2025-07-28 10:19:03.563076'),
+(945, 0, NULL, NULL, 'ērēris', NULL, NULL, 1, 1, 1, 2, 2, '2025-07-28 10:19:03.567056', '2025-07-28 10:19:03.567056'),
+(946, 1, NULL, NULL, 'ērēminī', NULL, NULL, 1, 1, 1, 2, 2, '2025-07-28 10:19:03.569334', '2025-07-28 10:19:03.569334'),
+(947, 0, NULL, NULL, 'ērētur', NULL, NULL, 1, 1, 1, 3, 2, '2025-07-28 10:19:03.573379', '2025-07-28 10:19:03.573379'),
+(948, 1, NULL, NULL, 'ērentur', NULL, NULL, 1, 1, 1, 3, 2, '2025-07-28 10:19:03.575745', '2025-07-28 10:19:03.575745'),
+(949, 0, NULL, NULL, 'sim', NULL, NULL, 2, 1, 1, 1, 2, '2025-07-28 10:19:03.579350', '2025-07-28 10:19:03.579350'),
+(950, 1, NULL, NULL, 'sīmus', NULL, NULL, 2, 1, 1, 1, 2, '2025-07-28 10:19:03.581636', '2025-07-28 10:19:03.581636'),
+(951, 0, NULL, NULL, 'sīs', NULL, NULL, 2, 1, 1, 2, 2, '2025-07-28 10:19:03.585372', '2025-07-28 10:19:03.585372'),
+(952, 1, NULL, NULL, 'sītis', NULL, NULL, 2, 1, 1, 2, 2, '2025-07-28 10:19:03.588795', '2025-07-28 10:19:03.588795'),
+(953, 0, NULL, NULL, 'sit', NULL, NULL, 2, 1, 1, 3, 2, '2025-07-28 10:19:03.591313', '2025-07-28 10:19:03.591313'),
+(954, 1, NULL, NULL, 'sint', NULL, NULL, 2, 1, 1, 3, 2, '2025-07-28 10:19:03.594330', '2025-07-28 10:19:03.594330'),
+(955, 0, NULL, NULL, 'essem', NULL, NULL, 3, 1, 1, 1, 2, '2025-07-28 10:19:03.596527', '2025-07-28 10:19:03.596527'),
+(956, 1, NULL, NULL, 'essēmus', NULL, NULL, 3, 1, 1, 1, 2, '2025-07-28 10:19:03.599599', '2025-07-28 10:19:03.599599'),
+(957, 0, NULL, NULL, 'essēs', NULL, NULL, 3, 1, 1, 2, 2, '2025-07-28 10:19:03.601902', '2025-07-28 10:19:03.601902'),
+(958, 1, NULL, NULL, 'essētis', NULL, NULL, 3, 1, 1, 2, 2, '2025-07-28 10:19:03.604935', '2025-07-28 10:19:03.604935'),
+(959, 0, NULL, NULL, 'esset', NULL, NULL, 3, 1, 1, 3, 2, '2025-07-28 10:19:03.625815', '2025-07-28 10:19:03.625815'),
+(960, 1, NULL, NULL, 'essent', NULL, NULL, 3, 1, 1, 3, 2, '2025-07-28 10:19:03.636655', '2025-07-28 10:19:03.636655'),
+(961, 0, NULL, NULL, 'ē', NULL, NULL, 0, 2, 0, 2, 2, '2025-07-28 10:19:03.639215', '2025-07-28 10:19:03.639215'),
+(962, 1, NULL, NULL, 'ēte', NULL, NULL, 0, 2, 0, 2, 2, '2025-07-28 10:19:03.642728', '2025-07-28 10:19:03.642728'),
+(963, 0, NULL, NULL, 'ētō', NULL, NULL, 4, 2, 0, 2, 2, '2025-07-28 10:19:03.645165', '2025-07-28 10:19:03.645165'),
+(964, 1, NULL, NULL, 'ētōte', NULL, NULL, 4, 2, 0, 2, 2, '2025-07-28 10:19:03.647195', '2025-07-28 10:19:03.647195'),
+(965, 0, NULL, NULL, 'ētō', NULL, NULL, 4, 2, 0, 3, 2, '2025-07-28 10:19:03.649575', '2025-07-28 10:19:03.649575'),
+(966, 1, NULL, NULL, 'ēntō', NULL, NULL, 4, 2, 0, 3, 2, '2025-07-28 10:19:03.652044', '2025-07-28 10:19:03.652044'),
+(967, 0, NULL, NULL, 'ēre', NULL, NULL, 0, 2, 1, 2, 2, '2025-07-28 10:19:03.654532', '2025-07-28 10:19:03.654532'),
+(968, 1, NULL, NULL, 'ēminī', NULL, NULL, 0, 2, 1, 2, 2, '2025-07-28 10:19:03.657141', '2025-07-28 10:19:03.657141'),
+(969, 0, NULL, NULL, 'ētor', NULL, NULL, 4, 2, 1, 2, 2, '2025-07-28 10:19:03.659665', '2025-07-28 10:19:03.659665'),
+(970, 1, NULL, NULL, NULL, NULL, NULL, 4, 2, 1, 2, 2, '2025-07-28 10:19:03.661747', '2025-07-28 10:19:03.661747'),
+(971, 0, NULL, NULL, 'ētor', NULL, NULL, 4, 2, 1, 3, 2, '2025-07-28 10:19:03.663886', '2025-07-28 10:19:03.663886'),
+(972, 1, NULL, NULL, 'entor', NULL, NULL, 4, 2, 1, 3, 2, '2025-07-28 10:19:03.666030', '2025-07-28 10:19:03.666030'),
+(973, 0, NULL, NULL, 'ēre', NULL, NULL, 0, 6, 0, 1, 2, '2025-07-28 10:19:03.668246', '2025-07-28 10:19:03.668246'),
+(974, 1, NULL, NULL, NULL, NULL, NULL, 0, 6, 0, 1, 2, '2025-07-28 10:19:03.670423', '2025-07-28 10:19:03.670423'),
+(975, 0, NULL, NULL, 'isse', NULL, NULL, 2, 6, 0, 1, 2, '2025-07-28 10:19:03.672585', '2025-07-28 10:19:03.672585'),
+(976, 1, NULL, NULL, NULL, NULL, NULL, 2, 6, 0, 1, 2, '2025-07-28 10:19:03.674711', '2025-07-28 10:19:03.674711'),
+(977, 0, NULL, NULL, 'esse', NULL, NULL, 4, 6, 0, 1, 2, '2025-07-28 10:19:03.676847', '2025-07-28 10:19:03.676847'),
+(978, 1, NULL, NULL, NULL, NULL, NULL, 4, 6, 0, 1, 2, '2025-07-28 10:19:03.678995', '2025-07-28 10:19:03.678995'),
+(979, 0, NULL, NULL, 'ērī', NULL, NULL, 0, 6, 1, 1, 2, '2025-07-28 10:19:03.681164', '2025-07-28 10:19:03.681164'),
+(980, 1, NULL, NULL, NULL, NULL, NULL, 0, 6, 1, 1, 2, '2025-07-28 10:19:03.683312', '2025-07-28 10:19:03.683312'),
+(981, 0, NULL, NULL, 'esse', NULL, NULL, 2, 6, 1, 1, 2, '2025-07-28 10:19:03.685499', '2025-07-28 10:19:03.685499'),
+(982, 1, NULL, NULL, NULL, NULL, NULL, 2, 6, 1, 1, 2, '2025-07-28 10:19:03.687621', '2025-07-28 10:19:03.687621'),
+(983, 0, NULL, NULL, 'īrī', NULL, NULL, 4, 6, 1, 1, 2, '2025-07-28 10:19:03.689813', '2025-07-28 10:19:03.689813'),
+(984, 1, NULL, NULL, NULL, NULL, NULL, 4, 6, 1, 1, 2, '2025-07-28 10:19:03.691929', '2025-07-28 10:19:03.691929'),
+(985, 0, NULL, NULL, 'ēns', NULL, NULL, 0, 8, 0, 1, 2, '2025-07-28 10:19:03.694031', '2025-07-28 10:19:03.694031'),
+(986, 1, NULL, NULL, NULL, NULL, NULL, 0, 8, 0, 1, 2, '2025-07-28 10:19:03.696034', '2025-07-28 10:19:03.696034'),
+(987, 0, NULL, NULL, 'ūrus', NULL, NULL, 4, 8, 0, 1, 2, '2025-07-28 10:19:03.698126', '2025-07-28 10:19:03.698126'),
+(988, 1, NULL, NULL, NULL, NULL, NULL, 4, 8, 0, 1, 2, '2025-07-28 10:19:03.700237', '2025-07-28 10:19:03.700237'),
+(989, 0, NULL, NULL, 'us', NULL, NULL, 2, 8, 1, 1, 2, '2025-07-28 10:19:03.702354', '2025-07-28 10:19:03.702354'),
+(990, 1, NULL, NULL, NULL, NULL, NULL, 2, 8, 1, 1, 2, '2025-07-28 10:19:03.704462', '2025-07-28 10:19:03.704462'),
+(991, 0, NULL, NULL, 'endus', NULL, NULL, 4, 8, 1, 1, 2, '2025-07-28 10:19:03.706597', '2025-07-28 10:19:03.706597'),
+(992, 1, NULL, NULL, NULL, NULL, NULL, 4, 8, 1, 1, 2, '2025-07-28 10:19:03.708704', '2025-07-28 10:19:03.708704'),
+(993, 0, NULL, NULL, 'ō', NULL, NULL, 0, 0, 0, 1, 3, '2025-07-28 10:19:03.715727', '2025-07-28 10:19:03.715727'),
+(994, 1, NULL, NULL, 'imus', NULL, NULL, 0, 0, 0, 1, 3, '2025-07-28 10:19:03.717971', '2025-07-28 10:19:03.717971'),
+(995, 0, NULL, NULL, 'is', NULL, NULL, 0, 0, 0, 2, 3, '2025-07-28 10:19:03.720112', '2025-07-28 10:19:03.720112'),
+(996, 1, NULL, NULL, 'itis', NULL, NULL, 0, 0, 0, 2, 3, '2025-07-28 10:19:03.722306', '2025-07-28 10:19:03.722306'),
+(997, 0, NULL, NULL, 'it', NULL, NULL, 0, 0, 0, 3, 3, '2025-07-28 10:19:03.724471', '2025-07-28 10:19:03.724471'),
+(998, 1, NULL, NULL, 'unt', NULL, NULL, 0, 0, 0, 3, 3, '2025-07-28 10:19:03.726782', '2025-07-28 10:19:03.726782'),
+(999, 0, NULL, NULL, 'ēbam', NULL, NULL, 1, 0, 0, 1, 3, '2025-07-28 10:19:03.729115', '2025-07-28 10:19:03.729115'),
+(1000, 1, NULL, NULL, 'ēbāmus', NULL, NULL, 1, 0, 0, 1, 3, '2025-07-28 10:19:03.731364', '2025-07-28 10:19:03.731364'),
+(1001, 0, NULL, NULL, 'ēbās', NULL, NULL, 1, 0, 0, 2, 3, '2025-07-28 10:19:03.733755', '2025-07-28 10:19:03.733755'),
+(1002, 1, NULL, NULL, 'ēbātis', NULL, NULL, 1, 0, 0, 2, 3, '2025-07-28 10:19:03.736127', '2025-07-28 10:19:03.736127'),
+(1003, 0, NULL, NULL, 'ēbat', NULL, NULL, 1, 0, 0, 3, 3, '2025-07-28 10:19:03.738422', '2025-07-28 10:19:03.738422'),
+(1004, 1, NULL, NULL, 'ēbant', NULL, NULL, 1, 0, 0, 3, 3, '2025-07-28 10:19:03.740876', '2025-07-28 10:19:03.740876'),
+(1005, 0, NULL, NULL, 'ī', NULL, NULL, 2, 0, 0, 1, 3, '2025-07-28 10:19:03.743106', '2025-07-28 10:19:03.743106'),
+(1006, 1, NULL, NULL, 'imus', NULL, NULL, 2, 0, 0, 1, 3, '2025-07-28 10:19:03.745380', '2025-07-28 10:19:03.745380'),
+(1007, 0, NULL, NULL, 'istī', NULL, NULL, 2, 0, 0, 2, 3, '2025-07-28 10:19:03.747691', '2025-07-28 10:19:03.747691'),
+(1008, 1, NULL, NULL, 'istis', NULL, NULL, 2, 0, 0, 2, 3, '2025-07-28 10:19:03.750114', '2025-07-28 10:19:03.750114'),
+(1009, 0, NULL, NULL, 'it', NULL, NULL, 2, 0, 0, 3, 3, '2025-07-28 10:19:03.756996', '2025-07-28 10:19:03.756996'),
+(1010, 1, NULL, NULL, 'ērunt', NULL, NULL, 2, 0, 0, 3, 3, '2025-07-28 10:19:03.761145', '2025-07-28 10:19:03.761145'),
+(1011, 0, NULL, NULL, 'eram', NULL, NULL, 3, 0, 0, 1, 3, '2025-07-28 10:19:03.763935', '2025-07-28 10:19:03.763935'),
+(1012, 1, NULL, NULL, 'erāmus', NULL, NULL, 3, 0, 0, 1, 3, '2025-07-28 10:19:03.768446', '2025-07-28 10:19:03.768446'),
+(1013, 0, NULL, NULL, 'erās', NULL, NULL, 3, 0, 0, 2, 3, '2025-07-28 10:19:03.770743', '2025-07-28 10:19:03.770743'),
+(1014, 1, NULL, NULL, 'erātis', NULL, NULL, 3, 0, 0, 2, 3, '2025-07-28 10:19:03.774951', '2025-07-28 10:19:03.774951'),
+(1015, 0, NULL, NULL, 'erat', NULL, NULL, 3, 0, 0, 3, 3, '2025-07-28 10:19:03.777225', '2025-07-28 10:19:03.777225'),
+(1016, 1, NULL, NULL, 'erant', NULL, NULL, 3, 0, 0, 3, 3, '2025-07-28 10:19:03.781271', '2025-07-28 10:19:03.781271'),
+(1017, 0, NULL, NULL, 'am', NULL, NULL, 4, 0, 0, 1, 3, '2025-07-28 10:19:03.783608', '2025-07-28 10:19:03.783608'),
+(1018, 1, NULL, NULL, 'ēmus', NULL, NULL, 4, 0, 0, 1, 3, '2025-07-28 10:19:03.787580', '2025-07-28 10:19:03.787580'),
+(1019, 0, NULL, NULL, 'ēs', NULL, NULL, 4, 0, 0, 2, 3, '2025-07-28 10:19:03.789948', '2025-07-28 10:19:03.789948'),
+(1020, 1, NULL, NULL, 'ētis', NULL, NULL, 4, 0, 0, 2, 3, '2025-07-28 10:19:03.793921', '2025-07-28 10:19:03.793921'),
+(1021, 0, NULL, NULL, 'et', NULL, NULL, 4, 0, 0, 3, 3, '2025-07-28 10:19:03.796202', '2025-07-28 10:19:03.796202'),
+(1022, 1, NULL, NULL, 'ent', NULL, NULL, 4, 0, 0, 3, 3, '2025-07-28 10:19:03.800178', '2025-07-28 10:19:03.800178'),
+(1023, 0, NULL, NULL, 'erō', NULL, NULL, 5, 0, 0, 1, 3, '2025-07-28 10:19:03.802448', '2025-07-28 10:19:03.802448'),
+(1024, 1, NULL, NULL, 'erimus', NULL, NULL, 5, 0, 0, 1, 3, '2025-07-28 10:19:03.806320', '2025-07-28 10:19:03.806320'),
+(1025, 0, NULL, NULL, 'eris', NULL, NULL, 5, 0, 0, 2, 3, '2025-07-28 10:19:03.809914', '2025-07-28 10:19:03.809914'),
+(1026, 1, NULL, NULL, 'eritis', NULL, NULL, 5, 0, 0, 2, 3, '2025-07-28 10:19:03.812430', '2025-07-28 10:19:03.812430'),
+(1027, 0, NULL, NULL, 'erit', NULL, NULL, 5, 0, 0, 3, 3, '2025-07-28 10:19:03.816066', '2025-07-28 10:19:03.816066'),
+(1028, 1, NULL, NULL, 'erint', NULL, NULL, 5, 0, 0, 3, 3, '2025-07-28 10:19:03.818407', '2025-07-28 10:19:03.818407'),
+(1029, 0, NULL, NULL, 'or', NULL, NULL, 0, 0, 1, 1, 3, '2025-07-28 10:19:03.822285', '2025-07-28 10:19:03.822285'),
+(1030, 1, NULL, NULL, 'imur', NULL, NULL, 0, 0, 1, 1, 3, '2025-07-28 10:19:03.824600', '2025-07-28 10:19:03.824600'),
+(1031, 0, NULL, NULL, 'eris', NULL, NULL, 0, 0, 1, 2, 3, '2025-07-28 10:19:03.827940', '2025-07-28 10:19:03.827940'),
+(1032, 1, NULL, NULL, 'iminī', NULL, NULL, 0, 0, 1, 2, 3, '2025-07-28 10:19:03.830208', '2025-07-28 10:19:03.830208'),
+(1033, 0, NULL, NULL, 'itur', NULL, NULL, 0, 0, 1, 3, 3, '2025-07-28 10:19:03.833355', '2025-07-28 10:19:03.833355'),
+(1034, 1, NULL, NULL, 'untur', NULL, NULL, 0, 0, 1, 3, 3, '2025-07-28 10:19:03.835674', '2025-07-28 10:19:03.835674'),
+(1035, 0, NULL, NULL, 'ēbar', NULL, NULL, 1, 0, 1, 1, 3, '2025-07-28 10:19:03.837962', '2025-07-28 10:19:03.837962'),
+(1036, 1, NULL, NULL, 'ēbāmur', NULL, NULL, 1, 0, 1, 1, 3, '2025-07-28 10:19:03.840993', '2025-07-28 10:19:03.840993'),
+(1037, 0, NULL, NULL, 'ēbāris', NULL, NULL, 1, 0, 1, 2, 3, '2025-07-28 10:19:03.843173', '2025-07-28 10:19:03.843173'),
+(1038, 1, NULL, NULL, 'ēbāminī', NULL, NULL, 1, 0, 1, 2, 3, '2025-07-28 10:19:03.845886', '2025-07-28 10:19:03.845886'),
+(1039, 0, NULL, NULL, 'ēbātur', NULL, NULL, 1, 0, 1, 3, 3, '2025-07-28 10:19:03.848071', '2025-07-28 10:19:03.848071'),
+(1040, 1, NULL, NULL, 'ēbantur', NULL, NULL, 1, 0, 1, 3, 3, '2025-07-28 10:19:03.850807', '2025-07-28 10:19:03.850807'),
+(1041, 0, NULL, NULL, 'ar', NULL, NULL, 4, 0, 1, 1, 3, '2025-07-28 10:19:03.853064', '2025-07-28 10:19:03.853064'),
+(1042, 1, NULL, NULL, 'ēmur', NULL, NULL, 4, 0, 1, 1, 3, '2025-07-28 10:19:03.855321', '2025-07-28 10:19:03.855321'),
+(1043, 0, NULL, NULL, 'ēris', NULL, NULL, 4, 0, 1, 2, 3, '2025-07-28 10:19:03.858223', '2025-07-28 10:19:03.858223'),
+(1044, 1, NULL, NULL, 'ēminī', NULL, NULL, 4, 0, 1, 2, 3, '2025-07-28 10:19:03.860487', '2025-07-28 10:19:03.860487'),
+(1045, 0, NULL, NULL, 'ētur', NULL, NULL, 4, 0, 1, 3, 3, '2025-07-28 10:19:03.863242', '2025-07-28 10:19:03.863242'),
+(1046, 1, NULL, NULL, 'entur', NULL, NULL, 4, 0, 1, 3, 3, '2025-07-28 10:19:03.865454', '2025-07-28 10:19:03.865454'),
+(1047, 0, NULL, NULL, 'sum', NULL, NULL, 2, 0, 1, 1, 3, '2025-07-28 10:19:03.867772', '2025-07-28 10:19:03.867772'),
+(1048, 1, NULL, NULL, 'sumus', NULL, NULL, 2, 0, 1, 1, 3, '2025-07-28 10:19:03.870726', '2025-07-28 10:19:03.870726'),
+(1049, 0, NULL, NULL, 'es', NULL, NULL, 2, 0, 1, 2, 3, '2025-07-28 10:19:03.873011', '2025-07-28 10:19:03.873011'),
+(1050, 1, NULL, NULL, 'estis', NULL, NULL, 2, 0, 1, 2, 3, '2025-07-28 10:19:03.875224', '2025-07-28 10:19:03.875224'),
+(1051, 0, NULL, NULL, 'est', NULL, NULL, 2, 0, 1, 3, 3, '2025-07-28 10:19:03.877478', '2025-07-28 10:19:03.877478'),
+(1052, 1, NULL, NULL, 'sunt', NULL, NULL, 2, 0, 1, 3, 3, '2025-07-28 10:19:03.879756', '2025-07-28 10:19:03.879756'),
+(1053, 0, NULL, NULL, 'eram', NULL, NULL, 3, 0, 1, 1, 3, '2025-07-28 10:19:03.882050', '2025-07-28 10:19:03.882050'),
+(1054, 1, NULL, NULL, 'erāmus', NULL, NULL, 3, 0, 1, 1, 3, '2025-07-28 10:19:03.884343', '2025-07-28 10:19:03.884343'),
+(1055, 0, NULL, NULL, 'erās', NULL, NULL, 3, 0, 1, 2, 3, '2025-07-28 10:19:03.886630', '2025-07-28 10:19:03.886630'),
+(1056, 1, NULL, NULL, 'erātis', NULL, NULL, 3, 0, 1, 2, 3, '2025-07-28 10:19:03.888967', '2025-07-28 10:19:03.888967'),
+(1057, 0, NULL, NULL, 'erat', NULL, NULL, 3, 0, 1, 3, 3, '2025-07-28 10:19:03.891214', '2025-07-28 10:19:03.891214'),
+(1058, 1, NULL, NULL, 'erant', NULL, NULL, 3, 0, 1, 3, 3, '2025-07-28 10:19:03.893493', '2025-07-28 10:19:03.893493'),
+(1059, 0, NULL, NULL, 'erō', NULL, NULL, 5, 0, 1, 1, 3, '2025-07-28 10:19:03.895767', '2025-07-28 10:19:03.895767'),
+(1060, 1, NULL, NULL, 'erimus', NULL, NULL, 5, 0, 1, 1, 3, '2025-07-28 10:19:03.897970', '2025-07-28 10:19:03.897970'),
+(1061, 0, NULL, NULL, 'eris', NULL, NULL, 5, 0, 1, 2, 3, '2025-07-28 10:19:03.900194', '2025-07-28 10:19:03.900194'),
+(1062, 1, NULL, NULL, 'eritis', NULL, NULL, 5, 0, 1, 2, 3, '2025-07-28 10:19:03.902499', '2025-07-28 10:19:03.902499'),
+(1063, 0, NULL, NULL, 'erit', NULL, NULL, 5, 0, 1, 3, 3, '2025-07-28 10:19:03.904743', '2025-07-28 10:19:03.904743'),
+(1064, 1, NULL, NULL, 'erunt', NULL, NULL, 5, 0, 1, 3, 3, '2025-07-28 10:19:03.907008', '2025-07-28 10:19:03.907008'),
+(1065, 0, NULL, NULL, 'am', NULL, NULL, 0, 1, 0, 1, 3, '2025-07-28 10:19:03.909198', '2025-07-28 10:19:03.909198'),
+(1066, 1, NULL, NULL, 'āmus', NULL, NULL, 0, 1, 0, 1, 3, '2025-07-28 10:19:03.911607', '2025-07-28 10:19:03.911607'),
+(1067, 0, NULL, NULL, 'ās', NULL, NULL, 0, 1, 0, 2, 3, '2025-07-28 10:19:03.913843', '2025-07-28 10:19:03.913843'),
+(1068, 1, NULL, NULL, 'ātis', NULL, NULL, 0, 1, 0, 2, 3, '2025-07-28 10:19:03.916057', '2025-07-28 10:19:03.916057'),
+(1069, 0, NULL, NULL, 'at', NULL, NULL, 0, 1, 0, 3, 3, '2025-07-28 10:19:03.918327', '2025-07-28 10:19:03.918327'),
+(1070, 1, NULL, NULL, 'ant', NULL, NULL, 0, 1, 0, 3, 3, '2025-07-28 10:19:03.920561', '2025-07-28 10:19:03.920561'),
+(1071, 0, NULL, NULL, 'erem', NULL, NULL, 1, 1, 0, 1, 3, '2025-07-28 10:19:03.922921', '2025-07-28 10:19:03.922921'),
+(1072, 1, NULL, NULL, 'erēmus', NULL, NULL, 1, 1, 0, 1, 3, '2025-07-28 10:19:03.925158', '2025-07-28 10:19:03.925158'),
+(1073, 0, NULL, NULL, 'erēs', NULL, NULL, 1, 1, 0, 2, 3, '2025-07-28 10:19:03.927415', '2025-07-28 10:19:03.927415'),
+(1074, 1, NULL, NULL, 'erētis', NULL, NULL, 1, 1, 0, 2, 3, '2025-07-28 10:19:03.933711', '2025-07-28 10:19:03.933711'),
+(1075, 0, NULL, NULL, 'eret', NULL, NULL, 1, 1, 0, 3, 3, '2025-07-28 10:19:03.936090', '2025-07-28 10:19:03.936090'),
+(1076, 1, NULL, NULL, 'erent', NULL, NULL, 1, 1, 0, 3, 3, '2025-07-28 10:19:03.940337', '2025-07-28 10:19:03.940337'),
+(1077, 0, NULL, NULL, 'erim', NULL, NULL, 2, 1, 0, 1, 3, '2025-07-28 10:19:03.942711', '2025-07-28 10:19:03.942711'),
+(1078, 1, NULL, NULL, 'erīmus', NULL, NULL, 2, 1, 0, 1, 3, '2025-07-28 10:19:03.946784', '2025-07-28 10:19:03.946784'),
+(1079, 0, NULL, NULL, 'erīs', NULL, NULL, 2, 1, 0, 2, 3, '2025-07-28 10:19:03.949100', '2025-07-28 10:19:03.949100'),
+(1080, 1, NULL, NULL, 'erītis', NULL, NULL, 2, 1, 0, 2, 3, '2025-07-28 10:19:03.953023', '2025-07-28 10:19:03.953023'),
+(1081, 0, NULL, NULL, 'erit', NULL, NULL, 2, 1, 0, 3, 3, '2025-07-28 10:19:03.956662', '2025-07-28 10:19:03.956662'),
+(1082, 1, NULL, NULL, 'erint', NULL, NULL, 2, 1, 0, 3, 3, '2025-07-28 10:19:03.959257', '2025-07-28 10:19:03.959257'),
+(1083, 0, NULL, NULL, 'issem', NULL, NULL, 3, 1, 0, 1, 3, '2025-07-28 10:19:03.963256', '2025-07-28 10:19:03.963256'),
+(1084, 1, NULL, NULL, 'issēmus', NULL, NULL, 3, 1, 0, 1, 3, '2025-07-28 10:19:03.965545', '2025-07-28 10:19:03.965545'),
+(1085, 0, NULL, NULL, 'issēs', NULL, NULL, 3, 1, 0, 2, 3, '2025-07-28 10:19:03.969591', '2025-07-28 10:19:03.969591'),
+(1086, 1, NULL, NULL, 'issētis', NULL, NULL, 3, 1, 0, 2, 3, '2025-07-28 10:19:03.971937', '2025-07-28 10:19:03.971937'),
+(1087, 0, NULL, NULL, 'isset', NULL, NULL, 3, 1, 0, 3, 3, '2025-07-28 10:19:03.976023', '2025-07-28 10:19:03.976023'),
+(1088, 1, NULL, NULL, 'issent', NULL, NULL, 3, 1, 0, 3, 3, '2025-07-28 10:19:03.978318', '2025-07-28 10:19:03.978318'),
+(1089, 0, NULL, NULL, 'ar', NULL, NULL, 0, 1, 1, 1, 3, '2025-07-28 10:19:03.982279', '2025-07-28 10:19:03.982279'),
+(1090, 1, NULL, NULL, 'āmur', NULL, NULL, 0, 1, 1, 1, 3, '2025-07-28 10:19:03.984577', '2025-07-28 10:19:03.984577'),
+(1091, 0, NULL, NULL, 'āris', NULL, NULL, 0, 1, 1, 2, 3, '2025-07-28 10:19:03.988263', '2025-07-28 10:19:03.988263'),
+(1092, 1, NULL, NULL, 'āminī', NULL, NULL, 0, 1, 1, 2, 3, '2025-07-28 10:19:03.990983', '2025-07-28 10:19:03.990983'),
+(1093, 0, NULL, NULL, 'ātur', NULL, NULL, 0, 1, 1, 3, 3, '2025-07-28 10:19:03.994990', '2025-07-28 10:19:03.994990'),
+(1094, 1, NULL, NULL, 'antur', NULL, NULL, 0, 1, 1, 3, 3, '2025-07-28 10:19:03.997218', '2025-07-28 10:19:03.997218'),
+(1095, 0, NULL, NULL, 'erer', NULL, NULL, 1, 1, 1, 1, 3, '2025-07-28 10:19:03.999527', '2025-07-28 10:19:03.999527'),
+(1096, 1, NULL, NULL, 'erēmur', NULL, NULL, 1, 1, 1, 1, 3, '2025-07-28 10:19:04.003250', '2025-07-28 10:19:04.003250'),
+(1097, 0, NULL, NULL, 'erēris', NULL, NULL, 1, 1, 1, 2, 3, '2025-07-28 10:19:04.005615', '2025-07-28 10:19:04.005615'),
+(1098, 1, NULL, NULL, 'erēminī', NULL, NULL, 1, 1, 1, 2, 3, '2025-07-28 10:19:04.008813', '2025-07-28 10:19:04.008813'),
+(1099, 0, NULL, NULL, 'erētur', NULL, NULL, 1, 1, 1, 3, 3, '2025-07-28 10:19:04.011042', '2025-07-28 10:19:04.011042'),
+(1100, 1, NULL, NULL, 'erentur', NULL, NULL, 1, 1, 1, 3, 3, '2025-07-28 10:19:04.013272', '2025-07-28 10:19:04.013272'),
+(1101, 0, NULL, NULL, 'sim', NULL, NULL, 2, 1, 1, 1, 3, '2025-07-28 10:19:04.016420', '2025-07-28 10:19:04.016420'),
+(1102, 1, NULL, NULL, 'sīmus', NULL, NULL, 2, 1, 1, 1, 3, '2025-07-28 10:19:04.018736', '2025-07-28 10:19:04.018736'),
+(1103, 0, NULL, NULL, 'sīs', NULL, NULL, 2, 1, 1, 2, 3, '2025-07-28 10:19:04.021871', '2025-07-28 10:19:04.021871'),
+(1104, 1, NULL, NULL, 'sītis', NULL, NULL, 2, 1, 1, 2, 3, '2025-07-28 10:19:04.024101', '2025-07-28 10:19:04.024101'),
+(1105, 0, NULL, NULL, 'sit', NULL, NULL, 2, 1, 1, 3, 3, '2025-07-28 10:19:04.026898', '2025-07-28 10:19:04.026898'),
+(1106, 1, NULL, NULL, 'sint', NULL, NULL, 2, 1, 1, 3, 3, '2025-07-28 10:19:04.029150', '2025-07-28 10:19:04.029150'),
+(1107, 0, NULL, NULL, 'essem', NULL, NULL, 3, 1, 1, 1, 3, '2025-07-28 10:19:04.032057', '2025-07-28 10:19:04.032057'),
+(1108, 1, NULL, NULL, 'essēmus', NULL, NULL, 3, 1, 1, 1, 3, '2025-07-28 10:19:04.034299', '2025-07-28 10:19:04.034299'),
+(1109, 0, NULL, NULL, 'essēs', NULL, NULL, 3, 1, 1, 2, 3, '2025-07-28 10:19:04.036595', '2025-07-28 10:19:04.036595'),
+(1110, 1, NULL, NULL, 'essētis', NULL, NULL, 3, 1, 1, 2, 3, '2025-07-28 10:19:04.039612', '2025-07-28 10:19:04.039612'),
+(1111, 0, NULL, NULL, 'esset', NULL, NULL, 3, 1, 1, 3, 3, '2025-07-28 10:19:04.041886', '2025-07-28 10:19:04.041886'),
+(1112, 1, NULL, NULL, 'essent', NULL, NULL, 3, 1, 1, 3, 3, '2025-07-28 10:19:04.045072', '2025-07-28 10:19:04.045072'),
+(1113, 0, NULL, NULL, 'e', NULL, NULL, 0, 2, 0, 2, 3, '2025-07-28 10:19:04.047288', '2025-07-28 10:19:04.047288'),
+(1114, 1, NULL, NULL, 'ite', NULL, NULL, 0, 2, 0, 2, 3, '2025-07-28 10:19:04.049551', '2025-07-28 10:19:04.049551'),
+(1115, 0, NULL, NULL, 'itō', NULL, NULL, 4, 2, 0, 2, 3, '2025-07-28 10:19:04.051875', '2025-07-28 10:19:04.051875'),
+(1116, 1, NULL, NULL, 'itōte', NULL, NULL, 4, 2, 0, 2, 3, '2025-07-28 10:19:04.054159', '2025-07-28 10:19:04.054159'),
+(1117, 0, NULL, NULL, 'itō', NULL, NULL, 4, 2, 0, 3, 3, '2025-07-28 10:19:04.056511', '2025-07-28 10:19:04.056511'),
+(1118, 1, NULL, NULL, 'untō', NULL, NULL, 4, 2, 0, 3, 3, '2025-07-28 10:19:04.058770', '2025-07-28 10:19:04.058770'),
+(1119, 0, NULL, NULL, 'ere', NULL, NULL, 0, 2, 1, 2, 3, '2025-07-28 10:19:04.061015', '2025-07-28 10:19:04.061015'),
+(1120, 1, NULL, NULL, 'iminī', NULL, NULL, 0, 2, 1, 2, 3, '2025-07-28 10:19:04.063184', '2025-07-28 10:19:04.063184'),
+(1121, 0, NULL, NULL, 'itor', NULL, NULL, 4, 2, 1, 2, 3, '2025-07-28 10:19:04.065389', '2025-07-28 10:19:04.065389'),
+(1122, 1, NULL, NULL, NULL, NULL, NULL, 4, 2, 1, 2, 3, '2025-07-28 10:19:04.067605', '2025-07-28 10:19:04.067605'),
+(1123, 0, NULL, NULL, 'itor', NULL, NULL, 4, 2, 1, 3, 3, '2025-07-28 10:19:04.069874', '2025-07-28 10:19:04.069874'),
+(1124, 1, NULL, NULL, 'untor', NULL, NULL, 4, 2, 1, 3, 3, '2025-07-28 10:19:04.072139', '2025-07-28 10:19:04.072139'),
+(1125, 0, NULL, NULL, 'ere', NULL, NULL, 0, 6, 0, 1, 3, '2025-07-28 10:19:04.074374', '2025-07-28 10:19:04.074374'),
+(1126, 1, NULL, NULL, NULL, NULL, NULL, 0, 6, 0, 1, 3, '2025-07-28 10:19:04.076612', '2025-07-28 10:19:04.076612'),
+(1127, 0, NULL, NULL, 'isse', NULL, NULL, 2, 6, 0, 1, 3, '2025-07-28 10:19:04.078844', '2025-07-28 10:19:04.078844'),
+(1128, 1, NULL, NULL, NULL, NULL, NULL, 2, 6, 0, 1, 3, '2025-07-28 10:19:04.081030', '2025-07-28 10:19:04.081030'),
+(1129, 0, NULL, NULL, 'esse', NULL, NULL, 4, 6, 0, 1, 3, '2025-07-28 10:19:04.083244', '2025-07-28 10:19:04.083244'),
+(1130, 1, NULL, NULL, NULL, NULL, NULL, 4, 6, 0, 1, 3, '2025-07-28 10:19:04.085489', '2025-07-28 10:19:04.085489'),
+(1131, 0, NULL, NULL, 'ī', NULL, NULL, 0, 6, 1, 1, 3, '2025-07-28 10:19:04.087712', '2025-07-28 10:19:04.087712'),
+(1132, 1, NULL, NULL, NULL, NULL, NULL, 0, 6, 1, 1, 3, '2025-07-28 10:19:04.090017', '2025-07-28 10:19:04.090017'),
+(1133, 0, NULL, NULL, 'esse', NULL, NULL, 2, 6, 1, 1, 3, '2025-07-28 10:19:04.092273', '2025-07-28 10:19:04.092273'),
+(1134, 1, NULL, NULL, NULL, NULL, NULL, 2, 6, 1, 1, 3, '2025-07-28 10:19:04.098629', '2025-07-28 10:19:04.098629'),
+(1135, 0, NULL, NULL, 'īrī', NULL, NULL, 4, 6, 1, 1, 3, '2025-07-28 10:19:04.101057', '2025-07-28 10:19:04.101057'),
+(1136, 1, NULL, NULL, NULL, NULL, NULL, 4, 6, 1, 1, 3, '2025-07-28 10:19:04.105063', '2025-07-28 10:19:04.105063'),
+(1137, 0, NULL, NULL, 'ēns', NULL, NULL, 0, 8, 0, 1, 3, '2025-07-28 10:19:04.107468', '2025-07-28 10:19:04.107468'),
+(1138, 1, NULL, NULL, NULL, NULL, NULL, 0, 8, 0, 1, 3, '2025-07-28 10:19:04.111235', '2025-07-28 10:19:04.111235'),
+(1139, 0, NULL, NULL, 'ūrus', NULL, NULL, 4, 8, 0, 1, 3, '2025-07-28 10:19:04.113572', '2025-07-28 10:19:04.113572'),
+(1140, 1, NULL, NULL, NULL, NULL, NULL, 4, 8, 0, 1, 3, '2025-07-28 10:19:04.117439', '2025-07-28 10:19:04.117439'),
+(1141, 0, NULL, NULL, 'us', NULL, NULL, 2, 8, 1, 1, 3, '2025-07-28 10:19:04.119791', '2025-07-28 10:19:04.119791'),
+(1142, 1, NULL, NULL, NULL, NULL, NULL, 2, 8, 1, 1, 3, '2025-07-28 10:19:04.123712', '2025-07-28 10:19:04.123712'),
+(1143, 0, NULL, NULL, 'endus', NULL, NULL, 4, 8, 1, 1, 3, '2025-07-28 10:19:04.127303', '2025-07-28 10:19:04.127303'),
+(1144, 1, NULL, NULL, NULL, NULL, NULL, 4, 8, 1, 1, 3, '2025-07-28 10:19:04.129991', '2025-07-28 10:19:04.129991'),
+(1145, 0, NULL, NULL, 'iō', NULL, NULL, 0, 0, 0, 1, 4, '2025-07-28 10:19:04.139470', '2025-07-28 10:19:04.139470'),
+(1146, 1, NULL, NULL, 'imus', NULL, NULL, 0, 0, 0, 1, 4, '2025-07-28 10:19:04.143730', '2025-07-28 10:19:04.143730'),
+(1147, 0, NULL, NULL, 'is', NULL, NULL, 0, 0, 0, 2, 4, '2025-07-28 10:19:04.146050', '2025-07-28 10:19:04.146050'),
+(1148, 1, NULL, NULL, 'itis', NULL, NULL, 0, 0, 0, 2, 4, '2025-07-28 10:19:04.150255', '2025-07-28 10:19:04.150255'),
+(1149, 0, NULL, NULL, 'it', NULL, NULL, 0, 0, 0, 3, 4, '2025-07-28 10:19:04.152675', '2025-07-28 10:19:04.152675'),
+(1150, 1, NULL, NULL, 'iunt', NULL, NULL, 0, 0, 0, 3, 4, '2025-07-28 10:19:04.156863', '2025-07-28 10:19:04.156863'),
+(1151, 0, NULL, NULL, 'iēbam', NULL, NULL, 1, 0, 0, 1, 4, '2025-07-28 10:19:04.159393', '2025-07-28 10:19:04.159393'),
+(1152, 1, NULL, NULL, 'iēbāmus', NULL, NULL, 1, 0, 0, 1, 4, '2025-07-28 10:19:04.163169', '2025-07-28 10:19:04.163169'),
+(1153, 0, NULL, NULL, 'iēbās', NULL, NULL, 1, 0, 0, 2, 4, '2025-07-28 10:19:04.165494', '2025-07-28 10:19:04.165494'),
+(1154, 1, NULL, NULL, 'iēbātis', NULL, NULL, 1, 0, 0, 2, 4, '2025-07-28 10:19:04.168995', '2025-07-28 10:19:04.168995'),
+(1155, 0, NULL, NULL, 'iēbat', NULL, NULL, 1, 0, 0, 3, 4, '2025-07-28 10:19:04.171350', '2025-07-28 10:19:04.171350'),
+(1156, 1, NULL, NULL, 'iēbant', NULL, NULL, 1, 0, 0, 3, 4, '2025-07-28 10:19:04.173829', '2025-07-28 10:19:04.173829'),
+(1157, 0, NULL, NULL, 'ī', NULL, NULL, 2, 0, 0, 1, 4, '2025-07-28 10:19:04.177597', '2025-07-28 10:19:04.177597'),
+(1158, 1, NULL, NULL, 'imus', NULL, NULL, 2, 0, 0, 1, 4, '2025-07-28 10:19:04.180077', '2025-07-28 10:19:04.180077'),
+(1159, 0, NULL, NULL, 'istī', NULL, NULL, 2, 0, 0, 2, 4, '2025-07-28 10:19:04.183591', '2025-07-28 10:19:04.183591'),
+(1160, 1, NULL, NULL, 'istis', NULL, NULL, 2, 0, 0, 2, 4, '2025-07-28 10:19:04.186208', '2025-07-28 10:19:04.186208'),
+(1161, 0, NULL, NULL, 'it', NULL, NULL, 2, 0, 0, 3, 4, '2025-07-28 10:19:04.189726', '2025-07-28 10:19:04.189726'),
+(1162, 1, NULL, NULL, 'ērunt', NULL, NULL, 2, 0, 0, 3, 4, '2025-07-28 10:19:04.192192', '2025-07-28 10:19:04.192192'),
+(1163, 0, NULL, NULL, 'eram', NULL, NULL, 3, 0, 0, 1, 4, '2025-07-28 10:19:04.194628', '2025-07-28 10:19:04.194628'),
+(1164, 1, NULL, NULL, 'erāmus', NULL, NULL, 3, 0, 0, 1, 4, '2025-07-28 10:19:04.197753', '2025-07-28 10:19:04.197753'),
+(1165, 0, NULL, NULL, 'erās', NULL, NULL, 3, 0, 0, 2, 4, '2025-07-28 10:19:04.200160', '2025-07-28 10:19:04.200160'),
+(1166, 1, NULL, NULL, 'erātis', NULL, NULL, 3, 0, 0, 2, 4, '2025-07-28 10:19:04.203288', '2025-07-28 10:19:04.203288'),
+(1167, 0, NULL, NULL, 'erat', NULL, NULL, 3, 0, 0, 3, 4, '2025-07-28 10:19:04.207789', '2025-07-28 10:19:04.207789'),
+(1168, 1, NULL, NULL, 'erant', NULL, NULL, 3, 0, 0, 3, 4, '2025-07-28 10:19:04.210117', '2025-07-28 10:19:04.210117'),
+(1169, 0, NULL, NULL, 'iam', NULL, NULL, 4, 0, 0, 1, 4, '2025-07-28 10:19:04.212475', '2025-07-28 10:19:04.212475'),
+(1170, 1, NULL, NULL, 'iēmus', NULL, NULL, 4, 0, 0, 1, 4, '2025-07-28 10:19:04.214749', '2025-07-28 10:19:04.214749'),
+(1171, 0, NULL, NULL, 'iēs', NULL, NULL, 4, 0, 0, 2, 4, '2025-07-28 10:19:04.217070', '2025-07-28 10:19:04.217070'),
+(1172, 1, NULL, NULL, 'iētis', NULL, NULL, 4, 0, 0, 2, 4, '2025-07-28 10:19:04.219342', '2025-07-28 10:19:04.219342'),
+(1173, 0, NULL, NULL, 'iet', NULL, NULL, 4, 0, 0, 3, 4, '2025-07-28 10:19:04.221683', '2025-07-28 10:19:04.221683'),
+(1174, 1, NULL, NULL, 'ient', NULL, NULL, 4, 0, 0, 3, 4, '2025-07-28 10:19:04.224081', '2025-07-28 10:19:04.224081'),
+(1175, 0, NULL, NULL, 'erō', NULL, NULL, 5, 0, 0, 1, 4, '2025-07-28 10:19:04.226375', '2025-07-28 10:19:04.226375'),
+(1176, 1, NULL, NULL, 'erimus', NULL, NULL, 5, 0, 0, 1, 4, '2025-07-28 10:19:04.228807', '2025-07-28 10:19:04.228807'),
+(1177, 0, NULL, NULL, 'eris', NULL, NULL, 5, 0, 0, 2, 4, '2025-07-28 10:19:04.231052', '2025-07-28 10:19:04.231052'),
+(1178, 1, NULL, NULL, 'eritis', NULL, NULL, 5, 0, 0, 2, 4, '2025-07-28 10:19:04.233268', '2025-07-28 10:19:04.233268'),
+(1179, 0, NULL, NULL, 'erit', NULL, NULL, 5, 0, 0, 3, 4, '2025-07-28 10:19:04.235526', '2025-07-28 10:19:04.235526'),
+(1180, 1, NULL, NULL, 'erint', NULL, NULL, 5, 0, 0, 3, 4, '2025-07-28 10:19:04.237790', '2025-07-28 10:19:04.237790'),
+(1181, 0, NULL, NULL, 'ior', NULL, NULL, 0, 0, 1, 1, 4, '2025-07-28 10:19:04.240093', '2025-07-28 10:19:04.240093'),
+(1182, 1, NULL, NULL, 'imur', NULL, NULL, 0, 0, 1, 1, 4, '2025-07-28 10:19:04.242326', '2025-07-28 10:19:04.242326'),
+(1183, 0, NULL, NULL, 'eris', NULL, NULL, 0, 0, 1, 2, 4, '2025-07-28 10:19:04.244551', '2025-07-28 10:19:04.244551'),
+(1184, 1, NULL, NULL, 'iminī', NULL, NULL, 0, 0, 1, 2, 4, '2025-07-28 10:19:04.246798', '2025-07-28 10:19:04.246798'),
+(1185, 0, NULL, NULL, 'itur', NULL, NULL, 0, 0, 1, 3, 4, '2025-07-28 10:19:04.249033', '2025-07-28 10:19:04.249033'),
+(1186, 1, NULL, NULL, 'iuntur', NULL, NULL, 0, 0, 1, 3, 4, '2025-07-28 10:19:04.251283', '2025-07-28 10:19:04.251283'),
+(1187, 0, NULL, NULL, 'iēbar', NULL, NULL, 1, 0, 1, 1, 4, '2025-07-28 10:19:04.253565', '2025-07-28 10:19:04.253565'),
+(1188, 1, NULL, NULL, 'iēbāmur', NULL, NULL, 1, 0, 1, 1, 4, '2025-07-28 10:19:04.260320', '2025-07-28 10:19:04.260320'),
+(1189, 0, NULL, NULL, 'iēbāris', NULL, NULL, 1, 0, 1, 2, 4, '2025-07-28 10:19:04.264156', '2025-07-28 10:19:04.264156'),
+(1190, 1, NULL, NULL, 'iēbāminī', NULL, NULL, 1, 0, 1, 2, 4, '2025-07-28 10:19:04.266820', '2025-07-28 10:19:04.266820'),
+(1191, 0, NULL, NULL, 'iēbātur', NULL, NULL, 1, 0, 1, 3, 4, '2025-07-28 10:19:04.270378', '2025-07-28 10:19:04.270378'),
+(1192, 1, NULL, NULL, 'iēbantur', NULL, NULL, 1, 0, 1, 3, 4, '2025-07-28 10:19:04.273030', '2025-07-28 10:19:04.273030'),
+(1193, 0, NULL, NULL, 'iar', NULL, NULL, 4, 0, 1, 1, 4, '2025-07-28 10:19:04.276907', '2025-07-28 10:19:04.276907'),
+(1194, 1, NULL, NULL, 'iēmur', NULL, NULL, 4, 0, 1, 1, 4, '2025-07-28 10:19:04.279159', '2025-07-28 10:19:04.279159'),
+(1195, 0, NULL, NULL, 'iēris', NULL, NULL, 4, 0, 1, 2, 4, '2025-07-28 10:19:04.282619', '2025-07-28 10:19:04.282619'),
+(1196, 1, NULL, NULL, 'iēminī', NULL, NULL, 4, 0, 1, 2, 4, '2025-07-28 10:19:04.285247', '2025-07-28 10:19:04.285247'),
+(1197, 0, NULL, NULL, 'iētur', NULL, NULL, 4, 0, 1, 3, 4, '2025-07-28 10:19:04.289131', '2025-07-28 10:19:04.289131'),
+(1198, 1, NULL, NULL, 'ientur', NULL, NULL, 4, 0, 1, 3, 4, '2025-07-28 10:19:04.291464', '2025-07-28 10:19:04.291464'),
+(1199, 0, NULL, NULL, 'sum', NULL, NULL, 2, 0, 1, 1, 4, '2025-07-28 10:19:04.295322', '2025-07-28 10:19:04.295322'),
+(1200, 1, NULL, NULL, 'sumus', NULL, NULL, 2, 0, 1, 1, 4, '2025-07-28 10:19:04.297626', '2025-07-28 10:19:04.297626'),
+(1201, 0, NULL, NULL, 'es', NULL, NULL, 2, 0, 1, 2, 4, '2025-07-28 10:19:04.301571', '2025-07-28 10:19:04.301571'),
+(1202, 1, NULL, NULL, 'estis', NULL, NULL, 2, 0, 1, 2, 4, '2025-07-28 10:19:04.303893', '2025-07-28 10:19:04.303893'),
+(1203, 0, NULL, NULL, 'est', NULL, NULL, 2, 0, 1, 3, 4, '2025-07-28 10:19:04.307778', '2025-07-28 10:19:04.307778'),
+(1204, 1, NULL, NULL, 'sunt', NULL, NULL, 2, 0, 1, 3, 4, '2025-07-28 10:19:04.310064', '2025-07-28 10:19:04.310064'),
+(1205, 0, NULL, NULL, 'eram', NULL, NULL, 3, 0, 1, 1, 4, '2025-07-28 10:19:04.313381', '2025-07-28 10:19:04.313381'),
+(1206, 1, NULL, NULL, 'erāmus', NULL, NULL, 3, 0, 1, 1, 4, '2025-07-28 10:19:04.316034', '2025-07-28 10:19:04.316034'),
+(1207, 0, NULL, NULL, 'erās', NULL, NULL, 3, 0, 1, 2, 4, '2025-07-28 10:19:04.319487', '2025-07-28 10:19:04.319487'),
+(1208, 1, NULL, NULL, 'erātis', NULL, NULL, 3, 0, 1, 2, 4, '2025-07-28 10:19:04.321804', '2025-07-28 10:19:04.321804'),
+(1209, 0, NULL, NULL, 'erat', NULL, NULL, 3, 0, 1, 3, 4, '2025-07-28 10:19:04.324205', '2025-07-28 10:19:04.324205'),
+(1210, 1, NULL, NULL, 'erant', NULL, NULL, 3, 0, 1, 3, 4, '2025-07-28 10:19:04.327523', '2025-07-28 10:19:04.327523'),
+(1211, 0, NULL, NULL, 'erō', NULL, NULL, 5, 0, 1, 1, 4, '2025-07-28 10:19:04.329878', '2025-07-28 10:19:04.329878'),
+(1212, 1, NULL, NULL, 'erimus', NULL, NULL, 5, 0, 1, 1, 4, '2025-07-28 10:19:04.333072', '2025-07-28 10:19:04.333072'),
+(1213, 0, NULL, NULL, 'eris', NULL, NULL, 5, 0, 1, 2, 4, '2025-07-28 10:19:04.335381', '2025-07-28 10:19:04.335381'),
+(1214, 1, NULL, NULL, 'eritis', NULL, NULL, 5, 0, 1, 2, 4, '2025-07-28 10:19:04.338634', '2025-07-28 10:19:04.338634'),
+(1215, 0, NULL, NULL, 'erit', NULL, NULL, 5, 0, 1, 3, 4, '2025-07-28 10:19:04.341028', '2025-07-28 10:19:04.341028'),
+(1216, 1, NULL, NULL, 'erunt', NULL, NULL, 5, 0, 1, 3, 4, '2025-07-28 10:19:04.343360', '2025-07-28 10:19:04.343360'),
+(1217, 0, NULL, NULL, 'iam', NULL, NULL, 0, 1, 0, 1, 4, '2025-07-28 10:19:04.346529', '2025-07-28 10:19:04.346529'),
+(1218, 1, NULL, NULL, 'iāmus', NULL, NULL, 0, 1, 0, 1, 4, '2025-07-28 10:19:04.348831', '2025-07-28 10:19:04.348831'),
+(1219, 0, NULL, NULL, 'iās', NULL, NULL, 0, 1, 0, 2, 4, '2025-07-28 10:19:04.351766', '2025-07-28 10:19:04.351766'),
+(1220, 1, NULL, NULL, 'iātis', NULL, NULL, 0, 1, 0, 2, 4, '2025-07-28 10:19:04.354095', '2025-07-28 10:19:04.354095'),
+(1221, 0, NULL, NULL, 'iat', NULL, NULL, 0, 1, 0, 3, 4, '2025-07-28 10:19:04.358763', '2025-07-28 10:19:04.358763'),
+(1222, 1, NULL, NULL, 'iant', NULL, NULL, 0, 1, 0, 3, 4, '2025-07-28 10:19:04.361064', '2025-07-28 10:19:04.361064'),
+(1223, 0, NULL, NULL, 'erem', NULL, NULL, 1, 1, 0, 1, 4, '2025-07-28 10:19:04.363326', '2025-07-28 10:19:04.363326'),
+(1224, 1, NULL, NULL, 'erēmus', NULL, NULL, 1, 1, 0, 1, 4, '2025-07-28 10:19:04.365608', '2025-07-28 10:19:04.365608'),
+(1225, 0, NULL, NULL, 'erēs', NULL, NULL, 1, 1, 0, 2, 4, '2025-07-28 10:19:04.367888', '2025-07-28 10:19:04.367888'),
+(1226, 1, NULL, NULL, 'erētis', NULL, NULL, 1, 1, 0, 2, 4, '2025-07-28 10:19:04.370100', '2025-07-28 10:19:04.370100'),
+(1227, 0, NULL, NULL, 'eret', NULL, NULL, 1, 1, 0, 3, 4, '2025-07-28 10:19:04.372385', '2025-07-28 10:19:04.372385'),
+(1228, 1, NULL, NULL, 'erent', NULL, NULL, 1, 1, 0, 3, 4, '2025-07-28 10:19:04.374642', '2025-07-28 10:19:04.374642'),
+(1229, 0, NULL, NULL, 'erim', NULL, NULL, 2, 1, 0, 1, 4, '2025-07-28 10:19:04.377002', '2025-07-28 10:19:04.377002'),
+(1230, 1, NULL, NULL, 'erīmus', NULL, NULL, 2, 1, 0, 1, 4, '2025-07-28 10:19:04.379274', '2025-07-28 10:19:04.379274'),
+(1231, 0, NULL, NULL, 'erīs', NULL, NULL, 2, 1, 0, 2, 4, '2025-07-28 10:19:04.381518', '2025-07-28 10:19:04.381518'),
+(1232, 1, NULL, NULL, 'erītis', NULL, NULL, 2, 1, 0, 2, 4, '2025-07-28 10:19:04.383793', '2025-07-28 10:19:04.383793'),
+(1233, 0, NULL, NULL, 'erit', NULL, NULL, 2, 1, 0, 3, 4, '2025-07-28 10:19:04.386045', '2025-07-28 10:19:04.386045'),
+(1234, 1, NULL, NULL, 'erint', NULL, NULL, 2, 1, 0, 3, 4, '2025-07-28 10:19:04.388276', '2025-07-28 10:19:04.388276'),
+(1235, 0, NULL, NULL, 'issem', NULL, NULL, 3, 1, 0, 1, 4, '2025-07-28 10:19:04.390566', '2025-07-28 10:19:04.390566'),
+(1236, 1, NULL, NULL, 'issēmus', NULL, NULL, 3, 1, 0, 1, 4, '2025-07-28 10:19:04.392816', '2025-07-28 10:19:04.392816'),
+(1237, 0, NULL, NULL, 'issēs', NULL, NULL, 3, 1, 0, 2, 4, '2025-07-28 10:19:04.395062', '2025-07-28 10:19:04.395062'),
+(1238, 1, NULL, NULL, 'issētis', NULL, NULL, 3, 1, 0, 2, 4, '2025-07-28 10:19:04.397295', '2025-07-28 10:19:04.397295'),
+(1239, 0, NULL, NULL, 'isset', NULL, NULL, 3, 1, 0, 3, 4, '2025-07-28 10:19:04.403845', '2025-07-28 10:19:04.403845'),
+(1240, 1, NULL, NULL, 'issent', NULL, NULL, 3, 1, 0, 3, 4, '2025-07-28 10:19:04.406315', '2025-07-28 10:19:04.406315'),
+(1241, 0, NULL, NULL, 'iar', NULL, NULL, 0, 1, 1, 1, 4, '2025-07-28 10:19:04.410262', '2025-07-28 10:19:04.410262'),
+(1242, 1, NULL, NULL, 'iāmur', NULL, NULL, 0, 1, 1, 1, 4, '2025-07-28 10:19:04.412633', '2025-07-28 10:19:04.412633'),
+(1243, 0, NULL, NULL, 'iāris', NULL, NULL, 0, 1, 1, 2, 4, '2025-07-28 10:19:04.416349', '2025-07-28 10:19:04.416349'),
+(1244, 1, NULL, NULL, 'iāminī', NULL, NULL, 0, 1, 1, 2, 4, '2025-07-28 10:19:04.418720', '2025-07-28 10:19:04.418720'),
+(1245, 0, NULL, NULL, 'iātur', NULL, NULL, 0, 1, 1, 3, 4, '2025-07-28 10:19:04.422599', '2025-07-28 10:19:04.422599'),
+(1246, 1, NULL, NULL, 'iantur', NULL, NULL, 0, 1, 1, 3, 4, '2025-07-28 10:19:04.424937', '2025-07-28 10:19:04.424937'),
+(1247, 0, NULL, NULL, 'erer', NULL, NULL, 1, 1, 1, 1, 4, '2025-07-28 10:19:04.428503', '2025-07-28 10:19:04.428503'),
+(1248, 1, NULL, NULL, 'erēmur', NULL, NULL, 1, 1, 1, 1, 4, '2025-07-28 10:19:04.431833', '2025-07-28 10:19:04.431833'),
+(1249, 0, NULL, NULL, 'erēris', NULL, NULL, 1, 1, 1, 2, 4, '2025-07-28 10:19:04.434426', '2025-07-28 10:19:04.434426'),
+(1250, 1, NULL, NULL, 'erēminī', NULL, NULL, 1, 1, 1, 2, 4, '2025-07-28 10:19:04.438109', '2025-07-28 10:19:04.438109'),
+(1251, 0, NULL, NULL, 'erētur', NULL, NULL, 1, 1, 1, 3, 4, '2025-07-28 10:19:04.440477', '2025-07-28 10:19:04.440477'),
+(1252, 1, NULL, NULL, 'erentur', NULL, NULL, 1, 1, 1, 3, 4, '2025-07-28 10:19:04.444108', '2025-07-28 10:19:04.444108'),
+(1253, 0, NULL, NULL, 'sim', NULL, NULL, 2, 1, 1, 1, 4, '2025-07-28 10:19:04.446446', '2025-07-28 10:19:04.446446'),
+(1254, 1, NULL, NULL, 'sīmus', NULL, NULL, 2, 1, 1, 1, 4, '2025-07-28 10:19:04.450065', '2025-07-28 10:19:04.450065'),
+(1255, 0, NULL, NULL, 'sīs', NULL, NULL, 2, 1, 1, 2, 4, '2025-07-28 10:19:04.452334', '2025-07-28 10:19:04.452334'),
+(1256, 1, NULL, NULL, 'sītis', NULL, NULL, 2, 1, 1, 2, 4, '2025-07-28 10:19:04.455615', '2025-07-28 10:19:04.455615'),
+(1257, 0, NULL, NULL, 'sit', NULL, NULL, 2, 1, 1, 3, 4, '2025-07-28 10:19:04.458118', '2025-07-28 10:19:04.458118'),
+(1258, 1, NULL, NULL, 'sint', NULL, NULL, 2, 1, 1, 3, 4, '2025-07-28 10:19:04.461309', '2025-07-28 10:19:04.461309'),
+(1259, 0, NULL, NULL, 'essem', NULL, NULL, 3, 1, 1, 1, 4, '2025-07-28 10:19:04.463586', '2025-07-28 10:19:04.463586'),
+(1260, 1, NULL, NULL, 'essēmus', NULL, NULL, 3, 1, 1, 1, 4, '2025-07-28 10:19:04.465902', '2025-07-28 10:19:04.465902'),
+(1261, 0, NULL, NULL, 'essēs', NULL, NULL, 3, 1, 1, 2, 4, '2025-07-28 10:19:04.469213', '2025-07-28 10:19:04.469213'),
+(1262, 1, NULL, NULL, 'essētis', NULL, NULL, 3, 1, 1, 2, 4, '2025-07-28 10:19:04.471561', '2025-07-28 10:19:04.471561'),
+(1263, 0, NULL, NULL, 'esset', NULL, NULL, 3, 1, 1, 3, 4, '2025-07-28 10:19:04.474776', '2025-07-28 10:19:04.474776'),
+(1264, 1, NULL, NULL, 'essent', NULL, NULL, 3, 1, 1, 3, 4, '2025-07-28 10:19:04.477079', '2025-07-28 10:19:04.477079'),
+(1265, 0, NULL, NULL, 'e', NULL, NULL, 0, 2, 0, 2, 4, '2025-07-28 10:19:04.480246', '2025-07-28 10:19:04.480246'),
+(1266, 1, NULL, NULL, 'ite', NULL, NULL, 0, 2, 0, 2, 4, '2025-07-28 10:19:04.482552', '2025-07-28 10:19:04.482552'),
+(1267, 0, NULL, NULL, 'itō', NULL, NULL, 4, 2, 0, 2, 4, '2025-07-28 10:19:04.484912', '2025-07-28 10:19:04.484912'),
+(1268, 1, NULL, NULL, 'itōte', NULL, NULL, 4, 2, 0, 2, 4, '2025-07-28 10:19:04.488087', '2025-07-28 10:19:04.488087'),
+(1269, 0, NULL, NULL, 'itō', NULL, NULL, 4, 2, 0, 3, 4, '2025-07-28 10:19:04.490453', '2025-07-28 10:19:04.490453'),
+(1270, 1, NULL, NULL, 'iuntō', NULL, NULL, 4, 2, 0, 3, 4, '2025-07-28 10:19:04.495503', '2025-07-28 10:19:04.495503'),
+(1271, 0, NULL, NULL, 'ere', NULL, NULL, 0, 2, 1, 2, 4, '2025-07-28 10:19:04.497842', '2025-07-28 10:19:04.497842'),
+(1272, 1, NULL, NULL, 'iminī', NULL, NULL, 0, 2, 1, 2, 4, '2025-07-28 10:19:04.500113', '2025-07-28 10:19:04.500113'),
+(1273, 0, NULL, NULL, 'itor', NULL, NULL, 4, 2, 1, 2, 4, '2025-07-28 10:19:04.502452', '2025-07-28 10:19:04.502452'),
+(1274, 1, NULL, NULL, NULL, NULL, NULL, 4, 2, 1, 2, 4, '2025-07-28 10:19:04.504790', '2025-07-28 10:19:04.504790'),
+(1275, 0, NULL, NULL, 'itor', NULL, NULL, 4, 2, 1, 3, 4, '2025-07-28 10:19:04.507128', '2025-07-28 10:19:04.507128'),
+(1276, 1, NULL, NULL, 'iuntor', NULL, NULL, 4, 2, 1, 3, 4, '2025-07-28 10:19:04.509365', '2025-07-28 10:19:04.509365'),
+(1277, 0, NULL, NULL, 'ere', NULL, NULL, 0, 6, 0, 1, 4, '2025-07-28 10:19:04.511631', '2025-07-28 10:19:04.511631'),
+(1278, 1, NULL, NULL, NULL, NULL, NULL, 0, 6, 0, 1, 4, '2025-07-28 10:19:04.513914', '2025-07-28 10:19:04.513914'),
+(1279, 0, NULL, NULL, 'isse', NULL, NULL, 2, 6, 0, 1, 4, '2025-07-28 10:19:04.516167', '2025-07-28 10:19:04.516167'),
+(1280, 1, NULL, NULL, NULL, NULL, NULL, 2, 6, 0, 1, 4, '2025-07-28 10:19:04.518445', '2025-07-28 10:19:04.518445'),
+(1281, 0, NULL, NULL, 'esse', NULL, NULL, 4, 6, 0, 1, 4, '2025-07-28 10:19:04.520740', '2025-07-28 10:19:04.520740'),
+(1282, 1, NULL, NULL, NULL, NULL, NULL, 4, 6, 0, 1, 4, '2025-07-28 10:19:04.523042', '2025-07-28 10:19:04.523042'),
+(1283, 0, NULL, NULL, 'ī', NULL, NULL, 0, 6, 1, 1, 4, '2025-07-28 10:19:04.525322', '2025-07-28 10:19:04.525322'),
+(1284, 1, NULL, NULL, NULL, NULL, NULL, 0, 6, 1, 1, 4, '2025-07-28 10:19:04.527592', '2025-07-28 10:19:04.527592'),
+(1285, 0, NULL, NULL, 'esse', NULL, NULL, 2, 6, 1, 1, 4, '2025-07-28 10:19:04.529832', '2025-07-28 10:19:04.529832'),
+(1286, 1, NULL, NULL, NULL, NULL, NULL, 2, 6, 1, 1, 4, '2025-07-28 10:19:04.536393', '2025-07-28 10:19:04.536393'),
+(1287, 0, NULL, NULL, 'īrī', NULL, NULL, 4, 6, 1, 1, 4, '2025-07-28 10:19:04.538871', '2025-07-28 10:19:04.538871'),
+(1288, 1, NULL, NULL, NULL, NULL, NULL, 4, 6, 1, 1, 4, '2025-07-28 10:19:04.543040', '2025-07-28 10:19:04.543040'),
+(1289, 0, NULL, NULL, 'iēns', NULL, NULL, 0, 8, 0, 1, 4, '2025-07-28 10:19:04.545435', '2025-07-28 10:19:04.545435'),
+(1290, 1, NULL, NULL, NULL, NULL, NULL, 0, 8, 0, 1, 4, '2025-07-28 10:19:04.549357', '2025-07-28 10:19:04.549357'),
+(1291, 0, NULL, NULL, 'ūrus', NULL, NULL, 4, 8, 0, 1, 4, '2025-07-28 10:19:04.551744', '2025-07-28 10:19:04.551744'),
+(1292, 1, NULL, NULL, NULL, NULL, NULL, 4, 8, 0, 1, 4, '2025-07-28 10:19:04.555708', '2025-07-28 10:19:04.555708'),
+(1293, 0, NULL, NULL, 'us', NULL, NULL, 2, 8, 1, 1, 4, '2025-07-28 10:19:04.558097', '2025-07-28 10:19:04.558097'),
+(1294, 1, NULL, NULL, NULL, NULL, NULL, 2, 8, 1, 1, 4, '2025-07-28 10:19:04.561844', '2025-07-28 10:19:04.561844'),
+(1295, 0, NULL, NULL, 'iendus', NULL, NULL, 4, 8, 1, 1, 4, '2025-07-28 10:19:04.564129', '2025-07-28 10:19:04.564129'),
+(1296, 1, NULL, NULL, NULL, NULL, NULL, 4, 8, 1, 1, 4, '2025-07-28 10:19:04.567913', '2025-07-28 10:19:04.567913'),
+(1297, 0, NULL, NULL, 'iō', NULL, NULL, 0, 0, 0, 1, 5, '2025-07-28 10:19:04.576444', '2025-07-28 10:19:04.576444'),
+(1298, 1, NULL, NULL, 'īmus', NULL, NULL, 0, 0, 0, 1, 5, '2025-07-28 10:19:04.580365', '2025-07-28 10:19:04.580365'),
+(1299, 0, NULL, NULL, 'īs', NULL, NULL, 0, 0, 0, 2, 5, '2025-07-28 10:19:04.582736', '2025-07-28 10:19:04.582736'),
+(1300, 1, NULL, NULL, 'ītis', NULL, NULL, 0, 0, 0, 2, 5, '2025-07-28 10:19:04.586311', '2025-07-28 10:19:04.586311'),
+(1301, 0, NULL, NULL, 'it', NULL, NULL, 0, 0, 0, 3, 5, '2025-07-28 10:19:04.588620', '2025-07-28 10:19:04.588620'),
+(1302, 1, NULL, NULL, 'iunt', NULL, NULL, 0, 0, 0, 3, 5, '2025-07-28 10:19:04.591780', '2025-07-28 10:19:04.591780'),
+(1303, 0, NULL, NULL, 'iēbam', NULL, NULL, 1, 0, 0, 1, 5, '2025-07-28 10:19:04.594090', '2025-07-28 10:19:04.594090'),
+(1304, 1, NULL, NULL, 'iēbāmus', NULL, NULL, 1, 0, 0, 1, 5, '2025-07-28 10:19:04.597202', '2025-07-28 10:19:04.597202'),
+(1305, 0, NULL, NULL, 'iēbās', NULL, NULL, 1, 0, 0, 2, 5, '2025-07-28 10:19:04.599503', '2025-07-28 10:19:04.599503'),
+(1306, 1, NULL, NULL, 'iēbātis', NULL, NULL, 1, 0, 0, 2, 5, '2025-07-28 10:19:04.602778', '2025-07-28 10:19:04.602778'),
+(1307, 0, NULL, NULL, 'iēbat', NULL, NULL, 1, 0, 0, 3, 5, '2025-07-28 10:19:04.605100', '2025-07-28 10:19:04.605100'),
+(1308, 1, NULL, NULL, 'iēbant', NULL, NULL, 1, 0, 0, 3, 5, '2025-07-28 10:19:04.607500', '2025-07-28 10:19:04.607500'),
+(1309, 0, NULL, NULL, 'ī', NULL, NULL, 2, 0, 0, 1, 5, '2025-07-28 10:19:04.610574', '2025-07-28 10:19:04.610574'),
+(1310, 1, NULL, NULL, 'imus', NULL, NULL, 2, 0, 0, 1, 5, '2025-07-28 10:19:04.612905', '2025-07-28 10:19:04.612905'),
+(1311, 0, NULL, NULL, 'istī', NULL, NULL, 2, 0, 0, 2, 5, '2025-07-28 10:19:04.619449', '2025-07-28 10:19:04.619449'),
+(1312, 1, NULL, NULL, 'istis', NULL, NULL, 2, 0, 0, 2, 5, '2025-07-28 10:19:04.621798', '2025-07-28 10:19:04.621798'),
+(1313, 0, NULL, NULL, 'it', NULL, NULL, 2, 0, 0, 3, 5, '2025-07-28 10:19:04.624117', '2025-07-28 10:19:04.624117'),
+(1314, 1, NULL, NULL, 'ērunt', NULL, NULL, 2, 0, 0, 3, 5, '2025-07-28 10:19:04.626451', '2025-07-28 10:19:04.626451'),
+(1315, 0, NULL, NULL, 'eram', NULL, NULL, 3, 0, 0, 1, 5, '2025-07-28 10:19:04.628844', '2025-07-28 10:19:04.628844'),
+(1316, 1, NULL, NULL, 'erāmus', NULL, NULL, 3, 0, 0, 1, 5, '2025-07-28 10:19:04.631108', '2025-07-28 10:19:04.631108'),
+(1317, 0, NULL, NULL, 'erās', NULL, NULL, 3, 0, 0, 2, 5, '2025-07-28 10:19:04.633411', '2025-07-28 10:19:04.633411'),
+(1318, 1, NULL, NULL, 'erātis', NULL, NULL, 3, 0, 0, 2, 5, '2025-07-28 10:19:04.635742', '2025-07-28 10:19:04.635742'),
+(1319, 0, NULL, NULL, 'erat', NULL, NULL, 3, 0, 0, 3, 5, '2025-07-28 10:19:04.638050', '2025-07-28 10:19:04.638050'),
+(1320, 1, NULL, NULL, 'erant', NULL, NULL, 3, 0, 0, 3, 5, '2025-07-28 10:19:04.640347', '2025-07-28 10:19:04.640347'),
+(1321, 0, NULL, NULL, 'iam', NULL, NULL, 4, 0, 0, 1, 5, '2025-07-28 10:19:04.642613', '2025-07-28 10:19:04.642613'),
+(1322, 1, NULL, NULL, 'iēmus', NULL, NULL, 4, 0, 0, 1, 5, '2025-07-28 10:19:04.644942', '2025-07-28 10:19:04.644942'),
+(1323, 0, NULL, NULL, 'iēs', NULL, NULL, 4, 0, 0, 2, 5, '2025-07-28 10:19:04.647168', '2025-07-28 10:19:04.647168'),
+(1324, 1, NULL, NULL, 'iētis', NULL, NULL, 4, 0, 0, 2, 5, '2025-07-28 10:19:04.649417', '2025-07-28 10:19:04.649417'),
+(1325, 0, NULL, NULL, 'iet', NULL, NULL, 4, 0, 0, 3, 5, '2025-07-28 10:19:04.651726', '2025-07-28 10:19:04.651726'),
+(1326, 1, NULL, NULL, 'ient', NULL, NULL, 4, 0, 0, 3, 5, '2025-07-28 10:19:04.654048', '2025-07-28 10:19:04.654048'),
+(1327, 0, NULL, NULL, 'erō', NULL, NULL, 5, 0, 0, 1, 5, '2025-07-28 10:19:04.656391', '2025-07-28 10:19:04.656391'),
+(1328, 1, NULL, NULL, 'erimus', NULL, NULL, 5, 0, 0, 1, 5, '2025-07-28 10:19:04.662524', '2025-07-28 10:19:04.662524'),
+(1329, 0, NULL, NULL, 'eris', NULL, NULL, 5, 0, 0, 2, 5, '2025-07-28 10:19:04.664957', '2025-07-28 10:19:04.664957'),
+(1330, 1, NULL, NULL, 'eritis', NULL, NULL, 5, 0, 0, 2, 5, '2025-07-28 10:19:04.668974', '2025-07-28 10:19:04.668974'),
+(1331, 0, NULL, NULL, 'erit', NULL, NULL, 5, 0, 0, 3, 5, '2025-07-28 10:19:04.671348', '2025-07-28 10:19:04.671348'),
+(1332, 1, NULL, NULL, 'erint', NULL, NULL, 5, 0, 0, 3, 5, '2025-07-28 10:19:04.675343', '2025-07-28 10:19:04.675343'),
+(1333, 0, NULL, NULL, 'ior', NULL, NULL, 0, 0, 1, 1, 5, '2025-07-28 10:19:04.677727', '2025-07-28 10:19:04.677727'),
+(1334, 1, NULL, NULL, 'īmur', NULL, NULL, 0, 0, 1, 1, 5, '2025-07-28 10:19:04.681456', '2025-07-28 10:19:04.681456'),
+(1335, 0, NULL, NULL, 'īris', NULL, NULL, 0, 0, 1, 2, 5, '2025-07-28 10:19:04.683807', '2025-07-28 10:19:04.683807'),
+(1336, 1, NULL, NULL, 'īminī', NULL, NULL, 0, 0, 1, 2, 5, '2025-07-28 10:19:04.687674', '2025-07-28 10:19:04.687674'),
+(1337, 0, NULL, NULL, 'ītur', NULL, NULL, 0, 0, 1, 3, 5, '2025-07-28 10:19:04.690090', '2025-07-28 10:19:04.690090'),
+(1338, 1, NULL, NULL, 'iuntur', NULL, NULL, 0, 0, 1, 3, 5, '2025-07-28 10:19:04.693807', '2025-07-28 10:19:04.693807'),
+(1339, 0, NULL, NULL, 'iēbar', NULL, NULL, 1, 0, 1, 1, 5, '2025-07-28 10:19:04.697264', '2025-07-28 10:19:04.697264'),
+(1340, 1, NULL, NULL, 'iēbāmur', NULL, NULL, 1, 0, 1, 1, 5, '2025-07-28 10:19:04.699957', '2025-07-28 10:19:04.699957'),
+(1341, 0, NULL, NULL, 'iēbāris', NULL, NULL, 1, 0, 1, 2, 5, '2025-07-28 10:19:04.703270', '2025-07-28 10:19:04.703270'),
+(1342, 1, NULL, NULL, 'iēbāminī', NULL, NULL, 1, 0, 1, 2, 5, '2025-07-28 10:19:04.705924', '2025-07-28 10:19:04.705924'),
+(1343, 0, NULL, NULL, 'iēbātur', NULL, NULL, 1, 0, 1, 3, 5, '2025-07-28 10:19:04.708970', '2025-07-28 10:19:04.708970'),
+(1344, 1, NULL, NULL, 'iēbantur', NULL, NULL, 1, 0, 1, 3, 5, '2025-07-28 10:19:04.711228', '2025-07-28 10:19:04.711228'),
+(1345, 0, NULL, NULL, 'iar', NULL, NULL, 4, 0, 1, 1, 5, '2025-07-28 10:19:04.713466', '2025-07-28 10:19:04.713466'),
+(1346, 1, NULL, NULL, 'iēmur', NULL, NULL, 4, 0, 1, 1, 5, '2025-07-28 10:19:04.716342', '2025-07-28 10:19:04.716342'),
+(1347, 0, NULL, NULL, 'iēris', NULL, NULL, 4, 0, 1, 2, 5, '2025-07-28 10:19:04.718664', '2025-07-28 10:19:04.718664'),
+(1348, 1, NULL, NULL, 'iēminī', NULL, NULL, 4, 0, 1, 2, 5, '2025-07-28 10:19:04.721851', '2025-07-28 10:19:04.721851'),
+(1349, 0, NULL, NULL, 'iētur', NULL, NULL, 4, 0, 1, 3, 5, '2025-07-28 10:19:04.724241', '2025-07-28 10:19:04.724241'),
+(1350, 1, NULL, NULL, 'ientur', NULL, NULL, 4, 0, 1, 3, 5, '2025-07-28 10:19:04.727307', '2025-07-28 10:19:04.727307'),
+(1351, 0, NULL, NULL, 'sum', NULL, NULL, 2, 0, 1, 1, 5, '2025-07-28 10:19:04.729658', '2025-07-28 10:19:04.729658'),
+(1352, 1, NULL, NULL, 'sumus', NULL, NULL, 2, 0, 1, 1, 5, '2025-07-28 10:19:04.732630', '2025-07-28 10:19:04.732630'),
+(1353, 0, NULL, NULL, 'es', NULL, NULL, 2, 0, 1, 2, 5, '2025-07-28 10:19:04.734958', '2025-07-28 10:19:04.734958'),
+(1354, 1, NULL, NULL, 'estis', NULL, NULL, 2, 0, 1, 2, 5, '2025-07-28 10:19:04.746529', '2025-07-28 10:19:04.746529'),
+(1355, 0, NULL, NULL, 'est', NULL, NULL, 2, 0, 1, 3, 5, '2025-07-28 10:19:04.758110', '2025-07-28 10:19:04.758110'),
+(1356, 1, NULL, NULL, 'sunt', NULL, NULL, 2, 0, 1, 3, 5, '2025-07-28 10:19:04.766567', '2025-07-28 10:19:04.766567'),
+(1357, 0, NULL, NULL, 'eram', NULL, NULL, 3, 0, 1, 1, 5, '2025-07-28 10:19:04.777314', '2025-07-28 10:19:04.777314'),
+(1358, 1, NULL, NULL, 'erāmus', NULL, NULL, 3, 0, 1, 1, 5, '2025-07-28 10:19:04.784108', '2025-07-28 10:19:04.784108'),
+(1359, 0, NULL, NULL, 'erās', NULL, NULL, 3, 0, 1, 2, 5, '2025-07-28 10:19:04.790109', '2025-07-28 10:19:04.790109'),
+(1360, 1, NULL, NULL, 'erātis', NULL, NULL, 3, 0, 1, 2, 5, '2025-07-28 10:19:04.798213', '2025-07-28 10:19:04.798213'),
+(1361, 0, NULL, NULL, 'erat', NULL, NULL, 3, 0, 1, 3, 5, '2025-07-28 10:19:04.807096', '2025-07-28 10:19:04.807096'),
+(1362, 1, NULL, NULL, 'erant', NULL, NULL, 3, 0, 1, 3, 5, '2025-07-28 10:19:04.812994', '2025-07-28 10:19:04.812994'),
+(1363, 0, NULL, NULL, 'erō', NULL, NULL, 5, 0, 1, 1, 5, '2025-07-28 10:19:04.821596', '2025-07-28 10:19:04.821596'),
+(1364, 1, NULL, NULL, 'erimus', NULL, NULL, 5, 0, 1, 1, 5, '2025-07-28 10:19:04.827114', '2025-07-28 10:19:04.827114'),
+(1365, 0, NULL, NULL, 'eris', NULL, NULL, 5, 0, 1, 2, 5, '2025-07-28 10:19:04.832776', '2025-07-28 10:19:04.832776'),
+(1366, 1, NULL, NULL, 'eritis', NULL, NULL, 5, 0, 1, 2, 5, '2025-07-28 10:19:04.840539', '2025-07-28 10:19:04.840539'),
+(1367, 0, NULL, NULL, 'erit', NULL, NULL, 5, 0, 1, 3, 5, '2025-07-28 10:19:04.846330', '2025-07-28 10:19:04.846330'),
+(1368, 1, NULL, NULL, 'erunt', NULL, NULL, 5, 0, 1, 3, 5, '2025-07-28 10:19:04.848790', '2025-07-28 10:19:04.848790'),
+(1369, 0, NULL, NULL, 'iam', NULL, NULL, 0, 1, 0, 1, 5, '2025-07-28 10:19:04.852780', '2025-07-28 10:19:04.852780'),
+(1370, 1, NULL, NULL, 'iāmus', NULL, NULL, 0, 1, 0, 1, 5, '2025-07-28 10:19:04.855139', '2025-07-28 10:19:04.855139'),
+(1371, 0, NULL, NULL, 'iās', NULL, NULL, 0, 1, 0, 2, 5, '2025-07-28 10:19:04.858993', '2025-07-28 10:19:04.858993'),
+(1372, 1, NULL, NULL, 'iātis', NULL, NULL, 0, 1, 0, 2, 5, '2025-07-28 10:19:04.862495', '2025-07-28 10:19:04.862495'),
+(1373, 0, NULL, NULL, 'iat', NULL, NULL, 0, 1, 0, 3, 5, '2025-07-28 10:19:04.865094', '2025-07-28 10:19:04.865094'),
+(1374, 1, NULL, NULL, 'iant', NULL, NULL, 0, 1, 0, 3, 5, '2025-07-28 10:19:04.867381', '2025-07-28 10:19:04.867381'),
+(1375, 0, NULL, NULL, 'īrem', NULL, NULL, 1, 1, 0, 1, 5, '2025-07-28 10:19:04.871217', '2025-07-28 10:19:04.871217'),
+(1376, 1, NULL, NULL, 'īrēmus', NULL, NULL, 1, 1, 0, 1, 5, '2025-07-28 10:19:04.873582', '2025-07-28 10:19:04.873582'),
+(1377, 0, NULL, NULL, 'īrēs', NULL, NULL, 1, 1, 0, 2, 5, '2025-07-28 10:19:04.877384', '2025-07-28 10:19:04.877384'),
+(1378, 1, NULL, NULL, 'īrētis', NULL, NULL, 1, 1, 0, 2, 5, '2025-07-28 10:19:04.879709', '2025-07-28 10:19:04.879709'),
+(1379, 0, NULL, NULL, 'īret', NULL, NULL, 1, 1, 0, 3, 5, '2025-07-28 10:19:04.883337', '2025-07-28 10:19:04.883337'),
+(1380, 1, NULL, NULL, 'īrent', NULL, NULL, 1, 1, 0, 3, 5, '2025-07-28 10:19:04.885628', '2025-07-28 10:19:04.885628'),
+(1381, 0, NULL, NULL, 'erim', NULL, NULL, 2, 1, 0, 1, 5, '2025-07-28 10:19:04.888687', '2025-07-28 10:19:04.888687'),
+(1382, 1, NULL, NULL, 'erīmus', NULL, NULL, 2, 1, 0, 1, 5, '2025-07-28 10:19:04.891830', '2025-07-28 10:19:04.891830'),
+(1383, 0, NULL, NULL, 'erīs', NULL, NULL, 2, 1, 0, 2, 5, '2025-07-28 10:19:04.894152', '2025-07-28 10:19:04.894152'),
+(1384, 1, NULL, NULL, 'erītis', NULL, NULL, 2, 1, 0, 2, 5, '2025-07-28 10:19:04.896504', '2025-07-28 10:19:04.896504'),
+(1385, 0, NULL, NULL, 'erit', NULL, NULL, 2, 1, 0, 3, 5, '2025-07-28 10:19:04.899825', '2025-07-28 10:19:04.899825'),
+(1386, 1, NULL, NULL, 'erint', NULL, NULL, 2, 1, 0, 3, 5, '2025-07-28 10:19:04.902190', '2025-07-28 10:19:04.902190'),
+(1387, 0, NULL, NULL, 'issem', NULL, NULL, 3, 1, 0, 1, 5, '2025-07-28 10:19:04.905251', '2025-07-28 10:19:04.905251'),
+(1388, 1, NULL, NULL, 'issēmus', NULL, NULL, 3, 1, 0, 1, 5, '2025-07-28 10:19:04.907668', '2025-07-28 10:19:04.907668'),
+(1389, 0, NULL, NULL, 'issēs', NULL, NULL, 3, 1, 0, 2, 5, '2025-07-28 10:19:04.912025', '2025-07-28 10:19:04.912025'),
+(1390, 1, NULL, NULL, 'issētis', NULL, NULL, 3, 1, 0, 2, 5, '2025-07-28 10:19:04.916023', '2025-07-28 10:19:04.916023'),
+(1391, 0, NULL, NULL, 'isset', NULL, NULL, 3, 1, 0, 3, 5, '2025-07-28 10:19:04.919033', '2025-07-28 10:19:04.919033'),
+(1392, 1, NULL, NULL, 'issent', NULL, NULL, 3, 1, 0, 3, 5, '2025-07-28 10:19:04.921383', '2025-07-28 10:19:04.921383'),
+(1393, 0, NULL, NULL, 'iar', NULL, NULL, 0, 1, 1, 1, 5, '2025-07-28 10:19:04.924244', '2025-07-28 10:19:04.924244'),
+(1394, 1, NULL, NULL, 'iāmur', NULL, NULL, 0, 1, 1, 1, 5, '2025-07-28 10:19:04.927052', '2025-07-28 10:19:04.927052'),
+(1395, 0, NULL, NULL, 'iāris', NULL, NULL, 0, 1, 1, 2, 5, '2025-07-28 10:19:04.929955', '2025-07-28 10:19:04.929955'),
+(1396, 1, NULL, NULL, 'iāminī', NULL, NULL, 0, 1, 1, 2, 5, '2025-07-28 10:19:04.932809', '2025-07-28 10:19:04.932809'),
+(1397, 0, NULL, NULL, 'iātur', NULL, NULL, 0, 1, 1, 3, 5, '2025-07-28 10:19:04.935412', '2025-07-28 10:19:04.935412'),
+(1398, 1, NULL, NULL, 'iantur', NULL, NULL, 0, 1, 1, 3, 5, '2025-07-28 10:19:04.937730', '2025-07-28 10:19:04.937730'),
+(1399, 0, NULL, NULL, 'īrer', NULL, NULL, 1, 1, 1, 1, 5, '2025-07-28 10:19:04.940145', '2025-07-28 10:19:04.940145'),
+(1400, 1, NULL, NULL, 'īrēmur', NULL, NULL, 1, 1, 1, 1, 5, '2025-07-28 10:19:04.942458', '2025-07-28 10:19:04.942458'),
+(1401, 0, NULL, NULL, 'īrēris', NULL, NULL, 1, 1, 1, 2, 5, '2025-07-28 10:19:04.944802', '2025-07-28 10:19:04.944802'),
+(1402, 1, NULL, NULL, 'īrēminī', NULL, NULL, 1, 1, 1, 2, 5, '2025-07-28 10:19:04.947039', '2025-07-28 10:19:04.947039'),
+(1403, 0, NULL, NULL, 'īrētur', NULL, NULL, 1, 1, 1, 3, 5, '2025-07-28 10:19:04.949222', '2025-07-28 10:19:04.949222'),
+(1404, 1, NULL, NULL, 'īrentur', NULL, NULL, 1, 1, 1, 3, 5, '2025-07-28 10:19:04.951460', '2025-07-28 10:19:04.951460'),
+(1405, 0, NULL, NULL, 'sim', NULL, NULL, 2, 1, 1, 1, 5, '2025-07-28 10:19:04.953759', '2025-07-28 10:19:04.953759'),
+(1406, 1, NULL, NULL, 'sīmus', NULL, NULL, 2, 1, 1, 1, 5, '2025-07-28 10:19:04.956118', '2025-07-28 10:19:04.956118'),
+(1407, 0, NULL, NULL, 'sīs', NULL, NULL, 2, 1, 1, 2, 5, '2025-07-28 10:19:04.958485', '2025-07-28 10:19:04.958485'),
+(1408, 1, NULL, NULL, 'sītis', NULL, NULL, 2, 1, 1, 2, 5, '2025-07-28 10:19:04.960794', '2025-07-28 10:19:04.960794'),
+(1409, 0, NULL, NULL, 'sit', NULL, NULL, 2, 1, 1, 3, 5, '2025-07-28 10:19:04.963059', '2025-07-28 10:19:04.963059'),
+(1410, 1, NULL, NULL, 'sint', NULL, NULL, 2, 1, 1, 3, 5, '2025-07-28 10:19:04.965296', '2025-07-28 10:19:04.965296'),
+(1411, 0, NULL, NULL, 'essem', NULL, NULL, 3, 1, 1, 1, 5, '2025-07-28 10:19:04.967473', '2025-07-28 10:19:04.967473'),
+(1412, 1, NULL, NULL, 'essēmus', NULL, NULL, 3, 1, 1, 1, 5, '2025-07-28 10:19:04.969745', '2025-07-28 10:19:04.969745'),
+(1413, 0, NULL, NULL, 'essēs', NULL, NULL, 3, 1, 1, 2, 5, '2025-07-28 10:19:04.972029', '2025-07-28 10:19:04.972029'),
+(1414, 1, NULL, NULL, 'essētis', NULL, NULL, 3, 1, 1, 2, 5, '2025-07-28 10:19:04.974285', '2025-07-28 10:19:04.974285'),
+(1415, 0, NULL, NULL, 'esset', NULL, NULL, 3, 1, 1, 3, 5, '2025-07-28 10:19:04.976454', '2025-07-28 10:19:04.976454'),
+(1416, 1, NULL, NULL, 'essent', NULL, NULL, 3, 1, 1, 3, 5, '2025-07-28 10:19:04.978669', '2025-07-28 10:19:04.978669'),
+(1417, 0, NULL, NULL, 'ī', NULL, NULL, 0, 2, 0, 2, 5, '2025-07-28 10:19:04.980921', '2025-07-28 10:19:04.980921'),
+(1418, 1, NULL, NULL, 'īte', NULL, NULL, 0, 2, 0, 2, 5, '2025-07-28 10:19:04.983134', '2025-07-28 10:19:04.983134'),
+(1419, 0, NULL, NULL, 'ītō', NULL, NULL, 4, 2, 0, 2, 5, '2025-07-28 10:19:04.985382', '2025-07-28 10:19:04.985382'),
+(1420, 1, NULL, NULL, 'ītōte', NULL, NULL, 4, 2, 0, 2, 5, '2025-07-28 10:19:04.987632', '2025-07-28 10:19:04.987632'),
+(1421, 0, NULL, NULL, 'ītō', NULL, NULL, 4, 2, 0, 3, 5, '2025-07-28 10:19:04.989919', '2025-07-28 10:19:04.989919'),
+(1422, 1, NULL, NULL, 'iuntō', NULL, NULL, 4, 2, 0, 3, 5, '2025-07-28 10:19:04.992171', '2025-07-28 10:19:04.992171'),
+(1423, 0, NULL, NULL, 'īre', NULL, NULL, 0, 2, 1, 2, 5, '2025-07-28 10:19:04.994412', '2025-07-28 10:19:04.994412'),
+(1424, 1, NULL, NULL, 'īminī', NULL, NULL, 0, 2, 1, 2, 5, '2025-07-28 10:19:04.996690', '2025-07-28 10:19:04.996690'),
+(1425, 0, NULL, NULL, 'ītor', NULL, NULL, 4, 2, 1, 2, 5, '2025-07-28 10:19:04.998898', '2025-07-28 10:19:04.998898'),
+(1426, 1, NULL, NULL, NULL, NULL, NULL, 4, 2, 1, 2, 5, '2025-07-28 10:19:05.001126', '2025-07-28 10:19:05.001126'),
+(1427, 0, NULL, NULL, 'ītor', NULL, NULL, 4, 2, 1, 3, 5, '2025-07-28 10:19:05.003334', '2025-07-28 10:19:05.003334'),
+(1428, 1, NULL, NULL, 'iuntor', NULL, NULL, 4, 2, 1, 3, 5, '2025-07-28 10:19:05.005607', '2025-07-28 10:19:05.005607'),
+(1429, 0, NULL, NULL, 'īre', NULL, NULL, 0, 6, 0, 1, 5, '2025-07-28 10:19:05.007977', '2025-07-28 10:19:05.007977'),
+(1430, 1, NULL, NULL, NULL, NULL, NULL, 0, 6, 0, 1, 5, '2025-07-28 10:19:05.010272', '2025-07-28 10:19:05.010272'),
+(1431, 0, NULL, NULL, 'isse', NULL, NULL, 2, 6, 0, 1, 5, '2025-07-28 10:19:05.012819', '2025-07-28 10:19:05.012819'),
+(1432, 1, NULL, NULL, NULL, NULL, NULL, 2, 6, 0, 1, 5, '2025-07-28 10:19:05.015043', '2025-07-28 10:19:05.015043'),
+(1433, 0, NULL, NULL, 'esse', NULL, NULL, 4, 6, 0, 1, 5, '2025-07-28 10:19:05.017348', '2025-07-28 10:19:05.017348'),
+(1434, 1, NULL, NULL, NULL, NULL, NULL, 4, 6, 0, 1, 5, '2025-07-28 10:19:05.019727', '2025-07-28 10:19:05.019727'),
+(1435, 0, NULL, NULL, 'īrī', NULL, NULL, 0, 6, 1, 1, 5, '2025-07-28 10:19:05.022031', '2025-07-28 10:19:05.022031'),
+(1436, 1, NULL, NULL, NULL, NULL, NULL, 0, 6, 1, 1, 5, '2025-07-28 10:19:05.024369', '2025-07-28 10:19:05.024369'),
+(1437, 0, NULL, NULL, 'esse', NULL, NULL, 2, 6, 1, 1, 5, '2025-07-28 10:19:05.026724', '2025-07-28 10:19:05.026724'),
+(1438, 1, NULL, NULL, NULL, NULL, NULL, 2, 6, 1, 1, 5, '2025-07-28 10:19:05.029093', '2025-07-28 10:19:05.029093'),
+(1439, 0, NULL, NULL, 'īrī', NULL, NULL, 4, 6, 1, 1, 5, '2025-07-28 10:19:05.031327', '2025-07-28 10:19:05.031327'),
+(1440, 1, NULL, NULL, NULL, NULL, NULL, 4, 6, 1, 1, 5, '2025-07-28 10:19:05.033666', '2025-07-28 10:19:05.033666'),
+(1441, 0, NULL, NULL, 'iēns', NULL, NULL, 0, 8, 0, 1, 5, '2025-07-28 10:19:05.036203', '2025-07-28 10:19:05.036203'),
+(1442, 1, NULL, NULL, NULL, NULL, NULL, 0, 8, 0, 1, 5, '2025-07-28 10:19:05.038511', '2025-07-28 10:19:05.038511'),
+(1443, 0, NULL, NULL, 'ūrus', NULL, NULL, 4, 8, 0, 1, 5, '2025-07-28 10:19:05.061275', '2025-07-28 10:19:05.061275'),
+(1444, 1, NULL, NULL, NULL, NULL, NULL, 4, 8, 0, 1, 5, '2025-07-28 10:19:05.074422', '2025-07-28 10:19:05.074422'),
+(1445, 0, NULL, NULL, 'us', NULL, NULL, 2, 8, 1, 1, 5, '2025-07-28 10:19:05.077121', '2025-07-28 10:19:05.077121'),
+(1446, 1, NULL, NULL, NULL, NULL, NULL, 2, 8, 1, 1, 5, '2025-07-28 10:19:05.080988', '2025-07-28 10:19:05.080988'),
+(1447, 0, NULL, NULL, 'iendus', NULL, NULL, 4, 8, 1, 1, 5, '2025-07-28 10:19:05.083008', '2025-07-28 10:19:05.083008'),
+(1448, 1, NULL, NULL, NULL, NULL, NULL, 4, 8, 1, 1, 5, '2025-07-28 10:19:05.086889', '2025-07-28 10:19:05.086889'),
+(1449, 0, NULL, NULL, 'sum', NULL, 'sum', 0, 0, 0, 1, 6, '2025-07-28 10:19:05.097353', '2025-07-28 10:19:05.097353'),
+(1450, 1, NULL, NULL, 'sumus', NULL, 'sum', 0, 0, 0, 1, 6, '2025-07-28 10:19:05.101023', '2025-07-28 10:19:05.101023'),
+(1451, 0, NULL, NULL, 'es', NULL, 'sum', 0, 0, 0, 2, 6, '2025-07-28 10:19:05.103241', '2025-07-28 10:19:05.103241'),
+(1452, 1, NULL, NULL, 'estis', NULL, 'sum', 0, 0, 0, 2, 6, '2025-07-28 10:19:05.107022', '2025-07-28 10:19:05.107022'),
+(1453, 0, NULL, NULL, 'est', NULL, 'sum', 0, 0, 0, 3, 6, '2025-07-28 10:19:05.109125', '2025-07-28 10:19:05.109125'),
+(1454, 1, NULL, NULL, 'sunt', NULL, 'sum', 0, 0, 0, 3, 6, '2025-07-28 10:19:05.112819', '2025-07-28 10:19:05.112819'),
+(1455, 0, NULL, NULL, 'eram', NULL, 'sum', 1, 0, 0, 1, 6, '2025-07-28 10:19:05.114884', '2025-07-28 10:19:05.114884'),
+(1456, 1, NULL, NULL, 'erāmus', NULL, 'sum', 1, 0, 0, 1, 6, '2025-07-28 10:19:05.118279', '2025-07-28 10:19:05.118279'),
+(1457, 0, NULL, NULL, 'erās', NULL, 'sum', 1, 0, 0, 2, 6, '2025-07-28 10:19:05.120457', '2025-07-28 10:19:05.120457'),
+(1458, 1, NULL, NULL, 'erātis', NULL, 'sum', 1, 0, 0, 2, 6, '2025-07-28 10:19:05.124120', '2025-07-28 10:19:05.124120'),
+(1459, 0, NULL, NULL, 'erat', NULL, 'sum', 1, 0, 0, 3, 6, '2025-07-28 10:19:05.126228', '2025-07-28 10:19:05.126228'),
+(1460, 1, NULL, NULL, 'erant', NULL, 'sum', 1, 0, 0, 3, 6, '2025-07-28 10:19:05.129930', '2025-07-28 10:19:05.129930'),
+(1461, 0, NULL, NULL, 'fuī', NULL, 'sum', 2, 0, 0, 1, 6, '2025-07-28 10:19:05.132037', '2025-07-28 10:19:05.132037'),
+(1462, 1, NULL, NULL, 'fuimus', NULL, 'sum', 2, 0, 0, 1, 6, '2025-07-28 10:19:05.135509', '2025-07-28 10:19:05.135509'),
+(1463, 0, NULL, NULL, 'fuistī', NULL, 'sum', 2, 0, 0, 2, 6, '2025-07-28 10:19:05.137716', '2025-07-28 10:19:05.137716'),
+(1464, 1, NULL, NULL, 'fuistis', NULL, 'sum', 2, 0, 0, 2, 6, '2025-07-28 10:19:05.141347', '2025-07-28 10:19:05.141347'),
+(1465, 0, NULL, NULL, 'fuit', NULL, 'sum', 2, 0, 0, 3, 6, '2025-07-28 10:19:05.143520', '2025-07-28 10:19:05.143520'),
+(1466, 1, NULL, NULL, 'fuērunt', NULL, 'sum', 2, 0, 0, 3, 6, '2025-07-28 10:19:05.147049', '2025-07-28 10:19:05.147049'),
+(1467, 0, NULL, NULL, 'fueram', NULL, 'sum', 3, 0, 0, 1, 6, '2025-07-28 10:19:05.149104', '2025-07-28 10:19:05.149104'),
+(1468, 1, NULL, NULL, 'fuerāmus', NULL, 'sum', 3, 0, 0, 1, 6, '2025-07-28 10:19:05.152727', '2025-07-28 10:19:05.152727'),
+(1469, 0, NULL, NULL, 'fuerās', NULL, 'sum', 3, 0, 0, 2, 6, '2025-07-28 10:19:05.154888', '2025-07-28 10:19:05.154888'),
+(1470, 1, NULL, NULL, 'fuerātis', NULL, 'sum', 3, 0, 0, 2, 6, '2025-07-28 10:19:05.157992', '2025-07-28 10:19:05.157992'),
+(1471, 0, NULL, NULL, 'fuerat', NULL, 'sum', 3, 0, 0, 3, 6, '2025-07-28 10:19:05.160142', '2025-07-28 10:19:05.160142'),
+(1472, 1, NULL, NULL, 'fuerant', NULL, 'sum', 3, 0, 0, 3, 6, '2025-07-28 10:19:05.162254', '2025-07-28 10:19:05.162254'),
+(1473, 0, NULL, NULL, 'erō', NULL, 'sum', 4, 0, 0, 1, 6, '2025-07-28 10:19:05.165056', '2025-07-28 10:19:05.165056'),
+(1474, 1, NULL, NULL, 'erimus', NULL, 'sum', 4, 0, 0, 1, 6, '2025-07-28 10:19:05.167098', '2025-07-28 10:19:05.167098'),
+(1475, 0, NULL, NULL, 'eris', NULL, 'sum', 4, 0, 0, 2, 6, '2025-07-28 10:19:05.170051', '2025-07-28 10:19:05.170051'),
+(1476, 1, NULL, NULL, 'eritis', NULL, 'sum', 4, 0, 0, 2, 6, '2025-07-28 10:19:05.172121', '2025-07-28 10:19:05.172121'),
+(1477, 0, NULL, NULL, 'erit', NULL, 'sum', 4, 0, 0, 3, 6, '2025-07-28 10:19:05.175039', '2025-07-28 10:19:05.175039'),
+(1478, 1, NULL, NULL, 'erunt', NULL, 'sum', 4, 0, 0, 3, 6, '2025-07-28 10:19:05.177263', '2025-07-28 10:19:05.177263'),
+(1479, 0, NULL, NULL, 'fuerō', NULL, 'sum', 5, 0, 0, 1, 6, '2025-07-28 10:19:05.179455', '2025-07-28 10:19:05.179455'),
+(1480, 1, NULL, NULL, 'fuerimus', NULL, 'sum', 5, 0, 0, 1, 6, '2025-07-28 10:19:05.182282', '2025-07-28 10:19:05.182282'),
+(1481, 0, NULL, NULL, 'fueris', NULL, 'sum', 5, 0, 0, 2, 6, '2025-07-28 10:19:05.184463', '2025-07-28 10:19:05.184463'),
+(1482, 1, NULL, NULL, 'fueritis', NULL, 'sum', 5, 0, 0, 2, 6, '2025-07-28 10:19:05.187250', '2025-07-28 10:19:05.187250'),
+(1483, 0, NULL, NULL, 'fuerit', NULL, 'sum', 5, 0, 0, 3, 6, '2025-07-28 10:19:05.189399', '2025-07-28 10:19:05.189399'),
+(1484, 1, NULL, NULL, 'fuerint', NULL, 'sum', 5, 0, 0, 3, 6, '2025-07-28 10:19:05.191617', '2025-07-28 10:19:05.191617'),
+(1485, 0, NULL, NULL, 'sim', NULL, 'sum', 0, 1, 0, 1, 6, '2025-07-28 10:19:05.194271', '2025-07-28 10:19:05.194271'),
+(1486, 1, NULL, NULL, 'sīmus', NULL, 'sum', 0, 1, 0, 1, 6, '2025-07-28 10:19:05.196472', '2025-07-28 10:19:05.196472'),
+(1487, 0, NULL, NULL, 'sīs', NULL, 'sum', 0, 1, 0, 2, 6, '2025-07-28 10:19:05.198677', '2025-07-28 10:19:05.198677'),
+(1488, 1, NULL, NULL, 'sītis', NULL, 'sum', 0, 1, 0, 2, 6, '2025-07-28 10:19:05.201551', '2025-07-28 10:19:05.201551'),
+(1489, 0, NULL, NULL, 'sit', NULL, 'sum', 0, 1, 0, 3, 6, '2025-07-28 10:19:05.203747', '2025-07-28 10:19:05.203747'),
+(1490, 1, NULL, NULL, 'sint', NULL, 'sum', 0, 1, 0, 3, 6, '2025-07-28 10:19:05.205973', '2025-07-28 10:19:05.205973'),
+(1491, 0, NULL, NULL, 'essem', NULL, 'sum', 1, 1, 0, 1, 6, '2025-07-28 10:19:05.208214', '2025-07-28 10:19:05.208214'),
+(1492, 1, NULL, NULL, 'essēmus', NULL, 'sum', 1, 1, 0, 1, 6, '2025-07-28 10:19:05.210467', '2025-07-28 10:19:05.210467'),
+(1493, 0, NULL, NULL, 'essēs', NULL, 'sum', 1, 1, 0, 2, 6, '2025-07-28 10:19:05.212732', '2025-07-28 10:19:05.212732'),
+(1494, 1, NULL, NULL, 'essētis', NULL, 'sum', 1, 1, 0, 2, 6, '2025-07-28 10:19:05.214980', '2025-07-28 10:19:05.214980'),
+(1495, 0, NULL, NULL, 'esset', NULL, 'sum', 1, 1, 0, 3, 6, '2025-07-28 10:19:05.217188', '2025-07-28 10:19:05.217188'),
+(1496, 1, NULL, NULL, 'essent', NULL, 'sum', 1, 1, 0, 3, 6, '2025-07-28 10:19:05.219426', '2025-07-28 10:19:05.219426'),
+(1497, 0, NULL, NULL, 'fuerim', NULL, 'sum', 2, 1, 0, 1, 6, '2025-07-28 10:19:05.221688', '2025-07-28 10:19:05.221688'),
+(1498, 1, NULL, NULL, 'fuerīmus', NULL, 'sum', 2, 1, 0, 1, 6, '2025-07-28 10:19:05.223985', '2025-07-28 10:19:05.223985'),
+(1499, 0, NULL, NULL, 'fuerīs', NULL, 'sum', 2, 1, 0, 2, 6, '2025-07-28 10:19:05.226195', '2025-07-28 10:19:05.226195'),
+(1500, 1, NULL, NULL, 'fuerītis', NULL, 'sum', 2, 1, 0, 2, 6, '2025-07-28 10:19:05.228424', '2025-07-28 10:19:05.228424'),
+(1501, 0, NULL, NULL, 'fuerit', NULL, 'sum', 2, 1, 0, 3, 6, '2025-07-28 10:19:05.230660', '2025-07-28 10:19:05.230660'),
+(1502, 1, NULL, NULL, 'fuerint', NULL, 'sum', 2, 1, 0, 3, 6, '2025-07-28 10:19:05.232856', '2025-07-28 10:19:05.232856'),
+(1503, 0, NULL, NULL, 'fuissem', NULL, 'sum', 3, 1, 0, 1, 6, '2025-07-28 10:19:05.235092', '2025-07-28 10:19:05.235092'),
+(1504, 1, NULL, NULL, 'fuissēmus', NULL, 'sum', 3, 1, 0, 1, 6, '2025-07-28 10:19:05.237361', '2025-07-28 10:19:05.237361'),
+(1505, 0, NULL, NULL, 'fuissēs', NULL, 'sum', 3, 1, 0, 2, 6, '2025-07-28 10:19:05.239649', '2025-07-28 10:19:05.239649'),
+(1506, 1, NULL, NULL, 'fuissētis', NULL, 'sum', 3, 1, 0, 2, 6, '2025-07-28 10:19:05.242019', '2025-07-28 10:19:05.242019'),
+(1507, 0, NULL, NULL, 'fuisset', NULL, 'sum', 3, 1, 0, 3, 6, '2025-07-28 10:19:05.244283', '2025-07-28 10:19:05.244283'),
+(1508, 1, NULL, NULL, 'fuissent', NULL, 'sum', 3, 1, 0, 3, 6, '2025-07-28 10:19:05.246555', '2025-07-28 10:19:05.246555'),
+(1509, 0, NULL, NULL, 'es', NULL, 'sum', 0, 2, 0, 2, 6, '2025-07-28 10:19:05.248797', '2025-07-28 10:19:05.248797'),
+(1510, 1, NULL, NULL, 'este', NULL, 'sum', 0, 2, 0, 2, 6, '2025-07-28 10:19:05.251029', '2025-07-28 10:19:05.251029'),
+(1511, 0, NULL, NULL, 'estō', NULL, 'sum', 4, 2, 0, 2, 6, '2025-07-28 10:19:05.253309', '2025-07-28 10:19:05.253309'),
+(1512, 1, NULL, NULL, 'estōte', NULL, 'sum', 4, 2, 0, 2, 6, '2025-07-28 10:19:05.255572', '2025-07-28 10:19:05.255572'),
+(1513, 0, NULL, NULL, 'estō', NULL, 'sum', 4, 2, 0, 3, 6, '2025-07-28 10:19:05.257839', '2025-07-28 10:19:05.257839'),
+(1514, 1, NULL, NULL, 'suntō', NULL, 'sum', 4, 2, 0, 3, 6, '2025-07-28 10:19:05.260115', '2025-07-28 10:19:05.260115'),
+(1515, 0, NULL, NULL, 'esse', NULL, 'sum', 0, 6, 0, 1, 6, '2025-07-28 10:19:05.262414', '2025-07-28 10:19:05.262414'),
+(1516, 1, NULL, NULL, NULL, NULL, 'sum', 0, 6, 0, 1, 6, '2025-07-28 10:19:05.264810', '2025-07-28 10:19:05.264810'),
+(1517, 0, NULL, NULL, 'fuisse', NULL, 'sum', 2, 6, 0, 1, 6, '2025-07-28 10:19:05.271274', '2025-07-28 10:19:05.271274'),
+(1518, 1, NULL, NULL, NULL, NULL, 'sum', 2, 6, 0, 1, 6, '2025-07-28 10:19:05.273488', '2025-07-28 10:19:05.273488'),
+(1519, 0, NULL, NULL, 'futūrum esse', NULL, 'sum', 4, 6, 0, 1, 6, '2025-07-28 10:19:05.277427', '2025-07-28 10:19:05.277427'),
+(1520, 1, NULL, NULL, NULL, NULL, 'sum', 4, 6, 0, 1, 6, '2025-07-28 10:19:05.279583', '2025-07-28 10:19:05.279583'),
+(1521, 0, NULL, NULL, 'futūrus', NULL, 'sum', 4, 8, 0, 1, 6, '2025-07-28 10:19:05.283260', '2025-07-28 10:19:05.283260'),
+(1522, 1, NULL, NULL, NULL, NULL, 'sum', 4, 8, 0, 1, 6, '2025-07-28 10:19:05.286895', '2025-07-28 10:19:05.286895'),
+(1523, 0, NULL, NULL, 'possum', NULL, 'possum', 0, 0, 0, 1, 7, '2025-07-28 10:19:05.298776', '2025-07-28 10:19:05.298776'),
+(1524, 1, NULL, NULL, 'possumus', NULL, 'possum', 0, 0, 0, 1, 7, '2025-07-28 10:19:05.300975', '2025-07-28 10:19:05.300975'),
+(1525, 0, NULL, NULL, 'potes', NULL, 'possum', 0, 0, 0, 2, 7, '2025-07-28 10:19:05.304886', '2025-07-28 10:19:05.304886'),
+(1526, 1, NULL, NULL, 'potestis', NULL, 'possum', 0, 0, 0, 2, 7, '2025-07-28 10:19:05.307071', '2025-07-28 10:19:05.307071'),
+(1527, 0, NULL, NULL, 'potest', NULL, 'possum', 0, 0, 0, 3, 7, '2025-07-28 10:19:05.310925', '2025-07-28 10:19:05.310925'),
+(1528, 1, NULL, NULL, 'possunt', NULL, 'possum', 0, 0, 0, 3, 7, '2025-07-28 10:19:05.313066', '2025-07-28 10:19:05.313066'),
+(1529, 0, NULL, NULL, 'poteram', NULL, 'possum', 1, 0, 0, 1, 7, '2025-07-28 10:19:05.316690', '2025-07-28 10:19:05.316690'),
+(1530, 1, NULL, NULL, 'poterāmus', NULL, 'possum', 1, 0, 0, 1, 7, '2025-07-28 10:19:05.318916', '2025-07-28 10:19:05.318916'),
+(1531, 0, NULL, NULL, 'poterās', NULL, 'possum', 1, 0, 0, 2, 7, '2025-07-28 10:19:05.322762', '2025-07-28 10:19:05.322762'),
+(1532, 1, NULL, NULL, 'poterātis', NULL, 'possum', 1, 0, 0, 2, 7, '2025-07-28 10:19:05.325025', '2025-07-28 10:19:05.325025'),
+(1533, 0, NULL, NULL, 'poterat', NULL, 'possum', 1, 0, 0, 3, 7, '2025-07-28 10:19:05.328966', '2025-07-28 10:19:05.328966'),
+(1534, 1, NULL, NULL, 'poterant', NULL, 'possum', 1, 0, 0, 3, 7, '2025-07-28 10:19:05.331125', '2025-07-28 10:19:05.331125'),
+(1535, 0, NULL, NULL, 'potuī', NULL, 'possum', 2, 0, 0, 1, 7, '2025-07-28 10:19:05.334885', '2025-07-28 10:19:05.334885'),
+(1536, 1, NULL, NULL, 'potuimus', NULL, 'possum', 2, 0, 0, 1, 7, '2025-07-28 10:19:05.337148', '2025-07-28 10:19:05.337148'),
+(1537, 0, NULL, NULL, 'potuistī', NULL, 'possum', 2, 0, 0, 2, 7, '2025-07-28 10:19:05.339374', '2025-07-28 10:19:05.339374'),
+(1538, 1, NULL, NULL, 'potuistis', NULL, 'possum', 2, 0, 0, 2, 7, '2025-07-28 10:19:05.343211', '2025-07-28 10:19:05.343211'),
+(1539, 0, NULL, NULL, 'potuit', NULL, 'possum', 2, 0, 0, 3, 7, '2025-07-28 10:19:05.345326', '2025-07-28 10:19:05.345326'),
+(1540, 1, NULL, NULL, 'potuērunt', NULL, 'possum', 2, 0, 0, 3, 7, '2025-07-28 10:19:05.348951', '2025-07-28 10:19:05.348951'),
+(1541, 0, NULL, NULL, 'potueram', NULL, 'possum', 3, 0, 0, 1, 7, '2025-07-28 10:19:05.351029', '2025-07-28 10:19:05.351029'),
+(1542, 1, NULL, NULL, 'potuerāmus', NULL, 'possum', 3, 0, 0, 1, 7, '2025-07-28 10:19:05.354492', '2025-07-28 10:19:05.354492'),
+(1543, 0, NULL, NULL, 'potuerās', NULL, 'possum', 3, 0, 0, 2, 7, '2025-07-28 10:19:05.356631', '2025-07-28 10:19:05.356631'),
+(1544, 1, NULL, NULL, 'potuerātis', NULL, 'possum', 3, 0, 0, 2, 7, '2025-07-28 10:19:05.359873', '2025-07-28 10:19:05.359873'),
+(1545, 0, NULL, NULL, 'potuerat', NULL, 'possum', 3, 0, 0, 3, 7, '2025-07-28 10:19:05.362038', '2025-07-28 10:19:05.362038'),
+(1546, 1, NULL, NULL, 'potuerant', NULL, 'possum', 3, 0, 0, 3, 7, '2025-07-28 10:19:05.365103', '2025-07-28 10:19:05.365103'),
+(1547, 0, NULL, NULL, 'poterō', NULL, 'possum', 4, 0, 0, 1, 7, '2025-07-28 10:19:05.367255', '2025-07-28 10:19:05.367255'),
+(1548, 1, NULL, NULL, 'poterimus', NULL, 'possum', 4, 0, 0, 1, 7, '2025-07-28 10:19:05.369492', '2025-07-28 10:19:05.369492'),
+(1549, 0, NULL, NULL, 'poteris', NULL, 'possum', 4, 0, 0, 2, 7, '2025-07-28 10:19:05.372427', '2025-07-28 10:19:05.372427'),
+(1550, 1, NULL, NULL, 'poteritis', NULL, 'possum', 4, 0, 0, 2, 7, '2025-07-28 10:19:05.374671', '2025-07-28 10:19:05.374671'),
+(1551, 0, NULL, NULL, 'poterit', NULL, 'possum', 4, 0, 0, 3, 7, '2025-07-28 10:19:05.377636', '2025-07-28 10:19:05.377636'),
+(1552, 1, NULL, NULL, 'poterunt', NULL, 'possum', 4, 0, 0, 3, 7, '2025-07-28 10:19:05.379918', '2025-07-28 10:19:05.379918'),
+(1553, 0, NULL, NULL, 'potuerō', NULL, 'possum', 5, 0, 0, 1, 7, '2025-07-28 10:19:05.382153', '2025-07-28 10:19:05.382153'),
+(1554, 1, NULL, NULL, 'potuerimus', NULL, 'possum', 5, 0, 0, 1, 7, '2025-07-28 10:19:05.385048', '2025-07-28 10:19:05.385048'),
+(1555, 0, NULL, NULL, 'potueris', NULL, 'possum', 5, 0, 0, 2, 7, '2025-07-28 10:19:05.387331', '2025-07-28 10:19:05.387331'),
+(1556, 1, NULL, NULL, 'potueritis', NULL, 'possum', 5, 0, 0, 2, 7, '2025-07-28 10:19:05.390231', '2025-07-28 10:19:05.390231'),
+(1557, 0, NULL, NULL, 'potuerit', NULL, 'possum', 5, 0, 0, 3, 7, '2025-07-28 10:19:05.392475', '2025-07-28 10:19:05.392475'),
+(1558, 1, NULL, NULL, 'potuerint', NULL, 'possum', 5, 0, 0, 3, 7, '2025-07-28 10:19:05.396090', '2025-07-28 10:19:05.396090'),
+(1559, 0, NULL, NULL, 'possim', NULL, 'possum', 0, 1, 0, 1, 7, '2025-07-28 10:19:05.398316', '2025-07-28 10:19:05.398316'),
+(1560, 1, NULL, NULL, 'possīmus', NULL, 'possum', 0, 1, 0, 1, 7, '2025-07-28 10:19:05.400488', '2025-07-28 10:19:05.400488'),
+(1561, 0, NULL, NULL, 'possīs', NULL, 'possum', 0, 1, 0, 2, 7, '2025-07-28 10:19:05.402746', '2025-07-28 10:19:05.402746'),
+(1562, 1, NULL, NULL, 'possītis', NULL, 'possum', 0, 1, 0, 2, 7, '2025-07-28 10:19:05.405019', '2025-07-28 10:19:05.405019'),
+(1563, 0, NULL, NULL, 'possit', NULL, 'possum', 0, 1, 0, 3, 7, '2025-07-28 10:19:05.407380', '2025-07-28 10:19:05.407380'),
+(1564, 1, NULL, NULL, 'possint', NULL, 'possum', 0, 1, 0, 3, 7, '2025-07-28 10:19:05.409673', '2025-07-28 10:19:05.409673'),
+(1565, 0, NULL, NULL, 'possem', NULL, 'possum', 1, 1, 0, 1, 7, '2025-07-28 10:19:05.411966', '2025-07-28 10:19:05.411966'),
+(1566, 1, NULL, NULL, 'possēmus', NULL, 'possum', 1, 1, 0, 1, 7, '2025-07-28 10:19:05.414208', '2025-07-28 10:19:05.414208'),
+(1567, 0, NULL, NULL, 'possēs', NULL, 'possum', 1, 1, 0, 2, 7, '2025-07-28 10:19:05.416478', '2025-07-28 10:19:05.416478'),
+(1568, 1, NULL, NULL, 'possētis', NULL, 'possum', 1, 1, 0, 2, 7, '2025-07-28 10:19:05.418780', '2025-07-28 10:19:05.418780'),
+(1569, 0, NULL, NULL, 'posset', NULL, 'possum', 1, 1, 0, 3, 7, '2025-07-28 10:19:05.421060', '2025-07-28 10:19:05.421060'),
+(1570, 1, NULL, NULL, 'possent', NULL, 'possum', 1, 1, 0, 3, 7, '2025-07-28 10:19:05.423295', '2025-07-28 10:19:05.423295'),
+(1571, 0, NULL, NULL, 'potuerim', NULL, 'possum', 2, 1, 0, 1, 7, '2025-07-28 10:19:05.425579', '2025-07-28 10:19:05.425579'),
+(1572, 1, NULL, NULL, 'potuerīmus', NULL, 'possum', 2, 1, 0, 1, 7, '2025-07-28 10:19:05.427827', '2025-07-28 10:19:05.427827'),
+(1573, 0, NULL, NULL, 'potuerīs', NULL, 'possum', 2, 1, 0, 2, 7, '2025-07-28 10:19:05.430051', '2025-07-28 10:19:05.430051'),
+(1574, 1, NULL, NULL, 'potuerītis', NULL, 'possum', 2, 1, 0, 2, 7, '2025-07-28 10:19:05.432303', '2025-07-28 10:19:05.432303'),
+(1575, 0, NULL, NULL, 'potuerit', NULL, 'possum', 2, 1, 0, 3, 7, '2025-07-28 10:19:05.434474', '2025-07-28 10:19:05.434474'),
+(1576, 1, NULL, NULL, 'potuerint', NULL, 'possum', 2, 1, 0, 3, 7, '2025-07-28 10:19:05.436789', '2025-07-28 10:19:05.436789'),
+(1577, 0, NULL, NULL, 'potuissem', NULL, 'possum', 3, 1, 0, 1, 7, '2025-07-28 10:19:05.439050', '2025-07-28 10:19:05.439050'),
+(1578, 1, NULL, NULL, 'potuissēmus', NULL, 'possum', 3, 1, 0, 1, 7, '2025-07-28 10:19:05.441357', '2025-07-28 10:19:05.441357'),
+(1579, 0, NULL, NULL, 'potuissēs', NULL, 'possum', 3, 1, 0, 2, 7, '2025-07-28 10:19:05.443630', '2025-07-28 10:19:05.443630'),
+(1580, 1, NULL, NULL, 'potuissētis', NULL, 'possum', 3, 1, 0, 2, 7, '2025-07-28 10:19:05.445923', '2025-07-28 10:19:05.445923'),
+(1581, 0, NULL, NULL, 'potuisset', NULL, 'possum', 3, 1, 0, 3, 7, '2025-07-28 10:19:05.448189', '2025-07-28 10:19:05.448189'),
+(1582, 1, NULL, NULL, 'potuissent', NULL, 'possum', 3, 1, 0, 3, 7, '2025-07-28 10:19:05.450437', '2025-07-28 10:19:05.450437'),
+(1583, 0, NULL, NULL, 'posse', NULL, 'possum', 0, 6, 0, 1, 7, '2025-07-28 10:19:05.452703', '2025-07-28 10:19:05.452703'),
+(1584, 1, NULL, NULL, NULL, NULL, 'possum', 0, 6, 0, 1, 7, '2025-07-28 10:19:05.459279', '2025-07-28 10:19:05.459279'),
+(1585, 0, NULL, NULL, 'potuisse', NULL, 'possum', 2, 6, 0, 1, 7, '2025-07-28 10:19:05.461553', '2025-07-28 10:19:05.461553'),
+(1586, 1, NULL, NULL, NULL, NULL, 'possum', 2, 6, 0, 1, 7, '2025-07-28 10:19:05.465165', '2025-07-28 10:19:05.465165'),
+(1587, 0, NULL, NULL, 'potēns', NULL, 'possum', 0, 8, 0, 1, 7, '2025-07-28 10:19:05.467269', '2025-07-28 10:19:05.467269'),
+(1588, 1, NULL, NULL, NULL, NULL, 'possum', 0, 8, 0, 1, 7, '2025-07-28 10:19:05.470945', '2025-07-28 10:19:05.470945'),
+(1589, 0, NULL, NULL, 'eō', NULL, 'eo', 0, 0, 0, 1, 8, '2025-07-28 10:19:05.478720', '2025-07-28 10:19:05.478720'),
+(1590, 1, NULL, NULL, 'īmus', NULL, 'eo', 0, 0, 0, 1, 8, '2025-07-28 10:19:05.482410', '2025-07-28 10:19:05.482410'),
+(1591, 0, NULL, NULL, 'īs', NULL, 'eo', 0, 0, 0, 2, 8, '2025-07-28 10:19:05.484521', '2025-07-28 10:19:05.484521'),
+(1592, 1, NULL, NULL, 'ītis', NULL, 'eo', 0, 0, 0, 2, 8, '2025-07-28 10:19:05.488178', '2025-07-28 10:19:05.488178'),
+(1593, 0, NULL, NULL, 'it', NULL, 'eo', 0, 0, 0, 3, 8, '2025-07-28 10:19:05.490451', '2025-07-28 10:19:05.490451'),
+(1594, 1, NULL, NULL, 'eunt', NULL, 'eo', 0, 0, 0, 3, 8, '2025-07-28 10:19:05.494194', '2025-07-28 10:19:05.494194'),
+(1595, 0, NULL, NULL, 'ībam', NULL, 'eo', 1, 0, 0, 1, 8, '2025-07-28 10:19:05.496286', '2025-07-28 10:19:05.496286'),
+(1596, 1, NULL, NULL, 'ībāmus', NULL, 'eo', 1, 0, 0, 1, 8, '2025-07-28 10:19:05.500066', '2025-07-28 10:19:05.500066'),
+(1597, 0, NULL, NULL, 'ībās', NULL, 'eo', 1, 0, 0, 2, 8, '2025-07-28 10:19:05.502171', '2025-07-28 10:19:05.502171'),
+(1598, 1, NULL, NULL, 'ībātis', NULL, 'eo', 1, 0, 0, 2, 8, '2025-07-28 10:19:05.506126', '2025-07-28 10:19:05.506126'),
+(1599, 0, NULL, NULL, 'ībat', NULL, 'eo', 1, 0, 0, 3, 8, '2025-07-28 10:19:05.508326', '2025-07-28 10:19:05.508326'),
+(1600, 1, NULL, NULL, 'ībant', NULL, 'eo', 1, 0, 0, 3, 8, '2025-07-28 10:19:05.512297', '2025-07-28 10:19:05.512297'),
+(1601, 0, NULL, NULL, 'iī', NULL, 'eo', 2, 0, 0, 1, 8, '2025-07-28 10:19:05.514483', '2025-07-28 10:19:05.514483'),
+(1602, 1, NULL, NULL, 'iimus', NULL, 'eo', 2, 0, 0, 1, 8, '2025-07-28 10:19:05.518165', '2025-07-28 10:19:05.518165'),
+(1603, 0, NULL, NULL, 'īstī', NULL, 'eo', 2, 0, 0, 2, 8, '2025-07-28 10:19:05.520670', '2025-07-28 10:19:05.520670'),
+(1604, 1, NULL, NULL, 'īstis', NULL, 'eo', 2, 0, 0, 2, 8, '2025-07-28 10:19:05.522950', '2025-07-28 10:19:05.522950'),
+(1605, 0, NULL, NULL, 'iit', NULL, 'eo', 2, 0, 0, 3, 8, '2025-07-28 10:19:05.526816', '2025-07-28 10:19:05.526816'),
+(1606, 1, NULL, NULL, 'iērunt', NULL, 'eo', 2, 0, 0, 3, 8, '2025-07-28 10:19:05.528961', '2025-07-28 10:19:05.528961'),
+(1607, 0, NULL, NULL, 'ieram', NULL, 'eo', 3, 0, 0, 1, 8, '2025-07-28 10:19:05.532105', '2025-07-28 10:19:05.532105'),
+(1608, 1, NULL, NULL, 'ierāmus', NULL, 'eo', 3, 0, 0, 1, 8, '2025-07-28 10:19:05.534255', '2025-07-28 10:19:05.534255'),
+(1609, 0, NULL, NULL, 'ierās', NULL, 'eo', 3, 0, 0, 2, 8, '2025-07-28 10:19:05.537527', '2025-07-28 10:19:05.537527'),
+(1610, 1, NULL, NULL, 'ierātis', NULL, 'eo', 3, 0, 0, 2, 8, '2025-07-28 10:19:05.539873', '2025-07-28 10:19:05.539873'),
+(1611, 0, NULL, NULL, 'ierat', NULL, 'eo', 3, 0, 0, 3, 8, '2025-07-28 10:19:05.543264', '2025-07-28 10:19:05.543264'),
+(1612, 1, NULL, NULL, 'ierant', NULL, 'eo', 3, 0, 0, 3, 8, '2025-07-28 10:19:05.545710', '2025-07-28 10:19:05.545710'),
+(1613, 0, NULL, NULL, 'ībō', NULL, 'eo', 4, 0, 0, 1, 8, '2025-07-28 10:19:05.548074', '2025-07-28 10:19:05.548074'),
+(1614, 1, NULL, NULL, 'ībimus', NULL, 'eo', 4, 0, 0, 1, 8, '2025-07-28 10:19:05.551390', '2025-07-28 10:19:05.551390'),
+(1615, 0, NULL, NULL, 'ībis', NULL, 'eo', 4, 0, 0, 2, 8, '2025-07-28 10:19:05.553811', '2025-07-28 10:19:05.553811'),
+(1616, 1, NULL, NULL, 'ībitis', NULL, 'eo', 4, 0, 0, 2, 8, '2025-07-28 10:19:05.557044', '2025-07-28 10:19:05.557044'),
+(1617, 0, NULL, NULL, 'ībit', NULL, 'eo', 4, 0, 0, 3, 8, '2025-07-28 10:19:05.559439', '2025-07-28 10:19:05.559439'),
+(1618, 1, NULL, NULL, 'ībunt', NULL, 'eo', 4, 0, 0, 3, 8, '2025-07-28 10:19:05.562508', '2025-07-28 10:19:05.562508'),
+(1619, 0, NULL, NULL, 'ierō', NULL, 'eo', 5, 0, 0, 1, 8, '2025-07-28 10:19:05.564840', '2025-07-28 10:19:05.564840'),
+(1620, 1, NULL, NULL, 'ierimus', NULL, 'eo', 5, 0, 0, 1, 8, '2025-07-28 10:19:05.567121', '2025-07-28 10:19:05.567121'),
+(1621, 0, NULL, NULL, 'ieris', NULL, 'eo', 5, 0, 0, 2, 8, '2025-07-28 10:19:05.570176', '2025-07-28 10:19:05.570176'),
+(1622, 1, NULL, NULL, 'ieritis', NULL, 'eo', 5, 0, 0, 2, 8, '2025-07-28 10:19:05.572525', '2025-07-28 10:19:05.572525'),
+(1623, 0, NULL, NULL, 'ierit', NULL, 'eo', 5, 0, 0, 3, 8, '2025-07-28 10:19:05.575586', '2025-07-28 10:19:05.575586'),
+(1624, 1, NULL, NULL, 'ierint', NULL, 'eo', 5, 0, 0, 3, 8, '2025-07-28 10:19:05.577943', '2025-07-28 10:19:05.577943'),
+(1625, 0, NULL, NULL, 'eor', NULL, 'eo', 0, 0, 1, 1, 8, '2025-07-28 10:19:05.581849', '2025-07-28 10:19:05.581849'),
+(1626, 1, NULL, NULL, 'īmur', NULL, 'eo', 0, 0, 1, 1, 8, '2025-07-28 10:19:05.584129', '2025-07-28 10:19:05.584129'),
+(1627, 0, NULL, NULL, 'īris', NULL, 'eo', 0, 0, 1, 2, 8, '2025-07-28 10:19:05.586408', '2025-07-28 10:19:05.586408'),
+(1628, 1, NULL, NULL, 'īminī', NULL, 'eo', 0, 0, 1, 2, 8, '2025-07-28 10:19:05.588717', '2025-07-28 10:19:05.588717'),
+(1629, 0, NULL, NULL, 'ītur', NULL, 'eo', 0, 0, 1, 3, 8, '2025-07-28 10:19:05.591101', '2025-07-28 10:19:05.591101'),
+(1630, 1, NULL, NULL, 'euntur', NULL, 'eo', 0, 0, 1, 3, 8, '2025-07-28 10:19:05.593436', '2025-07-28 10:19:05.593436'),
+(1631, 0, NULL, NULL, 'ībar', NULL, 'eo', 1, 0, 1, 1, 8, '2025-07-28 10:19:05.595766', '2025-07-28 10:19:05.595766'),
+(1632, 1, NULL, NULL, 'ībāmur', NULL, 'eo', 1, 0, 1, 1, 8, '2025-07-28 10:19:05.598034', '2025-07-28 10:19:05.598034'),
+(1633, 0, NULL, NULL, 'ībāris', NULL, 'eo', 1, 0, 1, 2, 8, '2025-07-28 10:19:05.600266', '2025-07-28 10:19:05.600266'),
+(1634, 1, NULL, NULL, 'ībāminī', NULL, 'eo', 1, 0, 1, 2, 8, '2025-07-28 10:19:05.602528', '2025-07-28 10:19:05.602528'),
+(1635, 0, NULL, NULL, 'ībātur', NULL, 'eo', 1, 0, 1, 3, 8, '2025-07-28 10:19:05.604817', '2025-07-28 10:19:05.604817'),
+(1636, 1, NULL, NULL, 'ībantur', NULL, 'eo', 1, 0, 1, 3, 8, '2025-07-28 10:19:05.607094', '2025-07-28 10:19:05.607094'),
+(1637, 0, NULL, NULL, 'ībor', NULL, 'eo', 4, 0, 1, 1, 8, '2025-07-28 10:19:05.609402', '2025-07-28 10:19:05.609402'),
+(1638, 1, NULL, NULL, 'ībimur', NULL, 'eo', 4, 0, 1, 1, 8, '2025-07-28 10:19:05.611691', '2025-07-28 10:19:05.611691'),
+(1639, 0, NULL, NULL, 'īberis', NULL, 'eo', 4, 0, 1, 2, 8, '2025-07-28 10:19:05.613965', '2025-07-28 10:19:05.613965'),
+(1640, 1, NULL, NULL, 'ībiminī', NULL, 'eo', 4, 0, 1, 2, 8, '2025-07-28 10:19:05.616176', '2025-07-28 10:19:05.616176'),
+(1641, 0, NULL, NULL, 'ībitur', NULL, 'eo', 4, 0, 1, 3, 8, '2025-07-28 10:19:05.618441', '2025-07-28 10:19:05.618441'),
+(1642, 1, NULL, NULL, 'ībuntur', NULL, 'eo', 4, 0, 1, 3, 8, '2025-07-28 10:19:05.620739', '2025-07-28 10:19:05.620739'),
+(1643, 0, NULL, NULL, 'sum', NULL, 'eo', 2, 0, 1, 1, 8, '2025-07-28 10:19:05.623043', '2025-07-28 10:19:05.623043'),
+(1644, 1, NULL, NULL, 'sumus', NULL, 'eo', 2, 0, 1, 1, 8, '2025-07-28 10:19:05.625371', '2025-07-28 10:19:05.625371'),
+(1645, 0, NULL, NULL, 'es', NULL, 'eo', 2, 0, 1, 2, 8, '2025-07-28 10:19:05.627619', '2025-07-28 10:19:05.627619'),
+(1646, 1, NULL, NULL, 'estis', NULL, 'eo', 2, 0, 1, 2, 8, '2025-07-28 10:19:05.629916', '2025-07-28 10:19:05.629916'),
+(1647, 0, NULL, NULL, 'est', NULL, 'eo', 2, 0, 1, 3, 8, '2025-07-28 10:19:05.637136', '2025-07-28 10:19:05.637136'),
+(1648, 1, NULL, NULL, 'sunt', NULL, 'eo', 2, 0, 1, 3, 8, '2025-07-28 10:19:05.641434', '2025-07-28 10:19:05.641434'),
+(1649, 0, NULL, NULL, 'eram', NULL, 'eo', 3, 0, 1, 1, 8, '2025-07-28 10:19:05.643822', '2025-07-28 10:19:05.643822'),
+(1650, 1, NULL, NULL, 'erāmus', NULL, 'eo', 3, 0, 1, 1, 8, '2025-07-28 10:19:05.647817', '2025-07-28 10:19:05.647817'),
+(1651, 0, NULL, NULL, 'erās', NULL, 'eo', 3, 0, 1, 2, 8, '2025-07-28 10:19:05.650148', '2025-07-28 10:19:05.650148'),
+(1652, 1, NULL, NULL, 'erātis', NULL, 'eo', 3, 0, 1, 2, 8, '2025-07-28 10:19:05.654225', '2025-07-28 10:19:05.654225'),
+(1653, 0, NULL, NULL, 'erat', NULL, 'eo', 3, 0, 1, 3, 8, '2025-07-28 10:19:05.656591', '2025-07-28 10:19:05.656591'),
+(1654, 1, NULL, NULL, 'erant', NULL, 'eo', 3, 0, 1, 3, 8, '2025-07-28 10:19:05.660574', '2025-07-28 10:19:05.660574'),
+(1655, 0, NULL, NULL, 'erō', NULL, 'eo', 5, 0, 1, 1, 8, '2025-07-28 10:19:05.662958', '2025-07-28 10:19:05.662958'),
+(1656, 1, NULL, NULL, 'erimus', NULL, 'eo', 5, 0, 1, 1, 8, '2025-07-28 10:19:05.666774', '2025-07-28 10:19:05.666774'),
+(1657, 0, NULL, NULL, 'eris', NULL, 'eo', 5, 0, 1, 2, 8, '2025-07-28 10:19:05.669125', '2025-07-28 10:19:05.669125'),
+(1658, 1, NULL, NULL, 'eritis', NULL, 'eo', 5, 0, 1, 2, 8, '2025-07-28 10:19:05.672940', '2025-07-28 10:19:05.672940'),
+(1659, 0, NULL, NULL, 'erit', NULL, 'eo', 5, 0, 1, 3, 8, '2025-07-28 10:19:05.675315', '2025-07-28 10:19:05.675315'),
+(1660, 1, NULL, NULL, 'erunt', NULL, 'eo', 5, 0, 1, 3, 8, '2025-07-28 10:19:05.679116', '2025-07-28 10:19:05.679116'),
+(1661, 0, NULL, NULL, 'eam', NULL, 'eo', 0, 1, 0, 1, 8, '2025-07-28 10:19:05.682689', '2025-07-28 10:19:05.682689'),
+(1662, 1, NULL, NULL, 'eāmus', NULL, 'eo', 0, 1, 0, 1, 8, '2025-07-28 10:19:05.685336', '2025-07-28 10:19:05.685336'),
+(1663, 0, NULL, NULL, 'eās', NULL, 'eo', 0, 1, 0, 2, 8, '2025-07-28 10:19:05.687664', '2025-07-28 10:19:05.687664'),
+(1664, 1, NULL, NULL, 'eātis', NULL, 'eo', 0, 1, 0, 2, 8, '2025-07-28 10:19:05.691731', '2025-07-28 10:19:05.691731'),
+(1665, 0, NULL, NULL, 'eat', NULL, 'eo', 0, 1, 0, 3, 8, '2025-07-28 10:19:05.694085', '2025-07-28 10:19:05.694085'),
+(1666, 1, NULL, NULL, 'eant', NULL, 'eo', 0, 1, 0, 3, 8, '2025-07-28 10:19:05.698187', '2025-07-28 10:19:05.698187'),
+(1667, 0, NULL, NULL, 'īrem', NULL, 'eo', 1, 1, 0, 1, 8, '2025-07-28 10:19:05.700457', '2025-07-28 10:19:05.700457'),
+(1668, 1, NULL, NULL, 'īrēmus', NULL, 'eo', 1, 1, 0, 1, 8, '2025-07-28 10:19:05.703960', '2025-07-28 10:19:05.703960'),
+(1669, 0, NULL, NULL, 'īrēs', NULL, 'eo', 1, 1, 0, 2, 8, '2025-07-28 10:19:05.706484', '2025-07-28 10:19:05.706484'),
+(1670, 1, NULL, NULL, 'īrētis', NULL, 'eo', 1, 1, 0, 2, 8, '2025-07-28 10:19:05.708915', '2025-07-28 10:19:05.708915'),
+(1671, 0, NULL, NULL, 'īret', NULL, 'eo', 1, 1, 0, 3, 8, '2025-07-28 10:19:05.712253', '2025-07-28 10:19:05.712253'),
+(1672, 1, NULL, NULL, 'īrent', NULL, 'eo', 1, 1, 0, 3, 8, '2025-07-28 10:19:05.714602', '2025-07-28 10:19:05.714602'),
+(1673, 0, NULL, NULL, 'ierim', NULL, 'eo', 2, 1, 0, 1, 8, '2025-07-28 10:19:05.717908', '2025-07-28 10:19:05.717908'),
+(1674, 1, NULL, NULL, 'ierīmus', NULL, 'eo', 2, 1, 0, 1, 8, '2025-07-28 10:19:05.720214', '2025-07-28 10:19:05.720214'),
+(1675, 0, NULL, NULL, 'ierīs', NULL, 'eo', 2, 1, 0, 2, 8, '2025-07-28 10:19:05.723475', '2025-07-28 10:19:05.723475'),
+(1676, 1, NULL, NULL, 'ierītis', NULL, 'eo', 2, 1, 0, 2, 8, '2025-07-28 10:19:05.725863', '2025-07-28 10:19:05.725863'),
+(1677, 0, NULL, NULL, 'ierit', NULL, 'eo', 2, 1, 0, 3, 8, '2025-07-28 10:19:05.729146', '2025-07-28 10:19:05.729146'),
+(1678, 1, NULL, NULL, 'ierint', NULL, 'eo', 2, 1, 0, 3, 8, '2025-07-28 10:19:05.731432', '2025-07-28 10:19:05.731432'),
+(1679, 0, NULL, NULL, 'īssem', NULL, 'eo', 3, 1, 0, 1, 8, '2025-07-28 10:19:05.734389', '2025-07-28 10:19:05.734389'),
+(1680, 1, NULL, NULL, 'īssēmus', NULL, 'eo', 3, 1, 0, 1, 8, '2025-07-28 10:19:05.736711', '2025-07-28 10:19:05.736711'),
+(1681, 0, NULL, NULL, 'īssēs', NULL, 'eo', 3, 1, 0, 2, 8, '2025-07-28 10:19:05.739033', '2025-07-28 10:19:05.739033'),
+(1682, 1, NULL, NULL, 'īssētis', NULL, 'eo', 3, 1, 0, 2, 8, '2025-07-28 10:19:05.741973', '2025-07-28 10:19:05.741973'),
+(1683, 0, NULL, NULL, 'īsset', NULL, 'eo', 3, 1, 0, 3, 8, '2025-07-28 10:19:05.744295', '2025-07-28 10:19:05.744295'),
+(1684, 1, NULL, NULL, 'īssent', NULL, 'eo', 3, 1, 0, 3, 8, '2025-07-28 10:19:05.747278', '2025-07-28 10:19:05.747278'),
+(1685, 0, NULL, NULL, 'ear', NULL, 'eo', 0, 1, 1, 1, 8, '2025-07-28 10:19:05.751602', '2025-07-28 10:19:05.751602'),
+(1686, 1, NULL, NULL, 'eāmur', NULL, 'eo', 0, 1, 1, 1, 8, '2025-07-28 10:19:05.753981', '2025-07-28 10:19:05.753981'),
+(1687, 0, NULL, NULL, 'eāris', NULL, 'eo', 0, 1, 1, 2, 8, '2025-07-28 10:19:05.756303', '2025-07-28 10:19:05.756303'),
+(1688, 1, NULL, NULL, 'eāminī', NULL, 'eo', 0, 1, 1, 2, 8, '2025-07-28 10:19:05.758627', '2025-07-28 10:19:05.758627'),
+(1689, 0, NULL, NULL, 'eātur', NULL, 'eo', 0, 1, 1, 3, 8, '2025-07-28 10:19:05.760934', '2025-07-28 10:19:05.760934'),
+(1690, 1, NULL, NULL, 'eantur', NULL, 'eo', 0, 1, 1, 3, 8, '2025-07-28 10:19:05.763211', '2025-07-28 10:19:05.763211'),
+(1691, 0, NULL, NULL, 'īrer', NULL, 'eo', 1, 1, 1, 1, 8, '2025-07-28 10:19:05.765523', '2025-07-28 10:19:05.765523'),
+(1692, 1, NULL, NULL, 'īrēmur', NULL, 'eo', 1, 1, 1, 1, 8, '2025-07-28 10:19:05.767836', '2025-07-28 10:19:05.767836'),
+(1693, 0, NULL, NULL, 'īrēris', NULL, 'eo', 1, 1, 1, 2, 8, '2025-07-28 10:19:05.770227', '2025-07-28 10:19:05.770227'),
+(1694, 1, NULL, NULL, 'īrēminī', NULL, 'eo', 1, 1, 1, 2, 8, '2025-07-28 10:19:05.772566', '2025-07-28 10:19:05.772566'),
+(1695, 0, NULL, NULL, 'īrētur', NULL, 'eo', 1, 1, 1, 3, 8, '2025-07-28 10:19:05.774946', '2025-07-28 10:19:05.774946'),
+(1696, 1, NULL, NULL, 'īrentur', NULL, 'eo', 1, 1, 1, 3, 8, '2025-07-28 10:19:05.777214', '2025-07-28 10:19:05.777214'),
+(1697, 0, NULL, NULL, 'sim', NULL, 'eo', 2, 1, 1, 1, 8, '2025-07-28 10:19:05.779500', '2025-07-28 10:19:05.779500'),
+(1698, 1, NULL, NULL, 'sīmus', NULL, 'eo', 2, 1, 1, 1, 8, '2025-07-28 10:19:05.781765', '2025-07-28 10:19:05.781765'),
+(1699, 0, NULL, NULL, 'sīs', NULL, 'eo', 2, 1, 1, 2, 8, '2025-07-28 10:19:05.784028', '2025-07-28 10:19:05.784028'),
+(1700, 1, NULL, NULL, 'sītis', NULL, 'eo', 2, 1, 1, 2, 8, '2025-07-28 10:19:05.786293', '2025-07-28 10:19:05.786293'),
+(1701, 0, NULL, NULL, 'sit', NULL, 'eo', 2, 1, 1, 3, 8, '2025-07-28 10:19:05.788579', '2025-07-28 10:19:05.788579'),
+(1702, 1, NULL, NULL, 'sint', NULL, 'eo', 2, 1, 1, 3, 8, '2025-07-28 10:19:05.790858', '2025-07-28 10:19:05.790858'),
+(1703, 0, NULL, NULL, 'essem', NULL, 'eo', 3, 1, 1, 1, 8, '2025-07-28 10:19:05.793081', '2025-07-28 10:19:05.793081'),
+(1704, 1, NULL, NULL, 'essēmus', NULL, 'eo', 3, 1, 1, 1, 8, '2025-07-28 10:19:05.795365', '2025-07-28 10:19:05.795365'),
+(1705, 0, NULL, NULL, 'essēs', NULL, 'eo', 3, 1, 1, 2, 8, '2025-07-28 10:19:05.802058', '2025-07-28 10:19:05.802058'),
+(1706, 1, NULL, NULL, 'essētis', NULL, 'eo', 3, 1, 1, 2, 8, '2025-07-28 10:19:05.804987', '2025-07-28 10:19:05.804987'),
+(1707, 0, NULL, NULL, 'esset', NULL, 'eo', 3, 1, 1, 3, 8, '2025-07-28 10:19:05.809296', '2025-07-28 10:19:05.809296'),
+(1708, 1, NULL, NULL, 'essent', NULL, 'eo', 3, 1, 1, 3, 8, '2025-07-28 10:19:05.811712', '2025-07-28 10:19:05.811712'),
+(1709, 0, NULL, NULL, 'ī', NULL, 'eo', 0, 2, 0, 2, 8, '2025-07-28 10:19:05.815647', '2025-07-28 10:19:05.815647'),
+(1710, 1, NULL, NULL, 'īte', NULL, 'eo', 0, 2, 0, 2, 8, '2025-07-28 10:19:05.818017', '2025-07-28 10:19:05.818017'),
+(1711, 0, NULL, NULL, 'ītō', NULL, 'eo', 4, 2, 0, 2, 8, '2025-07-28 10:19:05.822041', '2025-07-28 10:19:05.822041'),
+(1712, 1, NULL, NULL, 'ītōte', NULL, 'eo', 4, 2, 0, 2, 8, '2025-07-28 10:19:05.824458', '2025-07-28 10:19:05.824458'),
+(1713, 0, NULL, NULL, 'ītō', NULL, 'eo', 4, 2, 0, 3, 8, '2025-07-28 10:19:05.828384', '2025-07-28 10:19:05.828384'),
+(1714, 1, NULL, NULL, 'euntō', NULL, 'eo', 4, 2, 0, 3, 8, '2025-07-28 10:19:05.830752', '2025-07-28 10:19:05.830752'),
+(1715, 0, NULL, NULL, 'īre', NULL, 'eo', 0, 2, 1, 2, 8, '2025-07-28 10:19:05.834406', '2025-07-28 10:19:05.834406'),
+(1716, 1, NULL, NULL, 'īminī', NULL, 'eo', 0, 2, 1, 2, 8, '2025-07-28 10:19:05.837933', '2025-07-28 10:19:05.837933'),
+(1717, 0, NULL, NULL, 'ītor', NULL, 'eo', 4, 2, 1, 2, 8, '2025-07-28 10:19:05.840623', '2025-07-28 10:19:05.840623'),
+(1718, 1, NULL, NULL, NULL, NULL, 'eo', 4, 2, 1, 2, 8, '2025-07-28 10:19:05.844227', '2025-07-28 10:19:05.844227'),
+(1719, 0, NULL, NULL, 'ītor', NULL, 'eo', 4, 2, 1, 3, 8, '2025-07-28 10:19:05.846984', '2025-07-28 10:19:05.846984'),
+(1720, 1, NULL, NULL, 'euntor', NULL, 'eo', 4, 2, 1, 3, 8, '2025-07-28 10:19:05.850721', '2025-07-28 10:19:05.850721'),
+(1721, 0, NULL, NULL, 'īre', NULL, 'eo', 0, 6, 0, 1, 8, '2025-07-28 10:19:05.853021', '2025-07-28 10:19:05.853021'),
+(1722, 1, NULL, NULL, NULL, NULL, 'eo', 0, 6, 0, 1, 8, '2025-07-28 10:19:05.856890', '2025-07-28 10:19:05.856890'),
+(1723, 0, NULL, NULL, 'īsse', NULL, 'eo', 2, 6, 0, 1, 8, '2025-07-28 10:19:05.859240', '2025-07-28 10:19:05.859240'),
+(1724, 1, NULL, NULL, NULL, NULL, 'eo', 2, 6, 0, 1, 8, '2025-07-28 10:19:05.861591', '2025-07-28 10:19:05.861591'),
+(1725, 0, NULL, NULL, 'esse', NULL, 'eo', 4, 6, 0, 1, 8, '2025-07-28 10:19:05.865523', '2025-07-28 10:19:05.865523'),
+(1726, 1, NULL, NULL, NULL, NULL, 'eo', 4, 6, 0, 1, 8, '2025-07-28 10:19:05.867901', '2025-07-28 10:19:05.867901'),
+(1727, 0, NULL, NULL, 'īrī', NULL, 'eo', 0, 6, 1, 1, 8, '2025-07-28 10:19:05.871226', '2025-07-28 10:19:05.871226'),
+(1728, 1, NULL, NULL, NULL, NULL, 'eo', 0, 6, 1, 1, 8, '2025-07-28 10:19:05.873521', '2025-07-28 10:19:05.873521'),
+(1729, 0, NULL, NULL, 'esse', NULL, 'eo', 2, 6, 1, 1, 8, '2025-07-28 10:19:05.876879', '2025-07-28 10:19:05.876879'),
+(1730, 1, NULL, NULL, NULL, NULL, 'eo', 2, 6, 1, 1, 8, '2025-07-28 10:19:05.879205', '2025-07-28 10:19:05.879205'),
+(1731, 0, NULL, NULL, 'īrī', NULL, 'eo', 4, 6, 1, 1, 8, '2025-07-28 10:19:05.882462', '2025-07-28 10:19:05.882462'),
+(1732, 1, NULL, NULL, NULL, NULL, 'eo', 4, 6, 1, 1, 8, '2025-07-28 10:19:05.884754', '2025-07-28 10:19:05.884754'),
+(1733, 0, NULL, NULL, 'iēns', NULL, 'eo', 0, 8, 0, 1, 8, '2025-07-28 10:19:05.887125', '2025-07-28 10:19:05.887125'),
+(1734, 1, NULL, NULL, NULL, NULL, 'eo', 0, 8, 0, 1, 8, '2025-07-28 10:19:05.890385', '2025-07-28 10:19:05.890385'),
+(1735, 0, NULL, NULL, 'itūrus', NULL, 'eo', 4, 8, 0, 1, 8, '2025-07-28 10:19:05.892766', '2025-07-28 10:19:05.892766'),
+(1736, 1, NULL, NULL, NULL, NULL, 'eo', 4, 8, 0, 1, 8, '2025-07-28 10:19:05.896042', '2025-07-28 10:19:05.896042'),
+(1737, 0, NULL, NULL, 'us', NULL, 'eo', 2, 8, 1, 1, 8, '2025-07-28 10:19:05.898355', '2025-07-28 10:19:05.898355'),
+(1738, 1, NULL, NULL, NULL, NULL, 'eo', 2, 8, 1, 1, 8, '2025-07-28 10:19:05.901292', '2025-07-28 10:19:05.901292'),
+(1739, 0, NULL, NULL, 'eundus', NULL, 'eo', 4, 8, 1, 1, 8, '2025-07-28 10:19:05.903660', '2025-07-28 10:19:05.903660'),
+(1740, 1, NULL, NULL, NULL, NULL, 'eo', 4, 8, 1, 1, 8, '2025-07-28 10:19:05.906719', '2025-07-28 10:19:05.906719'),
+(1741, 0, NULL, NULL, 'volō', NULL, 'volo', 0, 0, 0, 1, 9, '2025-07-28 10:19:05.916031', '2025-07-28 10:19:05.916031'),
+(1742, 1, NULL, NULL, 'volumus', NULL, 'volo', 0, 0, 0, 1, 9, '2025-07-28 10:19:05.918422', '2025-07-28 10:19:05.918422'),
+(1743, 0, NULL, NULL, 'vīs', NULL, 'volo', 0, 0, 0, 2, 9, '2025-07-28 10:19:05.920961', '2025-07-28 10:19:05.920961'),
+(1744, 1, NULL, NULL, 'vultis', NULL, 'volo', 0, 0, 0, 2, 9, '2025-07-28 10:19:05.923383', '2025-07-28 10:19:05.923383'),
+(1745, 0, NULL, NULL, 'vult', NULL, 'volo', 0, 0, 0, 3, 9, '2025-07-28 10:19:05.925767', '2025-07-28 10:19:05.925767'),
+(1746, 1, NULL, NULL, 'volunt', NULL, 'volo', 0, 0, 0, 3, 9, '2025-07-28 10:19:05.928062', '2025-07-28 10:19:05.928062'),
+(1747, 0, NULL, NULL, 'volēbam', NULL, 'volo', 1, 0, 0, 1, 9, '2025-07-28 10:19:05.930348', '2025-07-28 10:19:05.930348'),
+(1748, 1, NULL, NULL, 'volēbāmus', NULL, 'volo', 1, 0, 0, 1, 9, '2025-07-28 10:19:05.932648', '2025-07-28 10:19:05.932648'),
+(1749, 0, NULL, NULL, 'volēbās', NULL, 'volo', 1, 0, 0, 2, 9, '2025-07-28 10:19:05.934999', '2025-07-28 10:19:05.934999'),
+(1750, 1, NULL, NULL, 'volēbātis', NULL, 'volo', 1, 0, 0, 2, 9, '2025-07-28 10:19:05.937463', '2025-07-28 10:19:05.937463'),
+(1751, 0, NULL, NULL, 'volēbat', NULL, 'volo', 1, 0, 0, 3, 9, '2025-07-28 10:19:05.939823', '2025-07-28 10:19:05.939823'),
+(1752, 1, NULL, NULL, 'volēbant', NULL, 'volo', 1, 0, 0, 3, 9, '2025-07-28 10:19:05.942182', '2025-07-28 10:19:05.942182'),
+(1753, 0, NULL, NULL, 'voluī', NULL, 'volo', 2, 0, 0, 1, 9, '2025-07-28 10:19:05.944490', '2025-07-28 10:19:05.944490'),
+(1754, 1, NULL, NULL, 'voluimus', NULL, 'volo', 2, 0, 0, 1, 9, '2025-07-28 10:19:05.946795', '2025-07-28 10:19:05.946795'),
+(1755, 0, NULL, NULL, 'voluistī', NULL, 'volo', 2, 0, 0, 2, 9, '2025-07-28 10:19:05.949080', '2025-07-28 10:19:05.949080'),
+(1756, 1, NULL, NULL, 'voluistis', NULL, 'volo', 2, 0, 0, 2, 9, '2025-07-28 10:19:05.951311', '2025-07-28 10:19:05.951311'),
+(1757, 0, NULL, NULL, 'voluit', NULL, 'volo', 2, 0, 0, 3, 9, '2025-07-28 10:19:05.953612', '2025-07-28 10:19:05.953612'),
+(1758, 1, NULL, NULL, 'voluērunt', NULL, 'volo', 2, 0, 0, 3, 9, '2025-07-28 10:19:05.960647', '2025-07-28 10:19:05.960647'),
+(1759, 0, NULL, NULL, 'volueram', NULL, 'volo', 3, 0, 0, 1, 9, '2025-07-28 10:19:05.963156', '2025-07-28 10:19:05.963156'),
+(1760, 1, NULL, NULL, 'voluerāmus', NULL, 'volo', 3, 0, 0, 1, 9, '2025-07-28 10:19:05.967205', '2025-07-28 10:19:05.967205'),
+(1761, 0, NULL, NULL, 'voluerās', NULL, 'volo', 3, 0, 0, 2, 9, '2025-07-28 10:19:05.969609', '2025-07-28 10:19:05.969609'),
+(1762, 1, NULL, NULL, 'voluerātis', NULL, 'volo', 3, 0, 0, 2, 9, '2025-07-28 10:19:05.973522', '2025-07-28 10:19:05.973522'),
+(1763, 0, NULL, NULL, 'voluerat', NULL, 'volo', 3, 0, 0, 3, 9, '2025-07-28 10:19:05.977206', '2025-07-28 10:19:05.977206'),
+(1764, 1, NULL, NULL, 'voluerant', NULL, 'volo', 3, 0, 0, 3, 9, '2025-07-28 10:19:05.979912', '2025-07-28 10:19:05.979912'),
+(1765, 0, NULL, NULL, 'volam', NULL, 'volo', 4, 0, 0, 1, 9, '2025-07-28 10:19:05.983872', '2025-07-28 10:19:05.983872'),
+(1766, 1, NULL, NULL, 'volēmus', NULL, 'volo', 4, 0, 0, 1, 9, '2025-07-28 10:19:05.986261', '2025-07-28 10:19:05.986261'),
+(1767, 0, NULL, NULL, 'volēs', NULL, 'volo', 4, 0, 0, 2, 9, '2025-07-28 10:19:05.989707', '2025-07-28 10:19:05.989707'),
+(1768, 1, NULL, NULL, 'volētis', NULL, 'volo', 4, 0, 0, 2, 9, '2025-07-28 10:19:05.992329', '2025-07-28 10:19:05.992329'),
+(1769, 0, NULL, NULL, 'volet', NULL, 'volo', 4, 0, 0, 3, 9, '2025-07-28 10:19:05.996186', '2025-07-28 10:19:05.996186'),
+(1770, 1, NULL, NULL, 'volent', NULL, 'volo', 4, 0, 0, 3, 9, '2025-07-28 10:19:05.998530', '2025-07-28 10:19:05.998530'),
+(1771, 0, NULL, NULL, 'voluerō', NULL, 'volo', 5, 0, 0, 1, 9, '2025-07-28 10:19:06.002383', '2025-07-28 10:19:06.002383'),
+(1772, 1, NULL, NULL, 'voluerimus', NULL, 'volo', 5, 0, 0, 1, 9, '2025-07-28 10:19:06.004792', '2025-07-28 10:19:06.004792'),
+(1773, 0, NULL, NULL, 'volueris', NULL, 'volo', 5, 0, 0, 2, 9, '2025-07-28 10:19:06.008671', '2025-07-28 10:19:06.008671'),
+(1774, 1, NULL, NULL, 'volueritis', NULL, 'volo', 5, 0, 0, 2, 9, '2025-07-28 10:19:06.011014', '2025-07-28 10:19:06.011014'),
+(1775, 0, NULL, NULL, 'voluerit', NULL, 'volo', 5, 0, 0, 3, 9, '2025-07-28 10:19:06.014879', '2025-07-28 10:19:06.014879'),
+(1776, 1, NULL, NULL, 'voluerint', NULL, 'volo', 5, 0, 0, 3, 9, '2025-07-28 10:19:06.017146', '2025-07-28 10:19:06.017146'),
+(1777, 0, NULL, NULL, 'velim', NULL, 'volo', 0, 1, 0, 1, 9, '2025-07-28 10:19:06.020420', '2025-07-28 10:19:06.020420'),
+(1778, 1, NULL, NULL, 'velīmus', NULL, 'volo', 0, 1, 0, 1, 9, '2025-07-28 10:19:06.022765', '2025-07-28 10:19:06.022765'),
+(1779, 0, NULL, NULL, 'velīs', NULL, 'volo', 0, 1, 0, 2, 9, '2025-07-28 10:19:06.025121', '2025-07-28 10:19:06.025121'),
+(1780, 1, NULL, NULL, 'velītis', NULL, 'volo', 0, 1, 0, 2, 9, '2025-07-28 10:19:06.028138', '2025-07-28 10:19:06.028138'),
+(1781, 0, NULL, NULL, 'velit', NULL, 'volo', 0, 1, 0, 3, 9, '2025-07-28 10:19:06.030465', '2025-07-28 10:19:06.030465'),
+(1782, 1, NULL, NULL, 'velint', NULL, 'volo', 0, 1, 0, 3, 9, '2025-07-28 10:19:06.033506', '2025-07-28 10:19:06.033506'),
+(1783, 0, NULL, NULL, 'vellem', NULL, 'volo', 1, 1, 0, 1, 9, '2025-07-28 10:19:06.035831', '2025-07-28 10:19:06.035831'),
+(1784, 1, NULL, NULL, 'vellēmus', NULL, 'volo', 1, 1, 0, 1, 9, '2025-07-28 10:19:06.039073', '2025-07-28 10:19:06.039073'),
+(1785, 0, NULL, NULL, 'vellēs', NULL, 'volo', 1, 1, 0, 2, 9, '2025-07-28 10:19:06.041457', '2025-07-28 10:19:06.041457'),
+(1786, 1, NULL, NULL, 'vellētis', NULL, 'volo', 1, 1, 0, 2, 9, '2025-07-28 10:19:06.044617', '2025-07-28 10:19:06.044617'),
+(1787, 0, NULL, NULL, 'vellet', NULL, 'volo', 1, 1, 0, 3, 9, '2025-07-28 10:19:06.046973', '2025-07-28 10:19:06.046973'),
+(1788, 1, NULL, NULL, 'vellent', NULL, 'volo', 1, 1, 0, 3, 9, '2025-07-28 10:19:06.050071', '2025-07-28 10:19:06.050071'),
+(1789, 0, NULL, NULL, 'voluerim', NULL, 'volo', 2, 1, 0, 1, 9, '2025-07-28 10:19:06.052349', '2025-07-28 10:19:06.052349'),
+(1790, 1, NULL, NULL, 'voluerīmus', NULL, 'volo', 2, 1, 0, 1, 9, '2025-07-28 10:19:06.055250', '2025-07-28 10:19:06.055250'),
+(1791, 0, NULL, NULL, 'voluerīs', NULL, 'volo', 2, 1, 0, 2, 9, '2025-07-28 10:19:06.064533', '2025-07-28 10:19:06.064533'),
+(1792, 1, NULL, NULL, 'voluerītis', NULL, 'volo', 2, 1, 0, 2, 9, '2025-07-28 10:19:06.069933', '2025-07-28 10:19:06.069933'),
+(1793, 0, NULL, NULL, 'voluerit', NULL, 'volo', 2, 1, 0, 3, 9, '2025-07-28 10:19:06.079419', '2025-07-28 10:19:06.079419'),
+(1794, 1, NULL, NULL, 'voluerint', NULL, 'volo', 2, 1, 0, 3, 9, '2025-07-28 10:19:06.090577', '2025-07-28 10:19:06.090577'),
+(1795, 0, NULL, NULL, 'voluissem', NULL, 'volo', 3, 1, 0, 1, 9, '2025-07-28 10:19:06.098853', '2025-07-28 10:19:06.098853'),
+(1796, 1, NULL, NULL, 'voluissēmus', NULL, 'volo', 3, 1, 0, 1, 9, '2025-07-28 10:19:06.107092', '2025-07-28 10:19:06.107092'),
+(1797, 0, NULL, NULL, 'voluissēs', NULL, 'volo', 3, 1, 0, 2, 9, '2025-07-28 10:19:06.114904', '2025-07-28 10:19:06.114904'),
+(1798, 1, NULL, NULL, 'voluissētis', NULL, 'volo', 3, 1, 0, 2, 9, '2025-07-28 10:19:06.123811', '2025-07-28 10:19:06.123811'),
+(1799, 0, NULL, NULL, 'voluisset', NULL, 'volo', 3, 1, 0, 3, 9, '2025-07-28 10:19:06.130240', '2025-07-28 10:19:06.130240'),
+(1800, 1, NULL, NULL, 'voluissent', NULL, 'volo', 3, 1, 0, 3, 9, '2025-07-28 10:19:06.137520', '2025-07-28 10:19:06.137520'),
+(1801, 0, NULL, NULL, 'velle', NULL, 'volo', 0, 6, 0, 1, 9, '2025-07-28 10:19:06.143057', '2025-07-28 10:19:06.143057'),
+(1802, 1, NULL, NULL, NULL, NULL, 'volo', 0, 6, 0, 1, 9, '2025-07-28 10:19:06.148619', '2025-07-28 10:19:06.148619'),
+(1803, 0, NULL, NULL, 'voluisse', NULL, 'volo', 2, 6, 0, 1, 9, '2025-07-28 10:19:06.154111', '2025-07-28 10:19:06.154111'),
+(1804, 1, NULL, NULL, NULL, NULL, 'volo', 2, 6, 0, 1, 9, '2025-07-28 10:19:06.160084', '2025-07-28 10:19:06.160084'),
+(1805, 0, NULL, NULL, 'volēns', NULL, 'volo', 0, 8, 0, 1, 9, '2025-07-28 10:19:06.166766', '2025-07-28 10:19:06.166766'),
+(1806, 1, NULL, NULL, NULL, NULL, 'volo', 0, 8, 0, 1, 9, '2025-07-28 10:19:06.171522', '2025-07-28 10:19:06.171522'),
+(1807, 0, NULL, NULL, 'nōlō', NULL, 'nolo', 0, 0, 0, 1, 10, '2025-07-28 10:19:06.180165', '2025-07-28 10:19:06.180165'),
+(1808, 1, NULL, NULL, 'nōlumus', NULL, 'nolo', 0, 0, 0, 1, 10, '2025-07-28 10:19:06.184772', '2025-07-28 10:19:06.184772'),
+(1809, 0, NULL, NULL, 'nōn vīs', NULL, 'nolo', 0, 0, 0, 2, 10, '2025-07-28 10:19:06.187492', '2025-07-28 10:19:06.187492'),
+(1810, 1, NULL, NULL, 'nōn vultis', NULL, 'nolo', 0, 0, 0, 2, 10, '2025-07-28 10:19:06.191729', '2025-07-28 10:19:06.191729'),
+(1811, 0, NULL, NULL, 'nōn vult', NULL, 'nolo', 0, 0, 0, 3, 10, '2025-07-28 10:19:06.194255', '2025-07-28 10:19:06.194255'),
+(1812, 1, NULL, NULL, 'nōlunt', NULL, 'nolo', 0, 0, 0, 3, 10, '2025-07-28 10:19:06.198305', '2025-07-28 10:19:06.198305'),
+(1813, 0, NULL, NULL, 'nōlēbam', NULL, 'nolo', 1, 0, 0, 1, 10, '2025-07-28 10:19:06.200757', '2025-07-28 10:19:06.200757'),
+(1814, 1, NULL, NULL, 'nōlēbāmus', NULL, 'nolo', 1, 0, 0, 1, 10, '2025-07-28 10:19:06.205041', '2025-07-28 10:19:06.205041'),
+(1815, 0, NULL, NULL, 'nōlēbās', NULL, 'nolo', 1, 0, 0, 2, 10, '2025-07-28 10:19:06.207455', '2025-07-28 10:19:06.207455'),
+(1816, 1, NULL, NULL, 'nōlēbātis', NULL, 'nolo', 1, 0, 0, 2, 10, '2025-07-28 10:19:06.211608', '2025-07-28 10:19:06.211608'),
+(1817, 0, NULL, NULL, 'nōlēbat', NULL, 'nolo', 1, 0, 0, 3, 10, '2025-07-28 10:19:06.214036', '2025-07-28 10:19:06.214036'),
+(1818, 1, NULL, NULL, 'nōlēbant', NULL, 'nolo', 1, 0, 0, 3, 10, '2025-07-28 10:19:06.218037', '2025-07-28 10:19:06.218037'),
+(1819, 0, NULL, NULL, 'nōluī', NULL, 'nolo', 2, 0, 0, 1, 10, '2025-07-28 10:19:06.220393', '2025-07-28 10:19:06.220393'),
+(1820, 1, NULL, NULL, 'nōluimus', NULL, 'nolo', 2, 0, 0, 1, 10, '2025-07-28 10:19:06.223777', '2025-07-28 10:19:06.223777'),
+(1821, 0, NULL, NULL, 'nōluistī', NULL, 'nolo', 2, 0, 0, 2, 10, '2025-07-28 10:19:06.226176', '2025-07-28 10:19:06.226176'),
+(1822, 1, NULL, NULL, 'nōluistis', NULL, 'nolo', 2, 0, 0, 2, 10, '2025-07-28 10:19:06.229464', '2025-07-28 10:19:06.229464'),
+(1823, 0, NULL, NULL, 'nōluit', NULL, 'nolo', 2, 0, 0, 3, 10, '2025-07-28 10:19:06.231828', '2025-07-28 10:19:06.231828'),
+(1824, 1, NULL, NULL, 'nōluērunt', NULL, 'nolo', 2, 0, 0, 3, 10, '2025-07-28 10:19:06.235010', '2025-07-28 10:19:06.235010'),
+(1825, 0, NULL, NULL, 'nōlueram', NULL, 'nolo', 3, 0, 0, 1, 10, '2025-07-28 10:19:06.237361', '2025-07-28 10:19:06.237361'),
+(1826, 1, NULL, NULL, 'nōluerāmus', NULL, 'nolo', 3, 0, 0, 1, 10, '2025-07-28 10:19:06.240548', '2025-07-28 10:19:06.240548'),
+(1827, 0, NULL, NULL, 'nōluerās', NULL, 'nolo', 3, 0, 0, 2, 10, '2025-07-28 10:19:06.243025', '2025-07-28 10:19:06.243025'),
+(1828, 1, NULL, NULL, 'nōluerātis', NULL, 'nolo', 3, 0, 0, 2, 10, '2025-07-28 10:19:06.246117', '2025-07-28 10:19:06.246117'),
+(1829, 0, NULL, NULL, 'nōluerat', NULL, 'nolo', 3, 0, 0, 3, 10, '2025-07-28 10:19:06.248441', '2025-07-28 10:19:06.248441'),
+(1830, 1, NULL, NULL, 'nōluerant', NULL, 'nolo', 3, 0, 0, 3, 10, '2025-07-28 10:19:06.251530', '2025-07-28 10:19:06.251530'),
+(1831, 0, NULL, NULL, 'nōlam', NULL, 'nolo', 4, 0, 0, 1, 10, '2025-07-28 10:19:06.254666', '2025-07-28 10:19:06.254666'),
+(1832, 1, NULL, NULL, 'nōlēmus', NULL, 'nolo', 4, 0, 0, 1, 10, '2025-07-28 10:19:06.257719', '2025-07-28 10:19:06.257719'),
+(1833, 0, NULL, NULL, 'nōlēs', NULL, 'nolo', 4, 0, 0, 2, 10, '2025-07-28 10:19:06.260133', '2025-07-28 10:19:06.260133'),
+(1834, 1, NULL, NULL, 'nōlētis', NULL, 'nolo', 4, 0, 0, 2, 10, '2025-07-28 10:19:06.262954', '2025-07-28 10:19:06.262954'),
+(1835, 0, NULL, NULL, 'nōlet', NULL, 'nolo', 4, 0, 0, 3, 10, '2025-07-28 10:19:06.265771', '2025-07-28 10:19:06.265771'),
+(1836, 1, NULL, NULL, 'nōlent', NULL, 'nolo', 4, 0, 0, 3, 10, '2025-07-28 10:19:06.268456', '2025-07-28 10:19:06.268456'),
+(1837, 0, NULL, NULL, 'nōluerō', NULL, 'nolo', 5, 0, 0, 1, 10, '2025-07-28 10:19:06.271289', '2025-07-28 10:19:06.271289'),
+(1838, 1, NULL, NULL, 'nōluerimus', NULL, 'nolo', 5, 0, 0, 1, 10, '2025-07-28 10:19:06.274113', '2025-07-28 10:19:06.274113'),
+(1839, 0, NULL, NULL, 'nōlueris', NULL, 'nolo', 5, 0, 0, 2, 10, '2025-07-28 10:19:06.276463', '2025-07-28 10:19:06.276463'),
+(1840, 1, NULL, NULL, 'nōlueritis', NULL, 'nolo', 5, 0, 0, 2, 10, '2025-07-28 10:19:06.279263', '2025-07-28 10:19:06.279263'),
+(1841, 0, NULL, NULL, 'nōluerit', NULL, 'nolo', 5, 0, 0, 3, 10, '2025-07-28 10:19:06.281613', '2025-07-28 10:19:06.281613'),
+(1842, 1, NULL, NULL, 'nōluerint', NULL, 'nolo', 5, 0, 0, 3, 10, '2025-07-28 10:19:06.283984', '2025-07-28 10:19:06.283984'),
+(1843, 0, NULL, NULL, 'nōlim', NULL, 'nolo', 0, 1, 0, 1, 10, '2025-07-28 10:19:06.286279', '2025-07-28 10:19:06.286279'),
+(1844, 1, NULL, NULL, 'nōlīmus', NULL, 'nolo', 0, 1, 0, 1, 10, '2025-07-28 10:19:06.288652', '2025-07-28 10:19:06.288652'),
+(1845, 0, NULL, NULL, 'nōlīs', NULL, 'nolo', 0, 1, 0, 2, 10, '2025-07-28 10:19:06.290978', '2025-07-28 10:19:06.290978'),
+(1846, 1, NULL, NULL, 'nōlītis', NULL, 'nolo', 0, 1, 0, 2, 10, '2025-07-28 10:19:06.293291', '2025-07-28 10:19:06.293291'),
+(1847, 0, NULL, NULL, 'nōlit', NULL, 'nolo', 0, 1, 0, 3, 10, '2025-07-28 10:19:06.295532', '2025-07-28 10:19:06.295532'),
+(1848, 1, NULL, NULL, 'nōlint', NULL, 'nolo', 0, 1, 0, 3, 10, '2025-07-28 10:19:06.297824', '2025-07-28 10:19:06.297824'),
+(1849, 0, NULL, NULL, 'nōllem', NULL, 'nolo', 1, 1, 0, 1, 10, '2025-07-28 10:19:06.300050', '2025-07-28 10:19:06.300050'),
+(1850, 1, NULL, NULL, 'nōllēmus', NULL, 'nolo', 1, 1, 0, 1, 10, '2025-07-28 10:19:06.302281', '2025-07-28 10:19:06.302281'),
+(1851, 0, NULL, NULL, 'nōllēs', NULL, 'nolo', 1, 1, 0, 2, 10, '2025-07-28 10:19:06.304589', '2025-07-28 10:19:06.304589'),
+(1852, 1, NULL, NULL, 'nōllētis', NULL, 'nolo', 1, 1, 0, 2, 10, '2025-07-28 10:19:06.306910', '2025-07-28 10:19:06.306910'),
+(1853, 0, NULL, NULL, 'nōllet', NULL, 'nolo', 1, 1, 0, 3, 10, '2025-07-28 10:19:06.309240', '2025-07-28 10:19:06.309240'),
+(1854, 1, NULL, NULL, 'nōllent', NULL, 'nolo', 1, 1, 0, 3, 10, '2025-07-28 10:19:06.311499', '2025-07-28 10:19:06.311499'),
+(1855, 0, NULL, NULL, 'nōluerim', NULL, 'nolo', 2, 1, 0, 1, 10, '2025-07-28 10:19:06.313767', '2025-07-28 10:19:06.313767'),
+(1856, 1, NULL, NULL, 'nōluerīmus', NULL, 'nolo', 2, 1, 0, 1, 10, '2025-07-28 10:19:06.316038', '2025-07-28 10:19:06.316038'),
+(1857, 0, NULL, NULL, 'nōluerīs', NULL, 'nolo', 2, 1, 0, 2, 10, '2025-07-28 10:19:06.318274', '2025-07-28 10:19:06.318274'),
+(1858, 1, NULL, NULL, 'nōluerītis', NULL, 'nolo', 2, 1, 0, 2, 10, '2025-07-28 10:19:06.320545', '2025-07-28 10:19:06.320545'),
+(1859, 0, NULL, NULL, 'nōluerit', NULL, 'nolo', 2, 1, 0, 3, 10, '2025-07-28 10:19:06.322808', '2025-07-28 10:19:06.322808'),
+(1860, 1, NULL, NULL, 'nōluerint', NULL, 'nolo', 2, 1, 0, 3, 10, '2025-07-28 10:19:06.325105', '2025-07-28 10:19:06.325105'),
+(1861, 0, NULL, NULL, 'nōluissem', NULL, 'nolo', 3, 1, 0, 1, 10, '2025-07-28 10:19:06.327332', '2025-07-28 10:19:06.327332'),
+(1862, 1, NULL, NULL, 'nōluissēmus', NULL, 'nolo', 3, 1, 0, 1, 10, '2025-07-28 10:19:06.329579', '2025-07-28 10:19:06.329579'),
+(1863, 0, NULL, NULL, 'nōluissēs', NULL, 'nolo', 3, 1, 0, 2, 10, '2025-07-28 10:19:06.331836', '2025-07-28 10:19:06.331836'),
+(1864, 1, NULL, NULL, 'nōluissētis', NULL, 'nolo', 3, 1, 0, 2, 10, '2025-07-28 10:19:06.334083', '2025-07-28 10:19:06.334083'),
+(1865, 0, NULL, NULL, 'nōluisset', NULL, 'nolo', 3, 1, 0, 3, 10, '2025-07-28 10:19:06.336281', '2025-07-28 10:19:06.336281'),
+(1866, 1, NULL, NULL, 'nōluissent', NULL, 'nolo', 3, 1, 0, 3, 10, '2025-07-28 10:19:06.338577', '2025-07-28 10:19:06.338577'),
+(1867, 0, NULL, NULL, 'nōlī', NULL, 'nolo', 0, 2, 0, 2, 10, '2025-07-28 10:19:06.340790', '2025-07-28 10:19:06.340790'),
+(1868, 1, NULL, NULL, 'nōlīte', NULL, 'nolo', 0, 2, 0, 2, 10, '2025-07-28 10:19:06.343091', '2025-07-28 10:19:06.343091'),
+(1869, 0, NULL, NULL, 'nōlitō', NULL, 'nolo', 4, 2, 0, 2, 10, '2025-07-28 10:19:06.345334', '2025-07-28 10:19:06.345334'),
+(1870, 1, NULL, NULL, 'nōlitōte', NULL, 'nolo', 4, 2, 0, 2, 10, '2025-07-28 10:19:06.347588', '2025-07-28 10:19:06.347588'),
+(1871, 0, NULL, NULL, 'nōlitō', NULL, 'nolo', 4, 2, 0, 3, 10, '2025-07-28 10:19:06.349826', '2025-07-28 10:19:06.349826'),
+(1872, 1, NULL, NULL, 'nōluntō', NULL, 'nolo', 4, 2, 0, 3, 10, '2025-07-28 10:19:06.352089', '2025-07-28 10:19:06.352089'),
+(1873, 0, NULL, NULL, 'nōlle', NULL, 'nolo', 0, 6, 0, 1, 10, '2025-07-28 10:19:06.354337', '2025-07-28 10:19:06.354337'),
+(1874, 1, NULL, NULL, NULL, NULL, 'nolo', 0, 6, 0, 1, 10, '2025-07-28 10:19:06.356636', '2025-07-28 10:19:06.356636'),
+(1875, 0, NULL, NULL, 'nōluisse', NULL, 'nolo', 2, 6, 0, 1, 10, '2025-07-28 10:19:06.359018', '2025-07-28 10:19:06.359018'),
+(1876, 1, NULL, NULL, NULL, NULL, 'nolo', 2, 6, 0, 1, 10, '2025-07-28 10:19:06.361380', '2025-07-28 10:19:06.361380'),
+(1877, 0, NULL, NULL, 'nōlēns', NULL, 'nolo', 0, 8, 0, 1, 10, '2025-07-28 10:19:06.363727', '2025-07-28 10:19:06.363727'),
+(1878, 1, NULL, NULL, NULL, NULL, 'nolo', 0, 8, 0, 1, 10, '2025-07-28 10:19:06.366022', '2025-07-28 10:19:06.366022'),
+(1879, 0, NULL, NULL, 'mālō', NULL, 'malo', 0, 0, 0, 1, 11, '2025-07-28 10:19:06.372933', '2025-07-28 10:19:06.372933'),
+(1880, 1, NULL, NULL, 'mālumus', NULL, 'malo', 0, 0, 0, 1, 11, '2025-07-28 10:19:06.375455', '2025-07-28 10:19:06.375455'),
+(1881, 0, NULL, NULL, 'māvīs', NULL, 'malo', 0, 0, 0, 2, 11, '2025-07-28 10:19:06.377763', '2025-07-28 10:19:06.377763'),
+(1882, 1, NULL, NULL, 'māvultis', NULL, 'malo', 0, 0, 0, 2, 11, '2025-07-28 10:19:06.380207', '2025-07-28 10:19:06.380207'),
+(1883, 0, NULL, NULL, 'māvult', NULL, 'malo', 0, 0, 0, 3, 11, '2025-07-28 10:19:06.382592', '2025-07-28 10:19:06.382592'),
+(1884, 1, NULL, NULL, 'mālunt', NULL, 'malo', 0, 0, 0, 3, 11, '2025-07-28 10:19:06.385008', '2025-07-28 10:19:06.385008'),
+(1885, 0, NULL, NULL, 'mālēbam', NULL, 'malo', 1, 0, 0, 1, 11, '2025-07-28 10:19:06.387368', '2025-07-28 10:19:06.387368'),
+(1886, 1, NULL, NULL, 'mālēbāmus', NULL, 'malo', 1, 0, 0, 1, 11, '2025-07-28 10:19:06.389759', '2025-07-28 10:19:06.389759'),
+(1887, 0, NULL, NULL, 'mālēbās', NULL, 'malo', 1, 0, 0, 2, 11, '2025-07-28 10:19:06.392170', '2025-07-28 10:19:06.392170'),
+(1888, 1, NULL, NULL, 'mālēbātis', NULL, 'malo', 1, 0, 0, 2, 11, '2025-07-28 10:19:06.394553', '2025-07-28 10:19:06.394553'),
+(1889, 0, NULL, NULL, 'mālēbat', NULL, 'malo', 1, 0, 0, 3, 11, '2025-07-28 10:19:06.396878', '2025-07-28 10:19:06.396878'),
+(1890, 1, NULL, NULL, 'mālēbant', NULL, 'malo', 1, 0, 0, 3, 11, '2025-07-28 10:19:06.399195', '2025-07-28 10:19:06.399195'),
+(1891, 0, NULL, NULL, 'māluī', NULL, 'malo', 2, 0, 0, 1, 11, '2025-07-28 10:19:06.406880', '2025-07-28 10:19:06.406880'),
+(1892, 1, NULL, NULL, 'māluimus', NULL, 'malo', 2, 0, 0, 1, 11, '2025-07-28 10:19:06.411252', '2025-07-28 10:19:06.411252'),
+(1893, 0, NULL, NULL, 'māluistī', NULL, 'malo', 2, 0, 0, 2, 11, '2025-07-28 10:19:06.414073', '2025-07-28 10:19:06.414073'),
+(1894, 1, NULL, NULL, 'māluistis', NULL, 'malo', 2, 0, 0, 2, 11, '2025-07-28 10:19:06.418522', '2025-07-28 10:19:06.418522'),
+(1895, 0, NULL, NULL, 'māluit', NULL, 'malo', 2, 0, 0, 3, 11, '2025-07-28 10:19:06.420976', '2025-07-28 10:19:06.420976'),
+(1896, 1, NULL, NULL, 'māluērunt', NULL, 'malo', 2, 0, 0, 3, 11, '2025-07-28 10:19:06.425181', '2025-07-28 10:19:06.425181'),
+(1897, 0, NULL, NULL, 'mālueram', NULL, 'malo', 3, 0, 0, 1, 11, '2025-07-28 10:19:06.427582', '2025-07-28 10:19:06.427582'),
+(1898, 1, NULL, NULL, 'māluerāmus', NULL, 'malo', 3, 0, 0, 1, 11, '2025-07-28 10:19:06.432009', '2025-07-28 10:19:06.432009'),
+(1899, 0, NULL, NULL, 'māluerās', NULL, 'malo', 3, 0, 0, 2, 11, '2025-07-28 10:19:06.434411', '2025-07-28 10:19:06.434411'),
+(1900, 1, NULL, NULL, 'māluerātis', NULL, 'malo', 3, 0, 0, 2, 11, '2025-07-28 10:19:06.438321', '2025-07-28 10:19:06.438321'),
+(1901, 0, NULL, NULL, 'māluerat', NULL, 'malo', 3, 0, 0, 3, 11, '2025-07-28 10:19:06.441136', '2025-07-28 10:19:06.441136'),
+(1902, 1, NULL, NULL, 'māluerant', NULL, 'malo', 3, 0, 0, 3, 11, '2025-07-28 10:19:06.443505', '2025-07-28 10:19:06.443505'),
+(1903, 0, NULL, NULL, 'mālam', NULL, 'malo', 4, 0, 0, 1, 11, '2025-07-28 10:19:06.447846', '2025-07-28 10:19:06.447846'),
+(1904, 1, NULL, NULL, 'mālēmus', NULL, 'malo', 4, 0, 0, 1, 11, '2025-07-28 10:19:06.450197', '2025-07-28 10:19:06.450197'),
+(1905, 0, NULL, NULL, 'mālēs', NULL, 'malo', 4, 0, 0, 2, 11, '2025-07-28 10:19:06.454341', '2025-07-28 10:19:06.454341'),
+(1906, 1, NULL, NULL, 'mālētis', NULL, 'malo', 4, 0, 0, 2, 11, '2025-07-28 10:19:06.456728', '2025-07-28 10:19:06.456728'),
+(1907, 0, NULL, NULL, 'mālet', NULL, 'malo', 4, 0, 0, 3, 11, '2025-07-28 10:19:06.461027', '2025-07-28 10:19:06.461027'),
+(1908, 1, NULL, NULL, 'mālent', NULL, 'malo', 4, 0, 0, 3, 11, '2025-07-28 10:19:06.463343', '2025-07-28 10:19:06.463343'),
+(1909, 0, NULL, NULL, 'māluerō', NULL, 'malo', 5, 0, 0, 1, 11, '2025-07-28 10:19:06.467383', '2025-07-28 10:19:06.467383'),
+(1910, 1, NULL, NULL, 'māluerimus', NULL, 'malo', 5, 0, 0, 1, 11, '2025-07-28 10:19:06.469625', '2025-07-28 10:19:06.469625'),
+(1911, 0, NULL, NULL, 'mālueris', NULL, 'malo', 5, 0, 0, 2, 11, '2025-07-28 10:19:06.473406', '2025-07-28 10:19:06.473406'),
+(1912, 1, NULL, NULL, 'mālueritis', NULL, 'malo', 5, 0, 0, 2, 11, '2025-07-28 10:19:06.476135', '2025-07-28 10:19:06.476135'),
+(1913, 0, NULL, NULL, 'māluerit', NULL, 'malo', 5, 0, 0, 3, 11, '2025-07-28 10:19:06.480022', '2025-07-28 10:19:06.480022'),
+(1914, 1, NULL, NULL, 'māluerint', NULL, 'malo', 5, 0, 0, 3, 11, '2025-07-28 10:19:06.482327', '2025-07-28 10:19:06.482327'),
+(1915, 0, NULL, NULL, 'mālim', NULL, 'malo', 0, 1, 0, 1, 11, '2025-07-28 10:19:06.485859', '2025-07-28 10:19:06.485859'),
+(1916, 1, NULL, NULL, 'mālīmus', NULL, 'malo', 0, 1, 0, 1, 11, '2025-07-28 10:19:06.488486', '2025-07-28 10:19:06.488486'),
+(1917, 0, NULL, NULL, 'mālīs', NULL, 'malo', 0, 1, 0, 2, 11, '2025-07-28 10:19:06.492124', '2025-07-28 10:19:06.492124'),
+(1918, 1, NULL, NULL, 'mālītis', NULL, 'malo', 0, 1, 0, 2, 11, '2025-07-28 10:19:06.494685', '2025-07-28 10:19:06.494685'),
+(1919, 0, NULL, NULL, 'mālit', NULL, 'malo', 0, 1, 0, 3, 11, '2025-07-28 10:19:06.497071', '2025-07-28 10:19:06.497071'),
+(1920, 1, NULL, NULL, 'mālint', NULL, 'malo', 0, 1, 0, 3, 11, '2025-07-28 10:19:06.500983', '2025-07-28 10:19:06.500983'),
+(1921, 0, NULL, NULL, 'māllem', NULL, 'malo', 1, 1, 0, 1, 11, '2025-07-28 10:19:06.522260', '2025-07-28 10:19:06.522260'),
+(1922, 1, NULL, NULL, 'māllēmus', NULL, 'malo', 1, 1, 0, 1, 11, '2025-07-28 10:19:06.534643', '2025-07-28 10:19:06.534643'),
+(1923, 0, NULL, NULL, 'māllēs', NULL, 'malo', 1, 1, 0, 2, 11, '2025-07-28 10:19:06.537199', '2025-07-28 10:19:06.537199'),
+(1924, 1, NULL, NULL, 'māllētis', NULL, 'malo', 1, 1, 0, 2, 11, '2025-07-28 10:19:06.540690', '2025-07-28 10:19:06.540690'),
+(1925, 0, NULL, NULL, 'māllet', NULL, 'malo', 1, 1, 0, 3, 11, '2025-07-28 10:19:06.542948', '2025-07-28 10:19:06.542948'),
+(1926, 1, NULL, NULL, 'māllent', NULL, 'malo', 1, 1, 0, 3, 11, '2025-07-28 10:19:06.546128', '2025-07-28 10:19:06.546128'),
+(1927, 0, NULL, NULL, 'māluerim', NULL, 'malo', 2, 1, 0, 1, 11, '2025-07-28 10:19:06.548258', '2025-07-28 10:19:06.548258'),
+(1928, 1, NULL, NULL, 'māluerīmus', NULL, 'malo', 2, 1, 0, 1, 11, '2025-07-28 10:19:06.550366', '2025-07-28 10:19:06.550366'),
+(1929, 0, NULL, NULL, 'māluerīs', NULL, 'malo', 2, 1, 0, 2, 11, '2025-07-28 10:19:06.553439', '2025-07-28 10:19:06.553439'),
+(1930, 1, NULL, NULL, 'māluerītis', NULL, 'malo', 2, 1, 0, 2, 11, '2025-07-28 10:19:06.555538', '2025-07-28 10:19:06.555538'),
+(1931, 0, NULL, NULL, 'māluerit', NULL, 'malo', 2, 1, 0, 3, 11, '2025-07-28 10:19:06.558361', '2025-07-28 10:19:06.558361'),
+(1932, 1, NULL, NULL, 'māluerint', NULL, 'malo', 2, 1, 0, 3, 11, '2025-07-28 10:19:06.560571', '2025-07-28 10:19:06.560571'),
+(1933, 0, NULL, NULL, 'māluissem', NULL, 'malo', 3, 1, 0, 1, 11, '2025-07-28 10:19:06.562761', '2025-07-28 10:19:06.562761'),
+(1934, 1, NULL, NULL, 'māluissēmus', NULL, 'malo', 3, 1, 0, 1, 11, '2025-07-28 10:19:06.565522', '2025-07-28 10:19:06.565522'),
+(1935, 0, NULL, NULL, 'māluissēs', NULL, 'malo', 3, 1, 0, 2, 11, '2025-07-28 10:19:06.567652', '2025-07-28 10:19:06.567652'),
+(1936, 1, NULL, NULL, 'māluissētis', NULL, 'malo', 3, 1, 0, 2, 11, '2025-07-28 10:19:06.570568', '2025-07-28 10:19:06.570568'),
+(1937, 0, NULL, NULL, 'māluisset', NULL, 'malo', 3, 1, 0, 3, 11, '2025-07-28 10:19:06.572777', '2025-07-28 10:19:06.572777'),
+(1938, 1, NULL, NULL, 'māluissent', NULL, 'malo', 3, 1, 0, 3, 11, '2025-07-28 10:19:06.575000', '2025-07-28 10:19:06.575000'),
+(1939, 0, NULL, NULL, 'mālle', NULL, 'malo', 0, 6, 0, 1, 11, '2025-07-28 10:19:06.577670', '2025-07-28 10:19:06.577670'),
+(1940, 1, NULL, NULL, NULL, NULL, 'malo', 0, 6, 0, 1, 11, '2025-07-28 10:19:06.579821', '2025-07-28 10:19:06.579821'),
+(1941, 0, NULL, NULL, 'māluisse', NULL, 'malo', 2, 6, 0, 1, 11, '2025-07-28 10:19:06.582006', '2025-07-28 10:19:06.582006'),
+(1942, 1, NULL, NULL, NULL, NULL, 'malo', 2, 6, 0, 1, 11, '2025-07-28 10:19:06.584500', '2025-07-28 10:19:06.584500'),
+(1943, 0, NULL, NULL, 'mālēns', NULL, 'malo', 0, 8, 0, 1, 11, '2025-07-28 10:19:06.586736', '2025-07-28 10:19:06.586736'),
+(1944, 1, NULL, NULL, NULL, NULL, 'malo', 0, 8, 0, 1, 11, '2025-07-28 10:19:06.589781', '2025-07-28 10:19:06.589781'),
+(1945, 0, NULL, NULL, 'ferō', NULL, 'fero', 0, 0, 0, 1, 12, '2025-07-28 10:19:06.595910', '2025-07-28 10:19:06.595910'),
+(1946, 1, NULL, NULL, 'ferimus', NULL, 'fero', 0, 0, 0, 1, 12, '2025-07-28 10:19:06.598154', '2025-07-28 10:19:06.598154'),
+(1947, 0, NULL, NULL, 'fers', NULL, 'fero', 0, 0, 0, 2, 12, '2025-07-28 10:19:06.600378', '2025-07-28 10:19:06.600378'),
+(1948, 1, NULL, NULL, 'fertis', NULL, 'fero', 0, 0, 0, 2, 12, '2025-07-28 10:19:06.602596', '2025-07-28 10:19:06.602596'),
+(1949, 0, NULL, NULL, 'fert', NULL, 'fero', 0, 0, 0, 3, 12, '2025-07-28 10:19:06.604955', '2025-07-28 10:19:06.604955'),
+(1950, 1, NULL, NULL, 'ferunt', NULL, 'fero', 0, 0, 0, 3, 12, '2025-07-28 10:19:06.607185', '2025-07-28 10:19:06.607185'),
+(1951, 0, NULL, NULL, 'ferēbam', NULL, 'fero', 1, 0, 0, 1, 12, '2025-07-28 10:19:06.609444', '2025-07-28 10:19:06.609444'),
+(1952, 1, NULL, NULL, 'ferēbāmus', NULL, 'fero', 1, 0, 0, 1, 12, '2025-07-28 10:19:06.611680', '2025-07-28 10:19:06.611680'),
+(1953, 0, NULL, NULL, 'ferēbās', NULL, 'fero', 1, 0, 0, 2, 12, '2025-07-28 10:19:06.613960', '2025-07-28 10:19:06.613960'),
+(1954, 1, NULL, NULL, 'ferēbātis', NULL, 'fero', 1, 0, 0, 2, 12, '2025-07-28 10:19:06.616185', '2025-07-28 10:19:06.616185'),
+(1955, 0, NULL, NULL, 'ferēbat', NULL, 'fero', 1, 0, 0, 3, 12, '2025-07-28 10:19:06.618409', '2025-07-28 10:19:06.618409'),
+(1956, 1, NULL, NULL, 'ferēbant', NULL, 'fero', 1, 0, 0, 3, 12, '2025-07-28 10:19:06.620751', '2025-07-28 10:19:06.620751'),
+(1957, 0, NULL, NULL, 'tulī', NULL, 'fero', 2, 0, 0, 1, 12, '2025-07-28 10:19:06.622973', '2025-07-28 10:19:06.622973'),
+(1958, 1, NULL, NULL, 'tulimus', NULL, 'fero', 2, 0, 0, 1, 12, '2025-07-28 10:19:06.625232', '2025-07-28 10:19:06.625232'),
+(1959, 0, NULL, NULL, 'tulistī', NULL, 'fero', 2, 0, 0, 2, 12, '2025-07-28 10:19:06.627526', '2025-07-28 10:19:06.627526'),
+(1960, 1, NULL, NULL, 'tulistis', NULL, 'fero', 2, 0, 0, 2, 12, '2025-07-28 10:19:06.629811', '2025-07-28 10:19:06.629811'),
+(1961, 0, NULL, NULL, 'tulit', NULL, 'fero', 2, 0, 0, 3, 12, '2025-07-28 10:19:06.632142', '2025-07-28 10:19:06.632142'),
+(1962, 1, NULL, NULL, 'tulērunt', NULL, 'fero', 2, 0, 0, 3, 12, '2025-07-28 10:19:06.634408', '2025-07-28 10:19:06.634408'),
+(1963, 0, NULL, NULL, 'tuleram', NULL, 'fero', 3, 0, 0, 1, 12, '2025-07-28 10:19:06.636655', '2025-07-28 10:19:06.636655'),
+(1964, 1, NULL, NULL, 'tulerāmus', NULL, 'fero', 3, 0, 0, 1, 12, '2025-07-28 10:19:06.638968', '2025-07-28 10:19:06.638968'),
+(1965, 0, NULL, NULL, 'tulerās', NULL, 'fero', 3, 0, 0, 2, 12, '2025-07-28 10:19:06.641243', '2025-07-28 10:19:06.641243'),
+(1966, 1, NULL, NULL, 'tulerātis', NULL, 'fero', 3, 0, 0, 2, 12, '2025-07-28 10:19:06.643537', '2025-07-28 10:19:06.643537'),
+(1967, 0, NULL, NULL, 'tulerat', NULL, 'fero', 3, 0, 0, 3, 12, '2025-07-28 10:19:06.645799', '2025-07-28 10:19:06.645799'),
+(1968, 1, NULL, NULL, 'tulerant', NULL, 'fero', 3, 0, 0, 3, 12, '2025-07-28 10:19:06.648082', '2025-07-28 10:19:06.648082'),
+(1969, 0, NULL, NULL, 'feram', NULL, 'fero', 4, 0, 0, 1, 12, '2025-07-28 10:19:06.650360', '2025-07-28 10:19:06.650360'),
+(1970, 1, NULL, NULL, 'ferēmus', NULL, 'fero', 4, 0, 0, 1, 12, '2025-07-28 10:19:06.652615', '2025-07-28 10:19:06.652615'),
+(1971, 0, NULL, NULL, 'ferēs', NULL, 'fero', 4, 0, 0, 2, 12, '2025-07-28 10:19:06.654923', '2025-07-28 10:19:06.654923'),
+(1972, 1, NULL, NULL, 'ferētis', NULL, 'fero', 4, 0, 0, 2, 12, '2025-07-28 10:19:06.657176', '2025-07-28 10:19:06.657176'),
+(1973, 0, NULL, NULL, 'feret', NULL, 'fero', 4, 0, 0, 3, 12, '2025-07-28 10:19:06.659533', '2025-07-28 10:19:06.659533'),
+(1974, 1, NULL, NULL, 'ferent', NULL, 'fero', 4, 0, 0, 3, 12, '2025-07-28 10:19:06.661853', '2025-07-28 10:19:06.661853'),
+(1975, 0, NULL, NULL, 'tulerō', NULL, 'fero', 5, 0, 0, 1, 12, '2025-07-28 10:19:06.664173', '2025-07-28 10:19:06.664173'),
+(1976, 1, NULL, NULL, 'tulerimus', NULL, 'fero', 5, 0, 0, 1, 12, '2025-07-28 10:19:06.671277', '2025-07-28 10:19:06.671277'),
+(1977, 0, NULL, NULL, 'tuleris', NULL, 'fero', 5, 0, 0, 2, 12, '2025-07-28 10:19:06.675263', '2025-07-28 10:19:06.675263'),
+(1978, 1, NULL, NULL, 'tuleritis', NULL, 'fero', 5, 0, 0, 2, 12, '2025-07-28 10:19:06.678109', '2025-07-28 10:19:06.678109'),
+(1979, 0, NULL, NULL, 'tulerit', NULL, 'fero', 5, 0, 0, 3, 12, '2025-07-28 10:19:06.682358', '2025-07-28 10:19:06.682358'),
+(1980, 1, NULL, NULL, 'tulerint', NULL, 'fero', 5, 0, 0, 3, 12, '2025-07-28 10:19:06.684732', '2025-07-28 10:19:06.684732'),
+(1981, 0, NULL, NULL, 'feror', NULL, 'fero', 0, 0, 1, 1, 12, '2025-07-28 10:19:06.688962', '2025-07-28 10:19:06.688962'),
+(1982, 1, NULL, NULL, 'ferimur', NULL, 'fero', 0, 0, 1, 1, 12, '2025-07-28 10:19:06.691357', '2025-07-28 10:19:06.691357'),
+(1983, 0, NULL, NULL, 'ferris', NULL, 'fero', 0, 0, 1, 2, 12, '2025-07-28 10:19:06.695721', '2025-07-28 10:19:06.695721'),
+(1984, 1, NULL, NULL, 'feriminī', NULL, 'fero', 0, 0, 1, 2, 12, '2025-07-28 10:19:06.698104', '2025-07-28 10:19:06.698104'),
+(1985, 0, NULL, NULL, 'fertur', NULL, 'fero', 0, 0, 1, 3, 12, '2025-07-28 10:19:06.702158', '2025-07-28 10:19:06.702158'),
+(1986, 1, NULL, NULL, 'feruntur', NULL, 'fero', 0, 0, 1, 3, 12, '2025-07-28 10:19:06.704520', '2025-07-28 10:19:06.704520'),
+(1987, 0, NULL, NULL, 'ferēbar', NULL, 'fero', 1, 0, 1, 1, 12, '2025-07-28 10:19:06.708765', '2025-07-28 10:19:06.708765'),
+(1988, 1, NULL, NULL, 'ferēbāmur', NULL, 'fero', 1, 0, 1, 1, 12, '2025-07-28 10:19:06.711189', '2025-07-28 10:19:06.711189'),
+(1989, 0, NULL, NULL, 'ferēbāris', NULL, 'fero', 1, 0, 1, 2, 12, '2025-07-28 10:19:06.715512', '2025-07-28 10:19:06.715512'),
+(1990, 1, NULL, NULL, 'ferēbāminī', NULL, 'fero', 1, 0, 1, 2, 12, '2025-07-28 10:19:06.717857', '2025-07-28 10:19:06.717857'),
+(1991, 0, NULL, NULL, 'ferēbātur', NULL, 'fero', 1, 0, 1, 3, 12, '2025-07-28 10:19:06.720179', '2025-07-28 10:19:06.720179'),
+(1992, 1, NULL, NULL, 'ferēbantur', NULL, 'fero', 1, 0, 1, 3, 12, '2025-07-28 10:19:06.724337', '2025-07-28 10:19:06.724337'),
+(1993, 0, NULL, NULL, 'ferar', NULL, 'fero', 4, 0, 1, 1, 12, '2025-07-28 10:19:06.726728', '2025-07-28 10:19:06.726728'),
+(1994, 1, NULL, NULL, 'ferēmur', NULL, 'fero', 4, 0, 1, 1, 12, '2025-07-28 10:19:06.730808', '2025-07-28 10:19:06.730808'),
+(1995, 0, NULL, NULL, 'ferēris', NULL, 'fero', 4, 0, 1, 2, 12, '2025-07-28 10:19:06.733102', '2025-07-28 10:19:06.733102'),
+(1996, 1, NULL, NULL, 'ferēminī', NULL, 'fero', 4, 0, 1, 2, 12, '2025-07-28 10:19:06.737124', '2025-07-28 10:19:06.737124'),
+(1997, 0, NULL, NULL, 'ferētur', NULL, 'fero', 4, 0, 1, 3, 12, '2025-07-28 10:19:06.739520', '2025-07-28 10:19:06.739520'),
+(1998, 1, NULL, NULL, 'ferentur', NULL, 'fero', 4, 0, 1, 3, 12, '2025-07-28 10:19:06.743640', '2025-07-28 10:19:06.743640'),
+(1999, 0, NULL, NULL, 'sum', NULL, 'fero', 2, 0, 1, 1, 12, '2025-07-28 10:19:06.746030', '2025-07-28 10:19:06.746030'),
+(2000, 1, NULL, NULL, 'sumus', NULL, 'fero', 2, 0, 1, 1, 12, '2025-07-28 10:19:06.750037', '2025-07-28 10:19:06.750037'),
+(2001, 0, NULL, NULL, 'es', NULL, 'fero', 2, 0, 1, 2, 12, '2025-07-28 10:19:06.752337', '2025-07-28 10:19:06.752337'),
+(2002, 1, NULL, NULL, 'estis', NULL, 'fero', 2, 0, 1, 2, 12, '2025-07-28 10:19:06.756399', '2025-07-28 10:19:06.756399'),
+(2003, 0, NULL, NULL, 'est', NULL, 'fero', 2, 0, 1, 3, 12, '2025-07-28 10:19:06.758791', '2025-07-28 10:19:06.758791'),
+(2004, 1, NULL, NULL, 'sunt', NULL, 'fero', 2, 0, 1, 3, 12, '2025-07-28 10:19:06.762937', '2025-07-28 10:19:06.762937'),
+(2005, 0, NULL, NULL, 'eram', NULL, 'fero', 3, 0, 1, 1, 12, '2025-07-28 10:19:06.765311', '2025-07-28 10:19:06.765311'),
+(2006, 1, NULL, NULL, 'erāmus', NULL, 'fero', 3, 0, 1, 1, 12, '2025-07-28 10:19:06.769179', '2025-07-28 10:19:06.769179'),
+(2007, 0, NULL, NULL, 'erās', NULL, 'fero', 3, 0, 1, 2, 12, '2025-07-28 10:19:06.771573', '2025-07-28 10:19:06.771573'),
+(2008, 1, NULL, NULL, 'erātis', NULL, 'fero', 3, 0, 1, 2, 12, '2025-07-28 10:19:06.774899', '2025-07-28 10:19:06.774899'),
+(2009, 0, NULL, NULL, 'erat', NULL, 'fero', 3, 0, 1, 3, 12, '2025-07-28 10:19:06.777219', '2025-07-28 10:19:06.777219'),
+(2010, 1, NULL, NULL, 'erant', NULL, 'fero', 3, 0, 1, 3, 12, '2025-07-28 10:19:06.779532', '2025-07-28 10:19:06.779532'),
+(2011, 0, NULL, NULL, 'erō', NULL, 'fero', 5, 0, 1, 1, 12, '2025-07-28 10:19:06.782840', '2025-07-28 10:19:06.782840'),
+(2012, 1, NULL, NULL, 'erimus', NULL, 'fero', 5, 0, 1, 1, 12, '2025-07-28 10:19:06.785172', '2025-07-28 10:19:06.785172'),
+(2013, 0, NULL, NULL, 'eris', NULL, 'fero', 5, 0, 1, 2, 12, '2025-07-28 10:19:06.788570', '2025-07-28 10:19:06.788570'),
+(2014, 1, NULL, NULL, 'eritis', NULL, 'fero', 5, 0, 1, 2, 12, '2025-07-28 10:19:06.790854', '2025-07-28 10:19:06.790854'),
+(2015, 0, NULL, NULL, 'erit', NULL, 'fero', 5, 0, 1, 3, 12, '2025-07-28 10:19:06.793230', '2025-07-28 10:19:06.793230'),
+(2016, 1, NULL, NULL, 'erunt', NULL, 'fero', 5, 0, 1, 3, 12, '2025-07-28 10:19:06.796245', '2025-07-28 10:19:06.796245'),
+(2017, 0, NULL, NULL, 'feram', NULL, 'fero', 0, 1, 0, 1, 12, '2025-07-28 10:19:06.798567', '2025-07-28 10:19:06.798567'),
+(2018, 1, NULL, NULL, 'ferāmus', NULL, 'fero', 0, 1, 0, 1, 12, '2025-07-28 10:19:06.801604', '2025-07-28 10:19:06.801604'),
+(2019, 0, NULL, NULL, 'ferās', NULL, 'fero', 0, 1, 0, 2, 12, '2025-07-28 10:19:06.803937', '2025-07-28 10:19:06.803937'),
+(2020, 1, NULL, NULL, 'ferātis', NULL, 'fero', 0, 1, 0, 2, 12, '2025-07-28 10:19:06.806261', '2025-07-28 10:19:06.806261'),
+(2021, 0, NULL, NULL, 'ferat', NULL, 'fero', 0, 1, 0, 3, 12, '2025-07-28 10:19:06.809356', '2025-07-28 10:19:06.809356'),
+(2022, 1, NULL, NULL, 'ferant', NULL, 'fero', 0, 1, 0, 3, 12, '2025-07-28 10:19:06.811686', '2025-07-28 10:19:06.811686'),
+(2023, 0, NULL, NULL, 'ferrem', NULL, 'fero', 1, 1, 0, 1, 12, '2025-07-28 10:19:06.814776', '2025-07-28 10:19:06.814776'),
+(2024, 1, NULL, NULL, 'ferrēmus', NULL, 'fero', 1, 1, 0, 1, 12, '2025-07-28 10:19:06.817044', '2025-07-28 10:19:06.817044'),
+(2025, 0, NULL, NULL, 'ferrēs', NULL, 'fero', 1, 1, 0, 2, 12, '2025-07-28 10:19:06.819313', '2025-07-28 10:19:06.819313'),
+(2026, 1, NULL, NULL, 'ferrētis', NULL, 'fero', 1, 1, 0, 2, 12, '2025-07-28 10:19:06.823713', '2025-07-28 10:19:06.823713'),
+(2027, 0, NULL, NULL, 'ferret', NULL, 'fero', 1, 1, 0, 3, 12, '2025-07-28 10:19:06.826086', '2025-07-28 10:19:06.826086'),
+(2028, 1, NULL, NULL, 'ferrent', NULL, 'fero', 1, 1, 0, 3, 12, '2025-07-28 10:19:06.828367', '2025-07-28 10:19:06.828367'),
+(2029, 0, NULL, NULL, 'tulerim', NULL, 'fero', 2, 1, 0, 1, 12, '2025-07-28 10:19:06.830670', '2025-07-28 10:19:06.830670'),
+(2030, 1, NULL, NULL, 'tulerīmus', NULL, 'fero', 2, 1, 0, 1, 12, '2025-07-28 10:19:06.833008', '2025-07-28 10:19:06.833008'),
+(2031, 0, NULL, NULL, 'tulerīs', NULL, 'fero', 2, 1, 0, 2, 12, '2025-07-28 10:19:06.835270', '2025-07-28 10:19:06.835270'),
+(2032, 1, NULL, NULL, 'tulerītis', NULL, 'fero', 2, 1, 0, 2, 12, '2025-07-28 10:19:06.837602', '2025-07-28 10:19:06.837602'),
+(2033, 0, NULL, NULL, 'tulerit', NULL, 'fero', 2, 1, 0, 3, 12, '2025-07-28 10:19:06.839951', '2025-07-28 10:19:06.839951'),
+(2034, 1, NULL, NULL, 'tulerint', NULL, 'fero', 2, 1, 0, 3, 12, '2025-07-28 10:19:06.842211', '2025-07-28 10:19:06.842211'),
+(2035, 0, NULL, NULL, 'tulissem', NULL, 'fero', 3, 1, 0, 1, 12, '2025-07-28 10:19:06.844568', '2025-07-28 10:19:06.844568'),
+(2036, 1, NULL, NULL, 'tulissēmus', NULL, 'fero', 3, 1, 0, 1, 12, '2025-07-28 10:19:06.846895', '2025-07-28 10:19:06.846895'),
+(2037, 0, NULL, NULL, 'tulissēs', NULL, 'fero', 3, 1, 0, 2, 12, '2025-07-28 10:19:06.849134', '2025-07-28 10:19:06.849134'),
+(2038, 1, NULL, NULL, 'tulissētis', NULL, 'fero', 3, 1, 0, 2, 12, '2025-07-28 10:19:06.851364', '2025-07-28 10:19:06.851364'),
+(2039, 0, NULL, NULL, 'tulisset', NULL, 'fero', 3, 1, 0, 3, 12, '2025-07-28 10:19:06.853607', '2025-07-28 10:19:06.853607'),
+(2040, 1, NULL, NULL, 'tulissent', NULL, 'fero', 3, 1, 0, 3, 12, '2025-07-28 10:19:06.855877', '2025-07-28 10:19:06.855877'),
+(2041, 0, NULL, NULL, 'ferar', NULL, 'fero', 0, 1, 1, 1, 12, '2025-07-28 10:19:06.858097', '2025-07-28 10:19:06.858097'),
+(2042, 1, NULL, NULL, 'ferāmur', NULL, 'fero', 0, 1, 1, 1, 12, '2025-07-28 10:19:06.860417', '2025-07-28 10:19:06.860417'),
+(2043, 0, NULL, NULL, 'ferāris', NULL, 'fero', 0, 1, 1, 2, 12, '2025-07-28 10:19:06.862679', '2025-07-28 10:19:06.862679'),
+(2044, 1, NULL, NULL, 'ferāminī', NULL, 'fero', 0, 1, 1, 2, 12, '2025-07-28 10:19:06.864975', '2025-07-28 10:19:06.864975'),
+(2045, 0, NULL, NULL, 'ferātur', NULL, 'fero', 0, 1, 1, 3, 12, '2025-07-28 10:19:06.867201', '2025-07-28 10:19:06.867201'),
+(2046, 1, NULL, NULL, 'ferantur', NULL, 'fero', 0, 1, 1, 3, 12, '2025-07-28 10:19:06.869464', '2025-07-28 10:19:06.869464'),
+(2047, 0, NULL, NULL, 'ferrer', NULL, 'fero', 1, 1, 1, 1, 12, '2025-07-28 10:19:06.871802', '2025-07-28 10:19:06.871802'),
+(2048, 1, NULL, NULL, 'ferrēmur', NULL, 'fero', 1, 1, 1, 1, 12, '2025-07-28 10:19:06.874089', '2025-07-28 10:19:06.874089'),
+(2049, 0, NULL, NULL, 'ferrēris', NULL, 'fero', 1, 1, 1, 2, 12, '2025-07-28 10:19:06.876353', '2025-07-28 10:19:06.876353'),
+(2050, 1, NULL, NULL, 'ferrēminī', NULL, 'fero', 1, 1, 1, 2, 12, '2025-07-28 10:19:06.878620', '2025-07-28 10:19:06.878620'),
+(2051, 0, NULL, NULL, 'ferrētur', NULL, 'fero', 1, 1, 1, 3, 12, '2025-07-28 10:19:06.880896', '2025-07-28 10:19:06.880896'),
+(2052, 1, NULL, NULL, 'ferrentur', NULL, 'fero', 1, 1, 1, 3, 12, '2025-07-28 10:19:06.883085', '2025-07-28 10:19:06.883085'),
+(2053, 0, NULL, NULL, 'sim', NULL, 'fero', 2, 1, 1, 1, 12, '2025-07-28 10:19:06.885343', '2025-07-28 10:19:06.885343'),
+(2054, 1, NULL, NULL, 'sīmus', NULL, 'fero', 2, 1, 1, 1, 12, '2025-07-28 10:19:06.887678', '2025-07-28 10:19:06.887678'),
+(2055, 0, NULL, NULL, 'sīs', NULL, 'fero', 2, 1, 1, 2, 12, '2025-07-28 10:19:06.889971', '2025-07-28 10:19:06.889971'),
+(2056, 1, NULL, NULL, 'sītis', NULL, 'fero', 2, 1, 1, 2, 12, '2025-07-28 10:19:06.897428', '2025-07-28 10:19:06.897428'),
+(2057, 0, NULL, NULL, 'sit', NULL, 'fero', 2, 1, 1, 3, 12, '2025-07-28 10:19:06.901448', '2025-07-28 10:19:06.901448'),
+(2058, 1, NULL, NULL, 'sint', NULL, 'fero', 2, 1, 1, 3, 12, '2025-07-28 10:19:06.903719', '2025-07-28 10:19:06.903719'),
+(2059, 0, NULL, NULL, 'essem', NULL, 'fero', 3, 1, 1, 1, 12, '2025-07-28 10:19:06.907733', '2025-07-28 10:19:06.907733'),
+(2060, 1, NULL, NULL, 'essēmus', NULL, 'fero', 3, 1, 1, 1, 12, '2025-07-28 10:19:06.910126', '2025-07-28 10:19:06.910126'),
+(2061, 0, NULL, NULL, 'essēs', NULL, 'fero', 3, 1, 1, 2, 12, '2025-07-28 10:19:06.913979', '2025-07-28 10:19:06.913979'),
+(2062, 1, NULL, NULL, 'essētis', NULL, 'fero', 3, 1, 1, 2, 12, '2025-07-28 10:19:06.916236', '2025-07-28 10:19:06.916236'),
+(2063, 0, NULL, NULL, 'esset', NULL, 'fero', 3, 1, 1, 3, 12, '2025-07-28 10:19:06.920353', '2025-07-28 10:19:06.920353'),
+(2064, 1, NULL, NULL, 'essent', NULL, 'fero', 3, 1, 1, 3, 12, '2025-07-28 10:19:06.922750', '2025-07-28 10:19:06.922750'),
+(2065, 0, NULL, NULL, 'fer', NULL, 'fero', 0, 2, 0, 2, 12, '2025-07-28 10:19:06.926869', '2025-07-28 10:19:06.926869'),
+(2066, 1, NULL, NULL, 'ferte', NULL, 'fero', 0, 2, 0, 2, 12, '2025-07-28 10:19:06.929174', '2025-07-28 10:19:06.929174'),
+(2067, 0, NULL, NULL, 'fertō', NULL, 'fero', 4, 2, 0, 2, 12, '2025-07-28 10:19:06.933017', '2025-07-28 10:19:06.933017'),
+(2068, 1, NULL, NULL, 'fertōte', NULL, 'fero', 4, 2, 0, 2, 12, '2025-07-28 10:19:06.935408', '2025-07-28 10:19:06.935408'),
+(2069, 0, NULL, NULL, 'fertō', NULL, 'fero', 4, 2, 0, 3, 12, '2025-07-28 10:19:06.939205', '2025-07-28 10:19:06.939205'),
+(2070, 1, NULL, NULL, 'feruntō', NULL, 'fero', 4, 2, 0, 3, 12, '2025-07-28 10:19:06.941948', '2025-07-28 10:19:06.941948'),
+(2071, 0, NULL, NULL, 'ferre', NULL, 'fero', 0, 2, 1, 2, 12, '2025-07-28 10:19:06.944277', '2025-07-28 10:19:06.944277'),
+(2072, 1, NULL, NULL, 'feriminī', NULL, 'fero', 0, 2, 1, 2, 12, '2025-07-28 10:19:06.948399', '2025-07-28 10:19:06.948399'),
+(2073, 0, NULL, NULL, 'fertor', NULL, 'fero', 4, 2, 1, 2, 12, '2025-07-28 10:19:06.950633', '2025-07-28 10:19:06.950633'),
+(2074, 1, NULL, NULL, NULL, NULL, 'fero', 4, 2, 1, 2, 12, '2025-07-28 10:19:06.954798', '2025-07-28 10:19:06.954798'),
+(2075, 0, NULL, NULL, 'fertor', NULL, 'fero', 4, 2, 1, 3, 12, '2025-07-28 10:19:06.957074', '2025-07-28 10:19:06.957074'),
+(2076, 1, NULL, NULL, 'feruntor', NULL, 'fero', 4, 2, 1, 3, 12, '2025-07-28 10:19:06.961417', '2025-07-28 10:19:06.961417'),
+(2077, 0, NULL, NULL, 'ferre', NULL, 'fero', 0, 6, 0, 1, 12, '2025-07-28 10:19:06.963675', '2025-07-28 10:19:06.963675'),
+(2078, 1, NULL, NULL, NULL, NULL, 'fero', 0, 6, 0, 1, 12, '2025-07-28 10:19:06.967729', '2025-07-28 10:19:06.967729'),
+(2079, 0, NULL, NULL, 'tulisse', NULL, 'fero', 2, 6, 0, 1, 12, '2025-07-28 10:19:06.969943', '2025-07-28 10:19:06.969943'),
+(2080, 1, NULL, NULL, NULL, NULL, 'fero', 2, 6, 0, 1, 12, '2025-07-28 10:19:06.974072', '2025-07-28 10:19:06.974072'),
+(2081, 0, NULL, NULL, 'esse', NULL, 'fero', 4, 6, 0, 1, 12, '2025-07-28 10:19:06.976386', '2025-07-28 10:19:06.976386'),
+(2082, 1, NULL, NULL, NULL, NULL, 'fero', 4, 6, 0, 1, 12, '2025-07-28 10:19:06.980395', '2025-07-28 10:19:06.980395'),
+(2083, 0, NULL, NULL, 'ferrī', NULL, 'fero', 0, 6, 1, 1, 12, '2025-07-28 10:19:06.982649', '2025-07-28 10:19:06.982649'),
+(2084, 1, NULL, NULL, NULL, NULL, 'fero', 0, 6, 1, 1, 12, '2025-07-28 10:19:06.986107', '2025-07-28 10:19:06.986107'),
+(2085, 0, NULL, NULL, 'esse', NULL, 'fero', 2, 6, 1, 1, 12, '2025-07-28 10:19:06.988490', '2025-07-28 10:19:06.988490'),
+(2086, 1, NULL, NULL, NULL, NULL, 'fero', 2, 6, 1, 1, 12, '2025-07-28 10:19:06.992036', '2025-07-28 10:19:06.992036'),
+(2087, 0, NULL, NULL, 'īrī', NULL, 'fero', 4, 6, 1, 1, 12, '2025-07-28 10:19:06.994386', '2025-07-28 10:19:06.994386'),
+(2088, 1, NULL, NULL, NULL, NULL, 'fero', 4, 6, 1, 1, 12, '2025-07-28 10:19:06.997746', '2025-07-28 10:19:06.997746'),
+(2089, 0, NULL, NULL, 'ferēns', NULL, 'fero', 0, 8, 0, 1, 12, '2025-07-28 10:19:07.000100', '2025-07-28 10:19:07.000100'),
+(2090, 1, NULL, NULL, NULL, NULL, 'fero', 0, 8, 0, 1, 12, '2025-07-28 10:19:07.002404', '2025-07-28 10:19:07.002404'),
+(2091, 0, NULL, NULL, 'ūrus', NULL, 'fero', 4, 8, 0, 1, 12, '2025-07-28 10:19:07.005964', '2025-07-28 10:19:07.005964'),
+(2092, 1, NULL, NULL, NULL, NULL, 'fero', 4, 8, 0, 1, 12, '2025-07-28 10:19:07.008311', '2025-07-28 10:19:07.008311'),
+(2093, 0, NULL, NULL, 'us', NULL, 'fero', 2, 8, 1, 1, 12, '2025-07-28 10:19:07.011788', '2025-07-28 10:19:07.011788'),
+(2094, 1, NULL, NULL, NULL, NULL, 'fero', 2, 8, 1, 1, 12, '2025-07-28 10:19:07.014095', '2025-07-28 10:19:07.014095'),
+(2095, 0, NULL, NULL, 'ferendus', NULL, 'fero', 4, 8, 1, 1, 12, '2025-07-28 10:19:07.016454', '2025-07-28 10:19:07.016454'),
+(2096, 1, NULL, NULL, NULL, NULL, 'fero', 4, 8, 1, 1, 12, '2025-07-28 10:19:07.019415', '2025-07-28 10:19:07.019415'),
+(2097, 0, NULL, NULL, 'faciō', NULL, 'facio', 0, 0, 0, 1, 13, '2025-07-28 10:19:07.026616', '2025-07-28 10:19:07.026616'),
+(2098, 1, NULL, NULL, 'facimus', NULL, 'facio', 0, 0, 0, 1, 13, '2025-07-28 10:19:07.028997', '2025-07-28 10:19:07.028997'),
+(2099, 0, NULL, NULL, 'facis', NULL, 'facio', 0, 0, 0, 2, 13, '2025-07-28 10:19:07.032050', '2025-07-28 10:19:07.032050'),
+(2100, 1, NULL, NULL, 'facitis', NULL, 'facio', 0, 0, 0, 2, 13, '2025-07-28 10:19:07.034377', '2025-07-28 10:19:07.034377'),
+(2101, 0, NULL, NULL, 'facit', NULL, 'facio', 0, 0, 0, 3, 13, '2025-07-28 10:19:07.036728', '2025-07-28 10:19:07.036728'),
+(2102, 1, NULL, NULL, 'faciunt', NULL, 'facio', 0, 0, 0, 3, 13, '2025-07-28 10:19:07.042103', '2025-07-28 10:19:07.042103'),
+(2103, 0, NULL, NULL, 'faciēbam', NULL, 'facio', 1, 0, 0, 1, 13, '2025-07-28 10:19:07.044570', '2025-07-28 10:19:07.044570'),
+(2104, 1, NULL, NULL, 'faciēbāmus', NULL, 'facio', 1, 0, 0, 1, 13, '2025-07-28 10:19:07.046962', '2025-07-28 10:19:07.046962'),
+(2105, 0, NULL, NULL, 'faciēbās', NULL, 'facio', 1, 0, 0, 2, 13, '2025-07-28 10:19:07.049248', '2025-07-28 10:19:07.049248'),
+(2106, 1, NULL, NULL, 'faciēbātis', NULL, 'facio', 1, 0, 0, 2, 13, '2025-07-28 10:19:07.051515', '2025-07-28 10:19:07.051515'),
+(2107, 0, NULL, NULL, 'faciēbat', NULL, 'facio', 1, 0, 0, 3, 13, '2025-07-28 10:19:07.053824', '2025-07-28 10:19:07.053824'),
+(2108, 1, NULL, NULL, 'faciēbant', NULL, 'facio', 1, 0, 0, 3, 13, '2025-07-28 10:19:07.056225', '2025-07-28 10:19:07.056225'),
+(2109, 0, NULL, NULL, 'fēcī', NULL, 'facio', 2, 0, 0, 1, 13, '2025-07-28 10:19:07.058583', '2025-07-28 10:19:07.058583'),
+(2110, 1, NULL, NULL, 'fēcimus', NULL, 'facio', 2, 0, 0, 1, 13, '2025-07-28 10:19:07.061045', '2025-07-28 10:19:07.061045'),
+(2111, 0, NULL, NULL, 'fēcistī', NULL, 'facio', 2, 0, 0, 2, 13, '2025-07-28 10:19:07.063341', '2025-07-28 10:19:07.063341'),
+(2112, 1, NULL, NULL, 'fēcistis', NULL, 'facio', 2, 0, 0, 2, 13, '2025-07-28 10:19:07.065674', '2025-07-28 10:19:07.065674'),
+(2113, 0, NULL, NULL, 'fēcit', NULL, 'facio', 2, 0, 0, 3, 13, '2025-07-28 10:19:07.067970', '2025-07-28 10:19:07.067970'),
+(2114, 1, NULL, NULL, 'fēcērunt', NULL, 'facio', 2, 0, 0, 3, 13, '2025-07-28 10:19:07.070156', '2025-07-28 10:19:07.070156'),
+(2115, 0, NULL, NULL, 'fēceram', NULL, 'facio', 3, 0, 0, 1, 13, '2025-07-28 10:19:07.072493', '2025-07-28 10:19:07.072493'),
+(2116, 1, NULL, NULL, 'fēcerāmus', NULL, 'facio', 3, 0, 0, 1, 13, '2025-07-28 10:19:07.074831', '2025-07-28 10:19:07.074831'),
+(2117, 0, NULL, NULL, 'fēcerās', NULL, 'facio', 3, 0, 0, 2, 13, '2025-07-28 10:19:07.077467', '2025-07-28 10:19:07.077467'),
+(2118, 1, NULL, NULL, 'fēcerātis', NULL, 'facio', 3, 0, 0, 2, 13, '2025-07-28 10:19:07.079780', '2025-07-28 10:19:07.079780'),
+(2119, 0, NULL, NULL, 'fēcerat', NULL, 'facio', 3, 0, 0, 3, 13, '2025-07-28 10:19:07.082000', '2025-07-28 10:19:07.082000'),
+(2120, 1, NULL, NULL, 'fēcerant', NULL, 'facio', 3, 0, 0, 3, 13, '2025-07-28 10:19:07.084272', '2025-07-28 10:19:07.084272'),
+(2121, 0, NULL, NULL, 'faciam', NULL, 'facio', 4, 0, 0, 1, 13, '2025-07-28 10:19:07.086532', '2025-07-28 10:19:07.086532'),
+(2122, 1, NULL, NULL, 'faciēmus', NULL, 'facio', 4, 0, 0, 1, 13, '2025-07-28 10:19:07.088857', '2025-07-28 10:19:07.088857'),
+(2123, 0, NULL, NULL, 'faciēs', NULL, 'facio', 4, 0, 0, 2, 13, '2025-07-28 10:19:07.091151', '2025-07-28 10:19:07.091151'),
+(2124, 1, NULL, NULL, 'faciētis', NULL, 'facio', 4, 0, 0, 2, 13, '2025-07-28 10:19:07.093498', '2025-07-28 10:19:07.093498'),
+(2125, 0, NULL, NULL, 'faciet', NULL, 'facio', 4, 0, 0, 3, 13, '2025-07-28 10:19:07.095799', '2025-07-28 10:19:07.095799'),
+(2126, 1, NULL, NULL, 'facient', NULL, 'facio', 4, 0, 0, 3, 13, '2025-07-28 10:19:07.098059', '2025-07-28 10:19:07.098059'),
+(2127, 0, NULL, NULL, 'fēcerō', NULL, 'facio', 5, 0, 0, 1, 13, '2025-07-28 10:19:07.100306', '2025-07-28 10:19:07.100306'),
+(2128, 1, NULL, NULL, 'fēcerimus', NULL, 'facio', 5, 0, 0, 1, 13, '2025-07-28 10:19:07.107983', '2025-07-28 10:19:07.107983'),
+(2129, 0, NULL, NULL, 'fēceris', NULL, 'facio', 5, 0, 0, 2, 13, '2025-07-28 10:19:07.111976', '2025-07-28 10:19:07.111976'),
+(2130, 1, NULL, NULL, 'fēceritis', NULL, 'facio', 5, 0, 0, 2, 13, '2025-07-28 10:19:07.114751', '2025-07-28 10:19:07.114751'),
+(2131, 0, NULL, NULL, 'fēcerit', NULL, 'facio', 5, 0, 0, 3, 13, '2025-07-28 10:19:07.118772', '2025-07-28 10:19:07.118772'),
+(2132, 1, NULL, NULL, 'fēcerint', NULL, 'facio', 5, 0, 0, 3, 13, '2025-07-28 10:19:07.121156', '2025-07-28 10:19:07.121156'),
+(2133, 0, NULL, NULL, 'fiō', NULL, 'facio', 0, 0, 1, 1, 13, '2025-07-28 10:19:07.125113', '2025-07-28 10:19:07.125113'),
+(2134, 1, NULL, NULL, 'fīmus', NULL, 'facio', 0, 0, 1, 1, 13, '2025-07-28 10:19:07.127584', '2025-07-28 10:19:07.127584'),
+(2135, 0, NULL, NULL, 'fīs', NULL, 'facio', 0, 0, 1, 2, 13, '2025-07-28 10:19:07.131643', '2025-07-28 10:19:07.131643'),
+(2136, 1, NULL, NULL, 'fītis', NULL, 'facio', 0, 0, 1, 2, 13, '2025-07-28 10:19:07.134028', '2025-07-28 10:19:07.134028'),
+(2137, 0, NULL, NULL, 'fit', NULL, 'facio', 0, 0, 1, 3, 13, '2025-07-28 10:19:07.137920', '2025-07-28 10:19:07.137920'),
+(2138, 1, NULL, NULL, 'fiunt', NULL, 'facio', 0, 0, 1, 3, 13, '2025-07-28 10:19:07.140277', '2025-07-28 10:19:07.140277'),
+(2139, 0, NULL, NULL, 'fiēbam', NULL, 'facio', 1, 0, 1, 1, 13, '2025-07-28 10:19:07.144373', '2025-07-28 10:19:07.144373'),
+(2140, 1, NULL, NULL, 'fiēbāmus', NULL, 'facio', 1, 0, 1, 1, 13, '2025-07-28 10:19:07.146755', '2025-07-28 10:19:07.146755'),
+(2141, 0, NULL, NULL, 'fiēbās', NULL, 'facio', 1, 0, 1, 2, 13, '2025-07-28 10:19:07.150727', '2025-07-28 10:19:07.150727'),
+(2142, 1, NULL, NULL, 'fiēbātis', NULL, 'facio', 1, 0, 1, 2, 13, '2025-07-28 10:19:07.153063', '2025-07-28 10:19:07.153063'),
+(2143, 0, NULL, NULL, 'fiēbat', NULL, 'facio', 1, 0, 1, 3, 13, '2025-07-28 10:19:07.155489', '2025-07-28 10:19:07.155489'),
+(2144, 1, NULL, NULL, 'fiēbant', NULL, 'facio', 1, 0, 1, 3, 13, '2025-07-28 10:19:07.159455', '2025-07-28 10:19:07.159455'),
+(2145, 0, NULL, NULL, 'fīam', NULL, 'facio', 4, 0, 1, 1, 13, '2025-07-28 10:19:07.161880', '2025-07-28 10:19:07.161880'),
+(2146, 1, NULL, NULL, 'fīet', NULL, 'facio', 4, 0, 1, 1, 13, '2025-07-28 10:19:07.165921', '2025-07-28 10:19:07.165921'),
+(2147, 0, NULL, NULL, 'fīēs', NULL, 'facio', 4, 0, 1, 2, 13, '2025-07-28 10:19:07.168191', '2025-07-28 10:19:07.168191'),
+(2148, 1, NULL, NULL, 'fīētis', NULL, 'facio', 4, 0, 1, 2, 13, '2025-07-28 10:19:07.172200', '2025-07-28 10:19:07.172200'),
+(2149, 0, NULL, NULL, 'fīet', NULL, 'facio', 4, 0, 1, 3, 13, '2025-07-28 10:19:07.174527', '2025-07-28 10:19:07.174527'),
+(2150, 1, NULL, NULL, 'fīent', NULL, 'facio', 4, 0, 1, 3, 13, '2025-07-28 10:19:07.178606', '2025-07-28 10:19:07.178606'),
+(2151, 0, NULL, NULL, 'sum', NULL, 'facio', 2, 0, 1, 1, 13, '2025-07-28 10:19:07.181005', '2025-07-28 10:19:07.181005'),
+(2152, 1, NULL, NULL, 'sumus', NULL, 'facio', 2, 0, 1, 1, 13, '2025-07-28 10:19:07.184804', '2025-07-28 10:19:07.184804'),
+(2153, 0, NULL, NULL, 'es', NULL, 'facio', 2, 0, 1, 2, 13, '2025-07-28 10:19:07.187237', '2025-07-28 10:19:07.187237'),
+(2154, 1, NULL, NULL, 'estis', NULL, 'facio', 2, 0, 1, 2, 13, '2025-07-28 10:19:07.191090', '2025-07-28 10:19:07.191090'),
+(2155, 0, NULL, NULL, 'est', NULL, 'facio', 2, 0, 1, 3, 13, '2025-07-28 10:19:07.193502', '2025-07-28 10:19:07.193502'),
+(2156, 1, NULL, NULL, 'sunt', NULL, 'facio', 2, 0, 1, 3, 13, '2025-07-28 10:19:07.196960', '2025-07-28 10:19:07.196960'),
+(2157, 0, NULL, NULL, 'eram', NULL, 'facio', 3, 0, 1, 1, 13, '2025-07-28 10:19:07.199316', '2025-07-28 10:19:07.199316'),
+(2158, 1, NULL, NULL, 'erāmus', NULL, 'facio', 3, 0, 1, 1, 13, '2025-07-28 10:19:07.202817', '2025-07-28 10:19:07.202817'),
+(2159, 0, NULL, NULL, 'erās', NULL, 'facio', 3, 0, 1, 2, 13, '2025-07-28 10:19:07.205190', '2025-07-28 10:19:07.205190'),
+(2160, 1, NULL, NULL, 'erātis', NULL, 'facio', 3, 0, 1, 2, 13, '2025-07-28 10:19:07.208767', '2025-07-28 10:19:07.208767'),
+(2161, 0, NULL, NULL, 'erat', NULL, 'facio', 3, 0, 1, 3, 13, '2025-07-28 10:19:07.211191', '2025-07-28 10:19:07.211191'),
+(2162, 1, NULL, NULL, 'erant', NULL, 'facio', 3, 0, 1, 3, 13, '2025-07-28 10:19:07.213592', '2025-07-28 10:19:07.213592'),
+(2163, 0, NULL, NULL, 'erō', NULL, 'facio', 5, 0, 1, 1, 13, '2025-07-28 10:19:07.217065', '2025-07-28 10:19:07.217065'),
+(2164, 1, NULL, NULL, 'erimus', NULL, 'facio', 5, 0, 1, 1, 13, '2025-07-28 10:19:07.219440', '2025-07-28 10:19:07.219440'),
+(2165, 0, NULL, NULL, 'eris', NULL, 'facio', 5, 0, 1, 2, 13, '2025-07-28 10:19:07.222859', '2025-07-28 10:19:07.222859'),
+(2166, 1, NULL, NULL, 'eritis', NULL, 'facio', 5, 0, 1, 2, 13, '2025-07-28 10:19:07.225228', '2025-07-28 10:19:07.225228'),
+(2167, 0, NULL, NULL, 'erit', NULL, 'facio', 5, 0, 1, 3, 13, '2025-07-28 10:19:07.228420', '2025-07-28 10:19:07.228420'),
+(2168, 1, NULL, NULL, 'erunt', NULL, 'facio', 5, 0, 1, 3, 13, '2025-07-28 10:19:07.230834', '2025-07-28 10:19:07.230834'),
+(2169, 0, NULL, NULL, 'faciam', NULL, 'facio', 0, 1, 0, 1, 13, '2025-07-28 10:19:07.233188', '2025-07-28 10:19:07.233188'),
+(2170, 1, NULL, NULL, 'faciāmus', NULL, 'facio', 0, 1, 0, 1, 13, '2025-07-28 10:19:07.236173', '2025-07-28 10:19:07.236173'),
+(2171, 0, NULL, NULL, 'faciās', NULL, 'facio', 0, 1, 0, 2, 13, '2025-07-28 10:19:07.238588', '2025-07-28 10:19:07.238588'),
+(2172, 1, NULL, NULL, 'faciātis', NULL, 'facio', 0, 1, 0, 2, 13, '2025-07-28 10:19:07.244496', '2025-07-28 10:19:07.244496'),
+(2173, 0, NULL, NULL, 'faciat', NULL, 'facio', 0, 1, 0, 3, 13, '2025-07-28 10:19:07.246952', '2025-07-28 10:19:07.246952'),
+(2174, 1, NULL, NULL, 'faciant', NULL, 'facio', 0, 1, 0, 3, 13, '2025-07-28 10:19:07.249274', '2025-07-28 10:19:07.249274'),
+(2175, 0, NULL, NULL, 'facerem', NULL, 'facio', 1, 1, 0, 1, 13, '2025-07-28 10:19:07.251606', '2025-07-28 10:19:07.251606'),
+(2176, 1, NULL, NULL, 'facerēmus', NULL, 'facio', 1, 1, 0, 1, 13, '2025-07-28 10:19:07.253948', '2025-07-28 10:19:07.253948'),
+(2177, 0, NULL, NULL, 'facerēs', NULL, 'facio', 1, 1, 0, 2, 13, '2025-07-28 10:19:07.256253', '2025-07-28 10:19:07.256253'),
+(2178, 1, NULL, NULL, 'facerētis', NULL, 'facio', 1, 1, 0, 2, 13, '2025-07-28 10:19:07.258606', '2025-07-28 10:19:07.258606'),
+(2179, 0, NULL, NULL, 'faceret', NULL, 'facio', 1, 1, 0, 3, 13, '2025-07-28 10:19:07.261012', '2025-07-28 10:19:07.261012'),
+(2180, 1, NULL, NULL, 'facerent', NULL, 'facio', 1, 1, 0, 3, 13, '2025-07-28 10:19:07.263323', '2025-07-28 10:19:07.263323'),
+(2181, 0, NULL, NULL, 'fēcerim', NULL, 'facio', 2, 1, 0, 1, 13, '2025-07-28 10:19:07.265672', '2025-07-28 10:19:07.265672'),
+(2182, 1, NULL, NULL, 'fēcerīmus', NULL, 'facio', 2, 1, 0, 1, 13, '2025-07-28 10:19:07.267981', '2025-07-28 10:19:07.267981'),
+(2183, 0, NULL, NULL, 'fēcerīs', NULL, 'facio', 2, 1, 0, 2, 13, '2025-07-28 10:19:07.270250', '2025-07-28 10:19:07.270250'),
+(2184, 1, NULL, NULL, 'fēcerītis', NULL, 'facio', 2, 1, 0, 2, 13, '2025-07-28 10:19:07.272573', '2025-07-28 10:19:07.272573'),
+(2185, 0, NULL, NULL, 'fēcerit', NULL, 'facio', 2, 1, 0, 3, 13, '2025-07-28 10:19:07.274887', '2025-07-28 10:19:07.274887'),
+(2186, 1, NULL, NULL, 'fēcerint', NULL, 'facio', 2, 1, 0, 3, 13, '2025-07-28 10:19:07.277215', '2025-07-28 10:19:07.277215'),
+(2187, 0, NULL, NULL, 'fēcissem', NULL, 'facio', 3, 1, 0, 1, 13, '2025-07-28 10:19:07.279517', '2025-07-28 10:19:07.279517'),
+(2188, 1, NULL, NULL, 'fēcissēmus', NULL, 'facio', 3, 1, 0, 1, 13, '2025-07-28 10:19:07.281789', '2025-07-28 10:19:07.281789'),
+(2189, 0, NULL, NULL, 'fēcissēs', NULL, 'facio', 3, 1, 0, 2, 13, '2025-07-28 10:19:07.284017', '2025-07-28 10:19:07.284017'),
+(2190, 1, NULL, NULL, 'fēcissētis', NULL, 'facio', 3, 1, 0, 2, 13, '2025-07-28 10:19:07.286272', '2025-07-28 10:19:07.286272'),
+(2191, 0, NULL, NULL, 'fēcisset', NULL, 'facio', 3, 1, 0, 3, 13, '2025-07-28 10:19:07.288577', '2025-07-28 10:19:07.288577'),
+(2192, 1, NULL, NULL, 'fēcissent', NULL, 'facio', 3, 1, 0, 3, 13, '2025-07-28 10:19:07.290897', '2025-07-28 10:19:07.290897'),
+(2193, 0, NULL, NULL, 'fīam', NULL, 'facio', 0, 1, 1, 1, 13, '2025-07-28 10:19:07.293209', '2025-07-28 10:19:07.293209'),
+(2194, 1, NULL, NULL, 'fīāmus', NULL, 'facio', 0, 1, 1, 1, 13, '2025-07-28 10:19:07.295577', '2025-07-28 10:19:07.295577'),
+(2195, 0, NULL, NULL, 'fīās', NULL, 'facio', 0, 1, 1, 2, 13, '2025-07-28 10:19:07.297921', '2025-07-28 10:19:07.297921'),
+(2196, 1, NULL, NULL, 'fīātis', NULL, 'facio', 0, 1, 1, 2, 13, '2025-07-28 10:19:07.305292', '2025-07-28 10:19:07.305292'),
+(2197, 0, NULL, NULL, 'fīat', NULL, 'facio', 0, 1, 1, 3, 13, '2025-07-28 10:19:07.309185', '2025-07-28 10:19:07.309185'),
+(2198, 1, NULL, NULL, 'fīant', NULL, 'facio', 0, 1, 1, 3, 13, '2025-07-28 10:19:07.312025', '2025-07-28 10:19:07.312025'),
+(2199, 0, NULL, NULL, 'fierem', NULL, 'facio', 1, 1, 1, 1, 13, '2025-07-28 10:19:07.316037', '2025-07-28 10:19:07.316037'),
+(2200, 1, NULL, NULL, 'fierēmus', NULL, 'facio', 1, 1, 1, 1, 13, '2025-07-28 10:19:07.318368', '2025-07-28 10:19:07.318368'),
+(2201, 0, NULL, NULL, 'fierēs', NULL, 'facio', 1, 1, 1, 2, 13, '2025-07-28 10:19:07.322391', '2025-07-28 10:19:07.322391'),
+(2202, 1, NULL, NULL, 'fierētis', NULL, 'facio', 1, 1, 1, 2, 13, '2025-07-28 10:19:07.324793', '2025-07-28 10:19:07.324793'),
+(2203, 0, NULL, NULL, 'fieret', NULL, 'facio', 1, 1, 1, 3, 13, '2025-07-28 10:19:07.328992', '2025-07-28 10:19:07.328992'),
+(2204, 1, NULL, NULL, 'fierent', NULL, 'facio', 1, 1, 1, 3, 13, '2025-07-28 10:19:07.331339', '2025-07-28 10:19:07.331339'),
+(2205, 0, NULL, NULL, 'sim', NULL, 'facio', 2, 1, 1, 1, 13, '2025-07-28 10:19:07.335331', '2025-07-28 10:19:07.335331'),
+(2206, 1, NULL, NULL, 'sīmus', NULL, 'facio', 2, 1, 1, 1, 13, '2025-07-28 10:19:07.337672', '2025-07-28 10:19:07.337672'),
+(2207, 0, NULL, NULL, 'sīs', NULL, 'facio', 2, 1, 1, 2, 13, '2025-07-28 10:19:07.341750', '2025-07-28 10:19:07.341750'),
+(2208, 1, NULL, NULL, 'sītis', NULL, 'facio', 2, 1, 1, 2, 13, '2025-07-28 10:19:07.344153', '2025-07-28 10:19:07.344153'),
+(2209, 0, NULL, NULL, 'sit', NULL, 'facio', 2, 1, 1, 3, 13, '2025-07-28 10:19:07.348136', '2025-07-28 10:19:07.348136'),
+(2210, 1, NULL, NULL, 'sint', NULL, 'facio', 2, 1, 1, 3, 13, '2025-07-28 10:19:07.350505', '2025-07-28 10:19:07.350505'),
+(2211, 0, NULL, NULL, 'essem', NULL, 'facio', 3, 1, 1, 1, 13, '2025-07-28 10:19:07.352915', '2025-07-28 10:19:07.352915'),
+(2212, 1, NULL, NULL, 'essēmus', NULL, 'facio', 3, 1, 1, 1, 13, '2025-07-28 10:19:07.356951', '2025-07-28 10:19:07.356951'),
+(2213, 0, NULL, NULL, 'essēs', NULL, 'facio', 3, 1, 1, 2, 13, '2025-07-28 10:19:07.359284', '2025-07-28 10:19:07.359284'),
+(2214, 1, NULL, NULL, 'essētis', NULL, 'facio', 3, 1, 1, 2, 13, '2025-07-28 10:19:07.363173', '2025-07-28 10:19:07.363173'),
+(2215, 0, NULL, NULL, 'esset', NULL, 'facio', 3, 1, 1, 3, 13, '2025-07-28 10:19:07.365549', '2025-07-28 10:19:07.365549'),
+(2216, 1, NULL, NULL, 'essent', NULL, 'facio', 3, 1, 1, 3, 13, '2025-07-28 10:19:07.369425', '2025-07-28 10:19:07.369425'),
+(2217, 0, NULL, NULL, 'fac', NULL, 'facio', 0, 2, 0, 2, 13, '2025-07-28 10:19:07.371795', '2025-07-28 10:19:07.371795'),
+(2218, 1, NULL, NULL, 'facite', NULL, 'facio', 0, 2, 0, 2, 13, '2025-07-28 10:19:07.375646', '2025-07-28 10:19:07.375646'),
+(2219, 0, NULL, NULL, 'facitō', NULL, 'facio', 4, 2, 0, 2, 13, '2025-07-28 10:19:07.378128', '2025-07-28 10:19:07.378128'),
+(2220, 1, NULL, NULL, 'facitōte', NULL, 'facio', 4, 2, 0, 2, 13, '2025-07-28 10:19:07.381937', '2025-07-28 10:19:07.381937'),
+(2221, 0, NULL, NULL, 'facitō', NULL, 'facio', 4, 2, 0, 3, 13, '2025-07-28 10:19:07.384228', '2025-07-28 10:19:07.384228'),
+(2222, 1, NULL, NULL, 'faciuntō', NULL, 'facio', 4, 2, 0, 3, 13, '2025-07-28 10:19:07.387496', '2025-07-28 10:19:07.387496'),
+(2223, 0, NULL, NULL, 'fī', NULL, 'facio', 0, 2, 1, 2, 13, '2025-07-28 10:19:07.389943', '2025-07-28 10:19:07.389943'),
+(2224, 1, NULL, NULL, 'fīte', NULL, 'facio', 0, 2, 1, 2, 13, '2025-07-28 10:19:07.393230', '2025-07-28 10:19:07.393230'),
+(2225, 0, NULL, NULL, 'fītō', NULL, 'facio', 4, 2, 1, 2, 13, '2025-07-28 10:19:07.395650', '2025-07-28 10:19:07.395650'),
+(2226, 1, NULL, NULL, 'fītōte', NULL, 'facio', 4, 2, 1, 2, 13, '2025-07-28 10:19:07.398981', '2025-07-28 10:19:07.398981'),
+(2227, 0, NULL, NULL, 'fītō', NULL, 'facio', 4, 2, 1, 3, 13, '2025-07-28 10:19:07.401327', '2025-07-28 10:19:07.401327'),
+(2228, 1, NULL, NULL, 'fīuntō', NULL, 'facio', 4, 2, 1, 3, 13, '2025-07-28 10:19:07.404490', '2025-07-28 10:19:07.404490'),
+(2229, 0, NULL, NULL, 'facere', NULL, 'facio', 0, 6, 0, 1, 13, '2025-07-28 10:19:07.406928', '2025-07-28 10:19:07.406928'),
+(2230, 1, NULL, NULL, NULL, NULL, 'facio', 0, 6, 0, 1, 13, '2025-07-28 10:19:07.410312', '2025-07-28 10:19:07.410312'),
+(2231, 0, NULL, NULL, 'fēcisse', NULL, 'facio', 2, 6, 0, 1, 13, '2025-07-28 10:19:07.412739', '2025-07-28 10:19:07.412739'),
+(2232, 1, NULL, NULL, NULL, NULL, 'facio', 2, 6, 0, 1, 13, '2025-07-28 10:19:07.415130', '2025-07-28 10:19:07.415130'),
+(2233, 0, NULL, NULL, 'esse', NULL, 'facio', 4, 6, 0, 1, 13, '2025-07-28 10:19:07.418339', '2025-07-28 10:19:07.418339'),
+(2234, 1, NULL, NULL, NULL, NULL, 'facio', 4, 6, 0, 1, 13, '2025-07-28 10:19:07.420647', '2025-07-28 10:19:07.420647'),
+(2235, 0, NULL, NULL, 'fierī', NULL, 'facio', 0, 6, 1, 1, 13, '2025-07-28 10:19:07.423708', '2025-07-28 10:19:07.423708'),
+(2236, 1, NULL, NULL, NULL, NULL, 'facio', 0, 6, 1, 1, 13, '2025-07-28 10:19:07.426082', '2025-07-28 10:19:07.426082'),
+(2237, 0, NULL, NULL, 'esse', NULL, 'facio', 2, 6, 1, 1, 13, '2025-07-28 10:19:07.432409', '2025-07-28 10:19:07.432409'),
+(2238, 1, NULL, NULL, NULL, NULL, 'facio', 2, 6, 1, 1, 13, '2025-07-28 10:19:07.434808', '2025-07-28 10:19:07.434808'),
+(2239, 0, NULL, NULL, 'īrī', NULL, 'facio', 4, 6, 1, 1, 13, '2025-07-28 10:19:07.437157', '2025-07-28 10:19:07.437157'),
+(2240, 1, NULL, NULL, NULL, NULL, 'facio', 4, 6, 1, 1, 13, '2025-07-28 10:19:07.439587', '2025-07-28 10:19:07.439587'),
+(2241, 0, NULL, NULL, 'faciēns', NULL, 'facio', 0, 8, 0, 1, 13, '2025-07-28 10:19:07.441969', '2025-07-28 10:19:07.441969'),
+(2242, 1, NULL, NULL, NULL, NULL, 'facio', 0, 8, 0, 1, 13, '2025-07-28 10:19:07.444399', '2025-07-28 10:19:07.444399'),
+(2243, 0, NULL, NULL, 'ūrus', NULL, 'facio', 4, 8, 0, 1, 13, '2025-07-28 10:19:07.447074', '2025-07-28 10:19:07.447074'),
+(2244, 1, NULL, NULL, NULL, NULL, 'facio', 4, 8, 0, 1, 13, '2025-07-28 10:19:07.449788', '2025-07-28 10:19:07.449788'),
+(2245, 0, NULL, NULL, 'us', NULL, 'facio', 2, 8, 1, 1, 13, '2025-07-28 10:19:07.452183', '2025-07-28 10:19:07.452183'),
+(2246, 1, NULL, NULL, NULL, NULL, 'facio', 2, 8, 1, 1, 13, '2025-07-28 10:19:07.454534', '2025-07-28 10:19:07.454534'),
+(2247, 0, NULL, NULL, 'faciendus', NULL, 'facio', 4, 8, 1, 1, 13, '2025-07-28 10:19:07.457000', '2025-07-28 10:19:07.457000'),
+(2248, 1, NULL, NULL, NULL, NULL, 'facio', 4, 8, 1, 1, 13, '2025-07-28 10:19:07.459374', '2025-07-28 10:19:07.459374'),
+(2249, 0, NULL, NULL, 'dō', NULL, 'do', 0, 0, 0, 1, 14, '2025-07-28 10:19:07.466475', '2025-07-28 10:19:07.466475'),
+(2250, 1, NULL, NULL, 'damus', NULL, 'do', 0, 0, 0, 1, 14, '2025-07-28 10:19:07.468869', '2025-07-28 10:19:07.468869'),
+(2251, 0, NULL, NULL, 'dās', NULL, 'do', 0, 0, 0, 2, 14, '2025-07-28 10:19:07.471132', '2025-07-28 10:19:07.471132'),
+(2252, 1, NULL, NULL, 'datis', NULL, 'do', 0, 0, 0, 2, 14, '2025-07-28 10:19:07.473571', '2025-07-28 10:19:07.473571'),
+(2253, 0, NULL, NULL, 'dat', NULL, 'do', 0, 0, 0, 3, 14, '2025-07-28 10:19:07.475936', '2025-07-28 10:19:07.475936'),
+(2254, 1, NULL, NULL, 'dant', NULL, 'do', 0, 0, 0, 3, 14, '2025-07-28 10:19:07.478284', '2025-07-28 10:19:07.478284'),
+(2255, 0, NULL, NULL, 'dabam', NULL, 'do', 1, 0, 0, 1, 14, '2025-07-28 10:19:07.480607', '2025-07-28 10:19:07.480607'),
+(2256, 1, NULL, NULL, 'dabāmus', NULL, 'do', 1, 0, 0, 1, 14, '2025-07-28 10:19:07.482977', '2025-07-28 10:19:07.482977'),
+(2257, 0, NULL, NULL, 'dabās', NULL, 'do', 1, 0, 0, 2, 14, '2025-07-28 10:19:07.490675', '2025-07-28 10:19:07.490675'),
+(2258, 1, NULL, NULL, 'dabātis', NULL, 'do', 1, 0, 0, 2, 14, '2025-07-28 10:19:07.493169', '2025-07-28 10:19:07.493169'),
+(2259, 0, NULL, NULL, 'dabat', NULL, 'do', 1, 0, 0, 3, 14, '2025-07-28 10:19:07.497415', '2025-07-28 10:19:07.497415'),
+(2260, 1, NULL, NULL, 'dabant', NULL, 'do', 1, 0, 0, 3, 14, '2025-07-28 10:19:07.501281', '2025-07-28 10:19:07.501281'),
+(2261, 0, NULL, NULL, 'dedī', NULL, 'do', 2, 0, 0, 1, 14, '2025-07-28 10:19:07.504077', '2025-07-28 10:19:07.504077'),
+(2262, 1, NULL, NULL, 'dedimus', NULL, 'do', 2, 0, 0, 1, 14, '2025-07-28 10:19:07.508069', '2025-07-28 10:19:07.508069'),
+(2263, 0, NULL, NULL, 'dedistī', NULL, 'do', 2, 0, 0, 2, 14, '2025-07-28 10:19:07.510378', '2025-07-28 10:19:07.510378'),
+(2264, 1, NULL, NULL, 'dedistis', NULL, 'do', 2, 0, 0, 2, 14, '2025-07-28 10:19:07.514431', '2025-07-28 10:19:07.514431'),
+(2265, 0, NULL, NULL, 'dedit', NULL, 'do', 2, 0, 0, 3, 14, '2025-07-28 10:19:07.516837', '2025-07-28 10:19:07.516837'),
+(2266, 1, NULL, NULL, 'dedērunt', NULL, 'do', 2, 0, 0, 3, 14, '2025-07-28 10:19:07.520740', '2025-07-28 10:19:07.520740'),
+(2267, 0, NULL, NULL, 'dederam', NULL, 'do', 3, 0, 0, 1, 14, '2025-07-28 10:19:07.523194', '2025-07-28 10:19:07.523194'),
+(2268, 1, NULL, NULL, 'dederāmus', NULL, 'do', 3, 0, 0, 1, 14, '2025-07-28 10:19:07.526770', '2025-07-28 10:19:07.526770'),
+(2269, 0, NULL, NULL, 'dederās', NULL, 'do', 3, 0, 0, 2, 14, '2025-07-28 10:19:07.529493', '2025-07-28 10:19:07.529493'),
+(2270, 1, NULL, NULL, 'dederātis', NULL, 'do', 3, 0, 0, 2, 14, '2025-07-28 10:19:07.533100', '2025-07-28 10:19:07.533100'),
+(2271, 0, NULL, NULL, 'dederat', NULL, 'do', 3, 0, 0, 3, 14, '2025-07-28 10:19:07.535819', '2025-07-28 10:19:07.535819'),
+(2272, 1, NULL, NULL, 'dederant', NULL, 'do', 3, 0, 0, 3, 14, '2025-07-28 10:19:07.538164', '2025-07-28 10:19:07.538164'),
+(2273, 0, NULL, NULL, 'dabō', NULL, 'do', 4, 0, 0, 1, 14, '2025-07-28 10:19:07.542249', '2025-07-28 10:19:07.542249'),
+(2274, 1, NULL, NULL, 'dabimus', NULL, 'do', 4, 0, 0, 1, 14, '2025-07-28 10:19:07.544718', '2025-07-28 10:19:07.544718'),
+(2275, 0, NULL, NULL, 'dabis', NULL, 'do', 4, 0, 0, 2, 14, '2025-07-28 10:19:07.548796', '2025-07-28 10:19:07.548796'),
+(2276, 1, NULL, NULL, 'dabitis', NULL, 'do', 4, 0, 0, 2, 14, '2025-07-28 10:19:07.551179', '2025-07-28 10:19:07.551179'),
+(2277, 0, NULL, NULL, 'dabit', NULL, 'do', 4, 0, 0, 3, 14, '2025-07-28 10:19:07.555294', '2025-07-28 10:19:07.555294'),
+(2278, 1, NULL, NULL, 'dabunt', NULL, 'do', 4, 0, 0, 3, 14, '2025-07-28 10:19:07.557676', '2025-07-28 10:19:07.557676'),
+(2279, 0, NULL, NULL, 'dederō', NULL, 'do', 5, 0, 0, 1, 14, '2025-07-28 10:19:07.561021', '2025-07-28 10:19:07.561021'),
+(2280, 1, NULL, NULL, 'dederimus', NULL, 'do', 5, 0, 0, 1, 14, '2025-07-28 10:19:07.563421', '2025-07-28 10:19:07.563421'),
+(2281, 0, NULL, NULL, 'dederis', NULL, 'do', 5, 0, 0, 2, 14, '2025-07-28 10:19:07.566540', '2025-07-28 10:19:07.566540'),
+(2282, 1, NULL, NULL, 'dederitis', NULL, 'do', 5, 0, 0, 2, 14, '2025-07-28 10:19:07.568889', '2025-07-28 10:19:07.568889'),
+(2283, 0, NULL, NULL, 'dederit', NULL, 'do', 5, 0, 0, 3, 14, '2025-07-28 10:19:07.572019', '2025-07-28 10:19:07.572019'),
+(2284, 1, NULL, NULL, 'dederint', NULL, 'do', 5, 0, 0, 3, 14, '2025-07-28 10:19:07.574424', '2025-07-28 10:19:07.574424'),
+(2285, 0, NULL, NULL, 'dor', NULL, 'do', 0, 0, 1, 1, 14, '2025-07-28 10:19:07.577790', '2025-07-28 10:19:07.577790'),
+(2286, 1, NULL, NULL, 'damur', NULL, 'do', 0, 0, 1, 1, 14, '2025-07-28 10:19:07.580277', '2025-07-28 10:19:07.580277'),
+(2287, 0, NULL, NULL, 'daris', NULL, 'do', 0, 0, 1, 2, 14, '2025-07-28 10:19:07.583618', '2025-07-28 10:19:07.583618'),
+(2288, 1, NULL, NULL, 'daminī', NULL, 'do', 0, 0, 1, 2, 14, '2025-07-28 10:19:07.586029', '2025-07-28 10:19:07.586029'),
+(2289, 0, NULL, NULL, 'datur', NULL, 'do', 0, 0, 1, 3, 14, '2025-07-28 10:19:07.589385', '2025-07-28 10:19:07.589385'),
+(2290, 1, NULL, NULL, 'dantur', NULL, 'do', 0, 0, 1, 3, 14, '2025-07-28 10:19:07.591780', '2025-07-28 10:19:07.591780'),
+(2291, 0, NULL, NULL, 'dabar', NULL, 'do', 1, 0, 1, 1, 14, '2025-07-28 10:19:07.595115', '2025-07-28 10:19:07.595115'),
+(2292, 1, NULL, NULL, 'dabāmur', NULL, 'do', 1, 0, 1, 1, 14, '2025-07-28 10:19:07.597492', '2025-07-28 10:19:07.597492'),
+(2293, 0, NULL, NULL, 'dabāris', NULL, 'do', 1, 0, 1, 2, 14, '2025-07-28 10:19:07.599910', '2025-07-28 10:19:07.599910'),
+(2294, 1, NULL, NULL, 'dabāminī', NULL, 'do', 1, 0, 1, 2, 14, '2025-07-28 10:19:07.602988', '2025-07-28 10:19:07.602988'),
+(2295, 0, NULL, NULL, 'dabātur', NULL, 'do', 1, 0, 1, 3, 14, '2025-07-28 10:19:07.616316', '2025-07-28 10:19:07.616316'),
+(2296, 1, NULL, NULL, 'dabantur', NULL, 'do', 1, 0, 1, 3, 14, '2025-07-28 10:19:07.627400', '2025-07-28 10:19:07.627400'),
+(2297, 0, NULL, NULL, 'dabor', NULL, 'do', 4, 0, 1, 1, 14, '2025-07-28 10:19:07.636258', '2025-07-28 10:19:07.636258'),
+(2298, 1, NULL, NULL, 'dabimur', NULL, 'do', 4, 0, 1, 1, 14, '2025-07-28 10:19:07.645677', '2025-07-28 10:19:07.645677'),
+(2299, 0, NULL, NULL, 'daberis', NULL, 'do', 4, 0, 1, 2, 14, '2025-07-28 10:19:07.651662', '2025-07-28 10:19:07.651662'),
+(2300, 1, NULL, NULL, 'dabiminī', NULL, 'do', 4, 0, 1, 2, 14, '2025-07-28 10:19:07.656982', '2025-07-28 10:19:07.656982'),
+(2301, 0, NULL, NULL, 'dabitur', NULL, 'do', 4, 0, 1, 3, 14, '2025-07-28 10:19:07.665326', '2025-07-28 10:19:07.665326'),
+(2302, 1, NULL, NULL, 'dabuntur', NULL, 'do', 4, 0, 1, 3, 14, '2025-07-28 10:19:07.672456', '2025-07-28 10:19:07.672456'),
+(2303, 0, NULL, NULL, 'sum', NULL, 'do', 2, 0, 1, 1, 14, '2025-07-28 10:19:07.678836', '2025-07-28 10:19:07.678836'),
+(2304, 1, NULL, NULL, 'sumus', NULL, 'do', 2, 0, 1, 1, 14, '2025-07-28 10:19:07.686555', '2025-07-28 10:19:07.686555'),
+(2305, 0, NULL, NULL, 'es', NULL, 'do', 2, 0, 1, 2, 14, '2025-07-28 10:19:07.692322', '2025-07-28 10:19:07.692322'),
+(2306, 1, NULL, NULL, 'estis', NULL, 'do', 2, 0, 1, 2, 14, '2025-07-28 10:19:07.697967', '2025-07-28 10:19:07.697967'),
+(2307, 0, NULL, NULL, 'est', NULL, 'do', 2, 0, 1, 3, 14, '2025-07-28 10:19:07.702869', '2025-07-28 10:19:07.702869'),
+(2308, 1, NULL, NULL, 'sunt', NULL, 'do', 2, 0, 1, 3, 14, '2025-07-28 10:19:07.708556', '2025-07-28 10:19:07.708556'),
+(2309, 0, NULL, NULL, 'eram', NULL, 'do', 3, 0, 1, 1, 14, '2025-07-28 10:19:07.713656', '2025-07-28 10:19:07.713656'),
+(2310, 1, NULL, NULL, 'erāmus', NULL, 'do', 3, 0, 1, 1, 14, '2025-07-28 10:19:07.720642', '2025-07-28 10:19:07.720642'),
+(2311, 0, NULL, NULL, 'erās', NULL, 'do', 3, 0, 1, 2, 14, '2025-07-28 10:19:07.726923', '2025-07-28 10:19:07.726923'),
+(2312, 1, NULL, NULL, 'erātis', NULL, 'do', 3, 0, 1, 2, 14, '2025-07-28 10:19:07.729421', '2025-07-28 10:19:07.729421'),
+(2313, 0, NULL, NULL, 'erat', NULL, 'do', 3, 0, 1, 3, 14, '2025-07-28 10:19:07.733429', '2025-07-28 10:19:07.733429'),
+(2314, 1, NULL, NULL, 'erant', NULL, 'do', 3, 0, 1, 3, 14, '2025-07-28 10:19:07.735830', '2025-07-28 10:19:07.735830'),
+(2315, 0, NULL, NULL, 'erō', NULL, 'do', 5, 0, 1, 1, 14, '2025-07-28 10:19:07.739960', '2025-07-28 10:19:07.739960'),
+(2316, 1, NULL, NULL, 'erimus', NULL, 'do', 5, 0, 1, 1, 14, '2025-07-28 10:19:07.742387', '2025-07-28 10:19:07.742387'),
+(2317, 0, NULL, NULL, 'eris', NULL, 'do', 5, 0, 1, 2, 14, '2025-07-28 10:19:07.746381', '2025-07-28 10:19:07.746381'),
+(2318, 1, NULL, NULL, 'eritis', NULL, 'do', 5, 0, 1, 2, 14, '2025-07-28 10:19:07.749971', '2025-07-28 10:19:07.749971'),
+(2319, 0, NULL, NULL, 'erit', NULL, 'do', 5, 0, 1, 3, 14, '2025-07-28 10:19:07.752670', '2025-07-28 10:19:07.752670'),
+(2320, 1, NULL, NULL, 'erunt', NULL, 'do', 5, 0, 1, 3, 14, '2025-07-28 10:19:07.756630', '2025-07-28 10:19:07.756630'),
+(2321, 0, NULL, NULL, 'dem', NULL, 'do', 0, 1, 0, 1, 14, '2025-07-28 10:19:07.758997', '2025-07-28 10:19:07.758997'),
+(2322, 1, NULL, NULL, 'dēmus', NULL, 'do', 0, 1, 0, 1, 14, '2025-07-28 10:19:07.763048', '2025-07-28 10:19:07.763048'),
+(2323, 0, NULL, NULL, 'dēs', NULL, 'do', 0, 1, 0, 2, 14, '2025-07-28 10:19:07.765415', '2025-07-28 10:19:07.765415'),
+(2324, 1, NULL, NULL, 'dētis', NULL, 'do', 0, 1, 0, 2, 14, '2025-07-28 10:19:07.769424', '2025-07-28 10:19:07.769424'),
+(2325, 0, NULL, NULL, 'det', NULL, 'do', 0, 1, 0, 3, 14, '2025-07-28 10:19:07.771779', '2025-07-28 10:19:07.771779'),
+(2326, 1, NULL, NULL, 'dent', NULL, 'do', 0, 1, 0, 3, 14, '2025-07-28 10:19:07.775365', '2025-07-28 10:19:07.775365'),
+(2327, 0, NULL, NULL, 'darem', NULL, 'do', 1, 1, 0, 1, 14, '2025-07-28 10:19:07.778199', '2025-07-28 10:19:07.778199'),
+(2328, 1, NULL, NULL, 'darēmus', NULL, 'do', 1, 1, 0, 1, 14, '2025-07-28 10:19:07.780616', '2025-07-28 10:19:07.780616'),
+(2329, 0, NULL, NULL, 'darēs', NULL, 'do', 1, 1, 0, 2, 14, '2025-07-28 10:19:07.784666', '2025-07-28 10:19:07.784666'),
+(2330, 1, NULL, NULL, 'darētis', NULL, 'do', 1, 1, 0, 2, 14, '2025-07-28 10:19:07.787041', '2025-07-28 10:19:07.787041'),
+(2331, 0, NULL, NULL, 'daret', NULL, 'do', 1, 1, 0, 3, 14, '2025-07-28 10:19:07.790611', '2025-07-28 10:19:07.790611'),
+(2332, 1, NULL, NULL, 'darent', NULL, 'do', 1, 1, 0, 3, 14, '2025-07-28 10:19:07.792968', '2025-07-28 10:19:07.792968'),
+(2333, 0, NULL, NULL, 'dederim', NULL, 'do', 2, 1, 0, 1, 14, '2025-07-28 10:19:07.796108', '2025-07-28 10:19:07.796108'),
+(2334, 1, NULL, NULL, 'dederīmus', NULL, 'do', 2, 1, 0, 1, 14, '2025-07-28 10:19:07.799230', '2025-07-28 10:19:07.799230'),
+(2335, 0, NULL, NULL, 'dederīs', NULL, 'do', 2, 1, 0, 2, 14, '2025-07-28 10:19:07.801635', '2025-07-28 10:19:07.801635'),
+(2336, 1, NULL, NULL, 'dederītis', NULL, 'do', 2, 1, 0, 2, 14, '2025-07-28 10:19:07.804823', '2025-07-28 10:19:07.804823'),
+(2337, 0, NULL, NULL, 'dederit', NULL, 'do', 2, 1, 0, 3, 14, '2025-07-28 10:19:07.807192', '2025-07-28 10:19:07.807192'),
+(2338, 1, NULL, NULL, 'dederint', NULL, 'do', 2, 1, 0, 3, 14, '2025-07-28 10:19:07.810407', '2025-07-28 10:19:07.810407'),
+(2339, 0, NULL, NULL, 'dedissem', NULL, 'do', 3, 1, 0, 1, 14, '2025-07-28 10:19:07.812814', '2025-07-28 10:19:07.812814'),
+(2340, 1, NULL, NULL, 'dedissēmus', NULL, 'do', 3, 1, 0, 1, 14, '2025-07-28 10:19:07.815264', '2025-07-28 10:19:07.815264'),
+(2341, 0, NULL, NULL, 'dedissēs', NULL, 'do', 3, 1, 0, 2, 14, '2025-07-28 10:19:07.818669', '2025-07-28 10:19:07.818669'),
+(2342, 1, NULL, NULL, 'dedissētis', NULL, 'do', 3, 1, 0, 2, 14, '2025-07-28 10:19:07.821407', '2025-07-28 10:19:07.821407'),
+(2343, 0, NULL, NULL, 'dedisset', NULL, 'do', 3, 1, 0, 3, 14, '2025-07-28 10:19:07.824889', '2025-07-28 10:19:07.824889'),
+(2344, 1, NULL, NULL, 'dedissent', NULL, 'do', 3, 1, 0, 3, 14, '2025-07-28 10:19:07.827783', '2025-07-28 10:19:07.827783'),
+(2345, 0, NULL, NULL, 'der', NULL, 'do', 0, 1, 1, 1, 14, '2025-07-28 10:19:07.831128', '2025-07-28 10:19:07.831128'),
+(2346, 1, NULL, NULL, 'dēmur', NULL, 'do', 0, 1, 1, 1, 14, '2025-07-28 10:19:07.833969', '2025-07-28 10:19:07.833969'),
+(2347, 0, NULL, NULL, 'dēris', NULL, 'do', 0, 1, 1, 2, 14, '2025-07-28 10:19:07.836535', '2025-07-28 10:19:07.836535'),
+(2348, 1, NULL, NULL, 'dēminī', NULL, 'do', 0, 1, 1, 2, 14, '2025-07-28 10:19:07.839605', '2025-07-28 10:19:07.839605'),
+(2349, 0, NULL, NULL, 'dētur', NULL, 'do', 0, 1, 1, 3, 14, '2025-07-28 10:19:07.842472', '2025-07-28 10:19:07.842472'),
+(2350, 1, NULL, NULL, 'dentur', NULL, 'do', 0, 1, 1, 3, 14, '2025-07-28 10:19:07.844970', '2025-07-28 10:19:07.844970'),
+(2351, 0, NULL, NULL, 'darer', NULL, 'do', 1, 1, 1, 1, 14, '2025-07-28 10:19:07.847833', '2025-07-28 10:19:07.847833'),
+(2352, 1, NULL, NULL, 'darēmur', NULL, 'do', 1, 1, 1, 1, 14, '2025-07-28 10:19:07.850684', '2025-07-28 10:19:07.850684'),
+(2353, 0, NULL, NULL, 'darēris', NULL, 'do', 1, 1, 1, 2, 14, '2025-07-28 10:19:07.853669', '2025-07-28 10:19:07.853669'),
+(2354, 1, NULL, NULL, 'darēminī', NULL, 'do', 1, 1, 1, 2, 14, '2025-07-28 10:19:07.856632', '2025-07-28 10:19:07.856632'),
+(2355, 0, NULL, NULL, 'darētur', NULL, 'do', 1, 1, 1, 3, 14, '2025-07-28 10:19:07.858982', '2025-07-28 10:19:07.858982'),
+(2356, 1, NULL, NULL, 'darentur', NULL, 'do', 1, 1, 1, 3, 14, '2025-07-28 10:19:07.861356', '2025-07-28 10:19:07.861356'),
+(2357, 0, NULL, NULL, 'sim', NULL, 'do', 2, 1, 1, 1, 14, '2025-07-28 10:19:07.863808', '2025-07-28 10:19:07.863808'),
+(2358, 1, NULL, NULL, 'sīmus', NULL, 'do', 2, 1, 1, 1, 14, '2025-07-28 10:19:07.866162', '2025-07-28 10:19:07.866162'),
+(2359, 0, NULL, NULL, 'sīs', NULL, 'do', 2, 1, 1, 2, 14, '2025-07-28 10:19:07.868531', '2025-07-28 10:19:07.868531'),
+(2360, 1, NULL, NULL, 'sītis', NULL, 'do', 2, 1, 1, 2, 14, '2025-07-28 10:19:07.870893', '2025-07-28 10:19:07.870893'),
+(2361, 0, NULL, NULL, 'sit', NULL, 'do', 2, 1, 1, 3, 14, '2025-07-28 10:19:07.873224', '2025-07-28 10:19:07.873224'),
+(2362, 1, NULL, NULL, 'sint', NULL, 'do', 2, 1, 1, 3, 14, '2025-07-28 10:19:07.875534', '2025-07-28 10:19:07.875534'),
+(2363, 0, NULL, NULL, 'essem', NULL, 'do', 3, 1, 1, 1, 14, '2025-07-28 10:19:07.877870', '2025-07-28 10:19:07.877870'),
+(2364, 1, NULL, NULL, 'essēmus', NULL, 'do', 3, 1, 1, 1, 14, '2025-07-28 10:19:07.880169', '2025-07-28 10:19:07.880169'),
+(2365, 0, NULL, NULL, 'essēs', NULL, 'do', 3, 1, 1, 2, 14, '2025-07-28 10:19:07.882482', '2025-07-28 10:19:07.882482'),
+(2366, 1, NULL, NULL, 'essētis', NULL, 'do', 3, 1, 1, 2, 14, '2025-07-28 10:19:07.884791', '2025-07-28 10:19:07.884791'),
+(2367, 0, NULL, NULL, 'esset', NULL, 'do', 3, 1, 1, 3, 14, '2025-07-28 10:19:07.887085', '2025-07-28 10:19:07.887085'),
+(2368, 1, NULL, NULL, 'essent', NULL, 'do', 3, 1, 1, 3, 14, '2025-07-28 10:19:07.889536', '2025-07-28 10:19:07.889536'),
+(2369, 0, NULL, NULL, 'dā', NULL, 'do', 0, 2, 0, 2, 14, '2025-07-28 10:19:07.891907', '2025-07-28 10:19:07.891907'),
+(2370, 1, NULL, NULL, 'date', NULL, 'do', 0, 2, 0, 2, 14, '2025-07-28 10:19:07.894312', '2025-07-28 10:19:07.894312'),
+(2371, 0, NULL, NULL, 'datō', NULL, 'do', 4, 2, 0, 2, 14, '2025-07-28 10:19:07.896661', '2025-07-28 10:19:07.896661'),
+(2372, 1, NULL, NULL, 'datōte', NULL, 'do', 4, 2, 0, 2, 14, '2025-07-28 10:19:07.898997', '2025-07-28 10:19:07.898997'),
+(2373, 0, NULL, NULL, 'datō', NULL, 'do', 4, 2, 0, 3, 14, '2025-07-28 10:19:07.901277', '2025-07-28 10:19:07.901277'),
+(2374, 1, NULL, NULL, 'dantō', NULL, 'do', 4, 2, 0, 3, 14, '2025-07-28 10:19:07.903600', '2025-07-28 10:19:07.903600'),
+(2375, 0, NULL, NULL, 'dare', NULL, 'do', 0, 2, 1, 2, 14, '2025-07-28 10:19:07.905912', '2025-07-28 10:19:07.905912'),
+(2376, 1, NULL, NULL, 'daminī', NULL, 'do', 0, 2, 1, 2, 14, '2025-07-28 10:19:07.908186', '2025-07-28 10:19:07.908186'),
+(2377, 0, NULL, NULL, 'dator', NULL, 'do', 4, 2, 1, 2, 14, '2025-07-28 10:19:07.910474', '2025-07-28 10:19:07.910474'),
+(2378, 1, NULL, NULL, NULL, NULL, 'do', 4, 2, 1, 2, 14, '2025-07-28 10:19:07.912828', '2025-07-28 10:19:07.912828'),
+(2379, 0, NULL, NULL, 'dator', NULL, 'do', 4, 2, 1, 3, 14, '2025-07-28 10:19:07.915115', '2025-07-28 10:19:07.915115'),
+(2380, 1, NULL, NULL, 'dantor', NULL, 'do', 4, 2, 1, 3, 14, '2025-07-28 10:19:07.917399', '2025-07-28 10:19:07.917399'),
+(2381, 0, NULL, NULL, 'dare', NULL, 'do', 0, 6, 0, 1, 14, '2025-07-28 10:19:07.919710', '2025-07-28 10:19:07.919710'),
+(2382, 1, NULL, NULL, NULL, NULL, 'do', 0, 6, 0, 1, 14, '2025-07-28 10:19:07.921976', '2025-07-28 10:19:07.921976'),
+(2383, 0, NULL, NULL, 'dedisse', NULL, 'do', 2, 6, 0, 1, 14, '2025-07-28 10:19:07.924169', '2025-07-28 10:19:07.924169'),
+(2384, 1, NULL, NULL, NULL, NULL, 'do', 2, 6, 0, 1, 14, '2025-07-28 10:19:07.926483', '2025-07-28 10:19:07.926483'),
+(2385, 0, NULL, NULL, 'esse', NULL, 'do', 4, 6, 0, 1, 14, '2025-07-28 10:19:07.928840', '2025-07-28 10:19:07.928840'),
+(2386, 1, NULL, NULL, NULL, NULL, 'do', 4, 6, 0, 1, 14, '2025-07-28 10:19:07.931139', '2025-07-28 10:19:07.931139'),
+(2387, 0, NULL, NULL, 'dārī', NULL, 'do', 0, 6, 1, 1, 14, '2025-07-28 10:19:07.933467', '2025-07-28 10:19:07.933467'),
+(2388, 1, NULL, NULL, NULL, NULL, 'do', 0, 6, 1, 1, 14, '2025-07-28 10:19:07.935837', '2025-07-28 10:19:07.935837'),
+(2389, 0, NULL, NULL, 'esse', NULL, 'do', 2, 6, 1, 1, 14, '2025-07-28 10:19:07.938061', '2025-07-28 10:19:07.938061'),
+(2390, 1, NULL, NULL, NULL, NULL, 'do', 2, 6, 1, 1, 14, '2025-07-28 10:19:07.940374', '2025-07-28 10:19:07.940374'),
+(2391, 0, NULL, NULL, 'īrī', NULL, 'do', 4, 6, 1, 1, 14, '2025-07-28 10:19:07.942654', '2025-07-28 10:19:07.942654'),
+(2392, 1, NULL, NULL, NULL, NULL, 'do', 4, 6, 1, 1, 14, '2025-07-28 10:19:07.945048', '2025-07-28 10:19:07.945048'),
+(2393, 0, NULL, NULL, 'dāns', NULL, 'do', 0, 8, 0, 1, 14, '2025-07-28 10:19:07.947396', '2025-07-28 10:19:07.947396'),
+(2394, 1, NULL, NULL, NULL, NULL, 'do', 0, 8, 0, 1, 14, '2025-07-28 10:19:07.949783', '2025-07-28 10:19:07.949783'),
+(2395, 0, NULL, NULL, 'ūrus', NULL, 'do', 4, 8, 0, 1, 14, '2025-07-28 10:19:07.952129', '2025-07-28 10:19:07.952129'),
+(2396, 1, NULL, NULL, NULL, NULL, 'do', 4, 8, 0, 1, 14, '2025-07-28 10:19:07.954462', '2025-07-28 10:19:07.954462'),
+(2397, 0, NULL, NULL, 'us', NULL, 'do', 2, 8, 1, 1, 14, '2025-07-28 10:19:07.956806', '2025-07-28 10:19:07.956806'),
+(2398, 1, NULL, NULL, NULL, NULL, 'do', 2, 8, 1, 1, 14, '2025-07-28 10:19:07.959082', '2025-07-28 10:19:07.959082'),
+(2399, 0, NULL, NULL, 'dandus', NULL, 'do', 4, 8, 1, 1, 14, '2025-07-28 10:19:07.961430', '2025-07-28 10:19:07.961430'),
+(2400, 1, NULL, NULL, NULL, NULL, 'do', 4, 8, 1, 1, 14, '2025-07-28 10:19:07.963831', '2025-07-28 10:19:07.963831'),
+(2401, 0, NULL, NULL, 'inquam', NULL, 'inquam', 0, 0, 0, 1, 15, '2025-07-28 10:19:07.970665', '2025-07-28 10:19:07.970665'),
+(2402, 1, NULL, NULL, 'inquimus', NULL, 'inquam', 0, 0, 0, 1, 15, '2025-07-28 10:19:07.991574', '2025-07-28 10:19:07.991574'),
+(2403, 0, NULL, NULL, 'inquis', NULL, 'inquam', 0, 0, 0, 2, 15, '2025-07-28 10:19:08.001509', '2025-07-28 10:19:08.001509'),
+(2404, 1, NULL, NULL, 'inquitis', NULL, 'inquam', 0, 0, 0, 2, 15, '2025-07-28 10:19:08.004004', '2025-07-28 10:19:08.004004'),
+(2405, 0, NULL, NULL, 'inquit', NULL, 'inquam', 0, 0, 0, 3, 15, '2025-07-28 10:19:08.006376', '2025-07-28 10:19:08.006376'),
+(2406, 1, NULL, NULL, 'inquiunt', NULL, 'inquam', 0, 0, 0, 3, 15, '2025-07-28 10:19:08.008538', '2025-07-28 10:19:08.008538'),
+(2407, 0, NULL, NULL, 'inquiēbat', NULL, 'inquam', 1, 0, 0, 3, 15, '2025-07-28 10:19:08.010911', '2025-07-28 10:19:08.010911'),
+(2408, 1, NULL, NULL, NULL, NULL, 'inquam', 1, 0, 0, 3, 15, '2025-07-28 10:19:08.013167', '2025-07-28 10:19:08.013167'),
+(2409, 0, NULL, NULL, 'inquiī', NULL, 'inquam', 2, 0, 0, 1, 15, '2025-07-28 10:19:08.015403', '2025-07-28 10:19:08.015403'),
+(2410, 1, NULL, NULL, NULL, NULL, 'inquam', 2, 0, 0, 1, 15, '2025-07-28 10:19:08.017585', '2025-07-28 10:19:08.017585'),
+(2411, 0, NULL, NULL, 'inquistī', NULL, 'inquam', 2, 0, 0, 2, 15, '2025-07-28 10:19:08.019894', '2025-07-28 10:19:08.019894'),
+(2412, 1, NULL, NULL, NULL, NULL, 'inquam', 2, 0, 0, 2, 15, '2025-07-28 10:19:08.022124', '2025-07-28 10:19:08.022124'),
+(2413, 0, NULL, NULL, 'inquit', NULL, 'inquam', 2, 0, 0, 3, 15, '2025-07-28 10:19:08.029385', '2025-07-28 10:19:08.029385'),
+(2414, 1, NULL, NULL, NULL, NULL, 'inquam', 2, 0, 0, 3, 15, '2025-07-28 10:19:08.031846', '2025-07-28 10:19:08.031846'),
+(2415, 0, NULL, NULL, 'inquiēs', NULL, 'inquam', 4, 0, 0, 2, 15, '2025-07-28 10:19:08.035682', '2025-07-28 10:19:08.035682'),
+(2416, 1, NULL, NULL, NULL, NULL, 'inquam', 4, 0, 0, 2, 15, '2025-07-28 10:19:08.037755', '2025-07-28 10:19:08.037755'),
+(2417, 0, NULL, NULL, 'inquiet', NULL, 'inquam', 4, 0, 0, 3, 15, '2025-07-28 10:19:08.041542', '2025-07-28 10:19:08.041542'),
+(2418, 1, NULL, NULL, NULL, NULL, 'inquam', 4, 0, 0, 3, 15, '2025-07-28 10:19:08.043652', '2025-07-28 10:19:08.043652'),
+(2419, 0, NULL, NULL, 'inquiat', NULL, 'inquam', 0, 1, 0, 3, 15, '2025-07-28 10:19:08.047339', '2025-07-28 10:19:08.047339'),
+(2420, 1, NULL, NULL, NULL, NULL, 'inquam', 0, 1, 0, 3, 15, '2025-07-28 10:19:08.049428', '2025-07-28 10:19:08.049428'),
+(2421, 0, NULL, NULL, 'inque', NULL, 'inquam', 0, 2, 0, 2, 15, '2025-07-28 10:19:08.053004', '2025-07-28 10:19:08.053004'),
+(2422, 1, NULL, NULL, NULL, NULL, 'inquam', 0, 2, 0, 2, 15, '2025-07-28 10:19:08.056514', '2025-07-28 10:19:08.056514'),
+(2423, 0, NULL, NULL, 'inquitō', NULL, 'inquam', 4, 2, 0, 2, 15, '2025-07-28 10:19:08.058843', '2025-07-28 10:19:08.058843'),
+(2424, 1, NULL, NULL, NULL, NULL, 'inquam', 4, 2, 0, 2, 15, '2025-07-28 10:19:08.061061', '2025-07-28 10:19:08.061061'),
+(2425, 0, NULL, NULL, 'inquitō', NULL, 'inquam', 4, 2, 0, 3, 15, '2025-07-28 10:19:08.064768', '2025-07-28 10:19:08.064768'),
+(2426, 1, NULL, NULL, NULL, NULL, 'inquam', 4, 2, 0, 3, 15, '2025-07-28 10:19:08.068249', '2025-07-28 10:19:08.068249'),
+(2427, 0, NULL, NULL, 'inquiēns', NULL, 'inquam', 0, 8, 0, 1, 15, '2025-07-28 10:19:08.070528', '2025-07-28 10:19:08.070528'),
+(2428, 1, NULL, NULL, NULL, NULL, 'inquam', 0, 8, 0, 1, 15, '2025-07-28 10:19:08.072673', '2025-07-28 10:19:08.072673'),
+(2429, 0, NULL, NULL, 'aiō', NULL, 'aio', 0, 0, 0, 1, 16, '2025-07-28 10:19:08.082207', '2025-07-28 10:19:08.082207'),
+(2430, 1, NULL, NULL, NULL, NULL, 'aio', 0, 0, 0, 1, 16, '2025-07-28 10:19:08.084357', '2025-07-28 10:19:08.084357'),
+(2431, 0, NULL, NULL, 'ais', NULL, 'aio', 0, 0, 0, 2, 16, '2025-07-28 10:19:08.087866', '2025-07-28 10:19:08.087866'),
+(2432, 1, NULL, NULL, NULL, NULL, 'aio', 0, 0, 0, 2, 16, '2025-07-28 10:19:08.090282', '2025-07-28 10:19:08.090282'),
+(2433, 0, NULL, NULL, 'ait', NULL, 'aio', 0, 0, 0, 3, 16, '2025-07-28 10:19:08.094088', '2025-07-28 10:19:08.094088'),
+(2434, 1, NULL, NULL, 'aiunt', NULL, 'aio', 0, 0, 0, 3, 16, '2025-07-28 10:19:08.096315', '2025-07-28 10:19:08.096315'),
+(2435, 0, NULL, NULL, 'aiēbam', NULL, 'aio', 1, 0, 0, 1, 16, '2025-07-28 10:19:08.099976', '2025-07-28 10:19:08.099976'),
+(2436, 1, NULL, NULL, 'aiēbāmus', NULL, 'aio', 1, 0, 0, 1, 16, '2025-07-28 10:19:08.102204', '2025-07-28 10:19:08.102204'),
+(2437, 0, NULL, NULL, 'aiēbās', NULL, 'aio', 1, 0, 0, 2, 16, '2025-07-28 10:19:08.105719', '2025-07-28 10:19:08.105719'),
+(2438, 1, NULL, NULL, 'aiēbātis', NULL, 'aio', 1, 0, 0, 2, 16, '2025-07-28 10:19:08.108142', '2025-07-28 10:19:08.108142'),
+(2439, 0, NULL, NULL, 'aiēbat', NULL, 'aio', 1, 0, 0, 3, 16, '2025-07-28 10:19:08.112165', '2025-07-28 10:19:08.112165'),
+(2440, 1, NULL, NULL, 'aiēbant', NULL, 'aio', 1, 0, 0, 3, 16, '2025-07-28 10:19:08.114543', '2025-07-28 10:19:08.114543'),
+(2441, 0, NULL, NULL, 'aistī', NULL, 'aio', 2, 0, 0, 2, 16, '2025-07-28 10:19:08.118535', '2025-07-28 10:19:08.118535'),
+(2442, 1, NULL, NULL, NULL, NULL, 'aio', 2, 0, 0, 2, 16, '2025-07-28 10:19:08.120903', '2025-07-28 10:19:08.120903'),
+(2443, 0, NULL, NULL, 'ait', NULL, 'aio', 2, 0, 0, 3, 16, '2025-07-28 10:19:08.124634', '2025-07-28 10:19:08.124634'),
+(2444, 1, NULL, NULL, NULL, NULL, 'aio', 2, 0, 0, 3, 16, '2025-07-28 10:19:08.127278', '2025-07-28 10:19:08.127278'),
+(2445, 0, NULL, NULL, 'aiās', NULL, 'aio', 0, 1, 0, 2, 16, '2025-07-28 10:19:08.129762', '2025-07-28 10:19:08.129762'),
+(2446, 1, NULL, NULL, NULL, NULL, 'aio', 0, 1, 0, 2, 16, '2025-07-28 10:19:08.133738', '2025-07-28 10:19:08.133738'),
+(2447, 0, NULL, NULL, 'aiant', NULL, 'aio', 0, 1, 0, 3, 16, '2025-07-28 10:19:08.136106', '2025-07-28 10:19:08.136106'),
+(2448, 1, NULL, NULL, NULL, NULL, 'aio', 0, 1, 0, 3, 16, '2025-07-28 10:19:08.139678', '2025-07-28 10:19:08.139678'),
+(2449, 0, NULL, NULL, 'ai', NULL, 'aio', 0, 2, 0, 2, 16, '2025-07-28 10:19:08.142093', '2025-07-28 10:19:08.142093'),
+(2450, 1, NULL, NULL, NULL, NULL, 'aio', 0, 2, 0, 2, 16, '2025-07-28 10:19:08.145394', '2025-07-28 10:19:08.145394'),
+(2451, 0, NULL, NULL, 'aiere', NULL, 'aio', 0, 6, 0, 1, 16, '2025-07-28 10:19:08.147774', '2025-07-28 10:19:08.147774'),
+(2452, 1, NULL, NULL, NULL, NULL, 'aio', 0, 6, 0, 1, 16, '2025-07-28 10:19:08.150114', '2025-07-28 10:19:08.150114'),
+(2453, 0, NULL, NULL, 'aiēns', NULL, 'aio', 0, 8, 0, 1, 16, '2025-07-28 10:19:08.153314', '2025-07-28 10:19:08.153314'),
+(2454, 1, NULL, NULL, NULL, NULL, 'aio', 0, 8, 0, 1, 16, '2025-07-28 10:19:08.155610', '2025-07-28 10:19:08.155610'),
+(2455, 0, 0, 0, 'quis', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.165180', '2025-07-28 10:19:08.165180'),
+(2456, 1, 0, 0, 'quī', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.168372', '2025-07-28 10:19:08.168372'),
+(2457, 0, 1, 0, 'quis', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.170707', '2025-07-28 10:19:08.170707'),
+(2458, 1, 1, 0, 'quae', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.173009', '2025-07-28 10:19:08.173009'),
+(2459, 0, 3, 0, 'quid', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.176069', '2025-07-28 10:19:08.176069'),
+(2460, 1, 3, 0, 'quae', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.178512', '2025-07-28 10:19:08.178512'),
+(2461, 0, 0, 2, 'quem', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.181486', '2025-07-28 10:19:08.181486'),
+(2462, 1, 0, 2, 'quōs', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.183764', '2025-07-28 10:19:08.183764'),
+(2463, 0, 1, 2, 'quem', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.186035', '2025-07-28 10:19:08.186035'),
+(2464, 1, 1, 2, 'quās', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.189152', '2025-07-28 10:19:08.189152'),
+(2465, 0, 3, 2, 'quid', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.191594', '2025-07-28 10:19:08.191594'),
+(2466, 1, 3, 2, 'quae', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.193966', '2025-07-28 10:19:08.193966'),
+(2467, 0, 0, 3, 'cuius', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.196939', '2025-07-28 10:19:08.196939'),
+(2468, 1, 0, 3, 'quōrum', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.199201', '2025-07-28 10:19:08.199201'),
+(2469, 0, 1, 3, 'cuius', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.201973', '2025-07-28 10:19:08.201973'),
+(2470, 1, 1, 3, 'quārum', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.205525', '2025-07-28 10:19:08.205525'),
+(2471, 0, 3, 3, 'cuius', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.207846', '2025-07-28 10:19:08.207846'),
+(2472, 1, 3, 3, 'quōrum', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.210138', '2025-07-28 10:19:08.210138'),
+(2473, 0, 0, 4, 'cui', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.212484', '2025-07-28 10:19:08.212484'),
+(2474, 1, 0, 4, 'quibus', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.214797', '2025-07-28 10:19:08.214797'),
+(2475, 0, 1, 4, 'cui', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.217080', '2025-07-28 10:19:08.217080'),
+(2476, 1, 1, 4, 'quīs', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.219388', '2025-07-28 10:19:08.219388'),
+(2477, 1, 3, 4, 'quibus', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.221679', '2025-07-28 10:19:08.221679'),
+(2478, 0, 3, 4, 'cui', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.224073', '2025-07-28 10:19:08.224073'),
+(2479, 0, 0, 5, 'quō', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.226381', '2025-07-28 10:19:08.226381'),
+(2480, 1, 0, 5, 'quibus', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.228808', '2025-07-28 10:19:08.228808'),
+(2481, 0, 1, 5, 'quō', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.231102', '2025-07-28 10:19:08.231102'),
+(2482, 1, 1, 5, 'quīs', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.233404', '2025-07-28 10:19:08.233404'),
+(2483, 0, 3, 5, 'quō', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.235688', '2025-07-28 10:19:08.235688'),
+(2484, 1, 3, 5, 'quibus', 7, 'interrogative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.238722', '2025-07-28 10:19:08.238722'),
+(2485, 0, 0, 0, 'quī', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.245297', '2025-07-28 10:19:08.245297'),
+(2486, 1, 0, 0, 'quī', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.247719', '2025-07-28 10:19:08.247719'),
+(2487, 0, 1, 0, 'quae', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.250041', '2025-07-28 10:19:08.250041'),
+(2488, 1, 1, 0, 'quae', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.252305', '2025-07-28 10:19:08.252305'),
+(2489, 0, 3, 0, 'quod', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.254612', '2025-07-28 10:19:08.254612'),
+(2490, 1, 3, 0, 'quae', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.256973', '2025-07-28 10:19:08.256973'),
+(2491, 0, 0, 2, 'quem', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.259219', '2025-07-28 10:19:08.259219'),
+(2492, 1, 0, 2, 'quōs', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.261584', '2025-07-28 10:19:08.261584'),
+(2493, 0, 1, 2, 'quam', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.263926', '2025-07-28 10:19:08.263926'),
+(2494, 1, 1, 2, 'quās', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.266171', '2025-07-28 10:19:08.266171'),
+(2495, 0, 3, 2, 'quod', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.268407', '2025-07-28 10:19:08.268407'),
+(2496, 1, 3, 2, 'quae', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.270645', '2025-07-28 10:19:08.270645'),
+(2497, 0, 0, 3, 'cuius', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.272938', '2025-07-28 10:19:08.272938'),
+(2498, 1, 0, 3, 'quōrum', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.275218', '2025-07-28 10:19:08.275218'),
+(2499, 0, 1, 3, 'cuius', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.277438', '2025-07-28 10:19:08.277438'),
+(2500, 1, 1, 3, 'quārum', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.279803', '2025-07-28 10:19:08.279803'),
+(2501, 0, 3, 3, 'cuius', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.282040', '2025-07-28 10:19:08.282040'),
+(2502, 1, 3, 3, 'quōrum', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.284272', '2025-07-28 10:19:08.284272'),
+(2503, 0, 0, 4, 'cuī', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.286523', '2025-07-28 10:19:08.286523'),
+(2504, 1, 0, 4, 'quibus', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.288731', '2025-07-28 10:19:08.288731'),
+(2505, 0, 1, 4, 'cuī', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.291037', '2025-07-28 10:19:08.291037'),
+(2506, 1, 1, 4, 'quibus', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.298775', '2025-07-28 10:19:08.298775'),
+(2507, 0, 3, 4, 'cuī', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.301189', '2025-07-28 10:19:08.301189'),
+(2508, 1, 3, 4, 'quibus', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.305264', '2025-07-28 10:19:08.305264'),
+(2509, 0, 0, 5, 'quō', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.307707', '2025-07-28 10:19:08.307707'),
+(2510, 1, 0, 5, 'quibus', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.311705', '2025-07-28 10:19:08.311705'),
+(2511, 0, 1, 5, 'quā', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.314104', '2025-07-28 10:19:08.314104'),
+(2512, 1, 1, 5, 'quibus', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.318024', '2025-07-28 10:19:08.318024'),
+(2513, 0, 3, 5, 'quō', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.320462', '2025-07-28 10:19:08.320462'),
+(2514, 1, 3, 5, 'quibus', 8, 'relative', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.324414', '2025-07-28 10:19:08.324414'),
+(2515, 0, NULL, 0, 'ego', 9, 'egonos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.333328', '2025-07-28 10:19:08.333328'),
+(2516, 1, NULL, 0, 'nōs', 9, 'egonos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.337453', '2025-07-28 10:19:08.337453'),
+(2517, 0, NULL, 2, 'mē', 9, 'egonos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.339917', '2025-07-28 10:19:08.339917'),
+(2518, 1, NULL, 2, 'nōs', 9, 'egonos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.344158', '2025-07-28 10:19:08.344158'),
+(2519, 0, NULL, 3, 'meī', 9, 'egonos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.346644', '2025-07-28 10:19:08.346644'),
+(2520, 1, NULL, 3, 'nostrī, nostrum', 9, 'egonos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.350839', '2025-07-28 10:19:08.350839'),
+(2521, 0, NULL, 4, 'mihi', 9, 'egonos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.353166', '2025-07-28 10:19:08.353166'),
+(2522, 1, NULL, 4, 'nōbīs', 9, 'egonos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.357528', '2025-07-28 10:19:08.357528'),
+(2523, 0, NULL, 5, 'mē', 9, 'egonos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.359927', '2025-07-28 10:19:08.359927'),
+(2524, 1, NULL, 5, 'nōbīs', 9, 'egonos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.364162', '2025-07-28 10:19:08.364162'),
+(2525, 0, 0, 0, 'is', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.372286', '2025-07-28 10:19:08.372286'),
+(2526, 1, 0, 0, 'eī, iī', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.376091', '2025-07-28 10:19:08.376091'),
+(2527, 0, 1, 0, 'ea', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.378917', '2025-07-28 10:19:08.378917'),
+(2528, 1, 1, 0, 'eae', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.382576', '2025-07-28 10:19:08.382576'),
+(2529, 0, 3, 0, 'id', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.385234', '2025-07-28 10:19:08.385234'),
+(2530, 1, 3, 0, 'ea', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.387605', '2025-07-28 10:19:08.387605'),
+(2531, 0, 0, 2, 'eum', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.391992', '2025-07-28 10:19:08.391992'),
+(2532, 1, 0, 2, 'eōs', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.394425', '2025-07-28 10:19:08.394425'),
+(2533, 0, 1, 2, 'eam', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.398542', '2025-07-28 10:19:08.398542'),
+(2534, 1, 1, 2, 'eās', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.400903', '2025-07-28 10:19:08.400903'),
+(2535, 0, 3, 2, 'id', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.404921', '2025-07-28 10:19:08.404921'),
+(2536, 1, 3, 2, 'ea', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.407257', '2025-07-28 10:19:08.407257'),
+(2537, 0, 0, 3, 'eius', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.411293', '2025-07-28 10:19:08.411293'),
+(2538, 1, 0, 3, 'eōrum', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.413674', '2025-07-28 10:19:08.413674'),
+(2539, 0, 1, 3, 'eius', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.416044', '2025-07-28 10:19:08.416044'),
+(2540, 1, 1, 3, 'eārum', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.419346', '2025-07-28 10:19:08.419346'),
+(2541, 0, 3, 3, 'eius', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.421682', '2025-07-28 10:19:08.421682'),
+(2542, 1, 3, 3, 'eōrum', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.425091', '2025-07-28 10:19:08.425091'),
+(2543, 0, 0, 4, 'eī', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.427419', '2025-07-28 10:19:08.427419'),
+(2544, 1, 0, 4, 'eīs, iīs', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.430789', '2025-07-28 10:19:08.430789'),
+(2545, 0, 1, 4, 'eī', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.433089', '2025-07-28 10:19:08.433089'),
+(2546, 1, 1, 4, 'eīs, iīs', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.435423', '2025-07-28 10:19:08.435423'),
+(2547, 0, 3, 4, 'eī', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.438766', '2025-07-28 10:19:08.438766'),
+(2548, 1, 3, 4, 'eīs, iīs', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.441072', '2025-07-28 10:19:08.441072'),
+(2549, 0, 0, 5, 'eō', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.444309', '2025-07-28 10:19:08.444309'),
+(2550, 1, 0, 5, 'eīs, iīs', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.446687', '2025-07-28 10:19:08.446687'),
+(2551, 0, 1, 5, 'eā', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.449176', '2025-07-28 10:19:08.449176'),
+(2552, 1, 1, 5, 'eīs, iīs', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.452175', '2025-07-28 10:19:08.452175'),
+(2553, 0, 3, 5, 'eō', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.454434', '2025-07-28 10:19:08.454434'),
+(2554, 1, 3, 5, 'eīs, iīs', 10, 'demonstrative-weak', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.456746', '2025-07-28 10:19:08.456746'),
+(2555, 0, 0, 0, 'hic', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.464131', '2025-07-28 10:19:08.464131'),
+(2556, 1, 0, 0, 'hī', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.467263', '2025-07-28 10:19:08.467263'),
+(2557, 0, 1, 0, 'haec', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.469592', '2025-07-28 10:19:08.469592'),
+(2558, 1, 1, 0, 'hae', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.472755', '2025-07-28 10:19:08.472755'),
+(2559, 0, 3, 0, 'hoc', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.478157', '2025-07-28 10:19:08.478157'),
+(2560, 1, 3, 0, 'haec', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.480560', '2025-07-28 10:19:08.480560'),
+(2561, 0, 0, 2, 'hunc', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.482870', '2025-07-28 10:19:08.482870'),
+(2562, 1, 0, 2, 'hōs', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.485182', '2025-07-28 10:19:08.485182'),
+(2563, 0, 1, 2, 'hanc', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.487532', '2025-07-28 10:19:08.487532'),
+(2564, 1, 1, 2, 'hās', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.489928', '2025-07-28 10:19:08.489928'),
+(2565, 0, 3, 2, 'hoc', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.492323', '2025-07-28 10:19:08.492323'),
+(2566, 1, 3, 2, 'haec', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.494674', '2025-07-28 10:19:08.494674'),
+(2567, 0, 0, 3, 'huius', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.497108', '2025-07-28 10:19:08.497108'),
+(2568, 1, 0, 3, 'hōrum', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.499382', '2025-07-28 10:19:08.499382'),
+(2569, 0, 1, 3, 'huius', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.501708', '2025-07-28 10:19:08.501708'),
+(2570, 1, 1, 3, 'hārum', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.503990', '2025-07-28 10:19:08.503990'),
+(2571, 0, 3, 3, 'huius', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.506250', '2025-07-28 10:19:08.506250'),
+(2572, 1, 3, 3, 'hōrum', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.508554', '2025-07-28 10:19:08.508554'),
+(2573, 0, 0, 4, 'huic', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.510839', '2025-07-28 10:19:08.510839'),
+(2574, 1, 0, 4, 'hīs', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.513126', '2025-07-28 10:19:08.513126'),
+(2575, 0, 1, 4, 'huic', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.515370', '2025-07-28 10:19:08.515370'),
+(2576, 1, 1, 4, 'hīs', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.517612', '2025-07-28 10:19:08.517612'),
+(2577, 0, 3, 4, 'huic', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.519937', '2025-07-28 10:19:08.519937'),
+(2578, 1, 3, 4, 'hīs', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.522146', '2025-07-28 10:19:08.522146'),
+(2579, 0, 0, 5, 'hōc', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.524434', '2025-07-28 10:19:08.524434'),
+(2580, 1, 0, 5, 'hīs', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.526663', '2025-07-28 10:19:08.526663'),
+(2581, 0, 1, 5, 'hāc', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.528980', '2025-07-28 10:19:08.528980'),
+(2582, 1, 1, 5, 'hīs', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.531207', '2025-07-28 10:19:08.531207'),
+(2583, 0, 3, 5, 'hōc', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.533479', '2025-07-28 10:19:08.533479'),
+(2584, 1, 3, 5, 'hīs', 11, 'demonstrative-proximal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.535688', '2025-07-28 10:19:08.535688'),
+(2585, 0, 0, 0, 'ille', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.543400', '2025-07-28 10:19:08.543400'),
+(2586, 1, 0, 0, 'illī', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.545794', '2025-07-28 10:19:08.545794'),
+(2587, 0, 1, 0, 'illa', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.548129', '2025-07-28 10:19:08.548129'),
+(2588, 1, 1, 0, 'illae', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.550376', '2025-07-28 10:19:08.550376'),
+(2589, 0, 3, 0, 'illud', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.552716', '2025-07-28 10:19:08.552716'),
+(2590, 1, 3, 0, 'illa', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.560815', '2025-07-28 10:19:08.560815'),
+(2591, 0, 0, 2, 'illum', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.563399', '2025-07-28 10:19:08.563399'),
+(2592, 1, 0, 2, 'illōs', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.567562', '2025-07-28 10:19:08.567562'),
+(2593, 0, 1, 2, 'illam', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.570014', '2025-07-28 10:19:08.570014'),
+(2594, 1, 1, 2, 'illās', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.574048', '2025-07-28 10:19:08.574048'),
+(2595, 0, 3, 2, 'illud', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.576488', '2025-07-28 10:19:08.576488'),
+(2596, 1, 3, 2, 'illa', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.580456', '2025-07-28 10:19:08.580456'),
+(2597, 0, 0, 3, 'illīus', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.582880', '2025-07-28 10:19:08.582880'),
+(2598, 1, 0, 3, 'illōrum', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.586767', '2025-07-28 10:19:08.586767'),
+(2599, 0, 1, 3, 'illīus', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.590507', '2025-07-28 10:19:08.590507'),
+(2600, 1, 1, 3, 'illārum', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.593214', '2025-07-28 10:19:08.593214'),
+(2601, 0, 3, 3, 'illīus', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.597293', '2025-07-28 10:19:08.597293'),
+(2602, 1, 3, 3, 'illōrum', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.599712', '2025-07-28 10:19:08.599712'),
+(2603, 0, 0, 4, 'illī', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.603785', '2025-07-28 10:19:08.603785'),
+(2604, 1, 0, 4, 'illīs', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.606081', '2025-07-28 10:19:08.606081'),
+(2605, 0, 1, 4, 'illī', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.610258', '2025-07-28 10:19:08.610258'),
+(2606, 1, 1, 4, 'illīs', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.612721', '2025-07-28 10:19:08.612721'),
+(2607, 0, 3, 4, 'illī', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.616294', '2025-07-28 10:19:08.616294'),
+(2608, 1, 3, 4, 'illīs', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.619070', '2025-07-28 10:19:08.619070'),
+(2609, 0, 0, 5, 'illō', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.621545', '2025-07-28 10:19:08.621545'),
+(2610, 1, 0, 5, 'illīs', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.625828', '2025-07-28 10:19:08.625828'),
+(2611, 0, 1, 5, 'illā', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.629576', '2025-07-28 10:19:08.629576'),
+(2612, 1, 1, 5, 'illīs', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.632336', '2025-07-28 10:19:08.632336'),
+(2613, 0, 3, 5, 'illō', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.635954', '2025-07-28 10:19:08.635954'),
+(2614, 1, 3, 5, 'illīs', 12, 'demonstrative-distal', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.638629', '2025-07-28 10:19:08.638629'),
+(2615, 0, 0, 0, 'iste', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.650242', '2025-07-28 10:19:08.650242'),
+(2616, 1, 0, 0, 'istī', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.654456', '2025-07-28 10:19:08.654456'),
+(2617, 0, 1, 0, 'ista', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.656886', '2025-07-28 10:19:08.656886'),
+(2618, 1, 1, 0, 'istae', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.660946', '2025-07-28 10:19:08.660946'),
+(2619, 0, 3, 0, 'istud', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.663399', '2025-07-28 10:19:08.663399'),
+(2620, 1, 3, 0, 'ista', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.667016', '2025-07-28 10:19:08.667016'),
+(2621, 0, 0, 2, 'istum', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.669319', '2025-07-28 10:19:08.669319'),
+(2622, 1, 0, 2, 'istōs', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.672726', '2025-07-28 10:19:08.672726'),
+(2623, 0, 1, 2, 'istam', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.675245', '2025-07-28 10:19:08.675245'),
+(2624, 1, 1, 2, 'istās', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.677678', '2025-07-28 10:19:08.677678'),
+(2625, 0, 3, 2, 'istud', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.681198', '2025-07-28 10:19:08.681198'),
+(2626, 1, 3, 2, 'ista', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.683601', '2025-07-28 10:19:08.683601'),
+(2627, 0, 0, 3, 'istīus', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.687103', '2025-07-28 10:19:08.687103'),
+(2628, 1, 0, 3, 'istōrum', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.689405', '2025-07-28 10:19:08.689405'),
+(2629, 0, 1, 3, 'istīus', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.691842', '2025-07-28 10:19:08.691842'),
+(2630, 1, 1, 3, 'istārum', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.695226', '2025-07-28 10:19:08.695226'),
+(2631, 0, 3, 3, 'istīus', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.697640', '2025-07-28 10:19:08.697640'),
+(2632, 1, 3, 3, 'istōrum', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.701063', '2025-07-28 10:19:08.701063'),
+(2633, 0, 0, 4, 'istī', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.703369', '2025-07-28 10:19:08.703369'),
+(2634, 1, 0, 4, 'istīs', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.706616', '2025-07-28 10:19:08.706616'),
+(2635, 0, 1, 4, 'istī', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.709020', '2025-07-28 10:19:08.709020'),
+(2636, 1, 1, 4, 'istīs', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.711342', '2025-07-28 10:19:08.711342'),
+(2637, 0, 3, 4, 'istī', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.714417', '2025-07-28 10:19:08.714417'),
+(2638, 1, 3, 4, 'istīs', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.716763', '2025-07-28 10:19:08.716763'),
+(2639, 0, 0, 5, 'istō', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.719071', '2025-07-28 10:19:08.719071'),
+(2640, 1, 0, 5, 'istīs', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.722063', '2025-07-28 10:19:08.722063'),
+(2641, 0, 1, 5, 'istā', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.724439', '2025-07-28 10:19:08.724439'),
+(2642, 1, 1, 5, 'istīs', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.727497', '2025-07-28 10:19:08.727497'),
+(2643, 0, 3, 5, 'istō', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.729880', '2025-07-28 10:19:08.729880'),
+(2644, 1, 3, 5, 'istīs', 13, 'demonstrative-medial', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.735710', '2025-07-28 10:19:08.735710'),
+(2645, 0, 0, 0, 'īdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.746593', '2025-07-28 10:19:08.746593'),
+(2646, 1, 0, 0, 'īdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.749008', '2025-07-28 10:19:08.749008'),
+(2647, 0, 1, 0, 'eadem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.751375', '2025-07-28 10:19:08.751375'),
+(2648, 1, 1, 0, 'eaedem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.753762', '2025-07-28 10:19:08.753762'),
+(2649, 0, 3, 0, 'idem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.756116', '2025-07-28 10:19:08.756116'),
+(2650, 1, 3, 0, 'eadem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.758554', '2025-07-28 10:19:08.758554'),
+(2651, 0, 0, 2, 'eundem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.760941', '2025-07-28 10:19:08.760941'),
+(2652, 1, 0, 2, 'eōsdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.763459', '2025-07-28 10:19:08.763459'),
+(2653, 0, 1, 2, 'eandem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.765821', '2025-07-28 10:19:08.765821'),
+(2654, 1, 1, 2, 'eāsdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.768145', '2025-07-28 10:19:08.768145'),
+(2655, 0, 3, 2, 'idem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.770375', '2025-07-28 10:19:08.770375'),
+(2656, 1, 3, 2, 'eadem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.772633', '2025-07-28 10:19:08.772633'),
+(2657, 0, 0, 3, 'eiusdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.774983', '2025-07-28 10:19:08.774983'),
+(2658, 1, 0, 3, 'eōrundem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.777241', '2025-07-28 10:19:08.777241'),
+(2659, 0, 1, 3, 'eiusdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.779599', '2025-07-28 10:19:08.779599'),
+(2660, 1, 1, 3, 'eārundem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.781884', '2025-07-28 10:19:08.781884'),
+(2661, 0, 3, 3, 'eiusdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.784126', '2025-07-28 10:19:08.784126'),
+(2662, 1, 3, 3, 'eōrundem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.786407', '2025-07-28 10:19:08.786407'),
+(2663, 0, 0, 4, 'eīdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.788663', '2025-07-28 10:19:08.788663'),
+(2664, 1, 0, 4, 'eīsdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.790946', '2025-07-28 10:19:08.790946'),
+(2665, 0, 1, 4, 'eīdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.793170', '2025-07-28 10:19:08.793170'),
+(2666, 1, 1, 4, 'eīsdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.795516', '2025-07-28 10:19:08.795516'),
+(2667, 0, 3, 4, 'eīdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.797812', '2025-07-28 10:19:08.797812'),
+(2668, 1, 3, 4, 'eīsdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.800041', '2025-07-28 10:19:08.800041'),
+(2669, 0, 0, 5, 'eōdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.802283', '2025-07-28 10:19:08.802283'),
+(2670, 1, 0, 5, 'eīsdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.804513', '2025-07-28 10:19:08.804513'),
+(2671, 0, 1, 5, 'eādem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.812606', '2025-07-28 10:19:08.812606'),
+(2672, 1, 1, 5, 'eīsdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.816414', '2025-07-28 10:19:08.816414'),
+(2673, 0, 3, 5, 'eōdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.819121', '2025-07-28 10:19:08.819121'),
+(2674, 1, 3, 5, 'eīsdem', 14, 'demonstrative-idem', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.823103', '2025-07-28 10:19:08.823103'),
+(2675, 0, 0, 0, 'ipse', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.831381', '2025-07-28 10:19:08.831381'),
+(2676, 1, 0, 0, 'ipsī', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.833848', '2025-07-28 10:19:08.833848'),
+(2677, 0, 1, 0, 'ipsa', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.837813', '2025-07-28 10:19:08.837813'),
+(2678, 1, 1, 0, 'ipsae', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.841447', '2025-07-28 10:19:08.841447'),
+(2679, 0, 3, 0, 'ipsum', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.844142', '2025-07-28 10:19:08.844142'),
+(2680, 1, 3, 0, 'ipsa', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.848058', '2025-07-28 10:19:08.848058'),
+(2681, 0, 0, 2, 'ipsum', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.850444', '2025-07-28 10:19:08.850444'),
+(2682, 1, 0, 2, 'ipsōs', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.854451', '2025-07-28 10:19:08.854451'),
+(2683, 0, 1, 2, 'ipsam', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.856794', '2025-07-28 10:19:08.856794'),
+(2684, 1, 1, 2, 'ipsās', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.860463', '2025-07-28 10:19:08.860463'),
+(2685, 0, 3, 2, 'ipsum', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.863257', '2025-07-28 10:19:08.863257'),
+(2686, 1, 3, 2, 'ipsa', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.866904', '2025-07-28 10:19:08.866904'),
+(2687, 0, 0, 3, 'ipsīus', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.869622', '2025-07-28 10:19:08.869622'),
+(2688, 1, 0, 3, 'ipsōrum', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.873153', '2025-07-28 10:19:08.873153'),
+(2689, 0, 1, 3, 'ipsīus', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.876045', '2025-07-28 10:19:08.876045'),
+(2690, 1, 1, 3, 'ipsārum', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.879691', '2025-07-28 10:19:08.879691'),
+(2691, 0, 3, 3, 'ipsīus', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.882401', '2025-07-28 10:19:08.882401'),
+(2692, 1, 3, 3, 'ipsōrum', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.886326', '2025-07-28 10:19:08.886326'),
+(2693, 0, 0, 4, 'ipsī', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.888671', '2025-07-28 10:19:08.888671'),
+(2694, 1, 0, 4, 'ipsīs', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.892935', '2025-07-28 10:19:08.892935'),
+(2695, 0, 1, 4, 'ipsī', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.895325', '2025-07-28 10:19:08.895325'),
+(2696, 1, 1, 4, 'ipsīs', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.899469', '2025-07-28 10:19:08.899469'),
+(2697, 0, 3, 4, 'ipsī', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.901816', '2025-07-28 10:19:08.901816'),
+(2698, 1, 3, 4, 'ipsīs', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.905372', '2025-07-28 10:19:08.905372'),
+(2699, 0, 0, 5, 'ipsō', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.908060', '2025-07-28 10:19:08.908060'),
+(2700, 1, 0, 5, 'ipsīs', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.910429', '2025-07-28 10:19:08.910429'),
+(2701, 0, 1, 5, 'ipsā', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.913969', '2025-07-28 10:19:08.913969'),
+(2702, 1, 1, 5, 'ipsīs', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.916321', '2025-07-28 10:19:08.916321'),
+(2703, 0, 3, 5, 'ipsō', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.919687', '2025-07-28 10:19:08.919687'),
+(2704, 1, 3, 5, 'ipsīs', 15, 'intensive', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.922079', '2025-07-28 10:19:08.922079'),
+(2705, 0, NULL, 0, 'tū', 16, 'tuvos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.929570', '2025-07-28 10:19:08.929570'),
+(2706, 1, NULL, 0, 'vōs', 16, 'tuvos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.932931', '2025-07-28 10:19:08.932931'),
+(2707, 0, NULL, 2, 'tē', 16, 'tuvos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.935267', '2025-07-28 10:19:08.935267'),
+(2708, 1, NULL, 2, 'vōs', 16, 'tuvos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.938640', '2025-07-28 10:19:08.938640'),
+(2709, 0, NULL, 3, 'tuī', 16, 'tuvos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.941074', '2025-07-28 10:19:08.941074'),
+(2710, 1, NULL, 3, 'vestrī, vestrum', 16, 'tuvos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.943505', '2025-07-28 10:19:08.943505'),
+(2711, 0, NULL, 4, 'tibi', 16, 'tuvos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.947118', '2025-07-28 10:19:08.947118'),
+(2712, 1, NULL, 4, 'vōbīs', 16, 'tuvos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.949498', '2025-07-28 10:19:08.949498'),
+(2713, 0, NULL, 5, 'tē', 16, 'tuvos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.952830', '2025-07-28 10:19:08.952830'),
+(2714, 1, NULL, 5, 'vōbīs', 16, 'tuvos', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.955147', '2025-07-28 10:19:08.955147'),
+(2715, 0, NULL, 0, '', 17, 'sesui', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.962510', '2025-07-28 10:19:08.962510'),
+(2716, 1, NULL, 0, '', 17, 'sesui', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.970179', '2025-07-28 10:19:08.970179'),
+(2717, 0, NULL, 2, 'sē', 17, 'sesui', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.972552', '2025-07-28 10:19:08.972552'),
+(2718, 1, NULL, 2, 'sē', 17, 'sesui', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.975052', '2025-07-28 10:19:08.975052'),
+(2719, 0, NULL, 3, 'suī', 17, 'sesui', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.977354', '2025-07-28 10:19:08.977354'),
+(2720, 1, NULL, 3, 'suī', 17, 'sesui', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.979852', '2025-07-28 10:19:08.979852'),
+(2721, 0, NULL, 4, 'sibi', 17, 'sesui', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.982208', '2025-07-28 10:19:08.982208'),
+(2722, 1, NULL, 4, 'sibi', 17, 'sesui', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.984580', '2025-07-28 10:19:08.984580'),
+(2723, 0, NULL, 5, 'sē', 17, 'sesui', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.986935', '2025-07-28 10:19:08.986935'),
+(2724, 1, NULL, 5, 'sē', 17, 'sesui', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.989210', '2025-07-28 10:19:08.989210'),
+(2725, 0, 0, 0, 'meus', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.995548', '2025-07-28 10:19:08.995548'),
+(2726, 1, 0, 0, 'meī', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:08.998009', '2025-07-28 10:19:08.998009'),
+(2727, 0, 1, 0, 'mea', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.000374', '2025-07-28 10:19:09.000374'),
+(2728, 1, 1, 0, 'meae', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.002731', '2025-07-28 10:19:09.002731'),
+(2729, 0, 3, 0, 'meum', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.005046', '2025-07-28 10:19:09.005046'),
+(2730, 1, 3, 0, 'mea', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.007427', '2025-07-28 10:19:09.007427'),
+(2731, 0, 0, 1, 'mī', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.009757', '2025-07-28 10:19:09.009757'),
+(2732, 1, 0, 1, 'meī', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.012089', '2025-07-28 10:19:09.012089'),
+(2733, 0, 1, 1, 'mea', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.014452', '2025-07-28 10:19:09.014452'),
+(2734, 1, 1, 1, 'meae', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.016766', '2025-07-28 10:19:09.016766'),
+(2735, 0, 3, 1, 'meum', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.019071', '2025-07-28 10:19:09.019071'),
+(2736, 1, 3, 1, 'mea', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.021324', '2025-07-28 10:19:09.021324'),
+(2737, 0, 0, 2, 'meum', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.023727', '2025-07-28 10:19:09.023727'),
+(2738, 1, 0, 2, 'meōs', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.026072', '2025-07-28 10:19:09.026072'),
+(2739, 0, 1, 2, 'meam', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.028363', '2025-07-28 10:19:09.028363'),
+(2740, 1, 1, 2, 'meās', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.030770', '2025-07-28 10:19:09.030770'),
+(2741, 0, 3, 2, 'meum', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.033005', '2025-07-28 10:19:09.033005'),
+(2742, 1, 3, 2, 'mea', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.040682', '2025-07-28 10:19:09.040682'),
+(2743, 0, 0, 3, 'meī', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.043198', '2025-07-28 10:19:09.043198'),
+(2744, 1, 0, 3, 'meōrum', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.047469', '2025-07-28 10:19:09.047469'),
+(2745, 0, 1, 3, 'meae', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.051294', '2025-07-28 10:19:09.051294'),
+(2746, 1, 1, 3, 'meārum', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.054062', '2025-07-28 10:19:09.054062'),
+(2747, 0, 3, 3, 'meī', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.057797', '2025-07-28 10:19:09.057797'),
+(2748, 1, 3, 3, 'meōrum', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.060508', '2025-07-28 10:19:09.060508'),
+(2749, 0, 0, 4, 'meō', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.064518', '2025-07-28 10:19:09.064518'),
+(2750, 1, 0, 4, 'meīs', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.066849', '2025-07-28 10:19:09.066849'),
+(2751, 0, 1, 4, 'meae', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.070315', '2025-07-28 10:19:09.070315'),
+(2752, 1, 1, 4, 'meīs', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.072998', '2025-07-28 10:19:09.072998'),
+(2753, 0, 3, 4, 'meō', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.076969', '2025-07-28 10:19:09.076969'),
+(2754, 1, 3, 4, 'meīs', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.079378', '2025-07-28 10:19:09.079378'),
+(2755, 0, 0, 5, 'meō', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.083263', '2025-07-28 10:19:09.083263'),
+(2756, 1, 0, 5, 'meīs', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.085589', '2025-07-28 10:19:09.085589'),
+(2757, 0, 1, 5, 'meā', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.089506', '2025-07-28 10:19:09.089506'),
+(2758, 1, 1, 5, 'meīs', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.091887', '2025-07-28 10:19:09.091887'),
+(2759, 0, 3, 5, 'meō', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.095763', '2025-07-28 10:19:09.095763'),
+(2760, 1, 3, 5, 'meīs', 18, 'meus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.098150', '2025-07-28 10:19:09.098150'),
+(2761, 0, 0, 0, 'tuus', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.106163', '2025-07-28 10:19:09.106163'),
+(2762, 1, 0, 0, 'tuī', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.110203', '2025-07-28 10:19:09.110203'),
+(2763, 0, 1, 0, 'tua', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.113787', '2025-07-28 10:19:09.113787'),
+(2764, 1, 1, 0, 'tuae', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.116534', '2025-07-28 10:19:09.116534'),
+(2765, 0, 3, 0, 'tuum', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.120013', '2025-07-28 10:19:09.120013'),
+(2766, 1, 3, 0, 'tua', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.122706', '2025-07-28 10:19:09.122706'),
+(2767, 0, 0, 1, 'tuus', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.126192', '2025-07-28 10:19:09.126192'),
+(2768, 1, 0, 1, 'tuī', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.128661', '2025-07-28 10:19:09.128661'),
+(2769, 0, 1, 1, 'tua', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.132088', '2025-07-28 10:19:09.132088'),
+(2770, 1, 1, 1, 'tuae', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.134431', '2025-07-28 10:19:09.134431'),
+(2771, 0, 3, 1, 'tuum', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.136799', '2025-07-28 10:19:09.136799'),
+(2772, 1, 3, 1, 'tua', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.140080', '2025-07-28 10:19:09.140080'),
+(2773, 0, 0, 2, 'tuum', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.142495', '2025-07-28 10:19:09.142495'),
+(2774, 1, 0, 2, 'tuōs', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.145835', '2025-07-28 10:19:09.145835'),
+(2775, 0, 1, 2, 'tuam', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.148227', '2025-07-28 10:19:09.148227'),
+(2776, 1, 1, 2, 'tuās', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.151546', '2025-07-28 10:19:09.151546'),
+(2777, 0, 3, 2, 'tuum', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.153944', '2025-07-28 10:19:09.153944'),
+(2778, 1, 3, 2, 'tua', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.156345', '2025-07-28 10:19:09.156345'),
+(2779, 0, 0, 3, 'tuī', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.159745', '2025-07-28 10:19:09.159745'),
+(2780, 1, 0, 3, 'tuōrum', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.162146', '2025-07-28 10:19:09.162146'),
+(2781, 0, 1, 3, 'tuae', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.165506', '2025-07-28 10:19:09.165506'),
+(2782, 1, 1, 3, 'tuārum', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.167905', '2025-07-28 10:19:09.167905'),
+(2783, 0, 3, 3, 'tuī', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.171177', '2025-07-28 10:19:09.171177'),
+(2784, 1, 3, 3, 'tuōrum', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.173529', '2025-07-28 10:19:09.173529'),
+(2785, 0, 0, 4, 'tuō', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.175982', '2025-07-28 10:19:09.175982'),
+(2786, 1, 0, 4, 'tuīs', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.179047', '2025-07-28 10:19:09.179047'),
+(2787, 0, 1, 4, 'tuae', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.186572', '2025-07-28 10:19:09.186572'),
+(2788, 1, 1, 4, 'tuīs', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.189025', '2025-07-28 10:19:09.189025'),
+(2789, 0, 3, 4, 'tuō', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.191416', '2025-07-28 10:19:09.191416'),
+(2790, 1, 3, 4, 'tuīs', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.193814', '2025-07-28 10:19:09.193814'),
+(2791, 0, 0, 5, 'tuō', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.196308', '2025-07-28 10:19:09.196308'),
+(2792, 1, 0, 5, 'tuīs', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.198730', '2025-07-28 10:19:09.198730'),
+(2793, 0, 1, 5, 'tuā', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.201120', '2025-07-28 10:19:09.201120'),
+(2794, 1, 1, 5, 'tuīs', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.203474', '2025-07-28 10:19:09.203474'),
+(2795, 0, 3, 5, 'tuō', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.205824', '2025-07-28 10:19:09.205824'),
+(2796, 1, 3, 5, 'tuīs', 19, 'tuus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.208118', '2025-07-28 10:19:09.208118'),
+(2797, 0, 0, 0, 'suus', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.214435', '2025-07-28 10:19:09.214435'),
+(2798, 1, 0, 0, 'suī', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.216846', '2025-07-28 10:19:09.216846'),
+(2799, 0, 1, 0, 'sua', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.219192', '2025-07-28 10:19:09.219192'),
+(2800, 1, 1, 0, 'suae', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.221538', '2025-07-28 10:19:09.221538'),
+(2801, 0, 3, 0, 'suum', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.223877', '2025-07-28 10:19:09.223877'),
+(2802, 1, 3, 0, 'sua', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.226232', '2025-07-28 10:19:09.226232'),
+(2803, 0, 0, 1, 'suus', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.228593', '2025-07-28 10:19:09.228593'),
+(2804, 1, 0, 1, 'suī', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.230982', '2025-07-28 10:19:09.230982'),
+(2805, 0, 1, 1, 'sua', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.233344', '2025-07-28 10:19:09.233344'),
+(2806, 1, 1, 1, 'suae', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.235668', '2025-07-28 10:19:09.235668'),
+(2807, 0, 3, 1, 'suum', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.237986', '2025-07-28 10:19:09.237986'),
+(2808, 1, 3, 1, 'sua', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.240229', '2025-07-28 10:19:09.240229'),
+(2809, 0, 0, 2, 'suum', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.242616', '2025-07-28 10:19:09.242616'),
+(2810, 1, 0, 2, 'suōs', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.244977', '2025-07-28 10:19:09.244977'),
+(2811, 0, 1, 2, 'suam', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.252712', '2025-07-28 10:19:09.252712'),
+(2812, 1, 1, 2, 'suās', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.255143', '2025-07-28 10:19:09.255143'),
+(2813, 0, 3, 2, 'suum', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.259272', '2025-07-28 10:19:09.259272'),
+(2814, 1, 3, 2, 'sua', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.263207', '2025-07-28 10:19:09.263207'),
+(2815, 0, 0, 3, 'suī', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.266016', '2025-07-28 10:19:09.266016'),
+(2816, 1, 0, 3, 'suōrum', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.269710', '2025-07-28 10:19:09.269710'),
+(2817, 0, 1, 3, 'suae', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.272369', '2025-07-28 10:19:09.272369'),
+(2818, 1, 1, 3, 'suārum', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.276089', '2025-07-28 10:19:09.276089'),
+(2819, 0, 3, 3, 'suī', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.278813', '2025-07-28 10:19:09.278813'),
+(2820, 1, 3, 3, 'suōrum', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.282492', '2025-07-28 10:19:09.282492'),
+(2821, 0, 0, 4, 'suō', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.285260', '2025-07-28 10:19:09.285260'),
+(2822, 1, 0, 4, 'suīs', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.289104', '2025-07-28 10:19:09.289104'),
+(2823, 0, 1, 4, 'suae', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.291449', '2025-07-28 10:19:09.291449'),
+(2824, 1, 1, 4, 'suīs', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.295392', '2025-07-28 10:19:09.295392'),
+(2825, 0, 3, 4, 'suō', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.297763', '2025-07-28 10:19:09.297763'),
+(2826, 1, 3, 4, 'suīs', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.301563', '2025-07-28 10:19:09.301563'),
+(2827, 0, 0, 5, 'suō', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.303919', '2025-07-28 10:19:09.303919'),
+(2828, 1, 0, 5, 'suīs', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.307809', '2025-07-28 10:19:09.307809'),
+(2829, 0, 1, 5, 'suā', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.310163', '2025-07-28 10:19:09.310163'),
+(2830, 1, 1, 5, 'suīs', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.314195', '2025-07-28 10:19:09.314195'),
+(2831, 0, 3, 5, 'suō', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.316515', '2025-07-28 10:19:09.316515'),
+(2832, 1, 3, 5, 'suīs', 20, 'suus', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.320305', '2025-07-28 10:19:09.320305'),
+(2833, 0, 0, 0, 'noster', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.328313', '2025-07-28 10:19:09.328313'),
+(2834, 1, 0, 0, 'nostrī', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.332099', '2025-07-28 10:19:09.332099'),
+(2835, 0, 1, 0, 'nostra', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.334437', '2025-07-28 10:19:09.334437'),
+(2836, 1, 1, 0, 'nostrae', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.337597', '2025-07-28 10:19:09.337597'),
+(2837, 0, 3, 0, 'nostrum', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.339962', '2025-07-28 10:19:09.339962'),
+(2838, 1, 3, 0, 'nostra', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.343204', '2025-07-28 10:19:09.343204'),
+(2839, 0, 0, 1, 'nostrus', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.345556', '2025-07-28 10:19:09.345556'),
+(2840, 1, 0, 1, 'nostrī', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.348034', '2025-07-28 10:19:09.348034'),
+(2841, 0, 1, 1, 'nostra', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.351278', '2025-07-28 10:19:09.351278'),
+(2842, 1, 1, 1, 'nostrae', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.353628', '2025-07-28 10:19:09.353628'),
+(2843, 0, 3, 1, 'nostrum', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.356936', '2025-07-28 10:19:09.356936'),
+(2844, 1, 3, 1, 'nostra', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.359411', '2025-07-28 10:19:09.359411'),
+(2845, 0, 0, 2, 'nostrum', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.362652', '2025-07-28 10:19:09.362652'),
+(2846, 1, 0, 2, 'nostrōs', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.365104', '2025-07-28 10:19:09.365104'),
+(2847, 0, 1, 2, 'nostram', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.368422', '2025-07-28 10:19:09.368422'),
+(2848, 1, 1, 2, 'nostrās', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.370805', '2025-07-28 10:19:09.370805'),
+(2849, 0, 3, 2, 'nostrum', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.373162', '2025-07-28 10:19:09.373162'),
+(2850, 1, 3, 2, 'nostra', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.376463', '2025-07-28 10:19:09.376463'),
+(2851, 0, 0, 3, 'nostrī', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.378909', '2025-07-28 10:19:09.378909'),
+(2852, 1, 0, 3, 'nostrōrum', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.392615', '2025-07-28 10:19:09.392615'),
+(2853, 0, 1, 3, 'nostrae', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.402612', '2025-07-28 10:19:09.402612'),
+(2854, 1, 1, 3, 'nostrārum', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.411866', '2025-07-28 10:19:09.411866'),
+(2855, 0, 3, 3, 'nostrī', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.418304', '2025-07-28 10:19:09.418304'),
+(2856, 1, 3, 3, 'nostrōrum', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.427264', '2025-07-28 10:19:09.427264'),
+(2857, 0, 0, 4, 'nostrō', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.432515', '2025-07-28 10:19:09.432515'),
+(2858, 1, 0, 4, 'nostrīs', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.437733', '2025-07-28 10:19:09.437733'),
+(2859, 0, 1, 4, 'nostrae', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.444987', '2025-07-28 10:19:09.444987'),
+(2860, 1, 1, 4, 'nostrīs', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.471007', '2025-07-28 10:19:09.471007'),
+(2861, 0, 3, 4, 'nostrō', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.484718', '2025-07-28 10:19:09.484718'),
+(2862, 1, 3, 4, 'nostrīs', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.490460', '2025-07-28 10:19:09.490460'),
+(2863, 0, 0, 5, 'nostrō', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.496663', '2025-07-28 10:19:09.496663'),
+(2864, 1, 0, 5, 'nostrīs', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.501713', '2025-07-28 10:19:09.501713'),
+(2865, 0, 1, 5, 'nostrā', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.506094', '2025-07-28 10:19:09.506094'),
+(2866, 1, 1, 5, 'nostrīs', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.510764', '2025-07-28 10:19:09.510764'),
+(2867, 0, 3, 5, 'nostrō', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.515438', '2025-07-28 10:19:09.515438'),
+(2868, 1, 3, 5, 'nostrīs', 21, 'noster', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.520737', '2025-07-28 10:19:09.520737'),
+(2869, 0, 0, 0, 'vester', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.535410', '2025-07-28 10:19:09.535410'),
+(2870, 1, 0, 0, 'vestrī', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.541013', '2025-07-28 10:19:09.541013'),
+(2871, 0, 1, 0, 'vestra', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.543315', '2025-07-28 10:19:09.543315'),
+(2872, 1, 1, 0, 'vestrae', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.546914', '2025-07-28 10:19:09.546914'),
+(2873, 0, 3, 0, 'vestrum', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.549034', '2025-07-28 10:19:09.549034'),
+(2874, 1, 3, 0, 'vestra', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.552532', '2025-07-28 10:19:09.552532'),
+(2875, 0, 0, 1, 'vestrus', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.554603', '2025-07-28 10:19:09.554603'),
+(2876, 1, 0, 1, 'vestrī', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.558137', '2025-07-28 10:19:09.558137'),
+(2877, 0, 1, 1, 'vestra', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.560362', '2025-07-28 10:19:09.560362'),
+(2878, 1, 1, 1, 'vestrae', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.564064', '2025-07-28 10:19:09.564064'),
+(2879, 0, 3, 1, 'vestrum', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.566166', '2025-07-28 10:19:09.566166'),
+(2880, 1, 3, 1, 'vestra', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.569505', '2025-07-28 10:19:09.569505'),
+(2881, 0, 0, 2, 'vestrum', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.572717', '2025-07-28 10:19:09.572717'),
+(2882, 1, 0, 2, 'vestrōs', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.574947', '2025-07-28 10:19:09.574947'),
+(2883, 0, 1, 2, 'vestram', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.578506', '2025-07-28 10:19:09.578506'),
+(2884, 1, 1, 2, 'vestrās', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.580720', '2025-07-28 10:19:09.580720'),
+(2885, 0, 3, 2, 'vestrum', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.584247', '2025-07-28 10:19:09.584247'),
+(2886, 1, 3, 2, 'vestra', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.586403', '2025-07-28 10:19:09.586403'),
+(2887, 0, 0, 3, 'vestrī', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.590036', '2025-07-28 10:19:09.590036'),
+(2888, 1, 0, 3, 'vestrōrum', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.592198', '2025-07-28 10:19:09.592198'),
+(2889, 0, 1, 3, 'vestrae', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.595995', '2025-07-28 10:19:09.595995'),
+(2890, 1, 1, 3, 'vestrārum', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.598239', '2025-07-28 10:19:09.598239'),
+(2891, 0, 3, 3, 'vestrī', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.601796', '2025-07-28 10:19:09.601796'),
+(2892, 1, 3, 3, 'vestrōrum', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.603930', '2025-07-28 10:19:09.603930'),
+(2893, 0, 0, 4, 'vestrō', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.606812', '2025-07-28 10:19:09.606812'),
+(2894, 1, 0, 4, 'vestrīs', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.608947', '2025-07-28 10:19:09.608947'),
+(2895, 0, 1, 4, 'vestrae', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.611994', '2025-07-28 10:19:09.611994'),
+(2896, 1, 1, 4, 'vestrīs', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.614211', '2025-07-28 10:19:09.614211'),
+(2897, 0, 3, 4, 'vestrō', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.617362', '2025-07-28 10:19:09.617362'),
+(2898, 1, 3, 4, 'vestrīs', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.619599', '2025-07-28 10:19:09.619599'),
+(2899, 0, 0, 5, 'vestrō', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.622552', '2025-07-28 10:19:09.622552'),
+(2900, 1, 0, 5, 'vestrīs', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.624741', '2025-07-28 10:19:09.624741'),
+(2901, 0, 1, 5, 'vestrā', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.627919', '2025-07-28 10:19:09.627919'),
+(2902, 1, 1, 5, 'vestrīs', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.630242', '2025-07-28 10:19:09.630242'),
+(2903, 0, 3, 5, 'vestrō', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.633349', '2025-07-28 10:19:09.633349'),
+(2904, 1, 3, 5, 'vestrīs', 22, 'vester', NULL, NULL, NULL, NULL, NULL, '2025-07-28 10:19:09.635596', '2025-07-28 10:19:09.635596'),
+(2905, 0, 1, 2, 'īs', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2026-01-13 13:59:02', '2026-01-13 13:59:02'),
+(2906, 0, 0, 2, 'īs', 3, 'two', NULL, NULL, NULL, NULL, NULL, '2026-01-13 14:00:32', '2026-01-13 14:00:32'),
+(2907, 1, 0, 2, 'īs', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2026-01-22 11:44:09', '2026-01-22 11:44:09'),
+(2908, 1, 1, 2, 'īs', 3, 'istem', NULL, NULL, NULL, NULL, NULL, '2026-01-22 11:44:29', '2026-01-22 11:44:29'),
+(2909, 1, 0, 2, 'īs', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2026-01-22 11:44:29', '2026-01-22 11:44:29'),
+(2910, 1, 1, 2, 'īs', 3, 'pureistem', NULL, NULL, NULL, NULL, NULL, '2026-01-22 11:44:29', '2026-01-22 11:44:29'),
+(2911, 0, 1, 0, 'us', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2912, 0, 1, 1, 'us', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2913, 0, 1, 2, 'um', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2914, 0, 1, 3, 'ūs', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2915, 0, 1, 3, 'ī', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2916, 0, 1, 4, 'uī', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2917, 0, 1, 4, 'ō', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2918, 0, 1, 5, 'ō', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2919, 0, 1, 5, 'ū', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2920, 0, 1, 6, 'ī', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2921, 1, 1, 0, 'ūs', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2922, 1, 1, 1, 'ūs', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2923, 1, 1, 2, 'ōs', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2924, 1, 1, 2, 'ūs', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2925, 1, 1, 3, 'uum', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2926, 1, 1, 3, 'ōrum', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2927, 1, 1, 4, 'ibus', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2928, 1, 1, 5, 'ibus', 4, 'domusdomus', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:50:37', '2026-10-16 07:50:37'),
+(2929, 0, 0, 0, 'ās', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2930, 0, 0, 1, 'ā', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2931, 0, 0, 2, 'ān', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2932, 0, 0, 2, 'am', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2933, 0, 0, 3, 'ae', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2934, 0, 0, 4, 'ae', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2935, 0, 0, 5, 'ā', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2936, 1, 0, 0, 'ae', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2937, 1, 0, 1, 'ae', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2938, 1, 0, 2, 'ās', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2939, 1, 0, 3, 'ārum', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2940, 1, 0, 4, 'īs', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2941, 1, 0, 5, 'īs', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2942, 0, 1, 0, 'ās', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2943, 0, 1, 1, 'ā', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2944, 0, 1, 2, 'ān', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2945, 0, 1, 2, 'am', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2946, 0, 1, 3, 'ae', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2947, 0, 1, 4, 'ae', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2948, 0, 1, 5, 'ā', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2949, 1, 1, 0, 'ae', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2950, 1, 1, 1, 'ae', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2951, 1, 1, 2, 'ās', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2952, 1, 1, 3, 'ārum', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2953, 1, 1, 4, 'īs', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2954, 1, 1, 5, 'īs', 1, 'greekas', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2955, 0, 0, 0, 'ēs', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2956, 0, 0, 1, 'ē', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2957, 0, 0, 1, 'ā', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2958, 0, 0, 2, 'ēn', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2959, 0, 0, 2, 'am', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2960, 0, 0, 3, 'ae', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2961, 0, 0, 4, 'ae', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2962, 0, 0, 5, 'ē', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2963, 0, 0, 5, 'ā', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2964, 1, 0, 0, 'ae', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2965, 1, 0, 1, 'ae', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2966, 1, 0, 2, 'ās', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2967, 1, 0, 3, 'ārum', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2968, 1, 0, 4, 'īs', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2969, 1, 0, 5, 'īs', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2970, 0, 1, 0, 'ēs', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2971, 0, 1, 1, 'ē', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2972, 0, 1, 1, 'ā', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2973, 0, 1, 2, 'ēn', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2974, 0, 1, 2, 'am', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2975, 0, 1, 3, 'ae', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2976, 0, 1, 4, 'ae', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2977, 0, 1, 5, 'ē', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2978, 0, 1, 5, 'ā', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2979, 1, 1, 0, 'ae', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2980, 1, 1, 1, 'ae', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2981, 1, 1, 2, 'ās', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2982, 1, 1, 3, 'ārum', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2983, 1, 1, 4, 'īs', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2984, 1, 1, 5, 'īs', 1, 'greekes', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2985, 0, 0, 0, 'ē', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2986, 0, 0, 1, 'ē', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2987, 0, 0, 2, 'ēn', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2988, 0, 0, 3, 'ēs', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2989, 0, 0, 4, 'ae', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2990, 0, 0, 5, 'ē', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2991, 1, 0, 0, 'ae', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2992, 1, 0, 1, 'ae', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2993, 1, 0, 2, 'ās', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2994, 1, 0, 3, 'ārum', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2995, 1, 0, 4, 'īs', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2996, 1, 0, 5, 'īs', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2997, 0, 1, 0, 'ē', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2998, 0, 1, 1, 'ē', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(2999, 0, 1, 2, 'ēn', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3000, 0, 1, 3, 'ēs', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3001, 0, 1, 4, 'ae', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3002, 0, 1, 5, 'ē', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3003, 1, 1, 0, 'ae', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3004, 1, 1, 1, 'ae', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3005, 1, 1, 2, 'ās', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3006, 1, 1, 3, 'ārum', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3007, 1, 1, 4, 'īs', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3008, 1, 1, 5, 'īs', 1, 'greeke', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3009, 0, 0, 0, 'ō', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3010, 0, 0, 1, 'ō', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3011, 0, 0, 2, 'ōnem', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3012, 0, 0, 2, 'ō', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3013, 0, 0, 3, 'ōnis', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3014, 0, 0, 3, 'ūs', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3015, 0, 0, 4, 'ōnī', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3016, 0, 0, 4, 'ō', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3017, 0, 0, 5, 'ōne', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3018, 0, 0, 5, 'ō', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3019, 1, 0, 0, 'ōnēs', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3020, 1, 0, 1, 'ōnēs', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3021, 1, 0, 2, 'ōnēs', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3022, 1, 0, 3, 'ōnum', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3023, 1, 0, 4, 'ōnibus', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3024, 1, 0, 5, 'ōnibus', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3025, 0, 1, 0, 'ō', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3026, 0, 1, 1, 'ō', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3027, 0, 1, 2, 'ōnem', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3028, 0, 1, 2, 'ō', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3029, 0, 1, 3, 'ōnis', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3030, 0, 1, 3, 'ūs', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3031, 0, 1, 4, 'ōnī', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3032, 0, 1, 4, 'ō', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3033, 0, 1, 5, 'ōne', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3034, 0, 1, 5, 'ō', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3035, 1, 1, 0, 'ōnēs', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3036, 1, 1, 1, 'ōnēs', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3037, 1, 1, 2, 'ōnēs', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3038, 1, 1, 3, 'ōnum', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3039, 1, 1, 4, 'ōnibus', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3040, 1, 1, 5, 'ōnibus', 3, 'greeko', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3041, 0, 0, 0, '', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3042, 0, 0, 1, '', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3043, 0, 0, 2, 'a', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3044, 0, 0, 2, 'em', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3045, 0, 0, 3, 'is', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3046, 0, 0, 4, 'ī', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3047, 0, 0, 5, 'e', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3048, 1, 0, 0, 'es', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3049, 1, 0, 1, 'es', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3050, 1, 0, 2, 'as', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3051, 1, 0, 2, 'ēs', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3052, 1, 0, 3, 'um', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3053, 1, 0, 4, 'ibus', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3054, 1, 0, 5, 'ibus', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3055, 0, 1, 0, '', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3056, 0, 1, 1, '', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3057, 0, 1, 2, 'a', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3058, 0, 1, 2, 'em', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3059, 0, 1, 3, 'is', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3060, 0, 1, 4, 'ī', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3061, 0, 1, 5, 'e', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3062, 1, 1, 0, 'es', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3063, 1, 1, 1, 'es', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3064, 1, 1, 2, 'as', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3065, 1, 1, 2, 'ēs', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3066, 1, 1, 3, 'um', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3067, 1, 1, 4, 'ibus', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21'),
+(3068, 1, 1, 5, 'ibus', 3, 'greekthird', NULL, NULL, NULL, NULL, NULL, '2026-10-16 07:51:21', '2026-10-16 07:51:21');
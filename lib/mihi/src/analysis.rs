@@ -0,0 +1,371 @@
+use crate::cfg::{configuration, MacronStrictness};
+use crate::inflection::{
+    get_adjective_table, get_noun_table, get_pronoun_table, get_verb_table, Case, DeclensionTable,
+    Number, MOODS, NUMBERS, PERSONS, TENSES, VOICES,
+};
+use crate::word::{
+    matches_form, select_all_words, strip_macrons, Category, Conjugation, Gender, Word,
+};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+
+/// A possible reading of an inflected form. Verb values are indexed as they are
+/// stored in the database (e.g. a `tense` of 1 is the imperfect).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Analysis {
+    /// A form of a noun, an adjective or a pronoun.
+    Declined {
+        lemma: String,
+        case: Case,
+        number: Number,
+        gender: Gender,
+    },
+
+    /// A finite form of a verb.
+    Conjugated {
+        lemma: String,
+        tense: usize,
+        mood: usize,
+        voice: usize,
+        person: usize,
+        number: usize,
+    },
+
+    /// An enclitic (e.g. '-que') which was attached to the analyzed form.
+    Enclitic { lemma: String },
+}
+
+impl Analysis {
+    /// Returns the enunciate of the word for this analysis.
+    pub fn lemma(&self) -> &str {
+        match self {
+            Self::Declined { lemma, .. }
+            | Self::Conjugated { lemma, .. }
+            | Self::Enclitic { lemma } => lemma,
+        }
+    }
+}
+
+impl std::fmt::Display for Analysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Declined {
+                lemma,
+                case,
+                number,
+                gender,
+            } => {
+                write!(f, "{lemma}: {case} {number}")?;
+                if !matches!(gender, Gender::None) {
+                    write!(f, " {gender}")?;
+                }
+                Ok(())
+            }
+            Self::Conjugated {
+                lemma,
+                tense,
+                mood,
+                voice,
+                person,
+                number,
+            } => write!(
+                f,
+                "{lemma}: {} person {} {} {} {}",
+                PERSONS[*person], NUMBERS[*number], TENSES[*tense], MOODS[*mood], VOICES[*voice]
+            ),
+            Self::Enclitic { lemma } => write!(f, "{lemma}: enclitic"),
+        }
+    }
+}
+
+// Returns true if the given `form`, already lowercased and without macrons,
+// might be an inflection of the given `word`. Regular words are matched by the
+// beginning of their particle or of any part of their enunciate (e.g. 'tulit'
+// from 'ferō, ferre, tulī, lātum'), but irregular ones have to be inflected
+// anyways.
+fn is_candidate(word: &Word, form: &str) -> bool {
+    if word.is_flag_set("indeclinable") {
+        return false;
+    }
+    if !matches!(
+        word.category,
+        Category::Noun | Category::Adjective | Category::Pronoun | Category::Verb
+    ) {
+        return false;
+    }
+    if !word.regular
+        || matches!(word.category, Category::Pronoun)
+        || matches!(word.conjugation, Some(Conjugation::Other))
+    {
+        return true;
+    }
+
+    std::iter::once(word.particle.as_str())
+        .chain(word.enunciated.split(','))
+        .any(|stem| {
+            let prefix: String = strip_macrons(&stem.trim().to_lowercase())
+                .chars()
+                .take(3)
+                .collect();
+            !prefix.is_empty() && form.starts_with(&prefix)
+        })
+}
+
+// Returns the declension tables of the given `word` alongside the gender that
+// each of them represents.
+fn declension_tables(word: &Word) -> Result<Vec<(Gender, DeclensionTable)>, String> {
+    let genders = [Gender::Masculine, Gender::Feminine, Gender::Neuter];
+
+    match word.category {
+        Category::Noun => Ok(vec![(word.gender, get_noun_table(word)?)]),
+        Category::Adjective => Ok(genders
+            .into_iter()
+            .zip(get_adjective_table(word)?)
+            .collect()),
+        Category::Pronoun => {
+            let tables = get_pronoun_table(word)?;
+            if tables.len() == 1 {
+                Ok(tables.into_iter().map(|t| (Gender::None, t)).collect())
+            } else {
+                Ok(genders.into_iter().zip(tables).collect())
+            }
+        }
+        _ => Ok(vec![]),
+    }
+}
+
+// Returns all the inflected forms of the given `word` alongside their
+// analysis.
+fn inflections(word: &Word) -> Result<Vec<(String, Analysis)>, String> {
+    let mut res = vec![];
+
+    if matches!(word.category, Category::Verb) {
+        let mut table = get_verb_table(word)?;
+        for mood in 0..MOODS.len() {
+            let Some(voices) = table.mood_mut(mood) else {
+                continue;
+            };
+            for (voice, mood_table) in voices.iter_mut().enumerate() {
+                for tense in 0..TENSES.len() {
+                    let Some(info) = mood_table.tense_mut(tense) else {
+                        continue;
+                    };
+                    for (number, persons) in info.forms.iter().enumerate() {
+                        for (person, cell) in persons.iter().enumerate() {
+                            for form in cell.inflected.iter() {
+                                res.push((
+                                    form.clone(),
+                                    Analysis::Conjugated {
+                                        lemma: word.enunciated.clone(),
+                                        tense,
+                                        mood,
+                                        voice,
+                                        person,
+                                        number,
+                                    },
+                                ));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        return Ok(res);
+    }
+
+    for (gender, table) in declension_tables(word)? {
+        for (case, number, info) in table.iter() {
+            // The locative is only to be considered for words which actually
+            // have it.
+            if case == Case::Locative && !word.locative {
+                continue;
+            }
+            for form in info.inflected.iter() {
+                res.push((
+                    form.clone(),
+                    Analysis::Declined {
+                        lemma: word.enunciated.clone(),
+                        case,
+                        number,
+                        gender,
+                    },
+                ));
+            }
+        }
+    }
+
+    Ok(res)
+}
+
+/// Analyzes inflected forms while keeping around the inflections of the words
+/// which have been considered so far. Prefer it over `analyze` when going
+/// through a whole text.
+#[derive(Debug)]
+pub struct Analyzer {
+    words: Vec<Word>,
+    inflections: HashMap<i32, Vec<(String, Analysis)>>,
+    strictness: MacronStrictness,
+}
+
+impl Analyzer {
+    /// Returns an analyzer for the words which are currently on the database,
+    /// which matches forms with the configured strictness for macrons.
+    pub fn new() -> Result<Self, String> {
+        Self::with_strictness(configuration().macron_strictness)
+    }
+
+    /// Returns an analyzer for the words which are currently on the database,
+    /// which matches forms as in `matches_form` with the given `strictness`.
+    pub fn with_strictness(strictness: MacronStrictness) -> Result<Self, String> {
+        Ok(Self {
+            words: select_all_words()?,
+            inflections: HashMap::new(),
+            strictness,
+        })
+    }
+
+    /// Returns all the possible analyses for the given inflected `form`. See
+    /// the `analyze` function.
+    pub fn analyze(&mut self, form: &str) -> Result<Vec<Analysis>, String> {
+        let form = form.trim().to_lowercase();
+        if form.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let res = self.analyze_form(&form)?;
+        if !res.is_empty() {
+            return Ok(res);
+        }
+
+        // The form could not be analyzed as is, so try again without an
+        // enclitic attached to it (e.g. 'rosamque').
+        let enclitics: Vec<(String, String)> = self
+            .words
+            .iter()
+            .filter_map(|word| {
+                word.enclitic_suffix()
+                    .map(|suffix| (suffix.trim().to_lowercase(), word.enunciated.clone()))
+            })
+            .collect();
+        for (suffix, lemma) in enclitics {
+            let Some(host) = form.strip_suffix(suffix.as_str()) else {
+                continue;
+            };
+            if host.is_empty() {
+                continue;
+            }
+
+            let mut host = self.analyze_form(host)?;
+            if !host.is_empty() {
+                host.push(Analysis::Enclitic { lemma });
+                return Ok(host);
+            }
+        }
+
+        Ok(vec![])
+    }
+
+    // Returns all the possible analyses for the given `form`, which has
+    // already been lowercased.
+    fn analyze_form(&mut self, form: &str) -> Result<Vec<Analysis>, String> {
+        let stripped = strip_macrons(form);
+        let mut res = vec![];
+        for word in self.words.iter() {
+            if !is_candidate(word, &stripped) {
+                continue;
+            }
+
+            let inflected = match self.inflections.entry(word.id) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(inflections(word)?),
+            };
+            for (inflection, analysis) in inflected.iter() {
+                if matches_form(form, inflection, self.strictness) && !res.contains(analysis) {
+                    res.push(analysis.clone());
+                }
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+/// Returns all the possible analyses for the given inflected `form` by
+/// inflecting the words that could have produced it. Macrons are matched with
+/// the configured strictness (see `Analyzer::new`), so by default an unmarked
+/// text can still be analyzed at the cost of more ambiguity. Forms with an enclitic
+/// attached to them (e.g. 'rosamque') are analyzed without it, and the enclitic
+/// itself is given as the last analysis. Note that neither comparison nor
+/// non-finite verb forms (e.g. participles) are considered.
+pub fn analyze(form: &str) -> Result<Vec<Analysis>, String> {
+    Analyzer::new()?.analyze(form)
+}
+
+/// Returns how many times each lemma occurs in the given `texts`, sorted by
+/// lemma. Forms which can be analyzed for multiple lemmas are counted for each
+/// of them.
+pub fn count_lemmas(texts: &[String]) -> Result<Vec<(String, usize)>, String> {
+    let mut analyzer = Analyzer::new()?;
+    let mut seen: HashMap<String, Vec<String>> = HashMap::new();
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+
+    for text in texts {
+        for token in text.split(|c: char| !c.is_alphabetic()) {
+            if token.is_empty() {
+                continue;
+            }
+
+            let lemmas = match seen.entry(token.to_string()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let mut lemmas: Vec<String> = analyzer
+                        .analyze(token)?
+                        .iter()
+                        .map(|analysis| analysis.lemma().to_string())
+                        .collect();
+                    lemmas.dedup();
+                    entry.insert(lemmas)
+                }
+            };
+            for lemma in lemmas.iter() {
+                *counts.entry(lemma.clone()).or_default() += 1;
+            }
+        }
+    }
+
+    Ok(counts.into_iter().collect())
+}
+
+/// Returns the first sentence from the given `texts` (e.g. the exercises, see
+/// `exercise::select_exercise_texts`) which contains an inflected form of the
+/// given `word`, if any. Macrons are not taken into account. Words which
+/// cannot be inflected are looked up by the parts of their enunciate.
+pub fn find_example(word: &Word, texts: &[String]) -> Result<Option<String>, String> {
+    let mut forms: Vec<String> = inflections(word)?
+        .into_iter()
+        .map(|(form, _)| form)
+        .collect();
+    if forms.is_empty() {
+        forms = word
+            .enunciated
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(str::to_string)
+            .collect();
+    }
+
+    for text in texts {
+        for sentence in text.split_inclusive(['.', '?', '!', ';', '\n']) {
+            let found = sentence.split(|c: char| !c.is_alphabetic()).any(|token| {
+                forms
+                    .iter()
+                    .any(|form| matches_form(token, form, MacronStrictness::Ignore))
+            });
+            if found {
+                return Ok(Some(sentence.trim().to_string()));
+            }
+        }
+    }
+    Ok(None)
+}
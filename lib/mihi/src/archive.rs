@@ -0,0 +1,1137 @@
+use crate::get_connection;
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
+use serde_json::{json, Map, Value};
+
+/// Version of the format written by `export_database`. Archives with a newer
+/// version are refused by `import_database`.
+pub const ARCHIVE_VERSION: u64 = 1;
+
+// The columns which tell forms apart, as they are not identified by their IDs
+// on archives.
+const FORM_KEY: &str = "kind, declension_id, conjugation_id, number, gender, \"case\", \
+                        tense, mood, voice, person, value";
+
+/// What to do with an imported tag, lesson, word or exercise which already
+/// exists in the database, or with the progress on a form which has already
+/// been practiced.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ConflictPolicy {
+    /// Leave the existing one untouched.
+    #[default]
+    Skip,
+    /// Replace the existing one with the imported one.
+    Overwrite,
+    /// Keep whichever was updated last, as told by their 'updated_at' column.
+    Merge,
+}
+
+impl TryFrom<&str> for ConflictPolicy {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "skip" => Ok(Self::Skip),
+            "overwrite" => Ok(Self::Overwrite),
+            "merge" => Ok(Self::Merge),
+            _ => Err(format!(
+                "unknown conflict policy '{value}'. Available: skip, overwrite, merge"
+            )),
+        }
+    }
+}
+
+/// How many items of a kind were created, updated or skipped on an import.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ImportCounts {
+    pub created: usize,
+    pub updated: usize,
+    pub skipped: usize,
+}
+
+impl std::fmt::Display for ImportCounts {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} created, {} updated, {} skipped",
+            self.created, self.updated, self.skipped
+        )
+    }
+}
+
+/// The result of `import_database`.
+#[derive(Clone, Debug, Default)]
+pub struct ImportSummary {
+    pub tags: ImportCounts,
+    pub lessons: ImportCounts,
+    pub words: ImportCounts,
+    pub exercises: ImportCounts,
+    pub forms: ImportCounts,
+    pub notes: ImportCounts,
+    pub sessions: ImportCounts,
+    /// Answers of the imported sessions. Answers of sessions which were
+    /// skipped are not counted.
+    pub session_answers: ImportCounts,
+    pub drill_attempts: ImportCounts,
+    pub exercise_attempts: ImportCounts,
+    pub exams: ImportCounts,
+    /// How many tags, lessons, words and exercises were removed because they
+    /// were removed from the database the archive comes from.
+    pub deleted: usize,
+}
+
+impl std::fmt::Display for ImportSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "Tags: {}", self.tags)?;
+        writeln!(f, "Lessons: {}", self.lessons)?;
+        writeln!(f, "Words: {}", self.words)?;
+        writeln!(f, "Exercises: {}", self.exercises)?;
+        writeln!(f, "Forms: {}", self.forms)?;
+        writeln!(f, "Notes: {}", self.notes)?;
+        writeln!(f, "Sessions: {}", self.sessions)?;
+        writeln!(f, "Session answers: {}", self.session_answers)?;
+        writeln!(f, "Drill attempts: {}", self.drill_attempts)?;
+        writeln!(f, "Exercise attempts: {}", self.exercise_attempts)?;
+        writeln!(f, "Exams: {}", self.exams)?;
+        write!(f, "Deleted: {}", self.deleted)
+    }
+}
+
+// Returns the given value from the database as a JSON value.
+fn to_json(value: SqlValue) -> Value {
+    match value {
+        SqlValue::Null | SqlValue::Blob(_) => Value::Null,
+        SqlValue::Integer(i) => json!(i),
+        SqlValue::Real(r) => json!(r),
+        SqlValue::Text(s) => json!(s),
+    }
+}
+
+// Returns the given JSON value as a value for the database.
+fn to_sql(value: &Value) -> SqlValue {
+    match value {
+        Value::Null => SqlValue::Null,
+        Value::Bool(b) => SqlValue::Integer(*b as i64),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => SqlValue::Integer(i),
+            None => SqlValue::Real(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => SqlValue::Text(s.clone()),
+        other => SqlValue::Text(other.to_string()),
+    }
+}
+
+// Returns the rows from the given `query` as JSON objects keyed by the name of
+// each column.
+fn select_objects(
+    conn: &Connection,
+    query: &str,
+    params: impl rusqlite::Params,
+) -> Result<Vec<Map<String, Value>>, String> {
+    let mut stmt = conn.prepare_cached(query).map_err(|e| e.to_string())?;
+    let names: Vec<String> = stmt.column_names().iter().map(|n| n.to_string()).collect();
+    let mut rows = stmt.query(params).map_err(|e| e.to_string())?;
+
+    let mut res = vec![];
+    while let Some(row) = rows.next().map_err(|e| e.to_string())? {
+        let mut object = Map::new();
+        for (idx, name) in names.iter().enumerate() {
+            let value = row.get::<usize, SqlValue>(idx).map_err(|e| e.to_string())?;
+            object.insert(name.clone(), to_json(value));
+        }
+        res.push(object);
+    }
+    Ok(res)
+}
+
+// Returns the values from the first column of the given `query` for the row
+// identified by `id`.
+fn select_strings(conn: &Connection, query: &str, id: i64) -> Result<Vec<String>, String> {
+    let mut stmt = conn.prepare_cached(query).map_err(|e| e.to_string())?;
+    stmt.query_map([id], |row| row.get::<usize, String>(0))
+        .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| e.to_string())
+}
+
+// Removes the 'id' from the given `object` and returns it.
+fn take_id(object: &mut Map<String, Value>) -> i64 {
+    object
+        .remove("id")
+        .and_then(|id| id.as_i64())
+        .unwrap_or_default()
+}
+
+// Returns the SQL expression for the last time that a row from the given
+// `table` changed. For words and exercises this also accounts for the changes
+// made on their associations (see `export_changes`), so it's the same time on
+// exports and when merging imported rows.
+fn changed_at(table: &str) -> String {
+    match table {
+        "words" => "MAX(words.updated_at, \
+                        IFNULL((SELECT MAX(updated_at) FROM tag_associations \
+                                WHERE word_id = words.id), ''), \
+                        IFNULL((SELECT MAX(updated_at) FROM word_relations \
+                                WHERE source_id = words.id), ''))"
+            .to_string(),
+        "exercises" => "MAX(exercises.updated_at, \
+                            IFNULL((SELECT MAX(updated_at) FROM exercise_tag_associations \
+                                    WHERE exercise_id = exercises.id), ''), \
+                            IFNULL((SELECT MAX(created_at) FROM exercise_words \
+                                    WHERE exercise_id = exercises.id), ''))"
+            .to_string(),
+        _ => format!("{table}.updated_at"),
+    }
+}
+
+// Replaces the 'updated_at' from the given `object` with its 'changed_at'
+// column, which also accounts for the changes made on its associations.
+fn take_changed_at(object: &mut Map<String, Value>) {
+    if let Some(changed_at) = object.remove("changed_at") {
+        object.insert("updated_at".to_string(), changed_at);
+    }
+}
+
+/// Returns the whole database as a JSON object which can be imported into
+/// another database with `import_database`: tags, lessons, words (with their
+/// tags and relations) and exercises (with their tags and words), including
+/// the progress made on words, exercises and forms. The history is also there:
+/// notes, practice sessions (with their answers), drill attempts, exercise
+/// attempts and exams. Everything refers to each other by name (e.g. words by
+/// their enunciate) instead of by their IDs, which do not match between
+/// databases. Forms come with every database, so they are identified by what
+/// they stand for (e.g. their declension, case and number).
+pub fn export_database() -> Result<Value, String> {
+    export_changes(None)
+}
+
+/// Returns the same as `export_database`, but only with the tags, lessons,
+/// words and exercises which changed after the given `since` time (e.g.
+/// '2025-07-28 10:19:00'), or everything if no time is given. Changing the
+/// tags, the relations or the words linked to a word or an exercise counts as
+/// a change on them, and so their 'updated_at' is the last time that either
+/// them or their associations changed. The history only has what was recorded
+/// after `since`. Tags, lessons, words and exercises which were removed (or
+/// renamed) are listed under 'deletions', so they can be removed on merge.
+pub fn export_changes(since: Option<&str>) -> Result<Value, String> {
+    let conn = get_connection()?;
+
+    let mut tags = select_objects(
+        &conn,
+        "SELECT t.*, p.name AS parent FROM tags t \
+         LEFT JOIN tags p ON p.id = t.parent_id \
+         WHERE ?1 IS NULL OR t.updated_at > ?1 \
+         ORDER BY t.name",
+        [since],
+    )?;
+    for tag in &mut tags {
+        take_id(tag);
+        tag.remove("parent_id");
+    }
+
+    let mut lessons = select_objects(
+        &conn,
+        "SELECT * FROM lessons WHERE ?1 IS NULL OR updated_at > ?1 ORDER BY position, name",
+        [since],
+    )?;
+    lessons.iter_mut().for_each(|lesson| {
+        take_id(lesson);
+    });
+
+    let mut words = select_objects(
+        &conn,
+        &format!(
+            "SELECT * FROM ( \
+                 SELECT words.*, l.name AS lesson, {} AS changed_at \
+                 FROM words \
+                 LEFT JOIN lessons l ON l.id = words.lesson_id) \
+             WHERE ?1 IS NULL OR changed_at > ?1 \
+             ORDER BY enunciated",
+            changed_at("words")
+        ),
+        [since],
+    )?;
+    for word in &mut words {
+        let id = take_id(word);
+        take_changed_at(word);
+        word.remove("lesson_id");
+        word.insert(
+            "tags".to_string(),
+            json!(select_strings(
+                &conn,
+                "SELECT t.name FROM tags t JOIN tag_associations ta ON ta.tag_id = t.id \
+                 WHERE ta.word_id = ?1 ORDER BY t.name",
+                id
+            )?),
+        );
+        word.insert(
+            "relations".to_string(),
+            json!(select_objects(
+                &conn,
+                "SELECT d.enunciated AS word, r.kind FROM word_relations r \
+                 JOIN words d ON d.id = r.destination_id \
+                 WHERE r.source_id = ?1 ORDER BY d.enunciated",
+                [id]
+            )?),
+        );
+    }
+
+    let mut exercises = select_objects(
+        &conn,
+        &format!(
+            "SELECT * FROM ( \
+                 SELECT exercises.*, l.name AS lesson, {} AS changed_at \
+                 FROM exercises \
+                 LEFT JOIN lessons l ON l.id = exercises.lesson_id) \
+             WHERE ?1 IS NULL OR changed_at > ?1 \
+             ORDER BY title",
+            changed_at("exercises")
+        ),
+        [since],
+    )?;
+    for exercise in &mut exercises {
+        let id = take_id(exercise);
+        take_changed_at(exercise);
+        exercise.remove("lesson_id");
+        exercise.insert(
+            "tags".to_string(),
+            json!(select_strings(
+                &conn,
+                "SELECT t.name FROM tags t JOIN exercise_tag_associations eta ON eta.tag_id = t.id \
+                 WHERE eta.exercise_id = ?1 ORDER BY t.name",
+                id
+            )?),
+        );
+        exercise.insert(
+            "words".to_string(),
+            json!(select_strings(
+                &conn,
+                "SELECT w.enunciated FROM words w JOIN exercise_words ew ON ew.word_id = w.id \
+                 WHERE ew.exercise_id = ?1 ORDER BY w.enunciated",
+                id
+            )?),
+        );
+    }
+
+    let forms = select_objects(
+        &conn,
+        &format!(
+            "SELECT {FORM_KEY}, asked_at, succeeded, failed, updated_at FROM forms \
+             WHERE (asked_at IS NOT NULL OR succeeded > 0 OR failed > 0) \
+               AND (?1 IS NULL OR updated_at > ?1) \
+             ORDER BY id"
+        ),
+        [since],
+    )?;
+
+    let notes = select_objects(
+        &conn,
+        "SELECT w.enunciated AS word, n.session, n.body, n.created_at FROM notes n \
+         LEFT JOIN words w ON w.id = n.word_id \
+         WHERE ?1 IS NULL OR n.created_at > ?1 \
+         ORDER BY n.created_at, n.id",
+        [since],
+    )?;
+
+    let mut sessions = select_objects(
+        &conn,
+        "SELECT * FROM sessions WHERE ?1 IS NULL OR finished_at > ?1 ORDER BY started_at, id",
+        [since],
+    )?;
+    for session in &mut sessions {
+        let id = take_id(session);
+        session.insert(
+            "answers".to_string(),
+            json!(select_objects(
+                &conn,
+                "SELECT w.enunciated AS word, a.given, a.succeeded, a.response_ms, a.created_at \
+                 FROM session_answers a JOIN words w ON w.id = a.word_id \
+                 WHERE a.session_id = ?1 ORDER BY a.created_at, a.id",
+                [id]
+            )?),
+        );
+    }
+
+    let drill_attempts = select_objects(
+        &conn,
+        "SELECT w.enunciated AS word, d.drill, d.expected, d.given, d.succeeded, d.created_at \
+         FROM drill_attempts d JOIN words w ON w.id = d.word_id \
+         WHERE ?1 IS NULL OR d.created_at > ?1 \
+         ORDER BY d.created_at, d.id",
+        [since],
+    )?;
+
+    let exercise_attempts = select_objects(
+        &conn,
+        "SELECT e.title AS exercise, a.seconds, a.succeeded, a.created_at \
+         FROM exercise_attempts a JOIN exercises e ON e.id = a.exercise_id \
+         WHERE ?1 IS NULL OR a.created_at > ?1 \
+         ORDER BY a.created_at, a.id",
+        [since],
+    )?;
+
+    let exams = select_objects(
+        &conn,
+        "SELECT tags, questions, score, breakdown, created_at FROM exams \
+         WHERE ?1 IS NULL OR created_at > ?1 \
+         ORDER BY created_at, id",
+        [since],
+    )?;
+
+    let deletions = select_objects(
+        &conn,
+        "SELECT kind, name, deleted_at FROM deletions \
+         WHERE ?1 IS NULL OR deleted_at > ?1 \
+         ORDER BY deleted_at, id",
+        [since],
+    )?;
+
+    Ok(json!({
+        "version": ARCHIVE_VERSION,
+        "since": since,
+        "deletions": deletions,
+        "tags": tags,
+        "lessons": lessons,
+        "words": words,
+        "exercises": exercises,
+        "forms": forms,
+        "notes": notes,
+        "sessions": sessions,
+        "drill_attempts": drill_attempts,
+        "exercise_attempts": exercise_attempts,
+        "exams": exams,
+    }))
+}
+
+// Returns the names of the columns from the given `table` which can be
+// imported, that is, all of them but the ID and the ones pointing to lessons
+// and tags, which are set separately. Declensions and conjugations come with
+// every database under the same IDs, so they are imported as they are.
+fn importable_columns(conn: &Connection, table: &str) -> Result<Vec<String>, String> {
+    let mut stmt = conn
+        .prepare_cached("SELECT name FROM pragma_table_info(?1)")
+        .map_err(|e| e.to_string())?;
+    let columns = stmt
+        .query_map([table], |row| row.get::<usize, String>(0))
+        .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| e.to_string())?;
+
+    Ok(columns
+        .into_iter()
+        .filter(|c| !["id", "lesson_id", "parent_id"].contains(&c.as_str()))
+        .collect())
+}
+
+// A table whose rows are identified by the `key` column (e.g. the enunciate
+// for words) when imported, alongside the columns which can be imported.
+struct Target<'a> {
+    table: &'a str,
+    key: &'a str,
+    columns: Vec<String>,
+}
+
+impl<'a> Target<'a> {
+    fn new(conn: &Connection, table: &'a str, key: &'a str) -> Result<Self, String> {
+        Ok(Self {
+            table,
+            key,
+            columns: importable_columns(conn, table)?,
+        })
+    }
+
+    // Creates or updates the row for the given `item` according to the given
+    // `policy`, where `extra` are the columns pointing to other rows. Returns
+    // the ID of the row if it was created or updated, or None if it was
+    // skipped.
+    fn import(
+        &self,
+        conn: &Connection,
+        item: &Map<String, Value>,
+        extra: &[(&str, SqlValue)],
+        policy: ConflictPolicy,
+        counts: &mut ImportCounts,
+    ) -> Result<Option<i64>, String> {
+        let (table, key) = (self.table, self.key);
+        let Some(name) = item.get(key).and_then(Value::as_str) else {
+            return Err(format!("there is an item in '{table}' without a '{key}'"));
+        };
+
+        let existing = find(conn, table, key, name)?;
+        let newer = |at: &str| {
+            item.get("updated_at")
+                .and_then(Value::as_str)
+                .is_some_and(|imported| imported > at)
+        };
+        let skip = match (&existing, policy) {
+            (Some(_), ConflictPolicy::Skip) => true,
+            (Some((_, changed_at)), ConflictPolicy::Merge) => !newer(changed_at),
+            // Rows removed from the database after the imported one was last
+            // changed stay removed.
+            (None, ConflictPolicy::Merge) => {
+                deleted_at(conn, table, name)?.is_some_and(|at| !newer(&at))
+            }
+            _ => false,
+        };
+        if skip {
+            counts.skipped += 1;
+            return Ok(None);
+        }
+
+        let mut columns: Vec<&str> = vec![];
+        let mut values: Vec<SqlValue> = vec![];
+        for column in &self.columns {
+            match item.get(column) {
+                Some(value) => values.push(to_sql(value)),
+                // Timestamps are required, so archives written by hand may
+                // leave them out.
+                None if column == "created_at" || column == "updated_at" => {
+                    values.push(SqlValue::Text(now(conn)?))
+                }
+                None => continue,
+            }
+            columns.push(column);
+        }
+        for (column, value) in extra {
+            columns.push(column);
+            values.push(value.clone());
+        }
+
+        let result = match existing {
+            Some((id, _)) => {
+                let assignments: Vec<String> = columns
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, column)| format!("{column} = ?{}", idx + 1))
+                    .collect();
+                values.push(SqlValue::Integer(id));
+                conn.execute(
+                    format!(
+                        "UPDATE {table} SET {} WHERE id = ?{}",
+                        assignments.join(", "),
+                        values.len()
+                    )
+                    .as_str(),
+                    params_from_iter(values),
+                )
+                .map(|_| {
+                    counts.updated += 1;
+                    id
+                })
+            }
+            None => conn
+                .execute(
+                    format!(
+                        "INSERT INTO {table} ({}) VALUES ({})",
+                        columns.join(", "),
+                        crate::tag::placeholders(1, columns.len())
+                    )
+                    .as_str(),
+                    params_from_iter(values),
+                )
+                .map(|_| {
+                    counts.created += 1;
+                    conn.last_insert_rowid()
+                }),
+        };
+        match result {
+            Ok(id) => Ok(Some(id)),
+            Err(e) => Err(format!("could not import '{name}': {e}")),
+        }
+    }
+}
+
+// Returns the ID of the row from the given `table` whose `key` column has the
+// given `name`, alongside the last time that it changed (see `changed_at`).
+fn find(
+    conn: &Connection,
+    table: &str,
+    key: &str,
+    name: &str,
+) -> Result<Option<(i64, String)>, String> {
+    conn.query_row(
+        format!(
+            "SELECT id, {} FROM {table} WHERE {key} = ?1",
+            changed_at(table)
+        )
+        .as_str(),
+        [name],
+        |row| Ok((row.get::<usize, i64>(0)?, row.get::<usize, String>(1)?)),
+    )
+    .optional()
+    .map_err(|e| format!("could not import '{name}': {e}"))
+}
+
+// Returns when the row from the given `table` with the given `name` was removed
+// from the database, if it was.
+fn deleted_at(conn: &Connection, table: &str, name: &str) -> Result<Option<String>, String> {
+    conn.query_row(
+        "SELECT deleted_at FROM deletions WHERE kind = ?1 AND name = ?2",
+        [table, name],
+        |row| row.get::<usize, String>(0),
+    )
+    .optional()
+    .map_err(|e| e.to_string())
+}
+
+// Removes the rows listed under 'deletions' in the given `archive` which did
+// not change after they were removed from the other database, and records
+// these removals as if they happened here. Returns how many were removed.
+fn import_deletions(conn: &Connection, archive: &Value) -> Result<usize, String> {
+    let mut removed = 0;
+
+    for deletion in section(archive, "deletions") {
+        let kind = deletion.get("kind").and_then(Value::as_str);
+        let key = match kind {
+            Some("words") => "enunciated",
+            Some("exercises") => "title",
+            Some("tags" | "lessons") => "name",
+            _ => continue,
+        };
+        let (Some(table), Some(name), Some(at)) = (
+            kind,
+            deletion.get("name").and_then(Value::as_str),
+            deletion.get("deleted_at").and_then(Value::as_str),
+        ) else {
+            continue;
+        };
+
+        match find(conn, table, key, name)? {
+            Some((_, changed_at)) if changed_at.as_str() > at => continue,
+            Some((id, _)) => {
+                // As with `tag::delete_tag` and `lesson::delete_lesson`,
+                // children are moved up and members are left without lesson.
+                let detach: &[&str] = match table {
+                    "tags" => &["UPDATE tags SET parent_id = \
+                                     (SELECT parent_id FROM tags WHERE id = ?1) \
+                                 WHERE parent_id = ?1"],
+                    "lessons" => &[
+                        "UPDATE words SET lesson_id = NULL WHERE lesson_id = ?1",
+                        "UPDATE exercises SET lesson_id = NULL WHERE lesson_id = ?1",
+                    ],
+                    _ => &[],
+                };
+                for query in detach {
+                    conn.execute(query, [id]).map_err(|e| e.to_string())?;
+                }
+                conn.execute(format!("DELETE FROM {table} WHERE id = ?1").as_str(), [id])
+                    .map_err(|e| format!("could not remove '{name}': {e}"))?;
+                removed += 1;
+            }
+            None => {}
+        }
+        conn.execute(
+            "INSERT INTO deletions (kind, name, deleted_at) VALUES (?1, ?2, ?3) \
+             ON CONFLICT (kind, name) DO UPDATE SET deleted_at = excluded.deleted_at",
+            [table, name, at],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(removed)
+}
+
+// Returns the current time as stored in the database.
+fn now(conn: &Connection) -> Result<String, String> {
+    conn.query_row("SELECT datetime('now')", [], |row| row.get(0))
+        .map_err(|e| e.to_string())
+}
+
+// Returns the time from the given `field` of the `item` (e.g. when it was last
+// updated), or the current time if the archive does not tell.
+fn time_of(conn: &Connection, item: &Map<String, Value>, field: &str) -> Result<String, String> {
+    match item.get(field).and_then(Value::as_str) {
+        Some(at) => Ok(at.to_string()),
+        None => now(conn),
+    }
+}
+
+// Returns the value under the given `field` of the `item` as a value for the
+// database, which is NULL if it's not there.
+fn field(item: &Map<String, Value>, field: &str) -> SqlValue {
+    item.get(field).map_or(SqlValue::Null, to_sql)
+}
+
+// Returns the ID of the row from the given `table` whose `key` column is the
+// value under the given `field` of the `item` (e.g. the word of a note). This
+// is Ok(None) if there is no such row, and NULL if the `item` has no `field`.
+fn reference(
+    conn: &Connection,
+    item: &Map<String, Value>,
+    field: &str,
+    (table, key): (&str, &str),
+) -> Result<Option<SqlValue>, String> {
+    let Some(name) = item.get(field).and_then(Value::as_str) else {
+        return Ok(Some(SqlValue::Null));
+    };
+    conn.query_row(
+        format!("SELECT id FROM {table} WHERE {key} = ?1").as_str(),
+        [name],
+        |row| row.get::<usize, i64>(0),
+    )
+    .optional()
+    .map(|id| id.map(SqlValue::Integer))
+    .map_err(|e| e.to_string())
+}
+
+// Inserts a row with the given `values` into the history `table`, unless there
+// is already one with the same values on the `key` columns (e.g. because the
+// same archive was imported twice). Returns the ID of the new row, or None if
+// it was skipped.
+fn import_history(
+    conn: &Connection,
+    table: &str,
+    values: &[(&str, SqlValue)],
+    key: &[&str],
+    counts: &mut ImportCounts,
+) -> Result<Option<i64>, String> {
+    let columns: Vec<&str> = values.iter().map(|(column, _)| *column).collect();
+    let conditions: Vec<String> = key
+        .iter()
+        .enumerate()
+        .map(|(idx, column)| format!("{column} IS ?{}", idx + 1))
+        .collect();
+    let key_values = key.iter().map(|column| {
+        values
+            .iter()
+            .find(|(c, _)| c == column)
+            .map_or(&SqlValue::Null, |(_, value)| value)
+    });
+
+    let exists = conn
+        .query_row(
+            format!("SELECT 1 FROM {table} WHERE {}", conditions.join(" AND ")).as_str(),
+            params_from_iter(key_values),
+            |_| Ok(()),
+        )
+        .optional()
+        .map_err(|e| e.to_string())?
+        .is_some();
+    if exists {
+        counts.skipped += 1;
+        return Ok(None);
+    }
+
+    conn.execute(
+        format!(
+            "INSERT INTO {table} ({}) VALUES ({})",
+            columns.join(", "),
+            crate::tag::placeholders(1, columns.len())
+        )
+        .as_str(),
+        params_from_iter(values.iter().map(|(_, value)| value)),
+    )
+    .map_err(|e| format!("could not import into '{table}': {e}"))?;
+    counts.created += 1;
+    Ok(Some(conn.last_insert_rowid()))
+}
+
+// Sets the progress from the given `form` of the archive on the form from the
+// database which stands for the same thing. Forms which have not been practiced
+// are always updated, while the rest are handled according to the `policy`.
+fn import_form(
+    conn: &Connection,
+    form: &Map<String, Value>,
+    policy: ConflictPolicy,
+    counts: &mut ImportCounts,
+) -> Result<(), String> {
+    let key: Vec<&str> = FORM_KEY.split(',').map(str::trim).collect();
+    let conditions: Vec<String> = key
+        .iter()
+        .enumerate()
+        .map(|(idx, column)| format!("{column} IS ?{}", idx + 1))
+        .collect();
+    let values = key
+        .iter()
+        .map(|column| field(form, column.trim_matches('"')));
+
+    let existing = conn
+        .query_row(
+            format!(
+                "SELECT id, updated_at, \
+                        asked_at IS NOT NULL OR succeeded > 0 OR failed > 0 \
+                 FROM forms WHERE {}",
+                conditions.join(" AND ")
+            )
+            .as_str(),
+            params_from_iter(values),
+            |row| {
+                Ok((
+                    row.get::<usize, i64>(0)?,
+                    row.get::<usize, String>(1)?,
+                    row.get::<usize, bool>(2)?,
+                ))
+            },
+        )
+        .optional()
+        .map_err(|e| e.to_string())?;
+    let Some((id, updated_at, practiced)) = existing else {
+        counts.skipped += 1;
+        return Ok(());
+    };
+
+    let at = time_of(conn, form, "updated_at")?;
+    if practiced
+        && (policy == ConflictPolicy::Skip || (policy == ConflictPolicy::Merge && at <= updated_at))
+    {
+        counts.skipped += 1;
+        return Ok(());
+    }
+
+    conn.execute(
+        "UPDATE forms SET asked_at = ?2, succeeded = ?3, failed = ?4, updated_at = ?5 \
+         WHERE id = ?1",
+        params![
+            id,
+            field(form, "asked_at"),
+            field(form, "succeeded"),
+            field(form, "failed"),
+            at
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+    counts.updated += 1;
+    Ok(())
+}
+
+// Returns the objects from the list under the given `section` of the archive.
+fn section<'a>(archive: &'a Value, section: &str) -> Vec<&'a Map<String, Value>> {
+    archive
+        .get(section)
+        .and_then(Value::as_array)
+        .map(|items| items.iter().filter_map(Value::as_object).collect())
+        .unwrap_or_default()
+}
+
+// Returns the strings from the list under the given `field` of the `item`.
+fn strings<'a>(item: &'a Map<String, Value>, field: &str) -> Vec<&'a str> {
+    item.get(field)
+        .and_then(Value::as_array)
+        .map(|values| values.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+// Returns the ID of the lesson named by the 'lesson' field of the given `item`,
+// if any.
+fn lesson_id(conn: &Connection, item: &Map<String, Value>) -> Result<SqlValue, String> {
+    let Some(name) = item.get("lesson").and_then(Value::as_str) else {
+        return Ok(SqlValue::Null);
+    };
+    conn.query_row("SELECT id FROM lessons WHERE name = ?1", [name], |row| {
+        row.get::<usize, i64>(0)
+    })
+    .optional()
+    .map(|id| id.map_or(SqlValue::Null, SqlValue::Integer))
+    .map_err(|e| e.to_string())
+}
+
+// Replaces the rows from the given association `table` which belong to the row
+// identified by `id` on the `owner` column with the ones for the given `names`.
+// These are linked on the `column` from the association table, and they are
+// looked up on the `key` column of the `other` table (e.g. tags by their name).
+// Names which do not exist are ignored. New rows are timestamped `at`, so they
+// do not look newer than the row which owns them (see `export_changes`).
+fn replace_associations(
+    conn: &Connection,
+    table: &str,
+    owner: &str,
+    (column, other, key): (&str, &str, &str),
+    (id, at): (i64, &str),
+    names: &[&str],
+) -> Result<(), String> {
+    conn.execute(
+        format!("DELETE FROM {table} WHERE {owner} = ?1").as_str(),
+        [id],
+    )
+    .map_err(|e| e.to_string())?;
+
+    // Links between exercises and words do not keep track of when they were
+    // updated.
+    let (updated, updated_value) = match table {
+        "exercise_words" => ("", ""),
+        _ => (", updated_at", ", ?3"),
+    };
+    for name in names {
+        conn.execute(
+            format!(
+                "INSERT OR IGNORE INTO {table} ({owner}, {column}, created_at{updated}) \
+                 SELECT ?1, id, ?3{updated_value} FROM {other} WHERE {key} = ?2"
+            )
+            .as_str(),
+            params![id, name, at],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Imports the given `archive`, as written by `export_database`, into the
+/// database. Tags, lessons, words and exercises which already exist (by name,
+/// enunciate or title) are handled according to the given `policy`. The tags,
+/// relations and words linked to the imported ones are replaced by the ones
+/// from the archive, but only for the ones which were not skipped. The same
+/// goes for the progress on forms, with the ones which were never practiced
+/// being always updated. The history is added to the one from the database,
+/// skipping what is already there and what belongs to words or exercises which
+/// do not exist. When merging, the removals listed on the archive are applied
+/// to rows which did not change afterwards, and rows which were removed from
+/// the database after they last changed on the archive are not brought back.
+/// Everything is imported on a single transaction.
+pub fn import_database(archive: &Value, policy: ConflictPolicy) -> Result<ImportSummary, String> {
+    match archive.get("version").and_then(Value::as_u64) {
+        Some(version) if version <= ARCHIVE_VERSION => {}
+        Some(version) => {
+            return Err(format!(
+                "the archive has version {version}, but only up to {ARCHIVE_VERSION} is supported"
+            ))
+        }
+        None => return Err("this is not an archive written by 'mihi export'".to_string()),
+    }
+
+    let conn = get_connection()?;
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    let mut summary = ImportSummary::default();
+
+    if policy == ConflictPolicy::Merge {
+        summary.deleted = import_deletions(&tx, archive)?;
+    }
+
+    // Parents are set once all tags exist, as they could come in any order.
+    let target = Target::new(&tx, "tags", "name")?;
+    let mut parents = vec![];
+    for tag in section(archive, "tags") {
+        if let Some(id) = target.import(&tx, tag, &[], policy, &mut summary.tags)? {
+            parents.push((id, tag.get("parent").and_then(Value::as_str)));
+        }
+    }
+    for (id, parent) in parents {
+        tx.execute(
+            "UPDATE tags SET parent_id = (SELECT id FROM tags WHERE name = ?2) WHERE id = ?1",
+            params![id, parent],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+
+    let target = Target::new(&tx, "lessons", "name")?;
+    for lesson in section(archive, "lessons") {
+        target.import(&tx, lesson, &[], policy, &mut summary.lessons)?;
+    }
+
+    // Relations are set once all words exist, as they could point to words
+    // further down the archive.
+    let target = Target::new(&tx, "words", "enunciated")?;
+    let mut relations = vec![];
+    for word in section(archive, "words") {
+        let extra = [("lesson_id", lesson_id(&tx, word)?)];
+        let Some(id) = target.import(&tx, word, &extra, policy, &mut summary.words)? else {
+            continue;
+        };
+        let at = time_of(&tx, word, "updated_at")?;
+        replace_associations(
+            &tx,
+            "tag_associations",
+            "word_id",
+            ("tag_id", "tags", "name"),
+            (id, &at),
+            &strings(word, "tags"),
+        )?;
+        relations.push((id, at, word.get("relations").and_then(Value::as_array)));
+    }
+    for (id, at, list) in relations {
+        tx.execute("DELETE FROM word_relations WHERE source_id = ?1", [id])
+            .map_err(|e| e.to_string())?;
+        for relation in list.into_iter().flatten() {
+            tx.execute(
+                "INSERT OR IGNORE INTO word_relations \
+                     (source_id, destination_id, kind, created_at, updated_at) \
+                 SELECT ?1, id, ?3, ?4, ?4 \
+                 FROM words WHERE enunciated = ?2",
+                params![
+                    id,
+                    relation.get("word").and_then(Value::as_str),
+                    relation.get("kind").and_then(Value::as_i64),
+                    at
+                ],
+            )
+            .map_err(|e| e.to_string())?;
+        }
+    }
+
+    let target = Target::new(&tx, "exercises", "title")?;
+    for exercise in section(archive, "exercises") {
+        let extra = [("lesson_id", lesson_id(&tx, exercise)?)];
+        let Some(id) = target.import(&tx, exercise, &extra, policy, &mut summary.exercises)? else {
+            continue;
+        };
+        let at = time_of(&tx, exercise, "updated_at")?;
+        replace_associations(
+            &tx,
+            "exercise_tag_associations",
+            "exercise_id",
+            ("tag_id", "tags", "name"),
+            (id, &at),
+            &strings(exercise, "tags"),
+        )?;
+        replace_associations(
+            &tx,
+            "exercise_words",
+            "exercise_id",
+            ("word_id", "words", "enunciated"),
+            (id, &at),
+            &strings(exercise, "words"),
+        )?;
+    }
+
+    for form in section(archive, "forms") {
+        import_form(&tx, form, policy, &mut summary.forms)?;
+    }
+
+    import_history_sections(&tx, archive, &mut summary)?;
+
+    match tx.commit() {
+        Ok(_) => Ok(summary),
+        Err(e) => Err(format!("could not import the archive: {e}")),
+    }
+}
+
+// Imports the history from the given `archive` (see `import_history`), which
+// refers to words by their enunciate and to exercises by their title.
+fn import_history_sections(
+    conn: &Connection,
+    archive: &Value,
+    summary: &mut ImportSummary,
+) -> Result<(), String> {
+    const WORDS: (&str, &str) = ("words", "enunciated");
+
+    for note in section(archive, "notes") {
+        let Some(word) = reference(conn, note, "word", WORDS)? else {
+            summary.notes.skipped += 1;
+            continue;
+        };
+        let values = [
+            ("word_id", word),
+            ("session", field(note, "session")),
+            ("body", field(note, "body")),
+            (
+                "created_at",
+                SqlValue::Text(time_of(conn, note, "created_at")?),
+            ),
+        ];
+        let key = ["word_id", "body", "created_at"];
+        import_history(conn, "notes", &values, &key, &mut summary.notes)?;
+    }
+
+    for session in section(archive, "sessions") {
+        let values = [
+            ("kind", field(session, "kind")),
+            ("asked", field(session, "asked")),
+            ("correct", field(session, "correct")),
+            ("category", field(session, "category")),
+            ("tags", field(session, "tags")),
+            ("time_limit", field(session, "time_limit")),
+            (
+                "started_at",
+                SqlValue::Text(time_of(conn, session, "started_at")?),
+            ),
+            (
+                "finished_at",
+                SqlValue::Text(time_of(conn, session, "finished_at")?),
+            ),
+        ];
+        let key = ["kind", "started_at", "finished_at"];
+        let Some(id) = import_history(conn, "sessions", &values, &key, &mut summary.sessions)?
+        else {
+            continue;
+        };
+
+        // Answers come along with their session, and so they are left out
+        // whenever their session is.
+        let answers = session.get("answers").and_then(Value::as_array);
+        for answer in answers.into_iter().flatten().filter_map(Value::as_object) {
+            let Some(word @ SqlValue::Integer(_)) = reference(conn, answer, "word", WORDS)? else {
+                summary.session_answers.skipped += 1;
+                continue;
+            };
+            conn.execute(
+                "INSERT INTO session_answers \
+                     (session_id, word_id, given, succeeded, response_ms, created_at) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    id,
+                    word,
+                    field(answer, "given"),
+                    field(answer, "succeeded"),
+                    field(answer, "response_ms"),
+                    time_of(conn, answer, "created_at")?
+                ],
+            )
+            .map_err(|e| format!("could not import into 'session_answers': {e}"))?;
+            summary.session_answers.created += 1;
+        }
+    }
+
+    for attempt in section(archive, "drill_attempts") {
+        let Some(word @ SqlValue::Integer(_)) = reference(conn, attempt, "word", WORDS)? else {
+            summary.drill_attempts.skipped += 1;
+            continue;
+        };
+        let values = [
+            ("word_id", word),
+            ("drill", field(attempt, "drill")),
+            ("expected", field(attempt, "expected")),
+            ("given", field(attempt, "given")),
+            ("succeeded", field(attempt, "succeeded")),
+            (
+                "created_at",
+                SqlValue::Text(time_of(conn, attempt, "created_at")?),
+            ),
+        ];
+        let key = ["word_id", "drill", "given", "created_at"];
+        import_history(
+            conn,
+            "drill_attempts",
+            &values,
+            &key,
+            &mut summary.drill_attempts,
+        )?;
+    }
+
+    for attempt in section(archive, "exercise_attempts") {
+        let Some(exercise @ SqlValue::Integer(_)) =
+            reference(conn, attempt, "exercise", ("exercises", "title"))?
+        else {
+            summary.exercise_attempts.skipped += 1;
+            continue;
+        };
+        let values = [
+            ("exercise_id", exercise),
+            ("seconds", field(attempt, "seconds")),
+            ("succeeded", field(attempt, "succeeded")),
+            (
+                "created_at",
+                SqlValue::Text(time_of(conn, attempt, "created_at")?),
+            ),
+        ];
+        import_history(
+            conn,
+            "exercise_attempts",
+            &values,
+            &["exercise_id", "seconds", "created_at"],
+            &mut summary.exercise_attempts,
+        )?;
+    }
+
+    for exam in section(archive, "exams") {
+        let values = [
+            ("tags", field(exam, "tags")),
+            ("questions", field(exam, "questions")),
+            ("score", field(exam, "score")),
+            ("breakdown", field(exam, "breakdown")),
+            (
+                "created_at",
+                SqlValue::Text(time_of(conn, exam, "created_at")?),
+            ),
+        ];
+        let key = ["tags", "questions", "created_at"];
+        import_history(conn, "exams", &values, &key, &mut summary.exams)?;
+    }
+
+    Ok(())
+}
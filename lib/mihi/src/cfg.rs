@@ -1,31 +1,12 @@
+use crate::inflection::Case;
+use crate::word::Word;
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::{self, BufRead, BufReader, Error};
-use std::path::{Path, PathBuf};
-
-/// Returns the configuration path for the application, and it even creates it
-/// if it doesn't exist already.
-pub fn get_config_path() -> Result<PathBuf, String> {
-    let dir = match &std::env::var("XDG_CONFIG_HOME") {
-        Ok(path) => PathBuf::from(path),
-        Err(_) => match &std::env::var("HOME") {
-            Ok(path) => Path::new(path).join(".config"),
-            Err(_) => {
-                return Err(String::from(
-                    "cannot find a suitable path for the configuration",
-                ))
-            }
-        },
-    }
-    .join("mihi");
 
-    match std::fs::create_dir_all(&dir) {
-        Ok(_) => {}
-        Err(e) => return Err(e.to_string()),
-    };
-
-    Ok(dir)
-}
+pub use crate::schedule::Scheduler;
+pub use mihi_core::matching::MacronStrictness;
+pub use mihi_store::get_config_path;
 
 /// The case order to be followed by the current session. This is stored in the
 /// configuration.
@@ -37,14 +18,31 @@ pub enum CaseOrder {
 }
 
 impl CaseOrder {
-    /// Returns the current case order as a bunch of integers which represent
-    /// each case on a given order.
-    pub fn to_usizes(&self) -> [usize; 7] {
+    /// Returns all the cases following the current case order.
+    pub fn cases(&self) -> [Case; 7] {
         match self {
-            CaseOrder::European => [0, 1, 2, 3, 4, 5, 6],
-            CaseOrder::English => [0, 3, 4, 2, 5, 1, 6],
+            CaseOrder::European => Case::ALL,
+            CaseOrder::English => [
+                Case::Nominative,
+                Case::Genitive,
+                Case::Dative,
+                Case::Accusative,
+                Case::Ablative,
+                Case::Vocative,
+                Case::Locative,
+            ],
         }
     }
+
+    /// Returns the cases to be shown for the given `word` following the
+    /// current case order. That is, the locative is left out unless the word
+    /// has it.
+    pub fn cases_for(&self, word: &Word) -> Vec<Case> {
+        self.cases()
+            .into_iter()
+            .filter(|&case| case != Case::Locative || word.locative)
+            .collect()
+    }
 }
 
 /// Representation for languages supported by this application.
@@ -105,6 +103,28 @@ pub fn add_language(language: String) -> Result<(), String> {
 pub struct Configuration {
     pub language: Language,
     pub case_order: CaseOrder,
+    pub max_session_items: Option<usize>,
+    pub max_session_minutes: Option<u64>,
+    /// Minimum number of words that are expected for each kind of paradigm
+    /// (e.g. 10 words for the 'istem' kind).
+    pub coverage_goals: Vec<(String, usize)>,
+    /// Whether words which occur in the user's own texts should be practiced
+    /// more often.
+    pub boost_occurrences: bool,
+    /// Whether verbs should also accept their syncopated perfect forms (e.g.
+    /// 'amāsse') and the alternative '-ēre' ending (e.g. 'amāvēre').
+    pub alternative_verb_forms: bool,
+    /// Codes of the locales whose translations are to be used, in order, when
+    /// a word has none in the current locale (e.g. 'en' so words with no
+    /// Catalan translation can still be practiced in English).
+    pub locale_fallbacks: Vec<String>,
+    /// How strictly answers are graded on practice sessions.
+    pub macron_strictness: MacronStrictness,
+    /// How words are scheduled for review.
+    pub scheduler: Scheduler,
+    /// Maximum number of words which have never been reviewed to be
+    /// introduced on each practice session.
+    pub max_new_words: Option<usize>,
 }
 
 /// Reads the global configuration and returns a proper object for it. It will
@@ -119,6 +139,163 @@ pub fn configuration() -> Configuration {
     Configuration {
         language: Language::Latin,
         case_order,
+        max_session_items: read_limit_from(2),
+        max_session_minutes: read_limit_from(3),
+        coverage_goals: read_line_from(4)
+            .map(|line| parse_coverage_goals(&line))
+            .unwrap_or_default(),
+        boost_occurrences: read_line_from(5).is_ok_and(|line| line.trim() == "1"),
+        alternative_verb_forms: read_line_from(6).is_ok_and(|line| line.trim() == "1"),
+        locale_fallbacks: parse_locale_fallbacks(&read_line_from(7).unwrap_or_default()),
+        macron_strictness: read_line_from(8)
+            .ok()
+            .and_then(|line| MacronStrictness::try_from(line.trim()).ok())
+            .unwrap_or_default(),
+        scheduler: read_line_from(9)
+            .ok()
+            .and_then(|line| Scheduler::try_from(line.trim()).ok())
+            .unwrap_or_default(),
+        max_new_words: read_limit_from(10),
+    }
+}
+
+/// Set the limits for a practice session on the configuration. A limit of zero
+/// means that there is no limit for it.
+pub fn set_session_limits(items: usize, minutes: u64) -> Result<(), String> {
+    write_line_at(2, &items.to_string())?;
+    write_line_at(3, &minutes.to_string())
+}
+
+/// Set the coverage goals on the configuration. Goals with a target of zero
+/// are dropped.
+pub fn set_coverage_goals(goals: &[(String, usize)]) -> Result<(), String> {
+    let line = goals
+        .iter()
+        .filter(|(_, target)| *target > 0)
+        .map(|(kind, target)| format!("{kind}={target}"))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    write_line_at(4, &line)
+}
+
+/// Set whether words which occur in the user's own texts should be practiced
+/// more often.
+pub fn set_boost_occurrences(boost: bool) -> Result<(), String> {
+    write_line_at(5, if boost { "1" } else { "0" })
+}
+
+/// Set whether verbs should also accept their syncopated and alternative
+/// forms.
+pub fn set_alternative_verb_forms(enabled: bool) -> Result<(), String> {
+    write_line_at(6, if enabled { "1" } else { "0" })
+}
+
+/// Set the codes of the locales to fall back to when a word has no
+/// translation in the current locale. An empty list disables falling back.
+pub fn set_locale_fallbacks(codes: &[String]) -> Result<(), String> {
+    if codes.is_empty() {
+        write_line_at(7, NO_LOCALE_FALLBACK)
+    } else {
+        write_line_at(7, &codes.join(","))
+    }
+}
+
+/// Set how strictly answers are graded on practice sessions.
+pub fn set_macron_strictness(strictness: MacronStrictness) -> Result<(), String> {
+    write_line_at(8, &strictness.to_string())
+}
+
+/// Set how words are scheduled for review.
+pub fn set_scheduler(scheduler: Scheduler) -> Result<(), String> {
+    write_line_at(9, &scheduler.to_string())
+}
+
+/// Set the maximum number of new words to be introduced on each practice
+/// session. A limit of zero means that there is no limit.
+pub fn set_max_new_words(words: usize) -> Result<(), String> {
+    write_line_at(10, &words.to_string())
+}
+
+// Locale to fall back to when none has been configured.
+const DEFAULT_LOCALE_FALLBACK: &str = "en";
+
+// Value stored when falling back to other locales has been disabled. An empty
+// line cannot be used for this, as it's what is left for settings which have
+// not been configured.
+const NO_LOCALE_FALLBACK: &str = "none";
+
+// Parses the given `line` of locale fallbacks, which is formatted as a comma
+// separated list of locale codes (e.g. 'en,ca'). An empty line stands for the
+// default fallback.
+fn parse_locale_fallbacks(line: &str) -> Vec<String> {
+    let line = line.trim();
+    if line.is_empty() {
+        return vec![DEFAULT_LOCALE_FALLBACK.to_string()];
+    } else if line == NO_LOCALE_FALLBACK {
+        return vec![];
+    }
+
+    line.split(',')
+        .map(|code| code.trim())
+        .filter(|code| !code.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+// Parses the given `line` of coverage goals, which is formatted as a comma
+// separated list of '<KIND>=<TARGET>' pairs (e.g. 'istem=10,a=20'). Malformed
+// goals and goals with a target of zero are ignored.
+fn parse_coverage_goals(line: &str) -> Vec<(String, usize)> {
+    line.split(',')
+        .filter_map(|goal| {
+            let (kind, target) = goal.split_once('=')?;
+            let target = target.trim().parse::<usize>().ok()?;
+            let kind = kind.trim();
+            if kind.is_empty() || target == 0 {
+                None
+            } else {
+                Some((kind.to_string(), target))
+            }
+        })
+        .collect()
+}
+
+// Write the given `value` into the given `line` of the configuration, while
+// keeping the rest of lines as they were. Missing lines before the given one
+// are filled with their default values.
+fn write_line_at(line: usize, value: &str) -> Result<(), String> {
+    let path = get_config_path()?;
+    let cfg = path.join("languages.txt");
+
+    let defaults = ["latin", "european", "0", "0"];
+    let mut lines: Vec<String> = std::fs::read_to_string(&cfg)
+        .unwrap_or_default()
+        .lines()
+        .map(String::from)
+        .collect();
+    while lines.len() <= line {
+        lines.push(defaults.get(lines.len()).unwrap_or(&"").to_string());
+    }
+    lines[line] = value.to_string();
+
+    match std::fs::write(cfg, lines.join("\n") + "\n") {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not save the configuration: {e}")),
+    }
+}
+
+// Read a limit from the given line of the configuration. Returns None if it was
+// not set, or if it was set to zero.
+fn read_limit_from<T>(line: usize) -> Option<T>
+where
+    T: std::str::FromStr + Default + PartialEq,
+{
+    let value = read_line_from(line).ok()?.trim().parse::<T>().ok()?;
+    if value == T::default() {
+        None
+    } else {
+        Some(value)
     }
 }
 
@@ -138,3 +315,33 @@ fn read_line_from(line: usize) -> Result<String, Error> {
 
     Ok(line)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_locale_fallbacks() {
+        assert_eq!(parse_locale_fallbacks(""), vec!["en".to_string()]);
+        assert!(parse_locale_fallbacks("none").is_empty());
+        assert_eq!(
+            parse_locale_fallbacks("ca, en,"),
+            vec!["ca".to_string(), "en".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_parse_coverage_goals() {
+        assert!(parse_coverage_goals("").is_empty());
+        assert_eq!(
+            parse_coverage_goals("istem=10, a = 2"),
+            vec![("istem".to_string(), 10), ("a".to_string(), 2)]
+        );
+
+        // Malformed goals and goals with a target of zero are dropped.
+        assert_eq!(
+            parse_coverage_goals("us=0,is,=3,es=many,um=1"),
+            vec![("um".to_string(), 1)]
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use crate::get_connection;
+use crate::word::{Gender, Word};
+use rusqlite::params;
+
+/// Drills which are tracked apart from the translation of words, so their
+/// errors do not get mixed with the rest of the reviews.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Drill {
+    /// Telling the gender of nouns.
+    Gender,
+    /// Telling the comparative, the superlative and the adverb of adjectives.
+    Comparison,
+}
+
+impl TryFrom<&str> for Drill {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "gender" => Ok(Self::Gender),
+            "comparison" => Ok(Self::Comparison),
+            _ => Err(format!(
+                "unknown drill '{value}'. Available: gender, comparison"
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Drill {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Gender => write!(f, "gender"),
+            Self::Comparison => write!(f, "comparison"),
+        }
+    }
+}
+
+/// Returns true if the `given` gender is right for a noun of the `expected`
+/// one. Nouns which can be either masculine or feminine accept both.
+pub fn is_right_gender(expected: Gender, given: Gender) -> bool {
+    expected == given
+        || (expected == Gender::MasculineOrFeminine
+            && matches!(given, Gender::Masculine | Gender::Feminine))
+}
+
+/// How the attempts on a drill went.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DrillStats {
+    pub attempts: usize,
+    pub succeeded: usize,
+    /// The wrong answers, grouped by what was expected and what was given
+    /// instead, with the most frequent ones first.
+    pub errors: Vec<(String, String, usize)>,
+}
+
+/// Records an attempt on the given `drill` for the given `word`, where the
+/// `expected` answer was asked and the `given` one was answered.
+pub fn record_drill_attempt(
+    word: &Word,
+    drill: Drill,
+    expected: &str,
+    given: &str,
+    succeeded: bool,
+) -> Result<(), String> {
+    let conn = get_connection()?;
+
+    match conn.execute(
+        "INSERT INTO drill_attempts (word_id, drill, expected, given, succeeded, created_at) \
+         VALUES (?1, ?2, ?3, ?4, ?5, datetime('now'))",
+        params![word.id, drill.to_string(), expected, given, succeeded],
+    ) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!(
+            "could not record the attempt for '{}': {e}",
+            word.enunciated
+        )),
+    }
+}
+
+/// Returns how the attempts on the given `drill` went so far.
+pub fn select_drill_stats(drill: Drill) -> Result<DrillStats, String> {
+    let conn = get_connection()?;
+    let name = drill.to_string();
+
+    let (attempts, succeeded) = match conn.query_row(
+        "SELECT COUNT(*), COALESCE(SUM(succeeded), 0) FROM drill_attempts WHERE drill = ?1",
+        [&name],
+        |row| Ok((row.get::<usize, isize>(0)?, row.get::<usize, isize>(1)?)),
+    ) {
+        Ok(counts) => counts,
+        Err(e) => return Err(format!("could not read the attempts for '{name}': {e}")),
+    };
+
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT expected, given, COUNT(*) AS errors FROM drill_attempts \
+             WHERE drill = ?1 AND succeeded = 0 \
+             GROUP BY expected, given \
+             ORDER BY errors DESC, expected ASC",
+        )
+        .map_err(|e| e.to_string())?;
+    let errors = stmt
+        .query_map([&name], |row| {
+            Ok((
+                row.get(0)?,
+                row.get(1)?,
+                row.get::<usize, isize>(2)? as usize,
+            ))
+        })
+        .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| format!("could not read the errors for '{name}': {e}"))?;
+
+    Ok(DrillStats {
+        attempts: attempts as usize,
+        succeeded: succeeded as usize,
+        errors,
+    })
+}
@@ -0,0 +1,308 @@
+use crate::get_connection;
+use crate::word::{select_tagged_words, Word};
+use rusqlite::params;
+
+pub use mihi_core::matching::partial_credit;
+
+/// A group of tags to be examined together, as expanded from the specification
+/// given by the user (e.g. 'cap-3' from 'cap-1..cap-10'). The `tags` are the
+/// names of the existing tags which belong to the group: the one named after
+/// it, and all of its children (e.g. 'cap-3/vocābula').
+#[derive(Clone, Debug, PartialEq)]
+pub struct TagGroup {
+    pub name: String,
+    pub tags: Vec<String>,
+}
+
+// Splits the given `value` into its prefix and its trailing number (e.g.
+// 'cap-' and 10 for 'cap-10'), or returns None if it does not end with a
+// number.
+fn split_numbered(value: &str) -> Option<(&str, usize)> {
+    let prefix = value.trim_end_matches(|c: char| c.is_ascii_digit());
+    if prefix.len() == value.len() {
+        return None;
+    }
+    value[prefix.len()..]
+        .parse::<usize>()
+        .ok()
+        .map(|number| (prefix, number))
+}
+
+// Returns the names from `existing` which are either `name` itself or one of
+// its children.
+fn tags_under(name: &str, existing: &[String]) -> Vec<String> {
+    existing
+        .iter()
+        .filter(|tag| {
+            tag.as_str() == name
+                || tag
+                    .strip_prefix(name)
+                    .is_some_and(|rest| rest.starts_with('/'))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Expands the given tag `spec` into the groups of tags from `existing` which
+/// it refers to. The `spec` is either the name of a single tag, or a range of
+/// numbered tags such as 'cap-1..cap-10' (which can also be written as
+/// 'cap-1..10'). Tags are hierarchical, so each group also contains the
+/// children of the named tag (e.g. 'cap-1/lectiō' for 'cap-1'). Tags from the
+/// range which do not exist are skipped, but it's an error if nothing matches
+/// at all.
+pub fn expand_tag_spec(spec: &str, existing: &[String]) -> Result<Vec<TagGroup>, String> {
+    let spec = spec.trim();
+
+    let names = match spec.split_once("..") {
+        Some((start, end)) => {
+            let Some((prefix, first)) = split_numbered(start.trim()) else {
+                return Err(format!("'{start}' does not end with a number"));
+            };
+            let end = end.trim();
+            let last = match end.parse::<usize>() {
+                Ok(last) => last,
+                Err(_) => match split_numbered(end) {
+                    Some((other, last)) if other == prefix => last,
+                    _ => return Err(format!("'{start}' and '{end}' are not from the same range")),
+                },
+            };
+            if first > last {
+                return Err(format!("the range '{spec}' is empty"));
+            }
+
+            (first..=last).map(|n| format!("{prefix}{n}")).collect()
+        }
+        None => vec![spec.to_string()],
+    };
+
+    let groups: Vec<TagGroup> = names
+        .into_iter()
+        .map(|name| TagGroup {
+            tags: tags_under(&name, existing),
+            name,
+        })
+        .filter(|group| !group.tags.is_empty())
+        .collect();
+    if groups.is_empty() {
+        return Err(format!("no tags match '{spec}'"));
+    }
+    Ok(groups)
+}
+
+/// A question from an exam: the translation of `word`, which is expected to
+/// be any of the comma-separated translations from `expected`. The `group` is
+/// the name of the tag group it was picked from.
+#[derive(Clone, Debug)]
+pub struct Question {
+    pub word: Word,
+    pub group: String,
+    pub expected: String,
+}
+
+/// Builds an exam of at most `size` questions out of the words tagged by the
+/// given `groups`. Words are picked at random and in turns from each group, so
+/// all the material is equally represented unless a group runs out of words.
+/// Words without a translation for the given `locale` are skipped, and no word
+/// is asked twice.
+pub fn build_exam(groups: &[TagGroup], size: usize, locale: &str) -> Result<Vec<Question>, String> {
+    let mut pools = vec![];
+    for group in groups {
+        let words = select_tagged_words(&group.tags)?;
+        pools.push(words.into_iter());
+    }
+
+    let mut res: Vec<Question> = vec![];
+    while res.len() < size {
+        let mut picked = false;
+
+        for (group, pool) in groups.iter().zip(pools.iter_mut()) {
+            if res.len() == size {
+                break;
+            }
+            for word in pool.by_ref() {
+                if res.iter().any(|q| q.word.id == word.id) {
+                    continue;
+                }
+                let Some(expected) = word.translation.get(locale).and_then(|t| t.as_str()) else {
+                    continue;
+                };
+                res.push(Question {
+                    expected: expected.to_string(),
+                    word,
+                    group: group.name.clone(),
+                });
+                picked = true;
+                break;
+            }
+        }
+
+        if !picked {
+            break;
+        }
+    }
+
+    Ok(res)
+}
+
+/// The summary of an exam which has been taken, as stored in the 'exams'
+/// table. The `breakdown` contains the score and the number of questions for
+/// each of the tag groups.
+#[derive(Clone, Debug, Default)]
+pub struct ExamSummary {
+    pub id: i32,
+    pub tags: String,
+    pub questions: usize,
+    pub score: f64,
+    pub breakdown: Vec<(String, f64, usize)>,
+    pub created_at: String,
+}
+
+impl ExamSummary {
+    /// Returns the summary for the given `questions` and the `scores` obtained
+    /// for each of them, where `tags` is how the material was specified.
+    pub fn new(tags: &str, questions: &[Question], scores: &[f64]) -> Self {
+        let mut breakdown: Vec<(String, f64, usize)> = vec![];
+        for (question, score) in questions.iter().zip(scores.iter()) {
+            match breakdown.iter_mut().find(|(g, _, _)| *g == question.group) {
+                Some((_, total, count)) => {
+                    *total += score;
+                    *count += 1;
+                }
+                None => breakdown.push((question.group.clone(), *score, 1)),
+            }
+        }
+
+        Self {
+            tags: tags.to_string(),
+            questions: scores.len(),
+            score: scores.iter().sum(),
+            breakdown,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the score as a percentage of the questions.
+    pub fn percentage(&self) -> f64 {
+        if self.questions == 0 {
+            0.0
+        } else {
+            self.score * 100.0 / self.questions as f64
+        }
+    }
+}
+
+impl std::fmt::Display for ExamSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "Exam taken on {}", self.created_at)?;
+        writeln!(f, "Material: {}", self.tags)?;
+        write!(
+            f,
+            "Score: {}/{} ({:.0}%)",
+            self.score,
+            self.questions,
+            self.percentage()
+        )?;
+        for (group, score, count) in self.breakdown.iter() {
+            write!(f, "\n  {group}: {score}/{count}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Saves the given exam `summary` into the database, and returns it with its
+/// ID and creation date set.
+pub fn save_exam(summary: ExamSummary) -> Result<ExamSummary, String> {
+    let conn = get_connection()?;
+
+    if let Err(e) = conn.execute(
+        "INSERT INTO exams (tags, questions, score, breakdown, created_at) \
+         VALUES (?1, ?2, ?3, ?4, datetime('now'))",
+        params![
+            summary.tags,
+            summary.questions as isize,
+            summary.score,
+            serde_json::to_string(&summary.breakdown).unwrap(),
+        ],
+    ) {
+        return Err(format!("could not save the exam: {e}"));
+    }
+
+    let id = conn.last_insert_rowid();
+    match conn.query_row("SELECT created_at FROM exams WHERE id = ?1", [id], |row| {
+        row.get::<usize, String>(0)
+    }) {
+        Ok(created_at) => Ok(ExamSummary {
+            id: id as i32,
+            created_at,
+            ..summary
+        }),
+        Err(e) => Err(format!("could not fetch the saved exam: {e}")),
+    }
+}
+
+/// Returns the summaries of all the exams taken so far, from the oldest to the
+/// newest.
+pub fn select_exams() -> Result<Vec<ExamSummary>, String> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id, tags, questions, score, breakdown, created_at \
+             FROM exams \
+             ORDER BY created_at, id",
+        )
+        .unwrap();
+    let mut it = stmt.query([]).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(ExamSummary {
+            id: row.get(0).unwrap(),
+            tags: row.get(1).unwrap(),
+            questions: row.get::<usize, isize>(2).unwrap() as usize,
+            score: row.get(3).unwrap(),
+            breakdown: serde_json::from_str(&row.get::<usize, String>(4).unwrap())
+                .unwrap_or_default(),
+            created_at: row.get(5).unwrap(),
+        });
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_tag_spec() {
+        let existing: Vec<String> = ["cap-1", "cap-2", "cap-2/lectiō", "cap-20", "cap-4", "LLPSI"]
+            .map(String::from)
+            .to_vec();
+
+        let groups = expand_tag_spec("cap-1..cap-4", &existing).unwrap();
+        assert_eq!(
+            groups,
+            [
+                TagGroup {
+                    name: "cap-1".to_string(),
+                    tags: vec!["cap-1".to_string()],
+                },
+                TagGroup {
+                    name: "cap-2".to_string(),
+                    tags: vec!["cap-2".to_string(), "cap-2/lectiō".to_string()],
+                },
+                TagGroup {
+                    name: "cap-4".to_string(),
+                    tags: vec!["cap-4".to_string()],
+                },
+            ]
+        );
+        assert_eq!(expand_tag_spec("cap-1..4", &existing).unwrap(), groups);
+        assert_eq!(expand_tag_spec("LLPSI", &existing).unwrap().len(), 1);
+
+        assert!(expand_tag_spec("cap-5..cap-9", &existing).is_err());
+        assert!(expand_tag_spec("cap-4..cap-1", &existing).is_err());
+        assert!(expand_tag_spec("cap-1..pars-4", &existing).is_err());
+        assert!(expand_tag_spec("cap..cap-4", &existing).is_err());
+        assert!(expand_tag_spec("Missing", &existing).is_err());
+    }
+}
@@ -1,17 +1,83 @@
+use crate::cfg::configuration;
 use crate::get_connection;
+use crate::pensum::Pensum;
+use crate::schedule::{Schedule, Scheduler};
+use crate::template::Template;
 use rusqlite::params;
 
+pub use mihi_core::text::{diff_lines, DiffLine};
+
+/// Number of minutes that an exercise is expected to take when none was given.
+pub const DEFAULT_EXERCISE_MINUTES: usize = 5;
+
+/// Highest score which can be given to the solution of an exercise. Scores go
+/// from zero (i.e. no idea at all) up to this one (i.e. a perfect solution).
+pub const MAX_SCORE: usize = 5;
+
+/// Lowest score for which the solution of an exercise is taken as good enough.
+pub const PASSING_SCORE: usize = 3;
+
 /// The exercise kinds supported by this application.
 #[derive(Clone, Copy, Debug, Default)]
 pub enum ExerciseKind {
     #[default]
     Simple = 0,
+    /// The enunciate has blanks to be filled in, as parsed by `pensum::Pensum`.
+    Pensum,
+    /// Random numbers to be converted between digits, Roman numerals and Latin
+    /// words (see `numeral`). Neither the enunciate nor the solution are used,
+    /// as questions are generated each time.
+    Numerical,
+    /// Random forms of known words to be transformed into another one (e.g.
+    /// from singular into plural, or from active into passive). As with
+    /// numerical exercises, questions are generated each time.
+    Transformation,
+    /// The enunciate and the solution have placeholders (e.g. `{{noun:acc:sg}}`)
+    /// to be filled with forms of random known words each time, as parsed by
+    /// `template::Template`.
+    Template,
+    /// Random forms of known nouns whose case and number have to be
+    /// identified. As with numerical exercises, questions are generated each
+    /// time.
+    Parsing,
+    /// An English text (the enunciate) to be translated into Latin prose, with
+    /// a model translation as its solution.
+    Composition,
+    /// A Latin text which is read out loud, sentence by sentence, to be written
+    /// down. Its solution is the enunciate itself.
+    Dictation,
+}
+
+impl ExerciseKind {
+    /// Returns the enunciate for exercises of this kind if their questions are
+    /// generated each time, and None if they have to be written by the user.
+    pub fn generated_enunciate(&self) -> Option<&'static str> {
+        match self {
+            Self::Simple | Self::Pensum | Self::Template | Self::Composition | Self::Dictation => {
+                None
+            }
+            Self::Numerical => {
+                Some("Convert random numbers between digits, Roman numerals and Latin words.")
+            }
+            Self::Transformation => {
+                Some("Transform forms of the words you know into another number or voice.")
+            }
+            Self::Parsing => Some("Identify the case and number of forms of the nouns you know."),
+        }
+    }
 }
 
 impl std::fmt::Display for ExerciseKind {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::Simple => write!(f, "Simple"),
+            Self::Pensum => write!(f, "Pensum"),
+            Self::Numerical => write!(f, "Numerical"),
+            Self::Transformation => write!(f, "Transformation"),
+            Self::Template => write!(f, "Template"),
+            Self::Parsing => write!(f, "Parsing"),
+            Self::Composition => write!(f, "Composition"),
+            Self::Dictation => write!(f, "Dictation"),
         }
     }
 }
@@ -22,6 +88,13 @@ impl TryFrom<isize> for ExerciseKind {
     fn try_from(value: isize) -> Result<Self, Self::Error> {
         match value {
             0 => Ok(Self::Simple),
+            1 => Ok(Self::Pensum),
+            2 => Ok(Self::Numerical),
+            3 => Ok(Self::Transformation),
+            4 => Ok(Self::Template),
+            5 => Ok(Self::Parsing),
+            6 => Ok(Self::Composition),
+            7 => Ok(Self::Dictation),
             _ => Err("unknonwn exercise kind"),
         }
     }
@@ -33,7 +106,16 @@ impl TryFrom<&str> for ExerciseKind {
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         match value {
             "simple" => Ok(Self::Simple),
-            _ => Err("unknonwn exercise kind. Available: simple"),
+            "pensum" => Ok(Self::Pensum),
+            "numerical" => Ok(Self::Numerical),
+            "transformation" => Ok(Self::Transformation),
+            "template" => Ok(Self::Template),
+            "parsing" => Ok(Self::Parsing),
+            "composition" => Ok(Self::Composition),
+            "dictation" => Ok(Self::Dictation),
+            _ => Err(
+                "unknonwn exercise kind. Available: simple, pensum, numerical, transformation, template, parsing, composition, dictation",
+            ),
         }
     }
 }
@@ -47,46 +129,132 @@ pub struct Exercise {
     pub solution: String,
     pub lessons: String,
     pub kind: ExerciseKind,
+    /// Number of minutes that the exercise is expected to take.
+    pub minutes: usize,
+    /// Score given to the last solution of this exercise, if it was ever
+    /// attempted.
+    pub score: Option<usize>,
+    /// Number of times that this exercise has been attempted.
+    pub attempts: usize,
+    /// When this exercise was last attempted, if ever.
+    pub last_attempted_at: Option<String>,
+    /// When this exercise is due to be proposed again, as set after grading
+    /// it. Exercises which were never graded are always due.
+    pub due_at: Option<String>,
+}
+
+// Condition for exercises which are ready to be proposed: all the words linked
+// to them have been mastered (i.e. they had at least one successful run).
+const READY_CONDITION: &str = "NOT EXISTS ( \
+         SELECT 1 FROM exercise_words ew JOIN words w ON w.id = ew.word_id \
+         WHERE ew.exercise_id = exercises.id AND w.succeeded = 0)";
+
+// Condition for exercises which are due by the end of today, either because
+// they were never graded or because their interval has already passed.
+const DUE_CONDITION: &str =
+    "(due_at IS NULL OR due_at < datetime('now', 'start of day', '+1 day'))";
+
+// Columns to be selected for an exercise, in the order expected by
+// `exercise_from_row`.
+const EXERCISE_COLUMNS: &str = "id, title, enunciate, solution, lessons, kind, minutes, score, \
+                                attempts, last_attempted_at, due_at";
+
+// Order in which exercises are picked: the ones with the lowest scores come
+// first, but each week without attempting an exercise counts as one point less
+// on its score, so good exercises come back eventually. Exercises which were
+// never attempted have no score, and they go first.
+fn relevance_order() -> String {
+    format!(
+        "IFNULL(score, 0) \
+             - MIN(IFNULL(julianday('now') - julianday(last_attempted_at), 365) / 7, {MAX_SCORE}) ASC, \
+         last_attempted_at ASC"
+    )
+}
+
+// Returns the exercise from the given `row`, which has the columns from
+// `EXERCISE_COLUMNS` in this order.
+fn exercise_from_row(row: &rusqlite::Row) -> Result<Exercise, String> {
+    Ok(Exercise {
+        id: row.get(0).unwrap(),
+        title: row.get(1).unwrap(),
+        enunciate: row.get(2).unwrap(),
+        solution: row.get(3).unwrap(),
+        lessons: row.get(4).unwrap(),
+        kind: row.get::<usize, isize>(5).unwrap().try_into()?,
+        minutes: row.get::<usize, isize>(6).unwrap() as usize,
+        score: row
+            .get::<usize, Option<isize>>(7)
+            .unwrap()
+            .map(|s| s as usize),
+        attempts: row.get::<usize, isize>(8).unwrap() as usize,
+        last_attempted_at: row.get(9).unwrap(),
+        due_at: row.get(10).unwrap(),
+    })
 }
 
-/// Creates the given exercise into the database.
-pub fn create_exercise(exercise: Exercise) -> Result<(), String> {
+/// Creates the given exercise into the database, and returns its ID.
+pub fn create_exercise(exercise: Exercise) -> Result<i64, String> {
     let conn = get_connection()?;
     match conn.execute(
-        "INSERT INTO exercises (title, enunciate, solution, lessons, kind, \
+        "INSERT INTO exercises (title, enunciate, solution, lessons, kind, minutes, \
                                 updated_at, created_at) \
-         VALUES (?1, ?2, ?3, ?4, ?5, datetime('now'), datetime('now'))",
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, datetime('now'), datetime('now'))",
         params![
             exercise.title,
             exercise.enunciate,
             exercise.solution,
             exercise.lessons,
             exercise.kind as isize,
+            exercise.minutes as isize,
         ],
     ) {
-        Ok(_) => Ok(()),
+        Ok(_) => Ok(conn.last_insert_rowid()),
         Err(e) => Err(format!("could not create '{}': {}", exercise.title, e)),
     }
 }
 
-pub fn select_by_title(filter: Option<String>) -> Result<Vec<String>, String> {
+/// Returns the titles of the exercises which match the given `filter`, or all
+/// of them if None is passed. If `tags` is not empty, only exercises tagged
+/// with any of them or with any of their descendants are returned.
+pub fn select_by_title(filter: Option<String>, tags: &[String]) -> Result<Vec<String>, String> {
     let conn = get_connection()?;
 
+    let first = if filter.is_some() { 2 } else { 1 };
+    let tags_clause = if tags.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "AND {}",
+            crate::tag::exercise_tagged_clause("id", tags, first)
+        )
+    };
+
     let mut stmt;
     let mut it = match filter {
         Some(filter) => {
             stmt = conn
-                .prepare(
-                    "SELECT title FROM exercises WHERE title LIKE ('%' || ?1 || '%') ORDER BY title",
+                .prepare_cached(
+                    format!(
+                        "SELECT title FROM exercises \
+                         WHERE title LIKE ('%' || ?1 || '%') {tags_clause} \
+                         ORDER BY title"
+                    )
+                    .as_str(),
                 )
                 .unwrap();
-            stmt.query([filter.as_str()]).unwrap()
+            stmt.query(rusqlite::params_from_iter(
+                std::iter::once(&filter).chain(tags.iter()),
+            ))
+            .unwrap()
         }
         None => {
             stmt = conn
-                .prepare("SELECT title FROM exercises ORDER BY title")
+                .prepare_cached(
+                    format!("SELECT title FROM exercises WHERE 1 {tags_clause} ORDER BY title")
+                        .as_str(),
+                )
                 .unwrap();
-            stmt.query([]).unwrap()
+            stmt.query(rusqlite::params_from_iter(tags)).unwrap()
         }
     };
 
@@ -100,10 +268,8 @@ pub fn select_by_title(filter: Option<String>) -> Result<Vec<String>, String> {
 pub fn find_exercise_by_title(title: &str) -> Result<Exercise, String> {
     let conn = get_connection()?;
     let mut stmt = conn
-        .prepare(
-            "SELECT id, title, enunciate, solution, lessons, kind  \
-             FROM exercises \
-             WHERE title = ?1",
+        .prepare_cached(
+            format!("SELECT {EXERCISE_COLUMNS} FROM exercises WHERE title = ?1").as_str(),
         )
         .unwrap();
     let mut it = stmt.query([title]).unwrap();
@@ -111,14 +277,7 @@ pub fn find_exercise_by_title(title: &str) -> Result<Exercise, String> {
     match it.next() {
         Err(_) => Err("no exercises were found with this title".to_string()),
         Ok(rows) => match rows {
-            Some(row) => Ok(Exercise {
-                id: row.get(0).unwrap(),
-                title: row.get(1).unwrap(),
-                enunciate: row.get(2).unwrap(),
-                solution: row.get(3).unwrap(),
-                lessons: row.get(4).unwrap(),
-                kind: row.get::<usize, isize>(5).unwrap().try_into()?,
-            }),
+            Some(row) => exercise_from_row(row),
             None => Err("no exercises were found with this title".to_string()),
         },
     }
@@ -135,7 +294,7 @@ pub fn update_exercise(exercise: Exercise) -> Result<(), String> {
     match conn.execute(
         "UPDATE exercises \
          SET title = ?2, enunciate = ?3, solution = ?4, lessons = ?5, kind = ?6, \
-             updated_at = datetime('now') \
+             minutes = ?7, updated_at = datetime('now') \
          WHERE id = ?1",
         params![
             exercise.id,
@@ -144,6 +303,7 @@ pub fn update_exercise(exercise: Exercise) -> Result<(), String> {
             exercise.solution,
             exercise.lessons,
             exercise.kind as isize,
+            exercise.minutes as isize,
         ],
     ) {
         Ok(_) => Ok(()),
@@ -170,62 +330,522 @@ pub fn touch_exercise(exercise: &Exercise) -> Result<(), String> {
     }
 }
 
+/// Saves the given `score` as the one for the last solution of the given
+/// `exercise`. Passing scores also touch the exercise as in `touch_exercise`.
+/// The score is taken as the SM-2 quality for the schedule of the exercise,
+/// which becomes due again after its new interval. With `Scheduler::Legacy`
+/// its due date is left untouched.
+pub fn grade_exercise(exercise: &Exercise, score: usize) -> Result<(), String> {
+    if exercise.id == 0 {
+        return Err("invalid exercise to update; seems it has not been created before".to_string());
+    }
+    if score > MAX_SCORE {
+        return Err(format!("the score must be between 0 and {MAX_SCORE}"));
+    }
+
+    let conn = get_connection()?;
+
+    let schedule = match conn.query_row(
+        "SELECT ease, interval_days FROM exercises WHERE id = ?1",
+        [exercise.id],
+        |row| {
+            Ok(Schedule {
+                ease: row.get(0)?,
+                interval_days: row.get(1)?,
+            })
+        },
+    ) {
+        Ok(schedule) => schedule.graded(score),
+        Err(e) => return Err(format!("could not grade '{}': {}", exercise.title, e)),
+    };
+    let due_in = match configuration().scheduler {
+        Scheduler::Legacy => None,
+        _ => Some(format!("+{} days", schedule.interval_days)),
+    };
+
+    match conn.execute(
+        "UPDATE exercises \
+         SET score = ?2, ease = ?4, interval_days = ?5, \
+             due_at = COALESCE(datetime('now', ?6), due_at), \
+             updated_at = CASE WHEN ?2 >= ?3 THEN datetime('now') ELSE updated_at END \
+         WHERE id = ?1",
+        params![
+            exercise.id,
+            score as isize,
+            PASSING_SCORE as isize,
+            schedule.ease,
+            schedule.interval_days,
+            due_in
+        ],
+    ) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not grade '{}': {}", exercise.title, e)),
+    }
+}
+
 /// Delete an exercise from the database.
 pub fn delete_exercise(title: &str) -> Result<(), String> {
     let conn = get_connection()?;
 
+    // Attempts, tags and links to words are removed along with the exercise.
     match conn.execute("DELETE FROM exercises WHERE title = ?1", params![title]) {
         Ok(_) => Ok(()),
         Err(e) => Err(format!("could not remove '{title}': {e}")),
     }
 }
 
-// Get a list of exercises sorted by relevance. A maximum of `limit` exercises
-// will be returned, and you can also specify to filter the returned exercises
-// by `kind`.
+/// Returns the enunciates of the words linked to the exercise identified by
+/// `exercise_id`.
+pub fn select_exercise_words(exercise_id: i32) -> Result<Vec<String>, String> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT w.enunciated FROM words w \
+             JOIN exercise_words ew ON w.id = ew.word_id \
+             WHERE ew.exercise_id = ?1 \
+             ORDER BY w.enunciated",
+        )
+        .unwrap();
+    let mut it = stmt.query([exercise_id]).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(row.get::<usize, String>(0).unwrap());
+    }
+    Ok(res)
+}
+
+/// Links the exercise identified by `exercise_id` to the words with the given
+/// `enunciates`, and only to them.
+pub fn set_exercise_words(exercise_id: i64, enunciates: &[String]) -> Result<(), String> {
+    let conn = get_connection()?;
+    let tx = match conn.unchecked_transaction() {
+        Ok(tx) => tx,
+        Err(e) => return Err(format!("could not link words: {e}")),
+    };
+
+    let placeholders = crate::tag::placeholders(2, enunciates.len());
+    let values = || {
+        rusqlite::params_from_iter(
+            std::iter::once(rusqlite::types::Value::from(exercise_id))
+                .chain(enunciates.iter().map(|e| e.clone().into())),
+        )
+    };
+    if let Err(e) = tx.execute(
+        format!(
+            "DELETE FROM exercise_words \
+             WHERE exercise_id = ?1 \
+                   AND word_id NOT IN (SELECT id FROM words WHERE enunciated IN ({placeholders}))"
+        )
+        .as_str(),
+        values(),
+    ) {
+        return Err(format!("could not unlink words: {e}"));
+    }
+    if let Err(e) = tx.execute(
+        format!(
+            "INSERT OR IGNORE INTO exercise_words (exercise_id, word_id, created_at) \
+             SELECT ?1, id, datetime('now') FROM words WHERE enunciated IN ({placeholders})"
+        )
+        .as_str(),
+        values(),
+    ) {
+        return Err(format!("could not link words: {e}"));
+    }
+
+    match tx.commit() {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not link words: {e}")),
+    }
+}
+
+/// Returns the titles of the exercises linked to the word identified by
+/// `word_id`.
+pub fn select_exercises_for_word(word_id: i32) -> Result<Vec<String>, String> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT e.title FROM exercises e \
+             JOIN exercise_words ew ON e.id = ew.exercise_id \
+             WHERE ew.word_id = ?1 \
+             ORDER BY e.title",
+        )
+        .unwrap();
+    let mut it = stmt.query([word_id]).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(row.get::<usize, String>(0).unwrap());
+    }
+    Ok(res)
+}
+
+// Get a list of exercises sorted by relevance (see `relevance_order`). A
+// maximum of `limit` exercises will be returned, and you can also specify to
+// filter the returned exercises by `kind`. Exercises linked to words which have
+// not been mastered yet are left out, and so are the ones which are not due
+// yet (see `grade_exercise`).
 pub fn select_relevant_exercises(
     kind: Option<ExerciseKind>,
     limit: isize,
 ) -> Result<Vec<Exercise>, String> {
     let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            format!(
+                "SELECT {EXERCISE_COLUMNS} \
+                 FROM exercises \
+                 WHERE (?1 IS NULL OR kind = ?1) AND {READY_CONDITION} AND {DUE_CONDITION} \
+                 ORDER BY {} \
+                 LIMIT ?2",
+                relevance_order()
+            )
+            .as_str(),
+        )
+        .unwrap();
+    let mut it = stmt
+        .query(params![kind.map(|k| k as isize), limit])
+        .unwrap();
 
-    let mut stmt;
-    let mut it = match kind {
-        Some(kind) => {
-            stmt = conn
-                .prepare(
-                    "SELECT id, title, enunciate, solution, lessons, kind  \
-                     FROM exercises \
-                     WHERE kind = ?1 \
-                     ORDER BY updated_at DESC \
-                     LIMIT ?2",
-                )
-                .unwrap();
-            stmt.query([kind as isize, limit]).unwrap()
-        }
-        None => {
-            stmt = conn
-                .prepare(
-                    "SELECT id, title, enunciate, solution, lessons, kind  \
-                     FROM exercises \
-                     ORDER BY updated_at DESC \
-                     LIMIT ?1",
-                )
-                .unwrap();
-            stmt.query([limit]).unwrap()
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(exercise_from_row(row)?);
+    }
+    Ok(res)
+}
+
+/// Returns the exercises linked to the lesson identified by `lesson_id` which
+/// are ready to be proposed, sorted in the same way as in
+/// `select_relevant_exercises`. Lessons are studied on purpose, so exercises
+/// which are not due yet are included too.
+pub fn select_lesson_exercises(lesson_id: i32) -> Result<Vec<Exercise>, String> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            format!(
+                "SELECT {EXERCISE_COLUMNS} \
+                 FROM exercises \
+                 WHERE lesson_id = ?1 AND {READY_CONDITION} \
+                 ORDER BY {}",
+                relevance_order()
+            )
+            .as_str(),
+        )
+        .unwrap();
+    let mut it = stmt.query([lesson_id]).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(exercise_from_row(row)?);
+    }
+    Ok(res)
+}
+
+/// Records an attempt at solving the given `exercise` which took `seconds` to
+/// complete, and whether the user `succeeded` at it. The number of attempts
+/// and the time of the last one are kept on the exercise as well.
+pub fn record_attempt(exercise: &Exercise, seconds: u64, succeeded: bool) -> Result<(), String> {
+    if exercise.id == 0 {
+        return Err("invalid exercise to update; seems it has not been created before".to_string());
+    }
+
+    let conn = get_connection()?;
+    let tx = match conn.unchecked_transaction() {
+        Ok(tx) => tx,
+        Err(e) => {
+            return Err(format!(
+                "could not record attempt for '{}': {}",
+                exercise.title, e
+            ))
         }
     };
 
+    if let Err(e) = tx.execute(
+        "INSERT INTO exercise_attempts (exercise_id, seconds, succeeded, created_at) \
+         VALUES (?1, ?2, ?3, datetime('now'))",
+        params![exercise.id, seconds as i64, succeeded],
+    ) {
+        return Err(format!(
+            "could not record attempt for '{}': {}",
+            exercise.title, e
+        ));
+    }
+    match tx
+        .execute(
+            "UPDATE exercises \
+             SET attempts = attempts + 1, last_attempted_at = datetime('now') \
+             WHERE id = ?1",
+            params![exercise.id],
+        )
+        .and_then(|_| tx.commit())
+    {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!(
+            "could not record attempt for '{}': {}",
+            exercise.title, e
+        )),
+    }
+}
+
+/// Returns exercises sorted by relevance which fit into the given budget of
+/// `minutes`, optionally filtered by `kind`. The time for each exercise is
+/// estimated from the average of its past attempts, falling back to the
+/// expected duration for exercises which have never been attempted. Exercises
+/// which do not fit are skipped in favor of shorter ones, and as in
+/// `select_relevant_exercises`, so are the ones linked to words which have not
+/// been mastered yet and the ones which are not due yet.
+pub fn select_timed_exercises(
+    kind: Option<ExerciseKind>,
+    minutes: usize,
+) -> Result<Vec<Exercise>, String> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            format!(
+                "SELECT {EXERCISE_COLUMNS}, \
+                        COALESCE((SELECT AVG(seconds) FROM exercise_attempts \
+                                  WHERE exercise_id = exercises.id), minutes * 60) \
+                 FROM exercises \
+                 WHERE (?1 IS NULL OR kind = ?1) AND {READY_CONDITION} AND {DUE_CONDITION} \
+                 ORDER BY {}",
+                relevance_order()
+            )
+            .as_str(),
+        )
+        .unwrap();
+    let mut it = stmt.query([kind.map(|k| k as isize)]).unwrap();
+
+    let mut budget = (minutes * 60) as f64;
     let mut res = vec![];
     while let Some(row) = it.next().unwrap() {
-        res.push(Exercise {
-            id: row.get(0).unwrap(),
-            title: row.get(1).unwrap(),
-            enunciate: row.get(2).unwrap(),
-            solution: row.get(3).unwrap(),
-            lessons: row.get(4).unwrap(),
-            kind: row.get::<usize, isize>(5).unwrap().try_into()?,
-        });
+        let estimate: f64 = row.get(11).unwrap();
+        if estimate > budget {
+            continue;
+        }
+        budget -= estimate;
+
+        res.push(exercise_from_row(row)?);
     }
     Ok(res)
 }
+
+/// Returns the texts stored on all the exercises. That is, both the enunciate
+/// and the solution of each of them.
+pub fn select_exercise_texts() -> Result<Vec<String>, String> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare_cached("SELECT enunciate, solution FROM exercises ORDER BY id")
+        .unwrap();
+    let mut it = stmt.query([]).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(row.get::<usize, String>(0).unwrap());
+        res.push(row.get::<usize, String>(1).unwrap());
+    }
+    Ok(res)
+}
+
+// Line which separates the enunciate from the solution on the Markdown files
+// of exercises. It's a comment so it does not show up when rendered.
+const SOLUTION_MARKER: &str = "<!-- solution -->";
+
+/// Returns the given `exercise` with its `tags` as a Markdown file, where the
+/// metadata goes into the front matter and the body holds the enunciate and
+/// the solution. See `from_markdown`.
+pub fn to_markdown(exercise: &Exercise, tags: &[String]) -> String {
+    let mut res = format!(
+        "---\ntitle: {}\nkind: {}\nminutes: {}\n",
+        exercise.title,
+        exercise.kind.to_string().to_lowercase(),
+        exercise.minutes
+    );
+    if !tags.is_empty() {
+        res.push_str(&format!("tags: {}\n", tags.join(", ")));
+    }
+    let lessons = exercise.lessons.trim();
+    if !lessons.is_empty() {
+        res.push_str("lessons: |\n");
+        for line in lessons.lines() {
+            if line.is_empty() {
+                res.push('\n');
+            } else {
+                res.push_str(&format!("  {line}\n"));
+            }
+        }
+    }
+    res.push_str("---\n\n");
+
+    // Generated exercises have nothing else to be written.
+    if exercise.kind.generated_enunciate().is_some() {
+        return res;
+    }
+    res.push_str(exercise.enunciate.trim());
+    res.push('\n');
+    let solution = exercise.solution.trim();
+    if !solution.is_empty() {
+        res.push_str(&format!("\n{SOLUTION_MARKER}\n\n{solution}\n"));
+    }
+    res
+}
+
+/// Parses an exercise and the names of its tags from the given Markdown `text`
+/// as written by `to_markdown`. The front matter accepts the 'title' (which is
+/// required), 'kind', 'minutes', 'tags' and 'lessons' fields, where the latter
+/// can be given as an indented block after 'lessons: |'. The returned exercise
+/// has not been saved, so its ID is zero.
+pub fn from_markdown(text: &str) -> Result<(Exercise, Vec<String>), String> {
+    let mut lines = text.lines();
+    if lines.next().map(str::trim_end) != Some("---") {
+        return Err("the front matter is missing".to_string());
+    }
+
+    let mut exercise = Exercise {
+        minutes: DEFAULT_EXERCISE_MINUTES,
+        ..Default::default()
+    };
+    let mut tags = vec![];
+    let mut lessons: Option<Vec<&str>> = None;
+    let mut closed = false;
+
+    for line in lines.by_ref() {
+        if let Some(block) = lessons.as_mut() {
+            if let Some(line) = line.strip_prefix("  ") {
+                block.push(line);
+                continue;
+            } else if line.trim().is_empty() {
+                block.push("");
+                continue;
+            }
+        }
+        if line.trim_end() == "---" {
+            closed = true;
+            break;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once(':') else {
+            return Err(format!("malformed line on the front matter: '{line}'"));
+        };
+        let value = value.trim();
+        match key.trim() {
+            "title" => exercise.title = value.to_string(),
+            "kind" => exercise.kind = value.to_lowercase().as_str().try_into()?,
+            "minutes" => match value.parse::<usize>() {
+                Ok(0) | Err(_) => {
+                    return Err("the expected minutes must be a positive number".to_string())
+                }
+                Ok(minutes) => exercise.minutes = minutes,
+            },
+            "tags" => {
+                tags = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|t| !t.is_empty())
+                    .map(String::from)
+                    .collect()
+            }
+            "lessons" if value == "|" => lessons = Some(vec![]),
+            "lessons" => exercise.lessons = value.to_string(),
+            key => return Err(format!("unknown field '{key}' on the front matter")),
+        }
+    }
+    if !closed {
+        return Err("the front matter is not closed with '---'".to_string());
+    }
+    if exercise.title.is_empty() {
+        return Err("the title is required".to_string());
+    }
+    if let Some(block) = lessons {
+        exercise.lessons = block.join("\n").trim().to_string();
+    }
+
+    if let Some(enunciate) = exercise.kind.generated_enunciate() {
+        exercise.enunciate = enunciate.to_string();
+        return Ok((exercise, tags));
+    }
+
+    let body = lines.collect::<Vec<_>>().join("\n");
+    let (enunciate, solution) = match body.split_once(SOLUTION_MARKER) {
+        Some((enunciate, solution)) => (enunciate.trim(), solution.trim()),
+        None => (body.trim(), ""),
+    };
+    if enunciate.is_empty() {
+        return Err("the enunciate is required".to_string());
+    }
+    exercise.enunciate = enunciate.to_string();
+
+    // As when creating a pensum interactively, its solution can be taken from
+    // the answers on its blanks.
+    exercise.solution = match exercise.kind {
+        ExerciseKind::Pensum if solution.is_empty() => {
+            let pensum = Pensum::try_from(enunciate)?;
+            if pensum.needs_solution() {
+                return Err("the solution is required".to_string());
+            }
+            pensum.solution()
+        }
+        ExerciseKind::Pensum => {
+            Pensum::with_solution(enunciate, solution)?;
+            solution.to_string()
+        }
+        // A dictation is just the text being dictated.
+        ExerciseKind::Dictation => enunciate.to_string(),
+        ExerciseKind::Template if !solution.is_empty() => {
+            Template::solution(&Template::enunciate(enunciate)?, solution)?;
+            solution.to_string()
+        }
+        _ if solution.is_empty() => return Err("the solution is required".to_string()),
+        _ => solution.to_string(),
+    };
+
+    Ok((exercise, tags))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown() {
+        let exercise = Exercise {
+            title: "Rose".to_string(),
+            enunciate: "# Translate\n\nThe rose".to_string(),
+            solution: "rosa".to_string(),
+            lessons: "- Nominative\n\n- Singular".to_string(),
+            minutes: 10,
+            ..Default::default()
+        };
+        let tags = vec!["llpsi/cap01".to_string(), "grammar".to_string()];
+
+        let text = to_markdown(&exercise, &tags);
+        assert!(text.starts_with("---\ntitle: Rose\nkind: simple\nminutes: 10\n"));
+        let (parsed, parsed_tags) = from_markdown(&text).unwrap();
+        assert_eq!(parsed.title, exercise.title);
+        assert_eq!(parsed.enunciate, exercise.enunciate);
+        assert_eq!(parsed.solution, exercise.solution);
+        assert_eq!(parsed.lessons, exercise.lessons);
+        assert_eq!(parsed.minutes, 10);
+        assert_eq!(parsed_tags, tags);
+
+        let (pensum, _) =
+            from_markdown("---\ntitle: Sea\nkind: pensum\n---\nNauta in [marī] nāvigat.").unwrap();
+        assert_eq!(pensum.solution, "Nauta in marī nāvigat.");
+        assert_eq!(pensum.minutes, DEFAULT_EXERCISE_MINUTES);
+
+        let (numbers, _) = from_markdown("---\ntitle: Numbers\nkind: numerical\n---\n").unwrap();
+        assert!(numbers.enunciate.starts_with("Convert random numbers"));
+
+        let (dictation, _) =
+            from_markdown("---\ntitle: Rose\nkind: dictation\n---\nRosa pulchra est.").unwrap();
+        assert_eq!(dictation.solution, "Rosa pulchra est.");
+
+        assert!(from_markdown("title: Rose\n").is_err());
+        assert!(from_markdown("---\ntitle: Rose\n").is_err());
+        assert!(from_markdown("---\nkind: simple\n---\nThe rose").is_err());
+        assert!(from_markdown("---\ntitle: Rose\ncolor: red\n---\nThe rose").is_err());
+        assert!(from_markdown("---\ntitle: Rose\n---\nThe rose").is_err());
+    }
+}
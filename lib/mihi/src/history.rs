@@ -0,0 +1,473 @@
+use crate::get_connection;
+use crate::session::SessionSummary;
+use crate::word::{select_words_by_ids, Category, Word};
+use rusqlite::params;
+use std::time::Duration;
+
+/// What was practiced on a session, as filtered by the user.
+#[derive(Clone, Debug, Default)]
+pub struct SessionFilters {
+    pub category: Option<Category>,
+    /// Boolean flags that the words had to have (e.g. 'deponent'). They are
+    /// only kept for resuming interrupted sessions.
+    pub flags: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+/// Saves the given `summary` of a practice session of the given `kind` (e.g.
+/// 'translation', 'reverse') alongside all of its answers and how long they
+/// took. The session is considered to be finished right now. Returns the ID of
+/// the saved session.
+pub fn save_session(
+    summary: &SessionSummary,
+    kind: &str,
+    filters: &SessionFilters,
+) -> Result<i64, String> {
+    let conn = get_connection()?;
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+
+    if let Err(e) = tx.execute(
+        "INSERT INTO sessions \
+             (kind, asked, correct, category, tags, time_limit, started_at, finished_at) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, datetime('now', ?7), datetime('now'))",
+        params![
+            kind,
+            summary.questions() as isize,
+            summary.correct() as isize,
+            filters.category.map(|cat| cat.to_string()),
+            filters.tags.join(","),
+            summary.time_limit().map(|limit| limit.as_secs() as i64),
+            format!("-{} seconds", summary.elapsed().as_secs()),
+        ],
+    ) {
+        return Err(format!("could not save the session: {e}"));
+    }
+    let id = tx.last_insert_rowid();
+
+    for answer in summary.answers() {
+        if let Err(e) = tx.execute(
+            "INSERT INTO session_answers \
+                 (session_id, word_id, given, succeeded, response_ms, created_at) \
+             VALUES (?1, ?2, ?3, ?4, ?5, datetime('now'))",
+            params![
+                id,
+                answer.word_id,
+                answer.given,
+                answer.correct,
+                answer.response.as_millis() as i64
+            ],
+        ) {
+            return Err(format!("could not save the answers of the session: {e}"));
+        }
+    }
+
+    match tx.commit() {
+        Ok(_) => Ok(id),
+        Err(e) => Err(format!("could not save the session: {e}")),
+    }
+}
+
+/// A practice session which was interrupted before going through all of its
+/// words, so it can be resumed later on.
+#[derive(Clone, Debug)]
+pub struct PendingSession {
+    /// Kind of the session, as given to `save_session`.
+    pub kind: String,
+    pub filters: SessionFilters,
+    /// Words which were still to be reviewed, in order.
+    pub words: Vec<Word>,
+}
+
+/// Saves the given `words` which were still to be reviewed on an interrupted
+/// session of the given `kind` and `filters`, so it can be resumed with
+/// `take_pending_session`. Only the last interrupted session is kept.
+pub fn save_pending_session(
+    kind: &str,
+    filters: &SessionFilters,
+    words: &[Word],
+) -> Result<(), String> {
+    let conn = get_connection()?;
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+
+    let ids = words
+        .iter()
+        .map(|word| word.id.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    if let Err(e) = tx
+        .execute("DELETE FROM pending_sessions", [])
+        .and_then(|_| {
+            tx.execute(
+                "INSERT INTO pending_sessions \
+                     (kind, category, flags, tags, word_ids, created_at) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, datetime('now'))",
+                params![
+                    kind,
+                    filters.category.map(|cat| cat as isize),
+                    filters.flags.join(","),
+                    filters.tags.join(","),
+                    ids
+                ],
+            )
+        })
+    {
+        return Err(format!("could not save the pending session: {e}"));
+    }
+
+    tx.commit()
+        .map_err(|e| format!("could not save the pending session: {e}"))
+}
+
+// Returns the items from the given comma-separated `list`.
+fn split_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Returns the last interrupted session, if any, and forgets about it. Words
+/// which have been removed since then are skipped.
+pub fn take_pending_session() -> Result<Option<PendingSession>, String> {
+    let conn = get_connection()?;
+
+    let row = conn.query_row(
+        "SELECT kind, category, flags, tags, word_ids FROM pending_sessions \
+         ORDER BY id DESC LIMIT 1",
+        [],
+        |row| {
+            Ok((
+                row.get::<usize, String>(0)?,
+                row.get::<usize, Option<isize>>(1)?,
+                row.get::<usize, String>(2)?,
+                row.get::<usize, String>(3)?,
+                row.get::<usize, String>(4)?,
+            ))
+        },
+    );
+    let (kind, category, flags, tags, ids) = match row {
+        Ok(row) => row,
+        Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(None),
+        Err(e) => return Err(format!("could not read the pending session: {e}")),
+    };
+    if let Err(e) = conn.execute("DELETE FROM pending_sessions", []) {
+        return Err(format!("could not remove the pending session: {e}"));
+    }
+
+    let ids: Vec<i32> = ids.split(',').filter_map(|id| id.parse().ok()).collect();
+    Ok(Some(PendingSession {
+        kind,
+        filters: SessionFilters {
+            category: category.map(Category::try_from).transpose()?,
+            flags: split_list(&flags),
+            tags: split_list(&tags),
+        },
+        words: select_words_by_ids(&ids)?,
+    }))
+}
+
+/// How many answers were given on something (e.g. a word, a category), how
+/// many of them were right and how long they took.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Accuracy {
+    pub name: String,
+    pub asked: usize,
+    pub correct: usize,
+    /// Average time it took to answer, if it was measured for any of the
+    /// answers.
+    pub response: Option<Duration>,
+}
+
+impl Accuracy {
+    /// Returns the share of answers which were right, as a percentage.
+    pub fn percentage(&self) -> f64 {
+        if self.asked == 0 {
+            0.0
+        } else {
+            self.correct as f64 * 100.0 / self.asked as f64
+        }
+    }
+}
+
+/// Statistics built out of the history of practice sessions.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HistoryStats {
+    /// Accuracy for each word which has been asked, by enunciate.
+    pub words: Vec<Accuracy>,
+    /// Accuracy for each category of words which has been asked.
+    pub categories: Vec<Accuracy>,
+    /// Accuracy for each tag with words which have been asked.
+    pub tags: Vec<Accuracy>,
+    /// Answers given on each day (as in 'YYYY-MM-DD'), oldest first.
+    pub days: Vec<Accuracy>,
+    /// Answers given on each week (as in 'YYYY-Www'), oldest first.
+    pub weeks: Vec<Accuracy>,
+    /// Number of days in a row up to today with at least one session. Today is
+    /// not required to count yet, so a streak is not lost until a whole day
+    /// goes by without practicing.
+    pub streak: usize,
+}
+
+impl HistoryStats {
+    /// Returns the words which have been answered wrong at least once, with
+    /// the ones with the worst accuracy first. Ties go to the most asked ones.
+    pub fn hardest(&self) -> Vec<&Accuracy> {
+        let mut hardest: Vec<&Accuracy> = self
+            .words
+            .iter()
+            .filter(|word| word.correct < word.asked)
+            .collect();
+        hardest.sort_by(|a, b| {
+            a.percentage()
+                .total_cmp(&b.percentage())
+                .then(b.asked.cmp(&a.asked))
+                .then(a.name.cmp(&b.name))
+        });
+        hardest
+    }
+}
+
+/// Returns the mastery over the given `words` as a percentage: the average of
+/// the accuracy of each of them, where words which have never been asked count
+/// as not known at all.
+pub fn mastery(words: &[Accuracy]) -> f64 {
+    if words.is_empty() {
+        0.0
+    } else {
+        words.iter().map(Accuracy::percentage).sum::<f64>() / words.len() as f64
+    }
+}
+
+/// Returns the accuracy of every word tagged with the tag with the given `name`
+/// or with any of its descendants, by enunciate. Words which have never been
+/// asked are also given, with no answers.
+pub fn select_tag_stats(name: &str) -> Result<Vec<Accuracy>, String> {
+    let conn = get_connection()?;
+
+    select_accuracies(
+        &conn,
+        format!(
+            "SELECT w.enunciated, COUNT(a.id), IFNULL(SUM(a.succeeded), 0), AVG(a.response_ms) \
+             FROM words w LEFT JOIN session_answers a ON w.id = a.word_id \
+             WHERE {} \
+             GROUP BY w.id ORDER BY w.enunciated",
+            crate::tag::tagged_clause("w.id", &[name.to_string()], 1)
+        )
+        .as_str(),
+        [name],
+    )
+}
+
+/// Returns the accuracy of every word on both practice sessions and drills, by
+/// enunciate. Words which have never been asked are also given, with no
+/// answers. Drills do not measure how long answers take, so only the answers
+/// from practice sessions count towards the response time.
+pub fn select_word_accuracies() -> Result<Vec<Accuracy>, String> {
+    let conn = get_connection()?;
+
+    select_accuracies(
+        &conn,
+        "SELECT w.enunciated, COUNT(x.word_id), IFNULL(SUM(x.succeeded), 0), \
+                AVG(x.response_ms) \
+         FROM words w LEFT JOIN ( \
+             SELECT word_id, succeeded, response_ms FROM session_answers \
+             UNION ALL \
+             SELECT word_id, succeeded, NULL FROM drill_attempts) x ON x.word_id = w.id \
+         GROUP BY w.id ORDER BY w.enunciated",
+        [],
+    )
+}
+
+// Returns the length of the streak of consecutive `days` ending either on
+// `today` or on the day before. Days are given as julian day numbers, and can
+// be in any order or repeated.
+fn streak(days: &[i64], today: i64) -> usize {
+    let mut days = days.to_vec();
+    days.sort_unstable_by(|a, b| b.cmp(a));
+    days.dedup();
+
+    let mut expected = match days.first() {
+        Some(&last) if last == today || last == today - 1 => last,
+        _ => return 0,
+    };
+    let mut res = 0;
+    for day in days {
+        if day != expected {
+            break;
+        }
+        res += 1;
+        expected -= 1;
+    }
+    res
+}
+
+// Returns the given average of response times in milliseconds as a duration.
+fn average_response(ms: Option<f64>) -> Option<Duration> {
+    ms.map(|ms| Duration::from_secs_f64(ms / 1000.0))
+}
+
+// Runs the given `query`, which is expected to return the name, the number of
+// answers, the number of right answers and the average response time in
+// milliseconds for each row.
+fn select_accuracies<P: rusqlite::Params>(
+    conn: &rusqlite::Connection,
+    query: &str,
+    params: P,
+) -> Result<Vec<Accuracy>, String> {
+    let mut stmt = conn.prepare_cached(query).map_err(|e| e.to_string())?;
+    stmt.query_map(params, |row| {
+        Ok(Accuracy {
+            name: row.get(0)?,
+            asked: row.get::<usize, isize>(1)? as usize,
+            correct: row.get::<usize, isize>(2)? as usize,
+            response: average_response(row.get(3)?),
+        })
+    })
+    .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+    .map_err(|e| format!("could not read the history of sessions: {e}"))
+}
+
+/// Returns the statistics over all the practice sessions saved so far.
+pub fn select_history_stats() -> Result<HistoryStats, String> {
+    let conn = get_connection()?;
+
+    let words = select_accuracies(
+        &conn,
+        "SELECT w.enunciated, COUNT(*), SUM(a.succeeded), AVG(a.response_ms) \
+         FROM session_answers a JOIN words w ON w.id = a.word_id \
+         GROUP BY w.id ORDER BY w.enunciated",
+        [],
+    )?;
+
+    // Categories are stored as integers, so they have to be named here.
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT w.category, COUNT(*), SUM(a.succeeded), AVG(a.response_ms) \
+             FROM session_answers a JOIN words w ON w.id = a.word_id \
+             GROUP BY w.category ORDER BY w.category",
+        )
+        .map_err(|e| e.to_string())?;
+    let categories = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<usize, isize>(0)?,
+                row.get::<usize, isize>(1)? as usize,
+                row.get::<usize, isize>(2)? as usize,
+                row.get::<usize, Option<f64>>(3)?,
+            ))
+        })
+        .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| format!("could not read the history of sessions: {e}"))?
+        .into_iter()
+        .map(|(category, asked, correct, response)| {
+            Ok(Accuracy {
+                name: Category::try_from(category)?.to_string(),
+                asked,
+                correct,
+                response: average_response(response),
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let tags = select_accuracies(
+        &conn,
+        "SELECT t.name, COUNT(*), SUM(a.succeeded), AVG(a.response_ms) \
+         FROM session_answers a \
+         JOIN tag_associations ta ON ta.word_id = a.word_id \
+         JOIN tags t ON t.id = ta.tag_id \
+         GROUP BY t.id ORDER BY t.name",
+        [],
+    )?;
+    let days = select_accuracies(
+        &conn,
+        "SELECT date(created_at) AS day, COUNT(*), SUM(succeeded), AVG(response_ms) \
+         FROM session_answers GROUP BY day ORDER BY day",
+        [],
+    )?;
+    let weeks = select_accuracies(
+        &conn,
+        "SELECT strftime('%Y-W%W', created_at) AS week, COUNT(*), SUM(succeeded), AVG(response_ms) \
+         FROM session_answers GROUP BY week ORDER BY week",
+        [],
+    )?;
+
+    let today = conn
+        .query_row(
+            "SELECT CAST(julianday(date('now')) AS integer)",
+            [],
+            |row| row.get::<usize, i64>(0),
+        )
+        .map_err(|e| e.to_string())?;
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT DISTINCT CAST(julianday(date(finished_at)) AS integer) FROM sessions",
+        )
+        .map_err(|e| e.to_string())?;
+    let session_days = stmt
+        .query_map([], |row| row.get::<usize, i64>(0))
+        .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| format!("could not read the history of sessions: {e}"))?;
+
+    Ok(HistoryStats {
+        words,
+        categories,
+        tags,
+        days,
+        weeks,
+        streak: streak(&session_days, today),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streak() {
+        assert_eq!(streak(&[], 100), 0);
+        assert_eq!(streak(&[100], 100), 1);
+        assert_eq!(streak(&[97, 98, 100, 99, 99], 100), 4);
+
+        // Today does not have to be practiced yet, but yesterday does.
+        assert_eq!(streak(&[98, 99], 100), 2);
+        assert_eq!(streak(&[97, 98], 100), 0);
+        assert_eq!(streak(&[95, 96, 98, 99, 100], 100), 3);
+    }
+
+    #[test]
+    fn test_mastery() {
+        let accuracy = |asked, correct| Accuracy {
+            name: String::new(),
+            asked,
+            correct,
+            response: None,
+        };
+
+        assert_eq!(mastery(&[]), 0.0);
+        assert_eq!(mastery(&[accuracy(4, 3), accuracy(2, 2)]), 87.5);
+        assert_eq!(mastery(&[accuracy(4, 4), accuracy(0, 0)]), 50.0);
+    }
+
+    #[test]
+    fn test_hardest() {
+        let accuracy = |name: &str, asked, correct| Accuracy {
+            name: name.to_string(),
+            asked,
+            correct,
+            response: None,
+        };
+        let stats = HistoryStats {
+            words: vec![
+                accuracy("bellum, bellī", 4, 3),
+                accuracy("puer, puerī", 2, 0),
+                accuracy("rosa, rosae", 3, 3),
+                accuracy("via, viae", 4, 0),
+            ],
+            ..Default::default()
+        };
+
+        let names: Vec<&str> = stats.hardest().iter().map(|w| w.name.as_str()).collect();
+        assert_eq!(names, vec!["via, viae", "puer, puerī", "bellum, bellī"]);
+        assert_eq!(stats.words[0].percentage(), 75.0);
+    }
+}
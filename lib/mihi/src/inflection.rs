@@ -1,13 +1,35 @@
+use crate::cfg::configuration;
 use crate::get_connection;
-use crate::word::{Declension, Gender, Word};
+use crate::word::{Category, Conjugation, Declension, Gender, Word};
+use rusqlite::params;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::sync::{Arc, LazyLock, Mutex};
+
+pub use mihi_core::grammar::{Case, Number};
 
 #[derive(Debug, Default)]
 pub struct DeclensionInfo {
     pub inflected: Vec<String>,
 }
 
+impl DeclensionInfo {
+    /// Removes empty and duplicated forms from this cell. The order is
+    /// otherwise kept, so the preferred form (i.e. the first one from the
+    /// database or from the 'sets' flag) stays first, and the ones coming from
+    /// the 'adds' flag go after it.
+    pub fn canonicalize(&mut self) {
+        let mut res: Vec<String> = vec![];
+        for form in self.inflected.drain(..) {
+            if !form.is_empty() && !res.contains(&form) {
+                res.push(form);
+            }
+        }
+        self.inflected = res;
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct DeclensionTable {
     pub nominative: [DeclensionInfo; 2],
@@ -20,109 +42,86 @@ pub struct DeclensionTable {
 }
 
 impl DeclensionTable {
+    /// Returns the forms for the given `case`, indexed by number.
+    pub fn row(&self, case: Case) -> &[DeclensionInfo; 2] {
+        match case {
+            Case::Nominative => &self.nominative,
+            Case::Vocative => &self.vocative,
+            Case::Accusative => &self.accusative,
+            Case::Genitive => &self.genitive,
+            Case::Dative => &self.dative,
+            Case::Ablative => &self.ablative,
+            Case::Locative => &self.locative,
+        }
+    }
+
+    /// Returns the forms for the given `case`, indexed by number, so they can
+    /// be modified.
+    pub fn row_mut(&mut self, case: Case) -> &mut [DeclensionInfo; 2] {
+        match case {
+            Case::Nominative => &mut self.nominative,
+            Case::Vocative => &mut self.vocative,
+            Case::Accusative => &mut self.accusative,
+            Case::Genitive => &mut self.genitive,
+            Case::Dative => &mut self.dative,
+            Case::Ablative => &mut self.ablative,
+            Case::Locative => &mut self.locative,
+        }
+    }
+
+    /// Returns the forms for the given `case` and `number`.
+    pub fn get(&self, case: Case, number: Number) -> &DeclensionInfo {
+        &self.row(case)[number as usize]
+    }
+
+    /// Returns an iterator over all the cells of this table as (case, number,
+    /// forms), in the order in which cases are stored in the database. Use
+    /// `CaseOrder::cases` along with `row` to follow the configured order
+    /// instead.
+    pub fn iter(&self) -> impl Iterator<Item = (Case, Number, &DeclensionInfo)> {
+        Case::ALL.into_iter().flat_map(move |case| {
+            Number::ALL
+                .into_iter()
+                .map(move |number| (case, number, self.get(case, number)))
+        })
+    }
+
     pub fn consume_blob(
         &mut self,
-        case: usize,
+        case: Case,
         blob: &Value,
         word: &Word,
-        gender: usize,
+        gender: Gender,
         add: bool,
     ) {
-        if let Some(singular) = blob.get("singular") {
-            let values = singular.as_array().unwrap();
-            for v in values {
-                let s = v.as_str().unwrap();
-                if add {
-                    self.add(word, case, 0, gender, s);
-                } else {
-                    self.set(word, case, 0, gender, s);
-                }
-            }
-        }
-
-        if let Some(plural) = blob.get("plural") {
-            let values = plural.as_array().unwrap();
-            for v in values {
+        for (number, key) in [(Number::Singular, "singular"), (Number::Plural, "plural")] {
+            let Some(values) = blob.get(key) else {
+                continue;
+            };
+            for v in values.as_array().unwrap() {
                 let s = v.as_str().unwrap();
                 if add {
-                    self.add(word, case, 1, gender, s);
+                    self.add(word, case, number, gender, s);
                 } else {
-                    self.set(word, case, 1, gender, s);
+                    self.set(word, case, number, gender, s);
                 }
             }
         }
     }
 
-    pub fn set(&mut self, word: &Word, case: usize, number: usize, gender: usize, term: &str) {
-        match case {
-            0 => {
-                self.nominative[number].inflected = inflect_from(word, case, number, gender, term);
-            }
-            1 => {
-                self.vocative[number].inflected = inflect_from(word, case, number, gender, term);
-            }
-            2 => {
-                self.accusative[number].inflected = inflect_from(word, case, number, gender, term);
-            }
-            3 => {
-                self.genitive[number].inflected = inflect_from(word, case, number, gender, term);
-            }
-            4 => {
-                self.dative[number].inflected = inflect_from(word, case, number, gender, term);
-            }
-            5 => {
-                self.ablative[number].inflected = inflect_from(word, case, number, gender, term);
-            }
-            6 => {
-                self.locative[number].inflected = inflect_from(word, case, number, gender, term);
-            }
-            _ => {}
-        }
+    pub fn set(&mut self, word: &Word, case: Case, number: Number, gender: Gender, term: &str) {
+        self.row_mut(case)[number as usize].inflected =
+            inflect_from(word, case, number, gender, term);
     }
 
-    pub fn add(&mut self, word: &Word, case: usize, number: usize, gender: usize, term: &str) {
-        match case {
-            0 => {
-                self.nominative[number]
-                    .inflected
-                    .append(&mut inflect_from(word, case, number, gender, term));
-            }
-            1 => {
-                self.vocative[number]
-                    .inflected
-                    .append(&mut inflect_from(word, case, number, gender, term));
-            }
-            2 => {
-                self.accusative[number]
-                    .inflected
-                    .append(&mut inflect_from(word, case, number, gender, term));
-            }
-            3 => {
-                self.genitive[number]
-                    .inflected
-                    .append(&mut inflect_from(word, case, number, gender, term));
-            }
-            4 => {
-                self.dative[number]
-                    .inflected
-                    .append(&mut inflect_from(word, case, number, gender, term));
-            }
-            5 => {
-                self.ablative[number]
-                    .inflected
-                    .append(&mut inflect_from(word, case, number, gender, term));
-            }
-            6 => {
-                self.locative[number]
-                    .inflected
-                    .append(&mut inflect_from(word, case, number, gender, term));
-            }
-            _ => {}
-        }
+    pub fn add(&mut self, word: &Word, case: Case, number: Number, gender: Gender, term: &str) {
+        self.row_mut(case)[number as usize]
+            .inflected
+            .append(&mut inflect_from(word, case, number, gender, term));
     }
 }
 
-fn contract_root(word: &Word, case: usize, number: usize, gender: usize) -> bool {
+fn contract_root(word: &Word, case: Case, number: Number, gender: Gender) -> bool {
     // First off, check out that this is a word explicitely marked as to be
     // contracted by either the flag or the kind.
     if !word.is_flag_set("contracted_root") {
@@ -133,36 +132,37 @@ fn contract_root(word: &Word, case: usize, number: usize, gender: usize) -> bool
     }
 
     // All plurals have to be contracted.
-    if number == 1 {
+    if number == Number::Plural {
         return true;
     }
 
     // Nominative/vocative singular are only contracted for feminine nouns. The
     // accusative is only not contracted on neuter words.
     match case {
-        0 | 1 => gender == Gender::Feminine as usize,
-        2 => gender != Gender::Neuter as usize,
+        Case::Nominative | Case::Vocative => gender == Gender::Feminine,
+        Case::Accusative => gender != Gender::Neuter,
         _ => true,
     }
 }
 
-fn should_use_first_root(word: &Word, case: usize, number: usize, gender: usize) -> bool {
+fn should_use_first_root(word: &Word, case: Case, number: Number, gender: Gender) -> bool {
     // All plurals always follow `word.particle`.
-    if number == 1 {
+    if number == Number::Plural {
         return false;
     }
 
     match case {
-        0 | 1 => {
+        Case::Nominative | Case::Vocative => {
             word.kind == "is"
                 || word.kind == "istem"
                 || word.kind == "pureistem"
                 || word.kind == "one"
                 || word.kind == "onenonistem"
+                || word.kind == "greekthird"
         }
-        2 => {
+        Case::Accusative => {
             // Only neuter words should consider this on the accusative.
-            if gender != 3 {
+            if gender != Gender::Neuter {
                 return false;
             }
             word.kind == "is"
@@ -175,7 +175,13 @@ fn should_use_first_root(word: &Word, case: usize, number: usize, gender: usize)
     }
 }
 
-fn inflect_from(word: &Word, case: usize, number: usize, gender: usize, term: &str) -> Vec<String> {
+fn inflect_from(
+    word: &Word,
+    case: Case,
+    number: Number,
+    gender: Gender,
+    term: &str,
+) -> Vec<String> {
     let mut inflections = vec![];
 
     if !word.regular {
@@ -185,13 +191,13 @@ fn inflect_from(word: &Word, case: usize, number: usize, gender: usize, term: &s
     } else if should_use_first_root(word, case, number, gender) {
         let parts: Vec<&str> = word.enunciated.split(',').collect();
         inflections.push(parts.first().unwrap().to_string() + term);
-    } else if word.kind == "ius" && number == 0 {
+    } else if word.kind == "ius" && number == Number::Singular {
         // Words of this kind are a bit troublesome on the singular, let's
         // handle them now.
-        if case == 1 && word.is_flag_set("contracted_vocative") {
+        if case == Case::Vocative && word.is_flag_set("contracted_vocative") {
             inflections.push(word.particle[0..word.particle.len() - 1].to_string() + term);
         } else {
-            if case == 3 {
+            if case == Case::Genitive {
                 inflections.push(word.particle[0..word.particle.len() - 1].to_string() + term);
             }
             inflections.push(word.particle.to_string() + term);
@@ -203,25 +209,14 @@ fn inflect_from(word: &Word, case: usize, number: usize, gender: usize, term: &s
     inflections
 }
 
-fn case_str_to_i(key: &str) -> Result<usize, String> {
-    match key {
-        "nominative" => Ok(0),
-        "vocative" => Ok(1),
-        "accusative" => Ok(2),
-        "genitive" => Ok(3),
-        "dative" => Ok(4),
-        "ablative" => Ok(5),
-        "locative" => Ok(6),
-        _ => Err(format!("bad key '{}' for a case", key)),
-    }
-}
-
 /// Returns a string which describes the enunciate of the given `word` as
-/// inflected considering the singular/plural declension `row`.
+/// inflected considering the singular/plural declension `row`. If the word
+/// lacks forms for a number on this case (e.g. the locative plural of 'domus'),
+/// then only the other number is returned.
 pub fn get_inflected_from(word: &Word, row: &[DeclensionInfo; 2]) -> String {
-    if word.is_flag_set("onlysingular") {
+    if word.is_flag_set("onlysingular") || row[1].inflected.is_empty() {
         row[0].inflected.join("/")
-    } else if word.is_flag_set("onlyplural") {
+    } else if word.is_flag_set("onlyplural") || row[0].inflected.is_empty() {
         row[1].inflected.join("/")
     } else {
         format!(
@@ -234,89 +229,378 @@ pub fn get_inflected_from(word: &Word, row: &[DeclensionInfo; 2]) -> String {
 
 /// Returns the declension table of the given `word` by assuming it's a noun.
 pub fn get_noun_table(word: &Word) -> Result<DeclensionTable, String> {
-    let gender = match word.gender {
-        Gender::MasculineOrFeminine => Gender::Masculine as usize,
-        _ => word.gender as usize,
-    };
-    group_declension_inflections(word, &word.kind, gender)
+    group_declension_inflections(word, &word.kind, noun_gender(word))
+}
+
+// Returns the gender by which the forms of the given noun `word` are to be
+// fetched.
+fn noun_gender(word: &Word) -> Gender {
+    match word.gender {
+        Gender::MasculineOrFeminine => Gender::Masculine,
+        _ => word.gender,
+    }
 }
 
 /// Returns the declension tables for each gender of the given `word` by
 /// assuming it's an adjective.
 pub fn get_adjective_table(word: &Word) -> Result<[DeclensionTable; 3], String> {
-    // Unless the word is a special "unus nauta" variant, force 1/2 declension
-    // adjectives in the feminine to grab the "a" kind.
-    let kind_f = if word.kind.as_str() == "unusnauta" {
-        &word.kind
+    Ok([
+        group_declension_inflections(word, &word.kind, Gender::Masculine)?,
+        group_declension_inflections(
+            word,
+            &adjective_kind(word, Gender::Feminine),
+            Gender::Feminine,
+        )?,
+        group_declension_inflections(word, &adjective_kind(word, Gender::Neuter), Gender::Neuter)?,
+    ])
+}
+
+// Returns the kind of forms to be used for the given `gender` of the given
+// adjective `word`.
+fn adjective_kind(word: &Word, gender: Gender) -> String {
+    match gender {
+        // Unless the word is a special "unus nauta" variant, force 1/2
+        // declension adjectives in the feminine to grab the "a" kind.
+        Gender::Feminine if word.kind != "unusnauta" => match word.declension {
+            Some(Declension::First | Declension::Second) => "a".to_string(),
+            _ => word.kind.clone(),
+        },
+        Gender::Neuter if word.kind == "us" => "um".to_string(),
+        _ => word.kind.clone(),
+    }
+}
+
+// Kinds of personal pronouns, which are not inflected by gender.
+const PERSONAL_PRONOUNS: [&str; 3] = ["egonos", "tuvos", "sesui"];
+
+/// Returns the declension tables of the given `word` by assuming it's a
+/// pronoun. Personal pronouns (e.g. 'ego, nōs') return a single table, as they
+/// are not inflected by gender. The rest of pronouns (demonstrative, relative,
+/// interrogative, intensive and possessive) return a table for the masculine,
+/// the feminine and the neuter, just like adjectives.
+pub fn get_pronoun_table(word: &Word) -> Result<Vec<DeclensionTable>, String> {
+    let mut tables = if PERSONAL_PRONOUNS.contains(&word.kind.as_str()) {
+        vec![group_declension_inflections(
+            word,
+            &word.kind,
+            Gender::Masculine,
+        )?]
     } else {
-        match word.declension {
-            Some(Declension::First | Declension::Second) => &"a".to_string(),
-            _ => &word.kind,
+        vec![
+            group_declension_inflections(word, &word.kind, Gender::Masculine)?,
+            group_declension_inflections(word, &word.kind, Gender::Feminine)?,
+            group_declension_inflections(word, &word.kind, Gender::Neuter)?,
+        ]
+    };
+
+    for table in tables.iter_mut() {
+        adjust_pronoun_forms(word, table);
+    }
+
+    Ok(tables)
+}
+
+// Adjusts the forms of the given pronoun `table` as fetched from the database
+// by splitting the ones stored together and attaching to them the prefix and
+// the suffix of the given `word`.
+fn adjust_pronoun_forms(word: &Word, table: &mut DeclensionTable) {
+    // Compounds of relative and interrogative pronouns might have a prefix
+    // (e.g. 'aliquis') stored as the particle.
+    let prefix = match word.kind.as_str() {
+        "relative" | "interrogative"
+            if word.particle != word.kind && !word.particle.starts_with("qu") =>
+        {
+            word.particle.as_str()
+        }
+        _ => "",
+    };
+    let suffix = word.suffix.as_deref().unwrap_or("");
+
+    for_each_info_mut(table, |info| {
+        // Some forms are stored together (e.g. 'nostrī, nostrum'), so split
+        // them before attaching any prefix or suffix. Missing forms (e.g. the
+        // nominative of 'sē') are stored empty.
+        info.inflected = info
+            .inflected
+            .iter()
+            .flat_map(|form| form.split(','))
+            .map(|form| form.trim())
+            .filter(|form| !form.is_empty())
+            .map(|form| format!("{prefix}{form}{suffix}"))
+            .collect();
+        info.canonicalize();
+    });
+}
+
+// Calls `f` for each cell (case and number) of the given declension `table`.
+fn for_each_info_mut(table: &mut DeclensionTable, mut f: impl FnMut(&mut DeclensionInfo)) {
+    for row in [
+        &mut table.nominative,
+        &mut table.vocative,
+        &mut table.accusative,
+        &mut table.genitive,
+        &mut table.dative,
+        &mut table.ablative,
+        &mut table.locative,
+    ] {
+        for info in row.iter_mut() {
+            f(info);
+        }
+    }
+}
+
+// Returns the adjective tables of the given `word` with each form preceded by
+// the given `adverb` (e.g. 'magis'). This is how comparison is built for words
+// with the 'compsup_prefix' flag.
+fn prefixed_adjective_table(word: &Word, adverb: &str) -> Result<[DeclensionTable; 3], String> {
+    let mut tables = get_adjective_table(word)?;
+    for table in tables.iter_mut() {
+        for_each_info_mut(table, |info| {
+            for form in info.inflected.iter_mut() {
+                *form = format!("{adverb} {form}");
+            }
+        });
+    }
+    Ok(tables)
+}
+
+/// Returns the declension tables for each gender of the comparative of the
+/// given adjective `word`. As with `word::comparative`, irregular comparatives
+/// are expected to be given in `related`. Otherwise it's built from the
+/// particle of the word, following the third declension without being an
+/// i-stem (e.g. 'altior, altius').
+pub fn get_comparative_table(
+    word: &Word,
+    related: &[Word],
+) -> Result<[DeclensionTable; 3], String> {
+    if related.is_empty() && word.is_flag_set("compsup_prefix") {
+        return prefixed_adjective_table(word, "magis");
+    }
+
+    let comparative = match related.first() {
+        Some(comparative) => comparative.clone(),
+        None => {
+            let part = word.real_particle();
+            let mut comparative = Word::from(
+                format!("{part}iōr"),
+                Category::Adjective,
+                Some(Declension::Third),
+                None,
+                Gender::None,
+                "onenonistem".to_string(),
+            );
+            comparative.enunciated = format!("{part}ior, {part}ius");
+            comparative
         }
     };
+    let mut tables = get_adjective_table(&comparative)?;
 
-    let kind_n = if word.kind == "us" {
-        &"um".to_owned()
+    // The neuter singular has its own form on the nominative, vocative and
+    // accusative (e.g. 'altius'), which is the second part of the enunciate.
+    if let Some(neuter) = comparative.enunciated.split(',').nth(1) {
+        let neuter = vec![neuter.trim().to_string()];
+        let table = &mut tables[2];
+        for row in [
+            &mut table.nominative,
+            &mut table.vocative,
+            &mut table.accusative,
+        ] {
+            row[0].inflected = neuter.clone();
+        }
+    }
+
+    Ok(tables)
+}
+
+/// Returns the declension tables for each gender of the superlative of the
+/// given adjective `word`. As with `word::superlative`, irregular superlatives
+/// are expected to be given in `related`. Otherwise it's built from the
+/// particle of the word, following the first and second declensions (e.g.
+/// 'altissimus, altissima, altissimum').
+pub fn get_superlative_table(
+    word: &Word,
+    related: &[Word],
+) -> Result<[DeclensionTable; 3], String> {
+    if let Some(superlative) = related.first() {
+        return get_adjective_table(superlative);
+    }
+    if word.is_flag_set("compsup_prefix") {
+        return prefixed_adjective_table(word, "maximē");
+    }
+
+    let part = &word.particle;
+    let particle = if word.is_flag_set("irregularsup") {
+        format!("{part}lim")
+    } else if word.is_flag_set("contracted_root") {
+        format!("{part}rim")
     } else {
-        &word.kind
+        format!("{part}issim")
     };
+    let mut superlative = Word::from(
+        particle.clone(),
+        Category::Adjective,
+        Some(Declension::First),
+        None,
+        Gender::None,
+        "us".to_string(),
+    );
+    superlative.enunciated = format!("{particle}us, {particle}a, {particle}um");
 
-    Ok([
-        group_declension_inflections(word, &word.kind, Gender::Masculine as usize)?,
-        group_declension_inflections(word, kind_f, Gender::Feminine as usize)?,
-        group_declension_inflections(word, kind_n, Gender::Neuter as usize)?,
-    ])
+    get_adjective_table(&superlative)
 }
 
 /// Returns the declension table for the given `word` by using the given `kind`
 /// and `gender`.
 pub fn group_declension_inflections(
     word: &Word,
-    kind: &String,
-    gender: usize,
+    kind: &str,
+    gender: Gender,
 ) -> Result<DeclensionTable, String> {
+    declension_inflections(word, kind, gender, None)
+}
+
+/// Returns the forms of the given `word` for the given `case`, `number` and
+/// `gender`. Only the forms for this case and number are fetched, so it's
+/// cheaper than asking for the whole table. The `gender` is ignored for nouns
+/// and personal pronouns, as they are not inflected by it.
+pub fn inflect(
+    word: &Word,
+    case: Case,
+    number: Number,
+    gender: Gender,
+) -> Result<Vec<String>, String> {
+    let cell = Some((case, number));
+    let gender = match gender {
+        Gender::MasculineOrFeminine | Gender::None => Gender::Masculine,
+        _ => gender,
+    };
+
+    let table = match word.category {
+        Category::Noun => declension_inflections(word, &word.kind, noun_gender(word), cell)?,
+        Category::Adjective => {
+            declension_inflections(word, &adjective_kind(word, gender), gender, cell)?
+        }
+        Category::Pronoun => {
+            let gender = if PERSONAL_PRONOUNS.contains(&word.kind.as_str()) {
+                Gender::Masculine
+            } else {
+                gender
+            };
+            let mut table = declension_inflections(word, &word.kind, gender, cell)?;
+            adjust_pronoun_forms(word, &mut table);
+            table
+        }
+        _ => return Err(format!("'{}' cannot be declined", word.enunciated)),
+    };
+
+    Ok(table.get(case, number).inflected.clone())
+}
+
+// Endings from the 'forms' table for a kind and a gender, as (number, case,
+// ending) triples in the order in which they are stored.
+type DeclensionRows = Arc<Vec<(Number, Case, String)>>;
+
+// Endings which have already been fetched, keyed by kind and gender. The
+// 'forms' table doesn't change while the application is running, so there's no
+// need to ask the database more than once for them (e.g. when printing the
+// three tables of an adjective, or on a declension drill).
+static DECLENSION_ROWS: LazyLock<Mutex<HashMap<(String, isize), DeclensionRows>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// Returns the endings for the given `kind` and `gender`, which includes the ones
+// stored with no gender. They are only fetched from the database the first
+// time.
+fn declension_rows(kind: &str, gender: Gender) -> Result<DeclensionRows, String> {
+    cached_declension_rows(kind, gender, || fetch_declension_rows(kind, gender))
+}
+
+// Returns the endings cached for the given `kind` and `gender`, which are taken
+// from `fetch` only if they were not cached already.
+fn cached_declension_rows<F>(kind: &str, gender: Gender, fetch: F) -> Result<DeclensionRows, String>
+where
+    F: FnOnce() -> Result<Vec<(Number, Case, String)>, String>,
+{
+    let key = (kind.to_string(), gender as isize);
+    if let Some(rows) = DECLENSION_ROWS.lock().unwrap().get(&key) {
+        return Ok(rows.clone());
+    }
+
+    let rows = Arc::new(fetch()?);
+    DECLENSION_ROWS.lock().unwrap().insert(key, rows.clone());
+    Ok(rows)
+}
+
+// Fetches from the database the endings for the given `kind` and `gender`. See
+// `declension_rows`.
+fn fetch_declension_rows(
+    kind: &str,
+    gender: Gender,
+) -> Result<Vec<(Number, Case, String)>, String> {
+    let error = |e: rusqlite::Error| format!("could not fetch the forms for '{kind}': {e}");
+
     let conn = get_connection()?;
     let mut stmt = conn
-        .prepare(
-            "SELECT id, number, gender, \"case\", value, declension_id, \
-                    kind, tense, mood, voice, person, conjugation_id \
+        .prepare_cached(
+            "SELECT number, \"case\", value \
              FROM forms \
-             WHERE kind = ?1 AND gender = ?2
+             WHERE kind = ?1 AND (gender = ?2 OR gender IS NULL) AND \"case\" IS NOT NULL \
              ORDER BY id",
         )
-        .unwrap();
-    let mut it = stmt.query([kind, &gender.to_string()]).unwrap();
+        .map_err(error)?;
+    let mut it = stmt.query(params![kind, gender as isize]).map_err(error)?;
 
-    let mut table = DeclensionTable::default();
+    let mut rows = vec![];
+    while let Some(row) = it.next().map_err(error)? {
+        rows.push((
+            row.get::<usize, isize>(0).map_err(error)?.try_into()?,
+            row.get::<usize, isize>(1).map_err(error)?.try_into()?,
+            row.get(2).map_err(error)?,
+        ));
+    }
+    Ok(rows)
+}
 
-    while let Some(row) = it.next().unwrap() {
-        let onlyplural = word.is_flag_set("onlyplural");
+/// Forgets all the endings fetched so far, so they are read again from the
+/// database the next time they are needed. Call it whenever the 'forms' table
+/// changes.
+pub fn clear_forms_cache() {
+    DECLENSION_ROWS.lock().unwrap().clear();
+}
 
-        // Fetch the number and account for defectives on number.
-        let number_i: isize = row.get(1).unwrap();
-        let number: usize = usize::try_from(number_i).expect("not expecting a negative number");
-        if (number == 0 && onlyplural) || (number == 1 && word.is_flag_set("onlysingular")) {
+// Returns the declension table for the given `word` by using the given `kind`
+// and `gender`. If a `cell` is given as a (case, number) pair, then only the
+// forms for it are taken.
+fn declension_inflections(
+    word: &Word,
+    kind: &str,
+    gender: Gender,
+    cell: Option<(Case, Number)>,
+) -> Result<DeclensionTable, String> {
+    let mut table = DeclensionTable::default();
+    let onlyplural = word.is_flag_set("onlyplural");
+
+    for (number, case, term) in declension_rows(kind, gender)?.iter() {
+        let (number, case) = (*number, *case);
+        if cell.is_some_and(|cell| cell != (case, number)) {
             continue;
         }
 
-        let case_i: isize = row.get(3).unwrap();
-        let term: String = row.get(4).unwrap();
+        // Account for defectives on number.
+        if (number == Number::Singular && onlyplural)
+            || (number == Number::Plural && word.is_flag_set("onlysingular"))
+        {
+            continue;
+        }
 
         // If this is the locative, on the plural, and 'onlyplural' was not
         // specified, then chances are that the locative in the plural doesn't
         // exist. That is because it only existed for defective nouns such as
         // 'Athēnīs'.
-        if case_i == 6 && number == 1 && !onlyplural {
+        if case == Case::Locative && number == Number::Plural && !onlyplural {
             continue;
         }
 
-        table.add(
-            word,
-            usize::try_from(case_i).expect("not expecting a negative number"),
-            number,
-            gender,
-            &term,
-        );
+        table.add(word, case, number, gender, term);
     }
 
     if let Some(sets) = word.flags.get("sets") {
@@ -326,20 +610,24 @@ pub fn group_declension_inflections(
             let case_gender_str = case_gender.as_str();
             match case_gender_str {
                 "masculine" | "feminine" | "neuter" => {
-                    if (gender == 0 && case_gender_str == "masculine")
-                        || (gender == 1 && case_gender_str == "feminine")
-                        || (gender == 2 && case_gender_str == "neuter")
+                    if (gender == Gender::Masculine && case_gender_str == "masculine")
+                        || (gender == Gender::Feminine && case_gender_str == "feminine")
+                        || (gender == Gender::Neuter && case_gender_str == "neuter")
                     {
                         let inner = blob.as_object().unwrap();
                         for (case, blob) in inner.iter() {
-                            let case_i = case_str_to_i(case)?;
-                            table.consume_blob(case_i, blob, word, gender, false);
+                            table.consume_blob(
+                                case.as_str().try_into()?,
+                                blob,
+                                word,
+                                gender,
+                                false,
+                            );
                         }
                     }
                 }
                 _ => {
-                    let case_i = case_str_to_i(case_gender)?;
-                    table.consume_blob(case_i, blob, word, gender, false);
+                    table.consume_blob(case_gender_str.try_into()?, blob, word, gender, false);
                 }
             }
         }
@@ -352,24 +640,1013 @@ pub fn group_declension_inflections(
             let case_gender_str = case_gender.as_str();
             match case_gender_str {
                 "masculine" | "feminine" | "neuter" => {
-                    if (gender == 0 && case_gender_str == "masculine")
-                        || (gender == 1 && case_gender_str == "feminine")
-                        || (gender == 2 && case_gender_str == "neuter")
+                    if (gender == Gender::Masculine && case_gender_str == "masculine")
+                        || (gender == Gender::Feminine && case_gender_str == "feminine")
+                        || (gender == Gender::Neuter && case_gender_str == "neuter")
                     {
                         let inner = blob.as_object().unwrap();
                         for (case, blob) in inner.iter() {
-                            let case_i = case_str_to_i(case)?;
-                            table.consume_blob(case_i, blob, word, gender, true);
+                            table.consume_blob(case.as_str().try_into()?, blob, word, gender, true);
                         }
                     }
                 }
                 _ => {
-                    let case_i = case_str_to_i(case_gender)?;
-                    table.consume_blob(case_i, blob, word, gender, true);
+                    table.consume_blob(case_gender_str.try_into()?, blob, word, gender, true);
                 }
             }
         }
     }
 
+    for_each_info_mut(&mut table, DeclensionInfo::canonicalize);
+
     Ok(table)
 }
+
+// Names for each of the values of the verb inflection columns, indexed as they
+// are stored in the database.
+pub(crate) const NUMBERS: [&str; 2] = ["singular", "plural"];
+pub(crate) const PERSONS: [&str; 3] = ["first", "second", "third"];
+pub(crate) const TENSES: [&str; 6] = [
+    "present",
+    "imperfect",
+    "perfect",
+    "pluperfect",
+    "future",
+    "future perfect",
+];
+pub(crate) const MOODS: [&str; 3] = ["indicative", "subjunctive", "imperative"];
+pub(crate) const VOICES: [&str; 2] = ["active", "passive"];
+
+/// Forms for a given tense, indexed first by number (singular/plural) and then
+/// by person (first, second and third).
+#[derive(Debug, Default)]
+pub struct TenseInfo {
+    pub forms: [[DeclensionInfo; 3]; 2],
+}
+
+impl TenseInfo {
+    /// Returns true if there are no forms at all for this tense.
+    pub fn is_empty(&self) -> bool {
+        self.forms
+            .iter()
+            .all(|number| number.iter().all(|info| info.inflected.is_empty()))
+    }
+}
+
+/// All the tenses for a given mood and voice.
+#[derive(Debug, Default)]
+pub struct MoodTable {
+    pub present: TenseInfo,
+    pub imperfect: TenseInfo,
+    pub future: TenseInfo,
+    pub perfect: TenseInfo,
+    pub pluperfect: TenseInfo,
+    pub future_perfect: TenseInfo,
+}
+
+impl MoodTable {
+    /// Returns the tense identified by the given index as stored in the
+    /// database.
+    pub fn tense_mut(&mut self, tense: usize) -> Option<&mut TenseInfo> {
+        match tense {
+            0 => Some(&mut self.present),
+            1 => Some(&mut self.imperfect),
+            2 => Some(&mut self.perfect),
+            3 => Some(&mut self.pluperfect),
+            4 => Some(&mut self.future),
+            5 => Some(&mut self.future_perfect),
+            _ => None,
+        }
+    }
+
+    /// Returns true if there are no forms at all for this mood.
+    pub fn is_empty(&self) -> bool {
+        self.present.is_empty()
+            && self.imperfect.is_empty()
+            && self.future.is_empty()
+            && self.perfect.is_empty()
+            && self.pluperfect.is_empty()
+            && self.future_perfect.is_empty()
+    }
+
+    /// Returns the tenses of this mood alongside their names, in the order in
+    /// which they are usually shown.
+    pub fn tenses(&self) -> [(&'static str, &TenseInfo); 6] {
+        [
+            ("Present", &self.present),
+            ("Imperfect", &self.imperfect),
+            ("Future", &self.future),
+            ("Perfect", &self.perfect),
+            ("Pluperfect", &self.pluperfect),
+            ("Future perfect", &self.future_perfect),
+        ]
+    }
+}
+
+/// The infinitives of a verb, each of them indexed by voice: active first,
+/// passive second.
+#[derive(Debug, Default)]
+pub struct InfinitiveTable {
+    pub present: [DeclensionInfo; 2],
+    pub perfect: [DeclensionInfo; 2],
+    pub future: [DeclensionInfo; 2],
+}
+
+impl InfinitiveTable {
+    /// Returns the infinitive identified by the given tense as stored in the
+    /// database.
+    pub fn tense_mut(&mut self, tense: usize) -> Option<&mut [DeclensionInfo; 2]> {
+        match tense {
+            0 => Some(&mut self.present),
+            2 => Some(&mut self.perfect),
+            4 => Some(&mut self.future),
+            _ => None,
+        }
+    }
+}
+
+/// The participles of a verb, given in the masculine singular nominative.
+#[derive(Debug, Default)]
+pub struct ParticipleTable {
+    pub present_active: DeclensionInfo,
+    pub perfect_passive: DeclensionInfo,
+    pub future_active: DeclensionInfo,
+    pub gerundive: DeclensionInfo,
+}
+
+impl ParticipleTable {
+    /// Returns the participle identified by the given tense and voice as stored
+    /// in the database.
+    pub fn get_mut(&mut self, tense: usize, voice: usize) -> Option<&mut DeclensionInfo> {
+        match (tense, voice) {
+            (0, 0) => Some(&mut self.present_active),
+            (2, 1) => Some(&mut self.perfect_passive),
+            (4, 0) => Some(&mut self.future_active),
+            (4, 1) => Some(&mut self.gerundive),
+            _ => None,
+        }
+    }
+}
+
+/// The gerund of a verb, which is declined only on these cases.
+#[derive(Debug, Default)]
+pub struct GerundTable {
+    pub genitive: DeclensionInfo,
+    pub dative: DeclensionInfo,
+    pub accusative: DeclensionInfo,
+    pub ablative: DeclensionInfo,
+}
+
+/// The supine of a verb, which only has an accusative and an ablative form.
+#[derive(Debug, Default)]
+pub struct SupineTable {
+    pub accusative: DeclensionInfo,
+    pub ablative: DeclensionInfo,
+}
+
+/// The periphrastic conjugations of a verb, which are built from a participle
+/// and a form of 'sum': the active one takes the future active participle
+/// (e.g. 'amātūrus est'), and the passive one takes the gerundive (e.g.
+/// 'amandus est'). Each of them is indexed by mood: indicative first,
+/// subjunctive second.
+#[derive(Debug, Default)]
+pub struct PeriphrasticTable {
+    pub active: [MoodTable; 2],
+    pub passive: [MoodTable; 2],
+}
+
+/// The conjugation table for a verb. Each mood is indexed by voice: active
+/// first, passive second. Note that the subjunctive has no future tenses, and
+/// that the imperative only has the present and the future tenses on the second
+/// and third persons.
+#[derive(Debug, Default)]
+pub struct ConjugationTable {
+    pub indicative: [MoodTable; 2],
+    pub subjunctive: [MoodTable; 2],
+    pub imperative: [MoodTable; 2],
+    pub infinitive: InfinitiveTable,
+    pub participle: ParticipleTable,
+    pub gerund: GerundTable,
+    pub supine: SupineTable,
+    pub periphrastic: PeriphrasticTable,
+}
+
+impl ConjugationTable {
+    /// Returns the voices for the mood identified by the given index as stored
+    /// in the database.
+    pub fn mood_mut(&mut self, mood: usize) -> Option<&mut [MoodTable; 2]> {
+        match mood {
+            0 => Some(&mut self.indicative),
+            1 => Some(&mut self.subjunctive),
+            2 => Some(&mut self.imperative),
+            _ => None,
+        }
+    }
+}
+
+/// Returns a string which describes the forms of a given `tense`, ordered by
+/// number and then by person. Missing forms are simply skipped.
+pub fn get_conjugated_from(tense: &TenseInfo) -> String {
+    tense
+        .forms
+        .iter()
+        .flat_map(|number| number.iter())
+        .filter(|info| !info.inflected.is_empty())
+        .map(|info| info.inflected.join("/"))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+// Returns true if the given person/number combination is available for the
+// given `voice` on the given `word`, as dictated by its flags.
+fn has_person(word: &Word, voice: usize, person: usize, number: usize) -> bool {
+    if word.is_flag_set("impersonal") {
+        return person == 3 && number == 0;
+    }
+    if voice == 1 {
+        if word.is_flag_set("impersonalpassive") {
+            return person == 3 && number == 0;
+        }
+        if word.is_flag_set("onlythirdpassive") {
+            return person == 3;
+        }
+    }
+    true
+}
+
+// Returns true if the given `mood` is to be shown for the given `word`.
+fn has_mood(word: &Word, mood: usize) -> bool {
+    match mood {
+        0 | 1 => true,
+        2 => !word.is_flag_set("noimperative"),
+        6 => !word.is_flag_set("noinfinitive"),
+        8 => true,
+        _ => false,
+    }
+}
+
+// Returns the given imperative `form` in its short version (e.g. 'dīc' instead
+// of 'dīce') if the given `word` is flagged as such. Only the present active in
+// the singular is affected.
+fn shorten_imperative(
+    word: &Word,
+    tense: usize,
+    voice: usize,
+    number: usize,
+    form: String,
+) -> String {
+    if !word.is_flag_set("shortimperative") || tense != 0 || voice != 0 || number != 0 {
+        return form;
+    }
+    match form.strip_suffix('e') {
+        Some(short) => short.to_string(),
+        None => form,
+    }
+}
+
+// Returns true if the given tense belongs to the perfect system.
+fn is_perfect_system(tense: usize) -> bool {
+    matches!(tense, 2 | 3 | 5)
+}
+
+// Returns true if the given `tense` is to be shown for the given `word`.
+fn has_tense(word: &Word, tense: usize) -> bool {
+    if is_perfect_system(tense) {
+        !word.is_flag_set("noperfect")
+    } else {
+        !word.is_flag_set("onlyperfect")
+    }
+}
+
+// Returns true if the given `voice` is to be shown for the given `word` on the
+// given `tense`. Note that semi-deponent verbs are active on the present system
+// but passive on the perfect one.
+fn has_voice(word: &Word, tense: usize, voice: usize) -> bool {
+    let passive_only = word.is_flag_set("deponent")
+        || (word.is_flag_set("semideponent") && is_perfect_system(tense));
+
+    if voice == 0 {
+        !passive_only
+    } else {
+        passive_only || (!word.is_flag_set("nopassive") && !word.is_flag_set("semideponent"))
+    }
+}
+
+// Returns the form of the perfect passive participle of the given `word` to be
+// used for the given `number`, or None if it has no such participle. This
+// takes into account that impersonal passives are built with the neuter.
+fn participle_for(word: &Word, number: usize) -> Option<String> {
+    let participle = word.perfect_participle()?;
+    let stem = participle.strip_suffix("us")?;
+
+    if word.is_flag_set("impersonalpassive") {
+        Some(format!("{stem}um"))
+    } else if number == 1 {
+        Some(format!("{stem}ī"))
+    } else {
+        Some(participle)
+    }
+}
+
+// Returns the verb form for the given `word` and the rest of parameters which
+// have been fetched from the database. On the perfect system, the active voice
+// is built from the perfect stem and the passive one from the participle and
+// the given form of 'sum' (which is what the database stores as `term`).
+fn conjugate_from(
+    word: &Word,
+    tense: usize,
+    voice: usize,
+    number: usize,
+    term: &str,
+) -> Option<String> {
+    let irregular = matches!(word.conjugation, Some(Conjugation::Other));
+
+    if !is_perfect_system(tense) || (irregular && voice == 0) {
+        Some(word.particle.clone() + term)
+    } else if voice == 0 {
+        word.perfect_stem().map(|stem| stem + term)
+    } else {
+        participle_for(word, number).map(|participle| format!("{participle} {term}"))
+    }
+}
+
+// Returns true if the given non-finite form (`mood` being either the infinitive
+// or the participle) is to be shown for the given `word`. Deponent verbs keep
+// their active participles and future infinitive.
+fn has_nonfinite(word: &Word, mood: usize, tense: usize, voice: usize) -> bool {
+    match (mood, tense, voice) {
+        (6, 4, 0) | (8, 0, 0) | (8, 4, 0) => true,
+        (6, 4, 1) => {
+            !word.is_flag_set("deponent")
+                && !word.is_flag_set("semideponent")
+                && !word.is_flag_set("nopassive")
+        }
+        (8, 2, 1) => {
+            word.is_flag_set("deponent")
+                || word.is_flag_set("semideponent")
+                || !word.is_flag_set("nopassive")
+        }
+        (8, 4, 1) => !word.is_flag_set("nogerundive"),
+        _ => has_voice(word, tense, voice),
+    }
+}
+
+// Returns the non-finite form (`mood` being either the infinitive or the
+// participle) for the given `word`. Forms built from the supine are given by
+// the database only as the auxiliary ending (e.g. 'esse' for 'amātum esse'),
+// and they are skipped for 'nosupine' verbs.
+fn nonfinite_from(
+    word: &Word,
+    mood: usize,
+    tense: usize,
+    voice: usize,
+    term: &str,
+) -> Option<String> {
+    let participle = || -> Option<String> {
+        if word.is_flag_set("nosupine") {
+            None
+        } else {
+            word.perfect_participle()
+        }
+    };
+    let supine_stem = || -> Option<String> {
+        participle().and_then(|p| p.strip_suffix("us").map(|stem| stem.to_string()))
+    };
+
+    match (mood, tense, voice, term) {
+        (6, 4, 0, "esse") => supine_stem().map(|stem| format!("{stem}ūrum esse")),
+        (6, 2, 1, "esse") => supine_stem().map(|stem| format!("{stem}um esse")),
+        (6, 4, 1, "īrī") => supine_stem().map(|stem| format!("{stem}um īrī")),
+        (8, 4, 0, "ūrus") => supine_stem().map(|stem| format!("{stem}ūrus")),
+        (8, 2, 1, _) => participle(),
+        (6, 2, 0, _) if !matches!(word.conjugation, Some(Conjugation::Other)) => {
+            word.perfect_stem().map(|stem| stem + term)
+        }
+        _ => Some(word.particle.clone() + term),
+    }
+}
+
+/// The forms of a verb for a given mood and tense on a synopsis, indexed by
+/// voice: active first, passive second.
+#[derive(Debug, Default)]
+pub struct SynopsisRow {
+    pub mood: &'static str,
+    pub tense: &'static str,
+    pub forms: [Vec<String>; 2],
+}
+
+/// Returns the synopsis of the given verb `word` for the given `person` (from 1
+/// to 3) and `number`. That is, the forms for this person and number across
+/// all the moods, tenses and voices. Rows without forms (e.g. imperatives for
+/// the first person) are skipped.
+pub fn get_synopsis(
+    word: &Word,
+    person: usize,
+    number: Number,
+) -> Result<Vec<SynopsisRow>, String> {
+    if !(1..=3).contains(&person) {
+        return Err(format!("bad person '{person}'; expected 1, 2 or 3"));
+    }
+    if !matches!(word.category, Category::Verb) {
+        return Err(format!("'{}' is not a verb", word.enunciated));
+    }
+
+    let table = get_verb_table(word)?;
+    let mut res = vec![];
+    for (mood, [active, passive]) in [
+        ("Indicative", &table.indicative),
+        ("Subjunctive", &table.subjunctive),
+        ("Imperative", &table.imperative),
+    ] {
+        for ((tense, active), (_, passive)) in active.tenses().into_iter().zip(passive.tenses()) {
+            let forms = [active, passive]
+                .map(|info| info.forms[number as usize][person - 1].inflected.clone());
+            if forms.iter().all(|f| f.is_empty()) {
+                continue;
+            }
+            res.push(SynopsisRow { mood, tense, forms });
+        }
+    }
+
+    Ok(res)
+}
+
+/// A finite form of a verb on a given mood, tense, person and number, together
+/// with its counterpart on the other voice. The forms are indexed by voice:
+/// active first, passive second.
+#[derive(Debug)]
+pub struct VoicePair {
+    pub mood: &'static str,
+    pub tense: &'static str,
+    pub person: usize,
+    pub number: Number,
+    pub forms: [Vec<String>; 2],
+}
+
+/// Returns the forms of the given verb `word` on the indicative and the
+/// subjunctive which exist both on the active and on the passive voices, so one
+/// can be transformed into the other. Hence verbs which have a single voice
+/// (i.e. deponent, semi-deponent and 'nopassive' verbs) return none, and
+/// impersonal passives only pair the third person singular.
+pub fn get_voice_pairs(word: &Word) -> Result<Vec<VoicePair>, String> {
+    if !matches!(word.category, Category::Verb) {
+        return Err(format!("'{}' is not a verb", word.enunciated));
+    }
+    if word.is_flag_set("deponent")
+        || word.is_flag_set("semideponent")
+        || word.is_flag_set("nopassive")
+    {
+        return Ok(vec![]);
+    }
+
+    let table = get_verb_table(word)?;
+    let mut res = vec![];
+    for (mood, [active, passive]) in [
+        ("Indicative", &table.indicative),
+        ("Subjunctive", &table.subjunctive),
+    ] {
+        for ((tense, active), (_, passive)) in active.tenses().into_iter().zip(passive.tenses()) {
+            for number in Number::ALL {
+                for person in 1..=3 {
+                    let forms = [active, passive]
+                        .map(|info| info.forms[number as usize][person - 1].inflected.clone());
+                    if forms.iter().any(|f| f.is_empty()) {
+                        continue;
+                    }
+                    res.push(VoicePair {
+                        mood,
+                        tense,
+                        person,
+                        number,
+                        forms,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(res)
+}
+
+/// Returns the conjugation table for the given `word` by assuming it's a
+/// verb, with its indicative, subjunctive and imperative moods, plus its
+/// infinitives, participles, gerund and supine. Deponent verbs will only have
+/// passive forms, while verbs flagged with 'nopassive' will have none.
+/// Imperatives are skipped for 'noimperative' verbs, and shortened for
+/// 'shortimperative' ones. The same goes for the 'noinfinitive', 'nosupine',
+/// 'nogerund' and 'nogerundive' flags. Perfect
+/// forms are built from the principal parts of the verb, and are skipped
+/// altogether for 'noperfect' verbs (the other way around for 'onlyperfect'
+/// verbs).
+pub fn get_verb_table(word: &Word) -> Result<ConjugationTable, String> {
+    let Some(conjugation) = &word.conjugation else {
+        return Err(format!("'{}' has no conjugation", word.enunciated));
+    };
+
+    let conn = get_connection()?;
+    let mut stmt;
+    let mut it = if matches!(conjugation, Conjugation::Other) {
+        stmt = conn
+            .prepare_cached(
+                "SELECT number, person, tense, mood, voice, value \
+                 FROM forms \
+                 WHERE kind = ?1 AND tense IS NOT NULL \
+                 ORDER BY id",
+            )
+            .unwrap();
+        stmt.query([&word.kind]).unwrap()
+    } else {
+        stmt = conn
+            .prepare_cached(
+                "SELECT number, person, tense, mood, voice, value \
+                 FROM forms \
+                 WHERE conjugation_id = ?1 AND IFNULL(kind, '') = '' \
+                 ORDER BY id",
+            )
+            .unwrap();
+        stmt.query([conjugation.clone() as isize]).unwrap()
+    };
+
+    let mut table = ConjugationTable::default();
+
+    while let Some(row) = it.next().unwrap() {
+        // Columns might be NULL for irrelevant values, which are then mapped
+        // into an out-of-bounds index so they are ignored below.
+        let index = |idx: usize| -> usize {
+            let value: Option<isize> = row.get(idx).unwrap();
+            value
+                .and_then(|v| usize::try_from(v).ok())
+                .unwrap_or(usize::MAX)
+        };
+        let number = index(0);
+        let person = index(1);
+        let tense = index(2);
+        let mood = index(3);
+        let voice = index(4);
+        let term: String = row
+            .get::<usize, Option<String>>(5)
+            .unwrap()
+            .unwrap_or_default();
+
+        if term.is_empty() || !has_mood(word, mood) || tense > 5 {
+            continue;
+        }
+
+        // Infinitives and participles have neither person nor number.
+        if mood == 6 || mood == 8 {
+            if voice > 1 || !has_tense(word, tense) {
+                continue;
+            }
+            let Some(form) = nonfinite_from(word, mood, tense, voice, &term) else {
+                continue;
+            };
+
+            // The gerund shares its stem with the gerundive, but it's to be
+            // shown even if the gerundive is not.
+            if mood == 8 && tense == 4 && voice == 1 && !word.is_flag_set("nogerund") {
+                if let Some(stem) = form.strip_suffix("us") {
+                    push_gerund_forms(&mut table.gerund, stem);
+                }
+            }
+            if !has_nonfinite(word, mood, tense, voice) {
+                continue;
+            }
+            let info = if mood == 6 {
+                table
+                    .infinitive
+                    .tense_mut(tense)
+                    .map(|voices| &mut voices[voice])
+            } else {
+                table.participle.get_mut(tense, voice)
+            };
+            if let Some(info) = info {
+                info.inflected.push(form);
+            }
+            continue;
+        }
+        if !(1..=3).contains(&person) || number > 1 || voice > 1 {
+            continue;
+        }
+        if !has_tense(word, tense)
+            || !has_voice(word, tense, voice)
+            || !has_person(word, voice, person, number)
+        {
+            continue;
+        }
+        let Some(mut form) = conjugate_from(word, tense, voice, number, &term) else {
+            continue;
+        };
+        if mood == 2 {
+            form = shorten_imperative(word, tense, voice, number, form);
+        }
+
+        if let Some(info) = table
+            .mood_mut(mood)
+            .and_then(|voices| voices[voice].tense_mut(tense))
+        {
+            info.forms[number][person - 1].inflected.push(form);
+        }
+    }
+
+    if !word.is_flag_set("nosupine") && !word.is_flag_set("onlyperfect") {
+        if let Some(stem) = word
+            .perfect_participle()
+            .and_then(|p| p.strip_suffix("us").map(|stem| stem.to_string()))
+        {
+            table.supine.accusative.inflected.push(format!("{stem}um"));
+            table.supine.ablative.inflected.push(format!("{stem}ū"));
+        }
+    }
+
+    if let Some(sets) = word.flags.get("sets") {
+        apply_verb_overrides(word, &mut table, sets, false)?;
+    }
+    if let Some(adds) = word.flags.get("adds") {
+        apply_verb_overrides(word, &mut table, adds, true)?;
+    }
+
+    if configuration().alternative_verb_forms {
+        push_alternative_forms(word, &mut table);
+    }
+    push_periphrastic_forms(word, &mut table);
+
+    Ok(table)
+}
+
+// Returns the syncopated versions of the given perfect `form` which has been
+// built from the given perfect `stem` (e.g. 'amāv'). That is, perfects in '-vī'
+// lose the 'v' and the following vowel before an 's' or an 'r' (e.g.
+// 'amāsse', 'amārunt'), except for perfects in '-īvī', which simply lose the
+// 'v' (e.g. 'audiērunt'), and also contract when an 's' follows (e.g.
+// 'audīsse').
+fn syncopated_forms(stem: &str, form: &str) -> Vec<String> {
+    let (Some(rest), Some(base)) = (form.strip_prefix(stem), stem.strip_suffix('v')) else {
+        return vec![];
+    };
+    let Some(first) = rest.chars().next() else {
+        return vec![];
+    };
+    let tail = &rest[first.len_utf8()..];
+
+    let mut res = vec![];
+    if let Some(root) = base.strip_suffix('ī') {
+        if "aeiouāēīōū".contains(first) {
+            res.push(format!("{root}i{rest}"));
+        }
+        if rest.starts_with("is") {
+            res.push(format!("{base}{tail}"));
+        }
+    } else if base.ends_with(['ā', 'ē', 'ō'])
+        && (rest.starts_with("is") || rest.starts_with("er") || rest.starts_with("ēr"))
+    {
+        res.push(format!("{base}{tail}"));
+    }
+    res
+}
+
+/// Pushes into the given conjugation `table` the alternative forms of the
+/// perfect system for the given `word`: the syncopated ones (e.g. 'amāsse' for
+/// 'amāvisse', or 'amārunt' for 'amāvērunt'), and the '-ēre' ending for the
+/// third person plural of the perfect indicative (e.g. 'amāvēre'). They are
+/// added after the regular forms. This is done by `get_verb_table` when
+/// enabled on the configuration.
+pub fn push_alternative_forms(word: &Word, table: &mut ConjugationTable) {
+    let Some(stem) = word.perfect_stem() else {
+        return;
+    };
+
+    let ere: Vec<String> = table.indicative[0].perfect.forms[1][2]
+        .inflected
+        .iter()
+        .filter_map(|form| form.strip_suffix("ērunt"))
+        .map(|root| format!("{root}ēre"))
+        .collect();
+
+    let mut cells: Vec<&mut DeclensionInfo> = vec![&mut table.infinitive.perfect[0]];
+    for mood in [&mut table.indicative[0], &mut table.subjunctive[0]] {
+        for tense in [
+            &mut mood.perfect,
+            &mut mood.pluperfect,
+            &mut mood.future_perfect,
+        ] {
+            cells.extend(tense.forms.iter_mut().flat_map(|number| number.iter_mut()));
+        }
+    }
+
+    for info in cells {
+        let extra: Vec<String> = info
+            .inflected
+            .iter()
+            .flat_map(|form| syncopated_forms(&stem, form))
+            .collect();
+        for form in extra {
+            if !info.inflected.contains(&form) {
+                info.inflected.push(form);
+            }
+        }
+    }
+
+    let info = &mut table.indicative[0].perfect.forms[1][2];
+    for form in ere {
+        if !info.inflected.contains(&form) {
+            info.inflected.push(form);
+        }
+    }
+}
+
+// Returns the index of the given `name` on `names`, or an error mentioning
+// what was being looked for.
+fn index_of(names: &[&str], name: &str, what: &str) -> Result<usize, String> {
+    names.iter().position(|n| *n == name).ok_or(format!(
+        "unknown {what} '{name}'. Available: {}",
+        names.join(", ")
+    ))
+}
+
+// Applies to the given conjugation `table` the overrides from the given `blob`,
+// which comes from either the "sets" or the "adds" flag. As with declensions,
+// values are terms which are conjugated as the ones from the database, and
+// which are keyed by mood, voice, tense, person and number. For example:
+//
+//   {"indicative": {"active": {"present": {"second": {"singular": ["s"]}}}}}
+//
+// Forms from "sets" replace the existing ones, while forms from "adds" (`add`
+// being true) are appended to them.
+fn apply_verb_overrides(
+    word: &Word,
+    table: &mut ConjugationTable,
+    blob: &Value,
+    add: bool,
+) -> Result<(), String> {
+    let Some(moods) = blob.as_object() else {
+        return Ok(());
+    };
+
+    for (mood, voices) in moods.iter() {
+        let mood = index_of(&MOODS, mood, "mood")?;
+        for (voice, tenses) in voices.as_object().into_iter().flatten() {
+            let voice = index_of(&VOICES, voice, "voice")?;
+            for (tense, persons) in tenses.as_object().into_iter().flatten() {
+                let tense = index_of(&TENSES, tense, "tense")?;
+                for (person, numbers) in persons.as_object().into_iter().flatten() {
+                    let person = index_of(&PERSONS, person, "person")?;
+                    for (number, terms) in numbers.as_object().into_iter().flatten() {
+                        let number = index_of(&NUMBERS, number, "number")?;
+                        let forms: Vec<String> = terms
+                            .as_array()
+                            .into_iter()
+                            .flatten()
+                            .filter_map(|term| term.as_str())
+                            .filter_map(|term| conjugate_from(word, tense, voice, number, term))
+                            .collect();
+
+                        let Some(info) = table
+                            .mood_mut(mood)
+                            .and_then(|voices| voices[voice].tense_mut(tense))
+                        else {
+                            continue;
+                        };
+                        let cell = &mut info.forms[number][person];
+                        if add {
+                            cell.inflected.extend(forms);
+                        } else {
+                            cell.inflected = forms;
+                        }
+                        cell.canonicalize();
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Forms of 'sum' on the indicative, indexed by tense as stored in the
+// database, then by number and then by person.
+const SUM_INDICATIVE: [[[&str; 3]; 2]; 6] = [
+    [["sum", "es", "est"], ["sumus", "estis", "sunt"]],
+    [["eram", "erās", "erat"], ["erāmus", "erātis", "erant"]],
+    [["fuī", "fuistī", "fuit"], ["fuimus", "fuistis", "fuērunt"]],
+    [
+        ["fueram", "fuerās", "fuerat"],
+        ["fuerāmus", "fuerātis", "fuerant"],
+    ],
+    [["erō", "eris", "erit"], ["erimus", "eritis", "erunt"]],
+    [
+        ["fuerō", "fueris", "fuerit"],
+        ["fuerimus", "fueritis", "fuerint"],
+    ],
+];
+
+// Forms of 'sum' on the subjunctive, indexed as `SUM_INDICATIVE`. The
+// subjunctive has no future tenses.
+const SUM_SUBJUNCTIVE: [[[&str; 3]; 2]; 4] = [
+    [["sim", "sīs", "sit"], ["sīmus", "sītis", "sint"]],
+    [
+        ["essem", "essēs", "esset"],
+        ["essēmus", "essētis", "essent"],
+    ],
+    [
+        ["fuerim", "fuerīs", "fuerit"],
+        ["fuerīmus", "fuerītis", "fuerint"],
+    ],
+    [
+        ["fuissem", "fuissēs", "fuisset"],
+        ["fuissēmus", "fuissētis", "fuissent"],
+    ],
+];
+
+// Fills the periphrastic conjugations of the given `table` from the participles
+// which have already been set on it. Impersonal constructions (e.g. the passive
+// periphrastic of intransitive verbs, as in 'veniendum est') only have the
+// third person singular, built with the neuter.
+fn push_periphrastic_forms(word: &Word, table: &mut ConjugationTable) {
+    let impersonal = word.is_flag_set("impersonal");
+    let impersonal_passive =
+        impersonal || word.is_flag_set("impersonalpassive") || word.is_flag_set("nopassive");
+
+    for (participles, moods, impersonal) in [
+        (
+            &table.participle.future_active,
+            &mut table.periphrastic.active,
+            impersonal,
+        ),
+        (
+            &table.participle.gerundive,
+            &mut table.periphrastic.passive,
+            impersonal_passive,
+        ),
+    ] {
+        for (mood, sum) in moods
+            .iter_mut()
+            .zip([&SUM_INDICATIVE[..], &SUM_SUBJUNCTIVE[..]])
+        {
+            for (tense, forms) in sum.iter().enumerate() {
+                let Some(info) = mood.tense_mut(tense) else {
+                    continue;
+                };
+                for (number, persons) in forms.iter().enumerate() {
+                    for (person, sum) in persons.iter().enumerate() {
+                        if impersonal && (number != 0 || person != 2) {
+                            continue;
+                        }
+                        for participle in participles.inflected.iter() {
+                            let Some(stem) = participle.strip_suffix("us") else {
+                                continue;
+                            };
+                            let participle = if impersonal {
+                                format!("{stem}um")
+                            } else if number == 1 {
+                                format!("{stem}ī")
+                            } else {
+                                participle.clone()
+                            };
+                            info.forms[number][person]
+                                .inflected
+                                .push(format!("{participle} {sum}"));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Pushes into the given gerund `table` the forms built from the given `stem`
+// (e.g. 'amand' from 'amandus').
+fn push_gerund_forms(table: &mut GerundTable, stem: &str) {
+    for (info, ending) in [
+        (&mut table.genitive, "ī"),
+        (&mut table.dative, "ō"),
+        (&mut table.accusative, "um"),
+        (&mut table.ablative, "ō"),
+    ] {
+        let form = format!("{stem}{ending}");
+        if !info.inflected.contains(&form) {
+            info.inflected.push(form);
+        }
+    }
+}
+
+// Pushes into `forms` all the inflected forms from the given declension
+// `table`.
+fn push_declension_forms(table: &DeclensionTable, forms: &mut Vec<String>) {
+    for (_, _, info) in table.iter() {
+        forms.extend(info.inflected.iter().cloned());
+    }
+}
+
+// Pushes into `forms` all the conjugated forms from the given mood `table`.
+fn push_mood_forms(table: &MoodTable, forms: &mut Vec<String>) {
+    for tense in [
+        &table.present,
+        &table.imperfect,
+        &table.future,
+        &table.perfect,
+        &table.pluperfect,
+        &table.future_perfect,
+    ] {
+        for info in tense.forms.iter().flat_map(|number| number.iter()) {
+            forms.extend(info.inflected.iter().cloned());
+        }
+    }
+}
+
+/// Returns all the forms of the given `word` without duplicates, starting with
+/// the first part of its enunciate. Words which cannot be inflected only
+/// return the latter.
+pub fn get_all_forms(word: &Word) -> Result<Vec<String>, String> {
+    let mut forms = vec![word.singular_nominative()];
+
+    if !word.is_flag_set("indeclinable") {
+        match word.category {
+            Category::Noun => push_declension_forms(&get_noun_table(word)?, &mut forms),
+            Category::Adjective => {
+                for table in get_adjective_table(word)? {
+                    push_declension_forms(&table, &mut forms);
+                }
+            }
+            Category::Pronoun => {
+                for table in get_pronoun_table(word)? {
+                    push_declension_forms(&table, &mut forms);
+                }
+            }
+            Category::Verb => {
+                let table = get_verb_table(word)?;
+                for voices in [&table.indicative, &table.subjunctive, &table.imperative] {
+                    for mood in voices {
+                        push_mood_forms(mood, &mut forms);
+                    }
+                }
+                for info in [
+                    &table.infinitive.present,
+                    &table.infinitive.perfect,
+                    &table.infinitive.future,
+                ]
+                .into_iter()
+                .flatten()
+                .chain([
+                    &table.participle.present_active,
+                    &table.participle.perfect_passive,
+                    &table.participle.future_active,
+                    &table.participle.gerundive,
+                    &table.gerund.genitive,
+                    &table.gerund.dative,
+                    &table.gerund.accusative,
+                    &table.gerund.ablative,
+                    &table.supine.accusative,
+                    &table.supine.ablative,
+                ]) {
+                    forms.extend(info.inflected.iter().cloned());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut res: Vec<String> = vec![];
+    for form in forms {
+        if !form.is_empty() && !res.contains(&form) {
+            res.push(form);
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_declension_rows_cache() {
+        // A kind which does not exist, so no other test can cache it.
+        let kind = "test-cached";
+        let fetches = Cell::new(0);
+        let fetch = || {
+            fetches.set(fetches.get() + 1);
+            Ok(vec![(Number::Singular, Case::Nominative, "a".to_string())])
+        };
+
+        let first = cached_declension_rows(kind, Gender::Feminine, fetch).unwrap();
+        let second = cached_declension_rows(kind, Gender::Feminine, fetch).unwrap();
+        assert_eq!(fetches.get(), 1);
+        assert!(Arc::ptr_eq(&first, &second));
+
+        // Each gender is cached on its own.
+        cached_declension_rows(kind, Gender::Masculine, fetch).unwrap();
+        assert_eq!(fetches.get(), 2);
+
+        // Errors are not cached.
+        assert!(cached_declension_rows(kind, Gender::Neuter, || Err("nope".into())).is_err());
+        cached_declension_rows(kind, Gender::Neuter, fetch).unwrap();
+        assert_eq!(fetches.get(), 3);
+
+        clear_forms_cache();
+        let third = cached_declension_rows(kind, Gender::Feminine, fetch).unwrap();
+        assert_eq!(fetches.get(), 4);
+        assert!(!Arc::ptr_eq(&first, &third));
+        assert_eq!(first, third);
+    }
+}
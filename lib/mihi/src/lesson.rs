@@ -0,0 +1,212 @@
+use crate::word::{select_words_by_ids, Word};
+use rusqlite::params;
+
+/// A lesson (e.g. a chapter from a book) which groups the words and the
+/// exercises to be studied together. It is mapped in the database via the
+/// 'lessons' table, and words and exercises point to it through their
+/// 'lesson_id' column.
+#[derive(Clone, Debug)]
+pub struct Lesson {
+    pub id: i32,
+    pub name: String,
+    /// Position of this lesson among the rest of them (e.g. the number of the
+    /// chapter).
+    pub position: isize,
+    /// Book or course from which this lesson comes, if any.
+    pub source: Option<String>,
+    /// Number of words linked to this lesson.
+    pub words: usize,
+    /// Number of exercises linked to this lesson.
+    pub exercises: usize,
+}
+
+impl std::fmt::Display for Lesson {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// Creates a lesson with the given `name`, and optionally the `source` where it
+/// comes from. Lessons are sorted by their `position`, which by default puts
+/// the new lesson after all the others.
+pub fn create_lesson(
+    name: &str,
+    source: Option<&str>,
+    position: Option<isize>,
+) -> Result<(), String> {
+    let conn = crate::get_connection()?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("the name of the lesson cannot be empty".to_string());
+    }
+
+    match conn.execute(
+        "INSERT INTO lessons (name, position, source, created_at, updated_at) \
+         VALUES (?1, IFNULL(?2, (SELECT IFNULL(MAX(position), 0) + 1 FROM lessons)), ?3, \
+                 datetime('now'), datetime('now'))",
+        params![
+            name,
+            position,
+            source.map(str::trim).filter(|s| !s.is_empty())
+        ],
+    ) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not create '{name}': {e}")),
+    }
+}
+
+/// Returns all the lessons sorted by their position, alongside the number of
+/// words and exercises linked to each of them.
+pub fn select_lessons() -> Result<Vec<Lesson>, String> {
+    let conn = crate::get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT l.id, l.name, l.position, l.source, \
+                    (SELECT COUNT(*) FROM words WHERE lesson_id = l.id), \
+                    (SELECT COUNT(*) FROM exercises WHERE lesson_id = l.id) \
+             FROM lessons l \
+             ORDER BY l.position, l.name",
+        )
+        .unwrap();
+    let mut it = stmt.query([]).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(Lesson {
+            id: row.get(0).unwrap(),
+            name: row.get(1).unwrap(),
+            position: row.get(2).unwrap(),
+            source: row.get(3).unwrap(),
+            words: row.get::<usize, isize>(4).unwrap() as usize,
+            exercises: row.get::<usize, isize>(5).unwrap() as usize,
+        });
+    }
+    Ok(res)
+}
+
+/// Returns the lesson with the given `name`.
+pub fn find_lesson(name: &str) -> Result<Lesson, String> {
+    match select_lessons()?
+        .into_iter()
+        .find(|l| l.name == name.trim())
+    {
+        Some(lesson) => Ok(lesson),
+        None => Err(format!("the lesson '{}' does not exist", name.trim())),
+    }
+}
+
+/// Links the words with the given `enunciates` and the exercises with the
+/// given `titles` to the given `lesson`, moving them away from any lesson they
+/// were on before. Everything is linked on a single transaction. Returns the
+/// enunciates and titles which could not be found, which are skipped.
+pub fn link_to_lesson(
+    lesson: &Lesson,
+    enunciates: &[String],
+    titles: &[String],
+) -> Result<Vec<String>, String> {
+    let conn = crate::get_connection()?;
+    let tx = match conn.unchecked_transaction() {
+        Ok(tx) => tx,
+        Err(e) => return Err(format!("could not link to '{}': {e}", lesson.name)),
+    };
+
+    let mut missing = vec![];
+    for (query, names) in [
+        (
+            "UPDATE words SET lesson_id = ?1 WHERE enunciated = ?2",
+            enunciates,
+        ),
+        (
+            "UPDATE exercises SET lesson_id = ?1 WHERE title = ?2",
+            titles,
+        ),
+    ] {
+        for name in names {
+            match tx.execute(query, params![lesson.id, name.trim()]) {
+                Ok(0) => missing.push(name.clone()),
+                Ok(_) => {}
+                Err(e) => return Err(format!("could not link '{name}': {e}")),
+            }
+        }
+    }
+
+    match tx.commit() {
+        Ok(_) => Ok(missing),
+        Err(e) => Err(format!("could not link to '{}': {e}", lesson.name)),
+    }
+}
+
+/// Returns the words linked to the given `lesson` which can be asked for (i.e.
+/// which have a translation and are not suspended), with the ones which have
+/// been answered right the fewest times first.
+pub fn select_lesson_words(lesson: &Lesson) -> Result<Vec<Word>, String> {
+    let conn = crate::get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id FROM words \
+             WHERE lesson_id = ?1 AND translation != '{}' AND suspended = 0 \
+             ORDER BY IFNULL(succeeded, 0) ASC, steps ASC, enunciated",
+        )
+        .unwrap();
+    let ids = stmt
+        .query_map([lesson.id], |row| row.get::<usize, i32>(0))
+        .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| e.to_string())?;
+
+    select_words_by_ids(&ids)
+}
+
+/// Returns the enunciates of all the words linked to the given `lesson`, and
+/// the titles of all of its exercises.
+pub fn select_lesson_contents(lesson: &Lesson) -> Result<(Vec<String>, Vec<String>), String> {
+    let conn = crate::get_connection()?;
+
+    let mut res = (vec![], vec![]);
+    for (query, names) in [
+        (
+            "SELECT enunciated FROM words WHERE lesson_id = ?1 ORDER BY enunciated",
+            &mut res.0,
+        ),
+        (
+            "SELECT title FROM exercises WHERE lesson_id = ?1 ORDER BY title",
+            &mut res.1,
+        ),
+    ] {
+        let mut stmt = conn.prepare_cached(query).unwrap();
+        *names = stmt
+            .query_map([lesson.id], |row| row.get::<usize, String>(0))
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(res)
+}
+
+/// Deletes the lesson with the given `name`. Its words and exercises are kept,
+/// but they no longer belong to any lesson.
+pub fn delete_lesson(name: &str) -> Result<(), String> {
+    let conn = crate::get_connection()?;
+    let tx = match conn.unchecked_transaction() {
+        Ok(tx) => tx,
+        Err(e) => return Err(format!("could not remove '{name}': {e}")),
+    };
+
+    for table in ["words", "exercises"] {
+        if let Err(e) = tx.execute(
+            format!(
+                "UPDATE {table} SET lesson_id = NULL \
+                 WHERE lesson_id IN (SELECT id FROM lessons WHERE name = ?1)"
+            )
+            .as_str(),
+            params![name.trim()],
+        ) {
+            return Err(format!("could not remove '{name}': {e}"));
+        }
+    }
+    match tx
+        .execute("DELETE FROM lessons WHERE name = ?1", params![name.trim()])
+        .and_then(|_| tx.commit())
+    {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not remove '{name}': {e}")),
+    }
+}
@@ -1,20 +1,46 @@
+pub mod analysis;
+pub mod archive;
 pub mod cfg;
+pub mod drill;
+pub mod exam;
 pub mod exercise;
+pub mod history;
 pub mod inflection;
+pub mod lesson;
+pub mod note;
+pub mod render;
+pub mod schedule;
+pub mod sentence;
+pub mod session;
+pub mod simulation;
+pub mod sync;
 pub mod tag;
 pub mod word;
+pub mod writer;
 
-/// Get a connection to the database. Note that you can set the 'MIHI_DATABASE'
-/// environment variable to define an alternative path.
-pub fn get_connection() -> Result<rusqlite::Connection, String> {
-    let name = &std::env::var("MIHI_DATABASE").unwrap_or("database.sqlite3".to_string());
-    let path = crate::cfg::get_config_path()?.join(name);
+pub use mihi_core::numeral;
+pub use mihi_core::pensum;
+pub use mihi_core::template;
+pub use mihi_store::{
+    current_profile, database_status, delete_profile, get_connection, get_data_path,
+    get_database_path, get_profile_path, migrate_database, profile_exists, select_profiles,
+    set_data_path, set_profile, DatabaseStatus,
+};
 
-    match rusqlite::Connection::open(&path) {
-        Ok(handle) => Ok(handle),
-        Err(_) => Err(format!(
-            "could not fetch the database in '{}'",
-            path.display()
-        )),
-    }
+use std::path::Path;
+
+/// Initializes the database. See `mihi_store::init_database`.
+pub fn init_database(seed: Option<&Path>) -> Result<(), String> {
+    mihi_store::init_database(seed)?;
+    crate::inflection::clear_forms_cache();
+    Ok(())
+}
+
+/// Populates the tables with the endings from which words are inflected, and
+/// drops the endings cached from a previous database. See
+/// `mihi_store::seed_inflection_data`.
+pub fn seed_inflection_data() -> Result<(), String> {
+    mihi_store::seed_inflection_data()?;
+    crate::inflection::clear_forms_cache();
+    Ok(())
 }
@@ -0,0 +1,108 @@
+use crate::word::Word;
+use rusqlite::params;
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A note jotted down by the user during a practice session. It's either
+/// attached to a word, or to the session as a whole. It is mapped in the
+/// database via the 'notes' table.
+#[derive(Clone, Debug)]
+pub struct Note {
+    pub id: i32,
+    pub word_id: Option<i32>,
+    pub body: String,
+    pub created_at: String,
+}
+
+impl std::fmt::Display for Note {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.created_at, self.body)
+    }
+}
+
+// Returns the identifier of the current session, which is the UNIX timestamp
+// of the first time it was asked for. That is, a session lasts as long as the
+// process does.
+fn current_session() -> i64 {
+    static SESSION: OnceLock<i64> = OnceLock::new();
+
+    *SESSION.get_or_init(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs() as i64)
+    })
+}
+
+/// Saves a note with the given `body` for the current session. If a `word` is
+/// given, then the note is attached to it. Otherwise it's a note about the
+/// session as a whole.
+pub fn create_note(word: Option<&Word>, body: &str) -> Result<(), String> {
+    let body = body.trim();
+    if body.is_empty() {
+        return Err("the note is empty".to_string());
+    }
+
+    let conn = crate::get_connection()?;
+    match conn.execute(
+        "INSERT INTO notes (word_id, session, body, created_at) \
+         VALUES (?1, ?2, ?3, datetime('now'))",
+        params![word.map(|w| w.id), current_session(), body],
+    ) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not save the note: {e}")),
+    }
+}
+
+/// Returns the notes attached to the given `word`, from the oldest to the
+/// newest.
+pub fn select_notes_for(word: &Word) -> Result<Vec<Note>, String> {
+    let conn = crate::get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id, word_id, body, created_at \
+             FROM notes \
+             WHERE word_id = ?1 \
+             ORDER BY created_at, id",
+        )
+        .unwrap();
+    let mut it = stmt.query([word.id]).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(Note {
+            id: row.get(0).unwrap(),
+            word_id: row.get(1).unwrap(),
+            body: row.get(2).unwrap(),
+            created_at: row.get(3).unwrap(),
+        });
+    }
+    Ok(res)
+}
+
+/// Returns the notes about the session as a whole which were taken on the last
+/// session that had any, not counting the current one.
+pub fn select_last_session_notes() -> Result<Vec<Note>, String> {
+    let conn = crate::get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id, word_id, body, created_at \
+             FROM notes \
+             WHERE word_id IS NULL AND session = ( \
+                 SELECT MAX(session) FROM notes WHERE word_id IS NULL AND session != ?1 \
+             ) \
+             ORDER BY created_at, id",
+        )
+        .unwrap();
+    let mut it = stmt.query(params![current_session()]).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(Note {
+            id: row.get(0).unwrap(),
+            word_id: row.get(1).unwrap(),
+            body: row.get(2).unwrap(),
+            created_at: row.get(3).unwrap(),
+        });
+    }
+    Ok(res)
+}
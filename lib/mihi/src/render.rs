@@ -0,0 +1,425 @@
+use crate::cfg::{configuration, CaseOrder};
+use crate::inflection::{
+    get_adjective_table, get_noun_table, get_pronoun_table, get_synopsis, get_verb_table,
+    ConjugationTable, DeclensionInfo, DeclensionTable, MoodTable, Number,
+};
+use crate::word::{Category, Word};
+
+/// The formats in which inflection tables can be rendered.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Format {
+    #[default]
+    Markdown,
+    Latex,
+    Html,
+}
+
+impl TryFrom<&str> for Format {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "md" | "markdown" => Ok(Self::Markdown),
+            "latex" | "tex" => Ok(Self::Latex),
+            "html" => Ok(Self::Html),
+            _ => Err("unknown format. Available: md, latex, html"),
+        }
+    }
+}
+
+/// A table of inflected forms ready to be rendered. Each row starts with its
+/// own header (e.g. the name of a case), followed by one cell per column.
+#[derive(Clone, Debug, Default)]
+pub struct Table {
+    pub title: String,
+    pub columns: Vec<String>,
+    pub rows: Vec<(String, Vec<String>)>,
+}
+
+impl Table {
+    /// Returns this table in the given `format`. Empty cells are rendered as a
+    /// dash.
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Markdown => self.markdown(),
+            Format::Latex => self.latex(),
+            Format::Html => self.html(),
+        }
+    }
+
+    fn markdown(&self) -> String {
+        let escape = |s: &str| s.replace('|', "\\|");
+
+        let mut res = format!("**{}**\n\n|", escape(&self.title));
+        for column in std::iter::once(&String::new()).chain(self.columns.iter()) {
+            res.push_str(format!(" {} |", escape(column)).as_str());
+        }
+        res.push_str("\n|");
+        res.push_str(&"---|".repeat(self.columns.len() + 1));
+        res.push('\n');
+        for (header, cells) in self.rows.iter() {
+            res.push_str(format!("| **{}** |", escape(header)).as_str());
+            for cell in cells {
+                res.push_str(format!(" {} |", escape(&dash(cell))).as_str());
+            }
+            res.push('\n');
+        }
+
+        res
+    }
+
+    fn latex(&self) -> String {
+        let escape = |s: &str| {
+            s.chars().fold(String::new(), |mut acc, c| {
+                match c {
+                    '\\' => acc.push_str("\\textbackslash{}"),
+                    '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                        acc.push('\\');
+                        acc.push(c);
+                    }
+                    '~' => acc.push_str("\\textasciitilde{}"),
+                    '^' => acc.push_str("\\textasciicircum{}"),
+                    _ => acc.push(c),
+                }
+                acc
+            })
+        };
+        let width = self.columns.len() + 1;
+
+        let mut res = format!("\\begin{{tabular}}{{{}}}\n", "l".repeat(width));
+        res.push_str(
+            format!(
+                "\\multicolumn{{{width}}}{{l}}{{\\textbf{{{}}}}} \\\\\n\\hline\n",
+                escape(&self.title)
+            )
+            .as_str(),
+        );
+        for column in self.columns.iter() {
+            res.push_str(format!(" & {}", escape(column)).as_str());
+        }
+        res.push_str(" \\\\\n\\hline\n");
+        for (header, cells) in self.rows.iter() {
+            res.push_str(format!("\\textbf{{{}}}", escape(header)).as_str());
+            for cell in cells {
+                res.push_str(format!(" & {}", escape(&dash(cell))).as_str());
+            }
+            res.push_str(" \\\\\n");
+        }
+        res.push_str("\\hline\n\\end{tabular}\n");
+
+        res
+    }
+
+    fn html(&self) -> String {
+        let escape = |s: &str| {
+            s.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+        };
+
+        let mut res = format!("<h3>{}</h3>\n<table>\n<tr><th></th>", escape(&self.title));
+        for column in self.columns.iter() {
+            res.push_str(format!("<th>{}</th>", escape(column)).as_str());
+        }
+        res.push_str("</tr>\n");
+        for (header, cells) in self.rows.iter() {
+            res.push_str(format!("<tr><th>{}</th>", escape(header)).as_str());
+            for cell in cells {
+                res.push_str(format!("<td>{}</td>", escape(&dash(cell))).as_str());
+            }
+            res.push_str("</tr>\n");
+        }
+        res.push_str("</table>\n");
+
+        res
+    }
+}
+
+// Returns the given `cell`, or a dash if it's empty.
+fn dash(cell: &str) -> String {
+    if cell.is_empty() {
+        "-".to_string()
+    } else {
+        cell.to_string()
+    }
+}
+
+// Returns the contents of a cell for the given inflected forms.
+fn cell(info: &DeclensionInfo) -> String {
+    info.inflected.join("/")
+}
+
+/// Returns the given declension `table` for `word` as a table with the singular
+/// and plural forms for each case, following the given case `order`. Cases
+/// with no forms at all (e.g. the vocative of most pronouns) are skipped.
+pub fn declension_table(
+    title: &str,
+    word: &Word,
+    table: &DeclensionTable,
+    order: &CaseOrder,
+) -> Table {
+    let rows = order
+        .cases_for(word)
+        .into_iter()
+        .filter(|&case| {
+            table
+                .row(case)
+                .iter()
+                .any(|info| !info.inflected.is_empty())
+        })
+        .map(|case| {
+            (
+                case.title().to_string(),
+                Number::ALL
+                    .iter()
+                    .map(|&number| cell(table.get(case, number)))
+                    .collect(),
+            )
+        })
+        .collect();
+
+    Table {
+        title: title.to_string(),
+        columns: vec!["Singular".to_string(), "Plural".to_string()],
+        rows,
+    }
+}
+
+// Returns a table for the given `mood` where each row is a tense and each
+// column is a person and a number.
+fn mood_table(title: String, mood: &MoodTable) -> Table {
+    let rows = mood
+        .tenses()
+        .into_iter()
+        .filter(|(_, tense)| !tense.is_empty())
+        .map(|(name, tense)| {
+            (
+                name.to_string(),
+                tense
+                    .forms
+                    .iter()
+                    .flat_map(|number| number.iter())
+                    .map(cell)
+                    .collect(),
+            )
+        })
+        .collect();
+
+    Table {
+        title,
+        columns: [
+            "1st sg.", "2nd sg.", "3rd sg.", "1st pl.", "2nd pl.", "3rd pl.",
+        ]
+        .map(String::from)
+        .to_vec(),
+        rows,
+    }
+}
+
+/// Returns the given conjugation `table` for the verb with the given
+/// `enunciated` form as a list of tables: one for each mood and voice which
+/// has forms, and then the ones for the non-finite forms.
+pub fn conjugation_tables(enunciated: &str, table: &ConjugationTable) -> Vec<Table> {
+    let mut res = vec![];
+
+    for (mood, voices) in [
+        ("indicative", &table.indicative),
+        ("subjunctive", &table.subjunctive),
+        ("imperative", &table.imperative),
+    ] {
+        for (voice, forms) in ["active", "passive"].iter().zip(voices.iter()) {
+            if !forms.is_empty() {
+                res.push(mood_table(format!("{enunciated}: {mood} {voice}"), forms));
+            }
+        }
+    }
+    for (voice, moods) in [
+        ("active", &table.periphrastic.active),
+        ("passive", &table.periphrastic.passive),
+    ] {
+        for (mood, forms) in ["indicative", "subjunctive"].iter().zip(moods.iter()) {
+            if !forms.is_empty() {
+                res.push(mood_table(
+                    format!("{enunciated}: {voice} periphrastic {mood}"),
+                    forms,
+                ));
+            }
+        }
+    }
+
+    let infinitive = &table.infinitive;
+    let participle = &table.participle;
+    let nonfinite = [
+        (
+            "infinitives",
+            vec![
+                (
+                    "Present",
+                    cell(&infinitive.present[0]),
+                    cell(&infinitive.present[1]),
+                ),
+                (
+                    "Perfect",
+                    cell(&infinitive.perfect[0]),
+                    cell(&infinitive.perfect[1]),
+                ),
+                (
+                    "Future",
+                    cell(&infinitive.future[0]),
+                    cell(&infinitive.future[1]),
+                ),
+            ],
+        ),
+        (
+            "participles",
+            vec![
+                ("Present", cell(&participle.present_active), String::new()),
+                ("Perfect", String::new(), cell(&participle.perfect_passive)),
+                (
+                    "Future",
+                    cell(&participle.future_active),
+                    cell(&participle.gerundive),
+                ),
+            ],
+        ),
+    ];
+    for (name, rows) in nonfinite {
+        if rows
+            .iter()
+            .all(|(_, active, passive)| active.is_empty() && passive.is_empty())
+        {
+            continue;
+        }
+        res.push(Table {
+            title: format!("{enunciated}: {name}"),
+            columns: vec!["Active".to_string(), "Passive".to_string()],
+            rows: rows
+                .into_iter()
+                .map(|(tense, active, passive)| (tense.to_string(), vec![active, passive]))
+                .collect(),
+        });
+    }
+
+    let gerund = &table.gerund;
+    let supine = &table.supine;
+    for (name, cases) in [
+        (
+            "gerund",
+            vec![
+                ("Genitive", &gerund.genitive),
+                ("Dative", &gerund.dative),
+                ("Accusative", &gerund.accusative),
+                ("Ablative", &gerund.ablative),
+            ],
+        ),
+        (
+            "supine",
+            vec![
+                ("Accusative", &supine.accusative),
+                ("Ablative", &supine.ablative),
+            ],
+        ),
+    ] {
+        if cases.iter().all(|(_, info)| info.inflected.is_empty()) {
+            continue;
+        }
+        res.push(Table {
+            title: format!("{enunciated}: {name}"),
+            columns: vec!["Forms".to_string()],
+            rows: cases
+                .into_iter()
+                .map(|(case, info)| (case.to_string(), vec![cell(info)]))
+                .collect(),
+        });
+    }
+
+    res
+}
+
+/// Returns the synopsis of the given verb `word` for the given `person` (from 1
+/// to 3) and `number` as a table where each row is a mood and a tense, and the
+/// columns are the active and the passive voices. See
+/// `inflection::get_synopsis`.
+pub fn synopsis_table(word: &Word, person: usize, number: Number) -> Result<Table, String> {
+    let ordinal = ["first", "second", "third"];
+    let rows = get_synopsis(word, person, number)?
+        .into_iter()
+        .map(|row| {
+            (
+                format!("{} {}", row.tense, row.mood.to_lowercase()),
+                row.forms.map(|forms| forms.join("/")).to_vec(),
+            )
+        })
+        .collect();
+
+    Ok(Table {
+        title: format!(
+            "{}: {} person {number}",
+            word.enunciated,
+            ordinal[person - 1]
+        ),
+        columns: vec!["Active".to_string(), "Passive".to_string()],
+        rows,
+    })
+}
+
+/// Returns the inflection tables for the given `word`, following the case
+/// order from the configuration. Adjectives and gendered pronouns get a table
+/// for each gender.
+pub fn tables_for(word: &Word) -> Result<Vec<Table>, String> {
+    if word.is_flag_set("indeclinable") {
+        return Err(format!("'{}' cannot be inflected", word.enunciated));
+    }
+
+    let order = configuration().case_order;
+    let gendered = |tables: &[DeclensionTable]| -> Vec<Table> {
+        ["masculine", "feminine", "neuter"]
+            .iter()
+            .zip(tables.iter())
+            .map(|(gender, table)| {
+                declension_table(
+                    &format!("{}: {gender}", word.enunciated),
+                    word,
+                    table,
+                    &order,
+                )
+            })
+            .collect()
+    };
+
+    match word.category {
+        Category::Noun => Ok(vec![declension_table(
+            &word.enunciated,
+            word,
+            &get_noun_table(word)?,
+            &order,
+        )]),
+        Category::Adjective => Ok(gendered(&get_adjective_table(word)?)),
+        Category::Pronoun => {
+            let tables = get_pronoun_table(word)?;
+            if tables.len() == 1 {
+                Ok(vec![declension_table(
+                    &word.enunciated,
+                    word,
+                    &tables[0],
+                    &order,
+                )])
+            } else {
+                Ok(gendered(&tables))
+            }
+        }
+        Category::Verb => Ok(conjugation_tables(&word.enunciated, &get_verb_table(word)?)),
+        _ => Err(format!("'{}' cannot be inflected", word.enunciated)),
+    }
+}
+
+/// Returns all the inflection tables for the given `word` rendered in the
+/// given `format`, separated by a blank line.
+pub fn render_word(word: &Word, format: Format) -> Result<String, String> {
+    Ok(tables_for(word)?
+        .iter()
+        .map(|table| table.render(format))
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
@@ -0,0 +1,352 @@
+use crate::cfg::configuration;
+use crate::get_connection;
+use crate::word::{Category, Word};
+use crate::writer::next_success;
+use rusqlite::params;
+
+/// Ease given to words which have never been reviewed.
+pub const DEFAULT_EASE: f64 = 2.5;
+
+/// Lowest ease a word can get, so words which are often failed are still
+/// spaced out a bit on each success.
+pub const MIN_EASE: f64 = 1.3;
+
+// Intervals, in days, given to the first and the second successful reviews in
+// a row. From then on, the interval grows by the ease of the word.
+const FIRST_INTERVAL: isize = 1;
+const SECOND_INTERVAL: isize = 6;
+
+// Lowest SM-2 quality for which a review counts as successful.
+const PASSING_QUALITY: usize = 3;
+
+/// Number of Leitner boxes. Words start on the first one.
+pub const LEITNER_BOXES: isize = 5;
+
+// Days until words on each Leitner box are due again.
+const LEITNER_INTERVALS: [isize; LEITNER_BOXES as usize] = [1, 2, 4, 8, 16];
+
+/// How words are scheduled for review. This is stored in the configuration.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Scheduler {
+    /// Intervals grow by the ease of each word (see `Schedule`).
+    #[default]
+    Sm2,
+    /// Words move between boxes with fixed intervals (see `next_box`).
+    Leitner,
+    /// Words are never due: they are picked by their weight and their
+    /// successful runs, as it was done before spaced repetition.
+    Legacy,
+}
+
+impl TryFrom<&str> for Scheduler {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "sm2" => Ok(Self::Sm2),
+            "leitner" => Ok(Self::Leitner),
+            "legacy" => Ok(Self::Legacy),
+            _ => Err(format!(
+                "unknown scheduler '{value}'. Available: sm2, leitner, legacy"
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Scheduler {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Sm2 => write!(f, "sm2"),
+            Self::Leitner => write!(f, "leitner"),
+            Self::Legacy => write!(f, "legacy"),
+        }
+    }
+}
+
+/// Returns the Leitner box for a word which was on the `current` one after a
+/// review, where `correct` tells whether the user got it right. Right answers
+/// promote the word to the next box, up to `LEITNER_BOXES`, and wrong ones
+/// demote it all the way back to the first one.
+pub fn next_box(current: isize, correct: bool) -> isize {
+    if correct {
+        (current + 1).clamp(1, LEITNER_BOXES)
+    } else {
+        1
+    }
+}
+
+/// Returns the number of days until a word on the given Leitner box is due.
+pub fn box_interval_days(leitner_box: isize) -> isize {
+    LEITNER_INTERVALS[(leitner_box.clamp(1, LEITNER_BOXES) - 1) as usize]
+}
+
+/// The spaced-repetition state of a word, following the SM-2 algorithm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Schedule {
+    /// How fast the interval grows after each successful review.
+    pub ease: f64,
+    /// Number of days until the next review. It's zero for words which have
+    /// never been reviewed successfully.
+    pub interval_days: isize,
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Self {
+            ease: DEFAULT_EASE,
+            interval_days: 0,
+        }
+    }
+}
+
+impl Schedule {
+    /// Returns the schedule after a review, where `correct` tells whether the
+    /// user got it right. Answers are graded as in SM-2 with a quality of 5 for
+    /// correct answers and of 2 for wrong ones. A wrong answer starts the
+    /// intervals all over again, with the word being due the next day.
+    pub fn next(&self, correct: bool) -> Self {
+        self.graded(if correct { 5 } else { 2 })
+    }
+
+    /// Returns the schedule after a review graded with the given SM-2
+    /// `quality`, from 0 (no idea at all) up to 5 (perfect). Qualities below 3
+    /// start the intervals all over again, being due the next day.
+    pub fn graded(&self, quality: usize) -> Self {
+        let correct = quality >= PASSING_QUALITY;
+        let quality = quality.min(5) as f64;
+        let ease =
+            (self.ease + 0.1 - (5.0 - quality) * (0.08 + (5.0 - quality) * 0.02)).max(MIN_EASE);
+
+        let interval_days = if !correct || self.interval_days < FIRST_INTERVAL {
+            FIRST_INTERVAL
+        } else if self.interval_days < SECOND_INTERVAL {
+            SECOND_INTERVAL
+        } else {
+            (self.interval_days as f64 * self.ease).round() as isize
+        };
+
+        Self {
+            ease,
+            interval_days,
+        }
+    }
+}
+
+/// Records on the given connection the review of the word identified by
+/// `word_id`, where `correct` tells whether the user got it right. This
+/// updates its successful runs (see `writer::next_success`), its SM-2 schedule
+/// and its Leitner box, and the word becomes due again after the interval from
+/// the given `scheduler`. With `Scheduler::Legacy` its due date is left
+/// untouched. It also records when the word was last asked. Returns the new
+/// number of successful runs and steps.
+pub(crate) fn review_on(
+    conn: &rusqlite::Connection,
+    word_id: i32,
+    correct: bool,
+    scheduler: Scheduler,
+) -> Result<(isize, isize), String> {
+    let (succeeded, steps, schedule, leitner_box) = match conn.query_row(
+        "SELECT succeeded, steps, ease, interval_days, box FROM words WHERE id = ?1",
+        [word_id],
+        |row| {
+            Ok((
+                row.get::<usize, Option<isize>>(0)?.unwrap_or(0),
+                row.get::<usize, isize>(1)?,
+                Schedule {
+                    ease: row.get(2)?,
+                    interval_days: row.get(3)?,
+                },
+                row.get::<usize, isize>(4)?,
+            ))
+        },
+    ) {
+        Ok(values) => values,
+        Err(e) => return Err(format!("could not find the word to review: {e}")),
+    };
+
+    let (succeeded, steps) = next_success(succeeded, steps, correct);
+    let schedule = schedule.next(correct);
+    let leitner_box = next_box(leitner_box, correct);
+    let due_in = match scheduler {
+        Scheduler::Sm2 => Some(format!("+{} days", schedule.interval_days)),
+        Scheduler::Leitner => Some(format!("+{} days", box_interval_days(leitner_box))),
+        Scheduler::Legacy => None,
+    };
+
+    match conn.execute(
+        "UPDATE words \
+         SET succeeded = ?1, steps = ?2, ease = ?3, interval_days = ?4, box = ?5, \
+             due_at = COALESCE(datetime('now', ?6), due_at), last_asked_at = datetime('now'), \
+             updated_at = datetime('now') \
+         WHERE id = ?7",
+        params![
+            succeeded,
+            steps,
+            schedule.ease,
+            schedule.interval_days,
+            leitner_box,
+            due_in,
+            word_id
+        ],
+    ) {
+        Ok(_) => Ok((succeeded, steps)),
+        Err(e) => Err(format!("could not update the word: {e}")),
+    }
+}
+
+/// Records the review of the given `word`, where `correct` tells whether the
+/// user got it right, with the configured scheduler. See `review_on`.
+pub fn record_review(word: &Word, correct: bool) -> Result<(isize, isize), String> {
+    match review_on(
+        &*get_connection()?,
+        word.id,
+        correct,
+        configuration().scheduler,
+    ) {
+        Ok(res) => Ok(res),
+        Err(e) => Err(format!("could not update '{}': {e}", word.enunciated)),
+    }
+}
+
+// Condition for words which have already been reviewed, which are not
+// suspended, and which are due for another review by the end of today. Columns
+// are prefixed with "w.".
+const DUE_CONDITION: &str = "w.translation != '{}' AND w.suspended = 0 AND w.due_at IS NOT NULL \
+                             AND w.due_at < datetime('now', 'start of day', '+1 day')";
+
+/// Returns the number of words which have already been reviewed and which are
+/// due for another review by the end of today. Words are never due with
+/// `Scheduler::Legacy`.
+pub fn count_due_words() -> Result<usize, String> {
+    if configuration().scheduler == Scheduler::Legacy {
+        return Ok(0);
+    }
+    let conn = get_connection()?;
+
+    match conn.query_row(
+        format!("SELECT COUNT(*) FROM words w WHERE {DUE_CONDITION}").as_str(),
+        [],
+        |row| row.get::<usize, isize>(0),
+    ) {
+        Ok(count) => Ok(count as usize),
+        Err(e) => Err(format!("could not count the due words: {e}")),
+    }
+}
+
+/// The words which are due for review by the end of today, as counted by
+/// `count_due_words`.
+#[derive(Debug, Default)]
+pub struct DueWords {
+    pub total: usize,
+    /// Number of due words for each category, skipping the empty ones.
+    pub categories: Vec<(Category, usize)>,
+    /// Number of due words for each tag, skipping the empty ones. Words with
+    /// multiple tags are counted on each of them.
+    pub tags: Vec<(String, usize)>,
+}
+
+/// Returns the words which are due for review by the end of today, grouped
+/// by category and by tag.
+pub fn select_due_words() -> Result<DueWords, String> {
+    let mut res = DueWords {
+        total: count_due_words()?,
+        ..Default::default()
+    };
+    if res.total == 0 {
+        return Ok(res);
+    }
+    let conn = get_connection()?;
+
+    let mut stmt = conn
+        .prepare_cached(
+            format!(
+                "SELECT w.category, COUNT(*) FROM words w \
+                 WHERE {DUE_CONDITION} \
+                 GROUP BY w.category \
+                 ORDER BY w.category"
+            )
+            .as_str(),
+        )
+        .map_err(|e| format!("could not count the due words: {e}"))?;
+    let mut it = stmt.query([]).unwrap();
+    while let Some(row) = it.next().unwrap() {
+        res.categories.push((
+            row.get::<usize, isize>(0).unwrap().try_into()?,
+            row.get::<usize, isize>(1).unwrap() as usize,
+        ));
+    }
+
+    let mut stmt = conn
+        .prepare_cached(
+            format!(
+                "SELECT t.name, COUNT(*) FROM words w \
+                 JOIN tag_associations ta ON w.id = ta.word_id \
+                 JOIN tags t ON t.id = ta.tag_id \
+                 WHERE {DUE_CONDITION} \
+                 GROUP BY t.name \
+                 ORDER BY t.name"
+            )
+            .as_str(),
+        )
+        .map_err(|e| format!("could not count the due words: {e}"))?;
+    let mut it = stmt.query([]).unwrap();
+    while let Some(row) = it.next().unwrap() {
+        res.tags.push((
+            row.get(0).unwrap(),
+            row.get::<usize, isize>(1).unwrap() as usize,
+        ));
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule() {
+        let first = Schedule::default().next(true);
+        assert_eq!(first.interval_days, 1);
+        assert!((first.ease - 2.6).abs() < 1e-9);
+
+        let second = first.next(true);
+        assert_eq!(second.interval_days, 6);
+        let third = second.next(true);
+        assert_eq!(third.interval_days, 16);
+
+        // A wrong answer starts over and makes the word harder, but never
+        // below the minimum ease.
+        let failed = third.next(false);
+        assert_eq!(failed.interval_days, 1);
+        assert!(failed.ease < third.ease);
+        let mut schedule = failed;
+        for _ in 0..10 {
+            schedule = schedule.next(false);
+        }
+        assert_eq!(schedule.ease, MIN_EASE);
+
+        // Passing grades keep the intervals growing, but lower grades lower
+        // the ease so they grow slower from then on.
+        let good = third.graded(3);
+        assert_eq!(good.interval_days, 45);
+        assert!(good.ease < third.ease);
+        assert_eq!(third.graded(1).interval_days, 1);
+        assert_eq!(third.graded(5), third.next(true));
+    }
+
+    #[test]
+    fn test_leitner() {
+        assert_eq!(next_box(1, true), 2);
+        assert_eq!(next_box(LEITNER_BOXES, true), LEITNER_BOXES);
+        assert_eq!(next_box(4, false), 1);
+
+        assert_eq!(box_interval_days(1), 1);
+        assert_eq!(box_interval_days(3), 4);
+        assert_eq!(box_interval_days(LEITNER_BOXES), 16);
+        assert_eq!(box_interval_days(LEITNER_BOXES + 1), 16);
+
+        assert_eq!(Scheduler::try_from("leitner"), Ok(Scheduler::Leitner));
+        assert!(Scheduler::try_from("anki").is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use crate::inflection::{get_verb_table, inflect, Case, Number};
+use crate::word::Word;
+
+/// A noun in the given number, optionally qualified by an adjective which has
+/// to agree with it.
+#[derive(Clone, Debug)]
+pub struct NounPhrase {
+    pub noun: Word,
+    pub adjective: Option<Word>,
+    pub number: Number,
+}
+
+/// Enunciated forms of the pronouns which can replace a noun phrase on
+/// substitution drills.
+pub const SUBSTITUTE_PRONOUNS: [&str; 3] = ["is, ea, id", "hic, haec, hoc", "ille, illa, illud"];
+
+/// The forms of a pronoun which replace a noun phrase for a given case and
+/// number.
+#[derive(Clone, Debug)]
+pub struct Substitution {
+    pub case: Case,
+    pub number: Number,
+    pub forms: Vec<String>,
+}
+
+impl NounPhrase {
+    /// Returns this phrase declined on the given `case`, with the adjective
+    /// agreeing with the noun in gender, number and case.
+    pub fn decline(&self, case: Case) -> Result<String, String> {
+        let noun = first_form(
+            inflect(&self.noun, case, self.number, self.noun.gender)?,
+            &self.noun,
+        )?;
+
+        match &self.adjective {
+            Some(adjective) => {
+                let adjective = first_form(
+                    inflect(adjective, case, self.number, self.noun.gender)?,
+                    adjective,
+                )?;
+                Ok(format!("{noun} {adjective}"))
+            }
+            None => Ok(noun),
+        }
+    }
+
+    /// Returns the forms of the given `pronoun` which replace this phrase once
+    /// declined on the given `case`, agreeing with the noun in gender. As the
+    /// declined phrase might be ambiguous (e.g. 'puellae' can be a genitive or
+    /// a dative singular, or a nominative plural), a substitution is given for
+    /// every case and number which yields the same phrase. The vocative and the
+    /// locative are left out, as pronouns cannot replace them.
+    pub fn substitutions(&self, pronoun: &Word, case: Case) -> Result<Vec<Substitution>, String> {
+        let declined = self.decline(case)?;
+        let mut res = vec![];
+
+        for other_case in Case::ALL {
+            if matches!(other_case, Case::Vocative | Case::Locative) {
+                continue;
+            }
+            for number in Number::ALL {
+                let phrase = NounPhrase {
+                    number,
+                    ..self.clone()
+                };
+                if phrase.decline(other_case).ok().as_ref() != Some(&declined) {
+                    continue;
+                }
+
+                let forms = inflect(pronoun, other_case, number, self.noun.gender)?;
+                if !forms.is_empty() {
+                    res.push(Substitution {
+                        case: other_case,
+                        number,
+                        forms,
+                    });
+                }
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+/// A simple sentence with a subject, an object and a transitive verb. Note
+/// that words are not checked for meaning, so the result is grammatical but it
+/// might be silly (e.g. 'puella mūrum amat').
+#[derive(Clone, Debug)]
+pub struct Sentence {
+    pub subject: NounPhrase,
+    pub object: NounPhrase,
+    pub verb: Word,
+}
+
+impl Sentence {
+    /// Returns this sentence in Latin, following the usual subject-object-verb
+    /// order. The subject is given in the nominative, the object in the
+    /// accusative, and the verb in the third person of the present indicative,
+    /// agreeing in number with the subject.
+    pub fn compose(&self) -> Result<String, String> {
+        let subject = self.subject.decline(Case::Nominative)?;
+        let object = self.object.decline(Case::Accusative)?;
+
+        // Deponent verbs only have forms on the passive voice.
+        let table = get_verb_table(&self.verb)?;
+        let verb = table
+            .indicative
+            .iter()
+            .map(|voice| &voice.present.forms[self.subject.number as usize][2].inflected)
+            .find(|forms| !forms.is_empty())
+            .and_then(|forms| forms.first())
+            .ok_or(format!(
+                "'{}' has no forms for the present indicative",
+                self.verb.enunciated
+            ))?;
+
+        Ok(format!("{subject} {object} {verb}"))
+    }
+
+    /// Returns the words used on this sentence, in the order in which they
+    /// appear.
+    pub fn words(&self) -> Vec<&Word> {
+        let mut words = vec![&self.subject.noun];
+        words.extend(self.subject.adjective.as_ref());
+        words.push(&self.object.noun);
+        words.extend(self.object.adjective.as_ref());
+        words.push(&self.verb);
+        words
+    }
+}
+
+// Returns the first of the given `forms` for `word`, or an error if there are
+// none (e.g. the plural of a word which only has singular forms).
+fn first_form(forms: Vec<String>, word: &Word) -> Result<String, String> {
+    forms.into_iter().next().ok_or(format!(
+        "'{}' is missing the requested form",
+        word.enunciated
+    ))
+}
@@ -0,0 +1,394 @@
+use crate::cfg::Configuration;
+use crate::exercise::Exercise;
+use crate::word::Word;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// An item to be reviewed on a practice session.
+#[derive(Clone, Debug)]
+pub enum SessionItem {
+    Word(Word),
+    Exercise(Exercise),
+}
+
+/// Builder for a practice session which interleaves word reviews with
+/// exercises.
+#[derive(Debug, Default)]
+pub struct SessionBuilder {
+    words: Vec<Word>,
+    exercises: Vec<Exercise>,
+    max_exercises: Option<usize>,
+}
+
+impl SessionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the words to be reviewed, in the order in which they should be
+    /// asked.
+    pub fn words(mut self, words: Vec<Word>) -> Self {
+        self.words = words;
+        self
+    }
+
+    /// Set the exercises to be reviewed, in the order in which they should be
+    /// asked.
+    pub fn exercises(mut self, exercises: Vec<Exercise>) -> Self {
+        self.exercises = exercises;
+        self
+    }
+
+    /// Set the maximum number of exercises to be included in the session. By
+    /// default all the given exercises are included.
+    pub fn max_exercises(mut self, max: usize) -> Self {
+        self.max_exercises = Some(max);
+        self
+    }
+
+    /// Build the session by spreading the exercises evenly between the words,
+    /// so that both queues are drained at the same pace.
+    pub fn build(self) -> Session {
+        let mut exercises = self.exercises;
+        if let Some(max) = self.max_exercises {
+            exercises.truncate(max);
+        }
+
+        // Each exercise goes after a block of `every` words. The first
+        // exercise is never asked before a word, unless there are no words at
+        // all.
+        let every = self.words.len() / (exercises.len() + 1);
+        let mut extra = self.words.len() % (exercises.len() + 1);
+
+        let mut items = VecDeque::with_capacity(self.words.len() + exercises.len());
+        let mut words = self.words.into_iter();
+        for exercise in exercises {
+            let block = if extra > 0 {
+                extra -= 1;
+                every + 1
+            } else {
+                every
+            };
+            items.extend(words.by_ref().take(block).map(SessionItem::Word));
+            items.push_back(SessionItem::Exercise(exercise));
+        }
+        items.extend(words.map(SessionItem::Word));
+
+        Session { items }
+    }
+}
+
+/// A practice session as built by a `SessionBuilder`. Iterate over it to get
+/// the items to be reviewed.
+#[derive(Debug)]
+pub struct Session {
+    items: VecDeque<SessionItem>,
+}
+
+impl Session {
+    /// Returns the number of items left on this session.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if there are no items left on this session.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl Iterator for Session {
+    type Item = SessionItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.pop_front()
+    }
+}
+
+/// Keeps track of how long a practice session has been going on, so marathon
+/// sessions can be interrupted before fatigued answers start to skew the
+/// success rate of words and exercises.
+#[derive(Debug)]
+pub struct SessionLimits {
+    max_items: Option<usize>,
+    max_time: Option<Duration>,
+    items: usize,
+    started: Instant,
+}
+
+impl SessionLimits {
+    /// Returns the limits as defined in the given `cfg`.
+    pub fn from(cfg: &Configuration) -> Self {
+        Self {
+            max_items: cfg.max_session_items,
+            max_time: cfg.max_session_minutes.map(|m| Duration::from_secs(m * 60)),
+            items: 0,
+            started: Instant::now(),
+        }
+    }
+
+    /// Record that one more item has been reviewed.
+    pub fn record(&mut self) {
+        self.items += 1;
+    }
+
+    /// Returns true if either the number of reviewed items or the time spent
+    /// on this session are beyond the configured limits.
+    pub fn exceeded(&self) -> bool {
+        self.max_items.is_some_and(|max| self.items >= max)
+            || self
+                .max_time
+                .is_some_and(|max| self.started.elapsed() >= max)
+    }
+
+    /// Returns the number of items reviewed so far.
+    pub fn items(&self) -> usize {
+        self.items
+    }
+
+    /// Returns the number of whole minutes elapsed since the session started.
+    pub fn minutes(&self) -> u64 {
+        self.started.elapsed().as_secs() / 60
+    }
+
+    /// Start counting again from zero, as if a new session had started.
+    pub fn reset(&mut self) {
+        self.items = 0;
+        self.started = Instant::now();
+    }
+}
+
+/// An answer given on a practice session.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionAnswer {
+    pub word_id: i32,
+    pub given: String,
+    pub correct: bool,
+    /// Time it took to answer since the question was asked.
+    pub response: Duration,
+    /// Whether the answer was given after the time limit of a timed session,
+    /// in which case it's not correct regardless of what was given.
+    pub timed_out: bool,
+}
+
+/// Tally of the answers given on a practice session, so the user can get some
+/// feedback once it's over.
+#[derive(Debug)]
+pub struct SessionSummary {
+    answers: Vec<SessionAnswer>,
+    missed: Vec<String>,
+    started: Instant,
+    // When the current question was asked.
+    asked: Instant,
+    time_limit: Option<Duration>,
+}
+
+impl Default for SessionSummary {
+    fn default() -> Self {
+        Self {
+            answers: vec![],
+            missed: vec![],
+            started: Instant::now(),
+            asked: Instant::now(),
+            time_limit: None,
+        }
+    }
+}
+
+impl SessionSummary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the summary of a timed session, where each question has to be
+    /// answered within the given `limit`.
+    pub fn timed(limit: Duration) -> Self {
+        Self {
+            time_limit: Some(limit),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the time given for each question, if this is a timed session.
+    pub fn time_limit(&self) -> Option<Duration> {
+        self.time_limit
+    }
+
+    /// Mark that a question is being asked right now, so the time it takes to
+    /// answer it is measured from this point.
+    pub fn ask(&mut self) {
+        self.asked = Instant::now();
+    }
+
+    /// Record the `given` answer about the given `word`, where `correct` tells
+    /// whether the user got it right. On timed sessions, answers which took
+    /// longer than the time limit since the last call to `ask` are recorded as
+    /// wrong. Returns the recorded answer.
+    pub fn record(&mut self, word: &Word, given: &str, correct: bool) -> &SessionAnswer {
+        let response = self.asked.elapsed();
+        let timed_out = self.time_limit.is_some_and(|limit| response > limit);
+        let correct = correct && !timed_out;
+
+        if !correct && !self.missed.contains(&word.enunciated) {
+            self.missed.push(word.enunciated.clone());
+        }
+        self.answers.push(SessionAnswer {
+            word_id: word.id,
+            given: given.to_string(),
+            correct,
+            response,
+            timed_out,
+        });
+        &self.answers[self.answers.len() - 1]
+    }
+
+    /// Returns the answers given so far, in the order in which they were given.
+    pub fn answers(&self) -> &[SessionAnswer] {
+        &self.answers
+    }
+
+    /// Returns the number of questions answered so far.
+    pub fn questions(&self) -> usize {
+        self.answers.len()
+    }
+
+    /// Returns the number of questions answered correctly.
+    pub fn correct(&self) -> usize {
+        self.answers.iter().filter(|answer| answer.correct).count()
+    }
+
+    /// Returns the number of questions answered incorrectly.
+    pub fn incorrect(&self) -> usize {
+        self.questions() - self.correct()
+    }
+
+    /// Returns the number of questions which were answered after the time
+    /// limit.
+    pub fn timeouts(&self) -> usize {
+        self.answers
+            .iter()
+            .filter(|answer| answer.timed_out)
+            .count()
+    }
+
+    /// Returns the average time it took to answer a question, or None if no
+    /// question has been answered yet.
+    pub fn average_response(&self) -> Option<Duration> {
+        if self.answers.is_empty() {
+            return None;
+        }
+        let total: Duration = self.answers.iter().map(|answer| answer.response).sum();
+        Some(total / self.answers.len() as u32)
+    }
+
+    /// Returns the share of questions answered correctly, as a percentage.
+    pub fn accuracy(&self) -> f64 {
+        if self.questions() == 0 {
+            0.0
+        } else {
+            self.correct() as f64 * 100.0 / self.questions() as f64
+        }
+    }
+
+    /// Returns the time elapsed since the session started.
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Returns the enunciates of the words which were answered incorrectly at
+    /// least once, in the order in which they were first missed.
+    pub fn missed(&self) -> &[String] {
+        &self.missed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word::{Category, Gender};
+
+    fn word(enunciated: &str) -> Word {
+        let mut word = Word::from(
+            String::new(),
+            Category::Noun,
+            None,
+            None,
+            Gender::None,
+            String::new(),
+        );
+        word.enunciated = enunciated.to_string();
+        word
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut summary = SessionSummary::new();
+        assert_eq!(summary.questions(), 0);
+        assert_eq!(summary.accuracy(), 0.0);
+        assert_eq!(summary.average_response(), None);
+
+        summary.record(&word("rosa, rosae"), "rose", true);
+        summary.record(&word("puer, puerī"), "girl", false);
+        summary.record(&word("puer, puerī"), "man", false);
+        summary.record(&word("bellum, bellī"), "war", true);
+
+        assert_eq!(summary.questions(), 4);
+        assert_eq!(summary.correct(), 2);
+        assert_eq!(summary.incorrect(), 2);
+        assert_eq!(summary.accuracy(), 50.0);
+        assert_eq!(summary.missed(), &["puer, puerī".to_string()]);
+        assert_eq!(summary.answers()[2].given, "man");
+        assert_eq!(summary.timeouts(), 0);
+        assert!(summary.average_response().is_some());
+
+        // Right answers given after the time limit are wrong.
+        let mut summary = SessionSummary::timed(Duration::ZERO);
+        summary.ask();
+        std::thread::sleep(Duration::from_millis(1));
+        let answer = summary.record(&word("rosa, rosae"), "rose", true);
+        assert!(answer.timed_out);
+        assert!(!answer.correct);
+        assert_eq!(summary.timeouts(), 1);
+        assert_eq!(summary.missed(), &["rosa, rosae".to_string()]);
+    }
+
+    fn limits(max_items: Option<usize>, max_minutes: Option<u64>) -> SessionLimits {
+        SessionLimits {
+            max_items,
+            max_time: max_minutes.map(|m| Duration::from_secs(m * 60)),
+            items: 0,
+            started: Instant::now(),
+        }
+    }
+
+    #[test]
+    fn test_limits() {
+        // The break is offered right when the item limit is reached.
+        let mut by_items = limits(Some(3), None);
+        by_items.record();
+        by_items.record();
+        assert!(!by_items.exceeded());
+        by_items.record();
+        assert!(by_items.exceeded());
+        assert_eq!(by_items.items(), 3);
+        by_items.reset();
+        assert!(!by_items.exceeded());
+        assert_eq!(by_items.items(), 0);
+
+        let mut by_time = limits(None, Some(1));
+        by_time.record();
+        assert!(!by_time.exceeded());
+        by_time.started = Instant::now() - Duration::from_secs(60);
+        assert!(by_time.exceeded());
+        assert_eq!(by_time.minutes(), 1);
+        by_time.reset();
+        assert!(!by_time.exceeded());
+
+        // Without limits, sessions can go on forever.
+        let mut unlimited = limits(None, None);
+        for _ in 0..1000 {
+            unlimited.record();
+        }
+        assert!(!unlimited.exceeded());
+    }
+}
@@ -0,0 +1,200 @@
+use crate::schedule::Schedule;
+use crate::word::{sampling_key, Word};
+use crate::writer::next_success;
+
+/// The parameters for simulating practice sessions against the scheduler.
+#[derive(Clone, Debug)]
+pub struct SimulationParams {
+    /// Number of days to be simulated, with one session per day.
+    pub days: usize,
+    /// Probability for each review to be answered correctly.
+    pub accuracy: f64,
+    /// Number of words to be reviewed on each session.
+    pub reviews_per_day: usize,
+    /// Seed for the outcome of the reviews, so runs can be compared.
+    pub seed: u64,
+}
+
+/// The state of the simulation at the end of a day.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DayStats {
+    pub day: usize,
+    /// Number of reviews done that day.
+    pub reviews: usize,
+    /// Number of reviews which were answered correctly that day.
+    pub correct: usize,
+    /// Number of distinct words reviewed so far.
+    pub seen: usize,
+    /// Number of words with at least one successful run.
+    pub learned: usize,
+    /// Number of words which still have no successful runs, reviewed or not.
+    /// That is, the workload which remains ahead.
+    pub pending: usize,
+}
+
+impl DayStats {
+    /// Returns the share of the words seen so far which have been learned, as a
+    /// percentage.
+    pub fn retention(&self) -> f64 {
+        if self.seen == 0 {
+            0.0
+        } else {
+            self.learned as f64 * 100.0 / self.seen as f64
+        }
+    }
+}
+
+// The values of a word which are relevant to the scheduler.
+#[derive(Clone, Debug)]
+struct Card {
+    weight: isize,
+    succeeded: isize,
+    steps: isize,
+    // Time of the last review, counted in reviews since the simulation
+    // started. Words which have never been reviewed in the simulation keep
+    // their original order.
+    updated_at: usize,
+    seen: bool,
+    schedule: Schedule,
+    // Day on which the word is due again, if it has been reviewed.
+    due: Option<usize>,
+}
+
+// A xorshift generator, which is more than enough for deciding whether a
+// simulated review was correct.
+struct Rng(u64);
+
+impl Rng {
+    // Returns a value between 0 and 1.
+    fn next(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Simulates practice sessions on the given `words` with the given `params`,
+/// and returns the stats at the end of each day. Words are picked the same way
+/// as when practicing (i.e. due words first, then new ones, and sampled at
+/// random by weight and by fewer successful runs within each group), and each
+/// review updates them as in `schedule::record_review`. The database is never
+/// touched.
+pub fn simulate(words: &[Word], params: &SimulationParams) -> Vec<DayStats> {
+    let mut cards: Vec<Card> = words
+        .iter()
+        .map(|word| Card {
+            weight: word.weight,
+            succeeded: word.succeeded,
+            steps: word.steps,
+            updated_at: 0,
+            seen: false,
+            schedule: Schedule::default(),
+            due: None,
+        })
+        .collect();
+    let mut rng = Rng(params.seed.max(1));
+    let mut clock = 0;
+    let mut res = vec![];
+
+    for day in 1..=params.days {
+        let keys: Vec<f64> = cards
+            .iter()
+            .map(|card| sampling_key(card.weight, card.succeeded, rng.next()))
+            .collect();
+        let mut order: Vec<usize> = (0..cards.len()).collect();
+        order.sort_by(|&a, &b| {
+            // Same as in 'word::priority_clause': due words first, then new
+            // ones, and then the ones which are not due yet.
+            let rank = |card: &Card| match card.due {
+                Some(due) if due <= day => 0,
+                None => 1,
+                Some(_) => 2,
+            };
+            rank(&cards[a])
+                .cmp(&rank(&cards[b]))
+                .then(keys[b].total_cmp(&keys[a]))
+                .then(cards[a].succeeded.cmp(&cards[b].succeeded))
+                .then(cards[b].updated_at.cmp(&cards[a].updated_at))
+        });
+
+        let mut stats = DayStats {
+            day,
+            ..Default::default()
+        };
+        for idx in order.into_iter().take(params.reviews_per_day) {
+            let correct = rng.next() < params.accuracy;
+            let card = &mut cards[idx];
+
+            (card.succeeded, card.steps) = next_success(card.succeeded, card.steps, correct);
+            card.schedule = card.schedule.next(correct);
+            card.due = Some(day + card.schedule.interval_days as usize);
+            clock += 1;
+            card.updated_at = clock;
+            card.seen = true;
+
+            stats.reviews += 1;
+            if correct {
+                stats.correct += 1;
+            }
+        }
+
+        stats.seen = cards.iter().filter(|card| card.seen).count();
+        stats.learned = cards
+            .iter()
+            .filter(|card| card.seen && card.succeeded > 0)
+            .count();
+        stats.pending = cards.iter().filter(|card| card.succeeded == 0).count();
+        res.push(stats);
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::word::{Category, Gender};
+    use crate::writer::MAX_STEPS;
+
+    #[test]
+    fn test_simulate() {
+        let words: Vec<Word> = (0..10)
+            .map(|_| {
+                Word::from(
+                    String::new(),
+                    Category::Noun,
+                    None,
+                    None,
+                    Gender::None,
+                    String::new(),
+                )
+            })
+            .collect();
+        let mut params = SimulationParams {
+            days: MAX_STEPS * 2,
+            accuracy: 1.0,
+            reviews_per_day: 10,
+            seed: 42,
+        };
+
+        // Reviewing everything every day with no mistakes learns all words
+        // after MAX_STEPS days.
+        let days = simulate(&words, &params);
+        assert_eq!(days.len(), MAX_STEPS * 2);
+        assert_eq!(days[MAX_STEPS - 2].learned, 0);
+        assert_eq!(days[MAX_STEPS - 1].learned, 10);
+        assert_eq!(days[MAX_STEPS - 1].pending, 0);
+        assert_eq!(days[MAX_STEPS - 1].retention(), 100.0);
+
+        // Nothing is ever learned without a correct review.
+        params.accuracy = 0.0;
+        let days = simulate(&words, &params);
+        assert!(days.iter().all(|day| day.correct == 0 && day.learned == 0));
+
+        // The same seed yields the same outcome.
+        params.accuracy = 0.5;
+        params.reviews_per_day = 3;
+        assert_eq!(simulate(&words, &params), simulate(&words, &params));
+    }
+}
@@ -0,0 +1,72 @@
+use crate::archive::{export_changes, import_database, ConflictPolicy, ImportSummary};
+use crate::get_connection;
+use rusqlite::params;
+use serde_json::Value;
+
+/// The changes to be written into a change file, as returned by
+/// `export_since_last_sync`.
+#[derive(Clone, Debug)]
+pub struct ChangeSet {
+    /// The archive with the changes (see `archive::export_changes`).
+    pub archive: Value,
+    /// The time of the previous export, or None if everything was exported.
+    pub since: Option<String>,
+    /// The time from which the next export has to pick up changes.
+    pub at: String,
+}
+
+/// Returns the time of the last export recorded with `record_export`, if any.
+pub fn last_export() -> Result<Option<String>, String> {
+    let conn = get_connection()?;
+
+    conn.query_row("SELECT MAX(synced_at) FROM sync_points", [], |row| {
+        row.get::<usize, Option<String>>(0)
+    })
+    .map_err(|e| format!("could not read the last sync: {e}"))
+}
+
+/// Returns the tags, lessons, words and exercises which changed since the last
+/// export recorded with `record_export`, or everything if there was none or if
+/// `all` is given.
+pub fn export_since_last_sync(all: bool) -> Result<ChangeSet, String> {
+    let conn = get_connection()?;
+
+    // Timestamps only have a precision of seconds, so rows updated right after
+    // this on the same second would be missed by the next export. Going back a
+    // second exports them twice at worst, which merging tolerates.
+    let at = match conn.query_row("SELECT datetime('now', '-1 seconds')", [], |row| {
+        row.get::<usize, String>(0)
+    }) {
+        Ok(at) => at,
+        Err(e) => return Err(format!("could not export the changes: {e}")),
+    };
+    let since = if all { None } else { last_export()? };
+
+    Ok(ChangeSet {
+        archive: export_changes(since.as_deref())?,
+        since,
+        at,
+    })
+}
+
+/// Records that the given `changes` were written into the given `file`, so the
+/// next call to `export_since_last_sync` only picks up what changed after them.
+pub fn record_export(changes: &ChangeSet, file: &str) -> Result<(), String> {
+    let conn = get_connection()?;
+
+    match conn.execute(
+        "INSERT INTO sync_points (file, synced_at) VALUES (?1, ?2)",
+        params![file, changes.at],
+    ) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not record the sync: {e}")),
+    }
+}
+
+/// Imports a change file written from another database by merging it into
+/// this one: for tags, lessons, words and exercises on both sides, the one
+/// which was updated last is kept, and so are removals made after the removed
+/// item last changed.
+pub fn import_changes(archive: &Value) -> Result<ImportSummary, String> {
+    import_database(archive, ConflictPolicy::Merge)
+}
@@ -1,12 +1,17 @@
-use crate::word::Word;
+use crate::word::{Category, Word};
 use rusqlite::params;
+use std::collections::HashMap;
 
 /// A tag which can be associated with multiple words. It is mapped in the
-/// database via the 'tags' and 'tag_associations' tables.
+/// database via the 'tags' and 'tag_associations' tables. Tags are
+/// hierarchical: a tag named like 'llpsi/cap09' is a child of 'llpsi', and
+/// filtering by a tag also includes the words from all of its descendants.
 #[derive(Clone, Debug)]
 pub struct Tag {
     pub id: i32,
     pub name: String,
+    pub description: Option<String>,
+    pub color: Option<TagColor>,
 }
 
 // Needed for inquire's (Multi)Select.
@@ -16,6 +21,123 @@ impl std::fmt::Display for Tag {
     }
 }
 
+/// The colors with which tags can be rendered, so different kinds of tags
+/// (e.g. chapters and topics) are told apart at a glance.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TagColor {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+/// Names of all the available tag colors.
+pub const TAG_COLORS: [&str; 6] = ["red", "green", "yellow", "blue", "magenta", "cyan"];
+
+impl TryFrom<&str> for TagColor {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().to_lowercase().as_str() {
+            "red" => Ok(Self::Red),
+            "green" => Ok(Self::Green),
+            "yellow" => Ok(Self::Yellow),
+            "blue" => Ok(Self::Blue),
+            "magenta" => Ok(Self::Magenta),
+            "cyan" => Ok(Self::Cyan),
+            _ => Err(format!(
+                "unknown color '{value}'. Available: {}",
+                TAG_COLORS.join(", ")
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for TagColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", TAG_COLORS[*self as usize])
+    }
+}
+
+impl TagColor {
+    /// Returns the ANSI escape sequence which renders text in this color.
+    pub fn ansi(&self) -> &'static str {
+        match self {
+            Self::Red => "\x1b[91m",
+            Self::Green => "\x1b[92m",
+            Self::Yellow => "\x1b[93m",
+            Self::Blue => "\x1b[94m",
+            Self::Magenta => "\x1b[95m",
+            Self::Cyan => "\x1b[96m",
+        }
+    }
+}
+
+/// Returns a clause which checks whether the word on the given `column` (e.g.
+/// "w.id") is tagged with any of the given `tags` or with any of their
+/// descendants. The clause is not prepended by "AND", and `tags` cannot be
+/// empty. Tag names are not written into the clause: they have to be bound as
+/// parameters starting from the index given by `first` (e.g. 2 for "?2").
+pub(crate) fn tagged_clause(column: &str, tags: &[String], first: usize) -> String {
+    assert!(!tags.is_empty());
+
+    format!(
+        "{column} IN ( \
+             SELECT ta.word_id FROM tag_associations ta \
+             WHERE ta.tag_id IN ({}))",
+        tree_query(tags, first)
+    )
+}
+
+/// Same as `tagged_clause` but for the exercise on the given `column` (e.g.
+/// "e.id").
+pub(crate) fn exercise_tagged_clause(column: &str, tags: &[String], first: usize) -> String {
+    assert!(!tags.is_empty());
+
+    format!(
+        "{column} IN ( \
+             SELECT eta.exercise_id FROM exercise_tag_associations eta \
+             WHERE eta.tag_id IN ({}))",
+        tree_query(tags, first)
+    )
+}
+
+/// Returns `count` numbered placeholders separated by commas, starting from
+/// the index given by `first` (e.g. "?2, ?3, ?4").
+pub(crate) fn placeholders(first: usize, count: usize) -> String {
+    (first..first + count)
+        .map(|idx| format!("?{idx}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Returns a query which selects the IDs of the given `tags` and of all of
+// their descendants, where the names of the tags are bound from the `first`
+// parameter onwards.
+fn tree_query(tags: &[String], first: usize) -> String {
+    format!(
+        "WITH RECURSIVE tree(id) AS ( \
+             SELECT id FROM tags WHERE name IN ({}) \
+             UNION SELECT c.id FROM tags c JOIN tree ON c.parent_id = tree.id) \
+         SELECT id FROM tree",
+        placeholders(first, tags.len())
+    )
+}
+
+// Returns the ID of the parent for a tag with the given `name`, creating the
+// parent first if needed (e.g. 'llpsi' for 'llpsi/cap09'), or None if the name
+// does not have a parent.
+fn find_or_create_parent(conn: &rusqlite::Connection, name: &str) -> Result<Option<i64>, String> {
+    match name.rsplit_once('/') {
+        Some((parent, _)) if !parent.trim().is_empty() => {
+            find_or_create_tag_on(conn, parent).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
 /// Returns a vector with the names for tags that match the given `filter`, or
 /// all of them if None is passed as the filter.
 pub fn select_tag_names(filter: &Option<String>) -> Result<Vec<String>, String> {
@@ -25,12 +147,16 @@ pub fn select_tag_names(filter: &Option<String>) -> Result<Vec<String>, String>
     let mut it = match filter {
         Some(filter) => {
             stmt = conn
-                .prepare("SELECT name FROM tags WHERE name LIKE ('%' || ?1 || '%') ORDER BY name")
+                .prepare_cached(
+                    "SELECT name FROM tags WHERE name LIKE ('%' || ?1 || '%') ORDER BY name",
+                )
                 .unwrap();
             stmt.query([filter.as_str()]).unwrap()
         }
         None => {
-            stmt = conn.prepare("SELECT name FROM tags ORDER BY name").unwrap();
+            stmt = conn
+                .prepare_cached("SELECT name FROM tags ORDER BY name")
+                .unwrap();
             stmt.query([]).unwrap()
         }
     };
@@ -51,8 +177,8 @@ pub fn select_tags_for(word: Option<i32>) -> Result<Vec<Tag>, String> {
     let mut it = match word {
         Some(id) => {
             stmt = conn
-                .prepare(
-                    "SELECT t.id, t.name \
+                .prepare_cached(
+                    "SELECT t.id, t.name, t.description, t.color \
                      FROM tags t \
                      JOIN tag_associations ta ON t.id = ta.tag_id \
                      JOIN words w ON w.id = ta.word_id \
@@ -64,7 +190,7 @@ pub fn select_tags_for(word: Option<i32>) -> Result<Vec<Tag>, String> {
         }
         None => {
             stmt = conn
-                .prepare("SELECT id, name FROM tags ORDER BY name")
+                .prepare_cached("SELECT id, name, description, color FROM tags ORDER BY name")
                 .unwrap();
             stmt.query([]).unwrap()
         }
@@ -72,32 +198,119 @@ pub fn select_tags_for(word: Option<i32>) -> Result<Vec<Tag>, String> {
 
     let mut res = vec![];
     while let Some(row) = it.next().unwrap() {
-        res.push(Tag {
-            id: row.get::<usize, i32>(0).unwrap(),
-            name: row.get::<usize, String>(1).unwrap(),
-        });
+        res.push(tag_from_row(row));
+    }
+    Ok(res)
+}
+
+// Returns the tag from the given `row`, which has its ID, name, description
+// and color in this order.
+fn tag_from_row(row: &rusqlite::Row) -> Tag {
+    Tag {
+        id: row.get::<usize, i32>(0).unwrap(),
+        name: row.get::<usize, String>(1).unwrap(),
+        description: row.get::<usize, Option<String>>(2).unwrap(),
+        color: row
+            .get::<usize, Option<String>>(3)
+            .unwrap()
+            .and_then(|color| TagColor::try_from(color.as_str()).ok()),
+    }
+}
+
+/// Returns the tags of every word which has any, indexed by the enunciate of
+/// the word.
+pub fn select_tags_by_enunciated() -> Result<HashMap<String, Vec<Tag>>, String> {
+    let conn = crate::get_connection()?;
+
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT t.id, t.name, t.description, t.color, w.enunciated \
+             FROM tags t \
+             JOIN tag_associations ta ON t.id = ta.tag_id \
+             JOIN words w ON w.id = ta.word_id \
+             ORDER BY t.name",
+        )
+        .unwrap();
+    let mut it = stmt.query([]).unwrap();
+
+    let mut res: HashMap<String, Vec<Tag>> = HashMap::new();
+    while let Some(row) = it.next().unwrap() {
+        res.entry(row.get::<usize, String>(4).unwrap())
+            .or_default()
+            .push(tag_from_row(row));
     }
     Ok(res)
 }
 
-/// Insert into the database the tag identified by the given name.
-pub fn create_tag(name: &str) -> Result<(), String> {
+/// Insert into the database the tag identified by the given name, with an
+/// optional `description` and `color`. Parent tags are created as well if they
+/// did not exist (e.g. 'llpsi' for 'llpsi/cap09').
+pub fn create_tag(
+    name: &str,
+    description: Option<&str>,
+    color: Option<TagColor>,
+) -> Result<(), String> {
     let conn = crate::get_connection()?;
+    let parent = find_or_create_parent(&conn, name.trim())?;
 
     match conn.execute(
-        "INSERT INTO tags (name, updated_at, created_at) \
-         VALUES (?1, datetime('now'), datetime('now'))",
-        params![name.trim()],
+        "INSERT INTO tags (name, parent_id, description, color, updated_at, created_at) \
+         VALUES (?1, ?2, ?3, ?4, datetime('now'), datetime('now'))",
+        params![
+            name.trim(),
+            parent,
+            description.map(str::trim).filter(|d| !d.is_empty()),
+            color.map(|c| c.to_string())
+        ],
     ) {
         Ok(_) => Ok(()),
         Err(e) => Err(format!("could not create '{}': {}", name, e)),
     }
 }
 
+/// Returns the ID of the tag identified by the given name, creating it first if
+/// it did not exist already (alongside its parents, as in `create_tag`).
+pub fn find_or_create_tag(name: &str) -> Result<i64, String> {
+    find_or_create_tag_on(&*crate::get_connection()?, name)
+}
+
+/// Same as `find_or_create_tag`, but on the given connection (e.g. the
+/// transaction of a `writer::Writer`).
+pub(crate) fn find_or_create_tag_on(
+    conn: &rusqlite::Connection,
+    name: &str,
+) -> Result<i64, String> {
+    let name = name.trim();
+
+    if let Ok(id) = conn.query_row("SELECT id FROM tags WHERE name = ?1", [name], |row| {
+        row.get::<usize, i64>(0)
+    }) {
+        return Ok(id);
+    }
+
+    let parent = find_or_create_parent(conn, name)?;
+    match conn.execute(
+        "INSERT INTO tags (name, parent_id, updated_at, created_at) \
+         VALUES (?1, ?2, datetime('now'), datetime('now'))",
+        params![name, parent],
+    ) {
+        Ok(_) => Ok(conn.last_insert_rowid()),
+        Err(e) => Err(format!("could not create '{}': {}", name, e)),
+    }
+}
+
 /// Inserts the pair of IDs into the tag_associations table.
 pub fn attach_tag_to_word(tag_id: i64, word_id: i64) -> Result<(), String> {
-    let conn = crate::get_connection()?;
+    attach_tag_to_word_on(&*crate::get_connection()?, tag_id, word_id)
+}
 
+/// Same as `attach_tag_to_word`, but on the given connection (e.g. the
+/// transaction of a `writer::Writer`).
+pub(crate) fn attach_tag_to_word_on(
+    conn: &rusqlite::Connection,
+    tag_id: i64,
+    word_id: i64,
+) -> Result<(), String> {
     match conn.execute(
         "INSERT INTO tag_associations (tag_id, word_id, updated_at, created_at) \
          VALUES (?1, ?2, datetime('now'), datetime('now'))",
@@ -119,25 +332,172 @@ pub fn dettach_tags_from_word(tags: &[i32], word_id: i64) -> Result<(), String>
     match conn.execute(
         format!(
             "DELETE FROM tag_associations \
-             WHERE tag_id in ({}) AND word_id = ?1",
-            tags.iter()
-                .map(|t| format!("{}", t))
-                .collect::<Vec<_>>()
-                .join(", ")
+             WHERE word_id = ?1 AND tag_id IN ({})",
+            placeholders(2, tags.len())
         )
         .as_str(),
-        params![word_id],
+        rusqlite::params_from_iter(std::iter::once(word_id).chain(tags.iter().map(|&t| t as i64))),
+    ) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not attach tag: {e}")),
+    }
+}
+
+/// Select all tags for the exercise identified by `exercise_id`.
+pub fn select_tags_for_exercise(exercise_id: i32) -> Result<Vec<Tag>, String> {
+    let conn = crate::get_connection()?;
+
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT t.id, t.name, t.description, t.color \
+             FROM tags t \
+             JOIN exercise_tag_associations eta ON t.id = eta.tag_id \
+             WHERE eta.exercise_id = ?1 \
+             ORDER BY t.name",
+        )
+        .unwrap();
+    let mut it = stmt.query([exercise_id]).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(tag_from_row(row));
+    }
+    Ok(res)
+}
+
+/// Inserts the pair of IDs into the exercise_tag_associations table.
+pub fn attach_tag_to_exercise(tag_id: i64, exercise_id: i64) -> Result<(), String> {
+    let conn = crate::get_connection()?;
+
+    match conn.execute(
+        "INSERT INTO exercise_tag_associations (tag_id, exercise_id, updated_at, created_at) \
+         VALUES (?1, ?2, datetime('now'), datetime('now'))",
+        params![tag_id, exercise_id],
     ) {
         Ok(_) => Ok(()),
         Err(e) => Err(format!("could not attach tag: {e}")),
     }
 }
 
-/// Delete the tag from the database.
+/// Attaches the tag named `tag` to every word which matches the given `filter`
+/// on its enunciate and which belongs to the given `category`, if any. All of
+/// them are tagged on a single transaction. Returns the enunciates of the
+/// words which were tagged, leaving out the ones which already had the tag. If
+/// `dry_run` is set, nothing is written and the words which would have been
+/// tagged are returned instead.
+pub fn attach_tag_to_matching_words(
+    tag: &str,
+    filter: Option<&str>,
+    category: Option<Category>,
+    dry_run: bool,
+) -> Result<Vec<String>, String> {
+    let conn = crate::get_connection()?;
+    let tag_id = match conn.query_row("SELECT id FROM tags WHERE name = ?1", [tag.trim()], |row| {
+        row.get::<usize, i64>(0)
+    }) {
+        Ok(id) => id,
+        Err(_) => return Err(format!("the tag '{}' does not exist", tag.trim())),
+    };
+    let tx = match conn.unchecked_transaction() {
+        Ok(tx) => tx,
+        Err(e) => return Err(format!("could not attach tag: {e}")),
+    };
+
+    let mut words = vec![];
+    {
+        let mut stmt = tx
+            .prepare_cached(
+                "SELECT id, enunciated FROM words \
+                 WHERE enunciated LIKE ('%' || ?1 || '%') \
+                       AND (?2 IS NULL OR category = ?2) \
+                       AND id NOT IN (SELECT word_id FROM tag_associations WHERE tag_id = ?3) \
+                 ORDER BY enunciated",
+            )
+            .unwrap();
+        let mut it = stmt
+            .query(params![
+                filter.unwrap_or("").trim(),
+                category.map(|c| c as isize),
+                tag_id
+            ])
+            .unwrap();
+        while let Some(row) = it.next().unwrap() {
+            words.push((
+                row.get::<usize, i64>(0).unwrap(),
+                row.get::<usize, String>(1).unwrap(),
+            ));
+        }
+    }
+    if dry_run {
+        return Ok(words
+            .into_iter()
+            .map(|(_, enunciated)| enunciated)
+            .collect());
+    }
+
+    for (word_id, _) in &words {
+        if let Err(e) = tx.execute(
+            "INSERT INTO tag_associations (tag_id, word_id, updated_at, created_at) \
+             VALUES (?1, ?2, datetime('now'), datetime('now'))",
+            params![tag_id, word_id],
+        ) {
+            return Err(format!("could not attach tag: {e}"));
+        }
+    }
+    match tx.commit() {
+        Ok(_) => Ok(words
+            .into_iter()
+            .map(|(_, enunciated)| enunciated)
+            .collect()),
+        Err(e) => Err(format!("could not attach tag: {e}")),
+    }
+}
+
+/// Removes the given `tags` from the exercise identified by `exercise_id`.
+pub fn dettach_tags_from_exercise(tags: &[i32], exercise_id: i64) -> Result<(), String> {
+    if tags.is_empty() {
+        return Ok(());
+    }
+
+    let conn = crate::get_connection()?;
+
+    match conn.execute(
+        format!(
+            "DELETE FROM exercise_tag_associations \
+             WHERE exercise_id = ?1 AND tag_id IN ({})",
+            placeholders(2, tags.len())
+        )
+        .as_str(),
+        rusqlite::params_from_iter(
+            std::iter::once(exercise_id).chain(tags.iter().map(|&t| t as i64)),
+        ),
+    ) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not dettach tag: {e}")),
+    }
+}
+
+/// Delete the tag from the database, alongside its associations to words and
+/// exercises. Its children are moved under its own parent, if any.
 pub fn delete_tag(name: &String) -> Result<(), String> {
     let conn = crate::get_connection()?;
+    let tx = match conn.unchecked_transaction() {
+        Ok(tx) => tx,
+        Err(e) => return Err(format!("could not remove '{name}': {e}")),
+    };
 
-    match conn.execute("DELETE FROM tags WHERE name = ?1", params![name.trim()]) {
+    if let Err(e) = tx.execute(
+        "UPDATE tags \
+         SET parent_id = (SELECT parent_id FROM tags WHERE name = ?1) \
+         WHERE parent_id = (SELECT id FROM tags WHERE name = ?1)",
+        params![name.trim()],
+    ) {
+        return Err(format!("could not remove '{name}': {e}"));
+    }
+    match tx
+        .execute("DELETE FROM tags WHERE name = ?1", params![name.trim()])
+        .and_then(|_| tx.commit())
+    {
         Ok(_) => Ok(()),
         Err(e) => Err(format!("could not remove '{name}': {e}")),
     }
@@ -1,9 +1,13 @@
 use crate::cfg::Language;
 use crate::get_connection;
 use rusqlite::params;
-use rusqlite::types::{FromSql, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
 use rusqlite::Result;
 use serde_json::Value;
+use std::collections::HashMap;
+
+pub use mihi_core::grammar::{Category, Conjugation, Declension, Gender};
+pub use mihi_core::matching::{matches_enunciated, matches_form, matches_translation};
+pub use mihi_core::text::{normalize_enunciated, strip_macrons};
 
 /// A word as represented in the 'words' table of the database.
 #[derive(Clone, Debug)]
@@ -65,6 +69,34 @@ impl Word {
         }
     }
 
+    /// Returns the suffix of this word if it's an enclitic (e.g. 'que' for
+    /// '-que'), or None otherwise.
+    pub fn enclitic_suffix(&self) -> Option<&str> {
+        if !self.is_flag_set("enclitic") {
+            return None;
+        }
+        let suffix = self.enunciated.trim().trim_start_matches('-');
+        if suffix.is_empty() {
+            None
+        } else {
+            Some(suffix)
+        }
+    }
+
+    /// Returns an example of this enclitic attached to a host word (e.g.
+    /// 'senātus populusque' for '-que'), as enclitics cannot be shown on their
+    /// own. Returns None if this is not an enclitic.
+    pub fn enclitic_example(&self) -> Option<String> {
+        let suffix = self.enclitic_suffix()?;
+
+        Some(match strip_macrons(suffix).as_str() {
+            "que" => format!("senātus populus{suffix}"),
+            "ne" => format!("vidēs{suffix}"),
+            "ve" => format!("bis ter{suffix}"),
+            _ => format!("rosa{suffix}"),
+        })
+    }
+
     /// Returns the nominative version of the enunciate.
     pub fn singular_nominative(&self) -> String {
         self.enunciated
@@ -85,234 +117,49 @@ impl Word {
         }
         self.particle.clone()
     }
-}
-
-#[derive(Clone, Copy, Debug, Default)]
-pub enum Category {
-    #[default]
-    Unknown = 0,
-    Noun,
-    Adjective,
-    Verb,
-    Pronoun,
-    Adverb,
-    Preposition,
-    Conjunction,
-    Interjection,
-    Determiner,
-}
-
-impl std::fmt::Display for Category {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Self::Unknown => write!(f, "unknown"),
-            Self::Noun => write!(f, "noun"),
-            Self::Adjective => write!(f, "adjective"),
-            Self::Verb => write!(f, "verb"),
-            Self::Pronoun => write!(f, "pronoun"),
-            Self::Adverb => write!(f, "adverb"),
-            Self::Preposition => write!(f, "preposition"),
-            Self::Conjunction => write!(f, "conjunction"),
-            Self::Interjection => write!(f, "interjection"),
-            Self::Determiner => write!(f, "determiner"),
-        }
-    }
-}
-
-impl TryFrom<isize> for Category {
-    type Error = &'static str;
-
-    fn try_from(value: isize) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Self::Unknown),
-            1 => Ok(Self::Noun),
-            2 => Ok(Self::Adjective),
-            3 => Ok(Self::Verb),
-            4 => Ok(Self::Pronoun),
-            5 => Ok(Self::Adverb),
-            6 => Ok(Self::Preposition),
-            7 => Ok(Self::Conjunction),
-            8 => Ok(Self::Interjection),
-            9 => Ok(Self::Determiner),
-            _ => Err("unknonwn category!"),
-        }
-    }
-}
-
-#[derive(Clone, Copy, Debug, Default)]
-pub enum Gender {
-    Masculine = 0,
-    Feminine,
-    MasculineOrFeminine,
-    Neuter,
-    #[default]
-    None,
-}
-
-impl Gender {
-    /// Returns a string containing the abbreviation for this gender.
-    pub fn abbrev(&self) -> &str {
-        match self {
-            Self::Masculine => "m.",
-            Self::Feminine => "f.",
-            Self::MasculineOrFeminine => "m./f.",
-            Self::Neuter => "n.",
-            Self::None => "(genderless)",
-        }
-    }
-}
-
-impl TryFrom<isize> for Gender {
-    type Error = &'static str;
-
-    fn try_from(value: isize) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(Self::Masculine),
-            1 => Ok(Self::Feminine),
-            2 => Ok(Self::MasculineOrFeminine),
-            3 => Ok(Self::Neuter),
-            4 => Ok(Self::None),
-            _ => Err("unknonwn gender!"),
-        }
-    }
-}
-
-impl std::fmt::Display for Gender {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Self::Masculine => write!(f, "masculine"),
-            Self::Feminine => write!(f, "feminine"),
-            Self::MasculineOrFeminine => write!(f, "masculine or feminine"),
-            Self::Neuter => write!(f, "neuter"),
-            Self::None => write!(f, "none"),
-        }
-    }
-}
-
-/// Identifies the declension for a given word, and it allows to do SQL to/from
-/// conversions.
-#[derive(Clone, Debug)]
-pub enum Declension {
-    First = 1,
-    Second,
-    Third,
-    Fourth,
-    Fifth,
-
-    // Used for highly irregular words (e.g. 'īnstar').
-    Other,
-}
-
-impl ToSql for Declension {
-    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
-        Ok(ToSqlOutput::from(self.clone() as isize))
-    }
-}
-
-impl FromSql for Declension {
-    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
-        let val = value.as_i64().unwrap_or(0);
-
-        match val {
-            1 => Ok(Declension::First),
-            2 => Ok(Declension::Second),
-            3 => Ok(Declension::Third),
-            4 => Ok(Declension::Fourth),
-            5 => Ok(Declension::Fifth),
-            _ => Ok(Declension::Other),
-        }
-    }
-}
 
-impl std::fmt::Display for Declension {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Declension::First => write!(f, "1st (-ae)"),
-            Declension::Second => write!(f, "2nd (-ī)"),
-            Declension::Third => write!(f, "3rd (-is)"),
-            Declension::Fourth => write!(f, "4th (-ūs)"),
-            Declension::Fifth => write!(f, "5th (-eī/-ēī)"),
-            Declension::Other => write!(f, "other"),
-        }
-    }
-}
-
-/// Identifies the conjugation for a given verb, and it allows to do SQL to/from
-/// conversions.
-#[derive(Clone, Debug)]
-pub enum Conjugation {
-    First = 1,
-    Second,
-    Third,
-    ThirdIo,
-    Fourth,
-
-    // The 'Other' conjugation is a container for a bunch of verbs like 'sum',
-    // 'volō', etc. That is, we expect the user to realize this is an irregular
-    // verb, and then we rely on the 'kind' column to determine which kind of
-    // irregular verb that is. This is better than enlarging a list of kinds of
-    // irregular verbs as it was done in the past.
-    Other,
-}
-
-impl ToSql for Conjugation {
-    fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
-        Ok(ToSqlOutput::from(self.clone() as isize))
-    }
-}
-
-impl FromSql for Conjugation {
-    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
-        let val = value.as_i64().unwrap_or(0);
-
-        match val {
-            1 => Ok(Conjugation::First),
-            2 => Ok(Conjugation::Second),
-            3 => Ok(Conjugation::Third),
-            4 => Ok(Conjugation::ThirdIo),
-            5 => Ok(Conjugation::Fourth),
-            _ => Ok(Conjugation::Other),
-        }
+    /// Returns the principal parts of this word as given by its enunciate
+    /// (e.g. 'amō, amāre, amāvī, amātum' -> ['amō', 'amāre', 'amāvī',
+    /// 'amātum']).
+    pub fn principal_parts(&self) -> Vec<String> {
+        self.enunciated
+            .split(',')
+            .map(|part| part.trim().to_string())
+            .filter(|part| !part.is_empty())
+            .collect()
     }
-}
 
-impl std::fmt::Display for Conjugation {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Conjugation::First => write!(f, "1st (ā stems)"),
-            Conjugation::Second => write!(f, "2nd (ē stems)"),
-            Conjugation::Third => write!(f, "3rd (ĕ stems)"),
-            Conjugation::ThirdIo => write!(f, "3rd (-iō variants)"),
-            Conjugation::Fourth => write!(f, "4th (ī stems)"),
-            Conjugation::Other => write!(f, "other"),
+    /// Returns the perfect stem of this verb (e.g. 'amāv' for 'amō, amāre,
+    /// amāvī, amātum'), or None if it cannot be guessed from its principal
+    /// parts (e.g. deponent verbs).
+    pub fn perfect_stem(&self) -> Option<String> {
+        let parts = self.principal_parts();
+        let perfect = if self.is_flag_set("onlyperfect") {
+            parts.first()?
+        } else {
+            parts.get(2)?
+        };
+
+        if perfect.ends_with(" sum") {
+            return None;
         }
+        perfect.strip_suffix('ī').map(|stem| stem.to_string())
     }
-}
 
-impl Conjugation {
-    /// Returns a String containing how a conjugation would be displayed by also
-    /// considering the given 'kind' identifier. This way we can display 'other'
-    /// conjugations in a more natural way.
-    pub fn display_with_kind(&self, kind: &str) -> String {
-        if !matches!(self, Conjugation::Other) {
-            return format!("{}", self);
-        }
+    /// Returns the perfect passive participle of this verb in the masculine
+    /// singular nominative (e.g. 'amātus' for 'amō, amāre, amāvī, amātum', or
+    /// 'locūtus' for 'loquor, loquī, locūtus sum'). None is returned if it
+    /// cannot be guessed from its principal parts.
+    pub fn perfect_participle(&self) -> Option<String> {
+        let parts = self.principal_parts();
 
-        match kind {
-            "sum" => "irregular; like 'sum, esse, fuī, futūrus'",
-            "possum" => "irregular; like 'possum, posse, potuī'",
-            "eo" => "irregular; like 'eō, īre, iī, itum'",
-            "volo" => "irregular; like 'volō, velle, voluī'",
-            "nolo" => "irregular; like 'nōlō, nōlle, nōluī'",
-            "malo" => "irregular; like 'mālō, mālle, māluī'",
-            "fero" => "irregular; like 'ferō, ferre, tulī, lātum'",
-            "facio" => "3rd (-iō variants) and suppletive; like 'faciō, facere, fēcī, factum'",
-            "do" => "1st; irregular short ă in most forms",
-            "inquam" => "irregular, highly defective",
-            "aio" => "3rd (-iō variants); highly defective",
-            _ => "other",
+        if let Some(participle) = parts.get(2).and_then(|p| p.strip_suffix(" sum")) {
+            return Some(participle.to_string());
         }
-        .to_string()
+        parts
+            .get(3)
+            .and_then(|supine| supine.strip_suffix("um"))
+            .map(|stem| format!("{stem}us"))
     }
 }
 
@@ -459,6 +306,7 @@ pub const BOOLEAN_FLAGS: &[&str] = &[
     "nosupine",
     "noperfect",
     "nogerundive",
+    "nogerund",
     "impersonal",
     "impersonalpassive",
     "noimperative",
@@ -472,6 +320,102 @@ pub const BOOLEAN_FLAGS: &[&str] = &[
     "contracted_vocative",
 ];
 
+// Flags which make a word harder to remember, as they describe either an
+// irregularity or a defective paradigm.
+const IRREGULAR_FLAGS: &[&str] = &[
+    "deponent",
+    "semideponent",
+    "irregularsup",
+    "compsup_prefix",
+    "contracted_root",
+    "contracted_vocative",
+    "shortimperative",
+    "impersonal",
+    "impersonalpassive",
+    "onlythirdpassive",
+    "onlyperfect",
+    "noperfect",
+];
+
+/// Minimum weight for a word.
+pub const MIN_WEIGHT: isize = 1;
+
+/// Maximum weight for a word.
+pub const MAX_WEIGHT: isize = 10;
+
+/// Returns a heuristic estimation of how hard the given `word` is to remember,
+/// to be used as its initial weight. It starts from a base of 3 and then adds
+/// up:
+///   - 2 for irregular words, plus 1 if its inflection is wholly irregular.
+///   - 1 for each irregularity flag (see `IRREGULAR_FLAGS`), up to 3; and 1
+///     for each set of 'sets'/'adds' overrides.
+///   - 1 for the less frequent declensions and conjugations, and for
+///     third declension nouns (their gender is not obvious).
+///   - 1 if the first part of the enunciate is longer than 10 characters.
+///   - 1 if some translation has more than two meanings, and 2 if it has more
+///     than four.
+///
+/// The result is clamped between `MIN_WEIGHT` and `MAX_WEIGHT`.
+pub fn estimate_difficulty(word: &Word) -> isize {
+    let mut score = 3;
+
+    if !word.regular {
+        score += 2;
+    }
+    if matches!(word.declension, Some(Declension::Other))
+        || matches!(word.conjugation, Some(Conjugation::Other))
+    {
+        score += 1;
+    }
+
+    let flags = IRREGULAR_FLAGS
+        .iter()
+        .filter(|flag| word.is_flag_set(flag))
+        .count() as isize;
+    score += flags.min(3);
+    for key in ["sets", "adds"] {
+        if word.flags.get(key).is_some() {
+            score += 1;
+        }
+    }
+
+    match word.declension {
+        Some(Declension::Fourth | Declension::Fifth) => score += 1,
+        Some(Declension::Third) if matches!(word.category, Category::Noun) => score += 1,
+        _ => {}
+    }
+    if matches!(
+        word.conjugation,
+        Some(Conjugation::Third | Conjugation::ThirdIo)
+    ) {
+        score += 1;
+    }
+
+    if word.singular_nominative().chars().count() > 10 {
+        score += 1;
+    }
+
+    let meanings = word
+        .translation
+        .as_object()
+        .map(|translations| {
+            translations
+                .values()
+                .filter_map(|t| t.as_str())
+                .map(|t| t.split([',', ';']).filter(|m| !m.trim().is_empty()).count())
+                .max()
+                .unwrap_or(0)
+        })
+        .unwrap_or(0);
+    if meanings > 4 {
+        score += 2;
+    } else if meanings > 2 {
+        score += 1;
+    }
+
+    score.clamp(MIN_WEIGHT, MAX_WEIGHT)
+}
+
 /// Returns true if the given flag is supported by this application.
 pub fn is_valid_word_flag(flag: &str) -> bool {
     BOOLEAN_FLAGS.contains(&flag)
@@ -479,6 +423,12 @@ pub fn is_valid_word_flag(flag: &str) -> bool {
 
 /// Creates the given word into the database and returns its ID on success.
 pub fn create_word(word: Word) -> Result<i64, String> {
+    create_word_on(&*get_connection()?, word)
+}
+
+/// Same as `create_word`, but on the given connection (e.g. the transaction
+/// of a `writer::Writer`).
+pub(crate) fn create_word_on(conn: &rusqlite::Connection, word: Word) -> Result<i64, String> {
     match word.category {
         Category::Noun | Category::Adjective => {
             if word.declension.is_none() {
@@ -511,7 +461,6 @@ pub fn create_word(word: Word) -> Result<i64, String> {
         }
     }
 
-    let conn = get_connection()?;
     match conn.execute(
         "INSERT INTO words (enunciated, particle, language_id, declension_id, \
                             conjugation_id, kind, category, regular, locative, \
@@ -598,10 +547,41 @@ pub fn update_timestamp(enunciated: &str) -> Result<(), String> {
     }
 }
 
+/// Suspends the given `word` if it was not suspended, and lifts its suspension
+/// otherwise. Suspended words are kept in the database, but they are never
+/// picked for practice sessions nor counted as due (e.g. words which are known
+/// forever like 'et'). Returns whether the word is now suspended.
+pub fn toggle_suspended(word: &Word) -> Result<bool, String> {
+    let conn = get_connection()?;
+
+    match conn.query_row(
+        "UPDATE words SET suspended = NOT suspended WHERE id = ?1 RETURNING suspended",
+        params![word.id],
+        |row| row.get::<usize, bool>(0),
+    ) {
+        Ok(suspended) => Ok(suspended),
+        Err(e) => Err(format!("could not update '{}': {e}", word.enunciated)),
+    }
+}
+
+/// Returns how many words are suspended (see `toggle_suspended`).
+pub fn count_suspended_words() -> Result<usize, String> {
+    let conn = get_connection()?;
+
+    match conn.query_row(
+        "SELECT COUNT(*) FROM words WHERE suspended = 1",
+        [],
+        |row| row.get::<usize, isize>(0),
+    ) {
+        Ok(count) => Ok(count as usize),
+        Err(e) => Err(format!("could not count the suspended words: {e}")),
+    }
+}
+
 /// Select words based on the given `filter` for the enunciated column, which
 /// can be further filtered out by providing a set of `tags`. The words selected
-/// must then have any of the given tags provided by this vector, and it will be
-/// ignored if the passed vector is empty.
+/// must then have any of the given tags provided by this vector (or any of
+/// their descendants), and it will be ignored if the passed vector is empty.
 pub fn select_enunciated(filter: Option<String>, tags: &[String]) -> Result<Vec<String>, String> {
     let conn = get_connection()?;
 
@@ -610,53 +590,46 @@ pub fn select_enunciated(filter: Option<String>, tags: &[String]) -> Result<Vec<
         Some(filter) => {
             stmt = if tags.is_empty() {
                 conn
-                .prepare(
+                .prepare_cached(
                     "SELECT enunciated FROM words WHERE enunciated LIKE ('%' || ?1 || '%') ORDER BY enunciated",
                 )
                     .unwrap()
             } else {
-                conn.prepare(
+                conn.prepare_cached(
                     format!(
                         "SELECT w.enunciated \
                          FROM words w \
-                         JOIN tag_associations ta ON w.id = ta.word_id \
-                         JOIN tags t ON t.id = ta.tag_id \
-                         WHERE w.enunciated LIKE ('%' || ?1 || '%') AND t.name IN ({}) \
+                         WHERE w.enunciated LIKE ('%' || ?1 || '%') AND {} \
                          ORDER BY w.enunciated",
-                        tags.iter()
-                            .map(|t| format!("'{}'", t))
-                            .collect::<Vec<_>>()
-                            .join(", "),
+                        crate::tag::tagged_clause("w.id", tags, 2),
                     )
                     .as_str(),
                 )
                 .unwrap()
             };
-            stmt.query([filter.as_str()]).unwrap()
+            stmt.query(rusqlite::params_from_iter(
+                std::iter::once(&filter).chain(tags.iter()),
+            ))
+            .unwrap()
         }
         None => {
             stmt = if tags.is_empty() {
-                conn.prepare("SELECT enunciated FROM words ORDER BY enunciated")
+                conn.prepare_cached("SELECT enunciated FROM words ORDER BY enunciated")
                     .unwrap()
             } else {
-                conn.prepare(
+                conn.prepare_cached(
                     format!(
                         "SELECT w.enunciated \
                          FROM words w \
-                         JOIN tag_associations ta ON w.id = ta.word_id \
-                         JOIN tags t ON t.id = ta.tag_id \
-                         WHERE t.name IN ({}) \
+                         WHERE {} \
                          ORDER BY w.enunciated",
-                        tags.iter()
-                            .map(|t| format!("'{}'", t))
-                            .collect::<Vec<_>>()
-                            .join(", "),
+                        crate::tag::tagged_clause("w.id", tags, 1),
                     )
                     .as_str(),
                 )
                 .unwrap()
             };
-            stmt.query([]).unwrap()
+            stmt.query(rusqlite::params_from_iter(tags)).unwrap()
         }
     };
 
@@ -675,7 +648,7 @@ pub fn select_related_words(word: &Word) -> Result<[Vec<Word>; 5], String> {
 
     let conn = get_connection()?;
     let mut stmt = conn
-        .prepare(
+        .prepare_cached(
                 "SELECT w.id, w.enunciated, w.particle, w.language_id, w.declension_id, w.conjugation_id, \
                     w.kind as wkind, w.category, w.regular, w.locative, w.gender, w.suffix, w.translation, \
                     w.succeeded, w.steps, w.flags, w.weight, r.kind as rkind \
@@ -716,7 +689,7 @@ pub fn select_related_words(word: &Word) -> Result<[Vec<Word>; 5], String> {
 pub fn find_by(enunciated: &str) -> Result<Word, String> {
     let conn = get_connection()?;
     let mut stmt = conn
-        .prepare(
+        .prepare_cached(
             "SELECT id, enunciated, particle, language_id, declension_id, conjugation_id, \
                     kind, category, regular, locative, gender, suffix, translation, \
                     succeeded, steps, flags, weight \
@@ -753,6 +726,32 @@ pub fn find_by(enunciated: &str) -> Result<Word, String> {
     }
 }
 
+/// Returns the word whose enunciated matches the given one once both are
+/// normalized (see `normalize_enunciated`), if any.
+pub fn find_by_normalized(enunciated: &str) -> Result<Option<Word>, String> {
+    let normalized = normalize_enunciated(enunciated);
+
+    for candidate in select_enunciated(None, &[])? {
+        if normalize_enunciated(&candidate) == normalized {
+            return Ok(Some(find_by(&candidate)?));
+        }
+    }
+    Ok(None)
+}
+
+/// Returns the enunciates of all words keyed by their normalized version (see
+/// `normalize_enunciated`). Useful when many lookups like the one from
+/// `find_by_normalized` are to be done in a row (e.g. when importing words).
+pub fn select_normalized_enunciates() -> Result<HashMap<String, String>, String> {
+    let mut res = HashMap::new();
+
+    for enunciated in select_enunciated(None, &[])? {
+        res.entry(normalize_enunciated(&enunciated))
+            .or_insert(enunciated);
+    }
+    Ok(res)
+}
+
 // Builds up a chain of OR clauses that check whether either of the given
 // `flags` are set for a row. If no flags are given, then an empty string is
 // returned. Otherwise the string is prepended by an "AND" clause, meaning that
@@ -770,52 +769,188 @@ fn flags_clause(flags: &[String]) -> String {
     "AND (".to_owned() + &clauses.join(" OR ") + ")"
 }
 
+/// Which words are to be selected depending on whether they have ever been
+/// reviewed. Words which have never been reviewed have no interval yet, as
+/// every review sets one regardless of the configured scheduler.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Novelty {
+    /// All words.
+    #[default]
+    Any,
+    /// Only words which have never been reviewed.
+    New,
+    /// Only words which have been reviewed at least once.
+    Review,
+}
+
+impl Novelty {
+    // Returns the clause which filters words for this novelty, prepended by
+    // "AND" as in `flags_clause`. The given `prefix` is prepended to each
+    // column (e.g. "w.").
+    fn clause(&self, prefix: &str) -> String {
+        match self {
+            Self::Any => "".to_string(),
+            Self::New => format!("AND {prefix}interval_days = 0"),
+            Self::Review => format!("AND {prefix}interval_days > 0"),
+        }
+    }
+}
+
+/// Returns the ids of the given `words` which have never been reviewed (see
+/// `Novelty`).
+pub fn select_new_word_ids(words: &[Word]) -> Result<Vec<i32>, String> {
+    if words.is_empty() {
+        return Ok(vec![]);
+    }
+    let conn = get_connection()?;
+
+    let ids = words
+        .iter()
+        .map(|word| word.id.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut stmt = conn
+        .prepare_cached(
+            format!(
+                "SELECT id FROM words WHERE id IN ({ids}) {}",
+                Novelty::New.clause("")
+            )
+            .as_str(),
+        )
+        .map_err(|e| format!("could not select new words: {e}"))?;
+    stmt.query_map([], |row| row.get(0))
+        .and_then(|rows| rows.collect::<Result<Vec<i32>, _>>())
+        .map_err(|e| format!("could not select new words: {e}"))
+}
+
+// Returns the first clauses of the "ORDER BY" statement when selecting words
+// to be practiced. Words which were asked within the last `RECENT_HOURS` go
+// last, so the same word is not asked on multiple sessions of the same day
+// unless there are not enough candidates. Otherwise, words which are due for a
+// review go first, then the ones
+// which have never been reviewed, and then the ones which are not due yet,
+// unless the legacy scheduler is configured. Within each group, words are
+// sampled at random so sessions do not repeat the same words in the same order,
+// but words with a higher weight and with fewer successful runs are more likely
+// to go first (see `sampling_key`). If the configuration says so, words which
+// occur in the user's own texts get a boost on their weight as well. The given
+// `prefix` is prepended to each column (e.g. "w.").
+fn priority_clause(prefix: &str) -> String {
+    let due = if crate::cfg::configuration().scheduler == crate::schedule::Scheduler::Legacy {
+        String::new()
+    } else {
+        format!(
+            "CASE WHEN {prefix}due_at <= datetime('now') THEN 0 \
+                  WHEN {prefix}due_at IS NULL THEN 1 \
+                  ELSE 2 END ASC,"
+        )
+    };
+
+    let weight = if crate::cfg::configuration().boost_occurrences {
+        format!("({prefix}weight + MIN({prefix}occurrences, 10))")
+    } else {
+        format!("{prefix}weight")
+    };
+    format!(
+        "CASE WHEN {prefix}last_asked_at > datetime('now', '-{RECENT_HOURS} hours') \
+              THEN 1 ELSE 0 END ASC, \
+         {due} ({weight} + 1) * (ABS(RANDOM()) % {SAMPLING_RANGE}) \
+             / (COALESCE({prefix}succeeded, 0) + 1.0) DESC,"
+    )
+}
+
+// Range of the random values used when sampling words to be practiced.
+const SAMPLING_RANGE: u64 = 1000;
+
+// Words which were asked within this number of hours go after all the other
+// ones, so they are only asked again if there are not enough candidates.
+const RECENT_HOURS: usize = 12;
+
+/// Returns the key by which a word with the given `weight` and `succeeded`
+/// runs is sorted when sampling words to be practiced, where `random` is a
+/// value between 0 and 1. Higher keys go first. This is the same key that is
+/// used on the database when selecting words.
+pub fn sampling_key(weight: isize, succeeded: isize, random: f64) -> f64 {
+    let random = (random * SAMPLING_RANGE as f64).floor();
+    (weight + 1) as f64 * random / (succeeded.max(0) + 1) as f64
+}
+
+/// Store how many times each word occurs in the user's own texts as given by
+/// `counts`, which is a list of enunciates and their occurrences (see
+/// `analysis::count_lemmas`). Words not in `counts` are set to zero.
+pub fn update_occurrences(counts: &[(String, usize)]) -> Result<(), String> {
+    let conn = get_connection()?;
+
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    tx.execute("UPDATE words SET occurrences = 0", [])
+        .map_err(|e| e.to_string())?;
+    for (enunciated, count) in counts {
+        tx.execute(
+            "UPDATE words SET occurrences = ?1 WHERE enunciated = ?2",
+            params![*count as isize, enunciated],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+
+    match tx.commit() {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not save occurrences: {e}")),
+    }
+}
+
 // Select a maximum of `number` words which match a given word `category` and
 // have set one of the given boolean `flags`. You may also pass a `tags` vector
 // which contains the name of the tags for which each word must have at least
-// one match.
+// one match, and a `novelty` to only pick either new or reviewed words.
 pub fn select_relevant_words(
     category: Category,
     flags: &[String],
     tags: &[String],
     number: isize,
+    novelty: Novelty,
 ) -> Result<Vec<Word>, String> {
     let conn = get_connection()?;
     let mut stmt = if tags.is_empty() {
-        conn.prepare(
+        conn.prepare_cached(
             format!(
                 "SELECT id, enunciated, particle, language_id, declension_id, conjugation_id, \
                     kind, category, regular, locative, gender, suffix, translation, \
                     succeeded, steps, flags, weight \
                  FROM words \
-                 WHERE category = ?1 AND translation != '{{}}' {} \
-                 ORDER BY weight DESC, succeeded ASC, updated_at DESC
+                 WHERE category = ?1 AND translation != '{{}}' AND suspended = 0 {} {} \
+                 ORDER BY {} succeeded ASC, updated_at DESC
                  LIMIT ?2",
-                flags_clause(flags)
+                flags_clause(flags),
+                novelty.clause(""),
+                priority_clause("")
             )
             .as_str(),
         )
         .unwrap()
     } else {
-        conn.prepare(
+        conn.prepare_cached(
             format!(
                 "SELECT w.id, w.enunciated, w.particle, w.language_id, w.declension_id, w.conjugation_id, \
                     w.kind, w.category, w.regular, w.locative, w.gender, w.suffix, w.translation, \
                     w.succeeded, w.steps, w.flags, w.weight \
                  FROM words w \
-                 JOIN tag_associations ta ON w.id = ta.word_id \
-                 JOIN tags t ON t.id = ta.tag_id \
-                 WHERE w.category = ?1 AND t.name IN ({}) AND w.translation != '{{}}' {} \
-                 ORDER BY w.weight DESC, w.succeeded ASC, w.updated_at DESC
+                 WHERE w.category = ?1 AND {} AND w.translation != '{{}}' \
+                       AND w.suspended = 0 {} {} \
+                 ORDER BY {} w.succeeded ASC, w.updated_at DESC
                  LIMIT ?2",
-                tags.iter().map(|t| format!("'{}'", t)).collect::<Vec<_>>().join(", "),
-                flags_clause(flags)
+                crate::tag::tagged_clause("w.id", tags, 3),
+                flags_clause(flags),
+                novelty.clause("w."),
+                priority_clause("w.")
             )
             .as_str(),
         )
         .unwrap()
     };
-    let mut it = stmt.query([category as isize, number]).unwrap();
+    let mut values: Vec<rusqlite::types::Value> =
+        vec![(category as i64).into(), (number as i64).into()];
+    values.extend(tags.iter().map(|tag| tag.clone().into()));
+    let mut it = stmt.query(rusqlite::params_from_iter(values)).unwrap();
 
     let mut res = vec![];
     while let Some(row) = it.next().unwrap() {
@@ -845,18 +980,20 @@ pub fn select_relevant_words(
 /// Select a set of words except for the ones passed in the `excluded`
 /// vector. You have to pass the categories to be selected via the `categories`
 /// parameter, which cannot be empty. It also accepts a set of boolean `flags`
-/// as with functions like `select_relevant_words`; and the `tags` filtering
-/// option.
+/// as with functions like `select_relevant_words`; and the `tags` and `novelty`
+/// filtering options. Enclitics are left out, as they cannot be inflected on
+/// their own.
 pub fn select_words_except(
     excluded: &[Word],
     categories: &[Category],
     flags: &[String],
     tags: &[String],
+    novelty: Novelty,
 ) -> Result<Vec<Word>, String> {
     assert!(!categories.is_empty());
 
     let ids = excluded.iter().map(|w| w.id).collect::<Vec<i32>>();
-    let placeholders = ids.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+    let placeholders = crate::tag::placeholders(1, ids.len());
     let cats = categories
         .iter()
         .map(|c| format!("{}", *c as isize))
@@ -865,45 +1002,327 @@ pub fn select_words_except(
 
     let conn = get_connection()?;
     let mut stmt = if tags.is_empty() {
-        conn.prepare(
+        conn.prepare_cached(
             format!(
                 "SELECT id, enunciated, particle, language_id, declension_id, conjugation_id, \
                     kind, category, regular, locative, gender, suffix, translation, \
                     succeeded, steps, flags, weight \
                  FROM words \
-                 WHERE id NOT IN ({}) AND category IN ({}) AND translation != '{{}}' {} \
-                 ORDER BY weight DESC, succeeded ASC, updated_at DESC
+                 WHERE id NOT IN ({}) AND category IN ({}) AND translation != '{{}}' \
+                       AND suspended = 0 AND IFNULL(json_extract(flags, '$.enclitic'), 0) != 1 {} {} \
+                 ORDER BY {} succeeded ASC, updated_at DESC
                  LIMIT 5",
                 placeholders,
                 cats,
-                flags_clause(flags)
+                flags_clause(flags),
+                novelty.clause(""),
+                priority_clause("")
             )
             .as_str(),
         )
         .unwrap()
     } else {
-        conn.prepare(
+        conn.prepare_cached(
             format!(
                 "SELECT w.id, w.enunciated, w.particle, w.language_id, w.declension_id, w.conjugation_id, \
                     w.kind, w.category, w.regular, w.locative, w.gender, w.suffix, w.translation, \
                     w.succeeded, w.steps, w.flags, w.weight \
                  FROM words w \
-                 JOIN tag_associations ta ON w.id = ta.word_id \
-                 JOIN tags t ON t.id = ta.tag_id \
-                 WHERE w.id NOT IN ({}) AND t.name IN ({}) AND w.category IN ({}) AND w.translation != '{{}}' {} \
-                 ORDER BY w.weight DESC, w.succeeded ASC, w.updated_at DESC
+                 WHERE w.id NOT IN ({}) AND {} AND w.category IN ({}) AND w.translation != '{{}}' \
+                       AND w.suspended = 0 AND IFNULL(json_extract(w.flags, '$.enclitic'), 0) != 1 {} {} \
+                 ORDER BY {} w.succeeded ASC, w.updated_at DESC
                  LIMIT 5",
                 placeholders,
-                tags.iter().map(|t| format!("'{}'", t)).collect::<Vec<_>>().join(", "),
+                crate::tag::tagged_clause("w.id", tags, ids.len() + 1),
                 cats,
-                flags_clause(flags)
+                flags_clause(flags),
+                novelty.clause("w."),
+                priority_clause("w.")
             )
             .as_str(),
         )
         .unwrap()
     };
 
+    let mut values: Vec<rusqlite::types::Value> = ids.into_iter().map(|id| id.into()).collect();
+    values.extend(tags.iter().map(|tag| tag.clone().into()));
+    let mut it = stmt.query(rusqlite::params_from_iter(values)).unwrap();
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(Word {
+            id: row.get(0).unwrap(),
+            enunciated: row.get(1).unwrap(),
+            particle: row.get(2).unwrap(),
+            language: row.get::<usize, isize>(3).unwrap().try_into()?,
+            declension: row.get(4).unwrap(),
+            conjugation: row.get(5).unwrap(),
+            kind: row.get(6).unwrap(),
+            category: row.get::<usize, isize>(7).unwrap().try_into()?,
+            regular: row.get(8).unwrap(),
+            locative: row.get(9).unwrap(),
+            gender: row.get::<usize, isize>(10).unwrap().try_into()?,
+            suffix: row.get(11).unwrap(),
+            translation: serde_json::from_str(&row.get::<usize, String>(12).unwrap()).unwrap(),
+            succeeded: row.get(13).unwrap(),
+            steps: row.get(14).unwrap(),
+            flags: serde_json::from_str(&row.get::<usize, String>(15).unwrap()).unwrap(),
+            weight: row.get(16).unwrap(),
+        });
+    }
+
+    Ok(res)
+}
+
+/// Returns all the words from the database.
+pub fn select_all_words() -> Result<Vec<Word>, String> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id, enunciated, particle, language_id, declension_id, conjugation_id, \
+                    kind, category, regular, locative, gender, suffix, translation, \
+                    succeeded, steps, flags, weight \
+             FROM words \
+             ORDER BY enunciated",
+        )
+        .unwrap();
+    let mut it = stmt.query([]).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(Word {
+            id: row.get(0).unwrap(),
+            enunciated: row.get(1).unwrap(),
+            particle: row.get(2).unwrap(),
+            language: row.get::<usize, isize>(3).unwrap().try_into()?,
+            declension: row.get(4).unwrap(),
+            conjugation: row.get(5).unwrap(),
+            kind: row.get(6).unwrap(),
+            category: row.get::<usize, isize>(7).unwrap().try_into()?,
+            regular: row.get(8).unwrap(),
+            locative: row.get(9).unwrap(),
+            gender: row.get::<usize, isize>(10).unwrap().try_into()?,
+            suffix: row.get(11).unwrap(),
+            translation: serde_json::from_str(&row.get::<usize, String>(12).unwrap()).unwrap(),
+            succeeded: row.get(13).unwrap(),
+            steps: row.get(14).unwrap(),
+            flags: serde_json::from_str(&row.get::<usize, String>(15).unwrap()).unwrap(),
+            weight: row.get(16).unwrap(),
+        });
+    }
+
+    Ok(res)
+}
+
+/// Returns the words with a translation which are tagged with any of the given
+/// `tags`, in random order. Suspended words are left out.
+pub fn select_tagged_words(tags: &[String]) -> Result<Vec<Word>, String> {
+    if tags.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            format!(
+                "SELECT DISTINCT w.id, w.enunciated, w.particle, w.language_id, w.declension_id, \
+                    w.conjugation_id, w.kind, w.category, w.regular, w.locative, w.gender, \
+                    w.suffix, w.translation, w.succeeded, w.steps, w.flags, w.weight \
+                 FROM words w \
+                 JOIN tag_associations ta ON w.id = ta.word_id \
+                 JOIN tags t ON t.id = ta.tag_id \
+                 WHERE t.name IN ({}) AND w.translation != '{{}}' AND w.suspended = 0 \
+                 ORDER BY RANDOM()",
+                vec!["?"; tags.len()].join(", ")
+            )
+            .as_str(),
+        )
+        .unwrap();
+    let mut it = stmt.query(rusqlite::params_from_iter(tags)).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(Word {
+            id: row.get(0).unwrap(),
+            enunciated: row.get(1).unwrap(),
+            particle: row.get(2).unwrap(),
+            language: row.get::<usize, isize>(3).unwrap().try_into()?,
+            declension: row.get(4).unwrap(),
+            conjugation: row.get(5).unwrap(),
+            kind: row.get(6).unwrap(),
+            category: row.get::<usize, isize>(7).unwrap().try_into()?,
+            regular: row.get(8).unwrap(),
+            locative: row.get(9).unwrap(),
+            gender: row.get::<usize, isize>(10).unwrap().try_into()?,
+            suffix: row.get(11).unwrap(),
+            translation: serde_json::from_str(&row.get::<usize, String>(12).unwrap()).unwrap(),
+            succeeded: row.get(13).unwrap(),
+            steps: row.get(14).unwrap(),
+            flags: serde_json::from_str(&row.get::<usize, String>(15).unwrap()).unwrap(),
+            weight: row.get(16).unwrap(),
+        });
+    }
+
+    Ok(res)
+}
+
+/// Returns the words identified by the given `ids`, in the same order. IDs of
+/// words which no longer exist are skipped.
+pub fn select_words_by_ids(ids: &[i32]) -> Result<Vec<Word>, String> {
+    if ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            format!(
+                "SELECT id, enunciated, particle, language_id, declension_id, conjugation_id, \
+                    kind, category, regular, locative, gender, suffix, translation, \
+                    succeeded, steps, flags, weight \
+                 FROM words \
+                 WHERE id IN ({})",
+                vec!["?"; ids.len()].join(", ")
+            )
+            .as_str(),
+        )
+        .unwrap();
     let mut it = stmt.query(rusqlite::params_from_iter(ids)).unwrap();
+
+    let mut found = vec![];
+    while let Some(row) = it.next().unwrap() {
+        found.push(Word {
+            id: row.get(0).unwrap(),
+            enunciated: row.get(1).unwrap(),
+            particle: row.get(2).unwrap(),
+            language: row.get::<usize, isize>(3).unwrap().try_into()?,
+            declension: row.get(4).unwrap(),
+            conjugation: row.get(5).unwrap(),
+            kind: row.get(6).unwrap(),
+            category: row.get::<usize, isize>(7).unwrap().try_into()?,
+            regular: row.get(8).unwrap(),
+            locative: row.get(9).unwrap(),
+            gender: row.get::<usize, isize>(10).unwrap().try_into()?,
+            suffix: row.get(11).unwrap(),
+            translation: serde_json::from_str(&row.get::<usize, String>(12).unwrap()).unwrap(),
+            succeeded: row.get(13).unwrap(),
+            steps: row.get(14).unwrap(),
+            flags: serde_json::from_str(&row.get::<usize, String>(15).unwrap()).unwrap(),
+            weight: row.get(16).unwrap(),
+        });
+    }
+
+    Ok(ids
+        .iter()
+        .filter_map(|id| found.iter().find(|word| word.id == *id).cloned())
+        .collect())
+}
+
+/// Returns how many words there are for each kind of paradigm.
+pub fn count_words_by_kind() -> Result<Vec<(String, usize)>, String> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT kind, COUNT(*) \
+             FROM words \
+             GROUP BY kind \
+             ORDER BY kind",
+        )
+        .unwrap();
+    let mut it = stmt.query([]).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push((
+            row.get(0).unwrap(),
+            row.get::<usize, isize>(1).unwrap() as usize,
+        ));
+    }
+
+    Ok(res)
+}
+
+/// Returns how many words there are for each category.
+pub fn count_words_by_category() -> Result<Vec<(Category, usize)>, String> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT category, COUNT(*) \
+             FROM words \
+             GROUP BY category \
+             ORDER BY category",
+        )
+        .unwrap();
+    let mut it = stmt.query([]).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push((
+            row.get::<usize, isize>(0).unwrap().try_into()?,
+            row.get::<usize, isize>(1).unwrap() as usize,
+        ));
+    }
+
+    Ok(res)
+}
+
+/// Select at random a maximum of `number` enunciates from the database in the
+/// `other` path, as long as they are not in our database already. If a `kind`
+/// is given, then only words of this kind are selected. This is useful to pick
+/// up candidates from a seed database.
+pub fn select_missing_from(
+    other: &std::path::Path,
+    kind: Option<&str>,
+    number: usize,
+) -> Result<Vec<String>, String> {
+    // Attaching a database which does not exist would create an empty one.
+    if !other.is_file() {
+        return Err(format!("'{}' does not exist", other.display()));
+    }
+
+    let conn = get_connection()?;
+    if let Err(e) = conn.execute("ATTACH DATABASE ?1 AS other", [other.to_string_lossy()]) {
+        return Err(format!("could not open '{}': {e}", other.display()));
+    }
+
+    let mut stmt = match conn.prepare_cached(
+        "SELECT enunciated \
+         FROM other.words \
+         WHERE (?1 IS NULL OR kind = ?1) AND enunciated NOT IN (SELECT enunciated FROM main.words) \
+         ORDER BY RANDOM() \
+         LIMIT ?2",
+    ) {
+        Ok(stmt) => stmt,
+        Err(e) => return Err(format!("could not read '{}': {e}", other.display())),
+    };
+    let mut it = stmt.query(params![kind, number as isize]).unwrap();
+
+    let mut res = vec![];
+    while let Some(row) = it.next().unwrap() {
+        res.push(row.get(0).unwrap());
+    }
+
+    Ok(res)
+}
+
+/// Select at random a maximum of `number` words from the given `category` which
+/// are already known by the user. That is, words which have been answered
+/// correctly at least once and which are not suspended.
+pub fn select_known_words(category: Category, number: isize) -> Result<Vec<Word>, String> {
+    let conn = get_connection()?;
+    let mut stmt = conn
+        .prepare_cached(
+            "SELECT id, enunciated, particle, language_id, declension_id, conjugation_id, \
+                    kind, category, regular, locative, gender, suffix, translation, \
+                    succeeded, steps, flags, weight \
+             FROM words \
+             WHERE category = ?1 AND translation != '{}' AND suspended = 0 \
+                   AND (succeeded > 0 OR steps > 0) \
+             ORDER BY RANDOM() \
+             LIMIT ?2",
+        )
+        .unwrap();
+    let mut it = stmt.query([category as isize, number]).unwrap();
+
     let mut res = vec![];
     while let Some(row) = it.next().unwrap() {
         res.push(Word {
@@ -935,37 +1354,106 @@ pub fn select_words_except(
 pub fn delete_word(word: &Word) -> Result<(), String> {
     let conn = get_connection()?;
 
-    // Remove the word itself.
-    if let Err(e) = conn.execute(
-        "DELETE FROM words \
-         WHERE id = ?1",
-        params![word.id],
-    ) {
-        return Err(format!("could not remove '{}': {e}", word.enunciated));
+    // Relationships, tags, notes and links from exercises are removed along
+    // with the word itself.
+    match conn.execute("DELETE FROM words WHERE id = ?1", params![word.id]) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("could not remove '{}': {e}", word.enunciated)),
     }
+}
 
-    // Remove any relationships that mention this word.
-    if let Err(e) = conn.execute(
-        "DELETE FROM word_relations \
-         WHERE source_id = ?1 OR destination_id = ?1",
-        params![word.id],
-    ) {
-        return Err(format!(
-            "could not remove relationships from '{}': {e}",
-            word.enunciated
-        ));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word_with(
+        enunciated: &str,
+        category: Category,
+        declension: Option<Declension>,
+        conjugation: Option<Conjugation>,
+        flags: &str,
+        translation: &str,
+    ) -> Word {
+        let mut word = Word::from(
+            "".to_string(),
+            category,
+            declension,
+            conjugation,
+            Gender::None,
+            "".to_string(),
+        );
+        word.enunciated = enunciated.to_string();
+        word.flags = serde_json::from_str(flags).unwrap();
+        word.translation = serde_json::from_str(translation).unwrap();
+        word
     }
 
-    // Remove any tag relationships with this now defunct word.
-    match conn.execute(
-        "DELETE FROM tag_associations \
-         WHERE word_id = ?1",
-        params![word.id],
-    ) {
-        Ok(_) => Ok(()),
-        Err(e) => Err(format!(
-            "count not detach words for '{}': {e}",
-            word.enunciated
-        )),
+    #[test]
+    fn difficulty_of_regular_words() {
+        let rosa = word_with(
+            "rosa, rosae",
+            Category::Noun,
+            Some(Declension::First),
+            None,
+            "{}",
+            r#"{"en": "rose"}"#,
+        );
+        assert_eq!(estimate_difficulty(&rosa), 3);
+
+        let rex = word_with(
+            "rēx, rēgis",
+            Category::Noun,
+            Some(Declension::Third),
+            None,
+            "{}",
+            r#"{"en": "king"}"#,
+        );
+        assert_eq!(estimate_difficulty(&rex), 4);
+
+        let duco = word_with(
+            "dūcō, dūcere, dūxī, ductum",
+            Category::Verb,
+            None,
+            Some(Conjugation::Third),
+            r#"{"shortimperative": true}"#,
+            r#"{"en": "I lead, I guide, I consider"}"#,
+        );
+        assert_eq!(estimate_difficulty(&duco), 6);
+    }
+
+    #[test]
+    fn difficulty_of_irregular_words() {
+        let mut fero = word_with(
+            "ferō, ferre, tulī, lātum",
+            Category::Verb,
+            None,
+            Some(Conjugation::Other),
+            "{}",
+            r#"{"en": "I carry, I bear, I endure, I report, I say"}"#,
+        );
+        fero.regular = false;
+        assert_eq!(estimate_difficulty(&fero), 8);
+
+        let mut hard = word_with(
+            "superlongenunciate, superlongenunciatī",
+            Category::Noun,
+            Some(Declension::Other),
+            None,
+            r#"{"contracted_root": true, "contracted_vocative": true, "onlyperfect": true, "noperfect": true, "sets": {}, "adds": {}}"#,
+            r#"{"en": "a, b, c, d, e"}"#,
+        );
+        hard.regular = false;
+        assert_eq!(estimate_difficulty(&hard), MAX_WEIGHT);
+    }
+
+    #[test]
+    fn test_sampling_key() {
+        assert_eq!(sampling_key(0, 0, 0.0), 0.0);
+        assert_eq!(sampling_key(0, 0, 0.5), 500.0);
+
+        // Heavier words are more likely to go first, and words with more
+        // successful runs less so.
+        assert!(sampling_key(4, 0, 0.5) > sampling_key(0, 0, 0.5));
+        assert!(sampling_key(4, 3, 0.5) < sampling_key(4, 0, 0.5));
     }
 }
@@ -0,0 +1,174 @@
+use crate::word::Word;
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+
+/// Number of consecutive successful reviews which are needed for a word to
+/// increase its number of successful runs.
+pub const MAX_STEPS: usize = 5;
+
+/// Returns the number of successful runs and steps for a word which had the
+/// given `succeeded` and `steps` values after being reviewed, where `correct`
+/// tells whether the user got it right. A word needs `MAX_STEPS` correct
+/// reviews in a row to increase its successful runs, and a wrong review takes
+/// one of them away.
+pub fn next_success(succeeded: isize, steps: isize, correct: bool) -> (isize, isize) {
+    if !correct {
+        if succeeded > 0 {
+            (succeeded - 1, 0)
+        } else {
+            (succeeded, steps)
+        }
+    } else if steps as usize >= MAX_STEPS - 1 {
+        (succeeded + 1, 0)
+    } else {
+        (succeeded, steps + 1)
+    }
+}
+
+// A write to be performed by the writer thread. It's given the transaction in
+// which it runs, and it's responsible for committing it and for sending back
+// its own result.
+type Job = Box<dyn FnOnce(rusqlite::Transaction) + Send>;
+
+/// Serializes writes into the database for long-lived frontends (e.g. 'mihi
+/// serve'). All writes are sent to a single thread which owns the connection
+/// and which runs each of them on its own transaction, one after the other.
+/// This way concurrent submissions cannot interleave their reads and writes
+/// (e.g. two reviews of the same word reading the same success rate). Cloning a
+/// writer is cheap, and all clones share the same thread, which finishes once
+/// all of them are dropped.
+#[derive(Clone)]
+pub struct Writer {
+    sender: mpsc::Sender<Job>,
+}
+
+impl Writer {
+    /// Spawns the writer thread for the database of this application.
+    pub fn spawn() -> Result<Self, String> {
+        Self::open(&crate::get_database_path()?)
+    }
+
+    /// Spawns the writer thread for the database on the given `path`.
+    pub fn open(path: &Path) -> Result<Self, String> {
+        let mut conn = mihi_store::open_connection(path)?;
+
+        let (sender, receiver) = mpsc::channel::<Job>();
+        thread::spawn(move || {
+            for job in receiver {
+                match conn.transaction() {
+                    Ok(tx) => job(tx),
+                    // Nothing can be done for this job other than dropping
+                    // it, which lets its submitter know.
+                    Err(_) => continue,
+                }
+            }
+        });
+
+        Ok(Self { sender })
+    }
+
+    /// Runs the given `write` on the writer thread and waits for its result.
+    /// The write runs on its own transaction, which is rolled back if it
+    /// returns an error.
+    pub fn submit<T, F>(&self, write: F) -> Result<T, String>
+    where
+        T: Send + 'static,
+        F: FnOnce(&rusqlite::Transaction) -> Result<T, String> + Send + 'static,
+    {
+        let (reply, result) = mpsc::channel();
+        let job: Job = Box::new(move |tx| {
+            // Only reply once the transaction is over, so the submitter can
+            // read right away whatever was written.
+            let res = match write(&tx) {
+                Ok(value) => match tx.commit() {
+                    Ok(_) => Ok(value),
+                    Err(e) => Err(format!("could not commit the write: {e}")),
+                },
+                Err(e) => {
+                    let _ = tx.rollback();
+                    Err(e)
+                }
+            };
+            let _ = reply.send(res);
+        });
+
+        if self.sender.send(job).is_err() {
+            return Err("the database writer is no longer running".to_string());
+        }
+        match result.recv() {
+            Ok(res) => res,
+            Err(_) => Err("the write was dropped by the database writer".to_string()),
+        }
+    }
+
+    /// Records the review of the word identified by `word_id`, where `correct`
+    /// tells whether the user got it right, with the configured scheduler. The
+    /// success rate and the schedule are read and updated on the same
+    /// transaction, so concurrent reviews are all taken into account. Returns
+    /// the new number of successful runs and steps.
+    pub fn review(&self, word_id: i32, correct: bool) -> Result<(isize, isize), String> {
+        let scheduler = crate::cfg::configuration().scheduler;
+        self.submit(move |tx| crate::schedule::review_on(tx, word_id, correct, scheduler))
+    }
+
+    /// Creates the given `word` and attaches it to the tag named `tag`, if
+    /// any, which is created on the fly if needed (see
+    /// `tag::find_or_create_tag`). Both happen on the same transaction, so no
+    /// word is left behind if tagging it fails. Returns the ID of the new word.
+    pub fn create_word(&self, word: Word, tag: Option<String>) -> Result<i64, String> {
+        self.submit(move |tx| {
+            let id = crate::word::create_word_on(tx, word)?;
+            if let Some(tag) = tag {
+                let tag_id = crate::tag::find_or_create_tag_on(tx, &tag)?;
+                crate::tag::attach_tag_to_word_on(tx, tag_id, id)?;
+            }
+            Ok(id)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concurrent_reviews() {
+        let path = std::env::temp_dir().join(format!("mihi-writer-{}.sqlite3", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        rusqlite::Connection::open(&path)
+            .unwrap()
+            .execute_batch(
+                "CREATE TABLE words (id integer PRIMARY KEY, succeeded integer, \
+                     steps integer DEFAULT 0 NOT NULL, ease real DEFAULT 2.5 NOT NULL, \
+                     interval_days integer DEFAULT 0 NOT NULL, due_at datetime, \
+                     box integer DEFAULT 1 NOT NULL, last_asked_at datetime, \
+                     updated_at datetime(6)); \
+                 INSERT INTO words (id, succeeded, steps) VALUES (1, 0, 0);",
+            )
+            .unwrap();
+
+        let writer = Writer::open(&path).unwrap();
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let writer = writer.clone();
+                thread::spawn(move || {
+                    for _ in 0..MAX_STEPS * 2 {
+                        writer.review(1, true).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // None of the 80 correct reviews is lost, which makes for 16
+        // successful runs. Then a wrong one takes one back.
+        assert_eq!(writer.review(1, false).unwrap(), (15, 0));
+        assert!(writer.review(2, true).is_err());
+
+        drop(writer);
+        let _ = std::fs::remove_file(&path);
+    }
+}